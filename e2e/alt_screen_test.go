@@ -0,0 +1,47 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+const enterAltScreen = "\x1b[?1049h"
+
+func TestAltScreenByDefault(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	_, err = tf.CreateTestRepo("api")
+	require.NoError(t, err, "Failed to create api repo")
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("api", 5*time.Second), "Should show the repo")
+	require.Contains(t, tf.Snapshot(), enterAltScreen, "Should draw in the alternate screen")
+}
+
+func TestNoAltScreenDrawsInTheNormalScreen(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	_, err = tf.CreateTestRepo("api")
+	require.NoError(t, err, "Failed to create api repo")
+
+	err = tf.StartApp("--no-alt-screen", "-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("api", 5*time.Second), "Should show the repo")
+	require.False(t, strings.Contains(tf.Snapshot(), enterAltScreen), "Should not switch to the alternate screen")
+}
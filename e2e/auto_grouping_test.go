@@ -3,8 +3,11 @@
 package main
 
 import (
+	"encoding/json"
 	"os"
+	"os/exec"
 	"path/filepath"
+	"strings"
 	"testing"
 	"time"
 
@@ -109,3 +112,49 @@ func TestAutomaticGroupCreation(t *testing.T) {
 
 	t.Logf("Automatic grouping test passed - created %d groups from directory structure", groupCount)
 }
+
+func TestAutoGroupByRelativePath(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+
+	// Two different "api" folders that the parent strategy would merge
+	for _, name := range []string{"work/api/billing", "work/api/orders", "oss/api/parser", "oss/api/lexer"} {
+		require.NoError(t, os.MkdirAll(filepath.Join(workspace, filepath.Dir(name)), 0755))
+		_, err := tf.CreateTestRepo(name)
+		require.NoError(t, err, "Failed to create %s repo", name)
+	}
+
+	configPath := filepath.Join(workspace, ".gitagrip.toml")
+	configContent := "version = 1\nbase_dir = \"" + workspace + "\"\n"
+	require.NoError(t, os.WriteFile(configPath, []byte(configContent), 0644))
+
+	out, err := exec.Command(binPath, "group", "auto", "-d", workspace, "-strategy", "path").CombinedOutput()
+	require.NoError(t, err, "group auto failed: %s", out)
+	require.Contains(t, string(out), "Grouped 4 repositories")
+
+	out, err = exec.Command(binPath, "group", "list", "-d", workspace, "-json").CombinedOutput()
+	require.NoError(t, err, "group list failed: %s", out)
+	var groups []struct {
+		Name  string   `json:"name"`
+		Repos []string `json:"repos"`
+	}
+	require.NoError(t, json.Unmarshal(out, &groups))
+	require.Len(t, groups, 2)
+	require.Equal(t, "oss/api", groups[0].Name)
+	require.Len(t, groups[0].Repos, 2)
+	require.Equal(t, "work/api", groups[1].Name)
+	require.Len(t, groups[1].Repos, 2)
+
+	data, err := os.ReadFile(configPath)
+	require.NoError(t, err)
+	require.True(t, strings.Contains(string(data), "[grouping]"), "Strategy should be remembered")
+
+	// Unknown strategies are rejected
+	out, err = exec.Command(binPath, "group", "auto", "-d", workspace, "-strategy", "deep").CombinedOutput()
+	require.Error(t, err)
+	require.Contains(t, string(out), "unknown grouping strategy")
+}
@@ -0,0 +1,42 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"os/exec"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestBranchOverviewCountsReposPerBranch(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	_, err = tf.CreateTestRepo("first-main")
+	require.NoError(t, err, "Failed to create first-main")
+	_, err = tf.CreateTestRepo("second-main")
+	require.NoError(t, err, "Failed to create second-main")
+	featurePath, err := tf.CreateTestRepo("on-feature")
+	require.NoError(t, err, "Failed to create on-feature")
+	out, err := exec.Command("git", "-C", featurePath, "checkout", "-b", "feature/x").CombinedOutput()
+	require.NoError(t, err, "Failed to switch branch: %s", out)
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.SeePlain("on-feature"), "Should show repos")
+
+	require.NoError(t, tf.SendKeys("B"))
+	require.True(t, tf.OutputContainsPlain("Branches", 2*time.Second), "Should open the branch overview")
+	require.True(t, tf.WaitFor(func(string) bool {
+		s := tf.SnapshotPlain()
+		return strings.Contains(s, "main: 2 repos") && strings.Contains(s, "feature/x: 1 repo")
+	}, 5*time.Second), "Should count the repos on each branch")
+	require.True(t, listedBefore(tf.SnapshotPlain(), "main: 2 repos", "feature/x: 1 repo"), "Most common branch should come first")
+}
@@ -0,0 +1,37 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestCreateAndSwitchBranch(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	_, err = tf.CreateTestRepo("branchy")
+	require.NoError(t, err, "Failed to create repo")
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.SeePlain("branchy (main)"), "Should show repo on main")
+
+	// Creating a branch checks it out, without any pull having failed before
+	require.NoError(t, tf.SendKeys("b"))
+	require.True(t, tf.OutputContainsPlain("New branch name:", 2*time.Second), "Should prompt for the branch name")
+	require.NoError(t, tf.SendKeys("feature-x"+KeyEnter))
+	require.True(t, tf.OutputContainsPlain("branchy (feature-x)", 5*time.Second), "Should be on the new branch")
+
+	require.NoError(t, tf.SendKeys("s"))
+	require.True(t, tf.OutputContainsPlain("Switch to branch:", 2*time.Second), "Should prompt for the branch to switch to")
+	require.NoError(t, tf.SendKeys("main"+KeyEnter))
+	require.True(t, tf.OutputContainsPlain("branchy (main)", 5*time.Second), "Should be back on main")
+}
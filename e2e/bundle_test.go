@@ -0,0 +1,90 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"archive/tar"
+	"compress/gzip"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestExportImportBundle(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+
+	apiPath, err := tf.CreateTestRepo("work/api", WithRemote())
+	require.NoError(t, err, "Failed to create api repo")
+
+	webPath, err := tf.CreateTestRepo("work/web")
+	require.NoError(t, err, "Failed to create web repo")
+
+	configContent := "version = 1\n" +
+		"base_dir = \"" + workspace + "\"\n\n" +
+		"forgotten = [\"" + filepath.Join(workspace, "work", "old") + "\"]\n\n" +
+		"[groups]\n" +
+		"Work = [\"" + apiPath + "\", \"" + webPath + "\"]\n\n" +
+		"[repos.\"" + webPath + "\"]\n" +
+		"name = \"Website\"\n\n" +
+		"[[watches]]\n" +
+		"repo = \"" + apiPath + "\"\n" +
+		"branch = \"main\"\n"
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(configContent), 0644))
+
+	// Export the workspace
+	bundlePath := filepath.Join(t.TempDir(), "workspace.tar.gz")
+	out, err := exec.Command(binPath, "export-bundle", "-d", workspace, "-o", bundlePath).CombinedOutput()
+	require.NoError(t, err, "export-bundle failed: %s", out)
+	require.Contains(t, string(out), "2 repositories", "Should report exported repositories")
+
+	// Import into a fresh directory
+	target := t.TempDir()
+	out, err = exec.Command(binPath, "import-bundle", "-d", target, bundlePath).CombinedOutput()
+	require.NoError(t, err, "import-bundle failed: %s", out)
+	require.Contains(t, string(out), "git clone", "Should list clone commands for missing repos")
+
+	imported, err := os.ReadFile(filepath.Join(target, ".gitagrip.toml"))
+	require.NoError(t, err, "Imported config should exist")
+	require.Contains(t, string(imported), filepath.Join(target, "work", "api"), "Group paths should be rebased onto the target directory")
+	require.Contains(t, string(imported), filepath.Join(target, "work", "web"), "[repos] keys should be rebased")
+	require.Contains(t, string(imported), filepath.Join(target, "work", "old"), "Forgotten paths should be rebased")
+	require.NotContains(t, string(imported), workspace, "Old workspace paths should not remain")
+
+	// A second import must not clobber the config without -force
+	out, err = exec.Command(binPath, "import-bundle", "-d", target, bundlePath).CombinedOutput()
+	require.Error(t, err, "import-bundle should refuse to overwrite: %s", out)
+}
+
+func TestImportBundleRejectsPathsOutsideTarget(t *testing.T) {
+	t.Parallel()
+
+	// A hand-made bundle whose manifest climbs out of the target directory
+	manifest := `{"version": 1, "base_dir": "/old", "repos": [{"path": "../../evil", "remote": "https://example.com/evil.git"}]}`
+	bundlePath := filepath.Join(t.TempDir(), "evil.tar.gz")
+	file, err := os.Create(bundlePath)
+	require.NoError(t, err, "Failed to create bundle")
+	gz := gzip.NewWriter(file)
+	tw := tar.NewWriter(gz)
+	for name, data := range map[string]string{"gitagrip.toml": "version = 1\nbase_dir = \"/old\"\n", "manifest.json": manifest} {
+		require.NoError(t, tw.WriteHeader(&tar.Header{Name: name, Mode: 0644, Size: int64(len(data))}))
+		_, err := tw.Write([]byte(data))
+		require.NoError(t, err)
+	}
+	require.NoError(t, tw.Close())
+	require.NoError(t, gz.Close())
+	require.NoError(t, file.Close())
+
+	target := t.TempDir()
+	out, err := exec.Command(binPath, "import-bundle", "-clone", "-d", target, bundlePath).CombinedOutput()
+	require.Error(t, err, "import-bundle should refuse the bundle: %s", out)
+	require.Contains(t, string(out), "outside", "Should name the escaping path")
+	require.NoFileExists(t, filepath.Join(target, ".gitagrip.toml"), "Nothing should be imported")
+}
@@ -0,0 +1,76 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestCleanShowsDryRunBeforeRemoving(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+
+	repoPath, err := tf.CreateTestRepo("artifacts")
+	require.NoError(t, err, "Failed to create artifacts repo")
+
+	// One ignored build directory and one untracked file
+	require.NoError(t, os.WriteFile(filepath.Join(repoPath, ".gitignore"), []byte("build/\n"), 0644))
+	require.NoError(t, os.MkdirAll(filepath.Join(repoPath, "build"), 0755))
+	require.NoError(t, os.WriteFile(filepath.Join(repoPath, "build", "app.bin"), []byte("binary"), 0644))
+	require.NoError(t, os.WriteFile(filepath.Join(repoPath, "scratch.txt"), []byte("notes"), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.SeePlain("artifacts"), "Should show artifacts repo")
+
+	// Dry run is shown first and nothing is removed yet
+	require.NoError(t, tf.SendKeys("X"))
+	require.True(t, tf.SeePlain("would remove 3"), "Should list what git clean would remove")
+	require.DirExists(t, filepath.Join(repoPath, "build"), "Dry run must not remove files")
+
+	// Confirm
+	require.NoError(t, tf.SendKeys("y"))
+	require.Eventually(t, func() bool {
+		_, err := os.Stat(filepath.Join(repoPath, "build"))
+		return os.IsNotExist(err)
+	}, 5*time.Second, 100*time.Millisecond, "Ignored build directory should be removed")
+	require.NoFileExists(t, filepath.Join(repoPath, "scratch.txt"), "Untracked file should be removed")
+}
+
+func TestCleanIgnoresConfirmWithoutAnythingToRemove(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+
+	repoPath, err := tf.CreateTestRepo("tidy")
+	require.NoError(t, err, "Failed to create tidy repo")
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.SeePlain("tidy"), "Should show tidy repo")
+
+	require.NoError(t, tf.SendKeys("X"))
+	require.True(t, tf.SeePlain("Nothing to clean"), "Dry run should find nothing")
+
+	// A file that appears after the dry run was never previewed, so y must not remove it
+	scratch := filepath.Join(repoPath, "scratch.txt")
+	require.NoError(t, os.WriteFile(scratch, []byte("notes"), 0644))
+	require.NoError(t, tf.SendKeys("y"))
+	time.Sleep(500 * time.Millisecond)
+	require.True(t, tf.SeePlain("Nothing to clean"), "Preview should stay open")
+	require.FileExists(t, scratch, "Nothing should be cleaned")
+}
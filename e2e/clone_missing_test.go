@@ -0,0 +1,54 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"fmt"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestCloneMissingClonesConfiguredRepos(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+
+	// A remote to clone from, outside the workspace
+	sourcePath, err := tf.CreateTestRepo("source")
+	require.NoError(t, err, "Failed to create source repo")
+	remotePath := filepath.Join(t.TempDir(), "api.git")
+	out, err := exec.Command("git", "clone", "--bare", sourcePath, remotePath).CombinedOutput()
+	require.NoError(t, err, "git clone --bare: %s", out)
+
+	apiPath := filepath.Join(workspace, "work", "api")
+	brokenPath := filepath.Join(workspace, "work", "broken")
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n\n[groups]\nWork = [%q, %q]\n\n[repos.%q]\nurl = %q\n\n[repos.%q]\nurl = %q\n",
+		workspace, apiPath, brokenPath, apiPath, remotePath, brokenPath, filepath.Join(t.TempDir(), "nowhere.git"))
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	out, err = exec.Command(binPath, "clone-missing", "-d", workspace, "-n").CombinedOutput()
+	require.NoError(t, err, "dry run should succeed: %s", out)
+	require.Contains(t, string(out), apiPath+" <- "+remotePath)
+	_, err = os.Stat(apiPath)
+	require.True(t, os.IsNotExist(err), "Dry run must not clone")
+
+	out, err = exec.Command(binPath, "clone-missing", "-d", workspace).CombinedOutput()
+	require.Error(t, err, "clone-missing should fail when a clone fails: %s", out)
+	require.Contains(t, string(out), "cloned "+apiPath)
+	require.Contains(t, string(out), "failed "+brokenPath)
+	require.Contains(t, string(out), "Cloned 1 of 2 repositories")
+	require.FileExists(t, filepath.Join(apiPath, "README.md"), "api should be cloned")
+
+	// Present repositories are left alone
+	require.NoError(t, os.MkdirAll(brokenPath, 0755))
+	out, err = exec.Command(binPath, "clone-missing", "-d", workspace).CombinedOutput()
+	require.NoError(t, err, "nothing left to clone: %s", out)
+	require.Contains(t, string(out), "Nothing to clone")
+}
@@ -0,0 +1,130 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"encoding/json"
+	"net/http"
+	"net/http/httptest"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+// serveOrg fakes the GitHub API for organization acme, whose repositories clone from
+// bare repos outside the workspace
+func serveOrg(t *testing.T, tf *TUITestFramework, names ...string) *httptest.Server {
+	t.Helper()
+	type repo struct {
+		Name     string `json:"name"`
+		CloneURL string `json:"clone_url"`
+		Archived bool   `json:"archived"`
+		Fork     bool   `json:"fork"`
+	}
+	var repos []repo
+	for _, name := range names {
+		sourcePath, err := tf.CreateTestRepo(filepath.Join("sources", name))
+		require.NoError(t, err, "Failed to create %s source", name)
+		remotePath := filepath.Join(t.TempDir(), name+".git")
+		out, err := exec.Command("git", "clone", "--bare", sourcePath, remotePath).CombinedOutput()
+		require.NoError(t, err, "git clone --bare: %s", out)
+		repos = append(repos, repo{Name: name, CloneURL: remotePath})
+	}
+	require.NoError(t, os.RemoveAll(filepath.Join(tf.workspace, "sources")))
+	repos = append(repos,
+		repo{Name: "old", CloneURL: filepath.Join(t.TempDir(), "old.git"), Archived: true},
+		repo{Name: "theirs", CloneURL: filepath.Join(t.TempDir(), "theirs.git"), Fork: true})
+
+	server := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if r.URL.Path != "/orgs/acme/repos" {
+			http.NotFound(w, r)
+			return
+		}
+		if r.URL.Query().Get("page") != "1" {
+			_, _ = w.Write([]byte("[]"))
+			return
+		}
+		_ = json.NewEncoder(w).Encode(repos)
+	}))
+	t.Cleanup(server.Close)
+	return server
+}
+
+func TestCloneOrgClonesIntoGroup(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	server := serveOrg(t, tf, "api", "web", "tools")
+
+	// A dry run lists them, archived repositories and forks left out
+	out, err := exec.Command(binPath, "clone-org", "-d", workspace, "-org", "acme", "-api", server.URL, "-n").CombinedOutput()
+	require.NoError(t, err, "dry run should succeed: %s", out)
+	apiPath := filepath.Join(workspace, "acme", "api")
+	require.Contains(t, string(out), apiPath+" <- ")
+	require.NotContains(t, string(out), filepath.Join(workspace, "acme", "old"))
+	require.NotContains(t, string(out), filepath.Join(workspace, "acme", "theirs"))
+	_, err = os.Stat(apiPath)
+	require.True(t, os.IsNotExist(err), "Dry run must not clone")
+
+	// Patterns narrow the list down
+	out, err = exec.Command(binPath, "clone-org", "-d", workspace, "-org", "acme", "-api", server.URL, "a*", "web").CombinedOutput()
+	require.NoError(t, err, "clone-org should succeed: %s", out)
+	require.Contains(t, string(out), `Added 2 repositories of acme to group "acme"`)
+	require.FileExists(t, filepath.Join(apiPath, "README.md"), "api should be cloned")
+	require.FileExists(t, filepath.Join(workspace, "acme", "web", "README.md"), "web should be cloned")
+	_, err = os.Stat(filepath.Join(workspace, "acme", "tools"))
+	require.True(t, os.IsNotExist(err), "tools was not asked for")
+
+	config, err := os.ReadFile(filepath.Join(workspace, ".gitagrip.toml"))
+	require.NoError(t, err)
+	require.Contains(t, string(config), "acme = [")
+	require.Contains(t, string(config), filepath.Join(workspace, "acme", "web"))
+}
+
+func TestCloneOrgWizard(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	server := serveOrg(t, tf, "api", "web")
+	_, err = tf.CreateTestRepo(filepath.Join("acme", "web"))
+	require.NoError(t, err, "Failed to create the present repo")
+	content := "version = 1\nbase_dir = \"" + workspace + "\"\n\n[clone_org]\ngithub_api = \"" + server.URL + "\"\n"
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("web", 5*time.Second), "Should show the present repo")
+
+	require.NoError(t, tf.SendKeys("o"))
+	require.True(t, tf.OutputContainsPlain("Clone from organization", 3*time.Second), "Should ask for the organization")
+	require.NoError(t, tf.SendKeys("acme"))
+	require.NoError(t, tf.SendEnter())
+	require.True(t, tf.OutputContainsPlain("Clone from github:acme", 3*time.Second), "Should open the wizard")
+
+	// Only the missing repository is picked; archived ones, forks and present ones are not
+	require.True(t, tf.OutputContainsPlain("[x] api", 5*time.Second), "Should pick the missing repo")
+	require.True(t, tf.WaitFor(func(string) bool {
+		s := tf.SnapshotPlain()
+		return strings.Contains(s, "[ ] old") && strings.Contains(s, "[ ] theirs") && strings.Contains(s, "[ ] web") && strings.Contains(s, "1 of 4 picked")
+	}, 3*time.Second), "Should leave the others unpicked")
+
+	require.NoError(t, tf.SendEnter())
+	require.True(t, tf.OutputContainsPlain("Cloning 1 repos of acme", 3*time.Second), "Should start cloning")
+	require.True(t, tf.WaitFor(func(string) bool {
+		_, err := os.Stat(filepath.Join(workspace, "acme", "api", "README.md"))
+		return err == nil
+	}, 10*time.Second), "Should clone api")
+	require.True(t, tf.OutputContainsPlain("acme", 5*time.Second), "Should show the new group")
+}
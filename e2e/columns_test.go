@@ -0,0 +1,68 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestColumnLayoutAlignsAndTruncates(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	longName := "a-very-long-repository-name-that-goes-on-and-on"
+	_, err = tf.CreateTestRepo(longName)
+	require.NoError(t, err, "Failed to create repo")
+	_, err = tf.CreateTestRepo("short")
+	require.NoError(t, err, "Failed to create repo")
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n\n[ui]\ncolumns = [\"branch\", \"last_commit\"]\n", workspace)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+
+	// The long name is cut to the 40-column cap, and both branches start in the same column
+	aligned := func(screen string) bool {
+		var branchColumns []int
+		for _, line := range strings.Split(screen, "\n") {
+			if strings.Contains(line, "a-very-long-repository") || strings.Contains(line, "short") {
+				if !strings.Contains(line, "main  now GT") {
+					return false
+				}
+				branchColumns = append(branchColumns, strings.Index(line, "main"))
+			}
+		}
+		return len(branchColumns) == 2 && branchColumns[0] == branchColumns[1]
+	}
+	require.True(t, tf.WaitFor(func(string) bool { return aligned(tf.SnapshotPlain()) }, 5*time.Second),
+		"Branches should line up in a column:\n%s", tf.SnapshotPlain())
+	screen := tf.SnapshotPlain()
+	require.NotContains(t, screen, longName, "The long name should be truncated")
+	require.Contains(t, screen, "~  main", "The truncated name should end in an ellipsis")
+}
+
+func TestUnknownColumnIsRejected(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n\n[ui]\ncolumns = [\"name\", \"size\"]\n", workspace)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.OutputContainsPlain(`ui.columns: unknown column "size"`, 3*time.Second), "Should report the unknown column")
+}
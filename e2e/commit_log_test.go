@@ -0,0 +1,72 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"os"
+	"os/exec"
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestCommitLogFiltersByMessageAuthorAndBranches(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	repoPath, err := tf.CreateTestRepo("history-repo")
+	require.NoError(t, err, "Failed to create repo")
+	git := func(args ...string) {
+		out, err := exec.Command("git", append([]string{"-C", repoPath}, args...)...).CombinedOutput()
+		require.NoError(t, err, "git %v: %s", args, out)
+	}
+	commit := func(file, message, author string) {
+		require.NoError(t, os.WriteFile(filepath.Join(repoPath, file), []byte(message), 0644))
+		git("add", file)
+		git("-c", "user.name="+author, "-c", "user.email="+strings.ToLower(author)+"@example.com", "commit", "-m", message)
+	}
+	commit("parser.go", "Fix parser crash on empty input", "Alice")
+	commit("docs.md", "Document the config format", "Bob")
+	git("checkout", "-b", "side")
+	commit("side.go", "Experiment on a side branch", "Alice")
+	git("checkout", "main")
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("history-repo", 5*time.Second), "Should show repo")
+
+	require.NoError(t, tf.SendKeys("H"))
+	require.True(t, tf.WaitFor(func(string) bool {
+		s := tf.SnapshotPlain()
+		return strings.Contains(s, "Commit log: history-repo") && strings.Contains(s, "Document the config format") &&
+			!strings.Contains(s, "Experiment on a side branch")
+	}, 3*time.Second), "Should list the current branch's commits")
+
+	// Words match the message
+	require.NoError(t, tf.SendKeys("/"))
+	require.NoError(t, tf.SendKeys("parser"))
+	require.NoError(t, tf.SendKeys("\r"))
+	require.True(t, tf.WaitFor(func(string) bool {
+		s := tf.SnapshotPlain()
+		return strings.Contains(s, "Fix parser crash") && !strings.Contains(s, "Document the config format")
+	}, 3*time.Second), "Should keep only the commits whose message matches")
+
+	// author: matches the author, and a adds the other branches
+	require.NoError(t, tf.SendKeys("c"))
+	require.NoError(t, tf.SendKeys("/"))
+	require.NoError(t, tf.SendKeys("author:alice"))
+	require.NoError(t, tf.SendKeys("\r"))
+	require.NoError(t, tf.SendKeys("a"))
+	require.True(t, tf.WaitFor(func(string) bool {
+		s := tf.SnapshotPlain()
+		return strings.Contains(s, "all branches") && strings.Contains(s, "Experiment on a side branch") &&
+			strings.Contains(s, "Fix parser crash") && !strings.Contains(s, "Document the config format")
+	}, 3*time.Second), "Should show Alice's commits on every branch")
+}
@@ -0,0 +1,76 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"fmt"
+	"net/http"
+	"net/http/httptest"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestComplianceReportsMissingFiles(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	goodPath, err := tf.CreateTestRepo("good", WithFiles(map[string]string{"LICENSE.md": "MIT", "SECURITY.md": "Report issues"}))
+	require.NoError(t, err, "Failed to create good repo")
+	require.NoError(t, os.MkdirAll(filepath.Join(goodPath, ".github"), 0755))
+	require.NoError(t, os.WriteFile(filepath.Join(goodPath, ".github", "CODEOWNERS"), []byte("* @acme/core\n"), 0644))
+	_, err = tf.CreateTestRepo("bad", WithFiles(map[string]string{"LICENSE": "MIT"}))
+	require.NoError(t, err, "Failed to create bad repo")
+
+	out, err := exec.Command(binPath, "compliance", "-d", workspace).CombinedOutput()
+	require.Error(t, err, "compliance should fail when a repo misses files: %s", out)
+	require.Regexp(t, `PASS\s+good`, string(out))
+	require.Regexp(t, `FAIL\s+bad\s+SECURITY.md: missing; CODEOWNERS: missing`, string(out))
+	require.Contains(t, string(out), "1 of 2 repositories pass")
+	require.Contains(t, string(out), "1 of 2 repositories failed")
+
+	reportPath := filepath.Join(t.TempDir(), "compliance.csv")
+	_, _ = exec.Command(binPath, "compliance", "-d", workspace, "-format", "csv", "-o", reportPath).CombinedOutput()
+	report, err := os.ReadFile(reportPath)
+	require.NoError(t, err, "CSV report should be written")
+	require.Equal(t, "repo,result,LICENSE,SECURITY.md,CODEOWNERS\nbad,fail,pass,fail,fail\ngood,pass,pass,pass,pass\n", string(report))
+}
+
+func TestComplianceChecksBranchProtection(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	forge := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		switch r.URL.Path {
+		case "/repos/acme/locked/branches/main":
+			_, _ = fmt.Fprint(w, `{"name": "main", "protected": true}`)
+		case "/repos/acme/open/branches/main":
+			_, _ = fmt.Fprint(w, `{"name": "main", "protected": false}`)
+		default:
+			http.NotFound(w, r)
+		}
+	}))
+	defer forge.Close()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	for _, name := range []string{"locked", "open"} {
+		repoPath, err := tf.CreateTestRepo(name, WithFiles(map[string]string{"LICENSE": "MIT"}))
+		require.NoError(t, err, "Failed to create %s repo", name)
+		require.NoError(t, exec.Command("git", "-C", repoPath, "remote", "add", "origin", "https://git.example.com/acme/"+name+".git").Run())
+	}
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n\n[compliance]\nrequired_files = [\"LICENSE\"]\nbranch_protection = true\napi_url = %q\n", workspace, forge.URL)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	out, err := exec.Command(binPath, "compliance", "-d", workspace, "-format", "json").CombinedOutput()
+	require.Error(t, err, "compliance should fail for the unprotected repo: %s", out)
+	require.Contains(t, string(out), `"detail": "main is not protected"`)
+	require.Contains(t, string(out), `"failed": 1`)
+}
@@ -0,0 +1,45 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"os"
+	"os/exec"
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestConfigDiffAndTake(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+
+	local := "version = 1\nbase_dir = \"" + workspace + "\"\n\n[groups]\nWork = [\"" + workspace + "/api\"]\n\n[ui]\nshow_ahead_behind = true\n"
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(local), 0644))
+
+	// Same Work group under another home directory, plus a Tools group and a different setting
+	otherPath := filepath.Join(t.TempDir(), "laptop.toml")
+	other := "version = 1\nbase_dir = \"/home/laptop/code\"\n\n[groups]\nWork = [\"/home/laptop/code/api\"]\nTools = [\"/home/laptop/code/cli\"]\n\n[ui]\nshow_ahead_behind = false\n"
+	require.NoError(t, os.WriteFile(otherPath, []byte(other), 0644))
+
+	out, err := exec.Command(binPath, "config", "diff", "-d", workspace, otherPath).CombinedOutput()
+	require.NoError(t, err, "config diff failed: %s", out)
+	require.Contains(t, string(out), "groups.Tools")
+	require.Contains(t, string(out), "ui.show_ahead_behind")
+	require.NotContains(t, string(out), "groups.Work", "rebased group paths should compare equal")
+
+	// Take only the group; the local setting is kept
+	out, err = exec.Command(binPath, "config", "diff", "-d", workspace, "-take", "groups.Tools", otherPath).CombinedOutput()
+	require.NoError(t, err, "config diff -take failed: %s", out)
+	require.Contains(t, string(out), "Took 1 entries")
+
+	saved, err := os.ReadFile(filepath.Join(workspace, ".gitagrip.toml"))
+	require.NoError(t, err)
+	require.Contains(t, string(saved), workspace+"/cli", "taken group should be rebased onto this workspace")
+	require.Contains(t, string(saved), "show_ahead_behind = true")
+}
@@ -0,0 +1,31 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"encoding/json"
+	"os/exec"
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestConfigSchema(t *testing.T) {
+	t.Parallel()
+
+	out, err := exec.Command(binPath, "config", "schema").Output()
+	require.NoError(t, err, "config schema failed")
+
+	var schema struct {
+		Type       string `json:"type"`
+		Properties map[string]struct {
+			Type       string                     `json:"type"`
+			Properties map[string]json.RawMessage `json:"properties"`
+		} `json:"properties"`
+	}
+	require.NoError(t, json.Unmarshal(out, &schema), "schema should be valid JSON")
+	require.Equal(t, "object", schema.Type)
+	require.Equal(t, "object", schema.Properties["groups"].Type)
+	require.Contains(t, schema.Properties["ui"].Properties, "show_ahead_behind")
+	require.Contains(t, string(out), `"solarized"`, "palette names should be listed")
+}
@@ -109,3 +109,39 @@ group_order = []`
 	require.NoError(t, err, "Should be able to read config file")
 	require.Contains(t, string(configContent), "version = 1", "Config should be preserved")
 }
+
+func TestGroupChangesWaitForSaveWithoutAutosave(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	_, err = tf.CreateTestRepo("unsaved-repo")
+	require.NoError(t, err, "Failed to create repo")
+
+	configPath := filepath.Join(workspace, ".gitagrip.toml")
+	config := "version = 1\nbase_dir = \"" + workspace + "\"\n\n[ui]\nautosave_on_exit = false\n"
+	require.NoError(t, os.WriteFile(configPath, []byte(config), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.SeePlain("unsaved-repo"), "Should show repo")
+
+	// A new group stays in memory and the title bar says so
+	require.NoError(t, tf.SendKeys("N"))
+	require.True(t, tf.OutputContainsPlain("tab for a template", 2*time.Second), "Should prompt for the group name")
+	require.NoError(t, tf.SendKeys("Later"+KeyEnter))
+	require.True(t, tf.OutputContainsPlain("[Unsaved]", 2*time.Second), "Should flag unsaved changes")
+	data, err := os.ReadFile(configPath)
+	require.NoError(t, err)
+	require.NotContains(t, string(data), "Later", "Group should not be saved yet")
+
+	// w writes it
+	require.NoError(t, tf.SendKeys("w"))
+	require.True(t, tf.OutputContainsPlain("Config saved", 2*time.Second), "Should confirm the save")
+	data, err = os.ReadFile(configPath)
+	require.NoError(t, err)
+	require.Contains(t, string(data), "Later", "Group should be saved")
+}
@@ -0,0 +1,71 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestConfirmDialogCancelKeepsRepo(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	_, err = tf.CreateTestRepo("keeper")
+	require.NoError(t, err, "Failed to create repo")
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n", workspace)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("keeper (main)", 5*time.Second), "Should show repo")
+
+	require.NoError(t, tf.SendKeys("G"))
+	require.NoError(t, tf.SendKeys(keyDelete))
+	require.True(t, tf.OutputContainsPlain("Forget keeper?", 2*time.Second), "Should open the confirmation dialog")
+	require.True(t, tf.OutputContainsPlain("y confirm • n/esc cancel", time.Second), "Dialog should list its keys")
+
+	// Other keys are swallowed while the dialog is open; n cancels
+	require.NoError(t, tf.SendKeys("j"))
+	require.NoError(t, tf.SendKeys("n"))
+	time.Sleep(300 * time.Millisecond)
+	require.False(t, tf.WaitForStatusMessage("Forgot keeper", 500*time.Millisecond), "Cancelling must not forget the repo")
+	require.True(t, tf.SeePlain("keeper (main)"), "Repo should still be listed")
+}
+
+func TestSkipConfirmationsForgetsRightAway(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	_, err = tf.CreateTestRepo("scratch")
+	require.NoError(t, err, "Failed to create repo")
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n\n[ui]\nskip_confirmations = true\n", workspace)
+	configPath := filepath.Join(workspace, ".gitagrip.toml")
+	require.NoError(t, os.WriteFile(configPath, []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("scratch (main)", 5*time.Second), "Should show repo")
+
+	require.NoError(t, tf.SendKeys("G"))
+	require.NoError(t, tf.SendKeys(keyDelete))
+	require.True(t, tf.WaitForStatusMessage("Forgot scratch", 2*time.Second), "Should forget without asking")
+	require.Eventually(t, func() bool {
+		data, err := os.ReadFile(configPath)
+		return err == nil && strings.Contains(string(data), "forgotten")
+	}, 3*time.Second, 100*time.Millisecond, "Forgotten repo should be saved")
+}
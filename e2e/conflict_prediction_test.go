@@ -0,0 +1,53 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestPullSkipsPredictedConflict(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	repoPath, err := tf.CreateTestRepo("diverged", WithRemote())
+	require.NoError(t, err, "Failed to create repo")
+	require.NoError(t, tf.runGitCommand(repoPath, "branch", "--set-upstream-to=origin/main"))
+
+	// The same line changes upstream and locally
+	clone := filepath.Join(t.TempDir(), "clone")
+	require.NoError(t, tf.runGitCommand("", "clone", filepath.Join(workspace, "diverged-remote.git"), clone))
+	require.NoError(t, os.WriteFile(filepath.Join(clone, "README.md"), []byte("# upstream\n"), 0644))
+	require.NoError(t, tf.runGitCommand(clone, "commit", "-am", "Upstream edit"))
+	require.NoError(t, tf.runGitCommand(clone, "push", "origin", "main"))
+	require.NoError(t, tf.runGitCommand(repoPath, "fetch", "origin"))
+	require.NoError(t, os.WriteFile(filepath.Join(repoPath, "README.md"), []byte("# local\n"), 0644))
+	require.NoError(t, tf.runGitCommand(repoPath, "commit", "-am", "Local edit"))
+
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n\n[guards]\npredict_conflicts = true\n", workspace)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("diverged (main)", 5*time.Second), "Repo should be discovered")
+
+	require.NoError(t, tf.SendKeys("G"))
+	require.NoError(t, tf.Pull())
+	time.Sleep(1 * time.Second)
+	require.NoError(t, tf.SendKeys("i"))
+	require.True(t, tf.OutputContainsPlain("would conflict in README.md", 3*time.Second), "Info should name the conflicting file")
+
+	// The pull never started, so nothing is left half-rebased
+	_, err = os.Stat(filepath.Join(repoPath, ".git", "rebase-merge"))
+	require.True(t, os.IsNotExist(err), "No rebase should be in progress")
+}
@@ -0,0 +1,59 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"os/exec"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestSendDrivesRunningInstance(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	for _, name := range []string{"work/api", "work/web", "standalone"} {
+		_, err := tf.CreateTestRepo(name)
+		require.NoError(t, err)
+	}
+	send := func(args ...string) (string, error) {
+		out, err := exec.Command(binPath, append([]string{"send", "-d", workspace}, args...)...).CombinedOutput()
+		return strings.TrimSpace(string(out)), err
+	}
+
+	// Nothing listens before the TUI runs
+	out, err := send("refresh")
+	require.Error(t, err, "send should fail without a running instance: %s", out)
+	require.Contains(t, out, "no gitagrip is running for this workspace")
+
+	require.NoError(t, tf.StartApp("-d", workspace))
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("standalone (main)", 5*time.Second), "Should show the repos")
+
+	out, err = send("fetch", "group:work")
+	require.NoError(t, err, "fetch: %s", out)
+	require.Equal(t, "ok: fetching 2 repositories", out)
+	require.True(t, tf.OutputContainsPlain("Fetching 2 repos", 3*time.Second), "The TUI should show the fetch")
+
+	// Jumping opens the repository's group and puts the cursor on it
+	out, err = send("jump", "repo:web")
+	require.NoError(t, err, "jump: %s", out)
+	require.Equal(t, "ok", out)
+	require.NoError(t, tf.SendKeys("i"))
+	require.True(t, tf.OutputContainsPlain("/work/web", 3*time.Second), "Info should show the repo jumped to")
+	require.True(t, tf.OutputContainsPlain("Group: work", time.Second), "Info should show its group")
+
+	// Mistakes are reported to the client
+	out, err = send("fetch", "group:nope")
+	require.Error(t, err)
+	require.Contains(t, out, `no group "nope"`)
+	out, err = send("push")
+	require.Error(t, err)
+	require.Contains(t, out, `unknown command "push"`)
+}
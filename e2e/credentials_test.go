@@ -0,0 +1,92 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+// fakeSSH asks for a key passphrase through SSH_ASKPASS like ssh does, and serves the
+// repository locally once it gets the right one
+const fakeSSH = `#!/bin/sh
+answer=$("$SSH_ASKPASS" "Enter passphrase for key 'test_key': ") || exit 255
+if [ "$answer" != "hunter2" ]; then
+	echo "git@example.com: Permission denied (publickey)." >&2
+	exit 255
+fi
+exec sh -c "$2"
+`
+
+// createSSHRepo creates a repository whose origin is reached through fakeSSH
+func createSSHRepo(t *testing.T, tf *TUITestFramework, name string) string {
+	repoPath, err := tf.CreateTestRepo(name, WithRemote())
+	require.NoError(t, err, "Failed to create repo")
+	sshPath := filepath.Join(t.TempDir(), "fake-ssh")
+	require.NoError(t, os.WriteFile(sshPath, []byte(fakeSSH), 0755))
+	remote := filepath.Join(tf.workspace, name+"-remote.git")
+	require.NoError(t, tf.runGitCommand(repoPath, "remote", "set-url", "origin", "ssh://example.com"+remote))
+	require.NoError(t, tf.runGitCommand(repoPath, "config", "core.sshCommand", sshPath))
+	require.NoError(t, tf.runGitCommand(repoPath, "config", "ssh.variant", "simple"))
+	return repoPath
+}
+
+func TestFetchAsksForKeyPassphrase(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	createSSHRepo(t, tf, "private")
+
+	// Someone else pushes to the remote
+	clone := filepath.Join(t.TempDir(), "clone")
+	require.NoError(t, tf.runGitCommand("", "clone", filepath.Join(workspace, "private-remote.git"), clone))
+	require.NoError(t, os.WriteFile(filepath.Join(clone, "new.txt"), []byte("new"), 0644))
+	require.NoError(t, tf.runGitCommand(clone, "add", "."))
+	require.NoError(t, tf.runGitCommand(clone, "commit", "-m", "Upstream change"))
+	require.NoError(t, tf.runGitCommand(clone, "push", "origin", "main"))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("private (main)", 5*time.Second), "Should show the repo")
+
+	require.NoError(t, tf.SendKeys("f"))
+	require.True(t, tf.OutputContainsPlain("private: Enter passphrase for key 'test_key':", 10*time.Second), "Should ask for the passphrase")
+	require.NoError(t, tf.SendKeys("hunter2"))
+	require.True(t, tf.OutputContainsPlain("*******", 2*time.Second), "Should mask the passphrase")
+	require.NoError(t, tf.SendKeys(KeyEnter))
+	require.True(t, tf.WaitForStatusMessage("press U to see what changed", 10*time.Second), "Fetch should finish")
+
+	require.NoError(t, tf.SendKeys("U"))
+	require.True(t, tf.OutputContainsPlain("behind 0 → 1", 5*time.Second), "Fetch should have succeeded")
+}
+
+func TestFetchReportsRejectedKey(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	createSSHRepo(t, tf, "private")
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("private (main)", 5*time.Second), "Should show the repo")
+
+	require.NoError(t, tf.SendKeys("f"))
+	require.True(t, tf.OutputContainsPlain("Enter passphrase for key 'test_key':", 10*time.Second), "Should ask for the passphrase")
+	require.NoError(t, tf.SendKeys("wrong"+KeyEnter))
+	require.True(t, tf.WaitForStatusMessage("press U to see what changed", 10*time.Second), "Fetch should finish")
+
+	require.NoError(t, tf.SendKeys("i"))
+	require.True(t, tf.OutputContainsPlain("Last command failed: SSH key rejected", 5*time.Second), "Should explain the failure")
+}
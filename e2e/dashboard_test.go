@@ -0,0 +1,42 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestDashboardSummarizesStatus(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	_, err = tf.CreateTestRepo("calm-repo")
+	require.NoError(t, err, "Failed to create calm-repo")
+	_, err = tf.CreateTestRepo("messy-repo", WithDirtyState())
+	require.NoError(t, err, "Failed to create messy-repo")
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.SeePlain("messy-repo"), "Should show repos")
+
+	require.NoError(t, tf.SendKeys("#"))
+	require.True(t, tf.OutputContainsPlain("Dashboard", 2*time.Second), "Should open the dashboard")
+	require.True(t, tf.WaitFor(func(string) bool {
+		s := tf.SnapshotPlain()
+		return strings.Contains(s, "2 repos") && strings.Contains(s, "1 dirty") &&
+			strings.Contains(s, "Ungrouped") && strings.Contains(s, "Refresh times")
+	}, 5*time.Second), "Should show totals, the group breakdown and refresh times")
+
+	require.NoError(t, tf.SendKeys(KeyQuit))
+	require.True(t, tf.WaitFor(func(string) bool {
+		return !strings.Contains(tf.SnapshotPlain(), "Dashboard")
+	}, 2*time.Second), "q should close the dashboard")
+}
@@ -0,0 +1,54 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestCommandLogUsesDateFormat(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	_, err = tf.CreateTestRepo("dated-repo", WithRemote())
+	require.NoError(t, err, "Failed to create repo")
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n\n[ui.dates]\nformat = \"day 02 of 01\"\n", workspace)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.SeePlain("dated-repo"), "Should show repo")
+
+	require.NoError(t, tf.SendKeys("G"))
+	require.NoError(t, tf.SendKeys("f"))
+	time.Sleep(500 * time.Millisecond)
+	require.NoError(t, tf.SendKeys("I"))
+
+	expected := fmt.Sprintf("[%s] fetch", time.Now().Format("day 02 of 01"))
+	require.True(t, tf.OutputContainsPlain(expected, 3*time.Second), "Command log should use ui.dates.format")
+}
+
+func TestUnknownDateStyleRefusesToStart(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n\n[ui.dates]\nstyle = \"fuzzy\"\n", workspace)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.OutputContainsPlain(`ui.dates.style: unknown style "fuzzy"`, 3*time.Second), "Should report the invalid style")
+}
@@ -0,0 +1,40 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestDebugLogTailsTheLogFile(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	_, err = tf.CreateTestRepo("api")
+	require.NoError(t, err, "Failed to create api repo")
+
+	tf.dir = workspace
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("api", 5*time.Second), "Should show the repo")
+
+	// Logs go to the XDG state directory, not the working directory
+	logPath := filepath.Join(workspace, ".state", "gitagrip", "logs", "gitagrip.log")
+	_, err = os.Stat(logPath)
+	require.NoError(t, err, "Should write the log file")
+	_, err = os.Stat(filepath.Join(workspace, "gitagrip.log"))
+	require.True(t, os.IsNotExist(err), "Should not log to the working directory")
+
+	require.NoError(t, tf.SendKeys("\x0c"))
+	require.True(t, tf.OutputContainsPlain("Debug log", 3*time.Second), "ctrl+l should open the log view")
+	require.True(t, tf.OutputContainsPlain("workspace opened", 3*time.Second), "Should show the logged records")
+}
@@ -0,0 +1,44 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"os"
+	"os/exec"
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestDetachedHeadShowsCommitAndNearestTag(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	repoPath, err := tf.CreateTestRepo("pinned-repo")
+	require.NoError(t, err, "Failed to create repo")
+	git := func(args ...string) string {
+		out, err := exec.Command("git", append([]string{"-C", repoPath}, args...)...).CombinedOutput()
+		require.NoError(t, err, "git %v: %s", args, out)
+		return strings.TrimSpace(string(out))
+	}
+	git("tag", "v1.4.2")
+	require.NoError(t, os.WriteFile(filepath.Join(repoPath, "next.txt"), []byte("next"), 0644))
+	git("add", "next.txt")
+	git("commit", "-m", "After the release")
+	git("checkout", "--detach")
+	sha := git("rev-parse", "--short", "HEAD")
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("pinned-repo (detached @ "+sha+" · v1.4.2-1-g…)", 5*time.Second), "Should show the commit and nearest tag")
+
+	require.NoError(t, tf.SendKeys("i"))
+	require.True(t, tf.OutputContainsPlain("Branch: detached @ "+sha+" (v1.4.2-1-g", 2*time.Second), "Info should show the full describe output")
+}
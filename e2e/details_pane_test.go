@@ -0,0 +1,66 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestDetailsPaneFollowsTheCursor(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	alphaPath, err := tf.CreateTestRepo("alpha-repo")
+	require.NoError(t, err, "Failed to create repo")
+	betaPath, err := tf.CreateTestRepo("beta-repo", WithRemote())
+	require.NoError(t, err, "Failed to create repo")
+	require.NoError(t, os.WriteFile(filepath.Join(betaPath, "notes.txt"), []byte("notes"), 0644))
+	require.NoError(t, tf.runGitCommand(betaPath, "add", "notes.txt"))
+	require.NoError(t, tf.runGitCommand(betaPath, "commit", "-m", "Add release notes"))
+
+	// The test terminal is 120 columns wide; the cursor starts on the group's header
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n\n[groups]\nWork = [%q, %q]\n\n[ui]\ntwo_pane_width = 100\n",
+		workspace, alphaPath, betaPath)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("Move to a repository to see its details", 5*time.Second), "Should show the pane beside the list")
+
+	require.NoError(t, tf.SendKeys("j"))
+	require.True(t, tf.OutputContainsPlain("Recent commits:", 5*time.Second), "Should read the commits of the repository under the cursor")
+	require.True(t, tf.SeePlain("alpha-repo"), "Should show the repository under the cursor")
+
+	require.NoError(t, tf.SendKeys("j"))
+	require.True(t, tf.OutputContainsPlain("Add release notes", 5*time.Second), "Should follow the cursor to the next repository")
+	require.True(t, tf.SeePlain("origin"), "Should list the remotes")
+}
+
+func TestDetailsPaneNeedsAWideTerminal(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	_, err = tf.CreateTestRepo("narrow-repo")
+	require.NoError(t, err, "Failed to create repo")
+
+	// Under the default threshold of 160 columns
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("narrow-repo (main)", 5*time.Second), "Should show the repo status")
+	require.NoError(t, tf.SendKeys("j"))
+	require.False(t, tf.SeePlain("Recent commits:"), "The details pane should only show on wide terminals")
+}
@@ -0,0 +1,93 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestDirBrowserScansAnotherDirectory(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	_, err = tf.CreateTestRepo(filepath.Join("main", "alpha-repo"))
+	require.NoError(t, err, "Failed to create repo in the base dir")
+	_, err = tf.CreateTestRepo(filepath.Join("other", "beta-repo"))
+	require.NoError(t, err, "Failed to create repo outside the base dir")
+
+	err = tf.StartApp("-d", filepath.Join(workspace, "main"))
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("alpha-repo", 5*time.Second), "Should show the base dir's repo")
+
+	// + browses from the base dir, marking repositories
+	require.NoError(t, tf.SendKeys("+"))
+	require.True(t, tf.OutputContainsPlain("Scan a directory", 3*time.Second), "Should open the browser")
+	require.True(t, tf.OutputContainsPlain("alpha-repo/  (repo)", 3*time.Second), "Should mark repositories")
+
+	// Up to the workspace (cursor on main), down to other, into it and scan it
+	require.NoError(t, tf.SendKeys("h"))
+	require.True(t, tf.OutputContainsPlain("other/", 3*time.Second), "Should list the parent directory")
+	require.NoError(t, tf.SendKeys("j"))
+	require.NoError(t, tf.SendEnter())
+	require.True(t, tf.OutputContainsPlain("beta-repo/  (repo)", 3*time.Second), "Should open the directory")
+	require.NoError(t, tf.SendKeys("s"))
+	require.True(t, tf.WaitFor(func(string) bool {
+		s := tf.SnapshotPlain()
+		return strings.Contains(s, "alpha-repo") && strings.Contains(s, "beta-repo") && !strings.Contains(s, "Scan a directory")
+	}, 5*time.Second), "Should add the scanned directory's repos to the list")
+}
+
+func TestRecentScansAndRescanDropsGoneRepos(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	_, err = tf.CreateTestRepo(filepath.Join("main", "alpha-repo"))
+	require.NoError(t, err, "Failed to create alpha repo")
+	gammaPath, err := tf.CreateTestRepo(filepath.Join("main", "gamma-repo"))
+	require.NoError(t, err, "Failed to create gamma repo")
+	_, err = tf.CreateTestRepo(filepath.Join("other", "beta-repo"))
+	require.NoError(t, err, "Failed to create repo outside the base dir")
+
+	err = tf.StartApp("-d", filepath.Join(workspace, "main"))
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("gamma-repo", 5*time.Second), "Should show the base dir's repos")
+
+	// Scan the other directory through the browser
+	require.NoError(t, tf.SendKeys("+"))
+	require.True(t, tf.OutputContainsPlain("Scan a directory", 3*time.Second), "Should open the browser")
+	require.NoError(t, tf.SendKeys("h"))
+	require.True(t, tf.OutputContainsPlain("other/", 3*time.Second), "Should list the parent directory")
+	require.NoError(t, tf.SendKeys("j"))
+	require.NoError(t, tf.SendEnter())
+	require.True(t, tf.OutputContainsPlain("beta-repo/", 3*time.Second), "Should open the directory")
+	require.NoError(t, tf.SendKeys("s"))
+	require.True(t, tf.OutputContainsPlain("beta-repo", 5*time.Second), "Should add the scanned repo")
+
+	// The browser offers it as a recent directory
+	require.NoError(t, tf.SendKeys("+"))
+	require.True(t, tf.OutputContainsPlain("1 "+filepath.Join(workspace, "other"), 3*time.Second), "Should list the recent scan")
+	require.NoError(t, tf.SendKeys("\x1b"))
+
+	// A deleted repo lingers until ctrl+r rescans both directories
+	require.NoError(t, os.RemoveAll(gammaPath))
+	require.NoError(t, tf.SendKeys("\x12"))
+	require.True(t, tf.WaitForStatusMessage("Rescan complete: 2 repos, 1 gone", 5*time.Second), "Should report the gone repo")
+	require.True(t, tf.WaitFor(func(string) bool {
+		s := tf.SnapshotPlain()
+		return !strings.Contains(s, "gamma-repo") && strings.Contains(s, "alpha-repo") && strings.Contains(s, "beta-repo")
+	}, 3*time.Second), "Should drop only the deleted repo")
+}
@@ -0,0 +1,63 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"fmt"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestDoctorSelfChecksEnvironment(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+
+	cmd := exec.Command(binPath, "doctor", "--self", "-d", workspace)
+	cmd.Env = append(os.Environ(), "XDG_CACHE_HOME="+t.TempDir(), "SSH_AUTH_SOCK=")
+	out, err := cmd.CombinedOutput()
+	require.NoError(t, err, "doctor should pass in a healthy environment: %s", out)
+	require.Regexp(t, `OK\s+git\s+git version`, string(out))
+	require.Regexp(t, `OK\s+cache\s+.* is writable`, string(out))
+	require.Regexp(t, `WARN\s+ssh-agent\s+SSH_AUTH_SOCK is not set`, string(out))
+	require.Contains(t, string(out), "fix: start an agent")
+}
+
+func TestDoctorSelfReportsInvalidConfig(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n\n[stale]\nafter = \"soon\"\n", workspace)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	out, err := exec.Command(binPath, "doctor", "--self", "-d", workspace).CombinedOutput()
+	require.Error(t, err, "doctor should fail with an invalid config: %s", out)
+	require.Regexp(t, `FAIL\s+config\s+stale.after: invalid age "soon"`, string(out))
+	require.Contains(t, string(out), "1 of 7 checks failed")
+}
+
+func TestDoctorSelfRefusesLibgit2Backend(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n\n[git]\nbackend = \"libgit2\"\n", workspace)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	out, err := exec.Command(binPath, "doctor", "--self", "-d", workspace).CombinedOutput()
+	require.Error(t, err, "doctor should fail with the libgit2 backend: %s", out)
+	require.Regexp(t, `FAIL\s+backend\s+git.backend: "libgit2" is not available`, string(out))
+	require.Contains(t, string(out), `fix: set [git] backend = "cli"`)
+}
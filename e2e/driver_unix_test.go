@@ -4,6 +4,7 @@ package main
 
 import (
 	"fmt"
+	"io"
 	"os"
 	"os/exec"
 	"path/filepath"
@@ -49,6 +50,8 @@ type TUITestFramework struct {
 	tty       *os.File
 	cmd       *exec.Cmd
 	workspace string
+	stdout    io.Writer // receives the app's stdout instead of the terminal when set
+	dir       string    // working directory of the app ("" for the test's)
 
 	// Ring buffer for continuous output capture
 	mu   sync.Mutex
@@ -73,14 +76,17 @@ func (tf *TUITestFramework) StartApp(args ...string) error {
 	// Build the command
 	cmdArgs := append([]string{binPath}, args...)
 	tf.cmd = exec.Command(cmdArgs[0], cmdArgs[1:]...)
+	tf.cmd.Dir = tf.dir
 
 	// Set per-process environment variables
 	tf.cmd.Env = append(os.Environ(),
 		"TERM=xterm-256color",
 		"LC_ALL=C",
 		"LANG=C",
-		"HOME="+tf.workspace,          // isolate $HOME
-		"GIT_CONFIG_GLOBAL=/dev/null", // ignore user ~/.gitconfig
+		"HOME="+tf.workspace,                                      // isolate $HOME
+		"XDG_CONFIG_HOME="+filepath.Join(tf.workspace, ".config"), // isolate profiles
+		"XDG_STATE_HOME="+filepath.Join(tf.workspace, ".state"),   // isolate logs
+		"GIT_CONFIG_GLOBAL=/dev/null",                             // ignore user ~/.gitconfig
 		"GITAGRIP_E2E_TEST=1",
 	)
 
@@ -95,6 +101,9 @@ func (tf *TUITestFramework) StartApp(args ...string) error {
 	tf.cmd.Stdout = tty
 	tf.cmd.Stdin = tty
 	tf.cmd.Stderr = tty
+	if tf.stdout != nil {
+		tf.cmd.Stdout = tf.stdout
+	}
 
 	// Set terminal size
 	ws := struct {
@@ -0,0 +1,34 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestEmptyRepoIsShownWithoutError(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	_, err = tf.CreateTestRepo("fresh-repo", WithCommit(false))
+	require.NoError(t, err, "Failed to create fresh-repo")
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("fresh-repo (empty)", 5*time.Second), "Empty repo should be marked as such")
+	require.False(t, tf.SeePlain("✗"), "Empty repo should not be flagged as failed")
+
+	require.NoError(t, tf.SendKeys("i"))
+	require.True(t, tf.OutputContainsPlain("Branch: main (empty, no commits yet)", 2*time.Second), "Info should name the unborn branch")
+	require.NoError(t, tf.SendKeys("i"))
+
+	require.NoError(t, tf.SendKeys("H"))
+	require.True(t, tf.OutputContainsPlain("fresh-repo has no commits yet", 2*time.Second), "Log should explain there is nothing to show")
+}
@@ -0,0 +1,88 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"os"
+	"os/exec"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestFetchCommandOptions(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	repoPath, err := tf.CreateTestRepo("tagged", WithRemote())
+	require.NoError(t, err, "Failed to create repo")
+	// A second remote that can't be reached
+	require.NoError(t, tf.runGitCommand(repoPath, "remote", "add", "mirror", filepath.Join(t.TempDir(), "nowhere.git")))
+
+	// Someone else tags a commit that isn't on any branch
+	clone := filepath.Join(t.TempDir(), "clone")
+	require.NoError(t, tf.runGitCommand("", "clone", filepath.Join(workspace, "tagged-remote.git"), clone))
+	require.NoError(t, tf.runGitCommand(clone, "commit", "--allow-empty", "-m", "Release"))
+	require.NoError(t, tf.runGitCommand(clone, "tag", "v1.0"))
+	require.NoError(t, tf.runGitCommand(clone, "push", "origin", "v1.0"))
+
+	out, err := exec.Command(binPath, "fetch", "-d", workspace, "-tags", "sometimes").CombinedOutput()
+	require.Error(t, err, "An unknown tags mode should be refused: %s", out)
+	require.Contains(t, string(out), "unknown tags mode")
+
+	out, err = exec.Command(binPath, "fetch", "-d", workspace).CombinedOutput()
+	require.Error(t, err, "Fetching every remote should fail on the unreachable one: %s", out)
+	require.Contains(t, string(out), "failed "+repoPath)
+
+	out, err = exec.Command(binPath, "fetch", "-d", workspace, "-remote", "origin", "-tags", "none", "tagged").CombinedOutput()
+	require.NoError(t, err, "Fetching only origin should succeed: %s", out)
+	require.Contains(t, string(out), "Fetched 1 of 1 repositories")
+	require.Error(t, tf.runGitCommand(repoPath, "rev-parse", "--verify", "refs/tags/v1.0"), "No tags should be fetched")
+
+	out, err = exec.Command(binPath, "fetch", "-d", workspace, "-remote", "origin", "-tags", "all").CombinedOutput()
+	require.NoError(t, err, "Fetching all tags should succeed: %s", out)
+	require.NoError(t, tf.runGitCommand(repoPath, "rev-parse", "--verify", "refs/tags/v1.0"), "The tag should be fetched")
+}
+
+func TestFetchOptionsPopup(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	repoPath, err := tf.CreateTestRepo("tagged", WithRemote())
+	require.NoError(t, err, "Failed to create repo")
+	require.NoError(t, tf.runGitCommand(repoPath, "remote", "add", "mirror", filepath.Join(t.TempDir(), "nowhere.git")))
+
+	clone := filepath.Join(t.TempDir(), "clone")
+	require.NoError(t, tf.runGitCommand("", "clone", filepath.Join(workspace, "tagged-remote.git"), clone))
+	require.NoError(t, os.WriteFile(filepath.Join(clone, "new.txt"), []byte("new"), 0644))
+	require.NoError(t, tf.runGitCommand(clone, "add", "."))
+	require.NoError(t, tf.runGitCommand(clone, "commit", "-m", "Upstream change"))
+	require.NoError(t, tf.runGitCommand(clone, "push", "origin", "main"))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("tagged (main)", 5*time.Second), "Should show the repo")
+
+	require.NoError(t, tf.SendKeys("O"))
+	require.True(t, tf.OutputContainsPlain("git fetch --prune --all", 5*time.Second), "Should preview fetching every remote")
+
+	// Remotes cycle in name order after "all remotes": mirror, then origin
+	require.NoError(t, tf.SendKeys("r"))
+	require.NoError(t, tf.SendKeys("r"))
+	require.NoError(t, tf.SendKeys("t"))
+	require.True(t, tf.OutputContainsPlain("git fetch --prune --tags -- origin", 5*time.Second), "Should preview the chosen options")
+
+	require.NoError(t, tf.SendKeys(KeyEnter))
+	require.True(t, tf.WaitForStatusMessage("press U to see what changed", 15*time.Second), "Fetch should finish")
+	require.NoError(t, tf.SendKeys("U"))
+	require.True(t, tf.OutputContainsPlain("behind 0 → 1", 5*time.Second), "Fetching origin alone should succeed")
+}
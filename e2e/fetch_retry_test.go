@@ -0,0 +1,63 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+// flakySSH drops the first connection like a flaky network does and serves the
+// repository locally from then on
+const flakySSH = `#!/bin/sh
+marker="%s"
+if [ ! -e "$marker" ]; then
+	touch "$marker"
+	echo "Connection reset by peer" >&2
+	echo "fatal: The remote end hung up unexpectedly" >&2
+	exit 255
+fi
+exec sh -c "$2"
+`
+
+func TestFetchRetriesTransientFailure(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	repoPath, err := tf.CreateTestRepo("flaky", WithRemote())
+	require.NoError(t, err, "Failed to create repo")
+
+	sshPath := filepath.Join(t.TempDir(), "flaky-ssh")
+	script := fmt.Sprintf(flakySSH, filepath.Join(t.TempDir(), "dropped"))
+	require.NoError(t, os.WriteFile(sshPath, []byte(script), 0755))
+	remote := filepath.Join(workspace, "flaky-remote.git")
+	require.NoError(t, tf.runGitCommand(repoPath, "remote", "set-url", "origin", "ssh://example.com"+remote))
+	require.NoError(t, tf.runGitCommand(repoPath, "config", "core.sshCommand", sshPath))
+	require.NoError(t, tf.runGitCommand(repoPath, "config", "ssh.variant", "simple"))
+
+	// Someone else pushes to the remote
+	clone := filepath.Join(t.TempDir(), "clone")
+	require.NoError(t, tf.runGitCommand("", "clone", remote, clone))
+	require.NoError(t, os.WriteFile(filepath.Join(clone, "new.txt"), []byte("new"), 0644))
+	require.NoError(t, tf.runGitCommand(clone, "add", "."))
+	require.NoError(t, tf.runGitCommand(clone, "commit", "-m", "Upstream change"))
+	require.NoError(t, tf.runGitCommand(clone, "push", "origin", "main"))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("flaky (main)", 5*time.Second), "Should show the repo")
+
+	require.NoError(t, tf.SendKeys("f"))
+	require.True(t, tf.WaitForStatusMessage("press U to see what changed", 15*time.Second), "Fetch should finish")
+	require.NoError(t, tf.SendKeys("U"))
+	require.True(t, tf.OutputContainsPlain("behind 0 → 1", 5*time.Second), "The second attempt should have fetched")
+}
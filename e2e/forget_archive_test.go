@@ -0,0 +1,144 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+const (
+	keyDelete = "\x1b[3~"
+	keyCtrlZ  = "\x1a"
+)
+
+func TestForgetRepoAndUndo(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	repoPath, err := tf.CreateTestRepo("old-notes")
+	require.NoError(t, err, "Failed to create repo")
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n", workspace)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("old-notes (main)", 5*time.Second), "Should show repo")
+
+	require.NoError(t, tf.SendKeys("G"))
+	require.NoError(t, tf.SendKeys(keyDelete))
+	require.True(t, tf.OutputContainsPlain("Forget old-notes?", 2*time.Second), "Should ask before forgetting")
+	require.NoError(t, tf.SendKeys("y"))
+	require.True(t, tf.WaitForStatusMessage("Forgot old-notes", 2*time.Second), "Should forget the repo")
+	require.DirExists(t, repoPath, "Forgetting must not touch the disk")
+	require.Eventually(t, func() bool {
+		data, err := os.ReadFile(filepath.Join(workspace, ".gitagrip.toml"))
+		return err == nil && strings.Contains(string(data), "forgotten") && strings.Contains(string(data), "old-notes")
+	}, 3*time.Second, 100*time.Millisecond, "Forgotten repo should be saved")
+
+	require.NoError(t, tf.SendKeys(keyCtrlZ))
+	require.True(t, tf.WaitForStatusMessage("Restored old-notes", 2*time.Second), "Undo should bring it back")
+	require.Eventually(t, func() bool {
+		data, err := os.ReadFile(filepath.Join(workspace, ".gitagrip.toml"))
+		return err == nil && !strings.Contains(string(data), "old-notes")
+	}, 3*time.Second, 100*time.Millisecond, "Restored repo should no longer be forgotten")
+}
+
+func TestArchiveRepoAndUndo(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	repoPath, err := tf.CreateTestRepo("retired")
+	require.NoError(t, err, "Failed to create repo")
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n", workspace)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("retired (main)", 5*time.Second), "Should show repo")
+
+	// Nothing happens before confirming
+	require.NoError(t, tf.SendKeys("G"))
+	require.NoError(t, tf.SendKeys("Z"))
+	require.True(t, tf.OutputContainsPlain("git bundle create", 2*time.Second), "Should preview the archive")
+	require.DirExists(t, repoPath, "Preview must not move the repo")
+
+	archived := filepath.Join(workspace, ".archive", "retired")
+	require.NoError(t, tf.SendKeys("y"))
+	require.Eventually(t, func() bool {
+		_, err := os.Stat(archived)
+		return err == nil
+	}, 5*time.Second, 100*time.Millisecond, "Repo should be moved to the archive directory")
+	require.FileExists(t, archived+".bundle", "Bundle should be written next to it")
+	require.NoDirExists(t, repoPath, "Repo should be gone from its old place")
+
+	require.NoError(t, tf.SendKeys(keyCtrlZ))
+	require.True(t, tf.WaitForStatusMessage("Restored retired", 2*time.Second), "Undo should bring it back")
+	require.DirExists(t, repoPath, "Repo should be moved back")
+	require.NoFileExists(t, archived+".bundle", "Bundle should be removed")
+}
+
+func TestArchiveRefusesDirtyRepo(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	repoPath, err := tf.CreateTestRepo("busy", WithDirtyState())
+	require.NoError(t, err, "Failed to create repo")
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("busy (main)", 5*time.Second), "Should show repo")
+
+	require.NoError(t, tf.SendKeys("G"))
+	require.NoError(t, tf.SendKeys("Z"))
+	require.True(t, tf.OutputContainsPlain("uncommitted changes", 2*time.Second), "Should refuse dirty repos")
+	require.NoError(t, tf.SendKeys("y"))
+	time.Sleep(300 * time.Millisecond)
+	require.DirExists(t, repoPath, "Dirty repo must stay in place")
+}
+
+func TestArchiveRefusesRepoWithLinkedWorktree(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	repoPath, err := tf.CreateTestRepo("service")
+	require.NoError(t, err, "Failed to create repo")
+	worktreePath := filepath.Join(workspace, "service-feature")
+	require.NoError(t, tf.runGitCommand(repoPath, "worktree", "add", "-b", "feature", worktreePath), "Failed to add worktree")
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("service-feature (feature)", 5*time.Second), "Should show the worktree")
+
+	// The worktree is listed below its main repository
+	require.NoError(t, tf.SendKeys("G"))
+	require.NoError(t, tf.SendKeys("k"))
+	require.NoError(t, tf.SendKeys("Z"))
+	require.True(t, tf.OutputContainsPlain("has a linked worktree", 2*time.Second), "Should refuse repos with linked worktrees")
+	require.NoError(t, tf.SendKeys("y"))
+	time.Sleep(300 * time.Millisecond)
+	require.DirExists(t, repoPath, "Main repo must stay in place")
+	require.FileExists(t, filepath.Join(worktreePath, ".git"), "Worktree must keep its link")
+}
@@ -141,3 +141,26 @@ func TestGitOperationsWithSelection(t *testing.T) {
 
 	t.Logf("✅ Bulk operations test passed - UI handled operations on selected repos")
 }
+
+func TestPullSkipsDirtyRepo(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+
+	_, err = tf.CreateTestRepo("dirty-pull-repo", WithRemote(), WithCommit(true), WithDirtyState())
+	require.NoError(t, err, "Failed to create test repo")
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("dirty-pull-repo", 5*time.Second), "Repo should be discovered")
+
+	// The guard refuses to pull over uncommitted changes and records why
+	require.NoError(t, tf.Pull())
+	time.Sleep(500 * time.Millisecond)
+	require.NoError(t, tf.SendKeys("i"))
+	require.True(t, tf.SeePlain("pull skipped"), "Info should show the skip reason")
+}
@@ -0,0 +1,59 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"encoding/json"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestGroupSubcommands(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+
+	apiPath, err := tf.CreateTestRepo("api")
+	require.NoError(t, err, "Failed to create api repo")
+	webPath, err := tf.CreateTestRepo("web")
+	require.NoError(t, err, "Failed to create web repo")
+
+	configContent := "version = 1\nbase_dir = \"" + workspace + "\"\n"
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(configContent), 0644))
+
+	run := func(args ...string) string {
+		out, err := exec.Command(binPath, args...).CombinedOutput()
+		require.NoError(t, err, "gitagrip %v failed: %s", args, out)
+		return string(out)
+	}
+
+	run("group", "add", "-d", workspace, "Work", apiPath, webPath)
+	run("group", "add", "-d", workspace, "Tools")
+	run("group", "move", "-d", workspace, "Tools", webPath)
+
+	var groups []struct {
+		Name  string   `json:"name"`
+		Repos []string `json:"repos"`
+	}
+	require.NoError(t, json.Unmarshal([]byte(run("group", "list", "-d", workspace, "-json")), &groups))
+	require.Len(t, groups, 2)
+	require.Equal(t, "Work", groups[0].Name)
+	require.Equal(t, []string{apiPath}, groups[0].Repos, "web should have moved out of Work")
+	require.Equal(t, "Tools", groups[1].Name)
+	require.Equal(t, []string{webPath}, groups[1].Repos)
+
+	run("group", "rm", "-d", workspace, "Tools")
+	require.NotContains(t, run("group", "list", "-d", workspace), "Tools")
+
+	// Adding an existing group is an error
+	out, err := exec.Command(binPath, "group", "add", "-d", workspace, "Work").CombinedOutput()
+	require.Error(t, err, "duplicate group should fail: %s", out)
+	require.Contains(t, string(out), "already exists")
+}
@@ -0,0 +1,84 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestGroupColorMarksItsRepos(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	apiPath, err := tf.CreateTestRepo("api")
+	require.NoError(t, err, "Failed to create repo")
+	_, err = tf.CreateTestRepo("loose")
+	require.NoError(t, err, "Failed to create repo")
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n\n[groups]\nWork = [%q]\n\n[group_colors]\nWork = \"blue\"\n", workspace, apiPath)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	// LC_ALL=C: the ASCII gutter marker takes the first cell of the indent
+	require.True(t, tf.OutputContainsPlain("| + api (main)", 5*time.Second), "Repos of a colored group should carry the gutter marker")
+	require.True(t, tf.OutputContainsPlain("+ loose (main)", 5*time.Second), "Should show the ungrouped repo")
+	require.NotContains(t, tf.SnapshotPlain(), "| + loose", "Repos outside colored groups have no marker")
+}
+
+func TestGroupColorPickerSavesTheColor(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	apiPath, err := tf.CreateTestRepo("api")
+	require.NoError(t, err, "Failed to create repo")
+	configPath := filepath.Join(workspace, ".gitagrip.toml")
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n\n[groups]\nWork = [%q]\n", workspace, apiPath)
+	require.NoError(t, os.WriteFile(configPath, []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("  + api (main)", 5*time.Second), "Should show the repo without a marker")
+
+	// The cursor starts on the Work header
+	require.NoError(t, tf.SendKeys("c"))
+	require.True(t, tf.OutputContainsPlain("Color of Work", 2*time.Second), "Should open the color picker")
+	require.True(t, tf.SeePlain("2 | orange"), "Should list the palette")
+	require.NoError(t, tf.SendKeys("2"))
+	require.True(t, tf.WaitForStatusMessage("Colored group 'Work' orange", 2*time.Second), "Should color the group")
+	require.True(t, tf.OutputContainsPlain("| + api (main)", 2*time.Second), "The group's repos should get the marker")
+
+	require.True(t, tf.WaitFor(func(string) bool {
+		data, err := os.ReadFile(configPath)
+		return err == nil && strings.Contains(string(data), "[group_colors]") && strings.Contains(string(data), "orange")
+	}, 3*time.Second), "The color should be saved in [group_colors]")
+}
+
+func TestInvalidGroupColorIsRejected(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n\n[group_colors]\nWork = \"mauve\"\n", workspace)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.OutputContainsPlain(`group_colors: invalid color "mauve" for Work`, 3*time.Second), "Should report the bad color")
+}
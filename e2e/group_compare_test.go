@@ -0,0 +1,56 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"fmt"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestCompareGroupsSideBySide(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	legacyPath, err := tf.CreateTestRepo("legacy-svc", WithDirtyState())
+	require.NoError(t, err, "Failed to create legacy-svc")
+	migratedPath, err := tf.CreateTestRepo("migrated-svc")
+	require.NoError(t, err, "Failed to create migrated-svc")
+	out, err := exec.Command("git", "-C", migratedPath, "tag", "v2.0.0").CombinedOutput()
+	require.NoError(t, err, "Failed to tag: %s", out)
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\ngroup_order = [\"Old CI\", \"New CI\"]\n\n[groups]\n\"Old CI\" = [%q]\n\"New CI\" = [%q]\n",
+		workspace, legacyPath, migratedPath)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.SeePlain("migrated-svc"), "Should show repos")
+
+	// Mark the first group, then compare it with the second one
+	require.NoError(t, tf.SendKeys("C"))
+	require.True(t, tf.OutputContainsPlain("compare it with 'Old CI'", 2*time.Second), "Should mark the first group")
+	require.NoError(t, tf.SendKeys("j"))
+	require.NoError(t, tf.SendKeys("j"))
+	require.NoError(t, tf.SendKeys("C"))
+	require.True(t, tf.OutputContainsPlain("Compare groups", 2*time.Second), "Should open the comparison")
+	require.True(t, tf.WaitFor(func(string) bool {
+		s := tf.SnapshotPlain()
+		return strings.Contains(s, "1 dirty, 0 behind") && strings.Contains(s, "main  v2.0.0")
+	}, 5*time.Second), "Should show the status totals and latest tags")
+	require.True(t, listedBefore(tf.SnapshotPlain(), "Old CI", "New CI"), "Marked group should be on the left")
+
+	require.NoError(t, tf.SendKeys("q"))
+	require.True(t, tf.WaitFor(func(string) bool {
+		return !strings.Contains(tf.SnapshotPlain(), "Compare groups")
+	}, 2*time.Second), "Should close the comparison")
+}
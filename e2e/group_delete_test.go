@@ -0,0 +1,53 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestDeleteGroupUnderCursor(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	apiPath, err := tf.CreateTestRepo("api")
+	require.NoError(t, err, "Failed to create api repo")
+	cliPath, err := tf.CreateTestRepo("cli")
+	require.NoError(t, err, "Failed to create cli repo")
+
+	configPath := filepath.Join(workspace, ".gitagrip.toml")
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\ngroup_order = [\"Work\", \"Tools\"]\n\n[groups]\nWork = [%q]\nTools = [%q]\n", workspace, apiPath, cliPath)
+	require.NoError(t, os.WriteFile(configPath, []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.SeePlain("Tools"), "Should show groups")
+
+	// Move onto the Tools header: last repo, then one up
+	require.NoError(t, tf.SendKeys("G"))
+	require.NoError(t, tf.SendKeys("k"))
+
+	// The prompt names the group under the cursor and says where its repos go
+	require.NoError(t, tf.SendKeys("d"))
+	require.True(t, tf.OutputContainsPlain("Delete group 'Tools' and move its 1 repos to Ungrouped?", 2*time.Second), "Should confirm deleting Tools")
+
+	require.NoError(t, tf.SendKeys("y"))
+	require.True(t, tf.WaitForStatusMessage("Deleted group 'Tools', moved 1 repos to Ungrouped", 2*time.Second), "Tools should be deleted")
+	require.True(t, tf.SeePlain("Ungrouped"), "cli should now be ungrouped")
+
+	require.Eventually(t, func() bool {
+		data, err := os.ReadFile(configPath)
+		return err == nil && !strings.Contains(string(data), "Tools") && strings.Contains(string(data), "Work")
+	}, 3*time.Second, 100*time.Millisecond, "Config should only keep the Work group")
+}
@@ -0,0 +1,46 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestGroupHeaderScopesRefreshFetchAndPull(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	apiPath, err := tf.CreateTestRepo("api")
+	require.NoError(t, err, "Failed to create api repo")
+	webPath, err := tf.CreateTestRepo("web")
+	require.NoError(t, err, "Failed to create web repo")
+	_, err = tf.CreateTestRepo("misc")
+	require.NoError(t, err, "Failed to create misc repo")
+
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n\n[groups]\nWork = [%q, %q]\n", workspace, apiPath, webPath)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("misc", 5*time.Second), "Should show repos")
+
+	// The cursor starts on the Work header
+	require.NoError(t, tf.SendKeys("r"))
+	require.True(t, tf.WaitForStatusMessage("Refreshing all repos in 'Work'", 2*time.Second), "r should refresh the group")
+	require.NoError(t, tf.SendKeys("f"))
+	require.True(t, tf.WaitForStatusMessage("Fetching all repos in 'Work'", 2*time.Second), "f should fetch the group")
+	require.NoError(t, tf.SendKeys("p"))
+	require.True(t, tf.OutputContainsPlain("Pull 0 of the repos in Work", 3*time.Second), "p should pull the group, dry run first")
+	require.True(t, tf.OutputContainsPlain("no upstream", 3*time.Second), "Repos without upstream should be left out")
+	require.NoError(t, tf.SendKeys("n"))
+}
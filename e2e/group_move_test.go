@@ -0,0 +1,45 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestMoveUngroupedRepoToNewGroup(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	_, err = tf.CreateTestRepo("loose-repo")
+	require.NoError(t, err, "Failed to create repo")
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n", workspace)
+	configPath := filepath.Join(workspace, ".gitagrip.toml")
+	require.NoError(t, os.WriteFile(configPath, []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.SeePlain("loose-repo"), "Should show repo")
+
+	// The only row is the ungrouped repository
+	require.NoError(t, tf.SendKeys("m"))
+	require.True(t, tf.OutputContainsPlain("Move to group:", 2*time.Second), "Should prompt for a group")
+	require.NoError(t, tf.SendKeys("tools"))
+	require.NoError(t, tf.SendKeys(KeyEnter))
+	require.True(t, tf.OutputContainsPlain("Moved 1 repos to 'tools'", 2*time.Second), "Should move the repo")
+
+	require.True(t, tf.WaitFor(func(string) bool {
+		data, err := os.ReadFile(configPath)
+		return err == nil && strings.Contains(string(data), "tools") && strings.Contains(string(data), "loose-repo")
+	}, 3*time.Second), "Config should record the new group")
+}
@@ -0,0 +1,55 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestRenameGroupFromHeader(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	apiPath, err := tf.CreateTestRepo("api")
+	require.NoError(t, err, "Failed to create api repo")
+	cliPath, err := tf.CreateTestRepo("cli")
+	require.NoError(t, err, "Failed to create cli repo")
+
+	configPath := filepath.Join(workspace, ".gitagrip.toml")
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\ngroup_order = [\"Work\", \"Tools\"]\n\n[groups]\nWork = [%q]\nTools = [%q]\n", workspace, apiPath, cliPath)
+	require.NoError(t, os.WriteFile(configPath, []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.SeePlain("Tools"), "Should show groups")
+
+	// R on the Work header opens the prompt with the current name
+	require.NoError(t, tf.SendKeys("R"))
+	require.True(t, tf.OutputContainsPlain("Work", 2*time.Second), "Prompt should be pre-filled")
+	erase := strings.Repeat("\x7f", len("Work"))
+
+	// Existing names are refused
+	require.NoError(t, tf.SendKeys(erase+"Tools"+KeyEnter))
+	require.True(t, tf.WaitForStatusMessage("Group 'Tools' already exists", 2*time.Second), "Collision should be refused")
+
+	require.NoError(t, tf.SendKeys("R"))
+	require.NoError(t, tf.SendKeys(erase+"Platform"+KeyEnter))
+	require.True(t, tf.WaitForStatusMessage("Renamed group 'Work' to 'Platform'", 2*time.Second), "Group should be renamed")
+	require.True(t, tf.SeePlain("api"), "Renamed group should keep its repos expanded")
+
+	require.Eventually(t, func() bool {
+		data, err := os.ReadFile(configPath)
+		return err == nil && strings.Contains(string(data), "Platform") && !strings.Contains(string(data), "Work")
+	}, 3*time.Second, 100*time.Millisecond, "Config should be saved under the new name")
+}
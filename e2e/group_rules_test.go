@@ -0,0 +1,53 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestGroupRulesAssignDiscoveredRepos(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+
+	require.NoError(t, os.MkdirAll(filepath.Join(workspace, "clients"), 0755))
+	_, err = tf.CreateTestRepo("clients/acme-web")
+	require.NoError(t, err, "Failed to create acme-web repo")
+	toolPath, err := tf.CreateTestRepo("tool")
+	require.NoError(t, err, "Failed to create tool repo")
+	require.NoError(t, tf.runGitCommand(toolPath, "remote", "add", "origin", "https://github.com/myorg/tool.git"))
+	_, err = tf.CreateTestRepo("scratch")
+	require.NoError(t, err, "Failed to create scratch repo")
+
+	content := fmt.Sprintf(`version = 1
+base_dir = %q
+
+[[rules]]
+pattern = "**/acme-*"
+group = "Acme"
+
+[[rules]]
+remote = "github.com/myorg"
+group = "MyOrg"
+`, workspace)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+
+	// Path rules apply on discovery, remote rules once the remote is known
+	require.True(t, tf.OutputContainsPlain("Acme", 5*time.Second), "Acme group should be created by the pattern rule")
+	require.True(t, tf.OutputContainsPlain("MyOrg", 5*time.Second), "MyOrg group should be created by the remote rule")
+	require.True(t, tf.SeePlain("scratch"), "Repos matching no rule stay ungrouped")
+}
@@ -0,0 +1,77 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"fmt"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestGroupStartupFetchesAndReadsLazily(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+
+	// api tracks a remote that got a commit since it was cloned
+	upstream := filepath.Join(t.TempDir(), "upstream")
+	remotePath := filepath.Join(t.TempDir(), "api.git")
+	apiPath := filepath.Join(workspace, "api")
+	git := func(dir string, args ...string) string {
+		t.Helper()
+		out, err := exec.Command("git", append([]string{"-C", dir}, args...)...).CombinedOutput()
+		require.NoError(t, err, "git %v: %s", args, out)
+		return strings.TrimSpace(string(out))
+	}
+	require.NoError(t, os.MkdirAll(upstream, 0755))
+	git(upstream, "init", "-b", "main")
+	git(upstream, "-c", "user.name=t", "-c", "user.email=t@example.com", "commit", "--allow-empty", "-m", "first")
+	git(upstream, "clone", "--bare", upstream, remotePath)
+	git(workspace, "clone", remotePath, apiPath)
+	git(upstream, "-c", "user.name=t", "-c", "user.email=t@example.com", "commit", "--allow-empty", "-m", "second")
+	git(upstream, "push", remotePath, "main")
+
+	oldPath, err := tf.CreateTestRepo("old-stuff")
+	require.NoError(t, err, "Failed to create archived repo")
+	require.NoError(t, os.WriteFile(filepath.Join(oldPath, "README.md"), []byte("# changed\n"), 0644))
+
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\ngroup_order = [\"Active\", \"Archive\"]\n\n[groups]\nActive = [%q]\nArchive = [%q]\n\n"+
+		"[group_startup.Active]\nfetch_on_start = true\nstatus_priority = \"high\"\n\n[group_startup.Archive]\nstatus_priority = \"lazy\"\n",
+		workspace, apiPath, oldPath)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	require.NoError(t, tf.StartApp("-d", workspace))
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.WaitFor(func(string) bool {
+		s := tf.SnapshotPlain()
+		return strings.Contains(s, "api") && strings.Contains(s, "Archive (1)") && !strings.Contains(s, "old-stuff")
+	}, 5*time.Second), "The lazy group should start collapsed")
+
+	// Active is fetched on start
+	head := git(upstream, "rev-parse", "HEAD")
+	require.True(t, tf.WaitFor(func(string) bool {
+		out, err := exec.Command("git", "-C", apiPath, "rev-parse", "origin/main").Output()
+		return err == nil && strings.TrimSpace(string(out)) == head
+	}, 10*time.Second), "Should fetch the Active group on start")
+
+	// The archived repo's status is not read while its group is collapsed
+	require.NoError(t, tf.SendKeys("2"))
+	require.True(t, tf.OutputContainsPlain("Archive (0/1)", 3*time.Second), "The unread repo should not count as dirty")
+
+	// Expanding the group reads it
+	require.NoError(t, tf.SendKeys("1"))
+	require.NoError(t, tf.SendKeys("G"))
+	require.NoError(t, tf.SendKeys("z"))
+	require.True(t, tf.OutputContainsPlain("old-stuff", 3*time.Second), "Should expand the group")
+	require.NoError(t, tf.SendKeys("2"))
+	require.True(t, tf.OutputContainsPlain("Archive (1/1)", 5*time.Second), "Should read the repo once expanded")
+}
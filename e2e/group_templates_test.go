@@ -0,0 +1,56 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestCreateGroupFromTemplate(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	for _, name := range []string{"billing-service", "orders-api", "notes"} {
+		_, err := tf.CreateTestRepo(name)
+		require.NoError(t, err, "Failed to create %s repo", name)
+	}
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.SeePlain("orders-api"), "Should show repositories")
+
+	// N works without a selection; tab cycles dotfiles -> microservices
+	require.NoError(t, tf.SendKeys("N"))
+	require.True(t, tf.OutputContainsPlain("tab for a template", 2*time.Second), "Prompt should mention templates")
+	require.NoError(t, tf.SendKeys("\t"))
+	require.True(t, tf.OutputContainsPlain("template: dotfiles", 2*time.Second), "First template should be dotfiles")
+	require.NoError(t, tf.SendKeys("\t"))
+	require.True(t, tf.OutputContainsPlain("template: microservices", 2*time.Second), "Second template should be microservices")
+
+	require.NoError(t, tf.SendKeys("Services"+KeyEnter))
+	require.True(t, tf.WaitForStatusMessage("Created group 'Services' from template 'microservices' with 2 repo(s)", 2*time.Second),
+		"Matching repos should join the new group")
+
+	configPath := filepath.Join(workspace, ".gitagrip.toml")
+	require.Eventually(t, func() bool {
+		data, err := os.ReadFile(configPath)
+		if err != nil {
+			return false
+		}
+		content := string(data)
+		return strings.Contains(content, "billing-service") &&
+			strings.Contains(content, "labels") &&
+			strings.Contains(content, "[[watches]]") &&
+			!strings.Contains(content, "notes")
+	}, 3*time.Second, 100*time.Millisecond, "Template labels and watches should be saved")
+}
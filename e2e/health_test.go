@@ -0,0 +1,52 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"os"
+	"os/exec"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestHealthReportsLeftoverLocks(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+
+	_, err = tf.CreateTestRepo("healthy")
+	require.NoError(t, err)
+	locked, err := tf.CreateTestRepo("locked")
+	require.NoError(t, err)
+
+	// A crashed git command left its index lock behind an hour ago
+	lock := filepath.Join(locked, ".git", "index.lock")
+	require.NoError(t, os.WriteFile(lock, nil, 0644))
+	old := time.Now().Add(-time.Hour)
+	require.NoError(t, os.Chtimes(lock, old, old))
+
+	out, err := exec.Command(binPath, "health", "-d", workspace, "-fsck").CombinedOutput()
+	require.Error(t, err, "health should fail with a leftover lock: %s", out)
+	require.Regexp(t, `OK\s+healthy`, string(out))
+	require.Regexp(t, `FAIL\s+locked\s+leftover index.lock blocks git commands`, string(out))
+	require.Contains(t, string(out), "fix: make sure no git command runs in the repository, then remove "+lock)
+	require.Contains(t, string(out), "1 of 2 repositories healthy")
+
+	// The TUI lists the same finding and removes the lock on request
+	require.NoError(t, tf.StartApp("-d", workspace))
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.NoError(t, tf.SendKeys("T"))
+	require.True(t, tf.OutputContainsPlain("leftover index.lock blocks git commands", 5*time.Second),
+		"health view should list the lock")
+	require.NoError(t, tf.SendKeys("x"))
+	require.True(t, tf.OutputContainsPlain("No findings", 5*time.Second),
+		"health view should show no findings once the lock is gone")
+	_, err = os.Stat(lock)
+	require.True(t, os.IsNotExist(err), "lock file should be removed")
+}
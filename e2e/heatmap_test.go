@@ -0,0 +1,39 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestActivityHeatmap(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	_, err = tf.CreateTestRepo("busy")
+	require.NoError(t, err, "Failed to create busy repo")
+	_, err = tf.CreateTestRepo("quiet")
+	require.NoError(t, err, "Failed to create quiet repo")
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("quiet (main)", 5*time.Second), "Should show repos")
+
+	require.NoError(t, tf.SendKeys("Y"))
+	require.True(t, tf.OutputContainsPlain("Commit activity: all repositories", 2*time.Second), "Should open the heatmap")
+	require.True(t, tf.OutputContainsPlain("in 2 of 2 repos", 5*time.Second), "Both repos have commits this year")
+	require.True(t, tf.OutputContainsPlain("Busiest repos", 2*time.Second), "Should list where the commits went")
+
+	// Reopening uses the counts from the first time
+	require.NoError(t, tf.SendKeys("Y"))
+	time.Sleep(200 * time.Millisecond)
+	require.NoError(t, tf.SendKeys("Y"))
+	require.True(t, tf.OutputContainsPlain("in 2 of 2 repos", 2*time.Second), "Should show the cached counts")
+}
@@ -0,0 +1,70 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestNerdFontIconsShowProjectAndBranch(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	apiPath, err := tf.CreateTestRepo("api")
+	require.NoError(t, err, "Failed to create repo")
+	require.NoError(t, os.WriteFile(filepath.Join(apiPath, "go.mod"), []byte("module api\n"), 0644))
+	_, err = tf.CreateTestRepo("notes")
+	require.NoError(t, err, "Failed to create repo")
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n\n[ui]\nicons = \"nerdfont\"\n", workspace)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	// ui.icons picks the set even though LC_ALL=C would fall back to ASCII
+	require.True(t, tf.OutputContainsPlain("\ue627 api (\ue0a0 main)", 5*time.Second), "Go repos should get the Go icon and the branch icon")
+	require.True(t, tf.SeePlain("\uf00c notes (\ue0a0 main)"), "Repos of no known project get only the branch icon")
+}
+
+func TestASCIIIconsLeaveNamesBare(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	apiPath, err := tf.CreateTestRepo("api")
+	require.NoError(t, err, "Failed to create repo")
+	require.NoError(t, os.WriteFile(filepath.Join(apiPath, "Cargo.toml"), []byte("[package]\nname = \"api\"\n"), 0644))
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n\n[ui]\nicons = \"ascii\"\n", workspace)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("* api (main)", 5*time.Second), "The ASCII set has no project or branch icons")
+}
+
+func TestUnknownIconSetIsRejected(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n\n[ui]\nicons = \"emoji\"\n", workspace)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.OutputContainsPlain(`ui.icons: unknown icon set "emoji"`, 3*time.Second), "Should report the unknown icon set")
+}
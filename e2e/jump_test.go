@@ -0,0 +1,44 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"bytes"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestJumpPrintsPickedRepoPath(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	repoPath, err := tf.CreateTestRepo("target")
+	require.NoError(t, err, "Failed to create repo")
+
+	// The picked path is all that goes to stdout; the UI is drawn on stderr
+	var stdout bytes.Buffer
+	tf.stdout = &stdout
+	err = tf.StartApp("jump", "-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("target (main)", 5*time.Second), "Should show the repo")
+
+	require.NoError(t, tf.SendKeys("G"))
+	require.NoError(t, tf.SendKeys(KeyEnter))
+
+	done := make(chan error, 1)
+	go func() { done <- tf.cmd.Wait() }()
+	select {
+	case err := <-done:
+		require.NoError(t, err, "Picking a repository should exit successfully")
+	case <-time.After(5 * time.Second):
+		t.Fatal("jump should quit once a repository is picked")
+	}
+	require.Equal(t, repoPath, strings.TrimSpace(stdout.String()), "Should print only the picked path")
+}
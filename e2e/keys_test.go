@@ -0,0 +1,134 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"regexp"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func writeKeysConfig(t *testing.T, workspace, keys string) {
+	t.Helper()
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n\n[ui]\nshow_ahead_behind = true\n\n[keys]\n%s\n", workspace, keys)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+}
+
+func TestRemappedQuitKey(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	_, err = tf.CreateTestRepo("keys-repo")
+	require.NoError(t, err, "Failed to create repo")
+	writeKeysConfig(t, workspace, `quit = "x"`)
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.SeePlain("keys-repo"), "Should show repo")
+
+	// The old key no longer quits, the new one does
+	require.NoError(t, tf.SendKeys("q"))
+	time.Sleep(300 * time.Millisecond)
+	require.True(t, tf.SeePlain("keys-repo"), "q should no longer quit")
+
+	require.NoError(t, tf.SendKeys("x"))
+	done := make(chan error, 1)
+	go func() { done <- tf.cmd.Wait() }()
+	select {
+	case <-done:
+	case <-time.After(2 * time.Second):
+		t.Fatal("app did not exit on remapped quit key")
+	}
+}
+
+func TestConflictingKeysRefuseToStart(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	writeKeysConfig(t, workspace, `fetch = "r"`)
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.OutputContainsPlain(`"r" is bound to both refresh and fetch`, 3*time.Second), "Should report the conflict")
+}
+
+func TestQuickBarFromConfig(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	repoPath, err := tf.CreateTestRepo("quick-repo")
+	require.NoError(t, err, "Failed to create repo")
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n\n[ui]\nquick_bar = [\"info\", \"switch_branch\"]\n", workspace)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.SeePlain("quick-repo"), "Should show repo")
+
+	// The footer lists the pinned actions with their digits, after those the status filters keep
+	require.True(t, tf.OutputContainsPlain("5 info  6 switch branch", 2*time.Second), "Footer should show the quick bar")
+
+	// Move onto the repo and trigger the first slot
+	require.NoError(t, tf.SendKeys("G"))
+	require.NoError(t, tf.SendKeys("5"))
+	require.True(t, tf.OutputContainsPlain("Path: "+repoPath, 2*time.Second), "5 should open the repository info")
+}
+
+func TestUnknownQuickBarActionRefusesToStart(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n\n[ui]\nquick_bar = [\"fetch\", \"deploy\"]\n", workspace)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.OutputContainsPlain(`ui.quick_bar: unknown action "deploy"`, 3*time.Second), "Should report the unknown action")
+}
+
+func TestHelpListsRemappedKeys(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	_, err = tf.CreateTestRepo("help-repo")
+	require.NoError(t, err, "Failed to create repo")
+	writeKeysConfig(t, workspace, `fetch = "e"`+"\n"+`help = "o"`)
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("help-repo (main)", 5*time.Second), "Should show repo")
+
+	// Prompts show their own keys in the footer
+	require.NoError(t, tf.SendKeys("/"))
+	require.True(t, tf.OutputContainsPlain("enter jump to first match  esc cancel", 2*time.Second), "Footer should show the search keys")
+	require.NoError(t, tf.SendKeys("\x1b"))
+	time.Sleep(200 * time.Millisecond)
+
+	require.NoError(t, tf.SendKeys("o"))
+	require.True(t, tf.OutputContainsPlain("Remap keys in the [keys] section", 2*time.Second), "Help should open on the remapped key")
+	require.True(t, tf.OutputContainsPlain("On help-repo", 2*time.Second), "Help should start with what applies under the cursor")
+	require.Regexp(t, regexp.MustCompile(`\be\s+Fetch from remote`), tf.SnapshotPlain(), "Help should list the remapped fetch key")
+}
@@ -0,0 +1,66 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"regexp"
+	"strconv"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestLazyStatusReadsReposAsTheyComeIntoView(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	// More dirty repos than fit on the screen
+	for i := 0; i < 60; i++ {
+		_, err := tf.CreateTestRepo(fmt.Sprintf("repo-%02d", i), WithDirtyState())
+		require.NoError(t, err, "Failed to create repo %d", i)
+	}
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n\n[status]\nlazy = true\n", workspace)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	require.NoError(t, tf.StartApp("-d", workspace))
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("repo-00", 5*time.Second), "Should show the repos")
+
+	dirtyCount := regexp.MustCompile(`(\d+) dirty`)
+	dirty := func() int {
+		match := dirtyCount.FindStringSubmatch(tf.SnapshotPlain())
+		if match == nil {
+			return -1
+		}
+		n, _ := strconv.Atoi(match[1])
+		return n
+	}
+
+	// Only the repos on screen are read
+	require.NoError(t, tf.SendKeys("#"))
+	require.True(t, tf.WaitFor(func(string) bool {
+		return strings.Contains(tf.SnapshotPlain(), "60 repos") && dirty() > 0
+	}, 5*time.Second), "Should read the repos on screen")
+	time.Sleep(time.Second)
+	require.Less(t, dirty(), 60, "Should leave the repos below the screen unread")
+
+	// Scrolling to the bottom reads the rest
+	require.NoError(t, tf.SendKeys(KeyQuit))
+	require.True(t, tf.WaitFor(func(string) bool {
+		return !strings.Contains(tf.SnapshotPlain(), "Dashboard")
+	}, 2*time.Second), "q should close the dashboard")
+	require.NoError(t, tf.SendKeys("G"))
+	require.True(t, tf.OutputContainsPlain("repo-59", 3*time.Second), "Should scroll to the bottom")
+	require.NoError(t, tf.SendKeys("#"))
+	require.True(t, tf.WaitFor(func(string) bool {
+		return dirty() == 60
+	}, 5*time.Second), "Should read the repos scrolled to")
+}
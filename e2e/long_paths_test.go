@@ -0,0 +1,31 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestInfoShortensLongPaths(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	_, err = tf.CreateTestRepo("platform-services-with-a-rather-long-directory-name/payments-team-with-another-long-directory-name/payments-api")
+	require.NoError(t, err, "Failed to create repo")
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("payments-api (main)", 5*time.Second), "Should show repo")
+
+	require.NoError(t, tf.SendKeys("i"))
+	// LC_ALL=C: the middle of the path gives way to "~", keeping its first directory and its end
+	require.True(t, tf.OutputContainsPlain("/~/payments-team-with-another-long-directory-name/payments-api", 3*time.Second), "The path should lose its middle")
+	require.NotContains(t, tf.SnapshotPlain(), "platform-services-with-a-rather-long-directory-name", "The path should fit the popup")
+}
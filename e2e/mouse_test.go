@@ -0,0 +1,46 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"fmt"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+// sgrMouse encodes an SGR mouse report for a 0-based cell (press when down, release otherwise)
+func sgrMouse(button, x, y int, down bool) string {
+	final := "m"
+	if down {
+		final = "M"
+	}
+	return fmt.Sprintf("\x1b[<%d;%d;%d%s", button, x+1, y+1, final)
+}
+
+func TestMouseDragSelectsRepositories(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	_, err = tf.CreateTestRepo("alpha")
+	require.NoError(t, err, "Failed to create alpha repo")
+	_, err = tf.CreateTestRepo("beta")
+	require.NoError(t, err, "Failed to create beta repo")
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.SeePlain("beta"), "Should show repos")
+	time.Sleep(300 * time.Millisecond)
+
+	// Rows: padding, title, then the list (alpha, beta)
+	require.NoError(t, tf.SendKeys(sgrMouse(0, 6, 2, true)))
+	require.NoError(t, tf.SendKeys(sgrMouse(32, 6, 3, true)))
+	require.NoError(t, tf.SendKeys(sgrMouse(0, 6, 3, false)))
+
+	require.True(t, tf.WaitForStatusMessage("Selected 2 repositories", 2*time.Second), "Dragging across both repos should select them")
+}
@@ -89,3 +89,29 @@ func TestKeyboardNavigationUpDown(t *testing.T) {
 	downOutput := tf.Snapshot()
 	require.NotEqual(t, initialOutput, downOutput, "Navigation should change TUI state")
 }
+
+func TestLongListFillsTerminalWithScrollbar(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	for i := 0; i < 45; i++ {
+		_, err = tf.CreateTestRepo(fmt.Sprintf("long-%02d", i))
+		require.NoError(t, err, "Failed to create repo")
+	}
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("long-44", 10*time.Second), "Should discover every repo")
+
+	// 40 rows: padding, title and footer leave 36 for the list, one of them the indicator
+	require.True(t, tf.OutputContainsPlain("10 more below", 3*time.Second), "The list should use the terminal's full height")
+	require.Regexp(t, `long-00 \(main\)\s*#`, tf.SnapshotPlain(), "The scrollbar thumb should be at the top")
+
+	require.NoError(t, tf.SendKeys("G"))
+	require.True(t, tf.OutputContainsPlain("more above", 2*time.Second), "Should scroll to the bottom")
+	require.Regexp(t, `long-44 \(main\)\s*#`, tf.SnapshotPlain(), "The scrollbar thumb should be at the bottom")
+}
@@ -0,0 +1,46 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+const keyCtrlP = "\x10"
+
+func TestPaletteRunsActionByName(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	_, err = tf.CreateTestRepo("tidy-repo")
+	require.NoError(t, err, "Failed to create repo")
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.SeePlain("tidy-repo"), "Should show repo")
+
+	require.NoError(t, tf.SendKeys(keyCtrlP))
+	require.True(t, tf.OutputContainsPlain("Command Palette", 2*time.Second), "Should open the palette")
+	require.True(t, tf.OutputContainsPlain("Fetch from remote", time.Second), "Should list the actions")
+
+	// Fuzzy search by name: "dsh" finds the dashboard
+	require.NoError(t, tf.SendKeys("dsh"))
+	require.True(t, tf.WaitFor(func(string) bool {
+		s := tf.SnapshotPlain()
+		return strings.Contains(s, "▸ Status summary dashboard") && !strings.Contains(s, "Fetch from remote")
+	}, 2*time.Second), "Should narrow the list down to the dashboard")
+
+	require.NoError(t, tf.SendKeys("\r"))
+	require.True(t, tf.WaitFor(func(string) bool {
+		s := tf.SnapshotPlain()
+		return strings.Contains(s, "Dashboard") && !strings.Contains(s, "Command Palette")
+	}, 2*time.Second), "Enter should run the action")
+}
@@ -0,0 +1,71 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+// writeProfile saves a workspace profile where gitagrip -profile looks for it
+func writeProfile(t *testing.T, workspace, name, content string) {
+	t.Helper()
+	dir := filepath.Join(workspace, ".config", "gitagrip", "profiles")
+	require.NoError(t, os.MkdirAll(dir, 0755))
+	require.NoError(t, os.WriteFile(filepath.Join(dir, name+".toml"), []byte(content), 0644))
+}
+
+func TestProfileSwitcherRestartsWithOtherProfile(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	apiPath, err := tf.CreateTestRepo(filepath.Join("work", "api-service"))
+	require.NoError(t, err, "Failed to create work repo")
+	libPath, err := tf.CreateTestRepo(filepath.Join("oss", "lib-tool"))
+	require.NoError(t, err, "Failed to create oss repo")
+
+	writeProfile(t, workspace, "work", fmt.Sprintf("version = 1\nbase_dir = %q\n\n[groups]\nServices = [%q]\n",
+		filepath.Join(workspace, "work"), apiPath))
+	writeProfile(t, workspace, "oss", fmt.Sprintf("version = 1\nbase_dir = %q\n\n[groups]\nLibraries = [%q]\n",
+		filepath.Join(workspace, "oss"), libPath))
+
+	err = tf.StartApp("--profile", "work")
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("api-service", 5*time.Second), "Should show the work profile's repos")
+	require.False(t, tf.SeePlain("lib-tool"), "Should not scan the other profile's base dir")
+
+	// Q lists the profiles with the one in use marked
+	require.NoError(t, tf.SendKeys("Q"))
+	require.True(t, tf.OutputContainsPlain("Workspace profiles", 3*time.Second), "Should open the switcher")
+	require.True(t, tf.OutputContainsPlain("(in use)", 3*time.Second), "Should mark the profile in use")
+
+	// oss sorts first; picking it restarts with its groups and base dir
+	require.NoError(t, tf.SendKeys("k"))
+	require.NoError(t, tf.SendEnter())
+	require.True(t, tf.OutputContainsPlain("Libraries", 5*time.Second), "Should restart with the oss profile's groups")
+	require.True(t, tf.OutputContainsPlain("lib-tool", 5*time.Second), "Should discover the oss profile's repos")
+}
+
+func TestUnknownProfileListsAvailableOnes(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	writeProfile(t, workspace, "work", fmt.Sprintf("version = 1\nbase_dir = %q\n", workspace))
+
+	err = tf.StartApp("-profile", "client-x")
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.OutputContainsPlain(`no profile "client-x" (profiles: work)`, 5*time.Second),
+		"Should name the profiles that exist")
+}
@@ -0,0 +1,73 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"os"
+	"path/filepath"
+	"regexp"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestBulkPullShowsDryRunAndPullsCheckedRepos(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+
+	// upstreamEdit pushes a README change from another clone and fetches it
+	upstreamEdit := func(name, repoPath, content string) {
+		clone := filepath.Join(t.TempDir(), "clone")
+		require.NoError(t, tf.runGitCommand("", "clone", filepath.Join(workspace, name+"-remote.git"), clone))
+		require.NoError(t, os.WriteFile(filepath.Join(clone, "README.md"), []byte(content), 0644))
+		require.NoError(t, tf.runGitCommand(clone, "commit", "-am", "Upstream edit"))
+		require.NoError(t, tf.runGitCommand(clone, "push", "origin", "main"))
+		require.NoError(t, tf.runGitCommand(repoPath, "fetch", "origin"))
+	}
+
+	behind, err := tf.CreateTestRepo("behind-repo", WithRemote())
+	require.NoError(t, err, "Failed to create repo")
+	require.NoError(t, tf.runGitCommand(behind, "branch", "--set-upstream-to=origin/main"))
+	upstreamEdit("behind-repo", behind, "# upstream\n")
+
+	diverged, err := tf.CreateTestRepo("diverged-repo", WithRemote())
+	require.NoError(t, err, "Failed to create repo")
+	require.NoError(t, tf.runGitCommand(diverged, "branch", "--set-upstream-to=origin/main"))
+	upstreamEdit("diverged-repo", diverged, "# upstream\n")
+	require.NoError(t, os.WriteFile(filepath.Join(diverged, "README.md"), []byte("# local\n"), 0644))
+	require.NoError(t, tf.runGitCommand(diverged, "commit", "-am", "Local edit"))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("diverged-repo", 5*time.Second), "Repos should be discovered")
+
+	require.NoError(t, tf.SendKeys("A"))
+	require.NoError(t, tf.Pull())
+	require.True(t, tf.WaitFor(func(string) bool {
+		s := tf.SnapshotPlain()
+		return strings.Contains(s, "Pull 1 of the selected repos") &&
+			regexp.MustCompile(`\[x\] behind-repo\s+fast-forward 1 commits`).MatchString(s) &&
+			regexp.MustCompile(`\[ \] diverged-repo\s+would conflict in README.md`).MatchString(s)
+	}, 5*time.Second), "Dry run should check the fast-forward and leave out the conflict")
+
+	// Nothing was pulled yet
+	_, err = os.Stat(filepath.Join(diverged, ".git", "rebase-merge"))
+	require.True(t, os.IsNotExist(err), "No rebase should be in progress")
+
+	require.NoError(t, tf.SendKeys("y"))
+	require.True(t, tf.WaitForStatusMessage("Pulling 1 of 2 repos", 3*time.Second), "Only the checked repo should be pulled")
+	require.True(t, tf.WaitFor(func(string) bool {
+		content, err := os.ReadFile(filepath.Join(behind, "README.md"))
+		return err == nil && string(content) == "# upstream\n"
+	}, 5*time.Second), "The fast-forward should be pulled")
+	content, err := os.ReadFile(filepath.Join(diverged, "README.md"))
+	require.NoError(t, err)
+	require.Equal(t, "# local\n", string(content), "The diverged repo should be left alone")
+}
@@ -0,0 +1,75 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"fmt"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestPushAheadRepos(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+
+	commit := func(repoPath, message string) {
+		require.NoError(t, tf.runGitCommand(repoPath, "commit", "--allow-empty", "-m", message))
+	}
+
+	// One repo is ahead, one was pushed to meanwhile (its push is rejected), one is protected
+	ahead, err := tf.CreateTestRepo("ahead", WithRemote())
+	require.NoError(t, err)
+	commit(ahead, "Local work")
+
+	diverged, err := tf.CreateTestRepo("diverged", WithRemote())
+	require.NoError(t, err)
+	clone := filepath.Join(t.TempDir(), "clone")
+	require.NoError(t, tf.runGitCommand("", "clone", filepath.Join(workspace, "diverged-remote.git"), clone))
+	commit(clone, "Someone else's work")
+	require.NoError(t, tf.runGitCommand(clone, "push", "origin", "main"))
+	commit(diverged, "Local work")
+
+	guarded, err := tf.CreateTestRepo("guarded", WithRemote())
+	require.NoError(t, err)
+	commit(guarded, "Local work")
+
+	_, err = tf.CreateTestRepo("synced", WithRemote())
+	require.NoError(t, err)
+
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n\n[repos.%q]\nlabels = [\"protected\"]\n", workspace, guarded)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("synced (main)", 5*time.Second), "Should show the repos")
+
+	require.NoError(t, tf.SendKeys("P"))
+	require.True(t, tf.OutputContainsPlain("Push 2 of all repos", 5*time.Second), "Should list the ahead repos")
+	require.True(t, tf.OutputContainsPlain("main → origin/main", 5*time.Second), "Should show where each branch goes")
+	require.True(t, tf.OutputContainsPlain(`labeled "protected"`, 5*time.Second), "Should leave out the protected repo")
+
+	require.NoError(t, tf.SendKeys("y"))
+	require.True(t, tf.WaitForStatusMessage("Pushed 1 of 2 repos", 15*time.Second), "Should summarize the push")
+	require.True(t, tf.OutputContainsPlain("non-fast-forward", 5*time.Second), "Should report the rejection")
+
+	head := func(args ...string) string {
+		out, err := exec.Command("git", args...).Output()
+		require.NoError(t, err)
+		return strings.TrimSpace(string(out))
+	}
+	require.Equal(t, head("-C", ahead, "rev-parse", "HEAD"),
+		head("--git-dir", filepath.Join(workspace, "ahead-remote.git"), "rev-parse", "main"), "ahead should be pushed")
+	require.NotEqual(t, head("-C", guarded, "rev-parse", "HEAD"),
+		head("--git-dir", filepath.Join(workspace, "guarded-remote.git"), "rev-parse", "main"), "guarded must not be pushed")
+}
@@ -0,0 +1,108 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"bufio"
+	"encoding/json"
+	"fmt"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestAgentReportsStatusOverStdio(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	repoPath, err := tf.CreateTestRepo("far-repo", WithDirtyState())
+	require.NoError(t, err, "Failed to create repo")
+
+	cmd := exec.Command(binPath, "agent", "-d", workspace)
+	cmd.Env = append(os.Environ(), "HOME="+workspace, "GIT_CONFIG_GLOBAL=/dev/null")
+	stdin, err := cmd.StdinPipe()
+	require.NoError(t, err)
+	stdout, err := cmd.StdoutPipe()
+	require.NoError(t, err)
+	require.NoError(t, cmd.Start())
+	defer func() {
+		_ = stdin.Close()
+		_ = cmd.Wait()
+	}()
+
+	lines := make(chan map[string]any)
+	go func() {
+		scanner := bufio.NewScanner(stdout)
+		scanner.Buffer(make([]byte, 64*1024), 16<<20)
+		for scanner.Scan() {
+			var f map[string]any
+			if json.Unmarshal(scanner.Bytes(), &f) == nil {
+				lines <- f
+			}
+		}
+		close(lines)
+	}()
+
+	hello := <-lines
+	require.Equal(t, "hello", hello["kind"], "Agent should start with its config")
+	require.Contains(t, hello["config"], workspace, "Hello should carry the workspace config")
+
+	_, err = fmt.Fprintf(stdin, `{"kind":"event","type":"ScanRequested","event":{"Paths":[%q]}}`+"\n", workspace)
+	require.NoError(t, err)
+
+	deadline := time.After(10 * time.Second)
+	for {
+		select {
+		case f, ok := <-lines:
+			require.True(t, ok, "Agent closed the stream before reporting a status")
+			if f["type"] != "StatusUpdated" {
+				continue
+			}
+			event := f["event"].(map[string]any)
+			if event["RepoPath"] != repoPath {
+				continue
+			}
+			status := event["Status"].(map[string]any)
+			if status["Branch"] == "main" {
+				require.Equal(t, true, status["IsDirty"], "Status should be computed on the agent")
+				return
+			}
+		case <-deadline:
+			t.Fatal("Agent should report the status of the discovered repo")
+		}
+	}
+}
+
+func TestRemoteTUIShowsAgentRepos(t *testing.T) {
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	_, err = tf.CreateTestRepo("build-server-repo")
+	require.NoError(t, err, "Failed to create repo")
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n", workspace)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	// Stand-in for ssh: drop "-T host" and run the agent command locally
+	sshPath := filepath.Join(t.TempDir(), "fake-ssh.sh")
+	require.NoError(t, os.WriteFile(sshPath, []byte("#!/bin/sh\nshift 2\nexec \"$@\"\n"), 0755))
+	t.Setenv("GITAGRIP_SSH", sshPath)
+	t.Setenv("GITAGRIP_REMOTE_BIN", binPath)
+
+	err = tf.StartApp("-remote", "buildbox:"+workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.WaitFor(func(string) bool {
+		s := tf.SnapshotPlain()
+		return strings.Contains(s, "build-server-repo") && strings.Contains(s, "main")
+	}, 10*time.Second), "Should list the agent's repo with its status")
+}
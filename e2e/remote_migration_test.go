@@ -0,0 +1,44 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"os/exec"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestRemoteMigrationToSSH(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+
+	repoPath, err := tf.CreateTestRepo("https-repo")
+	require.NoError(t, err, "Failed to create https-repo")
+	require.NoError(t, tf.runGitCommand(repoPath, "remote", "add", "origin", "https://github.com/acme/widgets.git"), "Failed to add origin")
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.SeePlain("https-repo"), "Should show https-repo")
+
+	// Open the migration preview
+	require.NoError(t, tf.SendKeys("M"))
+	require.True(t, tf.SeePlain("Migrate origin remotes to SSH (1)"), "Should show migration preview")
+	require.True(t, tf.SeePlain("git@github.com:acme/widgets.git"), "Should preview the SSH URL")
+
+	// Apply the repository under the cursor
+	require.NoError(t, tf.SendEnter())
+	require.True(t, tf.WaitForStatusMessage("Updated origin remote", 5*time.Second), "Should report the update")
+
+	require.Eventually(t, func() bool {
+		out, err := exec.Command("git", "-C", repoPath, "remote", "get-url", "origin").Output()
+		return err == nil && strings.TrimSpace(string(out)) == "git@github.com:acme/widgets.git"
+	}, 5*time.Second, 100*time.Millisecond, "origin should be rewritten to SSH")
+}
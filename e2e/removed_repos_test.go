@@ -0,0 +1,63 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"fmt"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestDeletedRepoIsDroppedWhenItsStatusFails(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	apiPath, err := tf.CreateTestRepo("api")
+	require.NoError(t, err, "Failed to create api repo")
+	webPath, err := tf.CreateTestRepo("web")
+	require.NoError(t, err, "Failed to create web repo")
+	ghostPath := filepath.Join(workspace, "ghost")
+
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n\n[groups]\nWork = [%q, %q, %q]\n", workspace, apiPath, webPath, ghostPath)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("web", 5*time.Second), "Should show repos")
+	require.True(t, tf.OutputContainsPlain("ghost is in the config but no longer exists", 5*time.Second), "Should flag the missing config path")
+
+	// The cursor starts on the Work header; refreshing the group hits the deleted repo
+	require.NoError(t, os.RemoveAll(webPath))
+	require.NoError(t, tf.SendKeys("r"))
+	require.True(t, tf.WaitFor(func(string) bool {
+		s := tf.SnapshotPlain()
+		return !strings.Contains(s, "web") && strings.Contains(s, "api")
+	}, 5*time.Second), "Should drop the deleted repo")
+}
+
+func TestDoctorListsMissingConfigPaths(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n\n[groups]\nWork = [%q]\n", workspace, filepath.Join(workspace, "ghost"))
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	cmd := exec.Command(binPath, "doctor", "--self", "-d", workspace)
+	cmd.Env = append(os.Environ(), "XDG_CACHE_HOME="+t.TempDir())
+	out, _ := cmd.CombinedOutput()
+	require.Regexp(t, `WARN\s+config paths\s+1 missing: .*ghost`, string(out))
+	require.Contains(t, string(out), "fix: remove them from [groups] and [repos]")
+}
@@ -0,0 +1,46 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"os"
+	"os/exec"
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestInfoShowsRemotesStashesTagsAndWorktrees(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	repoPath, err := tf.CreateTestRepo("detailed-repo", WithRemote())
+	require.NoError(t, err, "Failed to create repo")
+	git := func(args ...string) {
+		out, err := exec.Command("git", append([]string{"-C", repoPath}, args...)...).CombinedOutput()
+		require.NoError(t, err, "git %v: %s", args, out)
+	}
+	git("tag", "v2.0.0")
+	require.NoError(t, os.WriteFile(filepath.Join(repoPath, "README.md"), []byte("work in progress"), 0644))
+	git("stash")
+	// Outside the workspace, so the scan doesn't list it as a repository of its own
+	git("worktree", "add", "-b", "hotfix", filepath.Join(t.TempDir(), "hotfix-wt"))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("detailed-repo", 5*time.Second), "Should show repo")
+
+	require.NoError(t, tf.SendKeys("i"))
+	require.True(t, tf.WaitFor(func(string) bool {
+		s := tf.SnapshotPlain()
+		return strings.Contains(s, "detailed-repo-remote.git") && strings.Contains(s, "Stashes: 1") &&
+			strings.Contains(s, "Latest tags: v2.0.0") && strings.Contains(s, "hotfix-wt (hotfix)")
+	}, 5*time.Second), "Info should show the remote, stash count, tags and the other worktree")
+}
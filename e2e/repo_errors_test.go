@@ -0,0 +1,60 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestFailedRepoIsMarkedAndRetried(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	repoPath, err := tf.CreateTestRepo("broken-repo")
+	require.NoError(t, err, "Failed to create repo")
+	headPath := filepath.Join(repoPath, ".git", "HEAD")
+	head, err := os.ReadFile(headPath)
+	require.NoError(t, err)
+	require.NoError(t, os.WriteFile(headPath, []byte("garbage\n"), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("✗", 5*time.Second), "Failed repo should be marked")
+
+	require.NoError(t, tf.SendKeys("i"))
+	require.True(t, tf.OutputContainsPlain("not a git repository", 2*time.Second), "Info should show why the status failed")
+	require.NoError(t, tf.SendKeys("i"))
+
+	require.NoError(t, os.WriteFile(headPath, head, 0644))
+	require.NoError(t, tf.SendKeys("E"))
+	require.True(t, tf.OutputContainsPlain("Retrying 1 failed repos", 2*time.Second), "Should retry the failed repo")
+	require.True(t, tf.OutputContainsPlain("broken-repo (main)", 5*time.Second), "Repo should recover after the retry")
+}
+
+func TestRetryWithoutFailures(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	_, err = tf.CreateTestRepo("healthy-repo")
+	require.NoError(t, err, "Failed to create repo")
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("healthy-repo (main)", 5*time.Second), "Should show the repo status")
+
+	require.NoError(t, tf.SendKeys("E"))
+	require.True(t, tf.OutputContainsPlain("No failed repos", 2*time.Second), "Should report there is nothing to retry")
+}
@@ -0,0 +1,37 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestRepoNameAndGroupOverrides(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	longPath, err := tf.CreateTestRepo("acme-monorepo-services-payment")
+	require.NoError(t, err, "Failed to create repo")
+
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n\n[repos.%q]\nname = \"payments\"\ngroup = \"Billing\"\n", workspace, longPath)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("Billing", 3*time.Second), "Override group should be created")
+	require.True(t, tf.OutputContainsPlain("payments", 3*time.Second), "Friendly name should be shown")
+
+	// Search matches the friendly name
+	require.NoError(t, tf.SendKeys("/paym"+KeyEnter))
+	require.False(t, tf.SeePlain("acme-monorepo"), "Directory name should not be shown")
+}
@@ -0,0 +1,86 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestInProgressOperationBadge(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+
+	repoPath, err := tf.CreateTestRepo("half-done")
+	require.NoError(t, err, "Failed to create half-done repo")
+
+	_, err = tf.CreateTestRepo("all-good")
+	require.NoError(t, err, "Failed to create all-good repo")
+
+	// Leave a bisect session open in one repository
+	require.NoError(t, tf.runGitCommand(repoPath, "bisect", "start"), "Failed to start bisect")
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+
+	require.True(t, tf.SeePlain("half-done"), "Should show half-done repo")
+	require.True(t, tf.SeePlain("BISECTING"), "Should show bisect badge for half-done repo")
+}
+
+func TestLinkedWorktreeDiscovery(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+
+	repoPath, err := tf.CreateTestRepo("service")
+	require.NoError(t, err, "Failed to create service repo")
+
+	// Linked worktrees have a .git file instead of a directory
+	worktreePath := filepath.Join(workspace, "service-feature")
+	require.NoError(t, tf.runGitCommand(repoPath, "worktree", "add", "-b", "feature", worktreePath), "Failed to add worktree")
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+
+	require.True(t, tf.SeePlain("service (main)"), "Should show main repository")
+	require.True(t, tf.SeePlain("service-feature (feature)"), "Should show worktree with its own branch")
+}
+
+func TestChangeCountsNextToTheBranch(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	repoPath, err := tf.CreateTestRepo("busy")
+	require.NoError(t, err, "Failed to create busy repo")
+
+	// One stash, then a staged file, a modified one and an untracked one
+	readme := filepath.Join(repoPath, "README.md")
+	require.NoError(t, os.WriteFile(readme, []byte("stashed\n"), 0644))
+	require.NoError(t, tf.runGitCommand(repoPath, "stash"), "Failed to stash")
+	require.NoError(t, os.WriteFile(filepath.Join(repoPath, "staged.txt"), []byte("new\n"), 0644))
+	require.NoError(t, tf.runGitCommand(repoPath, "add", "staged.txt"), "Failed to stage")
+	require.NoError(t, os.WriteFile(readme, []byte("changed\n"), 0644))
+	require.NoError(t, os.WriteFile(filepath.Join(repoPath, "notes.txt"), []byte("todo\n"), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+
+	// The test terminal has no UTF-8 locale, so the ASCII glyphs are used
+	require.True(t, tf.SeePlain("busy (main) +1 ~1 ?1 $1"), "Should show staged, modified, untracked and stash counts")
+}
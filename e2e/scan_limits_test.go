@@ -0,0 +1,78 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestScanStopsAtMaxRepos(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	for _, name := range []string{"mirror/a", "mirror/b", "mirror/c", "zeta"} {
+		_, err = tf.CreateTestRepo(name)
+		require.NoError(t, err, "Failed to create repo")
+	}
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n\n[scan]\nmax_repos = 2\n", workspace)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("! Scan stopped at 2 repos (scan.max_repos). Most are under", 5*time.Second), "Should warn that the scan stopped at the limit")
+	require.True(t, tf.SeePlain("+ b (main)"), "Should list the repos found before the limit")
+	require.NotContains(t, tf.SnapshotPlain(), "zeta", "Should not scan past the limit")
+}
+
+func TestScanReportsDirectoriesBelowMaxDepth(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	_, err = tf.CreateTestRepo("top")
+	require.NoError(t, err, "Failed to create repo")
+	_, err = tf.CreateTestRepo("deep/er/still/buried")
+	require.NoError(t, err, "Failed to create repo")
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n\n[scan]\nmax_depth = 1\n", workspace)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("+ top (main)", 5*time.Second), "Should list the shallow repo")
+	require.True(t, tf.SeePlain("Directories deeper than scan.max_depth (1) were not scanned: 1."), "Should warn about the directories left out")
+	require.NotContains(t, tf.SnapshotPlain(), "buried", "Should not go below the depth limit")
+}
+
+func TestScanExcludeSkipsDirectories(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	_, err = tf.CreateTestRepo("code/api")
+	require.NoError(t, err, "Failed to create repo")
+	_, err = tf.CreateTestRepo("mirrors/upstream")
+	require.NoError(t, err, "Failed to create repo")
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n\n[scan]\nexclude = [\"mirrors\"]\n", workspace)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("+ api (main)", 5*time.Second), "Should list the repo outside the excluded directory")
+	require.NotContains(t, tf.SnapshotPlain(), "upstream", "Should skip excluded directories")
+}
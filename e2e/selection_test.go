@@ -4,6 +4,8 @@ package main
 
 import (
 	"fmt"
+	"os"
+	"path/filepath"
 	"testing"
 	"time"
 
@@ -88,3 +90,66 @@ func TestRepositorySelectionMultiple(t *testing.T) {
 	finalOutput := tf.Snapshot()
 	require.NotEqual(t, initialOutput, finalOutput, "Multiple selections should change TUI state")
 }
+
+func TestSelectGroupAllAndClear(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	apiPath, err := tf.CreateTestRepo("api")
+	require.NoError(t, err, "Failed to create api repo")
+	webPath, err := tf.CreateTestRepo("web")
+	require.NoError(t, err, "Failed to create web repo")
+	_, err = tf.CreateTestRepo("loose")
+	require.NoError(t, err, "Failed to create loose repo")
+
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\ngroup_order = [\"Work\"]\n\n[groups]\nWork = [%q, %q]\n", workspace, apiPath, webPath)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.SeePlain("loose"), "Should show repos")
+
+	// From a repo inside the group, a selects the whole group
+	require.NoError(t, tf.SendKeys("j"))
+	require.NoError(t, tf.SendKeys("a"))
+	require.True(t, tf.WaitForStatusMessage("Selected all repos in 'Work'", 2*time.Second), "a should select the current group")
+
+	require.NoError(t, tf.SendKeys("A"))
+	require.True(t, tf.WaitForStatusMessage("Selected 3 repositories", 2*time.Second), "A should select every repo")
+
+	require.NoError(t, tf.SendKeys("u"))
+	require.True(t, tf.WaitForStatusMessage("Selection cleared", 2*time.Second), "u should clear the selection")
+}
+
+func TestCursorFollowsRepoWhenListReorders(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	_, err = tf.CreateTestRepo("alpha")
+	require.NoError(t, err, "Failed to create alpha repo")
+	_, err = tf.CreateTestRepo("bravo")
+	require.NoError(t, err, "Failed to create bravo repo")
+	charliePath, err := tf.CreateTestRepo("charlie", WithDirtyState())
+	require.NoError(t, err, "Failed to create charlie repo")
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("charlie", 5*time.Second), "Should show repos")
+
+	// Cursor on charlie (last by name), then sort by status: dirty charlie moves to the top
+	require.NoError(t, tf.SendKeys("G"))
+	require.NoError(t, tf.SendKeys("\x13")) // Ctrl+S cycles the sort
+	require.True(t, tf.WaitForStatusMessage("Sorting by dirty first", 2*time.Second), "Should sort by status")
+
+	// The cursor is still on charlie
+	require.NoError(t, tf.SendKeys("i"))
+	require.True(t, tf.OutputContainsPlain("Path: "+charliePath, 2*time.Second), "Cursor should stay on charlie")
+}
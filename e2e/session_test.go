@@ -0,0 +1,65 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestSessionIsRestoredOnNextRun(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	for _, name := range []string{"alpha", "beta"} {
+		_, err := tf.CreateTestRepo(name)
+		require.NoError(t, err, "Failed to create %s", name)
+	}
+	gammaPath, err := tf.CreateTestRepo("gamma")
+	require.NoError(t, err, "Failed to create gamma")
+
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n\n[groups]\nTeam = [%q, %q]\n",
+		workspace, filepath.Join(workspace, "alpha"), filepath.Join(workspace, "beta"))
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	// First run: fold the group and leave the cursor on the last repository
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("gamma (main)", 5*time.Second), "Should show gamma")
+	require.True(t, tf.OutputContainsPlain("alpha (main)", 5*time.Second), "Team should start expanded")
+	require.NoError(t, tf.SendKeys("z"))
+	time.Sleep(200 * time.Millisecond)
+	require.NoError(t, tf.SendKeys("G"))
+	time.Sleep(200 * time.Millisecond)
+	require.NoError(t, tf.Quit())
+
+	done := make(chan error, 1)
+	go func() { done <- tf.cmd.Wait() }()
+	select {
+	case <-done:
+	case <-time.After(5 * time.Second):
+		t.Fatal("app did not exit after quit")
+	}
+
+	// Second run: same place (same $HOME, so the same cache directory)
+	tf2 := NewTUITest(t)
+	defer tf2.Cleanup()
+	tf2.workspace = workspace
+	err = tf2.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to restart app")
+	require.True(t, tf2.Ready(), "Should receive ready signal on restart")
+	require.True(t, tf2.OutputContainsPlain("gamma (main)", 5*time.Second), "Should show gamma after restart")
+	require.False(t, tf2.SeePlain("alpha (main)"), "Team should still be collapsed")
+
+	require.NoError(t, tf2.SendKeys("i"))
+	require.True(t, tf2.OutputContainsPlain("Path: "+gammaPath, 3*time.Second), "Cursor should be back on gamma")
+}
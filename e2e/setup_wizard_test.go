@@ -0,0 +1,51 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestSetupWizardPicksBaseDirAndGrouping(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	_, err = tf.CreateTestRepo(filepath.Join("code", "work", "acme", "api"))
+	require.NoError(t, err, "Failed to create api repo")
+	_, err = tf.CreateTestRepo(filepath.Join("code", "work", "acme", "web"))
+	require.NoError(t, err, "Failed to create web repo")
+	_, err = tf.CreateTestRepo(filepath.Join("code", "solo"))
+	require.NoError(t, err, "Failed to create solo repo")
+
+	// No directory given and no config where it runs: the wizard asks
+	tf.dir = workspace
+	err = tf.StartApp()
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.OutputContainsPlain("Welcome to gitagrip", 5*time.Second), "Should open the setup screen")
+
+	// Tab completes the subdirectory, which is then searched
+	require.NoError(t, tf.SendKeys("co"))
+	require.NoError(t, tf.SendKeys("\t"))
+	require.True(t, tf.OutputContainsPlain("Found 3 repositories", 5*time.Second), "Should preview the repos found")
+	require.True(t, tf.OutputContainsPlain("(1 groups)", 3*time.Second), "Should preview the groups made")
+
+	// Path grouping (ctrl+n, like down), then start
+	require.NoError(t, tf.SendKeys("\x0e"))
+	require.NoError(t, tf.SendEnter())
+	require.True(t, tf.Ready(), "Should start once the config is written")
+	require.True(t, tf.OutputContainsPlain("work/acme", 5*time.Second), "Should group by path")
+
+	data, err := os.ReadFile(filepath.Join(workspace, "code", ".gitagrip.toml"))
+	require.NoError(t, err, "Should write the config in the picked directory")
+	require.Contains(t, string(data), "path")
+	_, err = os.Stat(filepath.Join(workspace, ".gitagrip.toml"))
+	require.True(t, os.IsNotExist(err), "Should not write a config where it was started")
+}
@@ -0,0 +1,120 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"encoding/json"
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestSnapshotDropsReposGoneSinceLastRun(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	keepPath, err := tf.CreateTestRepo("snap-keep")
+	require.NoError(t, err, "Failed to create snap-keep")
+	gonePath, err := tf.CreateTestRepo("snap-gone")
+	require.NoError(t, err, "Failed to create snap-gone")
+
+	// First run: both repositories end up in the snapshot
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("snap-gone (main)", 5*time.Second), "Should show snap-gone")
+	require.True(t, tf.OutputContainsPlain("snap-keep (main)", 5*time.Second), "Should show snap-keep")
+	require.NoError(t, tf.Quit())
+	done := make(chan error, 1)
+	go func() { done <- tf.cmd.Wait() }()
+	select {
+	case <-done:
+	case <-time.After(5 * time.Second):
+		t.Fatal("app did not exit after quit")
+	}
+
+	// $HOME is the workspace, so the snapshot sits in its cache directory
+	snapshots, err := filepath.Glob(filepath.Join(workspace, ".cache", "gitagrip", "snapshot-*.json"))
+	require.NoError(t, err)
+	require.Len(t, snapshots, 1, "Should write a snapshot")
+	data, err := os.ReadFile(snapshots[0])
+	require.NoError(t, err)
+	require.Contains(t, string(data), keepPath)
+	require.Contains(t, string(data), gonePath)
+
+	// Second run: the scan misses the deleted repository, which leaves the snapshot
+	require.NoError(t, os.RemoveAll(gonePath))
+	tf2 := NewTUITest(t)
+	defer tf2.Cleanup()
+	tf2.workspace = workspace
+	err = tf2.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to restart app")
+	require.True(t, tf2.Ready(), "Should receive ready signal on restart")
+	require.True(t, tf2.OutputContainsPlain("snap-keep (main)", 5*time.Second), "Should show snap-keep after restart")
+	time.Sleep(500 * time.Millisecond) // let the scan complete
+	require.NoError(t, tf2.Quit())
+	go func() { done <- tf2.cmd.Wait() }()
+	select {
+	case <-done:
+	case <-time.After(5 * time.Second):
+		t.Fatal("app did not exit after quit")
+	}
+
+	data, err = os.ReadFile(snapshots[0])
+	require.NoError(t, err)
+	require.Contains(t, string(data), keepPath)
+	require.NotContains(t, string(data), gonePath, "Snapshot should forget snap-gone")
+}
+
+func TestSnapshotLeavesOutReposOutsideTheBaseDir(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	_, err = tf.CreateTestRepo("snap-home")
+	require.NoError(t, err, "Failed to create snap-home")
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("snap-home (main)", 5*time.Second), "Should show snap-home")
+	require.NoError(t, tf.Quit())
+	done := make(chan error, 1)
+	go func() { done <- tf.cmd.Wait() }()
+	select {
+	case <-done:
+	case <-time.After(5 * time.Second):
+		t.Fatal("app did not exit after quit")
+	}
+
+	// A repository a + scan found elsewhere, as the snapshot records it
+	snapshots, err := filepath.Glob(filepath.Join(workspace, ".cache", "gitagrip", "snapshot-*.json"))
+	require.NoError(t, err)
+	require.Len(t, snapshots, 1, "Should write a snapshot")
+	data, err := os.ReadFile(snapshots[0])
+	require.NoError(t, err)
+	var entries map[string]any
+	require.NoError(t, json.Unmarshal(data, &entries))
+	entries[filepath.Join(t.TempDir(), "far-away")] = map[string]any{"name": "far-away", "status": map[string]any{"Branch": "main"}}
+	data, err = json.Marshal(entries)
+	require.NoError(t, err)
+	require.NoError(t, os.WriteFile(snapshots[0], data, 0644))
+
+	tf2 := NewTUITest(t)
+	defer tf2.Cleanup()
+	tf2.workspace = workspace
+	err = tf2.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to restart app")
+	require.True(t, tf2.Ready(), "Should receive ready signal on restart")
+	require.True(t, tf2.OutputContainsPlain("snap-home (main)", 5*time.Second), "Should show snap-home after restart")
+	time.Sleep(500 * time.Millisecond) // let the scan complete
+	require.NotContains(t, tf2.SnapshotPlain(), "far-away", "Repos outside the base dir should not be restored")
+}
@@ -3,6 +3,9 @@
 package main
 
 import (
+	"fmt"
+	"os"
+	"path/filepath"
 	"strings"
 	"testing"
 	"time"
@@ -45,9 +48,9 @@ func TestSortByName(t *testing.T) {
 	// Get initial output to verify current order
 	initialOutput := tf.SnapshotPlain()
 
-	// Enter sort mode with 's'
-	err = tf.SendKeys("s")
-	require.NoError(t, err, "Failed to send s key to enter sort mode")
+	// Enter sort mode with 'S'
+	err = tf.SendKeys("S")
+	require.NoError(t, err, "Failed to send S key to enter sort mode")
 
 	// Wait for sort mode to appear
 	require.True(t, tf.WaitFor(func(s string) bool {
@@ -116,8 +119,8 @@ func TestSortByStatus(t *testing.T) {
 	// Get initial output
 	initialOutput := tf.SnapshotPlain()
 
-	// Enter sort mode with 's'
-	err = tf.SendKeys("s")
+	// Enter sort mode with 'S'
+	err = tf.SendKeys("S")
 	require.NoError(t, err, "Failed to enter sort mode")
 
 	// Wait for sort mode
@@ -186,8 +189,8 @@ func TestSortByBranch(t *testing.T) {
 	// Get initial output
 	initialOutput := tf.SnapshotPlain()
 
-	// Enter sort mode with 's'
-	err = tf.SendKeys("s")
+	// Enter sort mode with 'S'
+	err = tf.SendKeys("S")
 	require.NoError(t, err, "Failed to enter sort mode")
 
 	// Wait for sort mode
@@ -257,8 +260,8 @@ func TestSortNavigationAndCancel(t *testing.T) {
 	require.True(t, tf.OutputContainsPlain("test-repo-1", 5*time.Second), "test-repo-1 should be discovered")
 	require.True(t, tf.OutputContainsPlain("test-repo-2", 5*time.Second), "test-repo-2 should be discovered")
 
-	// Enter sort mode with 's'
-	err = tf.SendKeys("s")
+	// Enter sort mode with 'S'
+	err = tf.SendKeys("S")
 	require.NoError(t, err, "Failed to enter sort mode")
 
 	// Wait for sort mode
@@ -303,3 +306,107 @@ func TestSortNavigationAndCancel(t *testing.T) {
 
 	t.Logf("✅ Sort navigation and cancel test passed - navigation works and cancel preserves original order")
 }
+
+// keyCycleSort is Ctrl+S, which switches to the next sort without the menu
+const keyCycleSort = "\x13"
+
+// listedBefore reports whether first is drawn above second
+func listedBefore(s, first, second string) bool {
+	i, j := strings.Index(s, first), strings.Index(s, second)
+	return i >= 0 && j >= 0 && i < j
+}
+
+func TestSortCyclePutsDirtyReposFirst(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	_, err = tf.CreateTestRepo("alpha-clean")
+	require.NoError(t, err, "Failed to create alpha-clean repo")
+	_, err = tf.CreateTestRepo("zulu-dirty", WithDirtyState())
+	require.NoError(t, err, "Failed to create zulu-dirty repo")
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n", workspace)
+	configPath := filepath.Join(workspace, ".gitagrip.toml")
+	require.NoError(t, os.WriteFile(configPath, []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.WaitFor(func(string) bool {
+		return listedBefore(tf.SnapshotPlain(), "alpha-clean", "zulu-dirty")
+	}, 5*time.Second), "Repos should start out sorted by name")
+
+	require.NoError(t, tf.SendKeys(keyCycleSort))
+	require.True(t, tf.OutputContainsPlain("Sorting by dirty first", 2*time.Second), "Should announce the sort")
+	require.True(t, tf.SeePlain("[Sort: dirty first]"), "Title bar should show the sort")
+	require.True(t, tf.WaitFor(func(string) bool {
+		return listedBefore(tf.SnapshotPlain(), "zulu-dirty", "alpha-clean")
+	}, 5*time.Second), "Dirty repo should move to the top")
+
+	require.True(t, tf.WaitFor(func(string) bool {
+		data, err := os.ReadFile(configPath)
+		return err == nil && strings.Contains(string(data), `sort = "status"`)
+	}, 3*time.Second), "Sort should be saved in the config")
+}
+
+func TestSortCycleReturnsToName(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	_, err = tf.CreateTestRepo("cycle-repo")
+	require.NoError(t, err, "Failed to create repo")
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.SeePlain("cycle-repo"), "Should show repo")
+
+	for _, label := range []string{"dirty first", "ahead/behind", "last commit", "branch", "name"} {
+		require.NoError(t, tf.SendKeys(keyCycleSort))
+		require.True(t, tf.OutputContainsPlain("Sorting by "+label, 2*time.Second), "Should switch to sorting by %s", label)
+	}
+	require.False(t, tf.SeePlain("[Sort:"), "Sorting by name should not be flagged in the title bar")
+}
+
+func TestSavedSortIsRestored(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	_, err = tf.CreateTestRepo("alpha-clean")
+	require.NoError(t, err, "Failed to create alpha-clean repo")
+	_, err = tf.CreateTestRepo("zulu-dirty", WithDirtyState())
+	require.NoError(t, err, "Failed to create zulu-dirty repo")
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n\n[ui]\nsort = \"status\"\n", workspace)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("[Sort: dirty first]", 5*time.Second), "Title bar should show the saved sort")
+	require.True(t, tf.WaitFor(func(string) bool {
+		return listedBefore(tf.SnapshotPlain(), "zulu-dirty", "alpha-clean")
+	}, 5*time.Second), "Dirty repo should be listed first once its status is known")
+}
+
+func TestUnknownSortRefusesToStart(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n\n[ui]\nsort = \"size\"\n", workspace)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.OutputContainsPlain(`ui.sort: unknown sort "size"`, 3*time.Second), "Should report the invalid sort")
+}
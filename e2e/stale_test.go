@@ -0,0 +1,76 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"fmt"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestStaleReposAreFlaggedAndFiltered(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+
+	// A feature branch whose upstream was deleted on the remote
+	gonePath, err := tf.CreateTestRepo("gone-repo", WithRemote())
+	require.NoError(t, err, "Failed to create gone-repo")
+	for _, args := range [][]string{
+		{"checkout", "-b", "feature"},
+		{"push", "-u", "origin", "feature"},
+		{"push", "origin", "--delete", "feature"},
+	} {
+		out, err := exec.Command("git", append([]string{"-C", gonePath}, args...)...).CombinedOutput()
+		require.NoError(t, err, "git %v: %s", args, out)
+	}
+
+	// Nothing committed for years
+	oldPath, err := tf.CreateTestRepo("old-repo")
+	require.NoError(t, err, "Failed to create old-repo")
+	require.NoError(t, os.WriteFile(filepath.Join(oldPath, "old.txt"), []byte("old"), 0644))
+	for _, args := range [][]string{{"add", "old.txt"}, {"commit", "-m", "Long ago"}} {
+		cmd := exec.Command("git", append([]string{"-C", oldPath}, args...)...)
+		cmd.Env = append(os.Environ(), "GIT_COMMITTER_DATE=2020-01-01T00:00:00Z", "GIT_AUTHOR_DATE=2020-01-01T00:00:00Z")
+		out, err := cmd.CombinedOutput()
+		require.NoError(t, err, "git %v: %s", args, out)
+	}
+
+	_, err = tf.CreateTestRepo("fresh-repo")
+	require.NoError(t, err, "Failed to create fresh-repo")
+
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n\n[stale]\ngone_upstream = true\nafter = \"90d\"\n", workspace)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("gone-repo (feature) stale", 5*time.Second), "Gone upstream should be flagged stale")
+	require.True(t, tf.OutputContainsPlain("old-repo (main) stale", 5*time.Second), "Old last commit should be flagged stale")
+	require.True(t, tf.OutputContainsPlain("fresh-repo (main)", 5*time.Second), "Should show fresh-repo")
+	require.False(t, tf.SeePlain("fresh-repo (main) stale"), "Recently committed repo should not be stale")
+
+	require.NoError(t, tf.SendKeys("F"))
+	require.True(t, tf.OutputContainsPlain("Filter:", 2*time.Second), "Filter prompt should appear")
+	for _, char := range "is:stale" {
+		require.NoError(t, tf.SendKeys(string(char)))
+		time.Sleep(50 * time.Millisecond)
+	}
+	require.NoError(t, tf.SendKeys("\r"))
+	require.True(t, tf.OutputContains("[Filter: is:stale]", 3*time.Second), "Filter indicator should appear")
+
+	parts := strings.Split(tf.SnapshotPlain(), "[Filter: is:stale]")
+	require.Len(t, parts, 2, "Filter indicator should be shown once")
+	require.Contains(t, parts[1], "gone-repo", "Gone upstream repo should match is:stale")
+	require.Contains(t, parts[1], "old-repo", "Old repo should match is:stale")
+	require.NotContains(t, parts[1], "fresh-repo", "Fresh repo should be filtered out")
+}
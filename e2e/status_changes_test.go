@@ -0,0 +1,51 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestStatusChangesAfterFetch(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	_, err = tf.CreateTestRepo("moving", WithRemote())
+	require.NoError(t, err, "Failed to create moving repo")
+	_, err = tf.CreateTestRepo("steady", WithRemote())
+	require.NoError(t, err, "Failed to create steady repo")
+
+	// Someone else pushes to moving's remote
+	clone := filepath.Join(t.TempDir(), "clone")
+	require.NoError(t, tf.runGitCommand("", "clone", filepath.Join(workspace, "moving-remote.git"), clone))
+	require.NoError(t, os.WriteFile(filepath.Join(clone, "new.txt"), []byte("new"), 0644))
+	require.NoError(t, tf.runGitCommand(clone, "add", "."))
+	require.NoError(t, tf.runGitCommand(clone, "commit", "-m", "Upstream change"))
+	require.NoError(t, tf.runGitCommand(clone, "push", "origin", "main"))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("steady (main)", 5*time.Second), "Should show repos")
+
+	require.NoError(t, tf.SendKeys("U"))
+	require.True(t, tf.OutputContainsPlain("No fetch or pull yet", 2*time.Second), "Nothing to compare before a fetch")
+	require.NoError(t, tf.SendKeys("U"))
+
+	// Fetch everything
+	require.NoError(t, tf.SendKeys("A"))
+	require.NoError(t, tf.SendKeys("f"))
+	require.True(t, tf.WaitForStatusMessage("press U to see what changed", 10*time.Second), "Should point at the changes")
+
+	require.NoError(t, tf.SendKeys("U"))
+	require.True(t, tf.OutputContainsPlain("What the last fetch changed", 2*time.Second), "Should open the changes")
+	require.True(t, tf.OutputContainsPlain("behind 0 → 1", 5*time.Second), "moving should be one commit behind now")
+}
@@ -0,0 +1,57 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestStatusFilterKeysKeepGroupsAndCounts(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	dirtyPath, err := tf.CreateTestRepo("dirty-repo")
+	require.NoError(t, err, "Failed to create dirty repo")
+	cleanPath, err := tf.CreateTestRepo("clean-repo")
+	require.NoError(t, err, "Failed to create clean repo")
+	require.NoError(t, os.WriteFile(filepath.Join(dirtyPath, "README.md"), []byte("# changed\n"), 0644))
+
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n\n[groups]\nWork = [%q, %q]\n", workspace, dirtyPath, cleanPath)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("clean-repo", 5*time.Second), "Should show repos")
+
+	// 2: only dirty repos, the header counting them out of the group
+	require.NoError(t, tf.SendKeys("2"))
+	require.True(t, tf.WaitFor(func(string) bool {
+		s := tf.SnapshotPlain()
+		return strings.Contains(s, "Work (1/2)") && strings.Contains(s, "dirty-repo") && !strings.Contains(s, "clean-repo")
+	}, 3*time.Second), "Should show only the dirty repo within its group")
+
+	// 4: nothing errored
+	require.NoError(t, tf.SendKeys("4"))
+	require.True(t, tf.WaitFor(func(string) bool {
+		s := tf.SnapshotPlain()
+		return strings.Contains(s, "Work (0/2)") && !strings.Contains(s, "dirty-repo")
+	}, 3*time.Second), "Should hide the repos without errors")
+
+	// 1: everything again
+	require.NoError(t, tf.SendKeys("1"))
+	require.True(t, tf.WaitFor(func(string) bool {
+		s := tf.SnapshotPlain()
+		return strings.Contains(s, "Work (2)") && strings.Contains(s, "dirty-repo") && strings.Contains(s, "clean-repo")
+	}, 3*time.Second), "Should show every repo")
+}
@@ -0,0 +1,62 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestStatusModeFastSkipsUntrackedFiles(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	repoPath, err := tf.CreateTestRepo("slow-share")
+	require.NoError(t, err, "Failed to create repo")
+	require.NoError(t, os.WriteFile(filepath.Join(repoPath, "scratch.txt"), []byte("notes"), 0644))
+
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n\n[status]\nmode = \"fast\"\n", workspace)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	require.NoError(t, tf.StartApp("-d", workspace))
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("slow-share", 5*time.Second), "Should show the repo")
+
+	require.NoError(t, tf.SendKeys("i"))
+	require.True(t, tf.OutputContainsPlain("untracked files not checked", 5*time.Second),
+		"Info should say untracked files were skipped")
+}
+
+func TestStatusModeBranchOnlyPerGroup(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	repoPath, err := tf.CreateTestRepo("mounted")
+	require.NoError(t, err, "Failed to create repo")
+	require.NoError(t, os.WriteFile(filepath.Join(repoPath, "README.md"), []byte("changed"), 0644))
+
+	// The group's mode overrides the global one
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\ngroup_order = [\"NFS\"]\n\n[groups]\nNFS = [%q]\n\n[status]\nmode = \"full\"\n\n[status.groups]\nNFS = \"branch-only\"\n",
+		workspace, repoPath)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	require.NoError(t, tf.StartApp("-d", workspace))
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("mounted", 5*time.Second), "Should show the repo")
+
+	require.NoError(t, tf.SendKeys("j"))
+	require.NoError(t, tf.SendKeys("i"))
+	require.True(t, tf.OutputContainsPlain("Not read (status mode branch-only)", 5*time.Second),
+		"Info should say the working tree wasn't read")
+}
@@ -0,0 +1,118 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestTagsShowAsBadgesAndFilter(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	rustPath, err := tf.CreateTestRepo("crab-service")
+	require.NoError(t, err, "Failed to create crab-service repo")
+	_, err = tf.CreateTestRepo("gopher-service")
+	require.NoError(t, err, "Failed to create gopher-service repo")
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n\n[repos.%q]\nlabels = [\"rust\"]\n", workspace, rustPath)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("#rust", 5*time.Second), "Labels should show as tag badges")
+
+	// tag: filters by tag instead of name
+	require.NoError(t, tf.SendKeys("F"))
+	require.NoError(t, tf.SendKeys("tag:rust"+KeyEnter))
+	require.True(t, tf.WaitFor(func(s string) bool {
+		plain := tf.SnapshotPlain()
+		return strings.Contains(plain, "crab-service") && !strings.Contains(plain, "gopher-service")
+	}, 3*time.Second), "Only the tagged repo should remain")
+}
+
+func TestEditTagsSavesLabels(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	_, err = tf.CreateTestRepo("pager-duty")
+	require.NoError(t, err, "Failed to create repo")
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n", workspace)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.SeePlain("pager-duty"), "Should show repo")
+
+	require.NoError(t, tf.SendKeys("G"))
+	require.NoError(t, tf.SendKeys("t"))
+	require.True(t, tf.OutputContainsPlain("Tags (comma-separated):", 2*time.Second), "Tag editor should open")
+	require.NoError(t, tf.SendKeys("oncall, #deprecated"+KeyEnter))
+	require.True(t, tf.OutputContainsPlain("#oncall #deprecated", 3*time.Second), "New tags should show as badges")
+
+	require.Eventually(t, func() bool {
+		data, err := os.ReadFile(filepath.Join(workspace, ".gitagrip.toml"))
+		return err == nil && strings.Contains(string(data), "oncall") && strings.Contains(string(data), "deprecated")
+	}, 3*time.Second, 100*time.Millisecond, "Tags should be saved as labels")
+
+	// The editor starts from the current tags
+	require.NoError(t, tf.SendKeys("t"))
+	require.True(t, tf.OutputContainsPlain("oncall, deprecated", 2*time.Second), "Tag editor should be pre-filled")
+}
+
+func TestBulkLabelEditorTogglesAcrossSelection(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	migratedPath, err := tf.CreateTestRepo("migrated")
+	require.NoError(t, err, "Failed to create migrated repo")
+	_, err = tf.CreateTestRepo("pending")
+	require.NoError(t, err, "Failed to create pending repo")
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n\n[repos.%q]\nlabels = [\"ci-v2\"]\n", workspace, migratedPath)
+	configPath := filepath.Join(workspace, ".gitagrip.toml")
+	require.NoError(t, os.WriteFile(configPath, []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("#ci-v2", 5*time.Second), "Should show the existing label")
+
+	require.NoError(t, tf.SendKeys("A"))
+	require.NoError(t, tf.SendKeys("t"))
+	require.True(t, tf.OutputContainsPlain("Labels for 2 selected repos", 2*time.Second), "Should open the label editor")
+	require.True(t, tf.SeePlain("[-] ci-v2  1/2"), "Should count the label across the selection")
+
+	require.NoError(t, tf.SendKeys(KeySpace))
+	require.True(t, tf.OutputContainsPlain("+ add to 1", 2*time.Second), "Should mark the label to be added")
+	require.NoError(t, tf.SendKeys(KeyEnter))
+	require.True(t, tf.OutputContainsPlain("Updated labels on 1 repos", 2*time.Second), "Should apply the change")
+
+	// n adds a new label to the whole selection
+	require.NoError(t, tf.SendKeys("t"))
+	require.True(t, tf.OutputContainsPlain("[x] ci-v2  2/2", 2*time.Second), "Every selected repo should have the label now")
+	require.NoError(t, tf.SendKeys("n"))
+	require.NoError(t, tf.SendKeys("backend"+KeyEnter))
+	require.True(t, tf.OutputContainsPlain("Tagged 2 repos: backend", 2*time.Second), "Should add the new label to the selection")
+
+	require.True(t, tf.WaitFor(func(string) bool {
+		data, err := os.ReadFile(configPath)
+		return err == nil && strings.Count(string(data), "ci-v2") == 2 && strings.Count(string(data), "backend") == 2
+	}, 3*time.Second), "Labels should be saved for both repos")
+}
@@ -0,0 +1,36 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestToastSummarizesBulkFetch(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	_, err = tf.CreateTestRepo("reachable", WithRemote())
+	require.NoError(t, err, "Failed to create repo")
+	_, err = tf.CreateTestRepo("gone", WithRemote())
+	require.NoError(t, err, "Failed to create repo")
+	// The second repo's remote disappears, so its fetch fails
+	require.NoError(t, os.RemoveAll(filepath.Join(workspace, "gone-remote.git")))
+
+	require.NoError(t, tf.StartApp("-d", workspace))
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("reachable", 5*time.Second), "Should show repos")
+
+	require.NoError(t, tf.SendKeys("A"))
+	require.NoError(t, tf.SendKeys("f"))
+	require.True(t, tf.OutputContainsPlain("Fetched 1 of 2 repos, 1 failed", 10*time.Second),
+		"A toast should summarize the fetch")
+}
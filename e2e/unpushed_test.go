@@ -0,0 +1,102 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"os"
+	"os/exec"
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestUnpushedListsCommitsAheadOfUpstream(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	tracked, err := tf.CreateTestRepo("tracked-repo", WithRemote())
+	require.NoError(t, err, "Failed to create tracked repo")
+	local, err := tf.CreateTestRepo("local-repo")
+	require.NoError(t, err, "Failed to create local repo")
+	git := func(repo string, args ...string) {
+		out, err := exec.Command("git", append([]string{"-C", repo}, args...)...).CombinedOutput()
+		require.NoError(t, err, "git %v: %s", args, out)
+	}
+	commit := func(repo, file, message string) {
+		require.NoError(t, os.WriteFile(filepath.Join(repo, file), []byte(message), 0644))
+		git(repo, "add", file)
+		git(repo, "-c", "user.name=Tester", "-c", "user.email=tester@example.com", "commit", "-m", message)
+	}
+	git(tracked, "branch", "--set-upstream-to=origin/main")
+	commit(tracked, "plan.md", "Draft the vacation handover")
+	commit(local, "notes.md", "Local notes never pushed")
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("tracked-repo", 5*time.Second), "Should show repo")
+
+	// Only what the upstream lacks is listed
+	require.NoError(t, tf.SendKeys("/tracked-repo\r"))
+	require.NoError(t, tf.SendKeys("v"))
+	require.True(t, tf.WaitFor(func(string) bool {
+		s := tf.SnapshotPlain()
+		return strings.Contains(s, "Unpushed commits: tracked-repo") && strings.Contains(s, "Draft the vacation handover") &&
+			strings.Contains(s, "not in origin/main") && !strings.Contains(s, "Initial commit")
+	}, 5*time.Second), "Should list the commit ahead of origin/main")
+	require.NoError(t, tf.SendKeys("\x1b"))
+	require.True(t, tf.WaitFor(func(string) bool {
+		return !strings.Contains(tf.SnapshotPlain(), "Unpushed commits:")
+	}, 3*time.Second), "esc should close the view")
+
+	// Across a selection, a repo without a remote counts all its commits
+	require.NoError(t, tf.SendKeys("A"))
+	require.NoError(t, tf.SendKeys("v"))
+	require.True(t, tf.WaitFor(func(string) bool {
+		s := tf.SnapshotPlain()
+		return strings.Contains(s, "Unpushed commits: the selected repos") && strings.Contains(s, "3 commits in 2 repos") &&
+			strings.Contains(s, "Local notes never pushed") && strings.Contains(s, "not on any remote")
+	}, 5*time.Second), "Should aggregate the selected repos")
+}
+
+func TestUnpushedFollowsRenamingFetchRefspec(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	repo, err := tf.CreateTestRepo("mirrored-repo", WithRemote())
+	require.NoError(t, err, "Failed to create repo")
+	git := func(args ...string) {
+		out, err := exec.Command("git", append([]string{"-C", repo}, args...)...).CombinedOutput()
+		require.NoError(t, err, "git %v: %s", args, out)
+	}
+	// origin's branches land under refs/remotes/mirror, so origin/main does not exist
+	git("config", "remote.origin.fetch", "+refs/heads/*:refs/remotes/mirror/*")
+	git("fetch", "origin")
+	git("update-ref", "-d", "refs/remotes/origin/main")
+	git("config", "branch.main.remote", "origin")
+	git("config", "branch.main.merge", "refs/heads/main")
+	require.NoError(t, os.WriteFile(filepath.Join(repo, "plan.md"), []byte("plan"), 0644))
+	git("add", "plan.md")
+	git("-c", "user.name=Tester", "-c", "user.email=tester@example.com", "commit", "-m", "Sketch the mirror plan")
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.OutputContainsPlain("mirrored-repo", 5*time.Second), "Should show repo")
+
+	require.NoError(t, tf.SendKeys("/mirrored-repo\r"))
+	require.NoError(t, tf.SendKeys("v"))
+	require.True(t, tf.WaitFor(func(string) bool {
+		s := tf.SnapshotPlain()
+		return strings.Contains(s, "Sketch the mirror plan") && strings.Contains(s, "not in mirror/main")
+	}, 5*time.Second), "Should compare against the ref the fetch refspec maps main to")
+}
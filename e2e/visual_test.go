@@ -0,0 +1,40 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestVisualRangeSelection(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	for _, name := range []string{"alpha", "beta", "gamma"} {
+		_, err = tf.CreateTestRepo(name)
+		require.NoError(t, err, "Failed to create %s repo", name)
+	}
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.SeePlain("gamma"), "Should show repos")
+
+	require.NoError(t, tf.SendKeys("V"))
+	require.True(t, tf.OutputContainsPlain("-- VISUAL --", 2*time.Second), "Should show visual mode prompt")
+
+	// Extend over all three repos and select them
+	require.NoError(t, tf.SendKeys("jj"))
+	require.NoError(t, tf.SendKeys(" "))
+	require.True(t, tf.WaitForStatusMessage("Selected 3 repositories", 2*time.Second), "Space should select the whole range")
+
+	// Same range again deselects it
+	require.NoError(t, tf.SendKeys("Vkk "))
+	require.True(t, tf.WaitForStatusMessage("Deselected 3 repositories", 2*time.Second), "Space on a fully selected range should deselect it")
+}
@@ -0,0 +1,90 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"bufio"
+	"encoding/json"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestWatchPrintsJSONEvents(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	api, err := tf.CreateTestRepo("api")
+	require.NoError(t, err)
+
+	cmd := exec.Command(binPath, "watch", "-d", workspace, "-json", "-refresh", "200ms")
+	stdout, err := cmd.StdoutPipe()
+	require.NoError(t, err)
+	require.NoError(t, cmd.Start())
+	defer func() { _ = cmd.Process.Kill() }()
+
+	type event struct {
+		Event  string `json:"event"`
+		Repo   string `json:"repo"`
+		Name   string `json:"name"`
+		Repos  *int   `json:"repos"`
+		Status *struct {
+			Branch string `json:"branch"`
+			Dirty  bool   `json:"dirty"`
+		} `json:"status"`
+	}
+	events := make(chan event)
+	go func() {
+		defer close(events)
+		lines := bufio.NewScanner(stdout)
+		for lines.Scan() {
+			var e event
+			if json.Unmarshal(lines.Bytes(), &e) == nil {
+				events <- e
+			} else {
+				t.Errorf("not a JSON line: %q", lines.Text())
+			}
+		}
+	}()
+	next := func(kind string, match func(event) bool) event {
+		t.Helper()
+		timeout := time.After(10 * time.Second)
+		for {
+			select {
+			case e, ok := <-events:
+				require.True(t, ok, "watch exited before printing %s", kind)
+				if e.Event == kind && match(e) {
+					return e
+				}
+			case <-timeout:
+				t.Fatalf("no %s event", kind)
+			}
+		}
+	}
+	first := func(event) bool { return true }
+
+	discovered := next("repo_discovered", first)
+	require.Equal(t, "api", discovered.Name)
+	require.Equal(t, api, discovered.Repo)
+	clean := next("status_updated", func(e event) bool { return e.Repo == api })
+	require.Equal(t, "main", clean.Status.Branch)
+	require.False(t, clean.Status.Dirty)
+	scanned := next("scan_completed", first)
+	require.Equal(t, 1, *scanned.Repos)
+
+	// A change shows up with the next refresh; unchanged statuses are not repeated
+	require.NoError(t, os.WriteFile(filepath.Join(api, "new.txt"), []byte("x"), 0644))
+	dirty := next("status_updated", func(e event) bool { return e.Repo == api })
+	require.True(t, dirty.Status.Dirty)
+
+	// Interrupting it stops it cleanly
+	require.NoError(t, cmd.Process.Signal(os.Interrupt))
+	require.NoError(t, cmd.Wait())
+}
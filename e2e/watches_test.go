@@ -0,0 +1,54 @@
+//go:build e2e && unix
+
+package main
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/stretchr/testify/require"
+)
+
+func TestWatchedBranchReportsNewCommits(t *testing.T) {
+	t.Parallel()
+	tf := NewTUITest(t)
+	defer tf.Cleanup()
+
+	workspace, err := tf.CreateTestWorkspace()
+	require.NoError(t, err, "Failed to create test workspace")
+	_, err = tf.CreateTestRepo("watched", WithRemote())
+	require.NoError(t, err, "Failed to create repo")
+
+	content := fmt.Sprintf("version = 1\nbase_dir = %q\n\n[[watches]]\nrepo = \"watched\"\nbranch = \"main\"\n", workspace)
+	require.NoError(t, os.WriteFile(filepath.Join(workspace, ".gitagrip.toml"), []byte(content), 0644))
+
+	err = tf.StartApp("-d", workspace)
+	require.NoError(t, err, "Failed to start app")
+	require.True(t, tf.Ready(), "Should receive ready signal")
+	require.True(t, tf.SeePlain("watched"), "Should show repo")
+
+	// The first fetch only records where the branch is
+	require.NoError(t, tf.SendKeys("f"))
+	require.True(t, tf.WaitForStatusMessage("Fetch completed", 5*time.Second), "Fetch should complete")
+
+	// Someone else pushes to the watched branch
+	clone := filepath.Join(t.TempDir(), "clone")
+	require.NoError(t, tf.runGitCommand("", "clone", filepath.Join(workspace, "watched-remote.git"), clone))
+	require.NoError(t, os.WriteFile(filepath.Join(clone, "new.txt"), []byte("new"), 0644))
+	require.NoError(t, tf.runGitCommand(clone, "add", "."))
+	require.NoError(t, tf.runGitCommand(clone, "commit", "-m", "Add upstream feature"))
+	require.NoError(t, tf.runGitCommand(clone, "push", "origin", "main"))
+
+	require.NoError(t, tf.SendKeys("f"))
+	require.True(t, tf.WaitForStatusMessage("1 new commits on main in watched", 5*time.Second), "Should announce the new commit")
+	require.True(t, tf.OutputContainsPlain("[1 new]", 2*time.Second), "Repo row should show the badge")
+
+	require.NoError(t, tf.SendKeys("W"))
+	require.True(t, tf.OutputContainsPlain("Add upstream feature", 2*time.Second), "Watched changes should list the commit subject")
+
+	require.NoError(t, tf.SendKeys("a"))
+	require.True(t, tf.WaitForStatusMessage("Acknowledged 1 watched commits", 2*time.Second), "a should acknowledge the commits")
+}
@@ -0,0 +1,37 @@
+// Package engine is gitagrip's scanning and status engine without the terminal UI, for
+// tools that want to embed it (editor plugins, dashboards, scripts).
+//
+// An Engine discovers git repositories below one or more directories and keeps their
+// status up to date. Everything happens in the background and is reported as events:
+//
+//	eng := engine.New(engine.Options{})
+//	defer eng.Close()
+//
+//	eng.Subscribe(engine.EventStatusUpdated, func(e engine.Event) {
+//		ev := e.(engine.StatusUpdatedEvent)
+//		fmt.Println(ev.RepoPath, ev.Status.Branch, ev.Status.IsDirty)
+//	})
+//	_ = eng.Scan(ctx, "/home/me/code")
+//
+// Handlers run on the engine's dispatch goroutine, one event at a time and in the order
+// the events were published. Events are queued until their handlers are done with the
+// ones before, never dropped, so a slow handler holds up every event after it: hand long
+// work off to another goroutine. The function Subscribe returns removes the handler.
+// Discovered repositories are refreshed automatically. Refresh, Fetch, Pull and
+// CloneMissing queue more work, and Status computes one repository's status
+// synchronously.
+//
+// # Compatibility
+//
+// This package follows semantic versioning from gitagrip v1 on: within a major version,
+// exported identifiers are not removed or renamed and their behavior does not change
+// incompatibly. The following may change in minor releases and embedders must cope:
+//
+//   - new fields on structs (use keyed struct literals)
+//   - new event types and new Operation values (ignore what you don't recognize)
+//   - new methods on Engine
+//
+// Interfaces meant to be implemented by embedders (StatusCache, WatchState) only gain
+// methods in a new major version. Packages under internal/ have no guarantees at all;
+// use only what this package exports.
+package engine
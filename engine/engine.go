@@ -0,0 +1,162 @@
+package engine
+
+import (
+	"context"
+
+	"github.com/darksworm/gitagrip/internal/config"
+	"github.com/darksworm/gitagrip/internal/discovery"
+	"github.com/darksworm/gitagrip/internal/eventbus"
+	"github.com/darksworm/gitagrip/internal/git"
+)
+
+// Options configures an Engine. The zero value is ready to use.
+type Options struct {
+	Config  *Config     // base directory, groups and, from LoadConfig, the workspace's settings (nil uses the defaults)
+	Cache   StatusCache // last-known statuses published before fresh ones (nil disables caching)
+	Watches WatchState  // acknowledged commits of the config's [[watches]] (nil disables watches)
+}
+
+// Engine discovers repositories and keeps their status up to date in the background
+type Engine struct {
+	bus       eventbus.EventBus
+	discovery discovery.DiscoveryService
+	git       git.GitService
+}
+
+// New creates an engine. Subscribe before calling Scan so no event is missed.
+func New(opts Options) *Engine {
+	cfg := config.SettingsOf(opts.Config)
+	bus := eventbus.New()
+	return &Engine{
+		bus:       bus,
+		discovery: discovery.NewDiscoveryServiceWithConfig(bus, cfg),
+		git:       git.NewGitServiceWithWatches(bus, cfg, opts.Cache, opts.Watches),
+	}
+}
+
+// Subscribe registers a handler for one event type and returns a function that removes it
+func (e *Engine) Subscribe(eventType EventType, handler EventHandler) (unsubscribe func()) {
+	return e.bus.Subscribe(eventType, handler)
+}
+
+// Scan starts looking for repositories below roots. Each one found is reported with a
+// RepoDiscoveredEvent and refreshed, and a ScanCompletedEvent ends the scan. A scan
+// that is already running is an error.
+func (e *Engine) Scan(ctx context.Context, roots ...string) error {
+	return e.discovery.StartScan(ctx, roots)
+}
+
+// StopScan cancels a running scan
+func (e *Engine) StopScan() {
+	e.discovery.StopScan()
+}
+
+// Status computes the status of one repository and waits for it. The result is also
+// published as a StatusUpdatedEvent.
+func (e *Engine) Status(ctx context.Context, repoPath string) (RepoStatus, error) {
+	return e.git.RefreshRepo(ctx, repoPath)
+}
+
+// StatusWith computes the status of one repository with the given options instead of the
+// ones [status] selects, and waits for it
+func (e *Engine) StatusWith(ctx context.Context, repoPath string, opts StatusOptions) (RepoStatus, error) {
+	return e.git.Status(ctx, repoPath, opts)
+}
+
+// Branches lists a repository's local branches, then its remote-tracking ones, by name
+func (e *Engine) Branches(ctx context.Context, repoPath string) ([]Branch, error) {
+	return e.git.Branches(ctx, repoPath)
+}
+
+// Remotes lists a repository's remotes by name
+func (e *Engine) Remotes(ctx context.Context, repoPath string) ([]Remote, error) {
+	return e.git.Remotes(ctx, repoPath)
+}
+
+// UpstreamOf returns the branch a local branch tracks, e.g. "origin/main" ("" when none)
+func (e *Engine) UpstreamOf(ctx context.Context, repoPath, branch string) string {
+	return e.git.UpstreamOf(ctx, repoPath, branch)
+}
+
+// AheadBehind counts the commits on a that are not on b and the other way round. Both
+// can be anything git rev-parse understands, e.g. "main" and "origin/main".
+func (e *Engine) AheadBehind(ctx context.Context, repoPath, a, b string) (ahead, behind int, err error) {
+	return e.git.AheadBehind(ctx, repoPath, a, b)
+}
+
+// AnswerCredential replies to a CredentialRequestedEvent, which a fetch, pull or clone
+// publishes when git or ssh asks for a password or passphrase. Declining (or not answering
+// within five minutes) fails the command.
+func (e *Engine) AnswerCredential(id, value string, decline bool) {
+	e.bus.Publish(eventbus.CredentialProvidedEvent{ID: id, Value: value, Cancelled: decline})
+}
+
+// Refresh queues a status refresh of repositories (all discovered ones if none are given)
+func (e *Engine) Refresh(repoPaths ...string) {
+	e.bus.Publish(eventbus.StatusRefreshRequestedEvent{RepoPaths: repoPaths})
+}
+
+// Fetch queues git fetch in repositories (all discovered ones if none are given). Each
+// finished fetch is reported with a FetchCompletedEvent.
+func (e *Engine) Fetch(repoPaths ...string) {
+	e.bus.Publish(eventbus.FetchRequestedEvent{RepoPaths: repoPaths})
+}
+
+// FetchWith is Fetch limited to one remote, a tag mode or a history depth
+func (e *Engine) FetchWith(opts FetchOptions, repoPaths ...string) {
+	e.bus.Publish(eventbus.FetchRequestedEvent{RepoPaths: repoPaths, Options: opts})
+}
+
+// Pull queues git pull in repositories (all discovered ones if none are given). Each
+// finished pull is reported with a PullCompletedEvent.
+func (e *Engine) Pull(repoPaths ...string) {
+	e.bus.Publish(eventbus.PullRequestedEvent{RepoPaths: repoPaths})
+}
+
+// Push queues git push of the current branch in repositories, to its tracking branch or a
+// same-named one on the preferred remote or origin. Repositories with the guards'
+// protected label are skipped. Each finished push is reported with a PushCompletedEvent,
+// whose Reason says why the remote rejected it.
+func (e *Engine) Push(repoPaths ...string) {
+	e.bus.Publish(eventbus.PushRequestedEvent{RepoPaths: repoPaths})
+}
+
+// CloneMissing queues git clone of the repositories in the config's [repos] that have a
+// url but are not on disk, or of the given ones among them. Progress is reported with
+// "clone" OperationProgressEvents, each clone with a CommandExecutedEvent and each cloned
+// repository with a RepoDiscoveredEvent.
+func (e *Engine) CloneMissing(repoPaths ...string) {
+	e.bus.Publish(eventbus.CloneMissingRequestedEvent{RepoPaths: repoPaths})
+}
+
+// Cancel drops queued fetches and pulls; the ones already running finish
+func (e *Engine) Cancel() {
+	e.bus.Publish(eventbus.CancelOperationsRequestedEvent{})
+}
+
+// Close stops scanning, status reads and event delivery. The engine must not be used
+// afterwards.
+func (e *Engine) Close() {
+	e.discovery.StopScan()
+	e.git.Close()
+	if stopper, ok := e.bus.(interface{ Stop() }); ok {
+		stopper.Stop()
+	}
+}
+
+// FindRepositories walks root and returns the paths of the repositories below it
+// (linked worktrees are left out), without computing any status
+func FindRepositories(ctx context.Context, root string) ([]string, error) {
+	return discovery.FindRepositories(ctx, root)
+}
+
+// LoadConfig reads the .gitagrip.toml of a workspace, so an embedder sees the same
+// repository names, groups and settings as the TUI. A workspace without one gets the
+// default settings.
+func LoadConfig(dir string) (*Config, error) {
+	cfg, err := config.LoadWorkspace(dir)
+	if err != nil {
+		return nil, err
+	}
+	return config.WorkspaceOf(cfg), nil
+}
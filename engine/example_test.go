@@ -0,0 +1,82 @@
+package engine_test
+
+import (
+	"context"
+	"fmt"
+	"log"
+	"sync"
+
+	"github.com/darksworm/gitagrip/engine"
+)
+
+// Print the status of every repository below a directory once the scan is done
+func Example() {
+	eng := engine.New(engine.Options{})
+	defer eng.Close()
+
+	var mu sync.Mutex
+	statuses := make(map[string]engine.RepoStatus)
+	eng.Subscribe(engine.EventStatusUpdated, func(e engine.Event) {
+		ev := e.(engine.StatusUpdatedEvent)
+		mu.Lock()
+		statuses[ev.RepoPath] = ev.Status
+		mu.Unlock()
+	})
+
+	done := make(chan struct{})
+	eng.Subscribe(engine.EventScanCompleted, func(engine.Event) { close(done) })
+
+	if err := eng.Scan(context.Background(), "/home/me/code"); err != nil {
+		log.Fatal(err)
+	}
+	<-done
+
+	// Statuses keep arriving after the scan; a dashboard would redraw on each one
+	mu.Lock()
+	defer mu.Unlock()
+	for path, status := range statuses {
+		fmt.Printf("%s %s dirty=%v ahead=%d behind=%d\n", path, status.Branch, status.IsDirty, status.AheadCount, status.BehindCount)
+	}
+}
+
+// Compute one repository's status without scanning, using the workspace's settings
+func ExampleEngine_Status() {
+	cfg, err := engine.LoadConfig("/home/me/code")
+	if err != nil {
+		log.Fatal(err)
+	}
+	eng := engine.New(engine.Options{Config: cfg})
+	defer eng.Close()
+
+	status, err := eng.Status(context.Background(), "/home/me/code/api")
+	if err != nil {
+		log.Fatal(err)
+	}
+	if status.InProgress != engine.OperationNone {
+		fmt.Println("stuck in", status.InProgress)
+	}
+}
+
+// Fetch a few repositories and report failures
+func ExampleEngine_Fetch() {
+	eng := engine.New(engine.Options{})
+	defer eng.Close()
+
+	eng.Subscribe(engine.EventFetchCompleted, func(e engine.Event) {
+		if ev := e.(engine.FetchCompletedEvent); !ev.Success {
+			fmt.Println("fetch failed:", ev.RepoPath, ev.Error)
+		}
+	})
+	eng.Fetch("/home/me/code/api", "/home/me/code/web")
+}
+
+// List repositories without computing any status
+func ExampleFindRepositories() {
+	paths, err := engine.FindRepositories(context.Background(), "/home/me/code")
+	if err != nil {
+		log.Fatal(err)
+	}
+	for _, path := range paths {
+		fmt.Println(path)
+	}
+}
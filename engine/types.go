@@ -0,0 +1,94 @@
+package engine
+
+import (
+	"github.com/darksworm/gitagrip/internal/config"
+	"github.com/darksworm/gitagrip/internal/domain"
+	"github.com/darksworm/gitagrip/internal/eventbus"
+)
+
+// Domain types
+type (
+	Repository    = domain.Repository
+	RepoStatus    = domain.RepoStatus
+	Group         = domain.Group
+	CommandLog    = domain.CommandLog
+	Operation     = domain.Operation
+	Branch        = domain.Branch
+	Remote        = domain.Remote
+	FetchOptions  = domain.FetchOptions
+	StatusOptions = domain.StatusOptions
+)
+
+// Config is a workspace's base directory and groups. One that LoadConfig returns also
+// carries the workspace's other settings (repository names, status modes, upstream and
+// guard settings), which the engine applies but does not expose.
+type Config = config.Workspace
+
+// How a fetch gets tags (FetchOptions.Tags)
+const (
+	FetchTagsAuto = domain.FetchTagsAuto
+	FetchTagsAll  = domain.FetchTagsAll
+	FetchTagsNone = domain.FetchTagsNone
+)
+
+// How much of a repository a status reads (StatusOptions.Mode, RepoStatus.Mode)
+const (
+	StatusModeFull       = domain.StatusModeFull
+	StatusModeFast       = domain.StatusModeFast
+	StatusModeBranchOnly = domain.StatusModeBranchOnly
+)
+
+// Multi-step git operations reported in RepoStatus.InProgress
+const (
+	OperationNone       = domain.OperationNone
+	OperationMerge      = domain.OperationMerge
+	OperationRebase     = domain.OperationRebase
+	OperationBisect     = domain.OperationBisect
+	OperationCherryPick = domain.OperationCherryPick
+	OperationRevert     = domain.OperationRevert
+)
+
+// Ports the engine can be given to persist state between runs
+type (
+	StatusCache = domain.StatusCache
+	WatchState  = domain.WatchState
+)
+
+// Events
+type (
+	Event        = eventbus.DomainEvent
+	EventType    = eventbus.EventType
+	EventHandler = eventbus.EventHandler
+)
+
+// Event types an embedder can subscribe to
+const (
+	EventRepoDiscovered      = eventbus.EventRepoDiscovered
+	EventStatusUpdated       = eventbus.EventStatusUpdated
+	EventError               = eventbus.EventError
+	EventScanStarted         = eventbus.EventScanStarted
+	EventScanCompleted       = eventbus.EventScanCompleted
+	EventFetchCompleted      = eventbus.EventFetchCompleted
+	EventPullCompleted       = eventbus.EventPullCompleted
+	EventPushCompleted       = eventbus.EventPushCompleted
+	EventCommandExecuted     = eventbus.EventCommandExecuted
+	EventOperationProgress   = eventbus.EventOperationProgress
+	EventWatchUpdated        = eventbus.EventWatchUpdated
+	EventCredentialRequested = eventbus.EventCredentialRequested
+)
+
+// Event payloads
+type (
+	RepoDiscoveredEvent      = eventbus.RepoDiscoveredEvent
+	StatusUpdatedEvent       = eventbus.StatusUpdatedEvent
+	ErrorEvent               = eventbus.ErrorEvent
+	ScanStartedEvent         = eventbus.ScanStartedEvent
+	ScanCompletedEvent       = eventbus.ScanCompletedEvent
+	FetchCompletedEvent      = eventbus.FetchCompletedEvent
+	PullCompletedEvent       = eventbus.PullCompletedEvent
+	PushCompletedEvent       = eventbus.PushCompletedEvent
+	CommandExecutedEvent     = eventbus.CommandExecutedEvent
+	OperationProgressEvent   = eventbus.OperationProgressEvent
+	WatchUpdatedEvent        = eventbus.WatchUpdatedEvent
+	CredentialRequestedEvent = eventbus.CredentialRequestedEvent
+)
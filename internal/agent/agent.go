@@ -0,0 +1,155 @@
+// Package agent runs gitagrip headless on another machine and connects a local TUI to
+// it: the agent scans and queries the repositories where they live, and the TUI sends
+// it requests and shows the events it reports. The two talk over stdin/stdout, which
+// ssh carries between the machines.
+package agent
+
+import (
+	"bufio"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io"
+	"io/fs"
+	"log"
+	"os"
+	"path/filepath"
+	"sync"
+
+	"github.com/darksworm/gitagrip/internal/config"
+	"github.com/darksworm/gitagrip/internal/discovery"
+	"github.com/darksworm/gitagrip/internal/domain"
+	"github.com/darksworm/gitagrip/internal/eventbus"
+	"github.com/darksworm/gitagrip/internal/git"
+	"github.com/darksworm/gitagrip/internal/statuscache"
+	"github.com/darksworm/gitagrip/internal/watchstate"
+)
+
+// maxFrameSize bounds a single protocol line (a config or a large command output)
+const maxFrameSize = 16 << 20
+
+// Serve runs the engine for the workspace in dir and speaks the agent protocol on r
+// and w until r is closed
+func Serve(dir string, r io.Reader, w io.Writer) error {
+	configPath := filepath.Join(dir, ".gitagrip.toml")
+	cfg, err := loadConfig(configPath, dir)
+	if err != nil {
+		return err
+	}
+
+	bus := eventbus.New()
+	var cache domain.StatusCache
+	var watches domain.WatchState
+	if !cfg.Cache.Disable {
+		if path, err := statuscache.DefaultPath(dir); err == nil {
+			cache = statuscache.Open(path)
+		}
+		if path, err := watchstate.DefaultPath(dir); err == nil && len(cfg.Watches) > 0 {
+			watches = watchstate.Open(path)
+		}
+	}
+	discoverer := discovery.NewDiscoveryServiceWithConfig(bus, cfg)
+	reconciler := discovery.NewReconciler(bus, cfg, nil)
+	gitService := git.NewGitServiceWithWatches(bus, cfg, cache, watches)
+
+	out := &frameWriter{enc: json.NewEncoder(w)}
+	text, err := encodeConfig(cfg)
+	if err != nil {
+		return err
+	}
+	if err := out.write(frame{Kind: kindHello, Config: text}); err != nil {
+		return err
+	}
+
+	for _, eventType := range reportEvents {
+		bus.Subscribe(eventType, func(e eventbus.DomainEvent) {
+			f, err := encodeEvent(e)
+			if err == nil {
+				err = out.write(f)
+			}
+			if err != nil {
+				log.Printf("agent: failed to send %s: %v", e.Type(), err)
+			}
+		})
+	}
+
+	requests := make(map[domain.EventType]bool, len(requestEvents))
+	for _, eventType := range requestEvents {
+		requests[eventType] = true
+	}
+
+	scanner := bufio.NewScanner(r)
+	scanner.Buffer(make([]byte, 64*1024), maxFrameSize)
+	for scanner.Scan() {
+		var f frame
+		if err := json.Unmarshal(scanner.Bytes(), &f); err != nil {
+			log.Printf("agent: ignoring malformed frame: %v", err)
+			continue
+		}
+		switch f.Kind {
+		case kindConfig:
+			updated, err := decodeConfig(f.Config)
+			if err == nil {
+				err = config.NewConfigService().SaveToPath(updated, configPath)
+			}
+			if err != nil {
+				log.Printf("agent: failed to save config: %v", err)
+				continue
+			}
+			// The services read the config from their own goroutines, so each gets the new
+			// one whole rather than seeing this one change under them
+			discoverer.SetConfig(updated)
+			reconciler.SetConfig(updated)
+			gitService.SetConfig(updated)
+		case kindEvent:
+			if !requests[f.Type] {
+				log.Printf("agent: ignoring %s, which is not a request", f.Type)
+				continue
+			}
+			event, err := decodeEvent(f)
+			if err != nil {
+				log.Printf("agent: %v", err)
+				continue
+			}
+			bus.Publish(event)
+		}
+	}
+
+	if cache != nil {
+		if err := cache.Flush(); err != nil {
+			log.Printf("agent: failed to save status cache: %v", err)
+		}
+	}
+	if watches != nil {
+		if err := watches.Flush(); err != nil {
+			log.Printf("agent: failed to save watch state: %v", err)
+		}
+	}
+	return scanner.Err()
+}
+
+// loadConfig reads the workspace config; a workspace without one gets the defaults
+func loadConfig(path, dir string) (*config.Config, error) {
+	if _, err := os.Stat(path); errors.Is(err, fs.ErrNotExist) {
+		cfg := config.DefaultConfig()
+		cfg.BaseDir = dir
+		return cfg, nil
+	}
+	cfg, err := config.NewConfigService().LoadFromPath(path)
+	if err != nil {
+		return nil, fmt.Errorf("loading %s: %w", path, err)
+	}
+	return cfg, nil
+}
+
+// frameWriter writes frames one at a time from any goroutine
+type frameWriter struct {
+	mu  sync.Mutex
+	enc *json.Encoder
+}
+
+func (fw *frameWriter) write(f frame) error {
+	fw.mu.Lock()
+	defer fw.mu.Unlock()
+	return fw.enc.Encode(f)
+}
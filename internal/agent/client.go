@@ -0,0 +1,176 @@
+package agent
+
+import (
+	"bufio"
+	"context"
+	"encoding/json"
+	"fmt"
+	"io"
+	"log"
+	"os"
+	"os/exec"
+	"strings"
+	"sync"
+
+	"github.com/darksworm/gitagrip/internal/config"
+	"github.com/darksworm/gitagrip/internal/eventbus"
+)
+
+// Conn is a connection to an agent on another machine
+type Conn struct {
+	cmd     *exec.Cmd
+	stdin   io.WriteCloser
+	out     *frameWriter
+	scanner *bufio.Scanner
+	cfg     *config.Config
+	stderr  tailBuffer // end of the agent's log, shown when it fails or disconnects
+}
+
+// Dial starts an agent over ssh. The target is "[user@]host[:dir]", dir defaulting to
+// the remote home directory. GITAGRIP_SSH overrides the ssh program and
+// GITAGRIP_REMOTE_BIN the gitagrip binary on the remote machine.
+func Dial(ctx context.Context, target string) (*Conn, error) {
+	host, dir, _ := strings.Cut(target, ":")
+	if host == "" {
+		return nil, fmt.Errorf("invalid remote %q, expected [user@]host[:dir]", target)
+	}
+	sshBin := envOr("GITAGRIP_SSH", "ssh")
+	remoteBin := envOr("GITAGRIP_REMOTE_BIN", "gitagrip")
+
+	// ssh hands the command to the remote shell as one line, so the directory is quoted
+	args := []string{"-T", host, remoteBin, "agent"}
+	if dir != "" {
+		args = append(args, "-d", remoteDir(dir))
+	}
+	cmd := exec.CommandContext(ctx, sshBin, args...)
+	stdin, err := cmd.StdinPipe()
+	if err != nil {
+		return nil, err
+	}
+	stdout, err := cmd.StdoutPipe()
+	if err != nil {
+		return nil, err
+	}
+
+	c := &Conn{cmd: cmd, stdin: stdin, out: &frameWriter{enc: json.NewEncoder(stdin)}}
+	cmd.Stderr = &c.stderr
+	if err := cmd.Start(); err != nil {
+		return nil, fmt.Errorf("starting %s: %w", sshBin, err)
+	}
+
+	c.scanner = bufio.NewScanner(stdout)
+	c.scanner.Buffer(make([]byte, 64*1024), maxFrameSize)
+	var hello frame
+	if !c.scanner.Scan() || json.Unmarshal(c.scanner.Bytes(), &hello) != nil || hello.Kind != kindHello {
+		_ = c.Close()
+		return nil, fmt.Errorf("no agent answered on %s: %s", host, strings.TrimSpace(c.stderr.String()))
+	}
+	c.cfg, err = decodeConfig(hello.Config)
+	if err != nil {
+		_ = c.Close()
+		return nil, err
+	}
+	return c, nil
+}
+
+// remoteDir quotes a directory for the remote shell, leaving a leading ~/ unquoted so that
+// it still expands to the remote home directory
+func remoteDir(dir string) string {
+	if dir == "~" {
+		return dir
+	}
+	if rest, ok := strings.CutPrefix(dir, "~/"); ok {
+		return "~/" + shellQuote(rest)
+	}
+	return shellQuote(dir)
+}
+
+// shellQuote single-quotes s for a POSIX shell; a single quote inside becomes '\''
+func shellQuote(s string) string {
+	return "'" + strings.ReplaceAll(s, "'", `'\''`) + "'"
+}
+
+// Config returns the remote workspace's config
+func (c *Conn) Config() *config.Config {
+	return c.cfg
+}
+
+// Attach connects a local event bus to the agent: requests published on it are carried
+// out remotely and the agent's events are published on it
+func (c *Conn) Attach(bus eventbus.EventBus) {
+	for _, eventType := range requestEvents {
+		bus.Subscribe(eventType, func(e eventbus.DomainEvent) {
+			f, err := encodeEvent(e)
+			if err == nil {
+				err = c.out.write(f)
+			}
+			if err != nil {
+				log.Printf("remote: failed to send %s: %v", e.Type(), err)
+			}
+		})
+	}
+
+	go func() {
+		for c.scanner.Scan() {
+			var f frame
+			if err := json.Unmarshal(c.scanner.Bytes(), &f); err != nil || f.Kind != kindEvent {
+				continue
+			}
+			event, err := decodeEvent(f)
+			if err != nil {
+				log.Printf("remote: %v", err)
+				continue
+			}
+			bus.Publish(event)
+		}
+		log.Printf("remote: agent disconnected: %s", strings.TrimSpace(c.stderr.String()))
+		bus.Publish(eventbus.ErrorEvent{Message: "Lost the connection to the remote agent"})
+	}()
+}
+
+// SaveConfig sends the config to the agent, which saves it in the remote workspace
+func (c *Conn) SaveConfig(cfg *config.Config) error {
+	text, err := encodeConfig(cfg)
+	if err != nil {
+		return err
+	}
+	return c.out.write(frame{Kind: kindConfig, Config: text})
+}
+
+// Close ends the agent and waits for it to exit
+func (c *Conn) Close() error {
+	_ = c.stdin.Close()
+	return c.cmd.Wait()
+}
+
+// maxAgentLog is how much of the agent's log is kept
+const maxAgentLog = 4096
+
+// tailBuffer keeps the last maxAgentLog bytes written to it
+type tailBuffer struct {
+	mu   sync.Mutex
+	data []byte
+}
+
+func (t *tailBuffer) Write(p []byte) (int, error) {
+	t.mu.Lock()
+	defer t.mu.Unlock()
+	t.data = append(t.data, p...)
+	if len(t.data) > maxAgentLog {
+		t.data = t.data[len(t.data)-maxAgentLog:]
+	}
+	return len(p), nil
+}
+
+func (t *tailBuffer) String() string {
+	t.mu.Lock()
+	defer t.mu.Unlock()
+	return string(t.data)
+}
+
+func envOr(name, fallback string) string {
+	if value := os.Getenv(name); value != "" {
+		return value
+	}
+	return fallback
+}
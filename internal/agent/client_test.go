@@ -0,0 +1,18 @@
+package agent
+
+import "testing"
+
+func TestRemoteDirIsQuotedForTheShell(t *testing.T) {
+	for dir, want := range map[string]string{
+		"/srv/code":       `'/srv/code'`,
+		"/srv/my code":    `'/srv/my code'`,
+		"/srv/$(reboot)":  `'/srv/$(reboot)'`,
+		"/srv/bob's code": `'/srv/bob'\''s code'`,
+		"~":               `~`,
+		"~/code":          `~/'code'`,
+	} {
+		if got := remoteDir(dir); got != want {
+			t.Fatalf("remoteDir(%q) = %s, want %s", dir, got, want)
+		}
+	}
+}
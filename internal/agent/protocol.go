@@ -0,0 +1,185 @@
+package agent
+
+import (
+	"encoding/json"
+	"errors"
+	"fmt"
+
+	"github.com/pelletier/go-toml/v2"
+
+	"github.com/darksworm/gitagrip/internal/config"
+	"github.com/darksworm/gitagrip/internal/domain"
+	"github.com/darksworm/gitagrip/internal/eventbus"
+)
+
+// The agent protocol is one JSON frame per line in each direction. The agent starts
+// with a hello frame carrying the workspace config, then reports events; the client
+// sends request events and updated configs.
+const (
+	kindHello  = "hello"  // agent -> client: the workspace config
+	kindEvent  = "event"  // both ways: a domain event
+	kindConfig = "config" // client -> agent: config to save
+)
+
+// frame is one line of the agent protocol
+type frame struct {
+	Kind   string           `json:"kind"`
+	Type   domain.EventType `json:"type,omitempty"`
+	Event  json.RawMessage  `json:"event,omitempty"`
+	Error  string           `json:"error,omitempty"`  // text of the event's error, which JSON can't carry
+	Config string           `json:"config,omitempty"` // .gitagrip.toml contents
+}
+
+// requestEvents are sent by the client and carried out by the agent
+var requestEvents = []domain.EventType{
+	eventbus.EventScanRequested,
+	eventbus.EventStatusRefreshRequested,
+	eventbus.EventFetchRequested,
+	eventbus.EventPullRequested,
+	eventbus.EventPushRequested,
+	eventbus.EventBranchCreateRequested,
+	eventbus.EventBranchSwitchRequested,
+	eventbus.EventRemoteURLChangeRequested,
+	eventbus.EventCleanRequested,
+	eventbus.EventCancelOperationsRequested,
+	eventbus.EventWatchAcknowledgeRequested,
+	eventbus.EventCloneMissingRequested,
+	eventbus.EventCredentialProvided,
+	eventbus.EventReposVisible,
+}
+
+// reportEvents are sent by the agent as its work progresses
+var reportEvents = []domain.EventType{
+	eventbus.EventRepoDiscovered,
+	eventbus.EventRepoRemoved,
+	eventbus.EventStatusUpdated,
+	eventbus.EventError,
+	eventbus.EventScanStarted,
+	eventbus.EventScanCompleted,
+	eventbus.EventFetchCompleted,
+	eventbus.EventPullCompleted,
+	eventbus.EventPushCompleted,
+	eventbus.EventCommandExecuted,
+	eventbus.EventOperationProgress,
+	eventbus.EventWatchUpdated,
+	eventbus.EventCredentialRequested,
+	eventbus.EventAppMessage,
+}
+
+// decoders turn the JSON of an event back into the event
+var decoders = map[domain.EventType]func(data []byte) (domain.DomainEvent, error){
+	eventbus.EventScanRequested:             decodeAs[eventbus.ScanRequestedEvent],
+	eventbus.EventStatusRefreshRequested:    decodeAs[eventbus.StatusRefreshRequestedEvent],
+	eventbus.EventFetchRequested:            decodeAs[eventbus.FetchRequestedEvent],
+	eventbus.EventPullRequested:             decodeAs[eventbus.PullRequestedEvent],
+	eventbus.EventPushRequested:             decodeAs[eventbus.PushRequestedEvent],
+	eventbus.EventBranchCreateRequested:     decodeAs[eventbus.BranchCreateRequestedEvent],
+	eventbus.EventBranchSwitchRequested:     decodeAs[eventbus.BranchSwitchRequestedEvent],
+	eventbus.EventRemoteURLChangeRequested:  decodeAs[eventbus.RemoteURLChangeRequestedEvent],
+	eventbus.EventCleanRequested:            decodeAs[eventbus.CleanRequestedEvent],
+	eventbus.EventCancelOperationsRequested: decodeAs[eventbus.CancelOperationsRequestedEvent],
+	eventbus.EventWatchAcknowledgeRequested: decodeAs[eventbus.WatchAcknowledgeRequestedEvent],
+	eventbus.EventCloneMissingRequested:     decodeAs[eventbus.CloneMissingRequestedEvent],
+	eventbus.EventCredentialProvided:        decodeAs[eventbus.CredentialProvidedEvent],
+	eventbus.EventReposVisible:              decodeAs[eventbus.ReposVisibleEvent],
+	eventbus.EventRepoDiscovered:            decodeAs[eventbus.RepoDiscoveredEvent],
+	eventbus.EventRepoRemoved:               decodeAs[eventbus.RepoRemovedEvent],
+	eventbus.EventStatusUpdated:             decodeAs[eventbus.StatusUpdatedEvent],
+	eventbus.EventError:                     decodeAs[eventbus.ErrorEvent],
+	eventbus.EventScanStarted:               decodeAs[eventbus.ScanStartedEvent],
+	eventbus.EventScanCompleted:             decodeAs[eventbus.ScanCompletedEvent],
+	eventbus.EventFetchCompleted:            decodeAs[eventbus.FetchCompletedEvent],
+	eventbus.EventPullCompleted:             decodeAs[eventbus.PullCompletedEvent],
+	eventbus.EventPushCompleted:             decodeAs[eventbus.PushCompletedEvent],
+	eventbus.EventCommandExecuted:           decodeAs[eventbus.CommandExecutedEvent],
+	eventbus.EventOperationProgress:         decodeAs[eventbus.OperationProgressEvent],
+	eventbus.EventWatchUpdated:              decodeAs[eventbus.WatchUpdatedEvent],
+	eventbus.EventCredentialRequested:       decodeAs[eventbus.CredentialRequestedEvent],
+	eventbus.EventAppMessage:                decodeAs[eventbus.AppMessageEvent],
+}
+
+func decodeAs[T domain.DomainEvent](data []byte) (domain.DomainEvent, error) {
+	var event T
+	err := json.Unmarshal(data, &event)
+	return event, err
+}
+
+// encodeEvent builds the frame for an event. Errors are sent as text.
+func encodeEvent(event domain.DomainEvent) (frame, error) {
+	var errText string
+	switch e := event.(type) {
+	case eventbus.ErrorEvent:
+		errText, e.Err = errString(e.Err), nil
+		event = e
+	case eventbus.FetchCompletedEvent:
+		errText, e.Error = errString(e.Error), nil
+		event = e
+	case eventbus.PullCompletedEvent:
+		errText, e.Error = errString(e.Error), nil
+		event = e
+	case eventbus.PushCompletedEvent:
+		errText, e.Error = errString(e.Error), nil
+		event = e
+	}
+
+	data, err := json.Marshal(event)
+	if err != nil {
+		return frame{}, err
+	}
+	return frame{Kind: kindEvent, Type: event.Type(), Event: data, Error: errText}, nil
+}
+
+// decodeEvent turns an event frame back into the event
+func decodeEvent(f frame) (domain.DomainEvent, error) {
+	decode, ok := decoders[f.Type]
+	if !ok {
+		return nil, fmt.Errorf("unknown event type %q", f.Type)
+	}
+	event, err := decode(f.Event)
+	if err != nil {
+		return nil, fmt.Errorf("invalid %s event: %w", f.Type, err)
+	}
+
+	if f.Error != "" {
+		switch e := event.(type) {
+		case eventbus.ErrorEvent:
+			e.Err = errors.New(f.Error)
+			event = e
+		case eventbus.FetchCompletedEvent:
+			e.Error = errors.New(f.Error)
+			event = e
+		case eventbus.PullCompletedEvent:
+			e.Error = errors.New(f.Error)
+			event = e
+		case eventbus.PushCompletedEvent:
+			e.Error = errors.New(f.Error)
+			event = e
+		}
+	}
+	return event, nil
+}
+
+// encodeConfig renders a config as it is saved in .gitagrip.toml
+func encodeConfig(cfg *config.Config) (string, error) {
+	data, err := toml.Marshal(cfg)
+	return string(data), err
+}
+
+// decodeConfig parses a config sent over the protocol
+func decodeConfig(text string) (*config.Config, error) {
+	var cfg config.Config
+	if err := toml.Unmarshal([]byte(text), &cfg); err != nil {
+		return nil, fmt.Errorf("invalid config: %w", err)
+	}
+	if cfg.Groups == nil {
+		cfg.Groups = make(map[string][]string)
+	}
+	return &cfg, nil
+}
+
+func errString(err error) string {
+	if err == nil {
+		return ""
+	}
+	return err.Error()
+}
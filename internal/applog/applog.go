@@ -0,0 +1,160 @@
+// Package applog writes gitagrip's log to a file under the XDG state directory
+// (gitagrip/logs), as key=value records, rotating it once it grows too big. Nothing is
+// logged to the terminal, where it would garble the UI.
+package applog
+
+import (
+	"bytes"
+	"io"
+	"log/slog"
+	"os"
+	"path/filepath"
+	"strconv"
+	"strings"
+	"sync"
+)
+
+const (
+	maxSize = 5 << 20 // a log file is rotated once it would grow past this
+	backups = 3       // rotated files kept: gitagrip.log.1 (newest) to gitagrip.log.3
+)
+
+// Dir returns where the logs are kept: $XDG_STATE_HOME/gitagrip/logs, by default
+// ~/.local/state/gitagrip/logs
+func Dir() string {
+	stateDir := os.Getenv("XDG_STATE_HOME")
+	if stateDir == "" || !filepath.IsAbs(stateDir) {
+		home, err := os.UserHomeDir()
+		if err != nil {
+			home = "."
+		}
+		stateDir = filepath.Join(home, ".local", "state")
+	}
+	return filepath.Join(stateDir, "gitagrip", "logs")
+}
+
+// Path returns the log file written to
+func Path() string {
+	return filepath.Join(Dir(), "gitagrip.log")
+}
+
+// Setup sends the log package's and slog's output to the log file. The returned file is
+// closed when gitagrip exits.
+func Setup() (io.Closer, error) {
+	file, err := Open(Path())
+	if err != nil {
+		return nil, err
+	}
+	slog.SetDefault(slog.New(slog.NewTextHandler(file, &slog.HandlerOptions{Level: slog.LevelDebug})))
+	return file, nil
+}
+
+// File is a log file that rotates itself once it reaches maxSize
+type File struct {
+	mu   sync.Mutex
+	path string
+	file *os.File
+	size int64
+}
+
+// Open opens the log file at path for appending, creating it and its directory
+func Open(path string) (*File, error) {
+	if err := os.MkdirAll(filepath.Dir(path), 0755); err != nil {
+		return nil, err
+	}
+	f := &File{path: path}
+	if err := f.open(); err != nil {
+		return nil, err
+	}
+	return f, nil
+}
+
+func (f *File) open() error {
+	file, err := os.OpenFile(f.path, os.O_CREATE|os.O_WRONLY|os.O_APPEND, 0644)
+	if err != nil {
+		return err
+	}
+	info, err := file.Stat()
+	if err != nil {
+		_ = file.Close()
+		return err
+	}
+	f.file, f.size = file, info.Size()
+	return nil
+}
+
+// Write appends p, rotating the file first when p would take it past maxSize
+func (f *File) Write(p []byte) (int, error) {
+	f.mu.Lock()
+	defer f.mu.Unlock()
+	if f.size > 0 && f.size+int64(len(p)) > maxSize {
+		if err := f.rotate(); err != nil {
+			return 0, err
+		}
+	}
+	n, err := f.file.Write(p)
+	f.size += int64(n)
+	return n, err
+}
+
+// rotate shifts gitagrip.log.N to .N+1, dropping the oldest, and starts a new file
+func (f *File) rotate() error {
+	if err := f.file.Close(); err != nil {
+		return err
+	}
+	for i := backups - 1; i >= 1; i-- {
+		_ = os.Rename(f.path+"."+strconv.Itoa(i), f.path+"."+strconv.Itoa(i+1))
+	}
+	if err := os.Rename(f.path, f.path+".1"); err != nil {
+		return err
+	}
+	return f.open()
+}
+
+// Close closes the file
+func (f *File) Close() error {
+	f.mu.Lock()
+	defer f.mu.Unlock()
+	return f.file.Close()
+}
+
+// Tail returns the last n lines of the log file at path
+func Tail(path string, n int) ([]string, error) {
+	file, err := os.Open(path)
+	if err != nil {
+		return nil, err
+	}
+	defer func() {
+		_ = file.Close()
+	}()
+
+	// Lines are short; the end of the file holds more than enough of them
+	info, err := file.Stat()
+	if err != nil {
+		return nil, err
+	}
+	start := max(0, info.Size()-int64(n)*1024)
+	if _, err := file.Seek(start, io.SeekStart); err != nil {
+		return nil, err
+	}
+	data, err := io.ReadAll(file)
+	if err != nil {
+		return nil, err
+	}
+	if start > 0 {
+		// Skip the line cut in half
+		if i := bytes.IndexByte(data, '\n'); i >= 0 {
+			data = data[i+1:]
+		}
+	}
+
+	text := strings.TrimRight(string(data), "\n")
+	if text == "" {
+		return nil, nil
+	}
+	lines := strings.Split(text, "\n")
+	if len(lines) > n {
+		lines = lines[len(lines)-n:]
+	}
+	return lines, nil
+}
@@ -0,0 +1,168 @@
+package bench
+
+import (
+	"context"
+	"fmt"
+	"io"
+	"path/filepath"
+	"sync"
+	"text/tabwriter"
+	"time"
+
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/charmbracelet/colorprofile"
+
+	"github.com/darksworm/gitagrip/internal/config"
+	"github.com/darksworm/gitagrip/internal/discovery"
+	"github.com/darksworm/gitagrip/internal/domain"
+	"github.com/darksworm/gitagrip/internal/eventbus"
+	"github.com/darksworm/gitagrip/internal/git"
+	"github.com/darksworm/gitagrip/internal/ui"
+	"github.com/darksworm/gitagrip/internal/ui/termcaps"
+)
+
+// Frame size the list is drawn at
+const (
+	frameWidth  = 120
+	frameHeight = 40
+)
+
+// Budget is the most each stage may take; zero leaves a stage unchecked
+type Budget struct {
+	Scan   time.Duration
+	Status time.Duration
+	Render time.Duration // per frame
+}
+
+// Result holds the time each stage took
+type Result struct {
+	Repos  int           // repositories the scan found
+	Dirty  int           // of which dirty
+	Scan   time.Duration // walking the tree for repositories
+	Status time.Duration // computing the status of all of them
+	Render time.Duration // applying a status update and drawing the list, per frame
+	Frames int
+}
+
+// Run measures a tree: the scan of dir, the status of every repository found, computed
+// at once as a refresh does, and frames drawn with those statuses
+func Run(ctx context.Context, dir string, cfg *config.Config, frames int) (Result, error) {
+	var result Result
+
+	start := time.Now()
+	repos, err := discovery.FindRepositories(ctx, dir)
+	if err != nil {
+		return result, err
+	}
+	result.Scan = time.Since(start)
+	result.Repos = len(repos)
+
+	start = time.Now()
+	statuses := Statuses(ctx, cfg, repos)
+	result.Status = time.Since(start)
+	for _, status := range statuses {
+		if status.IsDirty || status.HasUntracked {
+			result.Dirty++
+		}
+	}
+
+	result.Render = Render(cfg, repos, statuses, frames)
+	result.Frames = frames
+	return result, ctx.Err()
+}
+
+// Statuses computes the status of every repository the way a refresh does: all at once,
+// with the git service throttling the git processes
+func Statuses(ctx context.Context, cfg *config.Config, repos []string) []domain.RepoStatus {
+	gs := git.NewGitServiceWithConfig(discardBus{}, cfg)
+	statuses := make([]domain.RepoStatus, len(repos))
+	var wg sync.WaitGroup
+	for i, repo := range repos {
+		wg.Add(1)
+		go func() {
+			defer wg.Done()
+			statuses[i], _ = gs.RefreshRepo(ctx, repo)
+		}()
+	}
+	wg.Wait()
+	return statuses
+}
+
+// Render fills the UI with the repositories and their statuses, then draws frames, each
+// after one more status update as they stream in while gitagrip runs. It returns the
+// average time per frame.
+func Render(cfg *config.Config, repos []string, statuses []domain.RepoStatus, frames int) time.Duration {
+	m := renderModel(cfg, repos, statuses)
+	if len(repos) == 0 || frames <= 0 {
+		return 0
+	}
+	start := time.Now()
+	drawFrames(m, repos, statuses, frames)
+	return time.Since(start) / time.Duration(frames)
+}
+
+// renderModel returns the UI listing the repositories with their statuses, drawn once
+func renderModel(cfg *config.Config, repos []string, statuses []domain.RepoStatus) *ui.Model {
+	m := ui.NewModel(discardBus{}, cfg, termcaps.Capabilities{Color: colorprofile.TrueColor, Unicode: true})
+	m.Update(tea.WindowSizeMsg{Width: frameWidth, Height: frameHeight})
+	for i, repo := range repos {
+		name := filepath.Base(repo)
+		m.Update(ui.EventMsg{Event: eventbus.RepoDiscoveredEvent{Repo: domain.Repository{Path: repo, Name: name, DisplayName: name}}})
+		m.Update(ui.EventMsg{Event: eventbus.StatusUpdatedEvent{RepoPath: repo, Status: statuses[i]}})
+	}
+	_ = m.View()
+	return m
+}
+
+// drawFrames applies the status of the next repository, round-robin, and draws the list
+func drawFrames(m *ui.Model, repos []string, statuses []domain.RepoStatus, frames int) {
+	for i := 0; i < frames; i++ {
+		repo := i % len(repos)
+		m.Update(ui.EventMsg{Event: eventbus.StatusUpdatedEvent{RepoPath: repos[repo], Status: statuses[repo]}})
+		_ = m.View()
+	}
+}
+
+// Check returns the stages over budget, described, in stage order
+func (r Result) Check(budget Budget) []string {
+	var over []string
+	check := func(stage string, took, limit time.Duration) {
+		if limit > 0 && took > limit {
+			over = append(over, fmt.Sprintf("%s took %s, over the %s budget", stage, round(took), limit))
+		}
+	}
+	check("scan", r.Scan, budget.Scan)
+	check("status", r.Status, budget.Status)
+	check("render", r.Render, budget.Render)
+	return over
+}
+
+// Write prints the result, one stage per line
+func (r Result) Write(w io.Writer) error {
+	tw := tabwriter.NewWriter(w, 0, 4, 2, ' ', 0)
+	fmt.Fprintf(tw, "scan\t%s\t%d repos found\n", round(r.Scan), r.Repos)
+	perRepo := time.Duration(0)
+	if r.Repos > 0 {
+		perRepo = r.Status / time.Duration(r.Repos)
+	}
+	fmt.Fprintf(tw, "status\t%s\t%d dirty, %s per repo\n", round(r.Status), r.Dirty, round(perRepo))
+	fmt.Fprintf(tw, "render\t%s\tper frame, %d frames at %dx%d\n", round(r.Render), r.Frames, frameWidth, frameHeight)
+	return tw.Flush()
+}
+
+// round keeps durations readable: microseconds below a millisecond, else milliseconds
+func round(d time.Duration) time.Duration {
+	if d < time.Millisecond {
+		return d.Round(time.Microsecond)
+	}
+	return d.Round(time.Millisecond)
+}
+
+// discardBus drops what the services and the UI publish; the bench only times them
+type discardBus struct{}
+
+func (discardBus) Publish(eventbus.DomainEvent) {}
+
+func (discardBus) Subscribe(eventbus.EventType, eventbus.EventHandler) func() {
+	return func() {}
+}
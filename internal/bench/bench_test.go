@@ -0,0 +1,87 @@
+package bench
+
+import (
+	"context"
+	"fmt"
+	"testing"
+
+	"github.com/darksworm/gitagrip/internal/config"
+	"github.com/darksworm/gitagrip/internal/discovery"
+)
+
+// The benchmarks run on generated trees; the fixture is built once per size, outside the
+// timed loop. Run them with: go test -run='^$' -bench=. ./internal/bench
+
+var benchSizes = []int{100, 1000}
+
+func fixture(b *testing.B, repos int) (string, []string) {
+	b.Helper()
+	dir := b.TempDir()
+	paths, err := Fixture(dir, FixtureOptions{Repos: repos, Dirty: 0.2})
+	if err != nil {
+		b.Fatal(err)
+	}
+	return dir, paths
+}
+
+func benchConfig(dir string) *config.Config {
+	cfg := config.DefaultConfig()
+	cfg.BaseDir = dir
+	return cfg
+}
+
+func BenchmarkScan(b *testing.B) {
+	for _, size := range benchSizes {
+		b.Run(fmt.Sprintf("repos=%d", size), func(b *testing.B) {
+			dir, _ := fixture(b, size)
+			b.ResetTimer()
+			for i := 0; i < b.N; i++ {
+				repos, err := discovery.FindRepositories(context.Background(), dir)
+				if err != nil || len(repos) != size {
+					b.Fatalf("found %d repos: %v", len(repos), err)
+				}
+			}
+		})
+	}
+}
+
+func BenchmarkStatus(b *testing.B) {
+	for _, size := range benchSizes {
+		b.Run(fmt.Sprintf("repos=%d", size), func(b *testing.B) {
+			dir, repos := fixture(b, size)
+			cfg := benchConfig(dir)
+			b.ResetTimer()
+			for i := 0; i < b.N; i++ {
+				Statuses(context.Background(), cfg, repos)
+			}
+		})
+	}
+}
+
+func BenchmarkRender(b *testing.B) {
+	for _, size := range benchSizes {
+		b.Run(fmt.Sprintf("repos=%d", size), func(b *testing.B) {
+			dir, repos := fixture(b, size)
+			cfg := benchConfig(dir)
+			statuses := Statuses(context.Background(), cfg, repos)
+			m := renderModel(cfg, repos, statuses)
+			b.ResetTimer()
+			drawFrames(m, repos, statuses, b.N)
+		})
+	}
+}
+
+func TestFixtureMarksTheRequestedShareDirty(t *testing.T) {
+	dir := t.TempDir()
+	repos, err := Fixture(dir, FixtureOptions{Repos: 10, Dirty: 0.3})
+	if err != nil {
+		t.Fatal(err)
+	}
+	result, err := Run(context.Background(), dir, benchConfig(dir), 1)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if result.Repos != len(repos) || result.Dirty != 3 {
+		t.Fatalf("found %d repos, %d dirty; want %d, 3", result.Repos, result.Dirty, len(repos))
+	}
+}
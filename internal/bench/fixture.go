@@ -0,0 +1,77 @@
+// Package bench measures how long gitagrip takes on a large tree of repositories: the
+// scan that finds them, computing their status and drawing the list. It also generates
+// such trees, so that the numbers are comparable from one run (and change) to the next.
+package bench
+
+import (
+	"fmt"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"strings"
+)
+
+// reposPerDir is how many repositories the fixture puts in each of its directories, so
+// that the tree has groups as real workspaces do
+const reposPerDir = 25
+
+// FixtureOptions shapes a generated tree
+type FixtureOptions struct {
+	Repos int     // repositories to create
+	Dirty float64 // fraction of them with uncommitted and untracked changes, 0 to 1
+}
+
+// Fixture creates opts.Repos repositories below dir, in directories of reposPerDir
+// (dir/group-00/repo-0000, ...), each with one commit. Every so many, as opts.Dirty
+// asks, gets a modified file and an untracked one. It returns the repositories' paths.
+func Fixture(dir string, opts FixtureOptions) ([]string, error) {
+	dirty := int(float64(opts.Repos)*opts.Dirty + 0.5)
+	repos := make([]string, 0, opts.Repos)
+	for i := 0; i < opts.Repos; i++ {
+		repo := filepath.Join(dir, fmt.Sprintf("group-%02d", i/reposPerDir), fmt.Sprintf("repo-%04d", i))
+		// Spread the dirty ones over the tree rather than filling the first groups
+		isDirty := dirty > 0 && i*dirty/opts.Repos != (i+1)*dirty/opts.Repos
+		if err := createRepo(repo, isDirty); err != nil {
+			return nil, fmt.Errorf("creating %s: %w", repo, err)
+		}
+		repos = append(repos, repo)
+	}
+	return repos, nil
+}
+
+// createRepo initializes a repository with a committed README, then changes it and adds
+// an untracked file when dirty
+func createRepo(repo string, dirty bool) error {
+	if err := os.MkdirAll(repo, 0755); err != nil {
+		return err
+	}
+	readme := filepath.Join(repo, "README.md")
+	if err := os.WriteFile(readme, []byte("# "+filepath.Base(repo)+"\n"), 0644); err != nil {
+		return err
+	}
+	if err := git(repo, "init", "-q", "-b", "main"); err != nil {
+		return err
+	}
+	if err := git(repo, "add", "README.md"); err != nil {
+		return err
+	}
+	if err := git(repo, "-c", "user.name=bench", "-c", "user.email=bench@example.com", "commit", "-q", "-m", "Initial commit"); err != nil {
+		return err
+	}
+	if !dirty {
+		return nil
+	}
+	if err := os.WriteFile(readme, []byte("# "+filepath.Base(repo)+"\n\nWork in progress\n"), 0644); err != nil {
+		return err
+	}
+	return os.WriteFile(filepath.Join(repo, "notes.txt"), []byte("todo\n"), 0644)
+}
+
+func git(dir string, args ...string) error {
+	cmd := exec.Command("git", args...)
+	cmd.Dir = dir
+	if out, err := cmd.CombinedOutput(); err != nil {
+		return fmt.Errorf("git %s: %w: %s", strings.Join(args, " "), err, out)
+	}
+	return nil
+}
@@ -0,0 +1,265 @@
+package bundle
+
+import (
+	"archive/tar"
+	"compress/gzip"
+	"context"
+	"encoding/json"
+	"fmt"
+	"io"
+	"path/filepath"
+	"sort"
+	"strings"
+	"time"
+
+	"github.com/pelletier/go-toml/v2"
+
+	"github.com/darksworm/gitagrip/internal/config"
+	"github.com/darksworm/gitagrip/internal/discovery"
+	"github.com/darksworm/gitagrip/internal/git"
+)
+
+// FormatVersion is the bundle layout version written into the manifest
+const FormatVersion = 1
+
+// Archive entry names
+const (
+	configEntry   = "gitagrip.toml"
+	manifestEntry = "manifest.json"
+)
+
+// Manifest describes the repositories of an exported workspace
+type Manifest struct {
+	Version    int            `json:"version"`
+	ExportedAt time.Time      `json:"exported_at"`
+	BaseDir    string         `json:"base_dir"` // base directory on the exporting machine
+	Repos      []ManifestRepo `json:"repos"`
+}
+
+// ManifestRepo records where a repository lives and where it can be cloned from
+type ManifestRepo struct {
+	Path   string `json:"path"`             // relative to base_dir
+	Remote string `json:"remote,omitempty"` // origin URL ("" if none)
+	Branch string `json:"branch,omitempty"` // checked out branch at export time
+}
+
+// Export scans the workspace of cfg and writes its config and repository manifest
+// as a gzipped tar archive to w
+func Export(ctx context.Context, cfg *config.Config, w io.Writer) (*Manifest, error) {
+	manifest, err := buildManifest(ctx, cfg)
+	if err != nil {
+		return nil, err
+	}
+
+	configData, err := toml.Marshal(cfg)
+	if err != nil {
+		return nil, fmt.Errorf("failed to marshal config: %w", err)
+	}
+	manifestData, err := json.MarshalIndent(manifest, "", "  ")
+	if err != nil {
+		return nil, fmt.Errorf("failed to marshal manifest: %w", err)
+	}
+
+	gz := gzip.NewWriter(w)
+	tw := tar.NewWriter(gz)
+	for _, entry := range []struct {
+		name string
+		data []byte
+	}{
+		{configEntry, configData},
+		{manifestEntry, manifestData},
+	} {
+		header := &tar.Header{
+			Name:    entry.name,
+			Mode:    0644,
+			Size:    int64(len(entry.data)),
+			ModTime: manifest.ExportedAt,
+		}
+		if err := tw.WriteHeader(header); err != nil {
+			return nil, fmt.Errorf("failed to write %s: %w", entry.name, err)
+		}
+		if _, err := tw.Write(entry.data); err != nil {
+			return nil, fmt.Errorf("failed to write %s: %w", entry.name, err)
+		}
+	}
+	if err := tw.Close(); err != nil {
+		return nil, fmt.Errorf("failed to finalize bundle: %w", err)
+	}
+	if err := gz.Close(); err != nil {
+		return nil, fmt.Errorf("failed to finalize bundle: %w", err)
+	}
+
+	return manifest, nil
+}
+
+// Read parses a bundle produced by Export
+func Read(r io.Reader) (*config.Config, *Manifest, error) {
+	gz, err := gzip.NewReader(r)
+	if err != nil {
+		return nil, nil, fmt.Errorf("not a gitagrip bundle: %w", err)
+	}
+	defer func() {
+		_ = gz.Close()
+	}()
+
+	var cfg *config.Config
+	var manifest *Manifest
+	tr := tar.NewReader(gz)
+	for {
+		header, err := tr.Next()
+		if err == io.EOF {
+			break
+		}
+		if err != nil {
+			return nil, nil, fmt.Errorf("failed to read bundle: %w", err)
+		}
+
+		data, err := io.ReadAll(tr)
+		if err != nil {
+			return nil, nil, fmt.Errorf("failed to read %s: %w", header.Name, err)
+		}
+
+		switch header.Name {
+		case configEntry:
+			cfg = &config.Config{}
+			if err := toml.Unmarshal(data, cfg); err != nil {
+				return nil, nil, fmt.Errorf("failed to parse bundled config: %w", err)
+			}
+		case manifestEntry:
+			manifest = &Manifest{}
+			if err := json.Unmarshal(data, manifest); err != nil {
+				return nil, nil, fmt.Errorf("failed to parse bundled manifest: %w", err)
+			}
+		}
+	}
+
+	if cfg == nil || manifest == nil {
+		return nil, nil, fmt.Errorf("bundle is missing %s or %s", configEntry, manifestEntry)
+	}
+	if manifest.Version > FormatVersion {
+		return nil, nil, fmt.Errorf("bundle format version %d is newer than supported version %d", manifest.Version, FormatVersion)
+	}
+	if cfg.Groups == nil {
+		cfg.Groups = make(map[string][]string)
+	}
+
+	return cfg, manifest, nil
+}
+
+// Rebase rewrites all repository paths in cfg from the exporting machine's base
+// directory to targetDir: groups, [repos], forgotten, upstream overrides, watches
+// and the archive directory
+func Rebase(cfg *config.Config, manifest *Manifest, targetDir string) {
+	oldBase := manifest.BaseDir
+	if oldBase == "" {
+		oldBase = cfg.BaseDir
+	}
+
+	move := func(path string) string {
+		rel, ok := relativeTo(oldBase, path)
+		if !ok {
+			// Outside the workspace; keep as-is
+			return path
+		}
+		return filepath.Join(targetDir, rel)
+	}
+
+	for _, paths := range cfg.Groups {
+		for i, path := range paths {
+			paths[i] = move(path)
+		}
+	}
+
+	if len(cfg.Repos) > 0 {
+		repos := make(map[string]config.RepoSettings, len(cfg.Repos))
+		for path, settings := range cfg.Repos {
+			repos[move(path)] = settings
+		}
+		cfg.Repos = repos
+	}
+
+	for i, path := range cfg.Forgotten {
+		cfg.Forgotten[i] = move(path)
+	}
+
+	if len(cfg.Upstream.Overrides) > 0 {
+		overrides := make(map[string]string, len(cfg.Upstream.Overrides))
+		for path, ref := range cfg.Upstream.Overrides {
+			overrides[move(path)] = ref
+		}
+		cfg.Upstream.Overrides = overrides
+	}
+
+	for i, watch := range cfg.Watches {
+		if watch.Repo != "" {
+			cfg.Watches[i].Repo = move(watch.Repo)
+		}
+	}
+
+	if cfg.Archive.Dir != "" {
+		cfg.Archive.Dir = move(cfg.Archive.Dir)
+	}
+
+	cfg.BaseDir = targetDir
+}
+
+// RecordRemotes stores the remote of every manifest repository as its url in cfg's [repos],
+// so `gitagrip clone-missing` can clone it. It expects cfg to be rebased already; urls
+// set in the config are kept. A manifest path that leads outside base_dir is an error.
+func RecordRemotes(cfg *config.Config, manifest *Manifest) error {
+	for _, repo := range manifest.Repos {
+		if repo.Remote == "" {
+			continue
+		}
+		path := filepath.Join(cfg.BaseDir, filepath.FromSlash(repo.Path))
+		if _, ok := relativeTo(cfg.BaseDir, path); !ok {
+			return fmt.Errorf("bundle lists %s outside %s", repo.Path, cfg.BaseDir)
+		}
+		if cfg.Repos == nil {
+			cfg.Repos = make(map[string]config.RepoSettings)
+		}
+		settings := cfg.Repos[path]
+		if settings.URL == "" {
+			settings.URL = repo.Remote
+			cfg.Repos[path] = settings
+		}
+	}
+	return nil
+}
+
+// relativeTo returns path relative to base, or false when path is not inside base
+func relativeTo(base, path string) (string, bool) {
+	rel, err := filepath.Rel(base, path)
+	if err != nil || rel == ".." || strings.HasPrefix(rel, ".."+string(filepath.Separator)) {
+		return "", false
+	}
+	return rel, true
+}
+
+// buildManifest scans the base directory and records each repository's remote and branch
+func buildManifest(ctx context.Context, cfg *config.Config) (*Manifest, error) {
+	paths, err := discovery.FindRepositories(ctx, cfg.BaseDir)
+	if err != nil {
+		return nil, fmt.Errorf("failed to scan %s: %w", cfg.BaseDir, err)
+	}
+	sort.Strings(paths)
+
+	manifest := &Manifest{
+		Version:    FormatVersion,
+		ExportedAt: time.Now().UTC(),
+		BaseDir:    cfg.BaseDir,
+	}
+	for _, path := range paths {
+		rel, err := filepath.Rel(cfg.BaseDir, path)
+		if err != nil {
+			continue
+		}
+		manifest.Repos = append(manifest.Repos, ManifestRepo{
+			Path:   filepath.ToSlash(rel),
+			Remote: git.Output(ctx, path, "config", "--get", "remote.origin.url"),
+			Branch: git.Output(ctx, path, "rev-parse", "--abbrev-ref", "HEAD"),
+		})
+	}
+
+	return manifest, nil
+}
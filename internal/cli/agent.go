@@ -0,0 +1,27 @@
+package cli
+
+import (
+	"flag"
+	"log"
+	"os"
+
+	"github.com/darksworm/gitagrip/internal/agent"
+)
+
+// runAgent implements `gitagrip agent [-d dir]`: scan and query the workspace for a TUI
+// on another machine (gitagrip -remote), speaking the agent protocol on stdin/stdout
+func runAgent(args []string) error {
+	fs := flag.NewFlagSet("agent", flag.ContinueOnError)
+	dir := fs.String("d", "", "Workspace directory (defaults to the current directory)")
+	if err := fs.Parse(args); err != nil {
+		return err
+	}
+
+	absDir, err := resolveDir(*dir)
+	if err != nil {
+		return err
+	}
+	// stdout carries the protocol; everything else goes to stderr, which ssh relays
+	log.SetOutput(os.Stderr)
+	return agent.Serve(absDir, os.Stdin, os.Stdout)
+}
@@ -0,0 +1,82 @@
+package cli
+
+import (
+	"context"
+	"flag"
+	"fmt"
+	"os"
+	"strings"
+	"time"
+
+	"github.com/darksworm/gitagrip/internal/bench"
+	"github.com/darksworm/gitagrip/internal/config"
+)
+
+// benchTimeout keeps a stuck git from hanging the bench forever
+const benchTimeout = 10 * time.Minute
+
+// runBench implements `gitagrip bench [-repos n] [-dirty fraction] [-d dir] [-max-scan d]
+// [-max-status d] [-max-render d]`. Without -d it generates a tree of repositories to
+// measure. It fails when a stage goes over its budget.
+func runBench(args []string) error {
+	fs := flag.NewFlagSet("bench", flag.ContinueOnError)
+	repos := fs.Int("repos", 500, "Repositories to generate")
+	dirty := fs.Float64("dirty", 0.2, "Fraction of the generated repositories with changes, 0 to 1")
+	dir := fs.String("d", "", "Measure this workspace instead of a generated tree")
+	keep := fs.Bool("keep", false, "Keep the generated tree (its path is printed)")
+	frames := fs.Int("frames", 200, "Frames to draw for the render time")
+	maxScan := fs.Duration("max-scan", 0, "Fail when the scan takes longer (e.g. 500ms)")
+	maxStatus := fs.Duration("max-status", 0, "Fail when computing every status takes longer")
+	maxRender := fs.Duration("max-render", 0, "Fail when a frame takes longer")
+	if err := fs.Parse(args); err != nil {
+		return err
+	}
+	if *repos < 1 || *dirty < 0 || *dirty > 1 {
+		return fmt.Errorf("-repos must be at least 1 and -dirty between 0 and 1")
+	}
+
+	root := *dir
+	if root != "" {
+		var err error
+		if root, err = resolveDir(root); err != nil {
+			return err
+		}
+	} else {
+		tmp, err := os.MkdirTemp("", "gitagrip-bench-")
+		if err != nil {
+			return err
+		}
+		if *keep {
+			fmt.Printf("fixture kept in %s\n", tmp)
+		} else {
+			defer func() {
+				_ = os.RemoveAll(tmp)
+			}()
+		}
+		fmt.Printf("generating %d repositories (%.0f%% dirty)...\n", *repos, *dirty*100)
+		if _, err := bench.Fixture(tmp, bench.FixtureOptions{Repos: *repos, Dirty: *dirty}); err != nil {
+			return err
+		}
+		root = tmp
+	}
+
+	cfg, err := config.LoadWorkspace(root)
+	if err != nil {
+		return err
+	}
+	cfg.BaseDir = root
+	ctx, cancel := context.WithTimeout(context.Background(), benchTimeout)
+	defer cancel()
+	result, err := bench.Run(ctx, root, cfg, *frames)
+	if err != nil {
+		return err
+	}
+	if err := result.Write(os.Stdout); err != nil {
+		return err
+	}
+
+	if over := result.Check(bench.Budget{Scan: *maxScan, Status: *maxStatus, Render: *maxRender}); len(over) > 0 {
+		return fmt.Errorf("over budget: %s", strings.Join(over, "; "))
+	}
+	return nil
+}
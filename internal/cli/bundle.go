@@ -0,0 +1,125 @@
+package cli
+
+import (
+	"context"
+	"flag"
+	"fmt"
+	"os"
+	"path/filepath"
+
+	"github.com/darksworm/gitagrip/internal/bundle"
+	"github.com/darksworm/gitagrip/internal/config"
+)
+
+// runExportBundle implements `gitagrip export-bundle [-d dir] [-o file]`
+func runExportBundle(args []string) error {
+	fs := flag.NewFlagSet("export-bundle", flag.ContinueOnError)
+	dir := fs.String("d", "", "Workspace directory (defaults to the current directory)")
+	output := fs.String("o", "gitagrip-bundle.tar.gz", "Output file")
+	if err := fs.Parse(args); err != nil {
+		return err
+	}
+
+	absDir, err := resolveDir(*dir)
+	if err != nil {
+		return err
+	}
+
+	configPath := filepath.Join(absDir, ".gitagrip.toml")
+	cfg, err := config.NewConfigService().LoadFromPath(configPath)
+	if err != nil {
+		return err
+	}
+	if cfg.BaseDir == "" {
+		cfg.BaseDir = absDir
+	}
+
+	file, err := os.Create(*output)
+	if err != nil {
+		return fmt.Errorf("failed to create %s: %w", *output, err)
+	}
+	manifest, err := bundle.Export(context.Background(), cfg, file)
+	if closeErr := file.Close(); err == nil && closeErr != nil {
+		err = closeErr
+	}
+	if err != nil {
+		_ = os.Remove(*output)
+		return err
+	}
+
+	fmt.Printf("Exported %d groups and %d repositories to %s\n", len(cfg.Groups), len(manifest.Repos), *output)
+	return nil
+}
+
+// runImportBundle implements `gitagrip import-bundle [-d dir] [-force] [-clone] FILE`
+func runImportBundle(args []string) error {
+	fs := flag.NewFlagSet("import-bundle", flag.ContinueOnError)
+	dir := fs.String("d", "", "Target workspace directory (defaults to the current directory)")
+	force := fs.Bool("force", false, "Overwrite an existing .gitagrip.toml")
+	clone := fs.Bool("clone", false, "Record each repository's remote in [repos] and clone the missing ones")
+	if err := fs.Parse(args); err != nil {
+		return err
+	}
+	if fs.NArg() != 1 {
+		return fmt.Errorf("usage: gitagrip import-bundle [-d dir] [-force] [-clone] FILE")
+	}
+
+	absDir, err := resolveDir(*dir)
+	if err != nil {
+		return err
+	}
+
+	configPath := filepath.Join(absDir, ".gitagrip.toml")
+	if _, err := os.Stat(configPath); err == nil && !*force {
+		return fmt.Errorf("%s already exists (use -force to overwrite)", configPath)
+	}
+
+	file, err := os.Open(fs.Arg(0))
+	if err != nil {
+		return err
+	}
+	defer func() {
+		_ = file.Close()
+	}()
+
+	cfg, manifest, err := bundle.Read(file)
+	if err != nil {
+		return err
+	}
+	bundle.Rebase(cfg, manifest, absDir)
+	if *clone {
+		if err := bundle.RecordRemotes(cfg, manifest); err != nil {
+			return err
+		}
+	}
+
+	if err := config.NewConfigService().SaveToPath(cfg, configPath); err != nil {
+		return err
+	}
+	fmt.Printf("Imported %d groups into %s\n", len(cfg.Groups), configPath)
+	if *clone {
+		fmt.Println()
+		return cloneMissing(cfg)
+	}
+
+	// Report repositories that still need to be cloned
+	var missing []bundle.ManifestRepo
+	for _, repo := range manifest.Repos {
+		if _, err := os.Stat(filepath.Join(absDir, filepath.FromSlash(repo.Path))); os.IsNotExist(err) {
+			missing = append(missing, repo)
+		}
+	}
+	if len(missing) > 0 {
+		fmt.Printf("\n%d repositories are not present locally:\n", len(missing))
+		for _, repo := range missing {
+			if repo.Remote != "" {
+				fmt.Printf("  git clone %s %s\n", repo.Remote, filepath.Join(absDir, filepath.FromSlash(repo.Path)))
+			} else {
+				fmt.Printf("  %s (no remote recorded)\n", repo.Path)
+			}
+		}
+		fmt.Printf("\nImport again with -force -clone to clone them all.\n")
+	}
+
+	return nil
+}
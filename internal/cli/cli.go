@@ -0,0 +1,105 @@
+package cli
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+)
+
+// command is a non-interactive subcommand
+type command struct {
+	summary string
+	run     func(args []string) error
+}
+
+// commands lists the available subcommands by name
+var commands = map[string]command{
+	"export-bundle": {
+		summary: "Package config, groups and repository manifest into an archive",
+		run:     runExportBundle,
+	},
+	"import-bundle": {
+		summary: "Restore a workspace from an archive created by export-bundle",
+		run:     runImportBundle,
+	},
+	"clone-missing": {
+		summary: "Clone the repositories configured with a url in [repos] that are not on disk",
+		run:     runCloneMissing,
+	},
+	"clone-org": {
+		summary: "List an organization's repositories on GitHub or GitLab, add them to a group and clone them",
+		run:     runCloneOrg,
+	},
+	"fetch": {
+		summary: "Fetch the repositories below the workspace, optionally one remote, tag mode or depth",
+		run:     runFetch,
+	},
+	"group": {
+		summary: "Create, move, delete and list groups without opening the TUI",
+		run:     runGroup,
+	},
+	"config": {
+		summary: "Compare configs across machines and print the config JSON Schema",
+		run:     runConfig,
+	},
+	"compliance": {
+		summary: "Check repos for required files and branch protection and export the report",
+		run:     runCompliance,
+	},
+	"health": {
+		summary: "Check repos for leftover lock files, large .git dirs, shallow clones and (-fsck) corruption",
+		run:     runHealth,
+	},
+	"doctor": {
+		summary: "Check the terminal, git, config, cache directory and ssh-agent (doctor --self)",
+		run:     runDoctor,
+	},
+	"watch": {
+		summary: "Keep statuses current without the TUI and print events (-json for one JSON object per line)",
+		run:     runWatch,
+	},
+	"send": {
+		summary: "Drive a running gitagrip: refresh, fetch or jump, for a group:<name> or repo:<name>",
+		run:     runSend,
+	},
+	"agent": {
+		summary: "Run headless for a TUI on another machine (gitagrip -remote)",
+		run:     runAgent,
+	},
+	// Left out of the README's command list: it is for working on gitagrip itself
+	"bench": {
+		summary: "Time scan, status and render on a generated tree (or -d dir) and check them against budgets",
+		run:     runBench,
+	},
+}
+
+// Run executes the subcommand named by args[0]. It reports handled=false when
+// args don't start with a known subcommand, in which case the TUI should start.
+func Run(args []string) (exitCode int, handled bool) {
+	if len(args) == 0 {
+		return 0, false
+	}
+
+	cmd, ok := commands[args[0]]
+	if !ok {
+		return 0, false
+	}
+
+	if err := cmd.run(args[1:]); err != nil {
+		fmt.Fprintf(os.Stderr, "gitagrip %s: %v\n", args[0], err)
+		return 1, true
+	}
+	return 0, true
+}
+
+// resolveDir returns the absolute workspace directory, defaulting to the current directory
+func resolveDir(dir string) (string, error) {
+	if dir == "" {
+		var err error
+		dir, err = os.Getwd()
+		if err != nil {
+			return "", fmt.Errorf("error getting current directory: %w", err)
+		}
+	}
+	return filepath.Abs(dir)
+}
@@ -0,0 +1,101 @@
+package cli
+
+import (
+	"flag"
+	"fmt"
+	"path/filepath"
+	"slices"
+	"strings"
+	"sync"
+
+	"github.com/darksworm/gitagrip/engine"
+	"github.com/darksworm/gitagrip/internal/config"
+	"github.com/darksworm/gitagrip/internal/git"
+)
+
+// runCloneMissing implements `gitagrip clone-missing [-d dir] [-n]`: it clones the
+// repositories configured with a url in [repos] that are not on disk yet
+func runCloneMissing(args []string) error {
+	fs := flag.NewFlagSet("clone-missing", flag.ContinueOnError)
+	dir := fs.String("d", "", "Workspace directory (defaults to the current directory)")
+	dryRun := fs.Bool("n", false, "Only list the repositories that would be cloned")
+	if err := fs.Parse(args); err != nil {
+		return err
+	}
+
+	absDir, err := resolveDir(*dir)
+	if err != nil {
+		return err
+	}
+	cfg, err := config.NewConfigService().LoadFromPath(filepath.Join(absDir, ".gitagrip.toml"))
+	if err != nil {
+		return err
+	}
+	if cfg.BaseDir == "" {
+		cfg.BaseDir = absDir
+	}
+
+	if *dryRun {
+		for _, path := range git.MissingRepos(cfg) {
+			fmt.Printf("%s <- %s\n", path, cfg.Repos[path].URL)
+		}
+		return nil
+	}
+	return cloneMissing(cfg)
+}
+
+// cloneMissing clones the missing repositories of cfg, or the missing ones of only when
+// given, printing each result as it arrives
+func cloneMissing(cfg *config.Config, only ...string) error {
+	missing := git.MissingRepos(cfg)
+	if len(only) > 0 {
+		missing = slices.DeleteFunc(missing, func(path string) bool { return !slices.Contains(only, path) })
+	}
+	if len(missing) == 0 {
+		fmt.Println("Nothing to clone: every repository with a url is present")
+		return nil
+	}
+
+	eng := engine.New(engine.Options{Config: config.WorkspaceOf(cfg)})
+	defer eng.Close()
+
+	// Every clone reports exactly one result; the last one ends the wait
+	var mu sync.Mutex
+	var cloned, failed int
+	done := make(chan struct{})
+	eng.Subscribe(engine.EventCommandExecuted, func(e engine.Event) {
+		event, ok := e.(engine.CommandExecutedEvent)
+		if !ok || event.Command != "clone" {
+			return
+		}
+		mu.Lock()
+		defer mu.Unlock()
+		if event.Success {
+			cloned++
+			fmt.Printf("[%d/%d] cloned %s\n", cloned+failed, len(missing), event.RepoPath)
+		} else {
+			failed++
+			fmt.Printf("[%d/%d] failed %s: %s\n", cloned+failed, len(missing), event.RepoPath, lastLine(event.Output, event.Error))
+		}
+		if cloned+failed == len(missing) {
+			close(done)
+		}
+	})
+	eng.CloneMissing(only...)
+	<-done
+
+	fmt.Printf("\nCloned %d of %d repositories\n", cloned, len(missing))
+	if failed > 0 {
+		return fmt.Errorf("%d of %d clones failed", failed, len(missing))
+	}
+	return nil
+}
+
+// lastLine returns the last non-empty line of output, or fallback when there is none
+func lastLine(output, fallback string) string {
+	lines := strings.Split(strings.TrimSpace(output), "\n")
+	if line := strings.TrimSpace(lines[len(lines)-1]); line != "" {
+		return line
+	}
+	return fallback
+}
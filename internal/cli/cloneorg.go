@@ -0,0 +1,109 @@
+package cli
+
+import (
+	"context"
+	"flag"
+	"fmt"
+	"path/filepath"
+	"strings"
+
+	"github.com/darksworm/gitagrip/internal/forge"
+)
+
+// runCloneOrg implements `gitagrip clone-org [-d dir] -org name [-host github] [pattern...]`:
+// it lists the repositories of an organization, records the ones picked in [repos] with
+// their url and a group named after the organization, and clones them
+func runCloneOrg(args []string) error {
+	fs := flag.NewFlagSet("clone-org", flag.ContinueOnError)
+	host := fs.String("host", "github", "Forge the organization is on: "+strings.Join(forge.Hosts, " or "))
+	org := fs.String("org", "", "Organization (GitHub) or group (GitLab) to clone from")
+	dest := fs.String("dest", "", "Directory to clone into (defaults to <base_dir>/<org>)")
+	group := fs.String("group", "", "Group to put the repositories in (defaults to the organization)")
+	api := fs.String("api", "", "API URL of a self-hosted forge (defaults to clone_org.github_api or gitlab_api)")
+	ssh := fs.Bool("ssh", false, "Clone over ssh rather than https (also clone_org.ssh)")
+	archived := fs.Bool("archived", false, "Include archived repositories")
+	forks := fs.Bool("forks", false, "Include forks")
+	dryRun := fs.Bool("n", false, "Only list the repositories that would be cloned")
+	w, patterns, err := loadWorkspaceConfig(fs, args)
+	if err != nil {
+		return err
+	}
+	for _, pattern := range patterns {
+		if _, err := filepath.Match(pattern, ""); err != nil {
+			return fmt.Errorf("bad pattern %q: %w", pattern, err)
+		}
+	}
+	if *org == "" {
+		return fmt.Errorf("-org is required")
+	}
+
+	cfg := w.cfg
+	if *api == "" {
+		*api = cfg.CloneOrg.APIURL(*host)
+	}
+	client, err := forge.New(*host, *api)
+	if err != nil {
+		return err
+	}
+	repos, err := client.List(context.Background(), *org)
+	if err != nil {
+		return err
+	}
+
+	baseDir := cfg.BaseDir
+	if baseDir == "" {
+		baseDir = filepath.Dir(w.path)
+	}
+	if *dest == "" {
+		*dest = filepath.Join(baseDir, filepath.FromSlash(*org))
+	} else if *dest, err = filepath.Abs(*dest); err != nil {
+		return err
+	}
+	if *group == "" {
+		*group = *org
+	}
+
+	// The picked repositories, pinned to the group so they land in it once cloned
+	var picked []string
+	for _, repo := range repos {
+		if (repo.Archived && !*archived) || (repo.Fork && !*forks) || !matchesAny(repo.Name, patterns) {
+			continue
+		}
+		dir := filepath.Join(*dest, filepath.FromSlash(repo.Name))
+		if *dryRun {
+			fmt.Printf("%s <- %s\n", dir, repo.CloneURL(*ssh || cfg.CloneOrg.SSH))
+			continue
+		}
+		cfg.AddClone(dir, repo.CloneURL(*ssh || cfg.CloneOrg.SSH), *group)
+		picked = append(picked, cfg.CanonicalPath(dir))
+	}
+	if *dryRun {
+		return nil
+	}
+	if len(picked) == 0 {
+		return fmt.Errorf("none of the %d repositories of %s match", len(repos), *org)
+	}
+	cfg.ApplyRepoGroups()
+	if err := w.save(); err != nil {
+		return err
+	}
+	fmt.Printf("Added %d repositories of %s to group %q in %s\n\n", len(picked), *org, *group, w.path)
+
+	if cfg.BaseDir == "" {
+		cfg.BaseDir = baseDir
+	}
+	return cloneMissing(cfg, picked...)
+}
+
+// matchesAny reports whether name matches one of the glob patterns, or there are none
+func matchesAny(name string, patterns []string) bool {
+	if len(patterns) == 0 {
+		return true
+	}
+	for _, pattern := range patterns {
+		if ok, _ := filepath.Match(pattern, name); ok {
+			return true
+		}
+	}
+	return false
+}
@@ -0,0 +1,77 @@
+package cli
+
+import (
+	"context"
+	"flag"
+	"fmt"
+	"io"
+	"os"
+	"path/filepath"
+
+	"github.com/darksworm/gitagrip/internal/compliance"
+	"github.com/darksworm/gitagrip/internal/config"
+)
+
+// runCompliance implements `gitagrip compliance [-d dir] [-format text|json|csv] [-o file] [-protection]`.
+// It fails when any repository misses a check, so it can gate CI jobs.
+func runCompliance(args []string) error {
+	fs := flag.NewFlagSet("compliance", flag.ContinueOnError)
+	dir := fs.String("d", "", "Workspace directory (defaults to the current directory)")
+	format := fs.String("format", compliance.FormatText, "Report format: text, json or csv")
+	output := fs.String("o", "", "Write the report to a file instead of stdout")
+	protection := fs.Bool("protection", false, "Check branch protection even if compliance.branch_protection is off")
+	if err := fs.Parse(args); err != nil {
+		return err
+	}
+
+	switch *format {
+	case compliance.FormatText, compliance.FormatJSON, compliance.FormatCSV:
+	default:
+		return fmt.Errorf("unknown format %q (want text, json or csv)", *format)
+	}
+
+	absDir, err := resolveDir(*dir)
+	if err != nil {
+		return err
+	}
+
+	cfg, err := config.NewConfigService().LoadFromPath(filepath.Join(absDir, ".gitagrip.toml"))
+	if err != nil {
+		return err
+	}
+	if cfg.BaseDir == "" {
+		cfg.BaseDir = absDir
+	}
+
+	var checker compliance.ProtectionChecker
+	if *protection || cfg.Compliance.BranchProtection {
+		token := os.Getenv("GITHUB_TOKEN")
+		if token == "" {
+			token = os.Getenv("GH_TOKEN")
+		}
+		checker = compliance.NewGitHub(cfg.Compliance.APIURL, token)
+	}
+
+	summary, err := compliance.Run(context.Background(), cfg, checker)
+	if err != nil {
+		return err
+	}
+
+	var w io.Writer = os.Stdout
+	if *output != "" {
+		file, err := os.Create(*output)
+		if err != nil {
+			return fmt.Errorf("failed to create %s: %w", *output, err)
+		}
+		defer func() { _ = file.Close() }()
+		w = file
+	}
+	if err := compliance.Write(w, summary, *format); err != nil {
+		return err
+	}
+
+	if summary.Failed > 0 {
+		return fmt.Errorf("%d of %d repositories failed", summary.Failed, summary.Total)
+	}
+	return nil
+}
@@ -0,0 +1,295 @@
+package cli
+
+import (
+	"encoding/json"
+	"flag"
+	"fmt"
+	"os"
+	"path/filepath"
+	"reflect"
+	"regexp"
+	"sort"
+	"strings"
+
+	"github.com/pelletier/go-toml/v2"
+
+	"github.com/darksworm/gitagrip/internal/bundle"
+	"github.com/darksworm/gitagrip/internal/config"
+)
+
+const configUsage = `usage: gitagrip config <command> [-d dir] ...
+
+Commands:
+  diff [-take key]... [-all] OTHER   Compare with another machine's .gitagrip.toml and
+                                     optionally take some of its entries
+  schema [-o file]                   Print the JSON Schema of .gitagrip.toml`
+
+// configSubcommands lists the `gitagrip config` subcommands by name
+var configSubcommands = map[string]func(args []string) error{
+	"diff":   runConfigDiff,
+	"schema": runConfigSchema,
+}
+
+// runConfig implements `gitagrip config <diff|schema> ...`
+func runConfig(args []string) error {
+	if len(args) == 0 {
+		return fmt.Errorf("%s", configUsage)
+	}
+	run, ok := configSubcommands[args[0]]
+	if !ok {
+		return fmt.Errorf("unknown command %q\n%s", args[0], configUsage)
+	}
+	return run(args[1:])
+}
+
+// stringList is a flag that can be given several times
+type stringList []string
+
+func (l *stringList) String() string     { return strings.Join(*l, ",") }
+func (l *stringList) Set(v string) error { *l = append(*l, v); return nil }
+
+// configDiffIgnored are machine-specific keys that are never compared
+var configDiffIgnored = map[string]bool{"version": true, "base_dir": true}
+
+// configEntry is a single setting (or a whole group) that differs between two configs
+type configEntry struct {
+	key   string
+	path  []string
+	local any // nil when only the other config has it
+	other any // nil when only the local config has it
+}
+
+// runConfigDiff implements `gitagrip config diff [-d dir] [-take key]... [-all] OTHER`.
+// Group paths in the other config are rebased onto this workspace before comparing, so
+// the same layout under a different home directory compares equal.
+func runConfigDiff(args []string) error {
+	fs := flag.NewFlagSet("config diff", flag.ContinueOnError)
+	var take stringList
+	fs.Var(&take, "take", "Copy this entry (or every entry under it) from the other config; repeatable")
+	all := fs.Bool("all", false, "Copy every differing entry from the other config")
+	w, rest, err := loadWorkspaceConfig(fs, args)
+	if err != nil {
+		return err
+	}
+	if len(rest) != 1 {
+		return fmt.Errorf("usage: gitagrip config diff [-d dir] [-take key]... [-all] OTHER")
+	}
+
+	other, err := config.NewConfigService().LoadFromPath(rest[0])
+	if err != nil {
+		return err
+	}
+	localBase := w.cfg.BaseDir
+	if localBase == "" {
+		localBase = filepath.Dir(w.path)
+	}
+	bundle.Rebase(other, &bundle.Manifest{BaseDir: other.BaseDir}, localBase)
+
+	localTree, err := configTree(w.cfg)
+	if err != nil {
+		return err
+	}
+	otherTree, err := configTree(other)
+	if err != nil {
+		return err
+	}
+	entries := diffTrees(localTree, otherTree)
+
+	if len(take) == 0 && !*all {
+		printConfigDiff(entries, rest[0])
+		return nil
+	}
+
+	taken := 0
+	for _, e := range entries {
+		if *all || matchesAny(e.key, take) {
+			setTreeValue(localTree, e.path, e.other)
+			taken++
+		}
+	}
+	if taken == 0 {
+		return fmt.Errorf("no differing entries match %s", strings.Join(take, ", "))
+	}
+
+	merged, err := configFromTree(localTree)
+	if err != nil {
+		return err
+	}
+	merged.BaseDir = w.cfg.BaseDir
+	merged.Version = w.cfg.Version
+	w.cfg = merged
+	if err := w.save(); err != nil {
+		return err
+	}
+
+	fmt.Printf("Took %d entries from %s\n", taken, rest[0])
+	return nil
+}
+
+// configTree converts a config into nested TOML tables
+func configTree(cfg *config.Config) (map[string]any, error) {
+	data, err := toml.Marshal(cfg)
+	if err != nil {
+		return nil, err
+	}
+	tree := make(map[string]any)
+	if err := toml.Unmarshal(data, &tree); err != nil {
+		return nil, err
+	}
+	return tree, nil
+}
+
+// configFromTree converts nested TOML tables back into a config
+func configFromTree(tree map[string]any) (*config.Config, error) {
+	data, err := toml.Marshal(tree)
+	if err != nil {
+		return nil, err
+	}
+	var cfg config.Config
+	if err := toml.Unmarshal(data, &cfg); err != nil {
+		return nil, err
+	}
+	if cfg.Groups == nil {
+		cfg.Groups = make(map[string][]string)
+	}
+	return &cfg, nil
+}
+
+// diffTrees lists the leaf values (anything but a table) that differ, sorted by key.
+// Each group is a single entry since its value is the list of repositories.
+func diffTrees(local, other map[string]any) []configEntry {
+	leaves := func(tree map[string]any) map[string]configEntry {
+		out := make(map[string]configEntry)
+		var walk func(node map[string]any, path []string)
+		walk = func(node map[string]any, path []string) {
+			for k, v := range node {
+				p := append(append([]string(nil), path...), k)
+				if len(path) == 0 && configDiffIgnored[k] {
+					continue
+				}
+				if sub, ok := v.(map[string]any); ok {
+					walk(sub, p)
+					continue
+				}
+				out[configKey(p)] = configEntry{key: configKey(p), path: p, local: v}
+			}
+		}
+		walk(tree, nil)
+		return out
+	}
+
+	localLeaves := leaves(local)
+	otherLeaves := leaves(other)
+
+	var entries []configEntry
+	for key, e := range localLeaves {
+		o, ok := otherLeaves[key]
+		if ok {
+			e.other = o.local
+		}
+		if !reflect.DeepEqual(e.local, e.other) {
+			entries = append(entries, e)
+		}
+	}
+	for key, o := range otherLeaves {
+		if _, ok := localLeaves[key]; !ok {
+			entries = append(entries, configEntry{key: key, path: o.path, other: o.local})
+		}
+	}
+	sort.Slice(entries, func(i, j int) bool { return entries[i].key < entries[j].key })
+	return entries
+}
+
+var bareKeyRE = regexp.MustCompile(`^[A-Za-z0-9_-]+$`)
+
+// configKey renders a path as a dotted TOML key, quoting segments that need it
+func configKey(path []string) string {
+	parts := make([]string, len(path))
+	for i, p := range path {
+		if bareKeyRE.MatchString(p) {
+			parts[i] = p
+		} else {
+			parts[i] = fmt.Sprintf("%q", p)
+		}
+	}
+	return strings.Join(parts, ".")
+}
+
+// matchesAny reports whether key equals one of the patterns or lies under one of them
+func matchesAny(key string, patterns []string) bool {
+	for _, p := range patterns {
+		if key == p || strings.HasPrefix(key, p+".") {
+			return true
+		}
+	}
+	return false
+}
+
+// setTreeValue sets (or, for nil, removes) the value at path, creating tables as needed
+func setTreeValue(tree map[string]any, path []string, value any) {
+	node := tree
+	for _, k := range path[:len(path)-1] {
+		sub, ok := node[k].(map[string]any)
+		if !ok {
+			sub = make(map[string]any)
+			node[k] = sub
+		}
+		node = sub
+	}
+	last := path[len(path)-1]
+	if value == nil {
+		delete(node, last)
+	} else {
+		node[last] = value
+	}
+}
+
+// printConfigDiff shows each differing entry with the value on both sides
+func printConfigDiff(entries []configEntry, otherName string) {
+	if len(entries) == 0 {
+		fmt.Printf("No differences with %s\n", otherName)
+		return
+	}
+
+	render := func(v any) string {
+		if v == nil {
+			return "(unset)"
+		}
+		data, err := json.Marshal(v)
+		if err != nil {
+			return fmt.Sprint(v)
+		}
+		return string(data)
+	}
+	for _, e := range entries {
+		fmt.Printf("%s\n", e.key)
+		fmt.Printf("  local: %s\n", render(e.local))
+		fmt.Printf("  other: %s\n", render(e.other))
+	}
+	fmt.Printf("\n%d differences. Take entries from %s with -take KEY (repeatable) or -all.\n", len(entries), otherName)
+}
+
+// runConfigSchema implements `gitagrip config schema [-o file]`
+func runConfigSchema(args []string) error {
+	fs := flag.NewFlagSet("config schema", flag.ContinueOnError)
+	output := fs.String("o", "", "Write the schema to a file instead of stdout")
+	if err := fs.Parse(args); err != nil {
+		return err
+	}
+
+	data, err := json.MarshalIndent(config.Schema(), "", "  ")
+	if err != nil {
+		return err
+	}
+	data = append(data, '\n')
+
+	if *output == "" {
+		_, err = os.Stdout.Write(data)
+		return err
+	}
+	if err := os.WriteFile(*output, data, 0644); err != nil {
+		return err
+	}
+	fmt.Printf("Wrote JSON Schema to %s\n", *output)
+	return nil
+}
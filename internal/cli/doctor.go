@@ -0,0 +1,236 @@
+package cli
+
+import (
+	"errors"
+	"flag"
+	"fmt"
+	"io"
+	"net"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"regexp"
+	"strconv"
+	"strings"
+	"text/tabwriter"
+	"time"
+
+	"github.com/darksworm/gitagrip/internal/config"
+	"github.com/darksworm/gitagrip/internal/statuscache"
+	"github.com/darksworm/gitagrip/internal/ui"
+	"github.com/darksworm/gitagrip/internal/ui/termcaps"
+)
+
+// minGitVersion is the oldest git whose plumbing output gitagrip parses correctly
+var minGitVersion = [2]int{2, 20}
+
+// Results of a doctor check
+const (
+	doctorOK   = "OK"
+	doctorWarn = "WARN"
+	doctorFail = "FAIL"
+)
+
+// doctorCheck is the outcome of one environment check, with what to do about it
+type doctorCheck struct {
+	name   string
+	result string
+	detail string
+	fix    string
+}
+
+// runDoctor implements `gitagrip doctor --self [-d dir]`: it checks the environment
+// gitagrip runs in and prints how to fix what's wrong. It fails when any check fails.
+func runDoctor(args []string) error {
+	fs := flag.NewFlagSet("doctor", flag.ContinueOnError)
+	self := fs.Bool("self", false, "Check the terminal, git, config, cache directory and ssh-agent")
+	dir := fs.String("d", "", "Workspace directory whose config is checked (defaults to the current directory)")
+	if err := fs.Parse(args); err != nil {
+		return err
+	}
+	if !*self {
+		return fmt.Errorf("usage: gitagrip doctor --self [-d dir]")
+	}
+
+	absDir, err := resolveDir(*dir)
+	if err != nil {
+		return err
+	}
+
+	cfg, configCheck := checkConfig(absDir)
+	checks := []doctorCheck{
+		checkTerminal(cfg),
+		checkGit(),
+		checkBackend(cfg),
+		configCheck,
+		checkConfigPaths(cfg),
+		checkCacheDir(absDir, cfg),
+		checkSSHAgent(),
+	}
+
+	failed := writeDoctorReport(os.Stdout, checks)
+	if failed > 0 {
+		return fmt.Errorf("%d of %d checks failed", failed, len(checks))
+	}
+	return nil
+}
+
+// writeDoctorReport prints one line per check, followed by its fix, and returns how many failed
+func writeDoctorReport(w io.Writer, checks []doctorCheck) int {
+	tw := tabwriter.NewWriter(w, 0, 4, 2, ' ', 0)
+	failed := 0
+	for _, check := range checks {
+		_, _ = fmt.Fprintf(tw, "%s\t%s\t%s\n", check.result, check.name, check.detail)
+		if check.fix != "" && check.result != doctorOK {
+			_, _ = fmt.Fprintf(tw, "\t\tfix: %s\n", check.fix)
+		}
+		if check.result == doctorFail {
+			failed++
+		}
+	}
+	_ = tw.Flush()
+	return failed
+}
+
+// checkTerminal reports the detected color and Unicode support, after [ui.terminal] overrides
+func checkTerminal(cfg *config.Config) doctorCheck {
+	caps := termcaps.Detect(os.Environ())
+	if cfg != nil {
+		caps = caps.WithOverrides(cfg.UISettings.Terminal)
+	}
+	detail := fmt.Sprintf("TERM=%s, colors: %s, unicode: %t", os.Getenv("TERM"), caps.Color, caps.Unicode)
+	switch {
+	case os.Getenv("TERM") == "" || os.Getenv("TERM") == "dumb":
+		return doctorCheck{name: "terminal", result: doctorWarn, detail: detail,
+			fix: "set TERM to your terminal's type, e.g. export TERM=xterm-256color"}
+	case !caps.Unicode:
+		return doctorCheck{name: "terminal", result: doctorWarn, detail: detail,
+			fix: "use a UTF-8 locale (e.g. export LANG=en_US.UTF-8) or set [ui.glyphs] preset = \"ascii\""}
+	}
+	return doctorCheck{name: "terminal", result: doctorOK, detail: detail}
+}
+
+// gitVersionRE extracts major and minor from `git --version` ("git version 2.43.0")
+var gitVersionRE = regexp.MustCompile(`(\d+)\.(\d+)`)
+
+// checkGit makes sure git is on PATH and recent enough
+func checkGit() doctorCheck {
+	output, err := exec.Command("git", "--version").Output()
+	if err != nil {
+		return doctorCheck{name: "git", result: doctorFail, detail: "git not found on PATH",
+			fix: "install git and make sure it's on PATH"}
+	}
+	version := strings.TrimSpace(string(output))
+
+	match := gitVersionRE.FindStringSubmatch(version)
+	if match == nil {
+		return doctorCheck{name: "git", result: doctorWarn, detail: version + " (unrecognized version)"}
+	}
+	major, _ := strconv.Atoi(match[1])
+	minor, _ := strconv.Atoi(match[2])
+	if major < minGitVersion[0] || (major == minGitVersion[0] && minor < minGitVersion[1]) {
+		return doctorCheck{name: "git", result: doctorFail, detail: version,
+			fix: fmt.Sprintf("upgrade git to %d.%d or newer", minGitVersion[0], minGitVersion[1])}
+	}
+	return doctorCheck{name: "git", result: doctorOK, detail: version}
+}
+
+// checkBackend reports which git backend [git] selects
+func checkBackend(cfg *config.Config) doctorCheck {
+	if cfg == nil {
+		return doctorCheck{name: "backend", result: doctorOK, detail: "cli (runs the git command; libgit2 is not needed)"}
+	}
+	backend, err := cfg.Git.BackendName()
+	if err != nil {
+		return doctorCheck{name: "backend", result: doctorFail, detail: err.Error(),
+			fix: "set [git] backend = \"cli\" or remove it"}
+	}
+	return doctorCheck{name: "backend", result: doctorOK, detail: backend + " (runs the git command; libgit2 is not needed)"}
+}
+
+// checkConfig parses and validates the workspace config. The config is nil when there is
+// none or it can't be read.
+func checkConfig(dir string) (*config.Config, doctorCheck) {
+	path := filepath.Join(dir, ".gitagrip.toml")
+	if _, err := os.Stat(path); os.IsNotExist(err) {
+		return nil, doctorCheck{name: "config", result: doctorOK, detail: path + " not created yet (written on first start)"}
+	}
+
+	cfg, err := config.NewConfigService().LoadFromPath(path)
+	if err != nil {
+		return nil, doctorCheck{name: "config", result: doctorFail, detail: err.Error(),
+			fix: "correct the TOML syntax in " + path + " (gitagrip config schema describes every key)"}
+	}
+	if err := ui.ValidateConfig(cfg); err != nil {
+		return cfg, doctorCheck{name: "config", result: doctorFail,
+			detail: strings.ReplaceAll(err.Error(), "\n", "; "),
+			fix:    "correct the listed settings in " + path}
+	}
+	return cfg, doctorCheck{name: "config", result: doctorOK, detail: path}
+}
+
+// checkConfigPaths looks for [groups] and [repos] entries whose repository is gone
+func checkConfigPaths(cfg *config.Config) doctorCheck {
+	if cfg == nil {
+		return doctorCheck{name: "config paths", result: doctorOK, detail: "no config"}
+	}
+	missing := cfg.MissingPaths()
+	if len(missing) == 0 {
+		return doctorCheck{name: "config paths", result: doctorOK, detail: "every repository in the config exists"}
+	}
+	return doctorCheck{name: "config paths", result: doctorWarn,
+		detail: fmt.Sprintf("%d missing: %s", len(missing), strings.Join(missing, ", ")),
+		fix:    "remove them from [groups] and [repos], or put the repositories back"}
+}
+
+// checkCacheDir makes sure the status cache can be written
+func checkCacheDir(dir string, cfg *config.Config) doctorCheck {
+	if cfg != nil && cfg.Cache.Disable {
+		return doctorCheck{name: "cache", result: doctorOK, detail: "disabled in [cache]"}
+	}
+
+	cachePath, err := statuscache.DefaultPath(dir)
+	if err != nil {
+		return doctorCheck{name: "cache", result: doctorWarn, detail: err.Error(),
+			fix: "set XDG_CACHE_HOME (or HOME) to a writable directory"}
+	}
+	cacheDir := filepath.Dir(cachePath)
+	if err := os.MkdirAll(cacheDir, 0755); err != nil {
+		return doctorCheck{name: "cache", result: doctorWarn, detail: err.Error(),
+			fix: "make " + cacheDir + " writable, or set [cache] disable = true"}
+	}
+	probe, err := os.CreateTemp(cacheDir, ".doctor-*")
+	if err != nil {
+		return doctorCheck{name: "cache", result: doctorWarn, detail: err.Error(),
+			fix: "make " + cacheDir + " writable, or set [cache] disable = true"}
+	}
+	_ = probe.Close()
+	_ = os.Remove(probe.Name())
+	return doctorCheck{name: "cache", result: doctorOK, detail: cacheDir + " is writable"}
+}
+
+// checkSSHAgent looks for a running ssh-agent, which fetching over SSH without
+// passphrase prompts relies on
+func checkSSHAgent() doctorCheck {
+	sock := os.Getenv("SSH_AUTH_SOCK")
+	if sock == "" {
+		return doctorCheck{name: "ssh-agent", result: doctorWarn, detail: "SSH_AUTH_SOCK is not set",
+			fix: "start an agent and add your key: eval \"$(ssh-agent)\" && ssh-add (only needed for SSH remotes)"}
+	}
+	conn, err := net.DialTimeout("unix", sock, 2*time.Second)
+	if err != nil {
+		return doctorCheck{name: "ssh-agent", result: doctorWarn, detail: "cannot reach " + sock,
+			fix: "restart the agent: eval \"$(ssh-agent)\" && ssh-add"}
+	}
+	_ = conn.Close()
+
+	// ssh-add -l exits 1 when the agent holds no keys
+	if err := exec.Command("ssh-add", "-l").Run(); err != nil {
+		var exitErr *exec.ExitError
+		if errors.As(err, &exitErr) && exitErr.ExitCode() == 1 {
+			return doctorCheck{name: "ssh-agent", result: doctorWarn, detail: "running, but holds no keys",
+				fix: "add your key: ssh-add"}
+		}
+	}
+	return doctorCheck{name: "ssh-agent", result: doctorOK, detail: "running at " + sock}
+}
@@ -0,0 +1,105 @@
+package cli
+
+import (
+	"context"
+	"flag"
+	"fmt"
+	"path/filepath"
+	"sync"
+
+	"github.com/darksworm/gitagrip/engine"
+	"github.com/darksworm/gitagrip/internal/config"
+)
+
+// runFetch implements `gitagrip fetch [-d dir] [-remote name] [-tags auto|all|none] [-depth n] [repo...]`:
+// it fetches the given repositories, or every repository below the workspace
+func runFetch(args []string) error {
+	fs := flag.NewFlagSet("fetch", flag.ContinueOnError)
+	dir := fs.String("d", "", "Workspace directory (defaults to the current directory)")
+	remote := fs.String("remote", "", "Fetch only this remote (defaults to every remote)")
+	tags := fs.String("tags", engine.FetchTagsAuto, "Which tags to fetch: auto, all or none")
+	depth := fs.Int("depth", 0, "Fetch only this many commits of history (0 for all of it)")
+	if err := fs.Parse(args); err != nil {
+		return err
+	}
+
+	switch *tags {
+	case engine.FetchTagsAuto, engine.FetchTagsAll, engine.FetchTagsNone:
+	default:
+		return fmt.Errorf("unknown tags mode %q (want auto, all or none)", *tags)
+	}
+	if *depth < 0 {
+		return fmt.Errorf("depth must not be negative")
+	}
+
+	absDir, err := resolveDir(*dir)
+	if err != nil {
+		return err
+	}
+	cfg, err := config.LoadWorkspace(absDir)
+	if err != nil {
+		return err
+	}
+
+	var repos []string
+	for _, repo := range fs.Args() {
+		if !filepath.IsAbs(repo) {
+			repo = filepath.Join(absDir, repo)
+		}
+		repos = append(repos, filepath.Clean(repo))
+	}
+	if len(repos) == 0 {
+		found, err := engine.FindRepositories(context.Background(), absDir)
+		if err != nil {
+			return err
+		}
+		for _, repo := range found {
+			if !cfg.IsForgotten(repo) {
+				repos = append(repos, repo)
+			}
+		}
+	}
+	if len(repos) == 0 {
+		fmt.Println("Nothing to fetch: no repositories found")
+		return nil
+	}
+
+	return fetch(cfg, repos, engine.FetchOptions{Remote: *remote, Tags: *tags, Depth: *depth})
+}
+
+// fetch fetches repos with opts, printing each result as it arrives
+func fetch(cfg *config.Config, repos []string, opts engine.FetchOptions) error {
+	eng := engine.New(engine.Options{Config: config.WorkspaceOf(cfg)})
+	defer eng.Close()
+
+	// Every fetch reports exactly one result; the last one ends the wait
+	var mu sync.Mutex
+	var fetched, failed int
+	done := make(chan struct{})
+	eng.Subscribe(engine.EventFetchCompleted, func(e engine.Event) {
+		event, ok := e.(engine.FetchCompletedEvent)
+		if !ok {
+			return
+		}
+		mu.Lock()
+		defer mu.Unlock()
+		if event.Success {
+			fetched++
+			fmt.Printf("[%d/%d] fetched %s\n", fetched+failed, len(repos), event.RepoPath)
+		} else {
+			failed++
+			fmt.Printf("[%d/%d] failed %s: %s\n", fetched+failed, len(repos), event.RepoPath, lastLine(event.Error.Error(), "unknown error"))
+		}
+		if fetched+failed == len(repos) {
+			close(done)
+		}
+	})
+	eng.FetchWith(opts, repos...)
+	<-done
+
+	fmt.Printf("\nFetched %d of %d repositories\n", fetched, len(repos))
+	if failed > 0 {
+		return fmt.Errorf("%d of %d fetches failed", failed, len(repos))
+	}
+	return nil
+}
@@ -0,0 +1,299 @@
+package cli
+
+import (
+	"encoding/json"
+	"flag"
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+
+	"github.com/darksworm/gitagrip/internal/config"
+	"github.com/darksworm/gitagrip/internal/discovery"
+)
+
+const groupUsage = `usage: gitagrip group <command> [-d dir] ...
+
+Commands:
+  add NAME [path...]   Create a group, optionally with repositories
+  move NAME path...    Move repositories into an existing group
+  rm NAME              Delete a group (its repositories become ungrouped)
+  list [-json]         List groups and their repositories
+  auto [-strategy S]   Group ungrouped repositories by directory (parent, path or flat)`
+
+// groupSubcommands lists the `gitagrip group` subcommands by name
+var groupSubcommands = map[string]func(args []string) error{
+	"add":  runGroupAdd,
+	"move": runGroupMove,
+	"rm":   runGroupRemove,
+	"list": runGroupList,
+	"auto": runGroupAuto,
+}
+
+// runGroup implements `gitagrip group <add|move|rm|list|auto> ...`
+func runGroup(args []string) error {
+	if len(args) == 0 {
+		return fmt.Errorf("%s", groupUsage)
+	}
+	run, ok := groupSubcommands[args[0]]
+	if !ok {
+		return fmt.Errorf("unknown command %q\n%s", args[0], groupUsage)
+	}
+	return run(args[1:])
+}
+
+// workspaceConfig is a loaded .gitagrip.toml and the path it was read from
+type workspaceConfig struct {
+	cfg  *config.Config
+	path string
+}
+
+// loadWorkspaceConfig parses the -d flag, loads .gitagrip.toml from that directory
+// and returns the remaining positional arguments
+func loadWorkspaceConfig(fs *flag.FlagSet, args []string) (*workspaceConfig, []string, error) {
+	dir := fs.String("d", "", "Workspace directory (defaults to the current directory)")
+	if err := fs.Parse(args); err != nil {
+		return nil, nil, err
+	}
+
+	absDir, err := resolveDir(*dir)
+	if err != nil {
+		return nil, nil, err
+	}
+
+	configPath := filepath.Join(absDir, ".gitagrip.toml")
+	cfg, err := config.NewConfigService().LoadFromPath(configPath)
+	if err != nil {
+		return nil, nil, err
+	}
+	return &workspaceConfig{cfg: cfg, path: configPath}, fs.Args(), nil
+}
+
+// save writes the workspace config back to disk
+func (w *workspaceConfig) save() error {
+	return config.NewConfigService().SaveToPath(w.cfg, w.path)
+}
+
+// moveRepos places the repositories in the named group, taking them out of any
+// other group first, as moving a repository in the TUI does
+func (w *workspaceConfig) moveRepos(name string, paths []string) error {
+	for _, p := range paths {
+		absPath, err := filepath.Abs(p)
+		if err != nil {
+			return fmt.Errorf("error resolving %s: %w", p, err)
+		}
+		for group, repos := range w.cfg.Groups {
+			w.cfg.Groups[group] = without(repos, absPath)
+		}
+		w.cfg.Groups[name] = append(w.cfg.Groups[name], absPath)
+	}
+	return nil
+}
+
+// without returns list with every occurrence of value removed
+func without(list []string, value string) []string {
+	kept := list[:0]
+	for _, v := range list {
+		if v != value {
+			kept = append(kept, v)
+		}
+	}
+	return kept
+}
+
+// runGroupAdd implements `gitagrip group add [-d dir] NAME [path...]`
+func runGroupAdd(args []string) error {
+	w, rest, err := loadWorkspaceConfig(flag.NewFlagSet("group add", flag.ContinueOnError), args)
+	if err != nil {
+		return err
+	}
+	if len(rest) < 1 {
+		return fmt.Errorf("usage: gitagrip group add [-d dir] NAME [path...]")
+	}
+
+	name := rest[0]
+	if _, exists := w.cfg.Groups[name]; exists {
+		return fmt.Errorf("group %s already exists", name)
+	}
+	w.cfg.Groups[name] = []string{}
+	w.cfg.GroupOrder = append(w.cfg.GroupOrder, name)
+	if err := w.moveRepos(name, rest[1:]); err != nil {
+		return err
+	}
+	if err := w.save(); err != nil {
+		return err
+	}
+
+	fmt.Printf("Created group %s with %d repositories\n", name, len(w.cfg.Groups[name]))
+	return nil
+}
+
+// runGroupMove implements `gitagrip group move [-d dir] NAME path...`
+func runGroupMove(args []string) error {
+	w, rest, err := loadWorkspaceConfig(flag.NewFlagSet("group move", flag.ContinueOnError), args)
+	if err != nil {
+		return err
+	}
+	if len(rest) < 2 {
+		return fmt.Errorf("usage: gitagrip group move [-d dir] NAME path...")
+	}
+
+	name := rest[0]
+	if _, exists := w.cfg.Groups[name]; !exists {
+		return fmt.Errorf("group %s does not exist", name)
+	}
+	if err := w.moveRepos(name, rest[1:]); err != nil {
+		return err
+	}
+	if err := w.save(); err != nil {
+		return err
+	}
+
+	fmt.Printf("Moved %d repositories to %s\n", len(rest)-1, name)
+	return nil
+}
+
+// runGroupRemove implements `gitagrip group rm [-d dir] NAME`
+func runGroupRemove(args []string) error {
+	w, rest, err := loadWorkspaceConfig(flag.NewFlagSet("group rm", flag.ContinueOnError), args)
+	if err != nil {
+		return err
+	}
+	if len(rest) != 1 {
+		return fmt.Errorf("usage: gitagrip group rm [-d dir] NAME")
+	}
+
+	name := rest[0]
+	if _, exists := w.cfg.Groups[name]; !exists {
+		return fmt.Errorf("group %s does not exist", name)
+	}
+	delete(w.cfg.Groups, name)
+	w.cfg.GroupOrder = without(w.cfg.GroupOrder, name)
+	if err := w.save(); err != nil {
+		return err
+	}
+
+	fmt.Printf("Deleted group %s\n", name)
+	return nil
+}
+
+// runGroupAuto implements `gitagrip group auto [-d dir] [-strategy S]`. Repositories
+// already in a group stay where they are; the others are grouped by directory using the
+// strategy, which is remembered as grouping.strategy when given.
+func runGroupAuto(args []string) error {
+	fs := flag.NewFlagSet("group auto", flag.ContinueOnError)
+	strategy := fs.String("strategy", "", "Grouping strategy: parent, path or flat (defaults to grouping.strategy)")
+	w, rest, err := loadWorkspaceConfig(fs, args)
+	if err != nil {
+		return err
+	}
+	if len(rest) != 0 {
+		return fmt.Errorf("usage: gitagrip group auto [-d dir] [-strategy parent|path|flat]")
+	}
+	if *strategy != "" {
+		w.cfg.Grouping.Strategy = *strategy
+	}
+
+	baseDir := w.cfg.BaseDir
+	if baseDir == "" {
+		baseDir = filepath.Dir(w.path)
+	}
+	found, err := discovery.AutoGroups(baseDir, w.cfg.Grouping.Strategy)
+	if err != nil {
+		return err
+	}
+
+	grouped := make(map[string]bool)
+	for _, repos := range w.cfg.Groups {
+		for _, repo := range repos {
+			grouped[repo] = true
+		}
+	}
+
+	names := make([]string, 0, len(found))
+	for name := range found {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+
+	moved := 0
+	for _, name := range names {
+		for _, repo := range found[name] {
+			if grouped[repo] {
+				continue
+			}
+			if _, exists := w.cfg.Groups[name]; !exists {
+				w.cfg.GroupOrder = append(w.cfg.GroupOrder, name)
+			}
+			w.cfg.Groups[name] = append(w.cfg.Groups[name], repo)
+			moved++
+		}
+	}
+	if err := w.save(); err != nil {
+		return err
+	}
+
+	fmt.Printf("Grouped %d repositories\n", moved)
+	return nil
+}
+
+// groupListing is the JSON form of a group printed by `group list -json`
+type groupListing struct {
+	Name  string   `json:"name"`
+	Repos []string `json:"repos"`
+}
+
+// runGroupList implements `gitagrip group list [-d dir] [-json]`
+func runGroupList(args []string) error {
+	fs := flag.NewFlagSet("group list", flag.ContinueOnError)
+	asJSON := fs.Bool("json", false, "Print groups as JSON")
+	w, _, err := loadWorkspaceConfig(fs, args)
+	if err != nil {
+		return err
+	}
+
+	listing := make([]groupListing, 0, len(w.cfg.Groups))
+	for _, name := range orderedGroupNames(w.cfg) {
+		repos := w.cfg.Groups[name]
+		if repos == nil {
+			repos = []string{}
+		}
+		listing = append(listing, groupListing{Name: name, Repos: repos})
+	}
+
+	if *asJSON {
+		enc := json.NewEncoder(os.Stdout)
+		enc.SetIndent("", "  ")
+		return enc.Encode(listing)
+	}
+
+	for _, group := range listing {
+		fmt.Printf("%s (%d)\n", group.Name, len(group.Repos))
+		for _, repo := range group.Repos {
+			fmt.Printf("  %s\n", repo)
+		}
+	}
+	return nil
+}
+
+// orderedGroupNames returns group names in the saved display order, followed by
+// any groups missing from it in alphabetical order
+func orderedGroupNames(cfg *config.Config) []string {
+	var names []string
+	seen := make(map[string]bool)
+	for _, name := range cfg.GroupOrder {
+		if _, exists := cfg.Groups[name]; exists && !seen[name] {
+			names = append(names, name)
+			seen[name] = true
+		}
+	}
+
+	var rest []string
+	for name := range cfg.Groups {
+		if !seen[name] {
+			rest = append(rest, name)
+		}
+	}
+	sort.Strings(rest)
+	return append(names, rest...)
+}
@@ -0,0 +1,87 @@
+package cli
+
+import (
+	"context"
+	"flag"
+	"fmt"
+	"os"
+	"path/filepath"
+	"sync"
+
+	"github.com/darksworm/gitagrip/engine"
+	"github.com/darksworm/gitagrip/internal/config"
+	"github.com/darksworm/gitagrip/internal/health"
+)
+
+// healthWorkers is how many repositories are checked at once
+const healthWorkers = 8
+
+// runHealth implements `gitagrip health [-d dir] [-fsck] [-format text|json] [repo...]`.
+// It fails when any repository has a warning or a failed check.
+func runHealth(args []string) error {
+	fs := flag.NewFlagSet("health", flag.ContinueOnError)
+	dir := fs.String("d", "", "Workspace directory (defaults to the current directory)")
+	fsck := fs.Bool("fsck", false, "Also run git fsck --no-full, which reads every pack")
+	format := fs.String("format", health.FormatText, "Report format: text or json")
+	if err := fs.Parse(args); err != nil {
+		return err
+	}
+
+	switch *format {
+	case health.FormatText, health.FormatJSON:
+	default:
+		return fmt.Errorf("unknown format %q (want text or json)", *format)
+	}
+
+	absDir, err := resolveDir(*dir)
+	if err != nil {
+		return err
+	}
+	cfg, err := config.LoadWorkspace(absDir)
+	if err != nil {
+		return err
+	}
+
+	var repos []string
+	for _, repo := range fs.Args() {
+		if !filepath.IsAbs(repo) {
+			repo = filepath.Join(absDir, repo)
+		}
+		repos = append(repos, filepath.Clean(repo))
+	}
+	if len(repos) == 0 {
+		found, err := engine.FindRepositories(context.Background(), absDir)
+		if err != nil {
+			return err
+		}
+		for _, repo := range found {
+			if !cfg.IsForgotten(repo) {
+				repos = append(repos, repo)
+			}
+		}
+	}
+
+	// Reports keep the order of repos however the checks finish
+	reports := make([]health.Report, len(repos))
+	slots := make(chan struct{}, healthWorkers)
+	var wg sync.WaitGroup
+	for i, repo := range repos {
+		wg.Add(1)
+		go func() {
+			defer wg.Done()
+			slots <- struct{}{}
+			defer func() { <-slots }()
+			reports[i] = health.Check(context.Background(), repo, health.Options{Fsck: *fsck})
+		}()
+	}
+	wg.Wait()
+
+	summary := health.Summarize(absDir, reports)
+	if err := health.Write(os.Stdout, summary, *format); err != nil {
+		return err
+	}
+	if summary.Unhealthy > 0 {
+		return fmt.Errorf("%d of %d repositories need attention", summary.Unhealthy, summary.Total)
+	}
+	return nil
+}
@@ -0,0 +1,36 @@
+package cli
+
+import (
+	"flag"
+	"fmt"
+	"strings"
+
+	"github.com/darksworm/gitagrip/internal/control"
+	"github.com/darksworm/gitagrip/internal/paths"
+)
+
+// runSend implements `gitagrip send [-d dir] <command>`: it passes a command such as
+// "refresh", "fetch group:Work" or "jump repo:frontend" to the gitagrip running for the
+// workspace and prints its reply
+func runSend(args []string) error {
+	fs := flag.NewFlagSet("send", flag.ContinueOnError)
+	dir := fs.String("d", "", "Workspace directory of the running gitagrip (defaults to the current directory)")
+	if err := fs.Parse(args); err != nil {
+		return err
+	}
+	command := strings.Join(fs.Args(), " ")
+	if _, err := control.Parse(command); err != nil {
+		return err
+	}
+
+	absDir, err := resolveDir(*dir)
+	if err != nil {
+		return err
+	}
+	reply, err := control.Send(control.DefaultPath(paths.Normalize(absDir)), command)
+	if err != nil {
+		return err
+	}
+	fmt.Println(reply)
+	return nil
+}
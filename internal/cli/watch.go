@@ -0,0 +1,249 @@
+package cli
+
+import (
+	"context"
+	"encoding/json"
+	"flag"
+	"fmt"
+	"io"
+	"log"
+	"os"
+	"os/signal"
+	"sync"
+	"syscall"
+	"time"
+
+	"github.com/darksworm/gitagrip/engine"
+	"github.com/darksworm/gitagrip/internal/config"
+	"github.com/darksworm/gitagrip/internal/watchstate"
+)
+
+// runWatch implements `gitagrip watch [-d dir] [-json] [-refresh 30s] [-fetch 0]`: it
+// scans the workspace, then keeps the status of its repositories current without a TUI,
+// printing what happens, one JSON object per line with -json, until interrupted
+func runWatch(args []string) error {
+	fs := flag.NewFlagSet("watch", flag.ContinueOnError)
+	dir := fs.String("d", "", "Workspace directory (defaults to the current directory)")
+	asJSON := fs.Bool("json", false, "Print events as newline-delimited JSON")
+	refresh := fs.Duration("refresh", 30*time.Second, "How often to refresh every status (0 never)")
+	fetchEvery := fs.Duration("fetch", 0, "How often to fetch every repository (0 never)")
+	if err := fs.Parse(args); err != nil {
+		return err
+	}
+	if *refresh < 0 || *fetchEvery < 0 {
+		return fmt.Errorf("intervals must not be negative")
+	}
+
+	absDir, err := resolveDir(*dir)
+	if err != nil {
+		return err
+	}
+	cfg, err := config.LoadWorkspace(absDir)
+	if err != nil {
+		return err
+	}
+	var watches engine.WatchState
+	if !cfg.Cache.Disable && len(cfg.Watches) > 0 {
+		if path, err := watchstate.DefaultPath(absDir); err == nil {
+			watches = watchstate.Open(path)
+		}
+	}
+	// stdout carries the events
+	log.SetOutput(os.Stderr)
+
+	eng := engine.New(engine.Options{Config: config.WorkspaceOf(cfg), Watches: watches})
+	out := &watchPrinter{w: os.Stdout, json: *asJSON, statuses: make(map[string]watchStatus)}
+	out.subscribe(eng)
+
+	ctx, stop := signal.NotifyContext(context.Background(), syscall.SIGINT, syscall.SIGTERM)
+	defer stop()
+	if err := eng.Scan(ctx, absDir); err != nil {
+		eng.Close()
+		return err
+	}
+
+	refreshTick, fetchTick := ticker(*refresh), ticker(*fetchEvery)
+	for done := false; !done; {
+		select {
+		case <-ctx.Done():
+			done = true
+		case <-refreshTick:
+			eng.Refresh()
+		case <-fetchTick:
+			eng.Fetch()
+		}
+	}
+
+	eng.Close()
+	if watches != nil {
+		if err := watches.Flush(); err != nil {
+			log.Printf("watch: failed to save watch state: %v", err)
+		}
+	}
+	return nil
+}
+
+// ticker ticks every interval, or never for 0
+func ticker(interval time.Duration) <-chan time.Time {
+	if interval == 0 {
+		return nil
+	}
+	return time.NewTicker(interval).C
+}
+
+// watchEvent is one line of `gitagrip watch -json`. Event is one of repo_discovered,
+// status_updated, fetch_completed, scan_completed, watch_updated or error; the other
+// fields are set as the kind of event has them.
+type watchEvent struct {
+	Event   string       `json:"event"`
+	Time    time.Time    `json:"time"`
+	Repo    string       `json:"repo,omitempty"`    // repository path
+	Name    string       `json:"name,omitempty"`    // repository name (repo_discovered)
+	Status  *watchStatus `json:"status,omitempty"`  // status_updated
+	Success *bool        `json:"success,omitempty"` // fetch_completed
+	Error   string       `json:"error,omitempty"`   // failed fetch, failed status or error
+	Repos   *int         `json:"repos,omitempty"`   // repositories found (scan_completed)
+	Branch  string       `json:"branch,omitempty"`  // watched branch (watch_updated)
+	Commits []string     `json:"commits,omitempty"` // new commits on it, "hash subject", newest first
+}
+
+// watchStatus is the status of a repository in a status_updated event
+type watchStatus struct {
+	Branch     string `json:"branch"`
+	Detached   string `json:"detached,omitempty"` // commit of a detached HEAD
+	Upstream   string `json:"upstream,omitempty"`
+	Ahead      int    `json:"ahead"`
+	Behind     int    `json:"behind"`
+	Dirty      bool   `json:"dirty"`
+	Staged     int    `json:"staged"`
+	Modified   int    `json:"modified"`
+	Untracked  int    `json:"untracked"`
+	Conflicted int    `json:"conflicted"`
+	Stashes    int    `json:"stashes"`
+	InProgress string `json:"in_progress,omitempty"` // merge, rebase, ...
+	Error      string `json:"error,omitempty"`
+}
+
+// watchPrinter prints the engine's events as they arrive, from any goroutine
+type watchPrinter struct {
+	mu       sync.Mutex
+	w        io.Writer
+	json     bool
+	statuses map[string]watchStatus // last printed status per repository
+}
+
+// subscribe prints discovered repositories, changed statuses, fetches, scans, new commits
+// on watched branches and errors. Credential prompts are declined: there is no one to ask.
+func (p *watchPrinter) subscribe(eng *engine.Engine) {
+	eng.Subscribe(engine.EventRepoDiscovered, func(e engine.Event) {
+		if event, ok := e.(engine.RepoDiscoveredEvent); ok {
+			p.print(watchEvent{Event: "repo_discovered", Repo: event.Repo.Path, Name: event.Repo.Name})
+		}
+	})
+	eng.Subscribe(engine.EventStatusUpdated, func(e engine.Event) {
+		event, ok := e.(engine.StatusUpdatedEvent)
+		if !ok || event.Status.Stale {
+			return
+		}
+		status := watchStatus{
+			Branch:     event.Status.Branch,
+			Detached:   event.Status.HeadCommit,
+			Upstream:   event.Status.Upstream,
+			Ahead:      event.Status.AheadCount,
+			Behind:     event.Status.BehindCount,
+			Dirty:      event.Status.IsDirty || event.Status.HasUntracked,
+			Staged:     event.Status.Staged,
+			Modified:   event.Status.Modified,
+			Untracked:  event.Status.Untracked,
+			Conflicted: event.Status.Conflicted,
+			Stashes:    event.Status.Stashes,
+			InProgress: string(event.Status.InProgress),
+			Error:      event.Status.Error,
+		}
+		// Periodic refreshes mostly find nothing new; only changes are printed
+		p.mu.Lock()
+		last, seen := p.statuses[event.RepoPath]
+		p.statuses[event.RepoPath] = status
+		p.mu.Unlock()
+		if !seen || last != status {
+			p.print(watchEvent{Event: "status_updated", Repo: event.RepoPath, Status: &status})
+		}
+	})
+	eng.Subscribe(engine.EventFetchCompleted, func(e engine.Event) {
+		if event, ok := e.(engine.FetchCompletedEvent); ok {
+			success := event.Success
+			line := watchEvent{Event: "fetch_completed", Repo: event.RepoPath, Success: &success}
+			if event.Error != nil {
+				line.Error = lastLine(event.Error.Error(), "unknown error")
+			}
+			p.print(line)
+		}
+	})
+	eng.Subscribe(engine.EventScanCompleted, func(e engine.Event) {
+		if event, ok := e.(engine.ScanCompletedEvent); ok {
+			repos := event.ReposFound
+			p.print(watchEvent{Event: "scan_completed", Repos: &repos})
+		}
+	})
+	eng.Subscribe(engine.EventWatchUpdated, func(e engine.Event) {
+		if event, ok := e.(engine.WatchUpdatedEvent); ok && len(event.Commits) > 0 {
+			line := watchEvent{Event: "watch_updated", Repo: event.RepoPath, Branch: event.Branch}
+			for _, commit := range event.Commits {
+				line.Commits = append(line.Commits, commit.Hash+" "+commit.Subject)
+			}
+			p.print(line)
+		}
+	})
+	eng.Subscribe(engine.EventError, func(e engine.Event) {
+		if event, ok := e.(engine.ErrorEvent); ok {
+			line := watchEvent{Event: "error", Error: event.Message}
+			if event.Err != nil {
+				line.Error += ": " + event.Err.Error()
+			}
+			p.print(line)
+		}
+	})
+	eng.Subscribe(engine.EventCredentialRequested, func(e engine.Event) {
+		if event, ok := e.(engine.CredentialRequestedEvent); ok {
+			eng.AnswerCredential(event.ID, "", true)
+		}
+	})
+}
+
+// print writes an event as a JSON line, or as text
+func (p *watchPrinter) print(event watchEvent) {
+	event.Time = time.Now()
+	p.mu.Lock()
+	defer p.mu.Unlock()
+	if p.json {
+		if err := json.NewEncoder(p.w).Encode(event); err != nil {
+			log.Printf("watch: failed to print %s: %v", event.Event, err)
+		}
+		return
+	}
+
+	line := event.Event
+	if event.Repo != "" {
+		line += " " + event.Repo
+	}
+	switch {
+	case event.Status != nil:
+		line += fmt.Sprintf(" %s ↑%d ↓%d", event.Status.Branch, event.Status.Ahead, event.Status.Behind)
+		if event.Status.Dirty {
+			line += " dirty"
+		}
+		if event.Status.Error != "" {
+			line += ": " + event.Status.Error
+		}
+	case event.Success != nil && *event.Success:
+		line += " ok"
+	case event.Repos != nil:
+		line += fmt.Sprintf(" %d repos", *event.Repos)
+	case event.Branch != "":
+		line += fmt.Sprintf(" %s: %d new", event.Branch, len(event.Commits))
+	}
+	if event.Error != "" {
+		line += ": " + event.Error
+	}
+	fmt.Fprintln(p.w, line)
+}
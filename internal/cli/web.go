@@ -0,0 +1,49 @@
+//go:build web
+
+package cli
+
+import (
+	"context"
+	"flag"
+	"fmt"
+	"net/http"
+
+	"github.com/darksworm/gitagrip/engine"
+	"github.com/darksworm/gitagrip/internal/web"
+)
+
+func init() {
+	commands["web"] = command{
+		summary: "Serve the repository list in a browser",
+		run:     runWeb,
+	}
+}
+
+// runWeb implements `gitagrip web [-d dir] [-addr host:port]`
+func runWeb(args []string) error {
+	fs := flag.NewFlagSet("web", flag.ContinueOnError)
+	dir := fs.String("d", "", "Workspace directory (defaults to the current directory)")
+	addr := fs.String("addr", "127.0.0.1:7070", "Address to listen on; the browser must use it as written")
+	if err := fs.Parse(args); err != nil {
+		return err
+	}
+
+	absDir, err := resolveDir(*dir)
+	if err != nil {
+		return err
+	}
+	cfg, err := engine.LoadConfig(absDir)
+	if err != nil {
+		return err
+	}
+
+	eng := engine.New(engine.Options{Config: cfg})
+	defer eng.Close()
+	server := web.NewServer(eng, cfg, *addr)
+	if err := eng.Scan(context.Background(), absDir); err != nil {
+		return err
+	}
+
+	fmt.Printf("Serving %s on http://%s\n", absDir, *addr)
+	return http.ListenAndServe(*addr, server.Handler())
+}
@@ -0,0 +1,14 @@
+//go:build !web
+
+package cli
+
+import "errors"
+
+func init() {
+	commands["web"] = command{
+		summary: "Serve the repository list in a browser (needs a build with -tags web)",
+		run: func([]string) error {
+			return errors.New("this binary was built without the web frontend; rebuild with: go build -tags web")
+		},
+	}
+}
@@ -0,0 +1,175 @@
+// Package compliance checks that repositories carry the files and branch protection an
+// organization requires, and writes the results as a report for audits
+package compliance
+
+import (
+	"context"
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+	"time"
+
+	"github.com/darksworm/gitagrip/internal/config"
+	"github.com/darksworm/gitagrip/internal/discovery"
+	"github.com/darksworm/gitagrip/internal/git"
+)
+
+// DefaultRequiredFiles are checked when compliance.required_files is not set
+var DefaultRequiredFiles = []string{"LICENSE", "SECURITY.md", "CODEOWNERS"}
+
+// BranchProtectionCheck is the name of the branch protection check
+const BranchProtectionCheck = "branch protection"
+
+// lookupDirs are where forges look for community files besides the repository root
+var lookupDirs = []string{"", ".github", "docs"}
+
+// Check is the outcome of one requirement for one repository
+type Check struct {
+	Name   string `json:"name"`
+	Passed bool   `json:"passed"`
+	Detail string `json:"detail,omitempty"` // where the file was found, or why the check failed
+}
+
+// Report lists the checks of one repository
+type Report struct {
+	Repo   string  `json:"repo"` // path relative to base_dir
+	Passed bool    `json:"passed"`
+	Checks []Check `json:"checks"`
+}
+
+// Summary is the result of a compliance run
+type Summary struct {
+	GeneratedAt time.Time `json:"generated_at"`
+	BaseDir     string    `json:"base_dir"`
+	Total       int       `json:"total"`
+	Failed      int       `json:"failed"`
+	Repos       []Report  `json:"repos"`
+}
+
+// ProtectionChecker reports whether a branch of a hosted repository is protected
+type ProtectionChecker interface {
+	Protected(ctx context.Context, remote git.RemoteURL, branch string) (bool, error)
+}
+
+// Run checks every repository below cfg.BaseDir. Branch protection is only checked
+// when protection is not nil.
+func Run(ctx context.Context, cfg *config.Config, protection ProtectionChecker) (*Summary, error) {
+	paths, err := discovery.FindRepositories(ctx, cfg.BaseDir)
+	if err != nil {
+		return nil, fmt.Errorf("failed to scan %s: %w", cfg.BaseDir, err)
+	}
+	sort.Strings(paths)
+
+	required := cfg.Compliance.RequiredFiles
+	if len(required) == 0 {
+		required = DefaultRequiredFiles
+	}
+
+	summary := &Summary{
+		GeneratedAt: time.Now().UTC(),
+		BaseDir:     cfg.BaseDir,
+	}
+	for _, path := range paths {
+		rel, err := filepath.Rel(cfg.BaseDir, path)
+		if err != nil {
+			rel = path
+		}
+		report := Report{Repo: filepath.ToSlash(rel), Passed: true}
+		for _, name := range required {
+			report.Checks = append(report.Checks, checkFile(path, name))
+		}
+		if protection != nil {
+			report.Checks = append(report.Checks, checkProtection(ctx, path, protection))
+		}
+		for _, check := range report.Checks {
+			report.Passed = report.Passed && check.Passed
+		}
+
+		summary.Total++
+		if !report.Passed {
+			summary.Failed++
+		}
+		summary.Repos = append(summary.Repos, report)
+	}
+
+	return summary, nil
+}
+
+// checkFile looks for a required file in the repository root, .github/ and docs/.
+// A bare name also matches it with any extension (LICENSE matches LICENSE.md), ignoring case.
+func checkFile(repoPath, name string) Check {
+	check := Check{Name: name, Detail: "missing"}
+
+	if strings.Contains(name, "/") {
+		if _, err := os.Stat(filepath.Join(repoPath, filepath.FromSlash(name))); err == nil {
+			check.Passed, check.Detail = true, name
+		}
+		return check
+	}
+
+	for _, dir := range lookupDirs {
+		entries, err := os.ReadDir(filepath.Join(repoPath, dir))
+		if err != nil {
+			continue
+		}
+		for _, entry := range entries {
+			if entry.IsDir() || !matchesFile(entry.Name(), name) {
+				continue
+			}
+			check.Passed, check.Detail = true, filepath.ToSlash(filepath.Join(dir, entry.Name()))
+			return check
+		}
+	}
+	return check
+}
+
+// matchesFile reports whether a file name is the required name, with or without an extension
+func matchesFile(fileName, required string) bool {
+	if strings.EqualFold(fileName, required) {
+		return true
+	}
+	base := strings.TrimSuffix(fileName, filepath.Ext(fileName))
+	return filepath.Ext(required) == "" && strings.EqualFold(base, required)
+}
+
+// checkProtection asks the forge whether the default branch of origin is protected
+func checkProtection(ctx context.Context, repoPath string, protection ProtectionChecker) Check {
+	check := Check{Name: BranchProtectionCheck}
+
+	remote, ok := git.ParseRemoteURL(git.Output(ctx, repoPath, "config", "--get", "remote.origin.url"))
+	if !ok {
+		check.Detail = "no hosted origin remote"
+		return check
+	}
+
+	branch := defaultBranch(ctx, repoPath)
+	if branch == "" {
+		check.Detail = "no default branch"
+		return check
+	}
+
+	protected, err := protection.Protected(ctx, remote, branch)
+	switch {
+	case err != nil:
+		check.Detail = err.Error()
+	case protected:
+		check.Passed, check.Detail = true, branch
+	default:
+		check.Detail = fmt.Sprintf("%s is not protected", branch)
+	}
+	return check
+}
+
+// defaultBranch returns the branch origin/HEAD points at, falling back to the checked out branch
+func defaultBranch(ctx context.Context, repoPath string) string {
+	if ref := git.Output(ctx, repoPath, "symbolic-ref", "--short", "refs/remotes/origin/HEAD"); ref != "" {
+		return strings.TrimPrefix(ref, "origin/")
+	}
+	branch := git.Output(ctx, repoPath, "rev-parse", "--abbrev-ref", "HEAD")
+	if branch == "HEAD" {
+		return ""
+	}
+	return branch
+}
@@ -0,0 +1,54 @@
+package compliance
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"net/http"
+	"net/url"
+	"strings"
+	"time"
+
+	"github.com/darksworm/gitagrip/internal/forge"
+	"github.com/darksworm/gitagrip/internal/git"
+)
+
+// GitHub checks branch protection through the GitHub REST API (or a GitHub Enterprise
+// server when BaseURL points at one)
+type GitHub struct {
+	BaseURL string // API base URL, forge.DefaultGitHubAPI if empty
+	Token   string // sent as a bearer token; private repositories need one
+	Client  *http.Client
+}
+
+// NewGitHub returns a checker for the given API URL and token
+func NewGitHub(baseURL, token string) *GitHub {
+	return &GitHub{
+		BaseURL: baseURL,
+		Token:   token,
+		Client:  &http.Client{Timeout: 15 * time.Second},
+	}
+}
+
+// Protected implements ProtectionChecker
+func (g *GitHub) Protected(ctx context.Context, remote git.RemoteURL, branch string) (bool, error) {
+	base := strings.TrimSuffix(g.BaseURL, "/")
+	if base == "" {
+		base = forge.DefaultGitHubAPI
+		if remote.Host != "github.com" {
+			return false, fmt.Errorf("%s is not github.com (set compliance.api_url)", remote.Host)
+		}
+	}
+
+	endpoint := fmt.Sprintf("%s/repos/%s/branches/%s", base, remote.Path, url.PathEscape(branch))
+	var body struct {
+		Protected bool `json:"protected"`
+	}
+	if err := forge.GitHubGet(ctx, g.Client, endpoint, g.Token, &body); err != nil {
+		if errors.Is(err, forge.ErrNotFound) {
+			return false, fmt.Errorf("%s@%s not found on the forge (private repositories need GITHUB_TOKEN)", remote.Path, branch)
+		}
+		return false, err
+	}
+	return body.Protected, nil
+}
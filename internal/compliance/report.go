@@ -0,0 +1,93 @@
+package compliance
+
+import (
+	"encoding/csv"
+	"encoding/json"
+	"fmt"
+	"io"
+	"strings"
+	"text/tabwriter"
+)
+
+// Report formats accepted by Write
+const (
+	FormatText = "text"
+	FormatJSON = "json"
+	FormatCSV  = "csv"
+)
+
+// Write renders the summary in the given format
+func Write(w io.Writer, summary *Summary, format string) error {
+	switch format {
+	case FormatText, "":
+		return writeText(w, summary)
+	case FormatJSON:
+		enc := json.NewEncoder(w)
+		enc.SetIndent("", "  ")
+		return enc.Encode(summary)
+	case FormatCSV:
+		return writeCSV(w, summary)
+	default:
+		return fmt.Errorf("unknown format %q (want text, json or csv)", format)
+	}
+}
+
+// writeText prints one line per repository with the failed checks, then the totals
+func writeText(w io.Writer, summary *Summary) error {
+	tw := tabwriter.NewWriter(w, 0, 4, 2, ' ', 0)
+	for _, report := range summary.Repos {
+		result := "PASS"
+		var failed []string
+		for _, check := range report.Checks {
+			if !check.Passed {
+				failed = append(failed, fmt.Sprintf("%s: %s", check.Name, check.Detail))
+			}
+		}
+		if !report.Passed {
+			result = "FAIL"
+		}
+		_, _ = fmt.Fprintf(tw, "%s\t%s\t%s\n", result, report.Repo, strings.Join(failed, "; "))
+	}
+	if err := tw.Flush(); err != nil {
+		return err
+	}
+
+	_, err := fmt.Fprintf(w, "\n%d of %d repositories pass\n", summary.Total-summary.Failed, summary.Total)
+	return err
+}
+
+// writeCSV writes a header of check names and a pass/fail row per repository.
+// Every repository runs the same checks, so the columns line up.
+func writeCSV(w io.Writer, summary *Summary) error {
+	cw := csv.NewWriter(w)
+
+	header := []string{"repo", "result"}
+	if len(summary.Repos) > 0 {
+		for _, check := range summary.Repos[0].Checks {
+			header = append(header, check.Name)
+		}
+	}
+	if err := cw.Write(header); err != nil {
+		return err
+	}
+
+	for _, report := range summary.Repos {
+		row := []string{report.Repo, passFail(report.Passed)}
+		for _, check := range report.Checks {
+			row = append(row, passFail(check.Passed))
+		}
+		if err := cw.Write(row); err != nil {
+			return err
+		}
+	}
+
+	cw.Flush()
+	return cw.Error()
+}
+
+func passFail(passed bool) string {
+	if passed {
+		return "pass"
+	}
+	return "fail"
+}
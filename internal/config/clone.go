@@ -0,0 +1,47 @@
+package config
+
+import "reflect"
+
+// Clone returns a deep copy of the config. Services reading the config on their own
+// goroutines get clones, so that changing the original never races with them.
+func (c *Config) Clone() *Config {
+	if c == nil {
+		return nil
+	}
+	clone := new(Config)
+	deepCopy(reflect.ValueOf(clone).Elem(), reflect.ValueOf(c).Elem())
+	return clone
+}
+
+// deepCopy sets dst to a copy of src that shares no maps, slices or pointers with it
+func deepCopy(dst, src reflect.Value) {
+	switch src.Kind() {
+	case reflect.Pointer:
+		if !src.IsNil() {
+			dst.Set(reflect.New(src.Elem().Type()))
+			deepCopy(dst.Elem(), src.Elem())
+		}
+	case reflect.Struct:
+		for i := 0; i < src.NumField(); i++ {
+			deepCopy(dst.Field(i), src.Field(i))
+		}
+	case reflect.Slice:
+		if !src.IsNil() {
+			dst.Set(reflect.MakeSlice(src.Type(), src.Len(), src.Len()))
+			for i := 0; i < src.Len(); i++ {
+				deepCopy(dst.Index(i), src.Index(i))
+			}
+		}
+	case reflect.Map:
+		if !src.IsNil() {
+			dst.Set(reflect.MakeMapWithSize(src.Type(), src.Len()))
+			for iter := src.MapRange(); iter.Next(); {
+				value := reflect.New(src.Type().Elem()).Elem()
+				deepCopy(value, iter.Value())
+				dst.SetMapIndex(iter.Key(), value)
+			}
+		}
+	default:
+		dst.Set(src)
+	}
+}
@@ -0,0 +1,24 @@
+package config
+
+import (
+	"reflect"
+	"testing"
+)
+
+func TestCloneSharesNothing(t *testing.T) {
+	cfg := DefaultConfig()
+	cfg.Groups = map[string][]string{"Work": {"/code/api"}}
+	cfg.Repos = map[string]RepoSettings{"/code/api": {Labels: []string{"backend"}}}
+	cfg.Forgotten = []string{"/code/old"}
+
+	clone := cfg.Clone()
+	if !reflect.DeepEqual(clone, cfg) {
+		t.Fatalf("clone differs from the original:\n%+v\n%+v", clone, cfg)
+	}
+	clone.Groups["Work"][0] = "/code/web"
+	clone.Repos["/code/api"].Labels[0] = "frontend"
+	clone.Forgotten = append(clone.Forgotten[:0], "/code/new")
+	if cfg.Groups["Work"][0] != "/code/api" || cfg.Repos["/code/api"].Labels[0] != "backend" || cfg.Forgotten[0] != "/code/old" {
+		t.Fatalf("changing the clone changed the original: %+v", cfg)
+	}
+}
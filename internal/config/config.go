@@ -1,27 +1,804 @@
 package config
 
 import (
+	"errors"
 	"fmt"
 	"os"
 	"path/filepath"
-
-	"gitagrip/internal/eventbus"
+	"slices"
+	"sort"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/darksworm/gitagrip/internal/domain"
+	"github.com/darksworm/gitagrip/internal/eventbus"
+	"github.com/darksworm/gitagrip/internal/paths"
 	"github.com/pelletier/go-toml/v2"
 )
 
 // Config represents the application configuration
 type Config struct {
-	Version    int                 `toml:"version"`
-	BaseDir    string              `toml:"base_dir"`
-	Groups     map[string][]string `toml:"groups"`      // group name -> repo paths
-	GroupOrder []string            `toml:"group_order"` // ordered list of group names
-	UISettings UISettings          `toml:"ui"`
+	Version        int                          `toml:"version"`
+	BaseDir        string                       `toml:"base_dir"`
+	Groups         map[string][]string          `toml:"groups"`      // group name -> repo paths
+	GroupOrder     []string                     `toml:"group_order"` // ordered list of group names
+	UISettings     UISettings                   `toml:"ui"`
+	Upstream       UpstreamSettings             `toml:"upstream,omitempty"`
+	Remotes        RemoteSettings               `toml:"remotes,omitempty"`
+	Environment    EnvironmentSettings          `toml:"environment,omitempty"`
+	Cache          CacheSettings                `toml:"cache,omitempty"`
+	Guards         GuardSettings                `toml:"guards,omitempty"`
+	Theme          ThemeSettings                `toml:"theme,omitempty"`
+	Repos          map[string]RepoSettings      `toml:"repos,omitempty"`         // per-repository settings keyed by path
+	GroupEnv       map[string]map[string]string `toml:"group_env,omitempty"`     // group name -> env vars for programs launched in its repos
+	GroupColors    map[string]string            `toml:"group_colors,omitempty"`  // group name -> color of its header and the gutter beside its repos
+	GroupStartup   map[string]GroupStartup      `toml:"group_startup,omitempty"` // group name -> what its repos do when gitagrip starts
+	Keys           map[string]string            `toml:"keys,omitempty"`          // action -> space-separated keys, e.g. fetch = "f ctrl+r"
+	Watches        []WatchSettings              `toml:"watches,omitempty"`
+	GroupTemplates map[string]GroupTemplate     `toml:"group_templates,omitempty"` // extra templates offered when creating a group
+	Grouping       GroupingSettings             `toml:"grouping,omitempty"`
+	Rules          []GroupRule                  `toml:"rules,omitempty"` // place discovered repositories in groups automatically
+	Compliance     ComplianceSettings           `toml:"compliance,omitempty"`
+	Stale          StaleSettings                `toml:"stale,omitempty"`
+	Forgotten      []string                     `toml:"forgotten,omitempty"` // repo paths left out of the list and of scans
+	Archive        ArchiveSettings              `toml:"archive,omitempty"`
+	Git            GitSettings                  `toml:"git,omitempty"`
+	Fetch          FetchSettings                `toml:"fetch,omitempty"`
+	Status         StatusSettings               `toml:"status,omitempty"`
+	Scan           ScanSettings                 `toml:"scan,omitempty"`
+	CloneOrg       CloneOrgSettings             `toml:"clone_org,omitempty"`
 }
 
 // UISettings represents UI-related configuration
 type UISettings struct {
-	ShowAheadBehind bool `toml:"show_ahead_behind"`
-	AutosaveOnExit  bool `toml:"autosave_on_exit"`
+	ShowAheadBehind   bool             `toml:"show_ahead_behind"`
+	AutosaveOnExit    bool             `toml:"autosave_on_exit"`
+	ShowCleanupStats  bool             `toml:"show_cleanup_stats,omitempty"` // count untracked files and ignored bytes per repo
+	ShowLastCommit    bool             `toml:"show_last_commit,omitempty"`   // show the age and author initials of the last commit
+	DisableMouse      bool             `toml:"disable_mouse,omitempty"`      // leave the mouse to the terminal (e.g. for text selection)
+	SkipConfirmations bool             `toml:"skip_confirmations,omitempty"` // run risky actions without asking first
+	QuickBar          []string         `toml:"quick_bar,omitempty"`          // actions pinned to the footer, triggered with the free digits
+	Sort              string           `toml:"sort,omitempty"`               // order of repos within groups; 'S' cycles it
+	Columns           []string         `toml:"columns,omitempty"`            // lay the list out in these columns, e.g. ["name", "branch", "changes"]
+	TwoPaneWidth      int              `toml:"two_pane_width,omitempty"`     // terminal width from which details show beside the list (0 = 160, -1 = never)
+	Icons             string           `toml:"icons,omitempty"`              // "unicode", "nerdfont" (status, branch and project icons) or "ascii"
+	Glyphs            GlyphSettings    `toml:"glyphs,omitempty"`
+	Terminal          TerminalSettings `toml:"terminal,omitempty"`
+	Dates             DateSettings     `toml:"dates,omitempty"`
+}
+
+// ThemeSettings selects a color palette and overrides individual colors.
+// Colors are ANSI 256 numbers ("214") or hex values ("#ff8800"); empty fields keep the palette color.
+type ThemeSettings struct {
+	Palette        string   `toml:"palette,omitempty"` // "dark" (default), "light" or "solarized"
+	Title          string   `toml:"title,omitempty"`
+	Accent         string   `toml:"accent,omitempty"`
+	Heading        string   `toml:"heading,omitempty"`
+	Key            string   `toml:"key,omitempty"`
+	Text           string   `toml:"text,omitempty"`
+	Muted          string   `toml:"muted,omitempty"`
+	Border         string   `toml:"border,omitempty"`
+	PopupBorder    string   `toml:"popup_border,omitempty"`
+	Faded          string   `toml:"faded,omitempty"`
+	Error          string   `toml:"error,omitempty"`
+	Warning        string   `toml:"warning,omitempty"`
+	Success        string   `toml:"success,omitempty"`
+	Info           string   `toml:"info,omitempty"`
+	Highlight      string   `toml:"highlight,omitempty"`
+	Cursor         string   `toml:"cursor,omitempty"`
+	Selected       string   `toml:"selected,omitempty"`
+	CursorSelected string   `toml:"cursor_selected,omitempty"`
+	MainBranch     string   `toml:"main_branch,omitempty"`
+	DevBranch      string   `toml:"dev_branch,omitempty"`
+	BranchColors   []string `toml:"branch_colors,omitempty"`
+}
+
+// TerminalSettings overrides automatic terminal capability detection
+type TerminalSettings struct {
+	Color   string `toml:"color,omitempty"`   // "auto" (default), "truecolor", "256", "16" or "none"
+	Unicode *bool  `toml:"unicode,omitempty"` // unset = detect from locale/TERM
+	Italics *bool  `toml:"italics,omitempty"` // unset = detect from TERM
+}
+
+// DateSettings controls how dates are shown in the UI and in reports
+type DateSettings struct {
+	Style  string `toml:"style,omitempty"`  // "absolute" (default) or "relative" ("5m ago")
+	Format string `toml:"format,omitempty"` // Go time layout for absolute dates, e.g. "2006-01-02 15:04"; default depends on the locale
+	Clock  string `toml:"clock,omitempty"`  // "24h" or "12h"; default depends on the locale
+	Locale string `toml:"locale,omitempty"` // e.g. "de_DE"; unset = LC_ALL, LC_TIME or LANG
+}
+
+// GlyphSettings overrides the symbols used for repository status indicators.
+// Empty fields keep the value from the selected preset.
+type GlyphSettings struct {
+	Preset  string `toml:"preset,omitempty"` // "unicode" (default), "nerdfont" or "ascii"; takes precedence over ui.icons
+	Dirty   string `toml:"dirty,omitempty"`
+	Clean   string `toml:"clean,omitempty"`
+	Loading string `toml:"loading,omitempty"`
+	Unknown string `toml:"unknown,omitempty"`
+	Ahead   string `toml:"ahead,omitempty"`
+	Behind  string `toml:"behind,omitempty"`
+
+	// Change counts next to the branch
+	Staged     string `toml:"staged,omitempty"`
+	Modified   string `toml:"modified,omitempty"`
+	Untracked  string `toml:"untracked,omitempty"`
+	Conflicted string `toml:"conflicted,omitempty"`
+	Stashed    string `toml:"stashed,omitempty"`
+
+	Gutter string `toml:"gutter,omitempty"` // marker beside the repositories of a colored group
+	Branch string `toml:"branch,omitempty"` // icon before the branch
+}
+
+// UpstreamSettings controls which ref ahead/behind counts are computed against
+type UpstreamSettings struct {
+	// Remote is tried before "origin" when a branch has no tracking configuration
+	Remote string `toml:"remote,omitempty"`
+	// Overrides maps a repository path to an explicit upstream ref (e.g. "upstream/main")
+	Overrides map[string]string `toml:"overrides,omitempty"`
+}
+
+// RemoteSettings configures how remote URLs are rewritten between HTTPS and SSH
+type RemoteSettings struct {
+	// Templates maps a host to the URL templates used when converting its remotes.
+	// Templates may use {host} and {path}, e.g. "ssh://git@{host}:7999/{path}.git".
+	Templates map[string]RemoteTemplate `toml:"templates,omitempty"`
+}
+
+// RemoteTemplate holds the SSH and HTTPS URL templates for one host
+type RemoteTemplate struct {
+	SSH   string `toml:"ssh,omitempty"`
+	HTTPS string `toml:"https,omitempty"`
+}
+
+// EnvironmentSettings controls how programs launched from gitagrip (lazygit, shells,
+// editors) pick up a repository's environment
+type EnvironmentSettings struct {
+	// Activator is "auto" (default: direnv for .envrc, mise for .mise.toml), "direnv", "mise" or "none"
+	Activator string `toml:"activator,omitempty"`
+}
+
+// CacheSettings controls the on-disk cache of last-known repository statuses
+type CacheSettings struct {
+	Disable bool `toml:"disable,omitempty"` // always start with empty statuses
+}
+
+// GuardSettings are checks evaluated before running operations on a repository.
+// By default checkout and pull refuse to run on repositories with uncommitted changes.
+type GuardSettings struct {
+	AllowDirty       bool   `toml:"allow_dirty,omitempty"`       // run checkout/pull on dirty repos anyway
+	StashFirst       bool   `toml:"stash_first,omitempty"`       // stash local changes around checkout/pull instead of skipping
+	ProtectedLabel   string `toml:"protected_label,omitempty"`   // repos with this label are never pushed (default "protected")
+	PredictConflicts bool   `toml:"predict_conflicts,omitempty"` // trial-merge before pulling and skip repos that would conflict
+}
+
+// ComplianceSettings configures the checks run by `gitagrip compliance`
+type ComplianceSettings struct {
+	RequiredFiles    []string `toml:"required_files,omitempty"`    // files every repo must have (default LICENSE, SECURITY.md, CODEOWNERS)
+	BranchProtection bool     `toml:"branch_protection,omitempty"` // check that origin's default branch is protected
+	APIURL           string   `toml:"api_url,omitempty"`           // forge API for branch protection (default https://api.github.com)
+}
+
+// StaleSettings flags repositories that look abandoned with a "stale" badge (matched by is:stale)
+type StaleSettings struct {
+	GoneUpstream bool   `toml:"gone_upstream,omitempty"` // the current branch's upstream was deleted on the remote
+	After        string `toml:"after,omitempty"`         // last commit older than this, e.g. "90d", "8w" or "720h" (off when empty)
+}
+
+// MaxAge parses After into a duration (zero when unset). Besides Go durations it accepts
+// whole days ("90d") and weeks ("8w").
+func (s StaleSettings) MaxAge() (time.Duration, error) {
+	if s.After == "" {
+		return 0, nil
+	}
+	unit := map[byte]time.Duration{'d': 24 * time.Hour, 'w': 7 * 24 * time.Hour}[s.After[len(s.After)-1]]
+	if unit != 0 {
+		n, err := strconv.Atoi(s.After[:len(s.After)-1])
+		if err != nil || n <= 0 {
+			return 0, fmt.Errorf("stale.after: invalid age %q (use e.g. 90d, 8w or 720h)", s.After)
+		}
+		return time.Duration(n) * unit, nil
+	}
+	age, err := time.ParseDuration(s.After)
+	if err != nil || age <= 0 {
+		return 0, fmt.Errorf("stale.after: invalid age %q (use e.g. 90d, 8w or 720h)", s.After)
+	}
+	return age, nil
+}
+
+// ArchiveSettings controls where archived repositories go
+type ArchiveSettings struct {
+	Dir string `toml:"dir,omitempty"` // receives each archived repository and its bundle (default <base_dir>/.archive)
+}
+
+// GitSettings selects how gitagrip talks to repositories
+type GitSettings struct {
+	Backend string `toml:"backend,omitempty"` // "cli" (default) runs the git command, so SSH agents and credential helpers work as in a shell
+}
+
+// FetchSettings limits how hard fetching many repositories hits their remotes. Unset or
+// non-positive values take the defaults.
+type FetchSettings struct {
+	Concurrency int `toml:"concurrency,omitempty"` // repositories fetched at once (default 4)
+	PerHost     int `toml:"per_host,omitempty"`    // fetches at once against one remote host (default 2)
+	Attempts    int `toml:"attempts,omitempty"`    // tries per repository when the network fails transiently (default 3)
+}
+
+// MaxConcurrent returns how many repositories are fetched at once
+func (f FetchSettings) MaxConcurrent() int {
+	if f.Concurrency > 0 {
+		return f.Concurrency
+	}
+	return 4
+}
+
+// MaxPerHost returns how many fetches run at once against one remote host
+func (f FetchSettings) MaxPerHost() int {
+	if f.PerHost > 0 {
+		return f.PerHost
+	}
+	return 2
+}
+
+// MaxAttempts returns how often a fetch is tried before its failure is reported
+func (f FetchSettings) MaxAttempts() int {
+	if f.Attempts > 0 {
+		return f.Attempts
+	}
+	return 3
+}
+
+// CloneOrgSettings configures listing organizations for `gitagrip clone-org` and the
+// clone wizard (o)
+type CloneOrgSettings struct {
+	GitHubAPI string `toml:"github_api,omitempty"` // GitHub Enterprise API, e.g. https://github.example.com/api/v3
+	GitLabAPI string `toml:"gitlab_api,omitempty"` // self-hosted GitLab API, e.g. https://gitlab.example.com/api/v4
+	SSH       bool   `toml:"ssh,omitempty"`        // clone over ssh rather than https
+}
+
+// APIURL returns the API configured for a forge ("github" or "gitlab"), "" for the hosted one
+func (s CloneOrgSettings) APIURL(host string) string {
+	if host == "gitlab" {
+		return s.GitLabAPI
+	}
+	return s.GitHubAPI
+}
+
+// ScanSettings bound repository discovery, so that a base_dir such as $HOME stops
+// cleanly instead of walking tens of thousands of directories
+type ScanSettings struct {
+	MaxRepos int      `toml:"max_repos,omitempty"` // stop after finding this many repositories (0 = 5000, -1 = no limit)
+	MaxDepth int      `toml:"max_depth,omitempty"` // directory levels scanned below each scanned directory (0 = 5)
+	Exclude  []string `toml:"exclude,omitempty"`   // directory names or paths never scanned, glob patterns allowed, e.g. ["go", "~/Library"]
+}
+
+// Default scan limits
+const (
+	DefaultScanMaxRepos = 5000
+	DefaultScanMaxDepth = 5
+)
+
+// Limits returns the most repositories a scan finds (-1 for no limit) and how many
+// directory levels it goes down
+func (s ScanSettings) Limits() (maxRepos, maxDepth int) {
+	maxRepos, maxDepth = s.MaxRepos, s.MaxDepth
+	if maxRepos == 0 {
+		maxRepos = DefaultScanMaxRepos
+	}
+	if maxDepth == 0 {
+		maxDepth = DefaultScanMaxDepth
+	}
+	return maxRepos, maxDepth
+}
+
+// Excluded reports whether a directory matches one of scan.exclude: patterns with a path
+// separator (or a leading ~) are matched against the whole path, others against its name
+func (s ScanSettings) Excluded(dir string) bool {
+	for _, pattern := range s.Exclude {
+		target := filepath.Base(dir)
+		if strings.HasPrefix(pattern, "~") {
+			if home, err := os.UserHomeDir(); err == nil {
+				pattern = home + pattern[1:]
+			}
+		}
+		if strings.ContainsAny(pattern, "/"+string(filepath.Separator)) {
+			target, pattern = paths.Key(dir), paths.Key(pattern)
+		}
+		if ok, _ := filepath.Match(pattern, target); ok {
+			return true
+		}
+	}
+	return false
+}
+
+// Validate reports limits below -1 (or below 0 for the depth) and malformed patterns
+func (s ScanSettings) Validate() error {
+	var errs []error
+	if s.MaxRepos < -1 {
+		errs = append(errs, fmt.Errorf("scan.max_repos: %d is not a limit (use a count, or -1 for none)", s.MaxRepos))
+	}
+	if s.MaxDepth < 0 {
+		errs = append(errs, fmt.Errorf("scan.max_depth: %d is below zero", s.MaxDepth))
+	}
+	for _, pattern := range s.Exclude {
+		if _, err := filepath.Match(pattern, ""); err != nil {
+			errs = append(errs, fmt.Errorf("scan.exclude: bad pattern %q", pattern))
+		}
+	}
+	return errors.Join(errs...)
+}
+
+// StatusSettings trade status detail for speed, e.g. on network filesystems where looking
+// for untracked files is slow
+type StatusSettings struct {
+	Mode   string            `toml:"mode,omitempty"`   // "full" (default), "fast" (no untracked files) or "branch-only" (no working tree)
+	Groups map[string]string `toml:"groups,omitempty"` // group name -> mode for its repositories, overriding mode
+	Lazy   bool              `toml:"lazy,omitempty"`   // read only the repositories on screen until the others are scrolled to or refreshed
+}
+
+// Validate reports modes that aren't full, fast or branch-only
+func (s StatusSettings) Validate() error {
+	var errs []error
+	check := func(key, mode string) {
+		switch mode {
+		case "", domain.StatusModeFull, domain.StatusModeFast, domain.StatusModeBranchOnly:
+		default:
+			errs = append(errs, fmt.Errorf("%s: unknown mode %q (use full, fast or branch-only)", key, mode))
+		}
+	}
+	check("status.mode", s.Mode)
+	groups := make([]string, 0, len(s.Groups))
+	for group := range s.Groups {
+		groups = append(groups, group)
+	}
+	sort.Strings(groups)
+	for _, group := range groups {
+		check(fmt.Sprintf("status.groups.%q", group), s.Groups[group])
+	}
+	return errors.Join(errs...)
+}
+
+// GroupStartup says what a group's repositories do when gitagrip starts
+type GroupStartup struct {
+	FetchOnStart   bool   `toml:"fetch_on_start,omitempty"`  // fetch them once the first scan is done
+	StatusPriority string `toml:"status_priority,omitempty"` // when their status is first read: "high", "normal" (default), "low" or "lazy"
+}
+
+// statusPriorities ranks status priorities, most urgent first
+var statusPriorities = []string{domain.StatusPriorityHigh, domain.StatusPriorityNormal, domain.StatusPriorityLow, domain.StatusPriorityLazy}
+
+// ValidateGroupStartup reports status priorities that aren't high, normal, low or lazy
+func (c *Config) ValidateGroupStartup() error {
+	groups := make([]string, 0, len(c.GroupStartup))
+	for group := range c.GroupStartup {
+		groups = append(groups, group)
+	}
+	sort.Strings(groups)
+	var errs []error
+	for _, group := range groups {
+		if priority := c.GroupStartup[group].StatusPriority; priority != "" && !slices.Contains(statusPriorities, priority) {
+			errs = append(errs, fmt.Errorf("group_startup.%q.status_priority: unknown priority %q (use high, normal, low or lazy)", group, priority))
+		}
+	}
+	return errors.Join(errs...)
+}
+
+// StatusPriority returns when a repository's status is first read: the most urgent
+// priority of its groups, where a group without one counts as normal. A repository in no
+// group is normal too.
+func (c *Config) StatusPriority(repoPath string) string {
+	rank := -1
+	for name, members := range c.Groups {
+		if !containsPath(members, repoPath) {
+			continue
+		}
+		priority := c.GroupStartup[name].StatusPriority
+		if priority == "" {
+			priority = domain.StatusPriorityNormal
+		}
+		if r := slices.Index(statusPriorities, priority); r >= 0 && (rank < 0 || r < rank) {
+			rank = r
+		}
+	}
+	if rank < 0 {
+		return domain.StatusPriorityNormal
+	}
+	return statusPriorities[rank]
+}
+
+// FetchOnStart reports whether any group of a repository fetches on start
+func (c *Config) FetchOnStart(repoPath string) bool {
+	for name, settings := range c.GroupStartup {
+		if settings.FetchOnStart && containsPath(c.Groups[name], repoPath) {
+			return true
+		}
+	}
+	return false
+}
+
+// StatusOptions returns how much of a repository a status refresh reads: the mode of its
+// group, or the global one. A repository in several groups with modes gets the first of
+// them by name.
+func (c *Config) StatusOptions(repoPath string) domain.StatusOptions {
+	groups := make([]string, 0, len(c.Status.Groups))
+	for group := range c.Status.Groups {
+		groups = append(groups, group)
+	}
+	sort.Strings(groups)
+	for _, group := range groups {
+		if containsPath(c.Groups[group], repoPath) {
+			return domain.StatusOptions{Mode: c.Status.Groups[group]}
+		}
+	}
+	return domain.StatusOptions{Mode: c.Status.Mode}
+}
+
+// Git backends
+const (
+	GitBackendCLI     = "cli"
+	GitBackendLibgit2 = "libgit2"
+)
+
+// ProtectedLabelName returns the label of repositories that are never pushed
+func (g GuardSettings) ProtectedLabelName() string {
+	if g.ProtectedLabel != "" {
+		return g.ProtectedLabel
+	}
+	return "protected"
+}
+
+// BackendName returns the git backend to use. Only the git command is built in; libgit2
+// is refused rather than silently replaced, since the two resolve credentials differently.
+func (g GitSettings) BackendName() (string, error) {
+	switch g.Backend {
+	case "", GitBackendCLI:
+		return GitBackendCLI, nil
+	case GitBackendLibgit2:
+		return "", fmt.Errorf("git.backend: %q is not available in this build (use %q)", g.Backend, GitBackendCLI)
+	}
+	return "", fmt.Errorf("git.backend: unknown backend %q (use %q)", g.Backend, GitBackendCLI)
+}
+
+// ArchiveDir returns the directory archived repositories are moved to
+func (c *Config) ArchiveDir() string {
+	if c.Archive.Dir != "" {
+		return c.Archive.Dir
+	}
+	return filepath.Join(c.BaseDir, ".archive")
+}
+
+// Grouping strategies for repositories found by directory
+const (
+	GroupingParent = "parent" // group by the immediate parent directory
+	GroupingPath   = "path"   // group by the parent's path relative to base_dir
+	GroupingFlat   = "flat"   // no automatic groups
+)
+
+// GroupingSettings controls how repositories are grouped by directory when a workspace
+// is created and by `gitagrip group auto`
+type GroupingSettings struct {
+	Strategy string `toml:"strategy,omitempty"` // "parent" (default), "path" or "flat"
+}
+
+// RepoSettings holds settings for a single repository
+type RepoSettings struct {
+	Labels []string `toml:"labels,omitempty"` // tags shown as badges in the list and matched by tag: searches
+	Name   string   `toml:"name,omitempty"`   // shown instead of the directory name
+	Group  string   `toml:"group,omitempty"`  // group the repository always belongs to
+	URL    string   `toml:"url,omitempty"`    // where `gitagrip clone-missing` clones it from when it's absent
+}
+
+// ApplyRepoGroups moves repositories with a group override into that group (creating it
+// if needed), taking them out of any other group
+func (c *Config) ApplyRepoGroups() {
+	repoPaths := make([]string, 0, len(c.Repos))
+	for path := range c.Repos {
+		repoPaths = append(repoPaths, path)
+	}
+	sort.Strings(repoPaths)
+
+	for _, path := range repoPaths {
+		target := c.Repos[path].Group
+		if target == "" || c.IsForgotten(path) {
+			continue
+		}
+		if c.Groups == nil {
+			c.Groups = make(map[string][]string)
+		}
+		for name, members := range c.Groups {
+			if name == target {
+				continue
+			}
+			kept := members[:0]
+			for _, p := range members {
+				if !paths.Equal(p, path) {
+					kept = append(kept, p)
+				}
+			}
+			c.Groups[name] = kept
+		}
+		if _, exists := c.Groups[target]; !exists {
+			c.GroupOrder = append(c.GroupOrder, target)
+		}
+		if !containsPath(c.Groups[target], path) {
+			c.Groups[target] = append(c.Groups[target], path)
+		}
+	}
+}
+
+// containsPath reports whether list holds path, however spelled
+func containsPath(list []string, path string) bool {
+	for _, p := range list {
+		if paths.Equal(p, path) {
+			return true
+		}
+	}
+	return false
+}
+
+// normalizePaths gives the repository paths of the config their spelling on this platform
+// (see paths.Normalize), so that they match the paths scans find
+func (c *Config) normalizePaths() {
+	c.BaseDir = paths.Normalize(c.BaseDir)
+	for name, members := range c.Groups {
+		normalized := make([]string, 0, len(members))
+		for _, p := range members {
+			if !containsPath(normalized, p) {
+				normalized = append(normalized, paths.Normalize(p))
+			}
+		}
+		c.Groups[name] = normalized
+	}
+	if len(c.Repos) > 0 {
+		repos := make(map[string]RepoSettings, len(c.Repos))
+		for p, settings := range c.Repos {
+			repos[paths.Normalize(p)] = settings
+		}
+		c.Repos = repos
+	}
+	for i, p := range c.Forgotten {
+		c.Forgotten[i] = paths.Normalize(p)
+	}
+	if len(c.Upstream.Overrides) > 0 {
+		overrides := make(map[string]string, len(c.Upstream.Overrides))
+		for p, ref := range c.Upstream.Overrides {
+			overrides[paths.Normalize(p)] = ref
+		}
+		c.Upstream.Overrides = overrides
+	}
+}
+
+// CanonicalPath returns the spelling the config uses for a repository path found by a
+// scan, so that its [groups], [repos] and forgotten entries apply: on Windows a path
+// written in another case in the config is the same repository
+func (c *Config) CanonicalPath(repoPath string) string {
+	repoPath = paths.Normalize(repoPath)
+	if _, ok := c.Repos[repoPath]; ok || !paths.IgnoresCase() {
+		return repoPath
+	}
+	for p := range c.Repos {
+		if paths.Equal(p, repoPath) {
+			return p
+		}
+	}
+	for _, members := range c.Groups {
+		for _, p := range members {
+			if paths.Equal(p, repoPath) {
+				return p
+			}
+		}
+	}
+	for _, p := range c.Forgotten {
+		if paths.Equal(p, repoPath) {
+			return p
+		}
+	}
+	return repoPath
+}
+
+// containsString reports whether list contains value
+func containsString(list []string, value string) bool {
+	for _, v := range list {
+		if v == value {
+			return true
+		}
+	}
+	return false
+}
+
+// WatchSettings registers a branch whose new upstream commits are reported after
+// each fetch, in one repository (path or directory name) or every repository of a group
+type WatchSettings struct {
+	Repo   string `toml:"repo,omitempty"`
+	Group  string `toml:"group,omitempty"`
+	Branch string `toml:"branch"`
+}
+
+// WatchedBranches returns the branches watched in a repository
+func (c *Config) WatchedBranches(repoPath string) []string {
+	var branches []string
+	seen := make(map[string]bool)
+	for _, w := range c.Watches {
+		if w.Branch == "" || seen[w.Branch] {
+			continue
+		}
+		matches := w.Repo != "" && (w.Repo == repoPath || w.Repo == filepath.Base(repoPath))
+		if !matches && w.Group != "" {
+			for _, p := range c.Groups[w.Group] {
+				if p == repoPath {
+					matches = true
+					break
+				}
+			}
+		}
+		if matches {
+			seen[w.Branch] = true
+			branches = append(branches, w.Branch)
+		}
+	}
+	return branches
+}
+
+// RenameGroupSettings moves settings that refer to a group by name (group env vars and
+// colors, status modes, startup settings, watches and rules) to its new name. Group membership itself lives
+// in Groups.
+func (c *Config) RenameGroupSettings(oldName, newName string) {
+	if env, ok := c.GroupEnv[oldName]; ok {
+		c.GroupEnv[newName] = env
+		delete(c.GroupEnv, oldName)
+	}
+	if color, ok := c.GroupColors[oldName]; ok {
+		c.GroupColors[newName] = color
+		delete(c.GroupColors, oldName)
+	}
+	if mode, ok := c.Status.Groups[oldName]; ok {
+		c.Status.Groups[newName] = mode
+		delete(c.Status.Groups, oldName)
+	}
+	if startup, ok := c.GroupStartup[oldName]; ok {
+		c.GroupStartup[newName] = startup
+		delete(c.GroupStartup, oldName)
+	}
+	for i := range c.Watches {
+		if c.Watches[i].Group == oldName {
+			c.Watches[i].Group = newName
+		}
+	}
+	for i := range c.Rules {
+		if c.Rules[i].Group == oldName {
+			c.Rules[i].Group = newName
+		}
+	}
+}
+
+// HasLabel reports whether a repository carries the given label
+func (c *Config) HasLabel(repoPath, label string) bool {
+	for _, l := range c.Repos[repoPath].Labels {
+		if l == label {
+			return true
+		}
+	}
+	return false
+}
+
+// SetLabels replaces the labels of a repository, dropping its [repos] entry once it has
+// no settings left
+func (c *Config) SetLabels(repoPath string, labels []string) {
+	settings := c.Repos[repoPath]
+	settings.Labels = labels
+	if len(settings.Labels) == 0 && settings.Name == "" && settings.Group == "" && settings.URL == "" {
+		delete(c.Repos, repoPath)
+		return
+	}
+	if c.Repos == nil {
+		c.Repos = make(map[string]RepoSettings)
+	}
+	c.Repos[repoPath] = settings
+}
+
+// AddClone records in [repos] that the repository at dir is cloned from url and belongs to
+// group, for clone-missing to clone it. A repository already there with a url is left as
+// it is; AddClone reports whether it added one.
+func (c *Config) AddClone(dir, url, group string) bool {
+	dir = paths.Normalize(dir)
+	for p, settings := range c.Repos {
+		if paths.Equal(p, dir) && settings.URL != "" {
+			return false
+		}
+	}
+	if c.Repos == nil {
+		c.Repos = make(map[string]RepoSettings)
+	}
+	settings := c.Repos[dir]
+	settings.URL = url
+	if settings.Group == "" {
+		settings.Group = group
+	}
+	c.Repos[dir] = settings
+	return true
+}
+
+// IsForgotten reports whether a repository was forgotten, i.e. is left out of scans
+func (c *Config) IsForgotten(repoPath string) bool {
+	return containsPath(c.Forgotten, repoPath)
+}
+
+// Forget leaves a repository out of later scans. Its [repos] settings are kept.
+func (c *Config) Forget(repoPath string) {
+	if !c.IsForgotten(repoPath) {
+		c.Forgotten = append(c.Forgotten, repoPath)
+	}
+}
+
+// Unforget lets scans find a forgotten repository again
+func (c *Config) Unforget(repoPath string) {
+	kept := make([]string, 0, len(c.Forgotten))
+	for _, path := range c.Forgotten {
+		if !paths.Equal(path, repoPath) {
+			kept = append(kept, path)
+		}
+	}
+	c.Forgotten = kept
+}
+
+// MissingPaths returns the repository paths in [groups] and [repos] whose directory is
+// gone, sorted. Repositories with a url are left out: they are cloned by clone-missing.
+func (c *Config) MissingPaths() []string {
+	seen := make(map[string]bool)
+	var missing []string
+	check := func(path string) {
+		if seen[path] || c.IsForgotten(path) || c.Repos[path].URL != "" {
+			return
+		}
+		seen[path] = true
+		dir := path
+		if !filepath.IsAbs(dir) && c.BaseDir != "" {
+			dir = filepath.Join(c.BaseDir, dir)
+		}
+		if _, err := os.Stat(dir); os.IsNotExist(err) {
+			missing = append(missing, path)
+		}
+	}
+	for _, members := range c.Groups {
+		for _, path := range members {
+			check(path)
+		}
+	}
+	for path := range c.Repos {
+		check(path)
+	}
+	sort.Strings(missing)
+	return missing
+}
+
+// GroupEnvFor returns the environment variables ("KEY=value") defined for the groups a
+// repository belongs to, sorted by group and variable name
+func (c *Config) GroupEnvFor(repoPath string) []string {
+	var groupNames []string
+	for name, members := range c.Groups {
+		if _, hasEnv := c.GroupEnv[name]; !hasEnv {
+			continue
+		}
+		if containsPath(members, repoPath) {
+			groupNames = append(groupNames, name)
+		}
+	}
+	sort.Strings(groupNames)
+
+	var env []string
+	for _, name := range groupNames {
+		vars := c.GroupEnv[name]
+		keys := make([]string, 0, len(vars))
+		for key := range vars {
+			keys = append(keys, key)
+		}
+		sort.Strings(keys)
+		for _, key := range keys {
+			env = append(env, key+"="+vars[key])
+		}
+	}
+	return env
 }
 
 // ConfigService handles configuration management
@@ -100,6 +877,7 @@ func (cs *configService) Load() (*Config, error) {
 	if cfg.Groups == nil {
 		cfg.Groups = make(map[string][]string)
 	}
+	cfg.normalizePaths()
 
 	// Publish ConfigLoaded event if bus is available
 	if cs.bus != nil {
@@ -162,6 +940,7 @@ func (cs *configService) LoadFromPath(path string) (*Config, error) {
 	if cfg.Groups == nil {
 		cfg.Groups = make(map[string][]string)
 	}
+	cfg.normalizePaths()
 
 	return &cfg, nil
 }
@@ -0,0 +1,50 @@
+package config
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+)
+
+// ProfilesDir is where named workspace configs live (gitagrip -profile <name>)
+func ProfilesDir() string {
+	configDir, err := os.UserConfigDir()
+	if err != nil {
+		// Fallback to home directory
+		configDir, err = os.UserHomeDir()
+		if err != nil {
+			configDir = "."
+		}
+		configDir = filepath.Join(configDir, ".config")
+	}
+	return filepath.Join(configDir, "gitagrip", "profiles")
+}
+
+// ProfilePath returns the config file of the named profile
+func ProfilePath(name string) (string, error) {
+	if name == "" || name == "." || name == ".." || strings.ContainsAny(name, `/\`) {
+		return "", fmt.Errorf("invalid profile name %q", name)
+	}
+	return filepath.Join(ProfilesDir(), name+".toml"), nil
+}
+
+// ListProfiles returns the names of the saved profiles, sorted
+func ListProfiles() ([]string, error) {
+	entries, err := os.ReadDir(ProfilesDir())
+	if os.IsNotExist(err) {
+		return nil, nil
+	}
+	if err != nil {
+		return nil, err
+	}
+	var names []string
+	for _, entry := range entries {
+		if name, ok := strings.CutSuffix(entry.Name(), ".toml"); ok && !entry.IsDir() && name != "" {
+			names = append(names, name)
+		}
+	}
+	sort.Strings(names)
+	return names, nil
+}
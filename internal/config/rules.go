@@ -0,0 +1,71 @@
+package config
+
+import (
+	"path"
+	"path/filepath"
+	"strings"
+)
+
+// GroupRule places repositories that are not in any group yet into a group when they
+// are discovered. Every condition that is set must match; the first matching rule wins.
+type GroupRule struct {
+	Pattern string `toml:"pattern,omitempty"` // glob on the path relative to base_dir; ** spans directories
+	Remote  string `toml:"remote,omitempty"`  // text the origin URL must contain, e.g. "github.com/myorg"
+	Group   string `toml:"group"`
+}
+
+// RuleGroupFor returns the group the first matching rule assigns a repository to.
+// remoteURL is "" while the repository's remote is not known yet; rules with a remote
+// condition don't match until it is.
+func (c *Config) RuleGroupFor(repoPath, remoteURL string) (string, bool) {
+	for _, rule := range c.Rules {
+		if rule.Group == "" || (rule.Pattern == "" && rule.Remote == "") {
+			continue
+		}
+		if rule.Remote != "" && (remoteURL == "" || !strings.Contains(remoteURL, rule.Remote)) {
+			continue
+		}
+		if rule.Pattern != "" && !matchRulePattern(rule.Pattern, c.relativeRepoPath(repoPath)) {
+			continue
+		}
+		return rule.Group, true
+	}
+	return "", false
+}
+
+// relativeRepoPath returns a repository path relative to base_dir with forward slashes
+func (c *Config) relativeRepoPath(repoPath string) string {
+	if c.BaseDir != "" {
+		if rel, err := filepath.Rel(c.BaseDir, repoPath); err == nil && !strings.HasPrefix(rel, "..") {
+			return filepath.ToSlash(rel)
+		}
+	}
+	return filepath.ToSlash(repoPath)
+}
+
+// matchRulePattern matches a slash-separated path against a glob in which "**" stands
+// for any number of directories (including none)
+func matchRulePattern(pattern, name string) bool {
+	return matchSegments(strings.Split(pattern, "/"), strings.Split(name, "/"))
+}
+
+func matchSegments(pattern, name []string) bool {
+	for len(pattern) > 0 {
+		if pattern[0] == "**" {
+			for i := 0; i <= len(name); i++ {
+				if matchSegments(pattern[1:], name[i:]) {
+					return true
+				}
+			}
+			return false
+		}
+		if len(name) == 0 {
+			return false
+		}
+		if ok, _ := path.Match(pattern[0], name[0]); !ok {
+			return false
+		}
+		pattern, name = pattern[1:], name[1:]
+	}
+	return len(name) == 0
+}
@@ -0,0 +1,80 @@
+package config
+
+import (
+	"reflect"
+	"strings"
+
+	"github.com/darksworm/gitagrip/internal/domain"
+)
+
+// schemaEnums lists the accepted values of free-form string settings, keyed by dotted TOML path
+var schemaEnums = map[string][]string{
+	"ui.glyphs.preset":      {"unicode", "nerdfont", "ascii"},
+	"ui.terminal.color":     {"auto", "truecolor", "256", "16", "none"},
+	"environment.activator": {"auto", "direnv", "mise", "none"},
+	"theme.palette":         {"dark", "light", "solarized"},
+	"grouping.strategy":     {GroupingParent, GroupingPath, GroupingFlat},
+	"ui.dates.style":        {"absolute", "relative"},
+	"ui.dates.clock":        {"24h", "12h"},
+	"ui.sort":               {"name", "status", "ahead-behind", "last-commit", "branch"},
+	"git.backend":           {GitBackendCLI, GitBackendLibgit2},
+	"status.mode":           {domain.StatusModeFull, domain.StatusModeFast, domain.StatusModeBranchOnly},
+	"status.groups.*":       {domain.StatusModeFull, domain.StatusModeFast, domain.StatusModeBranchOnly},
+
+	"group_startup.*.status_priority": {domain.StatusPriorityHigh, domain.StatusPriorityNormal, domain.StatusPriorityLow, domain.StatusPriorityLazy},
+}
+
+// Schema returns a JSON Schema (draft 2020-12) describing .gitagrip.toml. It is built
+// from the Config struct and its toml tags, so it always matches what gitagrip reads.
+func Schema() map[string]any {
+	schema := schemaFor(reflect.TypeOf(Config{}), "")
+	schema["$schema"] = "https://json-schema.org/draft/2020-12/schema"
+	schema["title"] = "gitagrip workspace configuration (.gitagrip.toml)"
+	return schema
+}
+
+// schemaFor describes a Go type; path is the dotted TOML key of the value
+func schemaFor(t reflect.Type, path string) map[string]any {
+	if t.Kind() == reflect.Pointer {
+		t = t.Elem()
+	}
+
+	switch t.Kind() {
+	case reflect.String:
+		s := map[string]any{"type": "string"}
+		if enum, ok := schemaEnums[path]; ok {
+			s["enum"] = enum
+		}
+		return s
+	case reflect.Bool:
+		return map[string]any{"type": "boolean"}
+	case reflect.Int, reflect.Int8, reflect.Int16, reflect.Int32, reflect.Int64,
+		reflect.Uint, reflect.Uint8, reflect.Uint16, reflect.Uint32, reflect.Uint64:
+		return map[string]any{"type": "integer"}
+	case reflect.Float32, reflect.Float64:
+		return map[string]any{"type": "number"}
+	case reflect.Slice, reflect.Array:
+		return map[string]any{"type": "array", "items": schemaFor(t.Elem(), path)}
+	case reflect.Map:
+		return map[string]any{"type": "object", "additionalProperties": schemaFor(t.Elem(), path+".*")}
+	case reflect.Struct:
+		properties := make(map[string]any)
+		for i := 0; i < t.NumField(); i++ {
+			field := t.Field(i)
+			name, _, _ := strings.Cut(field.Tag.Get("toml"), ",")
+			if !field.IsExported() || name == "-" {
+				continue
+			}
+			if name == "" {
+				name = field.Name
+			}
+			key := name
+			if path != "" {
+				key = path + "." + name
+			}
+			properties[name] = schemaFor(field.Type, key)
+		}
+		return map[string]any{"type": "object", "properties": properties, "additionalProperties": false}
+	}
+	return map[string]any{}
+}
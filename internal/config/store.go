@@ -0,0 +1,125 @@
+package config
+
+import (
+	"fmt"
+	"sync"
+
+	"github.com/darksworm/gitagrip/internal/domain"
+	"github.com/darksworm/gitagrip/internal/eventbus"
+)
+
+// Store persists the workspace config, e.g. to .gitagrip.toml or through a remote agent
+type Store interface {
+	Save(cfg *Config) error
+}
+
+// StoreFunc adapts a function to a Store
+type StoreFunc func(cfg *Config) error
+
+// Save calls f(cfg)
+func (f StoreFunc) Save(cfg *Config) error { return f(cfg) }
+
+// NewFileStore returns a Store that writes the config to a file
+func NewFileStore(svc ConfigService, path string) Store {
+	return StoreFunc(func(cfg *Config) error { return svc.SaveToPath(cfg, path) })
+}
+
+// MemoryStore is a Store that keeps what was saved in memory, for tests
+type MemoryStore struct {
+	mu    sync.Mutex
+	saved []Config
+	Err   error // returned by Save instead of saving when set
+}
+
+// Save records a copy of the config
+func (s *MemoryStore) Save(cfg *Config) error {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	if s.Err != nil {
+		return s.Err
+	}
+	s.saved = append(s.saved, *cfg)
+	return nil
+}
+
+// Saved returns the configs saved so far, oldest first
+func (s *MemoryStore) Saved() []Config {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	return append([]Config(nil), s.saved...)
+}
+
+// Saver applies the group changes of ConfigChangedEvents to the config and saves it
+// through a Store: right away with autosave_on_exit (the default), otherwise only when a
+// ConfigSaveRequestedEvent asks for it. Every save is followed by a ConfigSavedEvent.
+// The Saver's config is its own: whoever changes the config hands it a copy through
+// SetConfig before publishing the ConfigChangedEvent.
+type Saver struct {
+	mu    sync.Mutex
+	bus   eventbus.EventBus
+	cfg   *Config
+	store Store
+	dirty bool
+}
+
+// NewSaver subscribes a Saver for the config to the bus
+func NewSaver(bus eventbus.EventBus, cfg *Config, store Store) *Saver {
+	s := &Saver{bus: bus, cfg: cfg, store: store}
+	bus.Subscribe(eventbus.EventConfigChanged, func(e eventbus.DomainEvent) {
+		if event, ok := e.(eventbus.ConfigChangedEvent); ok {
+			s.Apply(event.Groups, event.GroupOrder)
+		}
+	})
+	bus.Subscribe(eventbus.EventConfigSaveRequested, func(e eventbus.DomainEvent) {
+		s.Save()
+	})
+	return s
+}
+
+// SetConfig replaces the config that is saved; the Saver keeps it and may change it
+func (s *Saver) SetConfig(cfg *Config) {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	s.cfg = cfg
+}
+
+// Apply updates the groups and their order, and saves them when autosaving
+func (s *Saver) Apply(groups map[string][]string, order []string) {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	s.cfg.Groups = groups
+	s.cfg.GroupOrder = order
+	s.dirty = true
+	if s.cfg.UISettings.AutosaveOnExit {
+		s.save()
+	}
+}
+
+// Save writes the config now and tells the user how it went
+func (s *Saver) Save() {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	if s.save() == nil {
+		s.bus.Publish(eventbus.AppMessageEvent{Level: domain.MessageSuccess, Text: "Config saved"})
+	}
+}
+
+// Dirty reports whether changes are waiting to be saved
+func (s *Saver) Dirty() bool {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	return s.dirty
+}
+
+// save writes the config (s.mu held). Failures are always reported to the user, since
+// autosaves happen unasked.
+func (s *Saver) save() error {
+	err := s.store.Save(s.cfg)
+	if err == nil {
+		s.dirty = false
+	} else {
+		s.bus.Publish(eventbus.AppMessageEvent{Level: domain.MessageError, Text: fmt.Sprintf("Saving config failed: %v", err)})
+	}
+	s.bus.Publish(eventbus.ConfigSavedEvent{Err: err})
+	return err
+}
@@ -0,0 +1,60 @@
+package config
+
+import (
+	"errors"
+	"reflect"
+	"testing"
+
+	"github.com/darksworm/gitagrip/internal/eventbus"
+)
+
+func TestSaverHonorsAutosave(t *testing.T) {
+	groups := map[string][]string{"Work": {"/code/api"}}
+
+	for _, autosave := range []bool{true, false} {
+		cfg := DefaultConfig()
+		cfg.UISettings.AutosaveOnExit = autosave
+		store := &MemoryStore{}
+		saver := NewSaver(eventbus.New(), cfg, store)
+
+		saver.Apply(groups, []string{"Work"})
+		if got, want := len(store.Saved()), map[bool]int{true: 1, false: 0}[autosave]; got != want {
+			t.Fatalf("autosave %t: %d saves after a change, want %d", autosave, got, want)
+		}
+		if saver.Dirty() == autosave {
+			t.Fatalf("autosave %t: dirty = %t after a change", autosave, saver.Dirty())
+		}
+
+		saver.Save()
+		saved := store.Saved()
+		if saver.Dirty() || !reflect.DeepEqual(saved[len(saved)-1].Groups, groups) {
+			t.Fatalf("autosave %t: groups not saved on request: %v", autosave, saved)
+		}
+	}
+}
+
+func TestSaverStaysDirtyWhenSavingFails(t *testing.T) {
+	cfg := DefaultConfig()
+	store := &MemoryStore{Err: errors.New("read-only file system")}
+	saver := NewSaver(eventbus.New(), cfg, store)
+
+	saver.Apply(map[string][]string{"Work": nil}, []string{"Work"})
+	if !saver.Dirty() {
+		t.Fatal("a change that failed to save should still be unsaved")
+	}
+}
+
+func TestSaverSavesTheConfigHandedToIt(t *testing.T) {
+	store := &MemoryStore{}
+	saver := NewSaver(eventbus.New(), DefaultConfig(), store)
+
+	changed := DefaultConfig()
+	changed.SetLabels("/code/api", []string{"backend"})
+	saver.SetConfig(changed)
+	saver.Apply(map[string][]string{"Work": {"/code/api"}}, []string{"Work"})
+
+	saved := store.Saved()
+	if len(saved) != 1 || !saved[0].HasLabel("/code/api", "backend") || len(saved[0].Groups["Work"]) != 1 {
+		t.Fatalf("saved %+v, want the handed config with the groups applied", saved)
+	}
+}
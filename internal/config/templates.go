@@ -0,0 +1,101 @@
+package config
+
+import (
+	"path/filepath"
+	"sort"
+)
+
+// GroupTemplate pre-populates a group created from it
+type GroupTemplate struct {
+	Description string            `toml:"description,omitempty"`
+	Match       []string          `toml:"match,omitempty"`  // name globs; matching ungrouped repositories join the group
+	Labels      []string          `toml:"labels,omitempty"` // added to every repository placed in the group
+	Env         map[string]string `toml:"env,omitempty"`    // becomes the group's [group_env] entry
+	Watch       []string          `toml:"watch,omitempty"`  // branches watched in every repository of the group
+	Fetch       bool              `toml:"fetch,omitempty"`  // fetch the group's repositories once it is created
+}
+
+// builtinGroupTemplates are available in every workspace; [group_templates] entries
+// with the same name replace them
+var builtinGroupTemplates = map[string]GroupTemplate{
+	"microservices": {
+		Description: "Service repositories, fetched right away with main watched",
+		Match:       []string{"*-service", "*-svc", "*-api"},
+		Labels:      []string{"service"},
+		Watch:       []string{"main"},
+		Fetch:       true,
+	},
+	"dotfiles": {
+		Description: "Dotfiles and machine configuration",
+		Match:       []string{"dotfiles", "dotfiles-*", ".dotfiles", "*-config"},
+		Labels:      []string{"dotfiles"},
+	},
+}
+
+// GroupTemplateNames returns the names of the built-in and configured templates, sorted
+func (c *Config) GroupTemplateNames() []string {
+	names := make([]string, 0, len(builtinGroupTemplates)+len(c.GroupTemplates))
+	for name := range builtinGroupTemplates {
+		names = append(names, name)
+	}
+	for name := range c.GroupTemplates {
+		if _, builtin := builtinGroupTemplates[name]; !builtin {
+			names = append(names, name)
+		}
+	}
+	sort.Strings(names)
+	return names
+}
+
+// GroupTemplate returns a template by name, preferring the configured one
+func (c *Config) GroupTemplate(name string) (GroupTemplate, bool) {
+	if t, ok := c.GroupTemplates[name]; ok {
+		return t, true
+	}
+	t, ok := builtinGroupTemplates[name]
+	return t, ok
+}
+
+// Matches reports whether a repository name matches one of the template's globs
+func (t GroupTemplate) Matches(name string) bool {
+	for _, pattern := range t.Match {
+		if ok, _ := filepath.Match(pattern, name); ok {
+			return true
+		}
+	}
+	return false
+}
+
+// ApplyGroupTemplate records a template's settings for a newly created group: labels on
+// its repositories, its environment variables and its watched branches
+func (c *Config) ApplyGroupTemplate(group string, t GroupTemplate, repoPaths []string) {
+	if len(t.Labels) > 0 {
+		if c.Repos == nil {
+			c.Repos = make(map[string]RepoSettings)
+		}
+		for _, path := range repoPaths {
+			settings := c.Repos[path]
+			for _, label := range t.Labels {
+				if !containsString(settings.Labels, label) {
+					settings.Labels = append(settings.Labels, label)
+				}
+			}
+			c.Repos[path] = settings
+		}
+	}
+
+	if len(t.Env) > 0 {
+		if c.GroupEnv == nil {
+			c.GroupEnv = make(map[string]map[string]string)
+		}
+		env := make(map[string]string, len(t.Env))
+		for key, value := range t.Env {
+			env[key] = value
+		}
+		c.GroupEnv[group] = env
+	}
+
+	for _, branch := range t.Watch {
+		c.Watches = append(c.Watches, WatchSettings{Group: group, Branch: branch})
+	}
+}
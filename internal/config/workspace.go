@@ -0,0 +1,53 @@
+package config
+
+import (
+	"errors"
+	"io/fs"
+	"os"
+	"path/filepath"
+)
+
+// Workspace is the part of a Config the engine package exposes as engine.Config: the base
+// directory and the groups. The other settings of a loaded .gitagrip.toml travel along
+// unexported, so the engine applies them without making them public API.
+type Workspace struct {
+	BaseDir string
+	Groups  map[string][]string // group name -> repository paths
+
+	settings *Config
+}
+
+// WorkspaceOf wraps cfg for the engine; the workspace shares cfg's groups
+func WorkspaceOf(cfg *Config) *Workspace {
+	return &Workspace{BaseDir: cfg.BaseDir, Groups: cfg.Groups, settings: cfg}
+}
+
+// SettingsOf returns the full settings behind a workspace, with its base directory and
+// groups applied. A workspace built by hand, or nil, gets the defaults.
+func SettingsOf(w *Workspace) *Config {
+	if w == nil {
+		return DefaultConfig()
+	}
+	cfg := w.settings
+	if cfg == nil {
+		cfg = DefaultConfig()
+		w.settings = cfg
+	}
+	cfg.BaseDir = w.BaseDir
+	if w.Groups != nil {
+		cfg.Groups = w.Groups
+	}
+	return cfg
+}
+
+// LoadWorkspace reads the .gitagrip.toml of a workspace directory. A workspace without
+// one gets the default settings with dir as its base directory.
+func LoadWorkspace(dir string) (*Config, error) {
+	path := filepath.Join(dir, ".gitagrip.toml")
+	if _, err := os.Stat(path); errors.Is(err, fs.ErrNotExist) {
+		cfg := DefaultConfig()
+		cfg.BaseDir = dir
+		return cfg, nil
+	}
+	return NewConfigService().LoadFromPath(path)
+}
@@ -0,0 +1,233 @@
+// Package control lets editor plugins and scripts drive a running gitagrip over a Unix
+// domain socket. A client writes one command per line, such as "refresh", "fetch
+// group:Work" or "jump repo:frontend", and gets one line back for each, starting with
+// "ok" or "error:". Windows 10 and later have Unix domain sockets too.
+package control
+
+import (
+	"bufio"
+	"crypto/sha1"
+	"encoding/hex"
+	"errors"
+	"fmt"
+	"log"
+	"net"
+	"os"
+	"path/filepath"
+	"strconv"
+	"strings"
+	"sync"
+	"time"
+)
+
+// Action is what a command does
+type Action string
+
+const (
+	ActionRefresh Action = "refresh" // refresh statuses
+	ActionFetch   Action = "fetch"   // fetch from the remotes
+	ActionJump    Action = "jump"    // move the cursor onto a repository or group
+)
+
+// Target selects what a command applies to
+type Target struct {
+	Kind string // "" for every repository, "group" or "repo"
+	Name string // group name, or repository name or path
+}
+
+// Request is a parsed command
+type Request struct {
+	Action Action
+	Target Target
+}
+
+// Parse parses a command: an action, optionally followed by group:<name> or
+// repo:<name or path>. The name is the rest of the line, so it may contain spaces.
+func Parse(line string) (Request, error) {
+	action, target, _ := strings.Cut(strings.TrimSpace(line), " ")
+	req := Request{Action: Action(action)}
+	switch req.Action {
+	case ActionRefresh, ActionFetch, ActionJump:
+	case "":
+		return req, errors.New("empty command")
+	default:
+		return req, fmt.Errorf("unknown command %q (use refresh, fetch or jump)", action)
+	}
+
+	if target = strings.TrimSpace(target); target != "" {
+		kind, name, ok := strings.Cut(target, ":")
+		if !ok || (kind != "group" && kind != "repo") || name == "" {
+			return req, fmt.Errorf("bad target %q (use group:<name> or repo:<name>)", target)
+		}
+		req.Target = Target{Kind: kind, Name: name}
+	}
+	if req.Action == ActionJump && req.Target.Kind == "" {
+		return req, errors.New("jump needs a target (group:<name> or repo:<name>)")
+	}
+	return req, nil
+}
+
+// String formats the request as it is written on the socket
+func (r Request) String() string {
+	if r.Target.Kind == "" {
+		return string(r.Action)
+	}
+	return string(r.Action) + " " + r.Target.Kind + ":" + r.Target.Name
+}
+
+// Handler carries out a request and returns what to report back
+type Handler func(Request) (string, error)
+
+// DefaultPath returns the socket of the instance managing a workspace directory. It lives
+// in $XDG_RUNTIME_DIR or in a private directory under the temp directory, which keeps it
+// short enough for the limit on socket paths.
+func DefaultPath(baseDir string) string {
+	dir := os.Getenv("XDG_RUNTIME_DIR")
+	if dir == "" {
+		dir = os.TempDir()
+	}
+	name := "gitagrip"
+	if uid := os.Getuid(); uid >= 0 {
+		name += "-" + strconv.Itoa(uid)
+	}
+	sum := sha1.Sum([]byte(baseDir))
+	return filepath.Join(dir, name, hex.EncodeToString(sum[:])[:16]+".sock")
+}
+
+// Server accepts commands on a socket until closed
+type Server struct {
+	listener net.Listener
+	path     string
+	handle   Handler
+
+	mu    sync.Mutex
+	conns map[net.Conn]bool
+	done  bool
+}
+
+// Listen creates the socket at path and serves commands on it in the background.
+// It fails when another running instance owns the socket, or when the socket's directory
+// is not a private one of the current user; a socket left behind by one that crashed is
+// replaced.
+func Listen(path string, handle Handler) (*Server, error) {
+	dir := filepath.Dir(path)
+	if err := os.MkdirAll(dir, 0o700); err != nil {
+		return nil, err
+	}
+	// The directory may sit in the shared temp directory, where someone else could have
+	// made it first to catch or take over the socket
+	if err := checkPrivateDir(dir); err != nil {
+		return nil, err
+	}
+	if conn, err := net.DialTimeout("unix", path, time.Second); err == nil {
+		_ = conn.Close()
+		return nil, fmt.Errorf("another gitagrip is listening on %s", path)
+	}
+	_ = os.Remove(path)
+
+	listener, err := net.Listen("unix", path)
+	if err != nil {
+		return nil, err
+	}
+	s := &Server{listener: listener, path: path, handle: handle, conns: make(map[net.Conn]bool)}
+	go s.serve()
+	return s, nil
+}
+
+// Path returns where the socket is
+func (s *Server) Path() string {
+	return s.path
+}
+
+// Close stops accepting commands, disconnects clients and removes the socket
+func (s *Server) Close() error {
+	s.mu.Lock()
+	s.done = true
+	for conn := range s.conns {
+		_ = conn.Close()
+	}
+	s.mu.Unlock()
+	// Closing a Unix listener also removes its socket file
+	return s.listener.Close()
+}
+
+func (s *Server) serve() {
+	for {
+		conn, err := s.listener.Accept()
+		if err != nil {
+			s.mu.Lock()
+			done := s.done
+			s.mu.Unlock()
+			if !done {
+				log.Printf("control: accept failed: %v", err)
+			}
+			return
+		}
+		s.mu.Lock()
+		if s.done {
+			s.mu.Unlock()
+			_ = conn.Close()
+			return
+		}
+		s.conns[conn] = true
+		s.mu.Unlock()
+		go s.serveConn(conn)
+	}
+}
+
+// serveConn answers the commands of one client, in order
+func (s *Server) serveConn(conn net.Conn) {
+	defer func() {
+		s.mu.Lock()
+		delete(s.conns, conn)
+		s.mu.Unlock()
+		_ = conn.Close()
+	}()
+
+	lines := bufio.NewScanner(conn)
+	for lines.Scan() {
+		if strings.TrimSpace(lines.Text()) == "" {
+			continue
+		}
+		reply := "ok"
+		req, err := Parse(lines.Text())
+		if err == nil {
+			var message string
+			message, err = s.handle(req)
+			if message != "" {
+				reply += ": " + message
+			}
+		}
+		if err != nil {
+			reply = "error: " + err.Error()
+		}
+		log.Printf("control: %q -> %s", lines.Text(), reply)
+		if _, err := fmt.Fprintln(conn, reply); err != nil {
+			return
+		}
+	}
+}
+
+// Send writes a command to the socket at path and returns the reply, which is an error
+// when the command failed
+func Send(path, command string) (string, error) {
+	conn, err := net.DialTimeout("unix", path, 2*time.Second)
+	if err != nil {
+		return "", fmt.Errorf("no gitagrip is running for this workspace (%w)", err)
+	}
+	defer func() { _ = conn.Close() }()
+	_ = conn.SetDeadline(time.Now().Add(30 * time.Second))
+
+	if _, err := fmt.Fprintln(conn, command); err != nil {
+		return "", err
+	}
+	reply, err := bufio.NewReader(conn).ReadString('\n')
+	if err != nil {
+		return "", fmt.Errorf("no reply: %w", err)
+	}
+	reply = strings.TrimSuffix(reply, "\n")
+	if message, failed := strings.CutPrefix(reply, "error: "); failed {
+		return "", errors.New(message)
+	}
+	return reply, nil
+}
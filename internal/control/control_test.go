@@ -0,0 +1,63 @@
+package control
+
+import (
+	"errors"
+	"os"
+	"path/filepath"
+	"testing"
+)
+
+func TestParse(t *testing.T) {
+	for _, tc := range []struct {
+		line string
+		want Request
+	}{
+		{"refresh", Request{Action: ActionRefresh}},
+		{"  fetch group:Work  ", Request{Action: ActionFetch, Target: Target{Kind: "group", Name: "Work"}}},
+		{"fetch group:Side Projects", Request{Action: ActionFetch, Target: Target{Kind: "group", Name: "Side Projects"}}},
+		{"jump repo:frontend", Request{Action: ActionJump, Target: Target{Kind: "repo", Name: "frontend"}}},
+	} {
+		got, err := Parse(tc.line)
+		if err != nil || got != tc.want {
+			t.Fatalf("Parse(%q) = %+v, %v, want %+v", tc.line, got, err, tc.want)
+		}
+	}
+	for _, line := range []string{"", "push", "jump", "fetch frontend", "fetch group:", "fetch branch:main"} {
+		if _, err := Parse(line); err == nil {
+			t.Fatalf("Parse(%q) should fail", line)
+		}
+	}
+}
+
+func TestServerAnswersEachCommand(t *testing.T) {
+	// Not t.TempDir(): its long names can exceed the limit on socket paths
+	dir, err := os.MkdirTemp("", "gitagrip")
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer func() { _ = os.RemoveAll(dir) }()
+	path := filepath.Join(dir, "control.sock")
+	server, err := Listen(path, func(req Request) (string, error) {
+		if req.Target.Name == "missing" {
+			return "", errors.New("no group \"missing\"")
+		}
+		return req.String(), nil
+	})
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer func() { _ = server.Close() }()
+
+	if _, err := Listen(path, nil); err == nil {
+		t.Fatal("a second server should not take over a socket in use")
+	}
+	if reply, err := Send(path, "fetch group:Work"); err != nil || reply != "ok: fetch group:Work" {
+		t.Fatalf("Send = %q, %v", reply, err)
+	}
+	if _, err := Send(path, "fetch group:missing"); err == nil || err.Error() != `no group "missing"` {
+		t.Fatalf("Send should fail with the handler's error, got %v", err)
+	}
+	if _, err := Send(path, "push"); err == nil {
+		t.Fatal("Send should fail for an unknown command")
+	}
+}
@@ -0,0 +1,21 @@
+//go:build !unix
+
+package control
+
+import (
+	"fmt"
+	"os"
+)
+
+// checkPrivateDir fails unless dir is a real directory, not a symlink. Ownership and
+// modes are left to the ACLs the directory inherits.
+func checkPrivateDir(dir string) error {
+	info, err := os.Lstat(dir)
+	if err != nil {
+		return err
+	}
+	if !info.IsDir() {
+		return fmt.Errorf("%s is not a directory", dir)
+	}
+	return nil
+}
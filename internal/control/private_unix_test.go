@@ -0,0 +1,35 @@
+//go:build unix
+
+package control
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+)
+
+func TestListenRefusesSharedDirectory(t *testing.T) {
+	dir, err := os.MkdirTemp("", "gitagrip")
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer func() { _ = os.RemoveAll(dir) }()
+
+	open := filepath.Join(dir, "open")
+	if err := os.Mkdir(open, 0o700); err != nil {
+		t.Fatal(err)
+	}
+	if err := os.Chmod(open, 0o755); err != nil {
+		t.Fatal(err)
+	}
+	link := filepath.Join(dir, "link")
+	if err := os.Symlink(dir, link); err != nil {
+		t.Fatal(err)
+	}
+	for _, sockDir := range []string{open, link} {
+		if server, err := Listen(filepath.Join(sockDir, "c.sock"), nil); err == nil {
+			_ = server.Close()
+			t.Fatalf("Listen in %s should fail", sockDir)
+		}
+	}
+}
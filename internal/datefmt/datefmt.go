@@ -0,0 +1,184 @@
+// Package datefmt formats the dates shown in the UI and in reports (command
+// history, last commits, fetch ages, digests) according to [ui.dates] and the
+// user's locale
+package datefmt
+
+import (
+	"errors"
+	"fmt"
+	"strings"
+	"time"
+
+	"github.com/darksworm/gitagrip/internal/config"
+)
+
+// Date styles selectable via ui.dates.style
+const (
+	StyleAbsolute = "absolute"
+	StyleRelative = "relative"
+)
+
+// Clock formats selectable via ui.dates.clock
+const (
+	Clock24h = "24h"
+	Clock12h = "12h"
+)
+
+// relativeAfter is how old a date can be before relative style falls back to the absolute date
+const relativeAfter = 30 * 24 * time.Hour
+
+// locale describes how one language writes dates
+type locale struct {
+	date    string // Go layout of a calendar date
+	clock24 bool   // whether times use a 24-hour clock by default
+
+	// Relative phrases; the %d ones take a count
+	justNow string
+	minutes string
+	hours   string
+	days    string
+}
+
+// languages holds the date layout, default clock and relative phrases of each translated language
+var languages = map[string]locale{
+	"en": {date: "2 Jan 2006", clock24: true, justNow: "just now", minutes: "%dm ago", hours: "%dh ago", days: "%dd ago"},
+	"de": {date: "02.01.2006", clock24: true, justNow: "gerade eben", minutes: "vor %d Min.", hours: "vor %d Std.", days: "vor %d T."},
+	"fr": {date: "02/01/2006", clock24: true, justNow: "à l'instant", minutes: "il y a %d min", hours: "il y a %d h", days: "il y a %d j"},
+	"es": {date: "02/01/2006", clock24: true, justNow: "ahora", minutes: "hace %d min", hours: "hace %d h", days: "hace %d d"},
+}
+
+// regions overrides the date layout and clock of a language in some countries
+var regions = map[string]locale{
+	"en_US": {date: "Jan 2, 2006", clock24: false},
+	"en_CA": {date: "2006-01-02", clock24: false},
+	"en_AU": {date: "2/01/2006", clock24: false},
+}
+
+// posix is used for the C/POSIX locale and languages without a translation
+var posix = locale{date: "2006-01-02", clock24: true, justNow: "just now", minutes: "%dm ago", hours: "%dh ago", days: "%dd ago"}
+
+// Formatter turns timestamps into display strings. The zero value is not usable; use New.
+type Formatter struct {
+	style  string
+	layout string // full Go layout for absolute dates
+	date   string // Go layout for the date alone
+	locale locale
+	now    func() time.Time
+}
+
+// New builds a formatter from [ui.dates]. The locale comes from the config or, if unset,
+// the environment (as returned by os.Environ). Invalid settings fall back to the
+// defaults and are reported in the error.
+func New(settings config.DateSettings, environ []string) (*Formatter, error) {
+	var errs []error
+
+	name := settings.Locale
+	if name == "" {
+		name = localeFromEnv(environ)
+	}
+	loc := resolveLocale(name)
+
+	style := strings.ToLower(settings.Style)
+	switch style {
+	case "":
+		style = StyleAbsolute
+	case StyleAbsolute, StyleRelative:
+	default:
+		errs = append(errs, fmt.Errorf("ui.dates.style: unknown style %q (available: %s, %s)", settings.Style, StyleAbsolute, StyleRelative))
+		style = StyleAbsolute
+	}
+
+	clock24 := loc.clock24
+	switch strings.ToLower(settings.Clock) {
+	case "":
+	case Clock24h:
+		clock24 = true
+	case Clock12h:
+		clock24 = false
+	default:
+		errs = append(errs, fmt.Errorf("ui.dates.clock: unknown clock %q (available: %s, %s)", settings.Clock, Clock24h, Clock12h))
+	}
+
+	layout := settings.Format
+	if layout == "" {
+		clock := "15:04"
+		if !clock24 {
+			clock = "3:04 PM"
+		}
+		layout = loc.date + " " + clock
+	}
+
+	f := &Formatter{style: style, layout: layout, date: loc.date, locale: loc, now: time.Now}
+	return f, errors.Join(errs...)
+}
+
+// Format renders t in the configured style
+func (f *Formatter) Format(t time.Time) string {
+	if f.style == StyleRelative {
+		return f.Relative(t)
+	}
+	return f.Absolute(t)
+}
+
+// Absolute renders t as a local date and time, whatever the configured style
+func (f *Formatter) Absolute(t time.Time) string {
+	if t.IsZero() {
+		return "-"
+	}
+	return t.Local().Format(f.layout)
+}
+
+// Relative renders t as an age ("5m ago"). Dates older than a month show the
+// calendar date instead, since "412d ago" is harder to place than a date.
+func (f *Formatter) Relative(t time.Time) string {
+	if t.IsZero() {
+		return "-"
+	}
+	age := f.now().Sub(t)
+	switch {
+	case age < time.Minute:
+		return f.locale.justNow
+	case age < time.Hour:
+		return fmt.Sprintf(f.locale.minutes, int(age/time.Minute))
+	case age < 24*time.Hour:
+		return fmt.Sprintf(f.locale.hours, int(age/time.Hour))
+	case age < relativeAfter:
+		return fmt.Sprintf(f.locale.days, int(age/(24*time.Hour)))
+	}
+	return t.Local().Format(f.date)
+}
+
+// localeFromEnv returns the locale used for times: LC_ALL overrides LC_TIME, which overrides LANG
+func localeFromEnv(environ []string) string {
+	env := make(map[string]string, len(environ))
+	for _, kv := range environ {
+		if k, v, ok := strings.Cut(kv, "="); ok {
+			env[k] = v
+		}
+	}
+	for _, key := range []string{"LC_ALL", "LC_TIME", "LANG"} {
+		if env[key] != "" {
+			return env[key]
+		}
+	}
+	return ""
+}
+
+// resolveLocale looks up a locale name such as "de_DE.UTF-8" or "en-GB"
+func resolveLocale(name string) locale {
+	name, _, _ = strings.Cut(name, ".")
+	name, _, _ = strings.Cut(name, "@")
+	name = strings.ReplaceAll(name, "-", "_")
+	lang, region, _ := strings.Cut(name, "_")
+	lang = strings.ToLower(lang)
+
+	loc, ok := languages[lang]
+	if !ok {
+		return posix
+	}
+	if r, ok := regions[lang+"_"+strings.ToUpper(region)]; ok {
+		loc.date = r.date
+		loc.clock24 = r.clock24
+	}
+	return loc
+}
@@ -0,0 +1,86 @@
+// Package devenv runs commands spawned from gitagrip through a repository's
+// environment activator (direnv or mise) so they get the expected toolchain.
+package devenv
+
+import (
+	"os"
+	"os/exec"
+	"path/filepath"
+)
+
+// Supported activators
+const (
+	ActivatorAuto   = "auto" // pick based on the files present in the repository
+	ActivatorDirenv = "direnv"
+	ActivatorMise   = "mise"
+	ActivatorNone   = "none"
+)
+
+// Files that mark a repository as managed by each activator
+var (
+	direnvFiles = []string{".envrc"}
+	miseFiles   = []string{".mise.toml", "mise.toml", ".config/mise.toml"}
+)
+
+// Detect returns the activator a repository uses, or ActivatorNone
+func Detect(repoPath string) string {
+	if anyExists(repoPath, direnvFiles) {
+		return ActivatorDirenv
+	}
+	if anyExists(repoPath, miseFiles) {
+		return ActivatorMise
+	}
+	return ActivatorNone
+}
+
+// Resolve returns the activator to use for a repository given the configured preference.
+// An activator whose binary is not installed resolves to ActivatorNone.
+func Resolve(repoPath, configured string) string {
+	activator := configured
+	switch activator {
+	case "", ActivatorAuto:
+		activator = Detect(repoPath)
+	case ActivatorDirenv:
+		if !anyExists(repoPath, direnvFiles) {
+			activator = ActivatorNone
+		}
+	case ActivatorMise:
+		if !anyExists(repoPath, miseFiles) {
+			activator = ActivatorNone
+		}
+	default:
+		activator = ActivatorNone
+	}
+
+	if activator != ActivatorNone {
+		if _, err := exec.LookPath(activator); err != nil {
+			return ActivatorNone
+		}
+	}
+	return activator
+}
+
+// Command builds a command that runs name with args inside repoPath, wrapped in the
+// repository's environment activator when one applies
+func Command(repoPath, configured, name string, args ...string) *exec.Cmd {
+	var cmd *exec.Cmd
+	switch Resolve(repoPath, configured) {
+	case ActivatorDirenv:
+		cmd = exec.Command("direnv", append([]string{"exec", repoPath, name}, args...)...)
+	case ActivatorMise:
+		cmd = exec.Command("mise", append([]string{"exec", "--", name}, args...)...)
+	default:
+		cmd = exec.Command(name, args...)
+	}
+	cmd.Dir = repoPath
+	return cmd
+}
+
+func anyExists(dir string, names []string) bool {
+	for _, name := range names {
+		if _, err := os.Stat(filepath.Join(dir, name)); err == nil {
+			return true
+		}
+	}
+	return false
+}
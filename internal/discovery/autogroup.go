@@ -0,0 +1,99 @@
+package discovery
+
+import (
+	"fmt"
+	"io/fs"
+	"path/filepath"
+	"strings"
+
+	"github.com/darksworm/gitagrip/internal/config"
+)
+
+// autoGroupMaxDepth limits how deep AutoGroups looks for repositories
+const autoGroupMaxDepth = 4
+
+// AutoGroups groups the repositories under baseDir by the directory they live in, as
+// chosen by strategy: "parent" names a group after the immediate parent directory,
+// "path" after the parent's path relative to baseDir (e.g. "work/clients/acme") so
+// same-named directories at different places stay apart, and "flat" creates no groups.
+// Repositories directly in baseDir stay ungrouped and only directories holding at
+// least two repositories become groups.
+func AutoGroups(baseDir, strategy string) (map[string][]string, error) {
+	switch strategy {
+	case "", config.GroupingParent, config.GroupingPath:
+	case config.GroupingFlat:
+		return make(map[string][]string), nil
+	default:
+		return nil, fmt.Errorf("unknown grouping strategy %q (use %s, %s or %s)",
+			strategy, config.GroupingParent, config.GroupingPath, config.GroupingFlat)
+	}
+	return GroupRepos(baseDir, FindRepos(baseDir), strategy), nil
+}
+
+// FindRepos lists the repositories under baseDir that AutoGroups considers, baseDir
+// itself included when it is one
+func FindRepos(baseDir string) []string {
+	var repos []string
+	_ = filepath.WalkDir(baseDir, func(path string, d fs.DirEntry, err error) error {
+		if err != nil {
+			return nil // Continue walking
+		}
+
+		// Check depth
+		relPath, _ := filepath.Rel(baseDir, path)
+		depth := strings.Count(relPath, string(filepath.Separator))
+		if depth > autoGroupMaxDepth {
+			return filepath.SkipDir
+		}
+
+		// Skip common non-repo directories
+		if d.IsDir() {
+			name := d.Name()
+			if name == "node_modules" || name == ".npm" || name == "__pycache__" ||
+				name == ".pytest_cache" || name == "venv" || name == ".venv" ||
+				name == "target" || name == "build" || name == "dist" {
+				return filepath.SkipDir
+			}
+		}
+
+		// Check if this is a .git directory
+		if d.IsDir() && d.Name() == ".git" {
+			repos = append(repos, filepath.Dir(path))
+			return filepath.SkipDir
+		}
+
+		return nil
+	})
+	return repos
+}
+
+// GroupRepos groups repositories found under baseDir as AutoGroups does with strategy
+// ("flat" is up to the caller)
+func GroupRepos(baseDir string, repos []string, strategy string) map[string][]string {
+	reposByGroup := make(map[string][]string)
+	for _, repoPath := range repos {
+		// Get the parent directory relative to base
+		relRepo, _ := filepath.Rel(baseDir, repoPath)
+		parentDir := filepath.Dir(relRepo)
+
+		// If repo is directly in base dir, don't create a group
+		if parentDir == "." {
+			continue
+		}
+
+		groupName := filepath.Base(parentDir)
+		if strategy == config.GroupingPath {
+			groupName = filepath.ToSlash(parentDir)
+		}
+		reposByGroup[groupName] = append(reposByGroup[groupName], repoPath)
+	}
+
+	// Only create groups that have 2 or more repos
+	groups := make(map[string][]string)
+	for groupName, repos := range reposByGroup {
+		if len(repos) >= 2 {
+			groups[groupName] = repos
+		}
+	}
+	return groups
+}
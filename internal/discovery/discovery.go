@@ -2,37 +2,60 @@ package discovery
 
 import (
 	"context"
+	"errors"
 	"fmt"
 	"io/fs"
 	"log"
+	"os"
 	"path/filepath"
 	"strings"
 	"sync"
+	"sync/atomic"
 
-	"gitagrip/internal/domain"
-	"gitagrip/internal/eventbus"
+	"github.com/darksworm/gitagrip/internal/config"
+	"github.com/darksworm/gitagrip/internal/domain"
+	"github.com/darksworm/gitagrip/internal/eventbus"
+	"github.com/darksworm/gitagrip/internal/paths"
 )
 
 // DiscoveryService finds git repositories in the filesystem
 type DiscoveryService interface {
 	StartScan(ctx context.Context, roots []string) error
 	StopScan()
+	// SetConfig replaces the config that later scans read
+	SetConfig(cfg *config.Config)
 }
 
 // discoveryService is the concrete implementation
 type discoveryService struct {
 	bus        eventbus.EventBus
+	cfg        atomic.Pointer[config.Config] // per-repository name overrides (may be nil)
 	mu         sync.Mutex
 	isScanning bool
 	cancelFunc context.CancelFunc
 	wg         sync.WaitGroup
 }
 
+// config returns the current config (nil without one)
+func (ds *discoveryService) config() *config.Config {
+	return ds.cfg.Load()
+}
+
+// SetConfig replaces the config that later scans read
+func (ds *discoveryService) SetConfig(cfg *config.Config) {
+	ds.cfg.Store(cfg)
+}
+
 // NewDiscoveryService creates a new discovery service
 func NewDiscoveryService(bus eventbus.EventBus) DiscoveryService {
-	ds := &discoveryService{
-		bus: bus,
-	}
+	return NewDiscoveryServiceWithConfig(bus, nil)
+}
+
+// NewDiscoveryServiceWithConfig creates a discovery service that gives repositories the
+// names configured under [repos."<path>"], so every view, search and report uses them
+func NewDiscoveryServiceWithConfig(bus eventbus.EventBus, cfg *config.Config) DiscoveryService {
+	ds := &discoveryService{bus: bus}
+	ds.cfg.Store(cfg)
 
 	// Subscribe to scan requests
 	bus.Subscribe(eventbus.EventScanRequested, func(e eventbus.DomainEvent) {
@@ -48,6 +71,7 @@ func NewDiscoveryService(bus eventbus.EventBus) DiscoveryService {
 
 // StartScan starts scanning for git repositories
 func (ds *discoveryService) StartScan(ctx context.Context, roots []string) error {
+	cfg := ds.config()
 	ds.mu.Lock()
 	if ds.isScanning {
 		ds.mu.Unlock()
@@ -63,8 +87,17 @@ func (ds *discoveryService) StartScan(ctx context.Context, roots []string) error
 	// Publish scan started event
 	ds.bus.Publish(eventbus.ScanStartedEvent{Paths: roots})
 
-	// Track repositories found
-	reposFound := 0
+	// Track repositories found, up to scan.max_repos
+	var found []string
+	partial := false
+	maxRepos, maxDepth := config.ScanSettings{}.Limits()
+	limits := walkLimits{}
+	if cfg != nil {
+		maxRepos, maxDepth = cfg.Scan.Limits()
+		limits.excluded = cfg.Scan.Excluded
+	}
+	limits.maxDepth = maxDepth
+	repoLimit, deepDirs := 0, 0
 
 	// Scan in background
 	ds.wg.Add(1)
@@ -77,7 +110,15 @@ func (ds *discoveryService) StartScan(ctx context.Context, roots []string) error
 			ds.mu.Unlock()
 
 			// Publish scan completed event
-			ds.bus.Publish(eventbus.ScanCompletedEvent{ReposFound: reposFound})
+			ds.bus.Publish(eventbus.ScanCompletedEvent{
+				ReposFound: len(found),
+				Roots:      roots,
+				Repos:      found,
+				Partial:    partial || repoLimit > 0 || scanCtx.Err() != nil,
+				RepoLimit:  repoLimit,
+				DeepDirs:   deepDirs,
+				MaxDepth:   maxDepth,
+			})
 		}()
 
 		for _, root := range roots {
@@ -85,8 +126,18 @@ func (ds *discoveryService) StartScan(ctx context.Context, roots []string) error
 			case <-scanCtx.Done():
 				return
 			default:
-				count := ds.scanDirectory(scanCtx, root)
-				reposFound += count
+				budget := -1
+				if maxRepos >= 0 {
+					budget = maxRepos - len(found)
+				}
+				repos, deep, err := ds.scanDirectory(scanCtx, paths.Normalize(root), limits, budget)
+				found = append(found, repos...)
+				deepDirs += deep
+				if errors.Is(err, errRepoLimit) {
+					repoLimit = maxRepos
+					return
+				}
+				partial = partial || err != nil
 			}
 		}
 	}()
@@ -105,10 +156,96 @@ func (ds *discoveryService) StopScan() {
 	ds.wg.Wait()
 }
 
-// scanDirectory recursively scans a directory for git repositories
-func (ds *discoveryService) scanDirectory(ctx context.Context, root string) int {
-	reposFound := 0
-	maxDepth := 5 // Maximum depth to scan
+// errRepoLimit stops a scan that found scan.max_repos repositories
+var errRepoLimit = errors.New("repository limit reached")
+
+// scanDirectory recursively scans a directory for up to budget git repositories (-1 for
+// no limit) and returns their paths and how many directories it left below the depth
+// limit. The error is errRepoLimit when it stopped at the budget, and set when the
+// directory couldn't be scanned as a whole.
+func (ds *discoveryService) scanDirectory(ctx context.Context, root string, limits walkLimits, budget int) ([]string, int, error) {
+	cfg := ds.config()
+	var found []string
+
+	deep, err := walkRepositories(ctx, root, limits, func(repoPath string, worktreeOf string) error {
+		if cfg != nil {
+			// The config's spelling of the path, for its settings to apply
+			repoPath = cfg.CanonicalPath(repoPath)
+			if cfg.IsForgotten(repoPath) {
+				return nil
+			}
+		}
+		if budget >= 0 && len(found) >= budget {
+			return errRepoLimit
+		}
+		repoName := filepath.Base(repoPath)
+		var tags []string
+		if cfg != nil {
+			settings := cfg.Repos[repoPath]
+			if settings.Name != "" {
+				repoName = settings.Name
+			}
+			tags = append(tags, settings.Labels...)
+		}
+
+		// Create repository info with minimal status
+		repo := domain.Repository{
+			Path:        repoPath,
+			Name:        repoName,
+			DisplayName: repoName, // Initially same as Name, will be updated if duplicates found
+			Group:       "",       // Will be determined by group manager
+			WorktreeOf:  worktreeOf,
+			Tags:        tags,
+			Project:     DetectProject(repoPath),
+			Status: domain.RepoStatus{
+				Branch: "⋯", // Loading indicator, will be updated by git service
+			},
+		}
+
+		// Publish discovery event immediately
+		ds.bus.Publish(eventbus.RepoDiscoveredEvent{Repo: repo})
+		found = append(found, repoPath)
+		return nil
+	})
+
+	if err != nil && err != context.Canceled && err != errRepoLimit {
+		log.Printf("Error scanning directory %s: %v", root, err)
+		ds.bus.Publish(eventbus.ErrorEvent{
+			Message: fmt.Sprintf("Failed to scan %s", root),
+			Err:     err,
+		})
+	}
+
+	return found, deep, err
+}
+
+// FindRepositories synchronously returns the paths of all git repositories under root.
+// Linked worktrees are not included.
+func FindRepositories(ctx context.Context, root string) ([]string, error) {
+	var repos []string
+	_, err := walkRepositories(ctx, root, walkLimits{maxDepth: config.DefaultScanMaxDepth}, func(repoPath string, worktreeOf string) error {
+		if worktreeOf == "" {
+			repos = append(repos, repoPath)
+		}
+		return nil
+	})
+	return repos, err
+}
+
+// walkLimits bound a walk: directories more than maxDepth levels down and those excluded
+// are not entered
+type walkLimits struct {
+	maxDepth int
+	excluded func(dir string) bool // scan.exclude (nil excludes nothing)
+}
+
+// walkRepositories walks root and calls found with the path of every git repository; an
+// error from found stops the walk and is returned. For linked worktrees worktreeOf is the
+// path of the main repository. It also returns how many directories outside repositories
+// it left unscanned below the depth limit, which may hold more of them.
+func walkRepositories(ctx context.Context, root string, limits walkLimits, found func(repoPath string, worktreeOf string) error) (int, error) {
+	repos := make(map[string]bool)
+	deep := 0
 
 	err := filepath.WalkDir(root, func(path string, d fs.DirEntry, err error) error {
 		// Check context cancellation
@@ -124,52 +261,44 @@ func (ds *discoveryService) scanDirectory(ctx context.Context, root string) int
 			return nil // Continue walking
 		}
 
-		// Skip if not a directory
+		// Linked worktrees have a .git file pointing at the main repository
 		if !d.IsDir() {
+			if d.Name() == ".git" {
+				if mainRepo, ok := linkedWorktreeMain(path); ok {
+					repos[filepath.Dir(path)] = true
+					return found(filepath.Dir(path), mainRepo)
+				}
+			}
 			return nil
 		}
 
-		// Check depth limit
+		// Check depth limit, counting what it leaves out unless inside a repository
+		dirName := d.Name()
 		relPath, _ := filepath.Rel(root, path)
 		depth := strings.Count(relPath, string(filepath.Separator))
-		if depth > maxDepth {
+		if depth > limits.maxDepth {
+			if !isNoiseDir(dirName) && !insideRepo(path, root, repos) {
+				deep++
+			}
+			return filepath.SkipDir
+		}
+		if path != root && limits.excluded != nil && limits.excluded(path) {
 			return filepath.SkipDir
 		}
 
 		// Skip common non-repository directories to speed up scanning
-		dirName := d.Name()
-		if dirName == "node_modules" || dirName == ".npm" ||
-			dirName == "vendor" || dirName == ".cache" ||
-			dirName == "dist" || dirName == "build" ||
-			dirName == "target" || dirName == ".gradle" ||
-			dirName == "__pycache__" || dirName == ".pytest_cache" ||
-			dirName == ".tox" || dirName == "venv" ||
-			dirName == ".venv" || dirName == "env" ||
-			strings.HasPrefix(dirName, ".") && dirName != ".git" {
+		if isNoiseDir(dirName) {
 			return filepath.SkipDir
 		}
 
 		// Check if this is a .git directory
 		if dirName == ".git" {
 			// Found a git repository - the parent is the repo root
-			repoPath := filepath.Dir(path)
-			repoName := filepath.Base(repoPath)
-
-			// Create repository info with minimal status
-			repo := domain.Repository{
-				Path:        repoPath,
-				Name:        repoName,
-				DisplayName: repoName, // Initially same as Name, will be updated if duplicates found
-				Group:       "",       // Will be determined by group manager
-				Status: domain.RepoStatus{
-					Branch: "⋯", // Loading indicator, will be updated by git service
-				},
+			repos[filepath.Dir(path)] = true
+			if err := found(filepath.Dir(path), ""); err != nil {
+				return err
 			}
 
-			// Publish discovery event immediately
-			ds.bus.Publish(eventbus.RepoDiscoveredEvent{Repo: repo})
-			reposFound++
-
 			// Don't descend into .git directory
 			return fs.SkipDir
 		}
@@ -189,14 +318,58 @@ func (ds *discoveryService) scanDirectory(ctx context.Context, root string) int
 
 		return nil
 	})
+	return deep, err
+}
 
-	if err != nil && err != context.Canceled {
-		log.Printf("Error scanning directory %s: %v", root, err)
-		ds.bus.Publish(eventbus.ErrorEvent{
-			Message: fmt.Sprintf("Failed to scan %s", root),
-			Err:     err,
-		})
+// isNoiseDir reports whether a directory is one that holds no repositories worth listing:
+// dependencies, build output, caches, virtualenvs and hidden directories (but .git)
+func isNoiseDir(name string) bool {
+	switch name {
+	case "node_modules", ".npm", "vendor", ".cache", "dist", "build", "target", ".gradle",
+		"__pycache__", ".pytest_cache", ".tox", "venv", ".venv", "env":
+		return true
+	}
+	return strings.HasPrefix(name, ".") && name != ".git"
+}
+
+// insideRepo reports whether dir lies in one of the repositories found so far below root
+func insideRepo(dir, root string, repos map[string]bool) bool {
+	for dir != root && len(dir) > len(root) {
+		if repos[dir] {
+			return true
+		}
+		dir = filepath.Dir(dir)
 	}
+	return repos[root]
+}
 
-	return reposFound
+// linkedWorktreeMain reads a .git file and, if it belongs to a linked worktree,
+// returns the path of the main repository. Submodules (which also use .git files) are ignored.
+func linkedWorktreeMain(gitFile string) (string, bool) {
+	data, err := os.ReadFile(gitFile)
+	if err != nil {
+		return "", false
+	}
+
+	gitDir, ok := strings.CutPrefix(strings.TrimSpace(string(data)), "gitdir:")
+	if !ok {
+		return "", false
+	}
+	gitDir = strings.TrimSpace(gitDir)
+	if !filepath.IsAbs(gitDir) {
+		gitDir = filepath.Join(filepath.Dir(gitFile), gitDir)
+	}
+	gitDir = filepath.Clean(gitDir)
+
+	// Worktree git dirs live in <common-dir>/worktrees/<name>
+	if filepath.Base(filepath.Dir(gitDir)) != "worktrees" {
+		return "", false
+	}
+	commonDir := filepath.Dir(filepath.Dir(gitDir))
+
+	// Non-bare main repositories keep their common dir in <repo>/.git
+	if filepath.Base(commonDir) == ".git" {
+		return filepath.Dir(commonDir), true
+	}
+	return commonDir, true
 }
@@ -0,0 +1,53 @@
+package discovery
+
+import (
+	"os"
+	"path/filepath"
+)
+
+// projectMarkers maps files at the root of a repository to the kind of project they mark,
+// checked in this order: a TypeScript config wins over package.json, for instance
+var projectMarkers = []struct{ file, project string }{
+	{"go.mod", "Go"},
+	{"Cargo.toml", "Rust"},
+	{"tsconfig.json", "TypeScript"},
+	{"package.json", "JavaScript"},
+	{"pyproject.toml", "Python"},
+	{"setup.py", "Python"},
+	{"requirements.txt", "Python"},
+	{"Gemfile", "Ruby"},
+	{"pom.xml", "Java"},
+	{"build.gradle", "Java"},
+	{"build.gradle.kts", "Java"},
+	{"composer.json", "PHP"},
+	{"mix.exs", "Elixir"},
+	{"Package.swift", "Swift"},
+	{"pubspec.yaml", "Dart"},
+	{"CMakeLists.txt", "C/C++"},
+	{"flake.nix", "Nix"},
+	{"*.sln", "C#"},
+	{"*.csproj", "C#"},
+}
+
+// DetectProject returns the kind of project at the root of a repository, e.g. "Go" or
+// "Python", from the build files there ("" when none is recognized)
+func DetectProject(repoPath string) string {
+	entries, err := os.ReadDir(repoPath)
+	if err != nil {
+		return ""
+	}
+	names := make([]string, 0, len(entries))
+	for _, entry := range entries {
+		if !entry.IsDir() {
+			names = append(names, entry.Name())
+		}
+	}
+	for _, marker := range projectMarkers {
+		for _, name := range names {
+			if ok, _ := filepath.Match(marker.file, name); ok {
+				return marker.project
+			}
+		}
+	}
+	return ""
+}
@@ -0,0 +1,148 @@
+package discovery
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+	"sync"
+	"sync/atomic"
+
+	"github.com/darksworm/gitagrip/internal/config"
+	"github.com/darksworm/gitagrip/internal/domain"
+	"github.com/darksworm/gitagrip/internal/eventbus"
+)
+
+// Reconciler removes the repositories that disappeared while gitagrip runs: after each
+// complete scan the known ones below its directories that it didn't find, and any whose
+// status fails because its directory is gone. Each gets a RepoRemovedEvent. Config
+// entries pointing at missing paths are reported once per scan that finds new ones.
+type Reconciler struct {
+	bus    eventbus.EventBus
+	cfg    atomic.Pointer[config.Config]
+	mu     sync.Mutex
+	known  map[string]bool // repositories listed: discovered, or shown from the last run
+	warned map[string]bool // missing config paths already reported
+}
+
+// config returns the current config (nil without one)
+func (r *Reconciler) config() *config.Config {
+	return r.cfg.Load()
+}
+
+// SetConfig replaces the config whose missing paths are reported
+func (r *Reconciler) SetConfig(cfg *config.Config) {
+	r.cfg.Store(cfg)
+}
+
+// NewReconciler starts reconciling; known are the repositories listed before any scan
+// (e.g. from the snapshot of the last run)
+func NewReconciler(bus eventbus.EventBus, cfg *config.Config, known []string) *Reconciler {
+	r := &Reconciler{
+		bus:    bus,
+		known:  make(map[string]bool),
+		warned: make(map[string]bool),
+	}
+	r.cfg.Store(cfg)
+	for _, path := range known {
+		r.known[path] = true
+	}
+
+	bus.Subscribe(eventbus.EventRepoDiscovered, func(e eventbus.DomainEvent) {
+		if event, ok := e.(eventbus.RepoDiscoveredEvent); ok {
+			r.mu.Lock()
+			r.known[event.Repo.Path] = true
+			r.mu.Unlock()
+		}
+	})
+	bus.Subscribe(eventbus.EventScanCompleted, func(e eventbus.DomainEvent) {
+		if event, ok := e.(eventbus.ScanCompletedEvent); ok && !event.Partial {
+			r.reconcile(event.Roots, event.Repos)
+			r.reportMissingConfigPaths()
+		}
+	})
+	bus.Subscribe(eventbus.EventStatusUpdated, func(e eventbus.DomainEvent) {
+		if event, ok := e.(eventbus.StatusUpdatedEvent); ok && event.Status.Error != "" {
+			r.statusFailed(event.RepoPath)
+		}
+	})
+	return r
+}
+
+// reconcile removes the known repositories below the scanned roots that the scan didn't find
+func (r *Reconciler) reconcile(roots, found []string) {
+	seen := make(map[string]bool, len(found))
+	for _, path := range found {
+		seen[path] = true
+	}
+
+	r.mu.Lock()
+	var removed []string
+	for path := range r.known {
+		if !seen[path] && below(path, roots) {
+			delete(r.known, path)
+			removed = append(removed, path)
+		}
+	}
+	r.mu.Unlock()
+
+	sort.Strings(removed)
+	for _, path := range removed {
+		r.bus.Publish(eventbus.RepoRemovedEvent{RepoPath: path})
+	}
+}
+
+// statusFailed removes a known repository whose directory no longer exists, so that its
+// status isn't refreshed (and failing) over and over
+func (r *Reconciler) statusFailed(repoPath string) {
+	if _, err := os.Stat(repoPath); !os.IsNotExist(err) {
+		return
+	}
+	r.mu.Lock()
+	known := r.known[repoPath]
+	delete(r.known, repoPath)
+	r.mu.Unlock()
+	if known {
+		r.bus.Publish(eventbus.RepoRemovedEvent{RepoPath: repoPath})
+	}
+}
+
+// reportMissingConfigPaths warns about [groups] and [repos] entries whose directory is
+// gone, unless they were all reported before
+func (r *Reconciler) reportMissingConfigPaths() {
+	cfg := r.config()
+	if cfg == nil {
+		return
+	}
+	missing := cfg.MissingPaths()
+	r.mu.Lock()
+	fresh := false
+	for _, path := range missing {
+		if !r.warned[path] {
+			r.warned[path] = true
+			fresh = true
+		}
+	}
+	r.mu.Unlock()
+	if !fresh {
+		return
+	}
+
+	text := fmt.Sprintf("%d paths in the config no longer exist (gitagrip doctor --self lists them)", len(missing))
+	if len(missing) == 1 {
+		text = fmt.Sprintf("%s is in the config but no longer exists", filepath.Base(missing[0]))
+	}
+	r.bus.Publish(eventbus.AppMessageEvent{Level: domain.MessageWarning, Text: text})
+}
+
+// below reports whether path is one of the roots or inside one
+func below(path string, roots []string) bool {
+	for _, root := range roots {
+		rel, err := filepath.Rel(root, path)
+		if err == nil && rel != ".." && !strings.HasPrefix(rel, ".."+string(filepath.Separator)) {
+			return true
+		}
+	}
+	return false
+}
@@ -5,27 +5,42 @@ type EventType string
 
 // Event types
 const (
-	EventRepoDiscovered         EventType = "RepoDiscovered"
-	EventStatusUpdated          EventType = "StatusUpdated"
-	EventError                  EventType = "Error"
-	EventGroupAdded             EventType = "GroupAdded"
-	EventGroupRemoved           EventType = "GroupRemoved"
-	EventRepoMoved              EventType = "RepoMoved"
-	EventScanStarted            EventType = "ScanStarted"
-	EventScanCompleted          EventType = "ScanCompleted"
-	EventScanRequested          EventType = "ScanRequested"
-	EventStatusRefreshRequested EventType = "StatusRefreshRequested"
-	EventFetchRequested         EventType = "FetchRequested"
-	EventPullRequested          EventType = "PullRequested"
-	EventFetchCompleted         EventType = "FetchCompleted"
-	EventPullCompleted          EventType = "PullCompleted"
-	EventConfigLoaded           EventType = "ConfigLoaded"
-	EventConfigSaved            EventType = "ConfigSaved"
-	EventConfigChanged          EventType = "ConfigChanged"
-	EventAppReady               EventType = "AppReady"
-	EventCommandExecuted        EventType = "CommandExecuted"
-	EventBranchCreateRequested  EventType = "BranchCreateRequested"
-	EventBranchSwitchRequested  EventType = "BranchSwitchRequested"
+	EventRepoDiscovered            EventType = "RepoDiscovered"
+	EventStatusUpdated             EventType = "StatusUpdated"
+	EventError                     EventType = "Error"
+	EventGroupAdded                EventType = "GroupAdded"
+	EventGroupRemoved              EventType = "GroupRemoved"
+	EventRepoMoved                 EventType = "RepoMoved"
+	EventRepoRemoved               EventType = "RepoRemoved"
+	EventScanStarted               EventType = "ScanStarted"
+	EventScanCompleted             EventType = "ScanCompleted"
+	EventScanRequested             EventType = "ScanRequested"
+	EventStatusRefreshRequested    EventType = "StatusRefreshRequested"
+	EventFetchRequested            EventType = "FetchRequested"
+	EventPullRequested             EventType = "PullRequested"
+	EventFetchCompleted            EventType = "FetchCompleted"
+	EventPullCompleted             EventType = "PullCompleted"
+	EventConfigLoaded              EventType = "ConfigLoaded"
+	EventConfigSaved               EventType = "ConfigSaved"
+	EventConfigChanged             EventType = "ConfigChanged"
+	EventConfigSaveRequested       EventType = "ConfigSaveRequested"
+	EventAppReady                  EventType = "AppReady"
+	EventCommandExecuted           EventType = "CommandExecuted"
+	EventBranchCreateRequested     EventType = "BranchCreateRequested"
+	EventBranchSwitchRequested     EventType = "BranchSwitchRequested"
+	EventRemoteURLChangeRequested  EventType = "RemoteURLChangeRequested"
+	EventCleanRequested            EventType = "CleanRequested"
+	EventOperationProgress         EventType = "OperationProgress"
+	EventCancelOperationsRequested EventType = "CancelOperationsRequested"
+	EventWatchUpdated              EventType = "WatchUpdated"
+	EventWatchAcknowledgeRequested EventType = "WatchAcknowledgeRequested"
+	EventCloneMissingRequested     EventType = "CloneMissingRequested"
+	EventCredentialRequested       EventType = "CredentialRequested"
+	EventCredentialProvided        EventType = "CredentialProvided"
+	EventPushRequested             EventType = "PushRequested"
+	EventPushCompleted             EventType = "PushCompleted"
+	EventAppMessage                EventType = "AppMessage"
+	EventReposVisible              EventType = "ReposVisible"
 )
 
 // DomainEvent is the interface for all domain events
@@ -79,6 +94,13 @@ type RepoMovedEvent struct {
 
 func (e RepoMovedEvent) Type() EventType { return EventRepoMoved }
 
+// RepoRemovedEvent is emitted when a listed repository turns out to no longer exist
+type RepoRemovedEvent struct {
+	RepoPath string
+}
+
+func (e RepoRemovedEvent) Type() EventType { return EventRepoRemoved }
+
 // ScanStartedEvent is emitted when repository scanning begins
 type ScanStartedEvent struct {
 	Paths []string
@@ -89,6 +111,12 @@ func (e ScanStartedEvent) Type() EventType { return EventScanStarted }
 // ScanCompletedEvent is emitted when repository scanning completes
 type ScanCompletedEvent struct {
 	ReposFound int
+	Roots      []string // directories scanned
+	Repos      []string // paths of the repositories found
+	Partial    bool     // the scan was cancelled or a directory failed, so Repos may be incomplete
+	RepoLimit  int      // scan.max_repos when the scan stopped at it (0 when it didn't)
+	DeepDirs   int      // directories outside repositories left unscanned below scan.max_depth
+	MaxDepth   int      // scan.max_depth the scan went down to
 }
 
 func (e ScanCompletedEvent) Type() EventType { return EventScanCompleted }
@@ -108,8 +136,10 @@ type ConfigLoadedEvent struct {
 
 func (e ConfigLoadedEvent) Type() EventType { return EventConfigLoaded }
 
-// ConfigSavedEvent is emitted when configuration is saved
-type ConfigSavedEvent struct{}
+// ConfigSavedEvent is emitted when configuration is saved, or failed to be
+type ConfigSavedEvent struct {
+	Err error // nil when saved
+}
 
 func (e ConfigSavedEvent) Type() EventType { return EventConfigSaved }
 
@@ -121,6 +151,12 @@ type ConfigChangedEvent struct {
 
 func (e ConfigChangedEvent) Type() EventType { return EventConfigChanged }
 
+// ConfigSaveRequestedEvent asks for the configuration to be saved now, e.g. when changes
+// are not saved automatically
+type ConfigSaveRequestedEvent struct{}
+
+func (e ConfigSaveRequestedEvent) Type() EventType { return EventConfigSaveRequested }
+
 // StatusRefreshRequestedEvent is emitted to request status refresh for specific repositories
 type StatusRefreshRequestedEvent struct {
 	RepoPaths []string // Empty means refresh all
@@ -131,6 +167,7 @@ func (e StatusRefreshRequestedEvent) Type() EventType { return EventStatusRefres
 // FetchRequestedEvent is emitted to request git fetch for specific repositories
 type FetchRequestedEvent struct {
 	RepoPaths []string // Empty means fetch all
+	Options   FetchOptions
 }
 
 func (e FetchRequestedEvent) Type() EventType { return EventFetchRequested }
@@ -160,6 +197,41 @@ type PullCompletedEvent struct {
 
 func (e PullCompletedEvent) Type() EventType { return EventPullCompleted }
 
+// PushRequestedEvent is emitted to request git push of the current branch in repositories
+type PushRequestedEvent struct {
+	RepoPaths []string
+}
+
+func (e PushRequestedEvent) Type() EventType { return EventPushRequested }
+
+// PushCompletedEvent is emitted when git push of a repository's current branch completes
+type PushCompletedEvent struct {
+	RepoPath string
+	Branch   string // local branch that was pushed
+	Success  bool
+	Error    error
+	Reason   string // why the remote rejected the push, e.g. non-fast-forward ("" if it didn't)
+}
+
+func (e PushCompletedEvent) Type() EventType { return EventPushCompleted }
+
+// AppMessageEvent is a brief notification for the user, e.g. that repositories were moved
+// or a bulk fetch finished. The TUI shows it as a toast that disappears by itself.
+type AppMessageEvent struct {
+	Level string // MessageInfo, MessageSuccess, MessageWarning or MessageError
+	Text  string
+}
+
+func (e AppMessageEvent) Type() EventType { return EventAppMessage }
+
+// Levels of an AppMessageEvent
+const (
+	MessageInfo    = "info"
+	MessageSuccess = "success"
+	MessageWarning = "warning"
+	MessageError   = "error"
+)
+
 // AppReadyEvent is emitted when the app is fully initialized and ready
 type AppReadyEvent struct {
 	HasExistingConfig bool
@@ -174,7 +246,8 @@ type CommandExecutedEvent struct {
 	Success  bool
 	Output   string
 	Error    string
-	Duration int64 // milliseconds
+	Reason   string // why it failed, when it's something to act on (e.g. "origin: SSH key rejected")
+	Duration int64  // milliseconds
 }
 
 func (e CommandExecutedEvent) Type() EventType { return EventCommandExecuted }
@@ -194,3 +267,94 @@ type BranchSwitchRequestedEvent struct {
 }
 
 func (e BranchSwitchRequestedEvent) Type() EventType { return EventBranchSwitchRequested }
+
+// RemoteURLChangeRequestedEvent requests rewriting a remote's URL in a repository
+type RemoteURLChangeRequestedEvent struct {
+	RepoPath string
+	Remote   string // remote name, e.g. "origin"
+	URL      string
+}
+
+func (e RemoteURLChangeRequestedEvent) Type() EventType { return EventRemoteURLChangeRequested }
+
+// CleanRequestedEvent requests removing untracked and ignored files (git clean -fdx)
+type CleanRequestedEvent struct {
+	RepoPaths []string
+}
+
+func (e CleanRequestedEvent) Type() EventType { return EventCleanRequested }
+
+// OperationProgressEvent reports progress of a bulk operation (fetch, pull) across repositories
+type OperationProgressEvent struct {
+	Operation string   // "fetch", "pull" or "clone"
+	Done      int      // repositories finished so far
+	Total     int      // repositories in the operation
+	Cancelled bool     // remaining repositories were skipped after a cancel request
+	Skipped   []string // repositories that never started (only when cancelled)
+}
+
+func (e OperationProgressEvent) Type() EventType { return EventOperationProgress }
+
+// CancelOperationsRequestedEvent asks running bulk operations to skip repositories that
+// have not started yet. Operations already in flight are allowed to finish.
+type CancelOperationsRequestedEvent struct{}
+
+func (e CancelOperationsRequestedEvent) Type() EventType { return EventCancelOperationsRequested }
+
+// WatchUpdatedEvent is emitted after a fetch with the commits on a watched branch
+// that arrived since it was last acknowledged (empty when there are none)
+type WatchUpdatedEvent struct {
+	RepoPath string
+	Branch   string
+	Commits  []WatchedCommit // newest first
+}
+
+func (e WatchUpdatedEvent) Type() EventType { return EventWatchUpdated }
+
+// WatchAcknowledgeRequestedEvent is emitted to mark watched commits as seen
+type WatchAcknowledgeRequestedEvent struct {
+	Acks []WatchAck
+}
+
+func (e WatchAcknowledgeRequestedEvent) Type() EventType { return EventWatchAcknowledgeRequested }
+
+// CloneMissingRequestedEvent requests cloning the repositories configured with a url in
+// [repos] that are not on disk. Progress is reported as a "clone" OperationProgressEvent
+// and each clone as a CommandExecutedEvent; cloned repositories are then discovered.
+type CloneMissingRequestedEvent struct {
+	RepoPaths []string // only these [repos] entries (every missing one when empty)
+}
+
+func (e CloneMissingRequestedEvent) Type() EventType { return EventCloneMissingRequested }
+
+// CredentialRequestedEvent is emitted when git or ssh asks for a password, passphrase,
+// username or host key confirmation while running a network command. The command waits
+// for the CredentialProvidedEvent with the same ID.
+type CredentialRequestedEvent struct {
+	ID       string
+	RepoPath string
+	Prompt   string // what git or ssh asked, e.g. "Enter passphrase for key '/home/me/.ssh/id_ed25519': "
+	Secret   bool   // the answer must not be shown while typed
+}
+
+func (e CredentialRequestedEvent) Type() EventType { return EventCredentialRequested }
+
+// CredentialProvidedEvent answers a CredentialRequestedEvent
+type CredentialProvidedEvent struct {
+	ID        string
+	Value     string
+	Cancelled bool // the user declined; the command fails
+}
+
+func (e CredentialProvidedEvent) Type() EventType { return EventCredentialProvided }
+
+// ReposVisibleEvent is emitted by a frontend whenever the repositories it shows or the one
+// under its cursor change. Status reads of these go first, the cursor's before the others;
+// repositories whose status is deferred ([status] lazy, lazy groups) are read as they come
+// into view.
+type ReposVisibleEvent struct {
+	RepoPaths []string // top to bottom
+	Cursor    string   // "" when the cursor is on no repository
+}
+
+func (e ReposVisibleEvent) Type() EventType { return EventReposVisible }
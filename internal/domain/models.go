@@ -1,29 +1,78 @@
 package domain
 
+import (
+	"strings"
+	"time"
+)
+
 // Repository represents a git repository
 type Repository struct {
 	Path        string
 	Name        string
-	DisplayName string // Name shown in UI, may include path for duplicates
-	Group       string // group name it belongs to ("" if ungrouped)
+	DisplayName string   // Name shown in UI, may include path for duplicates
+	Group       string   // group name it belongs to ("" if ungrouped)
+	WorktreeOf  string   // main repository path if this is a linked worktree
+	Tags        []string // labels from [repos], independent of the group
+	Project     string   // kind of project at its root, e.g. "Go" ("" when not recognized)
 	Status      RepoStatus
 	LastError   string       // Last command error
 	HasError    bool         // Whether there's an active error
 	CommandLogs []CommandLog // Recent command logs
 }
 
+// HasTag reports whether the repository carries a tag (case-insensitive)
+func (r *Repository) HasTag(tag string) bool {
+	for _, t := range r.Tags {
+		if strings.EqualFold(t, tag) {
+			return true
+		}
+	}
+	return false
+}
+
 // RepoStatus represents the current status of a repository
 type RepoStatus struct {
 	Branch          string
+	Upstream        string // ref ahead/behind counts are computed against ("" if none)
+	RemoteURL       string // URL of the origin remote ("" if none)
 	AheadCount      int
 	BehindCount     int
 	Uncommitted     int // number of unstaged/uncommitted changes
 	UnpushedCommits int // commits ahead of remote
 	IsDirty         bool
 	HasUntracked    bool
-	Error           string // error message if status check failed
+	Staged          int           // files with changes staged for commit
+	Modified        int           // tracked files changed but not staged
+	Untracked       int           // untracked paths git status lists (a new directory counts once; 0 in fast mode)
+	Conflicted      int           // files with unresolved merge conflicts
+	Stashes         int           // entries in the stash
+	UntrackedFiles  int           // untracked files (only counted when cleanup stats are enabled)
+	IgnoredBytes    int64         // size of ignored files present on disk (only when cleanup stats are enabled)
+	HeadCommit      string        // short hash of a detached HEAD ("" on a branch)
+	Describe        string        // git describe --tags of a detached HEAD ("" without reachable tags)
+	NoCommits       bool          // freshly initialized: Branch is the unborn branch HEAD points at
+	InProgress      Operation     // multi-step operation left in progress ("" if none)
+	StaleReason     string        // why the repository looks abandoned per [stale] ("" if it doesn't)
+	LastCommitTime  time.Time     // committer date of HEAD (zero if there are no commits)
+	LastCommitBy    string        // author name of HEAD ("" if there are no commits)
+	Duration        time.Duration // time spent computing the status (zero when loaded from the cache)
+	Error           string        // error message if status check failed
+	Stale           bool          // loaded from the status cache; a fresh status is on its way
+	Mode            string        // StatusModeFast or StatusModeBranchOnly when part of the status was skipped ("" when all of it was read)
 }
 
+// Operation identifies a multi-step git operation that is in progress in a repository
+type Operation string
+
+const (
+	OperationNone       Operation = ""
+	OperationMerge      Operation = "merge"
+	OperationRebase     Operation = "rebase"
+	OperationBisect     Operation = "bisect"
+	OperationCherryPick Operation = "cherry-pick"
+	OperationRevert     Operation = "revert"
+)
+
 // Group represents a collection of repositories
 type Group struct {
 	Name  string
@@ -39,10 +88,75 @@ type ScanProgress struct {
 
 // CommandLog represents a log entry for a command executed on a repository
 type CommandLog struct {
-	Timestamp string
-	Command   string // e.g., "fetch", "pull", "status"
-	Success   bool
-	Output    string
-	Error     string
-	Duration  int64 // milliseconds
+	Time     time.Time
+	Command  string // e.g., "fetch", "pull", "status"
+	Success  bool
+	Output   string
+	Error    string
+	Duration int64 // milliseconds
+}
+
+// WatchedCommit is a commit that appeared on a watched branch
+type WatchedCommit struct {
+	Hash    string
+	Subject string
+}
+
+// WatchAck marks a watched branch as seen up to a commit
+type WatchAck struct {
+	RepoPath string
+	Branch   string
+	Commit   string
+}
+
+// Branch is a local or remote-tracking branch of a repository
+type Branch struct {
+	Name     string // short name, e.g. "main" or "origin/main"
+	Remote   bool   // a remote-tracking branch
+	Current  bool   // the checked out branch
+	Upstream string // remote-tracking branch a local branch follows ("" if none)
+	Commit   string // abbreviated hash of the commit it points at
+}
+
+// Remote is a remote configured in a repository
+type Remote struct {
+	Name     string
+	FetchURL string
+	PushURL  string // same as FetchURL unless remote.<name>.pushurl is set
+}
+
+// FetchOptions narrows down what a fetch gets. The zero value fetches every remote,
+// following tags the way git does by default; deleted remote branches are always pruned.
+type FetchOptions struct {
+	Remote string // only this remote ("" for every remote)
+	Tags   string // FetchTagsAuto, FetchTagsAll or FetchTagsNone ("" is auto)
+	Depth  int    // fetch only this many commits of history (0 for all of it)
 }
+
+// StatusOptions select how much of a repository a status refresh reads. The zero value
+// reads everything.
+type StatusOptions struct {
+	Mode string // StatusModeFull, StatusModeFast or StatusModeBranchOnly ("" is full)
+}
+
+// How much of a repository a status refresh reads
+const (
+	StatusModeFull       = "full"        // branch, upstream and working tree, untracked files included
+	StatusModeFast       = "fast"        // like full, but without looking for untracked files
+	StatusModeBranchOnly = "branch-only" // branch and upstream only; the working tree isn't read
+)
+
+// How urgently the status of a discovered repository is first read
+const (
+	StatusPriorityHigh   = "high"   // before any other
+	StatusPriorityNormal = "normal" // in the order repositories are discovered
+	StatusPriorityLow    = "low"    // after all the others
+	StatusPriorityLazy   = "lazy"   // not until asked for, e.g. when its group is expanded
+)
+
+// How a fetch gets tags
+const (
+	FetchTagsAuto = "auto" // tags pointing into the fetched history
+	FetchTagsAll  = "all"  // every tag of the remote (--tags)
+	FetchTagsNone = "none" // no tags (--no-tags)
+)
@@ -0,0 +1,33 @@
+package domain
+
+// StatusCache persists last-known repository statuses between runs so they can be
+// shown immediately on startup while fresh statuses are computed
+type StatusCache interface {
+	// Load returns the cached status for a repository if it is still valid for the
+	// repository's current HEAD and index
+	Load(repoPath string) (RepoStatus, bool)
+	// Store records the latest status for a repository
+	Store(repoPath string, status RepoStatus)
+	// Flush writes pending changes to persistent storage
+	Flush() error
+}
+
+// WatchState remembers, per repository and watched branch, the last commit the
+// user acknowledged so new upstream commits can be reported after a fetch
+type WatchState interface {
+	// Acknowledged returns the last acknowledged commit ("" if the branch was never seen)
+	Acknowledged(repoPath, branch string) string
+	// Acknowledge records the commit the user has seen up to
+	Acknowledge(repoPath, branch, commit string)
+	// Flush writes pending changes to persistent storage
+	Flush() error
+}
+
+// Snapshot keeps the repositories of a workspace and their last statuses between runs,
+// so that the list can be shown before the first scan has found anything
+type Snapshot interface {
+	// Repos returns the repositories of the last run, their statuses marked stale
+	Repos() []Repository
+	// Flush writes pending changes to persistent storage
+	Flush() error
+}
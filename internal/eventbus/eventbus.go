@@ -1,8 +1,9 @@
 package eventbus
 
 import (
-	"gitagrip/internal/domain"
+	"github.com/darksworm/gitagrip/internal/domain"
 	"log"
+	"slices"
 	"sync"
 )
 
@@ -12,26 +13,41 @@ type EventType = domain.EventType
 
 // Event type constants
 const (
-	EventRepoDiscovered         = domain.EventRepoDiscovered
-	EventStatusUpdated          = domain.EventStatusUpdated
-	EventError                  = domain.EventError
-	EventGroupAdded             = domain.EventGroupAdded
-	EventGroupRemoved           = domain.EventGroupRemoved
-	EventRepoMoved              = domain.EventRepoMoved
-	EventScanStarted            = domain.EventScanStarted
-	EventScanCompleted          = domain.EventScanCompleted
-	EventScanRequested          = domain.EventScanRequested
-	EventStatusRefreshRequested = domain.EventStatusRefreshRequested
-	EventFetchRequested         = domain.EventFetchRequested
-	EventPullRequested          = domain.EventPullRequested
-	EventFetchCompleted         = domain.EventFetchCompleted
-	EventPullCompleted          = domain.EventPullCompleted
-	EventConfigLoaded           = domain.EventConfigLoaded
-	EventConfigSaved            = domain.EventConfigSaved
-	EventConfigChanged          = domain.EventConfigChanged
-	EventCommandExecuted        = domain.EventCommandExecuted
-	EventBranchCreateRequested  = domain.EventBranchCreateRequested
-	EventBranchSwitchRequested  = domain.EventBranchSwitchRequested
+	EventRepoDiscovered            = domain.EventRepoDiscovered
+	EventStatusUpdated             = domain.EventStatusUpdated
+	EventError                     = domain.EventError
+	EventGroupAdded                = domain.EventGroupAdded
+	EventGroupRemoved              = domain.EventGroupRemoved
+	EventRepoMoved                 = domain.EventRepoMoved
+	EventRepoRemoved               = domain.EventRepoRemoved
+	EventScanStarted               = domain.EventScanStarted
+	EventScanCompleted             = domain.EventScanCompleted
+	EventScanRequested             = domain.EventScanRequested
+	EventStatusRefreshRequested    = domain.EventStatusRefreshRequested
+	EventFetchRequested            = domain.EventFetchRequested
+	EventPullRequested             = domain.EventPullRequested
+	EventFetchCompleted            = domain.EventFetchCompleted
+	EventPullCompleted             = domain.EventPullCompleted
+	EventConfigLoaded              = domain.EventConfigLoaded
+	EventConfigSaved               = domain.EventConfigSaved
+	EventConfigChanged             = domain.EventConfigChanged
+	EventConfigSaveRequested       = domain.EventConfigSaveRequested
+	EventCommandExecuted           = domain.EventCommandExecuted
+	EventBranchCreateRequested     = domain.EventBranchCreateRequested
+	EventBranchSwitchRequested     = domain.EventBranchSwitchRequested
+	EventRemoteURLChangeRequested  = domain.EventRemoteURLChangeRequested
+	EventCleanRequested            = domain.EventCleanRequested
+	EventOperationProgress         = domain.EventOperationProgress
+	EventCancelOperationsRequested = domain.EventCancelOperationsRequested
+	EventWatchUpdated              = domain.EventWatchUpdated
+	EventWatchAcknowledgeRequested = domain.EventWatchAcknowledgeRequested
+	EventCloneMissingRequested     = domain.EventCloneMissingRequested
+	EventCredentialRequested       = domain.EventCredentialRequested
+	EventCredentialProvided        = domain.EventCredentialProvided
+	EventPushRequested             = domain.EventPushRequested
+	EventPushCompleted             = domain.EventPushCompleted
+	EventAppMessage                = domain.EventAppMessage
+	EventReposVisible              = domain.EventReposVisible
 )
 
 // Re-export domain event types
@@ -41,6 +57,7 @@ type ErrorEvent = domain.ErrorEvent
 type GroupAddedEvent = domain.GroupAddedEvent
 type GroupRemovedEvent = domain.GroupRemovedEvent
 type RepoMovedEvent = domain.RepoMovedEvent
+type RepoRemovedEvent = domain.RepoRemovedEvent
 type ScanStartedEvent = domain.ScanStartedEvent
 type ScanCompletedEvent = domain.ScanCompletedEvent
 type ScanRequestedEvent = domain.ScanRequestedEvent
@@ -52,9 +69,23 @@ type PullCompletedEvent = domain.PullCompletedEvent
 type ConfigLoadedEvent = domain.ConfigLoadedEvent
 type ConfigSavedEvent = domain.ConfigSavedEvent
 type ConfigChangedEvent = domain.ConfigChangedEvent
+type ConfigSaveRequestedEvent = domain.ConfigSaveRequestedEvent
 type CommandExecutedEvent = domain.CommandExecutedEvent
 type BranchCreateRequestedEvent = domain.BranchCreateRequestedEvent
 type BranchSwitchRequestedEvent = domain.BranchSwitchRequestedEvent
+type RemoteURLChangeRequestedEvent = domain.RemoteURLChangeRequestedEvent
+type CleanRequestedEvent = domain.CleanRequestedEvent
+type OperationProgressEvent = domain.OperationProgressEvent
+type CancelOperationsRequestedEvent = domain.CancelOperationsRequestedEvent
+type WatchUpdatedEvent = domain.WatchUpdatedEvent
+type WatchAcknowledgeRequestedEvent = domain.WatchAcknowledgeRequestedEvent
+type CloneMissingRequestedEvent = domain.CloneMissingRequestedEvent
+type CredentialRequestedEvent = domain.CredentialRequestedEvent
+type CredentialProvidedEvent = domain.CredentialProvidedEvent
+type PushRequestedEvent = domain.PushRequestedEvent
+type PushCompletedEvent = domain.PushCompletedEvent
+type AppMessageEvent = domain.AppMessageEvent
+type ReposVisibleEvent = domain.ReposVisibleEvent
 
 // EventHandler is a function that handles domain events
 type EventHandler func(DomainEvent)
@@ -65,21 +96,32 @@ type EventBus interface {
 	Subscribe(eventType EventType, handler EventHandler) func()
 }
 
-// bus is the concrete implementation of EventBus
+// subscription is a handler with the id its unsubscribe function looks it up by
+type subscription struct {
+	id      uint64
+	handler EventHandler
+}
+
+// bus is the concrete implementation of EventBus. Published events wait in an unbounded
+// queue and one dispatcher goroutine hands them to the handlers, one event at a time and
+// in the order they were published.
 type bus struct {
-	mu        sync.RWMutex
-	handlers  map[EventType][]EventHandler
-	eventChan chan DomainEvent
-	wg        sync.WaitGroup
-	quit      chan struct{}
+	mu       sync.RWMutex
+	handlers map[EventType][]subscription
+	nextID   uint64
+
+	queueMu sync.Mutex
+	pending []DomainEvent
+	stopped bool
+	wake    chan struct{}
+	wg      sync.WaitGroup
 }
 
 // New creates a new event bus
 func New() EventBus {
 	b := &bus{
-		handlers:  make(map[EventType][]EventHandler),
-		eventChan: make(chan DomainEvent, 100),
-		quit:      make(chan struct{}),
+		handlers: make(map[EventType][]subscription),
+		wake:     make(chan struct{}, 1),
 	}
 
 	// Start the event dispatcher
@@ -89,14 +131,18 @@ func New() EventBus {
 	return b
 }
 
-// Publish publishes an event to all subscribers
+// Publish queues an event for all subscribers. It never blocks and never drops an event;
+// events published after Stop are ignored.
 func (b *bus) Publish(event DomainEvent) {
+	b.queueMu.Lock()
+	defer b.queueMu.Unlock()
+	if b.stopped {
+		return
+	}
+	b.pending = append(b.pending, event)
 	select {
-	case b.eventChan <- event:
-		// Event sent successfully
+	case b.wake <- struct{}{}:
 	default:
-		// Channel full, log and drop
-		log.Printf("Event bus channel full, dropping event: %v", event.Type())
 	}
 }
 
@@ -107,70 +153,88 @@ func (b *bus) Subscribe(eventType EventType, handler EventHandler) func() {
 	defer b.mu.Unlock()
 
 	// Add handler to the list
-	b.handlers[eventType] = append(b.handlers[eventType], handler)
+	b.nextID++
+	id := b.nextID
+	b.handlers[eventType] = append(b.handlers[eventType], subscription{id: id, handler: handler})
 
 	// Return unsubscribe function
 	return func() {
-		b.unsubscribe(eventType, handler)
+		b.unsubscribe(eventType, id)
 	}
 }
 
-// unsubscribe removes a handler from the subscription list
-func (b *bus) unsubscribe(eventType EventType, handler EventHandler) {
+// unsubscribe removes a handler from the subscription list. An event already being
+// delivered may still reach it.
+func (b *bus) unsubscribe(eventType EventType, id uint64) {
 	b.mu.Lock()
 	defer b.mu.Unlock()
 
-	handlers := b.handlers[eventType]
-	for i, h := range handlers {
-		// Compare function pointers
-		if &h == &handler {
-			// Remove handler from slice
-			b.handlers[eventType] = append(handlers[:i], handlers[i+1:]...)
-			break
-		}
-	}
+	b.handlers[eventType] = slices.DeleteFunc(b.handlers[eventType], func(s subscription) bool {
+		return s.id == id
+	})
 }
 
-// dispatch runs in a goroutine and dispatches events to handlers
+// dispatch runs in a goroutine and dispatches the queued events to handlers until Stop
 func (b *bus) dispatch() {
 	defer b.wg.Done()
 
-	for {
-		select {
-		case event := <-b.eventChan:
+	for range b.wake {
+		for {
+			event, ok := b.next()
+			if !ok {
+				break
+			}
 			b.dispatchEvent(event)
-		case <-b.quit:
-			return
 		}
 	}
 }
 
-// dispatchEvent sends an event to all registered handlers
+// next takes the oldest queued event off the queue
+func (b *bus) next() (DomainEvent, bool) {
+	b.queueMu.Lock()
+	defer b.queueMu.Unlock()
+	if b.stopped || len(b.pending) == 0 {
+		return nil, false
+	}
+	event := b.pending[0]
+	b.pending[0] = nil
+	b.pending = b.pending[1:]
+	return event, true
+}
+
+// dispatchEvent hands an event to its handlers, one after the other
 func (b *bus) dispatchEvent(event DomainEvent) {
 	b.mu.RLock()
-	handlers := b.handlers[event.Type()]
 	// Make a copy of handlers to avoid holding lock during execution
-	handlersCopy := make([]EventHandler, len(handlers))
-	copy(handlersCopy, handlers)
+	handlers := slices.Clone(b.handlers[event.Type()])
 	b.mu.RUnlock()
 
-	// Execute handlers
-	for _, handler := range handlersCopy {
-		// Run each handler in a goroutine to avoid blocking
-		go func(h EventHandler) {
-			defer func() {
-				if r := recover(); r != nil {
-					log.Printf("Panic in event handler: %v", r)
-				}
-			}()
-			h(event)
-		}(handler)
+	for _, s := range handlers {
+		callHandler(s.handler, event)
 	}
 }
 
-// Stop stops the event bus (for cleanup)
+// callHandler runs one handler, so that a panicking handler doesn't take the bus down
+func callHandler(handler EventHandler, event DomainEvent) {
+	defer func() {
+		if r := recover(); r != nil {
+			log.Printf("Panic in event handler: %v", r)
+		}
+	}()
+	handler(event)
+}
+
+// Stop stops the event bus (for cleanup): the event being delivered finishes, the ones
+// still queued are dropped
 func (b *bus) Stop() {
-	close(b.quit)
+	b.queueMu.Lock()
+	if b.stopped {
+		b.queueMu.Unlock()
+		return
+	}
+	b.stopped = true
+	b.pending = nil
+	close(b.wake)
+	b.queueMu.Unlock()
 	b.wg.Wait()
-	close(b.eventChan)
 }
@@ -0,0 +1,87 @@
+package eventbus
+
+import (
+	"fmt"
+	"testing"
+	"time"
+)
+
+// collect subscribes to app messages and returns the channel their texts arrive on
+func collect(b EventBus, buffer int) (<-chan string, func()) {
+	texts := make(chan string, buffer)
+	unsubscribe := b.Subscribe(EventAppMessage, func(e DomainEvent) {
+		texts <- e.(AppMessageEvent).Text
+	})
+	return texts, unsubscribe
+}
+
+func TestBusDeliversEveryEventInOrder(t *testing.T) {
+	b := New()
+	defer b.(*bus).Stop()
+
+	// Far more than any buffer would hold, published faster than a slow handler takes them
+	const count = 1000
+	texts := make(chan string, count)
+	b.Subscribe(EventAppMessage, func(e DomainEvent) {
+		if len(texts) == 0 {
+			time.Sleep(10 * time.Millisecond)
+		}
+		texts <- e.(AppMessageEvent).Text
+	})
+	for i := range count {
+		b.Publish(AppMessageEvent{Text: fmt.Sprint(i)})
+	}
+
+	for i := range count {
+		select {
+		case text := <-texts:
+			if text != fmt.Sprint(i) {
+				t.Fatalf("event %d arrived as %s", i, text)
+			}
+		case <-time.After(5 * time.Second):
+			t.Fatalf("only %d of %d events arrived", i, count)
+		}
+	}
+}
+
+func TestBusUnsubscribeRemovesOnlyThatHandler(t *testing.T) {
+	b := New()
+	defer b.(*bus).Stop()
+
+	kept, _ := collect(b, 10)
+	removed, unsubscribe := collect(b, 10)
+	unsubscribe()
+
+	b.Publish(AppMessageEvent{Text: "after"})
+	select {
+	case <-kept:
+	case <-time.After(5 * time.Second):
+		t.Fatalf("the remaining handler got no event")
+	}
+	select {
+	case text := <-removed:
+		t.Fatalf("an unsubscribed handler got %q", text)
+	default:
+	}
+}
+
+func TestBusSurvivesAPanickingHandler(t *testing.T) {
+	b := New()
+	defer b.(*bus).Stop()
+
+	b.Subscribe(EventAppMessage, func(DomainEvent) { panic("boom") })
+	texts, _ := collect(b, 10)
+
+	b.Publish(AppMessageEvent{Text: "first"})
+	b.Publish(AppMessageEvent{Text: "second"})
+	for _, want := range []string{"first", "second"} {
+		select {
+		case text := <-texts:
+			if text != want {
+				t.Fatalf("got %q, want %q", text, want)
+			}
+		case <-time.After(5 * time.Second):
+			t.Fatalf("%q did not arrive after a handler panicked", want)
+		}
+	}
+}
@@ -0,0 +1,228 @@
+// Package forge lists the repositories of an organization on GitHub or GitLab, or on a
+// self-hosted server of either, for `gitagrip clone-org` and the TUI's clone wizard
+package forge
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"net/http"
+	"net/url"
+	"os"
+	"sort"
+	"strings"
+	"time"
+)
+
+// Hosts are the forges organizations can be listed on
+var Hosts = []string{"github", "gitlab"}
+
+// Default API URLs of the hosted forges
+const (
+	DefaultGitHubAPI = "https://api.github.com"
+	DefaultGitLabAPI = "https://gitlab.com/api/v4"
+)
+
+// perPage is the page size asked for; both forges allow 100
+const perPage = 100
+
+// Repo is a repository of an organization
+type Repo struct {
+	Name        string // path below the organization, e.g. "api" or "platform/api" for a GitLab subgroup
+	Description string
+	HTTPSURL    string
+	SSHURL      string
+	Archived    bool
+	Fork        bool
+}
+
+// CloneURL returns the URL to clone the repository from, over ssh or https
+func (r Repo) CloneURL(ssh bool) string {
+	if ssh && r.SSHURL != "" {
+		return r.SSHURL
+	}
+	return r.HTTPSURL
+}
+
+// Client lists organizations on one forge
+type Client struct {
+	Host    string // "github" or "gitlab"
+	BaseURL string // API base URL, the hosted forge's if empty
+	Token   string // private repositories need one
+	Client  *http.Client
+}
+
+// New returns a client for a host, reading the token from GITHUB_TOKEN or GITLAB_TOKEN
+func New(host, baseURL string) (*Client, error) {
+	c := &Client{Host: host, BaseURL: strings.TrimSuffix(baseURL, "/"), Client: &http.Client{Timeout: 30 * time.Second}}
+	switch host {
+	case "github":
+		c.Token = os.Getenv("GITHUB_TOKEN")
+		if c.BaseURL == "" {
+			c.BaseURL = DefaultGitHubAPI
+		}
+	case "gitlab":
+		c.Token = os.Getenv("GITLAB_TOKEN")
+		if c.BaseURL == "" {
+			c.BaseURL = DefaultGitLabAPI
+		}
+	default:
+		return nil, fmt.Errorf("unknown host %q (use %s)", host, strings.Join(Hosts, " or "))
+	}
+	return c, nil
+}
+
+// ParseOrg splits "gitlab:mygroup" into its host and organization; without a host the
+// organization is on GitHub
+func ParseOrg(s string) (host, org string) {
+	s = strings.TrimSpace(s)
+	if host, org, ok := strings.Cut(s, ":"); ok {
+		return strings.ToLower(strings.TrimSpace(host)), strings.TrimSpace(org)
+	}
+	return "github", s
+}
+
+// List returns the repositories of an organization (on GitHub also of a user), sorted by name
+func (c *Client) List(ctx context.Context, org string) ([]Repo, error) {
+	if org == "" {
+		return nil, fmt.Errorf("no organization given")
+	}
+	var repos []Repo
+	var err error
+	if c.Host == "gitlab" {
+		repos, err = c.listGitLab(ctx, org)
+	} else {
+		repos, err = c.listGitHub(ctx, org)
+	}
+	if err != nil {
+		return nil, err
+	}
+	sort.Slice(repos, func(i, j int) bool { return repos[i].Name < repos[j].Name })
+	return repos, nil
+}
+
+func (c *Client) listGitHub(ctx context.Context, org string) ([]Repo, error) {
+	type githubRepo struct {
+		Name        string `json:"name"`
+		Description string `json:"description"`
+		CloneURL    string `json:"clone_url"`
+		SSHURL      string `json:"ssh_url"`
+		Archived    bool   `json:"archived"`
+		Fork        bool   `json:"fork"`
+	}
+	list := func(kind string) ([]Repo, error) {
+		var repos []Repo
+		for page := 1; ; page++ {
+			var batch []githubRepo
+			endpoint := fmt.Sprintf("%s/%s/%s/repos?per_page=%d&page=%d", c.BaseURL, kind, url.PathEscape(org), perPage, page)
+			if err := c.get(ctx, endpoint, &batch); err != nil {
+				return nil, err
+			}
+			for _, r := range batch {
+				repos = append(repos, Repo{Name: r.Name, Description: r.Description, HTTPSURL: r.CloneURL, SSHURL: r.SSHURL, Archived: r.Archived, Fork: r.Fork})
+			}
+			if len(batch) < perPage {
+				return repos, nil
+			}
+		}
+	}
+
+	repos, err := list("orgs")
+	if errors.Is(err, ErrNotFound) {
+		// Personal accounts list their repositories as users
+		repos, err = list("users")
+	}
+	if errors.Is(err, ErrNotFound) {
+		return nil, fmt.Errorf("no organization or user %q on %s", org, c.BaseURL)
+	}
+	return repos, err
+}
+
+func (c *Client) listGitLab(ctx context.Context, group string) ([]Repo, error) {
+	type gitlabProject struct {
+		Path              string    `json:"path_with_namespace"`
+		Description       string    `json:"description"`
+		HTTPURL           string    `json:"http_url_to_repo"`
+		SSHURL            string    `json:"ssh_url_to_repo"`
+		Archived          bool      `json:"archived"`
+		ForkedFromProject *struct{} `json:"forked_from_project"`
+	}
+	var repos []Repo
+	for page := 1; ; page++ {
+		var batch []gitlabProject
+		endpoint := fmt.Sprintf("%s/groups/%s/projects?include_subgroups=true&per_page=%d&page=%d", c.BaseURL, url.PathEscape(group), perPage, page)
+		if err := c.get(ctx, endpoint, &batch); err != nil {
+			if errors.Is(err, ErrNotFound) {
+				return nil, fmt.Errorf("no group %q on %s", group, c.BaseURL)
+			}
+			return nil, err
+		}
+		for _, p := range batch {
+			// Projects of subgroups keep their subgroup as a directory
+			name := p.Path
+			if rest, ok := strings.CutPrefix(strings.ToLower(name), strings.ToLower(group)+"/"); ok {
+				name = name[len(name)-len(rest):]
+			}
+			repos = append(repos, Repo{Name: name, Description: p.Description, HTTPSURL: p.HTTPURL, SSHURL: p.SSHURL, Archived: p.Archived, Fork: p.ForkedFromProject != nil})
+		}
+		if len(batch) < perPage {
+			return repos, nil
+		}
+	}
+}
+
+// ErrNotFound is returned when the API answers 404
+var ErrNotFound = errors.New("not found")
+
+// get decodes the JSON at an API endpoint into v
+func (c *Client) get(ctx context.Context, endpoint string, v any) error {
+	if c.Host == "gitlab" {
+		return getJSON(ctx, c.Client, endpoint, v, func(req *http.Request) {
+			if c.Token != "" {
+				req.Header.Set("PRIVATE-TOKEN", c.Token)
+			}
+		})
+	}
+	return GitHubGet(ctx, c.Client, endpoint, c.Token, v)
+}
+
+// GitHubGet decodes the JSON at a GitHub API endpoint into v, sending token as a bearer
+// token when it is set. A 404 is ErrNotFound.
+func GitHubGet(ctx context.Context, client *http.Client, endpoint, token string, v any) error {
+	return getJSON(ctx, client, endpoint, v, func(req *http.Request) {
+		req.Header.Set("Accept", "application/vnd.github+json")
+		if token != "" {
+			req.Header.Set("Authorization", "Bearer "+token)
+		}
+	})
+}
+
+// getJSON decodes the JSON at an endpoint into v, letting auth set the request headers
+func getJSON(ctx context.Context, client *http.Client, endpoint string, v any, auth func(*http.Request)) error {
+	req, err := http.NewRequestWithContext(ctx, http.MethodGet, endpoint, nil)
+	if err != nil {
+		return err
+	}
+	auth(req)
+
+	resp, err := client.Do(req)
+	if err != nil {
+		return fmt.Errorf("forge API: %w", err)
+	}
+	defer func() { _ = resp.Body.Close() }()
+
+	switch resp.StatusCode {
+	case http.StatusOK:
+	case http.StatusNotFound:
+		return ErrNotFound
+	case http.StatusUnauthorized, http.StatusForbidden:
+		return fmt.Errorf("forge API: %s (check GITHUB_TOKEN or GITLAB_TOKEN)", resp.Status)
+	default:
+		return fmt.Errorf("forge API: %s", resp.Status)
+	}
+	if err := json.NewDecoder(resp.Body).Decode(v); err != nil {
+		return fmt.Errorf("forge API: %w", err)
+	}
+	return nil
+}
@@ -0,0 +1,61 @@
+package git
+
+import (
+	"sync"
+
+	"github.com/darksworm/gitagrip/internal/eventbus"
+)
+
+// Bulk operation names used in progress events
+const (
+	BulkFetch = "fetch"
+	BulkPull  = "pull"
+	BulkClone = "clone"
+	BulkPush  = "push"
+)
+
+// runBulk runs op on the repositories, up to workers at a time in list order, and
+// publishes progress after every one. A cancel request that arrives meanwhile skips the
+// repositories that have not started; those in flight are left to finish.
+func (gs *gitService) runBulk(operation string, repos []string, workers int, op func(repoPath string)) {
+	generation := gs.cancelGen.Load()
+	total := len(repos)
+
+	gs.bus.Publish(eventbus.OperationProgressEvent{Operation: operation, Done: 0, Total: total})
+
+	var mu sync.Mutex
+	next, done := 0, 0
+	cancelled := false
+	var wg sync.WaitGroup
+	for w := 0; w < max(1, min(workers, total)); w++ {
+		wg.Add(1)
+		go func() {
+			defer wg.Done()
+			for {
+				mu.Lock()
+				if next < total && gs.cancelGen.Load() != generation {
+					cancelled = true
+				}
+				if cancelled || next >= total {
+					mu.Unlock()
+					return
+				}
+				repoPath := repos[next]
+				next++
+				mu.Unlock()
+
+				op(repoPath)
+
+				mu.Lock()
+				done++
+				gs.bus.Publish(eventbus.OperationProgressEvent{Operation: operation, Done: done, Total: total})
+				mu.Unlock()
+			}
+		}()
+	}
+	wg.Wait()
+
+	if cancelled {
+		gs.bus.Publish(eventbus.OperationProgressEvent{Operation: operation, Done: done, Total: total, Cancelled: true, Skipped: repos[next:]})
+	}
+}
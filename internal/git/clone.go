@@ -0,0 +1,122 @@
+package git
+
+import (
+	"context"
+	"fmt"
+	"log"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"slices"
+	"sort"
+	"time"
+
+	"github.com/darksworm/gitagrip/internal/config"
+	"github.com/darksworm/gitagrip/internal/discovery"
+	"github.com/darksworm/gitagrip/internal/domain"
+	"github.com/darksworm/gitagrip/internal/eventbus"
+)
+
+// MissingRepos returns the paths of the repositories in [repos] that have a url but
+// are not on disk (and weren't forgotten), sorted. Relative paths are taken to be below base_dir.
+func MissingRepos(cfg *config.Config) []string {
+	if cfg == nil {
+		return nil
+	}
+	var missing []string
+	for path, settings := range cfg.Repos {
+		if settings.URL == "" || cfg.IsForgotten(path) {
+			continue
+		}
+		if _, err := os.Stat(clonePath(cfg, path)); os.IsNotExist(err) {
+			missing = append(missing, path)
+		}
+	}
+	sort.Strings(missing)
+	return missing
+}
+
+// clonePath resolves a [repos] key to the directory the repository is cloned into
+func clonePath(cfg *config.Config, path string) string {
+	if filepath.IsAbs(path) || cfg.BaseDir == "" {
+		return path
+	}
+	return filepath.Join(cfg.BaseDir, path)
+}
+
+// cloneMissing clones every missing repository in turn, or the missing ones of only when
+// given. Each clone that succeeds is announced like a discovered repository, so it shows
+// up in its configured group.
+func (gs *gitService) cloneMissing(only []string) {
+	cfg := gs.config()
+	missing := MissingRepos(cfg)
+	if len(only) > 0 {
+		missing = slices.DeleteFunc(missing, func(path string) bool { return !slices.Contains(only, path) })
+	}
+
+	gs.runBulk(BulkClone, missing, 1, func(path string) {
+		ctx, cancel := context.WithTimeout(context.Background(), 10*time.Minute)
+		defer cancel()
+
+		settings := cfg.Repos[path]
+		repoPath := clonePath(cfg, path)
+		if err := gs.cloneRepo(ctx, repoPath, settings.URL); err != nil {
+			log.Printf("Failed to clone %s: %v", repoPath, err)
+			return
+		}
+
+		name := filepath.Base(repoPath)
+		if settings.Name != "" {
+			name = settings.Name
+		}
+		gs.bus.Publish(eventbus.RepoDiscoveredEvent{Repo: domain.Repository{
+			Path:        repoPath,
+			Name:        name,
+			DisplayName: name,
+			Tags:        append([]string(nil), settings.Labels...),
+			Project:     discovery.DetectProject(repoPath),
+			Status:      domain.RepoStatus{Branch: "⋯"},
+		}})
+	})
+}
+
+// cloneRepo runs git clone into repoPath, creating its parent directories
+func (gs *gitService) cloneRepo(ctx context.Context, repoPath, url string) error {
+	startTime := time.Now()
+
+	// Acquire worker slot
+	select {
+	case gs.workerPool <- struct{}{}:
+		defer func() { <-gs.workerPool }()
+	case <-ctx.Done():
+		return ctx.Err()
+	}
+
+	var output []byte
+	err := os.MkdirAll(filepath.Dir(repoPath), 0755)
+	if err == nil {
+		cmd := exec.CommandContext(ctx, "git", "clone", url, repoPath)
+		env, done := gs.askpass.env(repoPath)
+		cmd.Env = env
+		output, err = cmd.CombinedOutput()
+		done()
+	}
+
+	event := eventbus.CommandExecutedEvent{
+		RepoPath: repoPath,
+		Command:  "clone",
+		Success:  err == nil,
+		Output:   string(output),
+		Duration: time.Since(startTime).Milliseconds(),
+	}
+	if err != nil {
+		event.Error = err.Error()
+		event.Reason = authFailureReason(string(output))
+	}
+	gs.bus.Publish(event)
+
+	if err != nil {
+		return fmt.Errorf("git clone %s failed: %v\nOutput: %s", url, err, output)
+	}
+	return nil
+}
@@ -0,0 +1,251 @@
+package git
+
+import (
+	"bufio"
+	"encoding/json"
+	"fmt"
+	"log"
+	"net"
+	"os"
+	"path/filepath"
+	"strconv"
+	"strings"
+	"sync"
+	"time"
+
+	"github.com/darksworm/gitagrip/internal/eventbus"
+)
+
+// Git and ssh ask for passwords, passphrases and host key confirmations through a helper
+// program when they can't use the terminal, which the UI owns. gitagrip is its own helper:
+// network commands run with GIT_ASKPASS and SSH_ASKPASS pointing at the gitagrip binary,
+// which hands the prompt over a unix socket to the running instance. That publishes a
+// CredentialRequestedEvent and replies with the CredentialProvidedEvent sent back.
+const (
+	askpassSocketEnv = "GITAGRIP_ASKPASS_SOCKET"
+	askpassRepoEnv   = "GITAGRIP_ASKPASS_REPO"
+)
+
+// askpassTimeout is how long a prompt waits for an answer before its command fails
+const askpassTimeout = 5 * time.Minute
+
+// askpassRequest and askpassReply are the helper's one exchange over the socket
+type askpassRequest struct {
+	Repo   string `json:"repo"`
+	Prompt string `json:"prompt"`
+}
+
+type askpassReply struct {
+	Value string `json:"value"`
+	OK    bool   `json:"ok"`
+}
+
+// IsAskpass reports whether the process was started by git or ssh to ask for a credential
+func IsAskpass(args []string) bool {
+	return os.Getenv(askpassSocketEnv) != "" && len(args) == 1
+}
+
+// RunAskpass asks the gitagrip instance that started the command for the answer to prompt
+// and prints it for git or ssh. It returns the exit code: 1 when the user declined or the
+// instance can't be reached, which makes the command fail.
+func RunAskpass(prompt string) int {
+	conn, err := net.Dial("unix", os.Getenv(askpassSocketEnv))
+	if err != nil {
+		fmt.Fprintf(os.Stderr, "gitagrip: cannot ask for credentials: %v\n", err)
+		return 1
+	}
+	defer func() { _ = conn.Close() }()
+
+	if err := json.NewEncoder(conn).Encode(askpassRequest{Repo: os.Getenv(askpassRepoEnv), Prompt: prompt}); err != nil {
+		fmt.Fprintf(os.Stderr, "gitagrip: cannot ask for credentials: %v\n", err)
+		return 1
+	}
+	var reply askpassReply
+	if err := json.NewDecoder(conn).Decode(&reply); err != nil || !reply.OK {
+		return 1
+	}
+	fmt.Println(reply.Value)
+	return 0
+}
+
+// askpass serves the prompts of running network commands. The socket only exists while
+// at least one of them runs.
+type askpass struct {
+	bus eventbus.EventBus
+
+	mu       sync.Mutex
+	users    int
+	dir      string
+	listener net.Listener
+	pending  map[string]chan eventbus.CredentialProvidedEvent
+	nextID   int
+}
+
+func newAskpass(bus eventbus.EventBus) *askpass {
+	a := &askpass{bus: bus, pending: make(map[string]chan eventbus.CredentialProvidedEvent)}
+	bus.Subscribe(eventbus.EventCredentialProvided, func(e eventbus.DomainEvent) {
+		if event, ok := e.(eventbus.CredentialProvidedEvent); ok {
+			a.mu.Lock()
+			reply, ok := a.pending[event.ID]
+			delete(a.pending, event.ID)
+			a.mu.Unlock()
+			if ok {
+				reply <- event
+			}
+		}
+	})
+	return a
+}
+
+// env returns the environment of a network command in repoPath, and a function to call
+// once it has finished. Without the helper, git and ssh fail right away instead of
+// prompting on the terminal.
+func (a *askpass) env(repoPath string) ([]string, func()) {
+	env := append(os.Environ(), "GIT_TERMINAL_PROMPT=0")
+	self, err := os.Executable()
+	if err == nil {
+		err = a.acquire()
+	}
+	if err != nil {
+		log.Printf("Credential prompts unavailable: %v", err)
+		return append(env, "GIT_SSH_COMMAND=ssh -o BatchMode=yes"), func() {}
+	}
+	return append(env,
+		"GIT_ASKPASS="+self,
+		"SSH_ASKPASS="+self,
+		"SSH_ASKPASS_REQUIRE=force", // OpenSSH 8.4+; older versions prompt on the terminal
+		askpassSocketEnv+"="+filepath.Join(a.dir, "socket"),
+		askpassRepoEnv+"="+repoPath,
+	), a.release
+}
+
+// acquire starts listening when the first network command starts
+func (a *askpass) acquire() error {
+	a.mu.Lock()
+	defer a.mu.Unlock()
+	if a.users == 0 {
+		dir, err := os.MkdirTemp("", "gitagrip-askpass-")
+		if err != nil {
+			return err
+		}
+		listener, err := net.Listen("unix", filepath.Join(dir, "socket"))
+		if err != nil {
+			_ = os.RemoveAll(dir)
+			return err
+		}
+		a.dir, a.listener = dir, listener
+		go a.serve(listener)
+	}
+	a.users++
+	return nil
+}
+
+// release stops listening when the last network command has finished
+func (a *askpass) release() {
+	a.mu.Lock()
+	defer a.mu.Unlock()
+	a.users--
+	if a.users == 0 {
+		_ = a.listener.Close()
+		_ = os.RemoveAll(a.dir)
+		a.listener, a.dir = nil, ""
+	}
+}
+
+func (a *askpass) serve(listener net.Listener) {
+	for {
+		conn, err := listener.Accept()
+		if err != nil {
+			return
+		}
+		go a.answer(conn)
+	}
+}
+
+// answer publishes one helper's prompt and writes back the answer, or a refusal when
+// none comes in time
+func (a *askpass) answer(conn net.Conn) {
+	defer func() { _ = conn.Close() }()
+	var request askpassRequest
+	if err := json.NewDecoder(bufio.NewReader(conn)).Decode(&request); err != nil {
+		return
+	}
+
+	a.mu.Lock()
+	a.nextID++
+	id := strconv.Itoa(a.nextID)
+	reply := make(chan eventbus.CredentialProvidedEvent, 1)
+	a.pending[id] = reply
+	a.mu.Unlock()
+
+	a.bus.Publish(eventbus.CredentialRequestedEvent{
+		ID:       id,
+		RepoPath: request.Repo,
+		Prompt:   request.Prompt,
+		Secret:   isSecretPrompt(request.Prompt),
+	})
+
+	var answer askpassReply
+	select {
+	case event := <-reply:
+		answer = askpassReply{Value: event.Value, OK: !event.Cancelled}
+	case <-time.After(askpassTimeout):
+		a.mu.Lock()
+		delete(a.pending, id)
+		a.mu.Unlock()
+	}
+	_ = json.NewEncoder(conn).Encode(answer)
+}
+
+// isSecretPrompt tells passwords and passphrases apart from usernames and host key
+// confirmations, which can be shown while typed
+func isSecretPrompt(prompt string) bool {
+	prompt = strings.ToLower(prompt)
+	for _, word := range []string{"password", "passphrase", "pin", "token"} {
+		if strings.Contains(prompt, word) {
+			return true
+		}
+	}
+	return false
+}
+
+// authFailures maps what git and ssh print when authenticating fails to what to do about it
+var authFailures = []struct{ marker, reason string }{
+	{"Permission denied (publickey", "SSH key rejected (add it to ssh-agent or the forge)"},
+	{"Host key verification failed", "host key not trusted (connect once with ssh to accept it)"},
+	{"Authentication failed for", "authentication failed (check the token or credential helper)"},
+	{"could not read Username", "no credentials (configure a credential helper)"},
+	{"could not read Password", "no credentials (configure a credential helper)"},
+	{"terminal prompts disabled", "credential prompt declined"},
+}
+
+// authFailureReason explains per remote why a network command could not authenticate
+// ("" when it failed for another reason). git fetch --all names each remote with a
+// "Fetching <remote>" line before its output.
+func authFailureReason(output string) string {
+	remote := ""
+	var reasons []string
+	seen := make(map[string]bool)
+	for _, line := range strings.Split(output, "\n") {
+		line = strings.TrimSpace(line)
+		if name, ok := strings.CutPrefix(line, "Fetching "); ok {
+			remote = name
+			continue
+		}
+		for _, failure := range authFailures {
+			if !strings.Contains(line, failure.marker) {
+				continue
+			}
+			reason := failure.reason
+			if remote != "" {
+				reason = remote + ": " + reason
+			}
+			if !seen[reason] {
+				seen[reason] = true
+				reasons = append(reasons, reason)
+			}
+			break
+		}
+	}
+	return strings.Join(reasons, "; ")
+}
@@ -1,43 +1,116 @@
 package git
 
 import (
-	"bytes"
 	"context"
+	"errors"
 	"fmt"
 	"log"
+	"os"
 	"os/exec"
 	"path/filepath"
 	"strconv"
 	"strings"
 	"sync"
+	"sync/atomic"
 	"time"
 
-	"gitagrip/internal/domain"
-	"gitagrip/internal/eventbus"
+	"github.com/darksworm/gitagrip/internal/config"
+	"github.com/darksworm/gitagrip/internal/domain"
+	"github.com/darksworm/gitagrip/internal/eventbus"
 )
 
 // GitService handles git repository operations
 type GitService interface {
+	// RefreshRepo reads and publishes the status of a repository, as much of it as [status]
+	// selects for it
 	RefreshRepo(ctx context.Context, repoPath string) (domain.RepoStatus, error)
+	// Status reads and publishes the status of a repository with the given options
+	Status(ctx context.Context, repoPath string, opts domain.StatusOptions) (domain.RepoStatus, error)
 	RefreshAll(ctx context.Context, repos []domain.Repository)
 	StartBackgroundRefresh(ctx context.Context, interval time.Duration)
+
+	// Branches lists the local branches, then the remote-tracking ones, by name
+	Branches(ctx context.Context, repoPath string) ([]domain.Branch, error)
+	// Remotes lists the configured remotes by name
+	Remotes(ctx context.Context, repoPath string) ([]domain.Remote, error)
+	// UpstreamOf returns the ref a branch is compared against ("" if none), honoring
+	// [upstream] overrides and the preferred remote
+	UpstreamOf(ctx context.Context, repoPath, branch string) string
+	// AheadBehind counts the commits on a that b lacks, and those on b that a lacks
+	AheadBehind(ctx context.Context, repoPath, a, b string) (ahead, behind int, err error)
+	// Push pushes the current branch to its tracking branch (or a same-named one on the
+	// preferred remote or origin) and returns that branch. A rejection is a *PushError.
+	Push(ctx context.Context, repoPath string) (string, error)
+	// SetConfig replaces the config that later operations read
+	SetConfig(cfg *config.Config)
+	// Close stops the background status reads; queued ones are dropped
+	Close()
 }
 
 // gitService is the concrete implementation
 type gitService struct {
 	bus        eventbus.EventBus
+	// replaced whole by SetConfig rather than changed under the goroutines reading it
+	cfg        atomic.Pointer[config.Config]
 	mu         sync.Mutex
 	knownRepos map[string]bool
-	workerPool chan struct{} // Semaphore for limiting concurrent git operations
+	lazy       map[string]bool    // known repos whose status is deferred until shown or asked for
+	visible    map[string]bool    // repos a frontend shows (nil until one reports them)
+	refreshes  *refreshQueue      // status reads waiting for a worker, the repos on screen first
+	startup    sync.Once          // fetches the fetch_on_start groups after the first scan
+	workerPool chan struct{}      // Semaphore for limiting concurrent git operations
+	cache      domain.StatusCache // last-known statuses (nil disables caching)
+	watches    domain.WatchState  // acknowledged commits of watched branches (nil disables watches)
+	cancelGen  atomic.Uint64      // bumped to cancel queued work of running bulk operations
+	askpass    *askpass           // answers credential prompts of network commands through the UI
+	hosts      hostSlots          // fetches running against each remote host
+}
+
+// config returns the current config (nil without one)
+func (gs *gitService) config() *config.Config {
+	return gs.cfg.Load()
+}
+
+// SetConfig replaces the config that later operations read
+func (gs *gitService) SetConfig(cfg *config.Config) {
+	gs.cfg.Store(cfg)
 }
 
 // NewGitService creates a new git service
 func NewGitService(bus eventbus.EventBus) GitService {
+	return NewGitServiceWithConfig(bus, nil)
+}
+
+// NewGitServiceWithConfig creates a git service that honors repository settings from config
+func NewGitServiceWithConfig(bus eventbus.EventBus, cfg *config.Config) GitService {
+	return NewGitServiceWithCache(bus, cfg, nil)
+}
+
+// NewGitServiceWithCache creates a git service that publishes cached statuses for newly
+// discovered repositories before computing fresh ones, and keeps the cache up to date
+func NewGitServiceWithCache(bus eventbus.EventBus, cfg *config.Config, cache domain.StatusCache) GitService {
+	return NewGitServiceWithWatches(bus, cfg, cache, nil)
+}
+
+// NewGitServiceWithWatches creates a git service that additionally reports new upstream
+// commits on the branches listed in the config's [[watches]] after each fetch
+func NewGitServiceWithWatches(bus eventbus.EventBus, cfg *config.Config, cache domain.StatusCache, watches domain.WatchState) GitService {
+	// Limit concurrent git operations, leaving room for as many fetches as [fetch] allows
+	workers := 5
+	if cfg != nil {
+		workers = max(workers, cfg.Fetch.MaxConcurrent())
+	}
 	gs := &gitService{
 		bus:        bus,
 		knownRepos: make(map[string]bool),
-		workerPool: make(chan struct{}, 5), // Limit to 5 concurrent git operations
+		lazy:       make(map[string]bool),
+		refreshes:  newRefreshQueue(),
+		workerPool: make(chan struct{}, workers),
+		cache:      cache,
+		watches:    watches,
+		askpass:    newAskpass(bus),
 	}
+	gs.cfg.Store(cfg)
 
 	// Subscribe to repo discovery events
 	bus.Subscribe(eventbus.EventRepoDiscovered, func(e eventbus.DomainEvent) {
@@ -46,15 +119,43 @@ func NewGitService(bus eventbus.EventBus) GitService {
 			gs.knownRepos[event.Repo.Path] = true
 			gs.mu.Unlock()
 
-			// Get initial status
-			go func() {
-				ctx, cancel := context.WithTimeout(context.Background(), 30*time.Second)
-				defer cancel()
-				_, _ = gs.RefreshRepo(ctx, event.Repo.Path)
-			}()
+			// Show the last-known status right away, marked stale until refreshed
+			if gs.cache != nil {
+				if status, ok := gs.cache.Load(event.Repo.Path); ok {
+					status.Stale = true
+					gs.publishStatus(event.Repo.Path, status)
+				}
+			}
+
+			// Get initial status, in the order of the groups' status priorities
+			gs.scheduleStatus(event.Repo.Path)
+		}
+	})
+	go gs.runRefreshQueue()
+
+	// Repositories that are gone are no longer refreshed or fetched
+	bus.Subscribe(eventbus.EventRepoRemoved, func(e eventbus.DomainEvent) {
+		if event, ok := e.(eventbus.RepoRemovedEvent); ok {
+			gs.mu.Lock()
+			delete(gs.knownRepos, event.RepoPath)
+			delete(gs.lazy, event.RepoPath)
+			gs.mu.Unlock()
+			gs.refreshes.remove(event.RepoPath)
+		}
+	})
+
+	// Deferred repositories are read as a frontend shows them
+	bus.Subscribe(eventbus.EventReposVisible, func(e eventbus.DomainEvent) {
+		if event, ok := e.(eventbus.ReposVisibleEvent); ok {
+			gs.showRepos(event.RepoPaths, event.Cursor)
 		}
 	})
 
+	// Groups that fetch on start do so once the first scan has found their repositories
+	bus.Subscribe(eventbus.EventScanCompleted, func(e eventbus.DomainEvent) {
+		gs.startup.Do(gs.fetchOnStart)
+	})
+
 	// Subscribe to status refresh requests
 	bus.Subscribe(eventbus.EventStatusRefreshRequested, func(e eventbus.DomainEvent) {
 		if event, ok := e.(eventbus.StatusRefreshRequestedEvent); ok {
@@ -64,15 +165,10 @@ func NewGitService(bus eventbus.EventBus) GitService {
 
 				if len(event.RepoPaths) == 0 {
 					// Refresh all known repos
-					gs.mu.Lock()
-					repos := make([]domain.Repository, 0, len(gs.knownRepos))
-					for path := range gs.knownRepos {
-						repos = append(repos, domain.Repository{Path: path})
-					}
-					gs.mu.Unlock()
-					gs.RefreshAll(ctx, repos)
+					gs.RefreshAll(ctx, gs.refreshableRepos())
 				} else {
 					// Refresh specific repos
+					gs.wantStatus(event.RepoPaths)
 					repos := make([]domain.Repository, 0, len(event.RepoPaths))
 					for _, path := range event.RepoPaths {
 						repos = append(repos, domain.Repository{Path: path})
@@ -87,9 +183,6 @@ func NewGitService(bus eventbus.EventBus) GitService {
 	bus.Subscribe(eventbus.EventFetchRequested, func(e eventbus.DomainEvent) {
 		if event, ok := e.(eventbus.FetchRequestedEvent); ok {
 			go func() {
-				ctx, cancel := context.WithTimeout(context.Background(), 120*time.Second) // Longer timeout for network ops
-				defer cancel()
-
 				var repos []string
 				if len(event.RepoPaths) == 0 {
 					// Fetch all known repos
@@ -102,10 +195,15 @@ func NewGitService(bus eventbus.EventBus) GitService {
 					repos = event.RepoPaths
 				}
 
-				// Fetch each repository
-				for _, repoPath := range repos {
-					err := gs.fetchRepo(ctx, repoPath)
+				// Fetch several repositories at once, each with its own timeout
+				var fetched, failed atomic.Int32
+				gs.runBulk(BulkFetch, repos, gs.fetchSettings().MaxConcurrent(), func(repoPath string) {
+					ctx, cancel := context.WithTimeout(context.Background(), 120*time.Second) // Longer timeout for network ops
+					defer cancel()
+
+					err := gs.fetchRepo(ctx, repoPath, event.Options)
 					if err != nil {
+						failed.Add(1)
 						log.Printf("Failed to fetch %s: %v", repoPath, err)
 						gs.bus.Publish(eventbus.FetchCompletedEvent{
 							RepoPath: repoPath,
@@ -113,6 +211,7 @@ func NewGitService(bus eventbus.EventBus) GitService {
 							Error:    err,
 						})
 					} else {
+						fetched.Add(1)
 						gs.bus.Publish(eventbus.FetchCompletedEvent{
 							RepoPath: repoPath,
 							Success:  true,
@@ -120,12 +219,28 @@ func NewGitService(bus eventbus.EventBus) GitService {
 						})
 						// Refresh status after successful fetch
 						_, _ = gs.RefreshRepo(ctx, repoPath)
+						gs.checkWatches(ctx, repoPath)
 					}
-				}
+				})
+				gs.publishFetchSummary(int(fetched.Load()), int(failed.Load()), len(repos))
 			}()
 		}
 	})
 
+	// Subscribe to requests to clone configured repositories that are missing
+	bus.Subscribe(eventbus.EventCloneMissingRequested, func(e eventbus.DomainEvent) {
+		if event, ok := e.(eventbus.CloneMissingRequestedEvent); ok {
+			go gs.cloneMissing(event.RepoPaths)
+		}
+	})
+
+	// Subscribe to acknowledgements of watched changes
+	bus.Subscribe(eventbus.EventWatchAcknowledgeRequested, func(e eventbus.DomainEvent) {
+		if event, ok := e.(eventbus.WatchAcknowledgeRequestedEvent); ok {
+			gs.acknowledgeWatches(event.Acks)
+		}
+	})
+
 	// Subscribe to pull requests
 	bus.Subscribe(eventbus.EventPullRequested, func(e eventbus.DomainEvent) {
 		if event, ok := e.(eventbus.PullRequestedEvent); ok {
@@ -146,7 +261,7 @@ func NewGitService(bus eventbus.EventBus) GitService {
 				}
 
 				// Pull each repository
-				for _, repoPath := range repos {
+				gs.runBulk(BulkPull, repos, 1, func(repoPath string) {
 					err := gs.pullRepo(ctx, repoPath)
 					if err != nil {
 						log.Printf("Failed to pull %s: %v", repoPath, err)
@@ -156,34 +271,6 @@ func NewGitService(bus eventbus.EventBus) GitService {
 							Error:    err,
 						})
 
-						// Subscribe to branch create requests
-						bus.Subscribe(eventbus.EventBranchCreateRequested, func(e eventbus.DomainEvent) {
-							if event, ok := e.(eventbus.BranchCreateRequestedEvent); ok {
-								go func() {
-									ctx, cancel := context.WithTimeout(context.Background(), 120*time.Second)
-									defer cancel()
-									for _, path := range event.RepoPaths {
-										_ = gs.createBranch(ctx, path, event.Name)
-										// Refresh after branch creation
-										_, _ = gs.RefreshRepo(ctx, path)
-									}
-								}()
-							}
-						})
-
-						// Subscribe to branch switch requests
-						bus.Subscribe(eventbus.EventBranchSwitchRequested, func(e eventbus.DomainEvent) {
-							if event, ok := e.(eventbus.BranchSwitchRequestedEvent); ok {
-								go func() {
-									ctx, cancel := context.WithTimeout(context.Background(), 120*time.Second)
-									defer cancel()
-									for _, path := range event.RepoPaths {
-										_ = gs.switchBranch(ctx, path, event.Name)
-										_, _ = gs.RefreshRepo(ctx, path)
-									}
-								}()
-							}
-						})
 						// Also publish error event for UI notification
 						gs.bus.Publish(eventbus.ErrorEvent{
 							Message: fmt.Sprintf("Pull failed for %s", filepath.Base(repoPath)),
@@ -198,6 +285,96 @@ func NewGitService(bus eventbus.EventBus) GitService {
 						// Refresh status after successful pull
 						_, _ = gs.RefreshRepo(ctx, repoPath)
 					}
+				})
+			}()
+		}
+	})
+
+	// Subscribe to push requests
+	bus.Subscribe(eventbus.EventPushRequested, func(e eventbus.DomainEvent) {
+		if event, ok := e.(eventbus.PushRequestedEvent); ok {
+			go func() {
+				// Push several repositories at once, each with its own timeout
+				gs.runBulk(BulkPush, event.RepoPaths, gs.fetchSettings().MaxConcurrent(), func(repoPath string) {
+					ctx, cancel := context.WithTimeout(context.Background(), 120*time.Second)
+					defer cancel()
+
+					branch, err := gs.Push(ctx, repoPath)
+					completed := eventbus.PushCompletedEvent{RepoPath: repoPath, Branch: branch, Success: err == nil, Error: err}
+					var pushErr *PushError
+					var skipped *SkippedError
+					switch {
+					case errors.As(err, &pushErr):
+						completed.Reason = pushErr.Reason
+					case errors.As(err, &skipped):
+						completed.Reason = skipped.Error()
+					}
+					if err != nil {
+						log.Printf("Failed to push %s: %v", repoPath, err)
+					}
+					gs.bus.Publish(completed)
+					// Ahead counts change after a push
+					_, _ = gs.RefreshRepo(ctx, repoPath)
+				})
+			}()
+		}
+	})
+
+	// Subscribe to branch create requests
+	bus.Subscribe(eventbus.EventBranchCreateRequested, func(e eventbus.DomainEvent) {
+		if event, ok := e.(eventbus.BranchCreateRequestedEvent); ok {
+			go func() {
+				ctx, cancel := context.WithTimeout(context.Background(), 120*time.Second)
+				defer cancel()
+				for _, path := range event.RepoPaths {
+					_ = gs.createBranch(ctx, path, event.Name)
+					// Refresh after branch creation
+					_, _ = gs.RefreshRepo(ctx, path)
+				}
+			}()
+		}
+	})
+
+	// Subscribe to branch switch requests
+	bus.Subscribe(eventbus.EventBranchSwitchRequested, func(e eventbus.DomainEvent) {
+		if event, ok := e.(eventbus.BranchSwitchRequestedEvent); ok {
+			go func() {
+				ctx, cancel := context.WithTimeout(context.Background(), 120*time.Second)
+				defer cancel()
+				for _, path := range event.RepoPaths {
+					_ = gs.switchBranch(ctx, path, event.Name)
+					_, _ = gs.RefreshRepo(ctx, path)
+				}
+			}()
+		}
+	})
+
+	// Subscribe to cancel requests for bulk operations
+	bus.Subscribe(eventbus.EventCancelOperationsRequested, func(e eventbus.DomainEvent) {
+		gs.cancelGen.Add(1)
+	})
+
+	// Subscribe to remote URL rewrites
+	bus.Subscribe(eventbus.EventRemoteURLChangeRequested, func(e eventbus.DomainEvent) {
+		if event, ok := e.(eventbus.RemoteURLChangeRequestedEvent); ok {
+			go func() {
+				ctx, cancel := context.WithTimeout(context.Background(), 30*time.Second)
+				defer cancel()
+				_ = gs.setRemoteURL(ctx, event.RepoPath, event.Remote, event.URL)
+				_, _ = gs.RefreshRepo(ctx, event.RepoPath)
+			}()
+		}
+	})
+
+	// Subscribe to clean requests
+	bus.Subscribe(eventbus.EventCleanRequested, func(e eventbus.DomainEvent) {
+		if event, ok := e.(eventbus.CleanRequestedEvent); ok {
+			go func() {
+				for _, path := range event.RepoPaths {
+					ctx, cancel := context.WithTimeout(context.Background(), 120*time.Second)
+					_ = gs.cleanRepo(ctx, path)
+					_, _ = gs.RefreshRepo(ctx, path)
+					cancel()
 				}
 			}()
 		}
@@ -208,6 +385,17 @@ func NewGitService(bus eventbus.EventBus) GitService {
 
 // RefreshRepo refreshes the status of a single repository
 func (gs *gitService) RefreshRepo(ctx context.Context, repoPath string) (domain.RepoStatus, error) {
+	cfg := gs.config()
+	opts := domain.StatusOptions{}
+	if cfg != nil {
+		opts = cfg.StatusOptions(repoPath)
+	}
+	return gs.Status(ctx, repoPath, opts)
+}
+
+// Status reads the status of a repository, skipping untracked files in fast mode and the
+// whole working tree in branch-only mode
+func (gs *gitService) Status(ctx context.Context, repoPath string, opts domain.StatusOptions) (domain.RepoStatus, error) {
 	// Acquire worker slot
 	select {
 	case gs.workerPool <- struct{}{}:
@@ -217,31 +405,87 @@ func (gs *gitService) RefreshRepo(ctx context.Context, repoPath string) (domain.
 	}
 
 	status := domain.RepoStatus{}
+	if opts.Mode != domain.StatusModeFull {
+		status.Mode = opts.Mode
+	}
+	start := time.Now()
 
 	// Get current branch
 	branch, err := gs.getCurrentBranch(ctx, repoPath)
 	if err != nil {
-		status.Error = fmt.Sprintf("Failed to get branch: %v", err)
+		// A freshly initialized repository has a HEAD but nothing it resolves to yet
+		if unborn := gs.unbornBranch(ctx, repoPath); unborn != "" {
+			branch, err = unborn, nil
+			status.NoCommits = true
+		}
+	}
+	if err != nil {
+		status.Error = fmt.Sprintf("Failed to get branch: %s", gitErrorReason(err))
+		status.Duration = time.Since(start)
 		gs.publishStatus(repoPath, status)
 		return status, err
 	}
 	status.Branch = branch
 
-	// Get working tree status
-	isDirty, hasUntracked, err := gs.getWorkingTreeStatus(ctx, repoPath)
-	if err != nil {
-		log.Printf("Failed to get working tree status for %s: %v", repoPath, err)
+	// Which commit a detached HEAD is on, and how it relates to the nearest tag
+	if sha, ok := strings.CutPrefix(branch, "detached@"); ok {
+		status.HeadCommit = sha
+		status.Describe = gs.describeHead(ctx, repoPath)
 	}
-	status.IsDirty = isDirty
-	status.HasUntracked = hasUntracked
 
-	// Get ahead/behind counts
-	ahead, behind, err := gs.getAheadBehind(ctx, repoPath, branch)
-	if err != nil {
-		log.Printf("Failed to get ahead/behind for %s: %v", repoPath, err)
+	// Get working tree status (branch-only mode leaves it out)
+	if opts.Mode != domain.StatusModeBranchOnly {
+		tree, err := gs.getWorkingTreeStatus(ctx, repoPath, opts.Mode != domain.StatusModeFast)
+		if err != nil {
+			log.Printf("Failed to get working tree status for %s: %v", repoPath, err)
+			status.Error = fmt.Sprintf("Failed to read working tree: %s", gitErrorReason(err))
+		}
+		status.IsDirty = tree.isDirty()
+		status.HasUntracked = tree.untracked > 0
+		status.Staged = tree.staged
+		status.Modified = tree.modified
+		status.Untracked = tree.untracked
+		status.Conflicted = tree.conflicted
+		status.Uncommitted = tree.changed
+		status.Stashes = gs.stashCount(ctx, repoPath)
+	}
+
+	// Untracked file count and ignored size (walks the working tree, so opt-in and only
+	// in full mode)
+	if cfg := gs.config(); cfg != nil && cfg.UISettings.ShowCleanupStats && status.Mode == "" {
+		status.UntrackedFiles, status.IgnoredBytes = gs.getCleanupStats(ctx, repoPath)
+	}
+
+	// Origin URL (used by the remote migration assistant)
+	status.RemoteURL = gs.gitConfigValue(ctx, repoPath, "remote.origin.url")
+
+	// Detect merge/rebase/bisect left in progress
+	status.InProgress = gs.getInProgressOperation(ctx, repoPath)
+
+	// Without commits there is no last commit and nothing to compare with the upstream
+	if !status.NoCommits {
+		// Date and author of the last commit (sorting by recent activity, ui.show_last_commit)
+		status.LastCommitTime, status.LastCommitBy = gs.getLastCommit(ctx, repoPath)
+
+		// Get ahead/behind counts
+		upstream := gs.resolveUpstream(ctx, repoPath, branch)
+		status.Upstream = upstream
+		ahead, behind, err := gs.getAheadBehind(ctx, repoPath, branch, upstream)
+		if err != nil {
+			log.Printf("Failed to get ahead/behind for %s: %v", repoPath, err)
+		}
+		status.AheadCount = ahead
+		status.BehindCount = behind
+
+		// Gone upstream or an old last commit ([stale])
+		status.StaleReason = gs.staleReason(ctx, repoPath, branch, status.LastCommitTime)
+	}
+	status.Duration = time.Since(start)
+
+	// Failed statuses are not cached so the next start retries them
+	if gs.cache != nil && status.Error == "" {
+		gs.cache.Store(repoPath, status)
 	}
-	status.AheadCount = ahead
-	status.BehindCount = behind
 
 	// Publish status update
 	gs.publishStatus(repoPath, status)
@@ -249,30 +493,19 @@ func (gs *gitService) RefreshRepo(ctx context.Context, repoPath string) (domain.
 	return status, nil
 }
 
-// RefreshAll refreshes the status of all repositories
+// RefreshAll queues the status reads of the repositories, which run the ones on screen
+// first, and waits for them
 func (gs *gitService) RefreshAll(ctx context.Context, repos []domain.Repository) {
-	var wg sync.WaitGroup
-
+	done := make([]<-chan struct{}, 0, len(repos))
 	for _, repo := range repos {
-		wg.Add(1)
-		go func(r domain.Repository) {
-			defer wg.Done()
-			_, _ = gs.RefreshRepo(ctx, r.Path)
-		}(repo)
+		done = append(done, gs.refreshes.push(repo.Path, gs.statusPriority(repo.Path)))
 	}
-
-	// Wait with timeout
-	done := make(chan struct{})
-	go func() {
-		wg.Wait()
-		close(done)
-	}()
-
-	select {
-	case <-done:
-		// All refreshes completed
-	case <-ctx.Done():
-		// Context cancelled
+	for _, read := range done {
+		select {
+		case <-read:
+		case <-ctx.Done():
+			return // the reads stay queued
+		}
 	}
 }
 
@@ -284,13 +517,7 @@ func (gs *gitService) StartBackgroundRefresh(ctx context.Context, interval time.
 	for {
 		select {
 		case <-ticker.C:
-			gs.mu.Lock()
-			repos := make([]domain.Repository, 0, len(gs.knownRepos))
-			for path := range gs.knownRepos {
-				repos = append(repos, domain.Repository{Path: path})
-			}
-			gs.mu.Unlock()
-
+			repos := gs.refreshableRepos()
 			if len(repos) > 0 {
 				refreshCtx, cancel := context.WithTimeout(ctx, interval)
 				gs.RefreshAll(refreshCtx, repos)
@@ -328,62 +555,316 @@ func (gs *gitService) getCurrentBranch(ctx context.Context, repoPath string) (st
 	return branch, nil
 }
 
-// getWorkingTreeStatus checks if the working tree is dirty or has untracked files
-func (gs *gitService) getWorkingTreeStatus(ctx context.Context, repoPath string) (isDirty bool, hasUntracked bool, err error) {
+// describeHead returns git describe --tags for HEAD ("" if no tag is reachable)
+func (gs *gitService) describeHead(ctx context.Context, repoPath string) string {
+	cmd := exec.CommandContext(ctx, "git", "describe", "--tags")
+	cmd.Dir = repoPath
+
+	output, err := cmd.Output()
+	if err != nil {
+		return ""
+	}
+	return strings.TrimSpace(string(output))
+}
+
+// unbornBranch returns the branch HEAD points at when it has no commits yet ("" otherwise)
+func (gs *gitService) unbornBranch(ctx context.Context, repoPath string) string {
+	cmd := exec.CommandContext(ctx, "git", "symbolic-ref", "--short", "HEAD")
+	cmd.Dir = repoPath
+	output, err := cmd.Output()
+	if err != nil {
+		return ""
+	}
+
+	// HEAD must not resolve to a commit, otherwise the failure was something else
+	verify := exec.CommandContext(ctx, "git", "rev-parse", "--verify", "--quiet", "HEAD")
+	verify.Dir = repoPath
+	if verify.Run() == nil {
+		return ""
+	}
+	return strings.TrimSpace(string(output))
+}
+
+// getWorkingTreeStatus counts the staged, modified, untracked and conflicted files. Without
+// untracked, git doesn't look for untracked files, which is the slow part on large trees.
+func (gs *gitService) getWorkingTreeStatus(ctx context.Context, repoPath string, untracked bool) (workingTree, error) {
 	// Use git status --porcelain for machine-readable output
-	cmd := exec.CommandContext(ctx, "git", "status", "--porcelain")
+	args := []string{"status", "--porcelain"}
+	if !untracked {
+		args = append(args, "--untracked-files=no")
+	}
+	cmd := exec.CommandContext(ctx, "git", args...)
 	cmd.Dir = repoPath
 
 	output, err := cmd.Output()
 	if err != nil {
-		return false, false, err
+		return workingTree{}, err
 	}
+	return parsePorcelain(string(output)), nil
+}
 
-	lines := strings.Split(string(output), "\n")
-	for _, line := range lines {
+// workingTree counts the entries of git status --porcelain by kind. A file both staged and
+// changed again counts as staged and as modified.
+type workingTree struct {
+	changed    int // entries with tracked changes, staged or not
+	staged     int
+	modified   int
+	untracked  int
+	conflicted int
+}
+
+func (t workingTree) isDirty() bool {
+	return t.changed > 0
+}
+
+// parsePorcelain counts the XY status codes of git status --porcelain output
+func parsePorcelain(output string) workingTree {
+	var tree workingTree
+	for _, line := range strings.Split(output, "\n") {
 		if len(line) < 2 {
 			continue
 		}
 
-		// First two characters indicate the status
-		status := line[:2]
+		// First two characters indicate the status: X the index, Y the working tree
+		x, y := line[0], line[1]
+		switch {
+		case x == '?' && y == '?':
+			tree.untracked++
+			continue
+		case x == '!':
+			continue
+		case x == 'U' || y == 'U' || (x == 'A' && y == 'A') || (x == 'D' && y == 'D'):
+			tree.conflicted++
+		default:
+			if x != ' ' {
+				tree.staged++
+			}
+			if y != ' ' {
+				tree.modified++
+			}
+		}
+		tree.changed++
+	}
+	return tree
+}
 
-		// Check for modifications
-		if status[0] != ' ' && status[0] != '?' || status[1] != ' ' && status[1] != '?' {
-			isDirty = true
+// stashCount returns how many entries the stash holds (0 without a stash)
+func (gs *gitService) stashCount(ctx context.Context, repoPath string) int {
+	cmd := exec.CommandContext(ctx, "git", "rev-list", "--walk-reflogs", "--count", "refs/stash")
+	cmd.Dir = repoPath
+	output, err := cmd.Output()
+	if err != nil {
+		return 0
+	}
+	count, _ := strconv.Atoi(strings.TrimSpace(string(output)))
+	return count
+}
+
+// getCleanupStats counts untracked files and sums the size of ignored files on disk
+func (gs *gitService) getCleanupStats(ctx context.Context, repoPath string) (untracked int, ignoredBytes int64) {
+	cmd := exec.CommandContext(ctx, "git", "ls-files", "--others", "--exclude-standard", "-z")
+	cmd.Dir = repoPath
+	if output, err := cmd.Output(); err == nil {
+		for _, entry := range strings.Split(string(output), "\x00") {
+			if entry != "" {
+				untracked++
+			}
 		}
+	}
 
-		// Check for untracked files
-		if status[0] == '?' || status[1] == '?' {
-			hasUntracked = true
+	// --directory collapses fully ignored directories (node_modules, target, ...) into one entry
+	cmd = exec.CommandContext(ctx, "git", "ls-files", "--others", "--ignored", "--exclude-standard", "--directory", "-z")
+	cmd.Dir = repoPath
+	output, err := cmd.Output()
+	if err != nil {
+		return untracked, 0
+	}
+	for _, entry := range strings.Split(string(output), "\x00") {
+		if entry == "" {
+			continue
 		}
+		_ = filepath.WalkDir(filepath.Join(repoPath, entry), func(path string, d os.DirEntry, err error) error {
+			if err != nil || ctx.Err() != nil {
+				return nil
+			}
+			if !d.IsDir() {
+				if info, err := d.Info(); err == nil {
+					ignoredBytes += info.Size()
+				}
+			}
+			return nil
+		})
+	}
+	return untracked, ignoredBytes
+}
+
+// getInProgressOperation inspects the git directory for state files left behind by
+// an unfinished merge, rebase, bisect, cherry-pick or revert
+func (gs *gitService) getInProgressOperation(ctx context.Context, repoPath string) domain.Operation {
+	cmd := exec.CommandContext(ctx, "git", "rev-parse", "--absolute-git-dir")
+	cmd.Dir = repoPath
+	output, err := cmd.Output()
+	if err != nil {
+		return domain.OperationNone
+	}
+	gitDir := strings.TrimSpace(string(output))
+
+	exists := func(name string) bool {
+		_, err := os.Stat(filepath.Join(gitDir, name))
+		return err == nil
+	}
+
+	// Rebase is checked first: an interrupted rebase can also leave MERGE_HEAD or CHERRY_PICK_HEAD
+	switch {
+	case exists("rebase-merge"), exists("rebase-apply"):
+		return domain.OperationRebase
+	case exists("MERGE_HEAD"):
+		return domain.OperationMerge
+	case exists("CHERRY_PICK_HEAD"):
+		return domain.OperationCherryPick
+	case exists("REVERT_HEAD"):
+		return domain.OperationRevert
+	case exists("BISECT_LOG"):
+		return domain.OperationBisect
+	}
+	return domain.OperationNone
+}
+
+// resolveUpstream determines the ref a branch should be compared against.
+// Order: config override, the branch's upstream, the configured preferred remote,
+// then origin.
+func (gs *gitService) resolveUpstream(ctx context.Context, repoPath string, branch string) string {
+	cfg := gs.config()
+	if cfg != nil {
+		if override, ok := cfg.Upstream.Overrides[repoPath]; ok && override != "" {
+			return override
+		}
+	}
+	return gs.remoteRefFor(ctx, repoPath, branch)
+}
+
+// remoteRefFor determines the ref tracking a branch without the config override: its
+// upstream, then a same-named branch on the preferred remote, then on origin
+func (gs *gitService) remoteRefFor(ctx context.Context, repoPath string, branch string) string {
+	cfg := gs.config()
+	if branch == "" || strings.HasPrefix(branch, "detached") {
+		return ""
+	}
+
+	// The branch's own upstream, as git resolves it: this follows fetch refspecs that
+	// rename branches, remotes with slashes in their names and local branches tracked
+	// with remote "."
+	if upstream := gs.trackedUpstream(ctx, repoPath, branch); upstream != "" {
+		return upstream
+	}
+
+	// No tracking info - fall back to a same-named branch on a known remote
+	candidates := []string{}
+	if cfg != nil && cfg.Upstream.Remote != "" {
+		candidates = append(candidates, cfg.Upstream.Remote)
+	}
+	candidates = append(candidates, "origin")
+	for _, candidate := range candidates {
+		ref := candidate + "/" + branch
+		if gs.refExists(ctx, repoPath, "refs/remotes/"+ref) {
+			return ref
+		}
+	}
+
+	return ""
+}
+
+// staleReason explains why a repository looks abandoned according to [stale] ("" if it doesn't)
+func (gs *gitService) staleReason(ctx context.Context, repoPath string, branch string, lastCommit time.Time) string {
+	cfg := gs.config()
+	if cfg == nil {
+		return ""
+	}
+	if cfg.Stale.GoneUpstream && gs.upstreamGone(ctx, repoPath, branch) {
+		return "upstream gone"
+	}
+	// An invalid stale.after is reported at startup; treat it as off here
+	maxAge, err := cfg.Stale.MaxAge()
+	if err == nil && maxAge > 0 && !lastCommit.IsZero() && time.Since(lastCommit) > maxAge {
+		return "no commits for " + cfg.Stale.After
+	}
+	return ""
+}
+
+// upstreamGone reports whether the branch tracks a remote branch that was deleted
+// (its remote-tracking ref is gone after a pruning fetch)
+func (gs *gitService) upstreamGone(ctx context.Context, repoPath string, branch string) bool {
+	if branch == "" || strings.HasPrefix(branch, "detached") {
+		return false
+	}
+	cmd := exec.CommandContext(ctx, "git", "for-each-ref", "--format=%(upstream:track)", "refs/heads/"+branch)
+	cmd.Dir = repoPath
+
+	output, err := cmd.Output()
+	if err != nil {
+		return false
 	}
+	return strings.TrimSpace(string(output)) == "[gone]"
+}
 
-	return isDirty, hasUntracked, nil
+// getLastCommit returns the committer date and author name of HEAD (zero and "" in a
+// repository without commits)
+func (gs *gitService) getLastCommit(ctx context.Context, repoPath string) (time.Time, string) {
+	cmd := exec.CommandContext(ctx, "git", "log", "-1", "--format=%ct%x00%an")
+	cmd.Dir = repoPath
+
+	output, err := cmd.Output()
+	if err != nil {
+		return time.Time{}, ""
+	}
+	timestamp, author, _ := strings.Cut(strings.TrimSpace(string(output)), "\x00")
+	seconds, err := strconv.ParseInt(timestamp, 10, 64)
+	if err != nil {
+		return time.Time{}, ""
+	}
+	return time.Unix(seconds, 0), author
 }
 
-// getAheadBehind gets the ahead/behind counts relative to the upstream branch
-func (gs *gitService) getAheadBehind(ctx context.Context, repoPath string, branch string) (ahead int, behind int, err error) {
-	// First check if there's an upstream branch
-	cmd := exec.CommandContext(ctx, "git", "rev-parse", "--abbrev-ref", branch+"@{u}")
+// gitConfigValue reads a single git config value, returning "" if unset
+func (gs *gitService) gitConfigValue(ctx context.Context, repoPath string, key string) string {
+	cmd := exec.CommandContext(ctx, "git", "config", "--get", key)
 	cmd.Dir = repoPath
 
-	var stderr bytes.Buffer
-	cmd.Stderr = &stderr
+	output, err := cmd.Output()
+	if err != nil {
+		return ""
+	}
+	return strings.TrimSpace(string(output))
+}
+
+// trackedUpstream returns the short name of the ref a branch tracks ("" when it tracks
+// none, or its upstream is gone)
+func (gs *gitService) trackedUpstream(ctx context.Context, repoPath string, branch string) string {
+	cmd := exec.CommandContext(ctx, "git", "rev-parse", "--abbrev-ref", "--symbolic-full-name", branch+"@{u}")
+	cmd.Dir = repoPath
 
-	upstream, err := cmd.Output()
+	output, err := cmd.Output()
 	if err != nil {
-		// No upstream branch
-		return 0, 0, nil
+		return ""
 	}
+	return strings.TrimSpace(string(output))
+}
 
-	upstreamBranch := strings.TrimSpace(string(upstream))
+// refExists checks whether a fully qualified ref exists in the repository
+func (gs *gitService) refExists(ctx context.Context, repoPath string, ref string) bool {
+	cmd := exec.CommandContext(ctx, "git", "rev-parse", "--verify", "--quiet", ref)
+	cmd.Dir = repoPath
+	return cmd.Run() == nil
+}
+
+// getAheadBehind gets the ahead/behind counts relative to the given upstream ref
+func (gs *gitService) getAheadBehind(ctx context.Context, repoPath string, branch string, upstreamBranch string) (ahead int, behind int, err error) {
 	if upstreamBranch == "" {
 		return 0, 0, nil
 	}
 
 	// Get ahead/behind counts
-	cmd = exec.CommandContext(ctx, "git", "rev-list", "--left-right", "--count", upstreamBranch+"..."+branch)
+	cmd := exec.CommandContext(ctx, "git", "rev-list", "--left-right", "--count", upstreamBranch+"..."+branch)
 	cmd.Dir = repoPath
 
 	output, err := cmd.Output()
@@ -410,8 +891,40 @@ func (gs *gitService) getAheadBehind(ctx context.Context, repoPath string, branc
 	return ahead, behind, nil
 }
 
-// fetchRepo performs a git fetch operation on the repository
-func (gs *gitService) fetchRepo(ctx context.Context, repoPath string) error {
+// FetchArgs returns the arguments of git fetch for the given options
+func FetchArgs(opts domain.FetchOptions) []string {
+	args := []string{"fetch", "--prune"}
+	switch opts.Tags {
+	case domain.FetchTagsAll:
+		args = append(args, "--tags")
+	case domain.FetchTagsNone:
+		args = append(args, "--no-tags")
+	}
+	if opts.Depth > 0 {
+		args = append(args, "--depth="+strconv.Itoa(opts.Depth))
+	}
+	if opts.Remote != "" {
+		return append(args, "--", opts.Remote)
+	}
+	return append(args, "--all")
+}
+
+// publishFetchSummary tells the user how a fetch of several repositories went, or that a
+// single one failed. A cancelled fetch is reported by its progress event instead.
+func (gs *gitService) publishFetchSummary(fetched, failed, total int) {
+	switch {
+	case fetched+failed < total, total == 1 && failed == 0:
+		return
+	case failed == 0:
+		gs.bus.Publish(eventbus.AppMessageEvent{Level: domain.MessageSuccess, Text: fmt.Sprintf("Fetched %d repos", total)})
+	default:
+		gs.bus.Publish(eventbus.AppMessageEvent{Level: domain.MessageWarning, Text: fmt.Sprintf("Fetched %d of %d repos, %d failed", fetched, total, failed)})
+	}
+}
+
+// fetchRepo performs a git fetch operation on the repository. Transient network failures
+// are tried again after a growing pause, up to [fetch] attempts.
+func (gs *gitService) fetchRepo(ctx context.Context, repoPath string, opts domain.FetchOptions) error {
 	startTime := time.Now()
 
 	// Acquire worker slot
@@ -422,11 +935,37 @@ func (gs *gitService) fetchRepo(ctx context.Context, repoPath string) error {
 		return ctx.Err()
 	}
 
+	// Don't run more fetches against one host than it tolerates
+	settings := gs.fetchSettings()
+	release, err := gs.hosts.acquire(ctx, gs.remoteHost(ctx, repoPath), settings.MaxPerHost())
+	if err != nil {
+		return err
+	}
+	defer release()
+
 	// Run git fetch
-	cmd := exec.CommandContext(ctx, "git", "fetch", "--all", "--prune")
-	cmd.Dir = repoPath
+	var output []byte
+	for attempt := 1; ; attempt++ {
+		cmd := exec.CommandContext(ctx, "git", FetchArgs(opts)...)
+		cmd.Dir = repoPath
+		env, done := gs.askpass.env(repoPath)
+		cmd.Env = env
 
-	output, err := cmd.CombinedOutput()
+		output, err = cmd.CombinedOutput()
+		done()
+		if err == nil || attempt >= settings.MaxAttempts() || !isTransientFailure(string(output)) {
+			break
+		}
+		backoff := fetchBackoff << (attempt - 1)
+		log.Printf("Fetching %s failed (attempt %d), retrying in %s", repoPath, attempt, backoff)
+		select {
+		case <-time.After(backoff):
+		case <-ctx.Done():
+		}
+		if ctx.Err() != nil {
+			break
+		}
+	}
 	duration := time.Since(startTime).Milliseconds()
 
 	// Emit command log event
@@ -437,6 +976,7 @@ func (gs *gitService) fetchRepo(ctx context.Context, repoPath string) error {
 			Success:  false,
 			Output:   string(output),
 			Error:    err.Error(),
+			Reason:   authFailureReason(string(output)),
 			Duration: duration,
 		})
 		return fmt.Errorf("git fetch failed: %v\nOutput: %s", err, output)
@@ -457,8 +997,6 @@ func (gs *gitService) fetchRepo(ctx context.Context, repoPath string) error {
 
 // pullRepo performs a git pull operation on the repository
 func (gs *gitService) pullRepo(ctx context.Context, repoPath string) error {
-	startTime := time.Now()
-
 	// Acquire worker slot
 	select {
 	case gs.workerPool <- struct{}{}:
@@ -467,11 +1005,23 @@ func (gs *gitService) pullRepo(ctx context.Context, repoPath string) error {
 		return ctx.Err()
 	}
 
+	return gs.runGuarded(ctx, repoPath, GuardPull, func() error {
+		return gs.runPull(ctx, repoPath)
+	})
+}
+
+// runPull runs git pull --rebase and records it in the command log
+func (gs *gitService) runPull(ctx context.Context, repoPath string) error {
+	startTime := time.Now()
+
 	// Run git pull
 	cmd := exec.CommandContext(ctx, "git", "pull", "--rebase")
 	cmd.Dir = repoPath
+	env, done := gs.askpass.env(repoPath)
+	cmd.Env = env
 
 	output, err := cmd.CombinedOutput()
+	done()
 	duration := time.Since(startTime).Milliseconds()
 
 	// Emit command log event
@@ -482,6 +1032,7 @@ func (gs *gitService) pullRepo(ctx context.Context, repoPath string) error {
 			Success:  false,
 			Output:   string(output),
 			Error:    err.Error(),
+			Reason:   authFailureReason(string(output)),
 			Duration: duration,
 		})
 		return fmt.Errorf("git pull failed: %v\nOutput: %s", err, output)
@@ -514,12 +1065,30 @@ func (gs *gitService) createBranch(ctx context.Context, repoPath, name string) e
 
 // switchBranch checks out an existing branch
 func (gs *gitService) switchBranch(ctx context.Context, repoPath, name string) error {
+	return gs.runGuarded(ctx, repoPath, GuardCheckout, func() error {
+		return gs.runLogged(ctx, repoPath, "checkout", "checkout", name)
+	})
+}
+
+// setRemoteURL points an existing remote at a new URL
+func (gs *gitService) setRemoteURL(ctx context.Context, repoPath, remote, url string) error {
 	start := time.Now()
-	cmd := exec.CommandContext(ctx, "git", "checkout", name)
+	cmd := exec.CommandContext(ctx, "git", "remote", "set-url", remote, url)
 	cmd.Dir = repoPath
 	out, err := cmd.CombinedOutput()
 	dur := time.Since(start).Milliseconds()
-	gs.bus.Publish(eventbus.CommandExecutedEvent{RepoPath: repoPath, Command: "checkout", Success: err == nil, Output: string(out), Error: errString(err), Duration: dur})
+	gs.bus.Publish(eventbus.CommandExecutedEvent{RepoPath: repoPath, Command: "remote set-url " + remote, Success: err == nil, Output: string(out), Error: errString(err), Duration: dur})
+	return err
+}
+
+// cleanRepo removes untracked and ignored files and directories
+func (gs *gitService) cleanRepo(ctx context.Context, repoPath string) error {
+	start := time.Now()
+	cmd := exec.CommandContext(ctx, "git", "clean", "-fdx")
+	cmd.Dir = repoPath
+	out, err := cmd.CombinedOutput()
+	dur := time.Since(start).Milliseconds()
+	gs.bus.Publish(eventbus.CommandExecutedEvent{RepoPath: repoPath, Command: "clean -fdx", Success: err == nil, Output: string(out), Error: errString(err), Duration: dur})
 	return err
 }
 
@@ -530,6 +1099,26 @@ func errString(err error) string {
 	return err.Error()
 }
 
+// gitErrorReason returns what git printed when it failed (e.g. "not a git repository"),
+// falling back to the error itself when git wrote nothing to stderr
+func gitErrorReason(err error) string {
+	var exitErr *exec.ExitError
+	if errors.As(err, &exitErr) {
+		lines := strings.Split(strings.TrimSpace(string(exitErr.Stderr)), "\n")
+		for i := len(lines) - 1; i >= 0; i-- {
+			line := strings.TrimSpace(lines[i])
+			if line == "" {
+				continue
+			}
+			for _, prefix := range []string{"fatal: ", "error: "} {
+				line = strings.TrimPrefix(line, prefix)
+			}
+			return line
+		}
+	}
+	return err.Error()
+}
+
 // publishStatus publishes a status update event
 func (gs *gitService) publishStatus(repoPath string, status domain.RepoStatus) {
 	gs.bus.Publish(eventbus.StatusUpdatedEvent{
@@ -0,0 +1,139 @@
+package git
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"os/exec"
+	"strings"
+	"time"
+
+	"github.com/darksworm/gitagrip/internal/config"
+	"github.com/darksworm/gitagrip/internal/eventbus"
+)
+
+// Operations that are checked against the configured guards before they run
+const (
+	GuardCheckout = "checkout"
+	GuardPull     = "pull"
+	GuardPush     = "push"
+)
+
+// SkippedError reports that a guard refused to run an operation on a repository
+type SkippedError struct {
+	Operation string
+	Reason    string
+}
+
+func (e *SkippedError) Error() string {
+	return fmt.Sprintf("%s skipped: %s", e.Operation, e.Reason)
+}
+
+// checkGuards decides whether op may run on a repository. It returns a *SkippedError
+// when the operation must be skipped, and stash=true when local changes should be
+// stashed around the operation instead.
+func (gs *gitService) checkGuards(ctx context.Context, repoPath, op string) (stash bool, err error) {
+	cfg := gs.config()
+	guards := gs.guardSettings()
+
+	if op == GuardPush {
+		label := guards.ProtectedLabelName()
+		if cfg != nil && cfg.HasLabel(repoPath, label) {
+			return false, &SkippedError{Operation: op, Reason: fmt.Sprintf("repository is labeled %q", label)}
+		}
+		return false, nil
+	}
+
+	if !guards.AllowDirty {
+		if tree, err := gs.getWorkingTreeStatus(ctx, repoPath, false); err == nil && tree.isDirty() {
+			if !guards.StashFirst {
+				return false, &SkippedError{Operation: op, Reason: "working tree has uncommitted changes (set guards.stash_first to stash them automatically)"}
+			}
+			stash = true
+		}
+	}
+
+	if op == GuardPull && guards.PredictConflicts {
+		if files := predictConflicts(ctx, repoPath); len(files) > 0 {
+			return false, &SkippedError{Operation: op, Reason: fmt.Sprintf("would conflict in %s (pull it by hand)", strings.Join(files, ", "))}
+		}
+	}
+	return stash, nil
+}
+
+// predictConflicts trial-merges the upstream into HEAD and returns the files that would
+// conflict. Only diverged branches can conflict.
+func predictConflicts(ctx context.Context, repoPath string) []string {
+	cmd := exec.CommandContext(ctx, "git", "rev-list", "--left-right", "--count", "HEAD...@{upstream}")
+	cmd.Dir = repoPath
+	out, err := cmd.Output()
+	if err != nil {
+		return nil
+	}
+	counts := strings.Fields(string(out))
+	if len(counts) != 2 || counts[0] == "0" || counts[1] == "0" {
+		return nil
+	}
+
+	return TrialMerge(ctx, repoPath, "@{upstream}")
+}
+
+// TrialMerge merges theirs into HEAD with git merge-tree, which touches neither the
+// working tree nor the index, and returns the files that would conflict. Errors (e.g. git
+// older than 2.38) predict none. Pulls rebase, so this is a merge's estimate: replaying
+// the local commits one by one can conflict where the merge doesn't, or the other way.
+func TrialMerge(ctx context.Context, repoPath, theirs string) []string {
+	// Exit status 1 means conflicts: the tree id is followed by the conflicted files
+	cmd := exec.CommandContext(ctx, "git", "merge-tree", "--write-tree", "--name-only", "--no-messages", "HEAD", theirs)
+	cmd.Dir = repoPath
+	out, err := cmd.Output()
+	var exitErr *exec.ExitError
+	if err == nil || !errors.As(err, &exitErr) || exitErr.ExitCode() != 1 {
+		return nil
+	}
+	lines := strings.Split(strings.TrimSpace(string(out)), "\n")
+	return lines[1:]
+}
+
+// guardSettings returns the configured guards (defaults when running without config)
+func (gs *gitService) guardSettings() config.GuardSettings {
+	cfg := gs.config()
+	if cfg == nil {
+		return config.GuardSettings{}
+	}
+	return cfg.Guards
+}
+
+// runGuarded checks the guards for op and runs it, stashing local changes around it when
+// configured. Skipped operations are recorded in the repository's command log.
+func (gs *gitService) runGuarded(ctx context.Context, repoPath, op string, run func() error) error {
+	stash, err := gs.checkGuards(ctx, repoPath, op)
+	if err != nil {
+		gs.bus.Publish(eventbus.CommandExecutedEvent{RepoPath: repoPath, Command: op, Success: false, Error: err.Error()})
+		return err
+	}
+	if !stash {
+		return run()
+	}
+
+	if err := gs.runLogged(ctx, repoPath, "stash push", "stash", "push", "--include-untracked", "-m", "gitagrip: auto-stash before "+op); err != nil {
+		return err
+	}
+	runErr := run()
+	// Restore local changes even if the operation failed
+	if err := gs.runLogged(ctx, repoPath, "stash pop", "stash", "pop"); err != nil && runErr == nil {
+		return fmt.Errorf("%s succeeded but restoring stashed changes failed: %w", op, err)
+	}
+	return runErr
+}
+
+// runLogged runs a git command and records it in the repository's command log
+func (gs *gitService) runLogged(ctx context.Context, repoPath, label string, args ...string) error {
+	start := time.Now()
+	cmd := exec.CommandContext(ctx, "git", args...)
+	cmd.Dir = repoPath
+	out, err := cmd.CombinedOutput()
+	dur := time.Since(start).Milliseconds()
+	gs.bus.Publish(eventbus.CommandExecutedEvent{RepoPath: repoPath, Command: label, Success: err == nil, Output: string(out), Error: errString(err), Duration: dur})
+	return err
+}
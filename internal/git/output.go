@@ -0,0 +1,18 @@
+package git
+
+import (
+	"context"
+	"os/exec"
+	"strings"
+)
+
+// Output runs a git command in repoPath and returns its trimmed output ("" on failure)
+func Output(ctx context.Context, repoPath string, args ...string) string {
+	cmd := exec.CommandContext(ctx, "git", args...)
+	cmd.Dir = repoPath
+	output, err := cmd.Output()
+	if err != nil {
+		return ""
+	}
+	return strings.TrimSpace(string(output))
+}
@@ -0,0 +1,152 @@
+package git
+
+import (
+	"context"
+	"fmt"
+	"log"
+	"os/exec"
+	"regexp"
+	"strings"
+	"time"
+
+	"github.com/darksworm/gitagrip/internal/eventbus"
+)
+
+// pushTarget returns the remote and branch the current branch of a repository is pushed
+// to: its tracking branch, or a same-named branch on the preferred remote or origin
+func (gs *gitService) pushTarget(ctx context.Context, repoPath string) (remote, branch string, err error) {
+	cfg := gs.config()
+	local, err := gs.getCurrentBranch(ctx, repoPath)
+	if err != nil {
+		return "", "", err
+	}
+	if strings.HasPrefix(local, "detached") {
+		return "", "", fmt.Errorf("HEAD is detached")
+	}
+
+	remote = gs.gitConfigValue(ctx, repoPath, "branch."+local+".remote")
+	merge := gs.gitConfigValue(ctx, repoPath, "branch."+local+".merge")
+	if remote != "" && remote != "." && merge != "" {
+		return remote, strings.TrimPrefix(merge, "refs/heads/"), nil
+	}
+
+	candidates := []string{}
+	if cfg != nil && cfg.Upstream.Remote != "" {
+		candidates = append(candidates, cfg.Upstream.Remote)
+	}
+	candidates = append(candidates, "origin")
+	for _, candidate := range candidates {
+		if gs.gitConfigValue(ctx, repoPath, "remote."+candidate+".url") != "" {
+			return candidate, local, nil
+		}
+	}
+	return "", "", fmt.Errorf("%s has no remote to push to", local)
+}
+
+// Push pushes the current branch of a repository unless a guard refuses it, and returns
+// the branch it pushed to
+func (gs *gitService) Push(ctx context.Context, repoPath string) (string, error) {
+	// Acquire worker slot
+	select {
+	case gs.workerPool <- struct{}{}:
+		defer func() { <-gs.workerPool }()
+	case <-ctx.Done():
+		return "", ctx.Err()
+	}
+
+	// Don't run more pushes against one host than it tolerates, like fetches
+	release, err := gs.hosts.acquire(ctx, gs.remoteHost(ctx, repoPath), gs.fetchSettings().MaxPerHost())
+	if err != nil {
+		return "", err
+	}
+	defer release()
+
+	var branch string
+	err = gs.runGuarded(ctx, repoPath, GuardPush, func() error {
+		remote, target, err := gs.pushTarget(ctx, repoPath)
+		if err != nil {
+			gs.bus.Publish(eventbus.CommandExecutedEvent{RepoPath: repoPath, Command: "push", Success: false, Error: err.Error()})
+			return err
+		}
+		branch = target
+		return gs.runPush(ctx, repoPath, remote, branch)
+	})
+	return branch, err
+}
+
+// runPush runs git push of HEAD to a remote branch and records it in the command log
+func (gs *gitService) runPush(ctx context.Context, repoPath, remote, branch string) error {
+	startTime := time.Now()
+
+	cmd := exec.CommandContext(ctx, "git", "push", remote, "HEAD:refs/heads/"+branch)
+	cmd.Dir = repoPath
+	env, done := gs.askpass.env(repoPath)
+	cmd.Env = env
+
+	output, err := cmd.CombinedOutput()
+	done()
+	duration := time.Since(startTime).Milliseconds()
+
+	if err != nil {
+		reason := pushRejectionReason(string(output))
+		if reason == "" {
+			reason = authFailureReason(string(output))
+		}
+		gs.bus.Publish(eventbus.CommandExecutedEvent{
+			RepoPath: repoPath,
+			Command:  "push",
+			Success:  false,
+			Output:   string(output),
+			Error:    err.Error(),
+			Reason:   reason,
+			Duration: duration,
+		})
+		return &PushError{Reason: reason, Output: string(output), Err: err}
+	}
+
+	gs.bus.Publish(eventbus.CommandExecutedEvent{
+		RepoPath: repoPath,
+		Command:  "push",
+		Success:  true,
+		Output:   string(output),
+		Duration: duration,
+	})
+
+	log.Printf("Pushed %s to %s/%s", repoPath, remote, branch)
+	return nil
+}
+
+// PushError reports a failed git push, with why the remote rejected it when known
+type PushError struct {
+	Reason string // e.g. "rejected: the branch is protected on the remote" ("" when unknown)
+	Output string
+	Err    error
+}
+
+func (e *PushError) Error() string {
+	if e.Reason != "" {
+		return "git push " + e.Reason
+	}
+	return fmt.Sprintf("git push failed: %v\nOutput: %s", e.Err, e.Output)
+}
+
+func (e *PushError) Unwrap() error { return e.Err }
+
+// rejectedRef matches git's summary line of a refused ref, e.g.
+// " ! [remote rejected] main -> main (protected branch hook declined)"
+var rejectedRef = regexp.MustCompile(`! \[(remote )?rejected\]\s+\S+ -> \S+ \((.*)\)`)
+
+// pushRejectionReason explains why the remote refused a push ("" when it wasn't refused)
+func pushRejectionReason(output string) string {
+	lower := strings.ToLower(output)
+	switch {
+	case strings.Contains(lower, "protected branch"):
+		return "rejected: the branch is protected on the remote"
+	case strings.Contains(output, "(non-fast-forward)"), strings.Contains(output, "(fetch first)"):
+		return "rejected: non-fast-forward, the remote has commits this branch lacks (pull first)"
+	}
+	if m := rejectedRef.FindStringSubmatch(output); m != nil {
+		return "rejected: " + m[2]
+	}
+	return ""
+}
@@ -0,0 +1,109 @@
+package git
+
+import (
+	"context"
+	"errors"
+	"os/exec"
+	"sort"
+	"strings"
+
+	"github.com/darksworm/gitagrip/internal/domain"
+)
+
+// Branches lists the local branches, then the remote-tracking ones, by name. The
+// symbolic <remote>/HEAD refs are left out.
+func (gs *gitService) Branches(ctx context.Context, repoPath string) ([]domain.Branch, error) {
+	cmd := exec.CommandContext(ctx, "git", "for-each-ref",
+		"--format=%(refname)%00%(HEAD)%00%(upstream:short)%00%(objectname:short)",
+		"refs/heads", "refs/remotes")
+	cmd.Dir = repoPath
+
+	output, err := cmd.Output()
+	if err != nil {
+		return nil, err
+	}
+
+	var branches []domain.Branch
+	for _, line := range strings.Split(strings.TrimSpace(string(output)), "\n") {
+		fields := strings.Split(line, "\x00")
+		if len(fields) != 4 {
+			continue
+		}
+		branch := domain.Branch{Current: fields[1] == "*", Upstream: fields[2], Commit: fields[3]}
+		if name, ok := strings.CutPrefix(fields[0], "refs/heads/"); ok {
+			branch.Name = name
+		} else if name, ok := strings.CutPrefix(fields[0], "refs/remotes/"); ok && !strings.HasSuffix(name, "/HEAD") {
+			branch.Name = name
+			branch.Remote = true
+		} else {
+			continue
+		}
+		branches = append(branches, branch)
+	}
+	sort.SliceStable(branches, func(i, j int) bool {
+		if branches[i].Remote != branches[j].Remote {
+			return !branches[i].Remote
+		}
+		return branches[i].Name < branches[j].Name
+	})
+	return branches, nil
+}
+
+// Remotes lists the configured remotes by name, with their fetch and push URLs
+func (gs *gitService) Remotes(ctx context.Context, repoPath string) ([]domain.Remote, error) {
+	cmd := exec.CommandContext(ctx, "git", "config", "--get-regexp", `^remote\..*\.(url|pushurl)$`)
+	cmd.Dir = repoPath
+
+	// git config exits 1 when nothing matches, i.e. there are no remotes
+	output, err := cmd.Output()
+	if err != nil {
+		var exitErr *exec.ExitError
+		if errors.As(err, &exitErr) && exitErr.ExitCode() == 1 {
+			return nil, nil
+		}
+		return nil, err
+	}
+
+	remotes := make(map[string]*domain.Remote)
+	for _, line := range strings.Split(strings.TrimSpace(string(output)), "\n") {
+		key, value, ok := strings.Cut(line, " ")
+		if !ok {
+			continue
+		}
+		key = strings.TrimPrefix(key, "remote.")
+		name, isPush := strings.CutSuffix(key, ".pushurl")
+		if !isPush {
+			name = strings.TrimSuffix(key, ".url")
+		}
+		remote, ok := remotes[name]
+		if !ok {
+			remote = &domain.Remote{Name: name}
+			remotes[name] = remote
+		}
+		if isPush {
+			remote.PushURL = value
+		} else {
+			remote.FetchURL = value
+		}
+	}
+
+	list := make([]domain.Remote, 0, len(remotes))
+	for _, remote := range remotes {
+		if remote.PushURL == "" {
+			remote.PushURL = remote.FetchURL
+		}
+		list = append(list, *remote)
+	}
+	sort.Slice(list, func(i, j int) bool { return list[i].Name < list[j].Name })
+	return list, nil
+}
+
+// UpstreamOf returns the ref a branch is compared against ("" if none)
+func (gs *gitService) UpstreamOf(ctx context.Context, repoPath, branch string) string {
+	return gs.resolveUpstream(ctx, repoPath, branch)
+}
+
+// AheadBehind counts the commits on a that b lacks, and those on b that a lacks
+func (gs *gitService) AheadBehind(ctx context.Context, repoPath, a, b string) (ahead, behind int, err error) {
+	return gs.getAheadBehind(ctx, repoPath, a, b)
+}
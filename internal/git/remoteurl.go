@@ -0,0 +1,105 @@
+package git
+
+import (
+	"net/url"
+	"strings"
+
+	"github.com/darksworm/gitagrip/internal/config"
+)
+
+// Remote transports that URLs can be converted between
+const (
+	TransportSSH   = "ssh"
+	TransportHTTPS = "https"
+)
+
+// Default templates used for hosts without a configured template
+const (
+	defaultSSHTemplate   = "git@{host}:{path}.git"
+	defaultHTTPSTemplate = "https://{host}/{path}.git"
+)
+
+// RemoteURL is a parsed git remote URL
+type RemoteURL struct {
+	Transport string // TransportSSH or TransportHTTPS
+	Host      string
+	Path      string // repository path without leading slash or ".git" suffix
+}
+
+// ParseRemoteURL parses scp-like SSH (git@host:owner/repo), ssh:// and http(s):// remote URLs.
+// Local paths and other transports are not recognized.
+func ParseRemoteURL(raw string) (RemoteURL, bool) {
+	raw = strings.TrimSpace(raw)
+
+	if strings.Contains(raw, "://") {
+		u, err := url.Parse(raw)
+		if err != nil || u.Hostname() == "" {
+			return RemoteURL{}, false
+		}
+
+		var transport string
+		switch u.Scheme {
+		case "http", "https":
+			transport = TransportHTTPS
+		case "ssh", "git+ssh":
+			transport = TransportSSH
+		default:
+			return RemoteURL{}, false
+		}
+
+		path := cleanRepoPath(u.Path)
+		if path == "" {
+			return RemoteURL{}, false
+		}
+		return RemoteURL{Transport: transport, Host: u.Hostname(), Path: path}, true
+	}
+
+	// scp-like syntax: [user@]host:path (a slash before the colon means a local path)
+	colon := strings.Index(raw, ":")
+	if colon <= 0 || strings.Contains(raw[:colon], "/") {
+		return RemoteURL{}, false
+	}
+	host := raw[:colon]
+	if at := strings.LastIndex(host, "@"); at >= 0 {
+		host = host[at+1:]
+	}
+	path := cleanRepoPath(raw[colon+1:])
+	if host == "" || path == "" {
+		return RemoteURL{}, false
+	}
+	return RemoteURL{Transport: TransportSSH, Host: host, Path: path}, true
+}
+
+// ConvertRemoteURL rewrites raw to the given transport using the template for its host.
+// It returns false if raw can't be parsed or already uses that transport.
+func ConvertRemoteURL(raw, transport string, templates map[string]config.RemoteTemplate) (string, bool) {
+	remote, ok := ParseRemoteURL(raw)
+	if !ok || remote.Transport == transport {
+		return "", false
+	}
+
+	template := ""
+	if t, ok := templates[remote.Host]; ok {
+		if transport == TransportSSH {
+			template = t.SSH
+		} else {
+			template = t.HTTPS
+		}
+	}
+	if template == "" {
+		if transport == TransportSSH {
+			template = defaultSSHTemplate
+		} else {
+			template = defaultHTTPSTemplate
+		}
+	}
+
+	converted := strings.NewReplacer("{host}", remote.Host, "{path}", remote.Path).Replace(template)
+	return converted, true
+}
+
+// cleanRepoPath strips slashes and the ".git" suffix from a repository path
+func cleanRepoPath(path string) string {
+	path = strings.Trim(path, "/")
+	return strings.TrimSuffix(path, ".git")
+}
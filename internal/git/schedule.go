@@ -0,0 +1,258 @@
+package git
+
+import (
+	"context"
+	"slices"
+	"sync"
+	"time"
+
+	"github.com/darksworm/gitagrip/internal/domain"
+	"github.com/darksworm/gitagrip/internal/eventbus"
+)
+
+// statusRanks orders the group priorities of queued status reads, most urgent first. Lazy
+// repositories are never queued.
+var statusRanks = []string{domain.StatusPriorityHigh, domain.StatusPriorityNormal, domain.StatusPriorityLow}
+
+// refreshEntry is a queued status read; done is closed once it ran or left the queue
+type refreshEntry struct {
+	repoPath string
+	rank     int
+	done     chan struct{}
+}
+
+// refreshQueue holds the status reads waiting for a worker. The repository under the
+// cursor goes first, then the others on screen top to bottom, then the rest by their
+// groups' status_priority ([group_startup]) in the order they were queued. Where a
+// repository stands is decided when a worker frees up, so scrolling requeues at once:
+// repositories coming into view move to the front and those scrolled away fall back.
+type refreshQueue struct {
+	mu      sync.Mutex
+	ready   *sync.Cond
+	pending [][]*refreshEntry        // entries by rank, possibly with ones that left
+	entries map[string]*refreshEntry // repo path -> its queued read
+	visible []string                 // repositories on screen, top to bottom
+	cursor  string                   // repository under the cursor
+	closed  bool                     // no more reads are handed out
+}
+
+func newRefreshQueue() *refreshQueue {
+	q := &refreshQueue{pending: make([][]*refreshEntry, len(statusRanks)), entries: make(map[string]*refreshEntry)}
+	q.ready = sync.NewCond(&q.mu)
+	return q
+}
+
+// push queues a status read of a repository at a group priority and returns a channel
+// closed once it ran. A repository already waiting keeps its place, or moves up to the
+// more urgent priority.
+func (q *refreshQueue) push(repoPath, priority string) <-chan struct{} {
+	rank := slices.Index(statusRanks, priority)
+	if rank < 0 {
+		rank = slices.Index(statusRanks, domain.StatusPriorityNormal)
+	}
+	q.mu.Lock()
+	defer q.mu.Unlock()
+	if q.closed {
+		done := make(chan struct{})
+		close(done)
+		return done
+	}
+	entry, ok := q.entries[repoPath]
+	if !ok {
+		entry = &refreshEntry{repoPath: repoPath, rank: rank, done: make(chan struct{})}
+		q.entries[repoPath] = entry
+	} else if rank >= entry.rank {
+		return entry.done
+	}
+	entry.rank = rank
+	q.pending[rank] = append(q.pending[rank], entry)
+	q.ready.Signal()
+	return entry.done
+}
+
+// remove takes a repository out of the queue, e.g. once it is gone
+func (q *refreshQueue) remove(repoPath string) {
+	q.mu.Lock()
+	defer q.mu.Unlock()
+	if entry, ok := q.entries[repoPath]; ok {
+		delete(q.entries, repoPath)
+		close(entry.done)
+	}
+}
+
+// show records the repositories on screen and the one under the cursor
+func (q *refreshQueue) show(repoPaths []string, cursor string) {
+	q.mu.Lock()
+	defer q.mu.Unlock()
+	q.visible, q.cursor = repoPaths, cursor
+}
+
+// close drops the queued reads and makes next return nil
+func (q *refreshQueue) close() {
+	q.mu.Lock()
+	defer q.mu.Unlock()
+	if q.closed {
+		return
+	}
+	q.closed = true
+	for repoPath, entry := range q.entries {
+		delete(q.entries, repoPath)
+		close(entry.done)
+	}
+	q.ready.Broadcast()
+}
+
+// next waits for a queued read and returns the most urgent one (nil once the queue is closed)
+func (q *refreshQueue) next() *refreshEntry {
+	q.mu.Lock()
+	defer q.mu.Unlock()
+	for {
+		if q.closed {
+			return nil
+		}
+		if entry := q.take(); entry != nil {
+			delete(q.entries, entry.repoPath)
+			return entry
+		}
+		q.ready.Wait()
+	}
+}
+
+// take finds the most urgent queued read, dropping the stale entries it passes
+func (q *refreshQueue) take() *refreshEntry {
+	if entry, ok := q.entries[q.cursor]; ok {
+		return entry
+	}
+	for _, repoPath := range q.visible {
+		if entry, ok := q.entries[repoPath]; ok {
+			return entry
+		}
+	}
+	for rank := range q.pending {
+		for len(q.pending[rank]) > 0 {
+			entry := q.pending[rank][0]
+			q.pending[rank] = q.pending[rank][1:]
+			if q.entries[entry.repoPath] == entry && entry.rank == rank {
+				return entry
+			}
+		}
+	}
+	return nil
+}
+
+// runRefreshQueue reads the queued statuses, as many at once as there are workers, always
+// taking the most urgent one next, until the queue is closed
+func (gs *gitService) runRefreshQueue() {
+	slots := make(chan struct{}, cap(gs.workerPool))
+	for {
+		slots <- struct{}{}
+		entry := gs.refreshes.next()
+		if entry == nil {
+			return
+		}
+		go func() {
+			defer func() { <-slots }()
+			defer close(entry.done)
+			ctx, cancel := context.WithTimeout(context.Background(), 30*time.Second)
+			defer cancel()
+			_, _ = gs.RefreshRepo(ctx, entry.repoPath)
+		}()
+	}
+}
+
+// Close stops reading queued statuses; reads already running finish
+func (gs *gitService) Close() {
+	gs.refreshes.close()
+}
+
+// statusPriority returns the group priority a repository's status reads are queued at
+func (gs *gitService) statusPriority(repoPath string) string {
+	cfg := gs.config()
+	if cfg == nil {
+		return domain.StatusPriorityNormal
+	}
+	return cfg.StatusPriority(repoPath)
+}
+
+// scheduleStatus queues the first status read of a discovered repository by its priority.
+// Repositories of lazy groups, and with [status] lazy those a frontend doesn't show, are
+// left unread until they come into view or a refresh names them.
+func (gs *gitService) scheduleStatus(repoPath string) {
+	cfg := gs.config()
+	priority := gs.statusPriority(repoPath)
+	lazy := cfg != nil && cfg.Status.Lazy
+	gs.mu.Lock()
+	deferred := (priority == domain.StatusPriorityLazy || (lazy && gs.visible != nil)) && !gs.visible[repoPath]
+	if deferred {
+		gs.lazy[repoPath] = true
+	}
+	gs.mu.Unlock()
+	if !deferred {
+		gs.refreshes.push(repoPath, priority)
+	}
+}
+
+// showRepos records the repositories a frontend shows, which the queue reads first, and
+// queues the deferred ones among them
+func (gs *gitService) showRepos(repoPaths []string, cursor string) {
+	gs.refreshes.show(repoPaths, cursor)
+	gs.mu.Lock()
+	gs.visible = make(map[string]bool, len(repoPaths))
+	var deferred []string
+	for _, repoPath := range repoPaths {
+		gs.visible[repoPath] = true
+		if gs.lazy[repoPath] {
+			delete(gs.lazy, repoPath)
+			deferred = append(deferred, repoPath)
+		}
+	}
+	gs.mu.Unlock()
+	for _, repoPath := range deferred {
+		gs.refreshes.push(repoPath, gs.statusPriority(repoPath))
+	}
+}
+
+// wantStatus marks repositories as asked for: deferred ones are refreshed along with the
+// rest from now on
+func (gs *gitService) wantStatus(repoPaths []string) {
+	gs.mu.Lock()
+	defer gs.mu.Unlock()
+	for _, repoPath := range repoPaths {
+		delete(gs.lazy, repoPath)
+	}
+}
+
+// refreshableRepos returns the known repositories a refresh of all of them reads: every
+// one but the deferred repositories nothing showed or asked for yet
+func (gs *gitService) refreshableRepos() []domain.Repository {
+	gs.mu.Lock()
+	defer gs.mu.Unlock()
+	repos := make([]domain.Repository, 0, len(gs.knownRepos))
+	for path := range gs.knownRepos {
+		if !gs.lazy[path] {
+			repos = append(repos, domain.Repository{Path: path})
+		}
+	}
+	return repos
+}
+
+// fetchOnStart fetches the repositories of the groups with fetch_on_start ([group_startup])
+// once the first scan is done
+func (gs *gitService) fetchOnStart() {
+	cfg := gs.config()
+	if cfg == nil {
+		return
+	}
+	gs.mu.Lock()
+	var repoPaths []string
+	for path := range gs.knownRepos {
+		if cfg.FetchOnStart(path) {
+			repoPaths = append(repoPaths, path)
+		}
+	}
+	gs.mu.Unlock()
+	if len(repoPaths) > 0 {
+		slices.Sort(repoPaths)
+		gs.bus.Publish(eventbus.FetchRequestedEvent{RepoPaths: repoPaths})
+	}
+}
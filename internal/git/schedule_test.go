@@ -0,0 +1,90 @@
+package git
+
+import (
+	"slices"
+	"testing"
+
+	"github.com/darksworm/gitagrip/internal/domain"
+)
+
+func TestRefreshQueueReadsVisibleReposFirst(t *testing.T) {
+	q := newRefreshQueue()
+	drain := func(n int) []string {
+		var order []string
+		for i := 0; i < n; i++ {
+			order = append(order, q.next().repoPath)
+		}
+		return order
+	}
+
+	q.push("low", domain.StatusPriorityLow)
+	q.push("normal-1", domain.StatusPriorityNormal)
+	q.push("high", domain.StatusPriorityHigh)
+	q.push("normal-2", domain.StatusPriorityNormal)
+	q.push("shown-2", domain.StatusPriorityLow)
+	q.push("shown-1", domain.StatusPriorityNormal)
+	q.push("cursor", domain.StatusPriorityLow)
+	q.show([]string{"shown-1", "cursor", "shown-2"}, "cursor")
+	q.push("normal-1", domain.StatusPriorityLow) // already waiting at normal
+	q.push("low", domain.StatusPriorityHigh)     // moves up
+
+	want := []string{"cursor", "shown-1", "shown-2", "high", "low", "normal-1", "normal-2"}
+	if got := drain(len(want)); !slices.Equal(got, want) {
+		t.Fatalf("read %v, want %v", got, want)
+	}
+}
+
+func TestRefreshQueueRequeuesOnScroll(t *testing.T) {
+	q := newRefreshQueue()
+	for _, repoPath := range []string{"a", "b", "c", "d"} {
+		q.push(repoPath, domain.StatusPriorityNormal)
+	}
+	q.show([]string{"a", "b"}, "a")
+	if got := q.next().repoPath; got != "a" {
+		t.Fatalf("read %q first, want the cursor's a", got)
+	}
+
+	// Scrolled down: d comes into view, b falls back to its place
+	q.show([]string{"c", "d"}, "d")
+	var order []string
+	for i := 0; i < 3; i++ {
+		order = append(order, q.next().repoPath)
+	}
+	if want := []string{"d", "c", "b"}; !slices.Equal(order, want) {
+		t.Fatalf("read %v after scrolling, want %v", order, want)
+	}
+}
+
+func TestRefreshQueueRemove(t *testing.T) {
+	q := newRefreshQueue()
+	done := q.push("gone", domain.StatusPriorityNormal)
+	q.push("kept", domain.StatusPriorityNormal)
+	q.remove("gone")
+	select {
+	case <-done:
+	default:
+		t.Fatal("removing a repository should release its waiters")
+	}
+	if got := q.next().repoPath; got != "kept" {
+		t.Fatalf("read %q, want kept", got)
+	}
+}
+
+func TestRefreshQueueClose(t *testing.T) {
+	q := newRefreshQueue()
+	done := q.push("queued", domain.StatusPriorityNormal)
+	q.close()
+	select {
+	case <-done:
+	default:
+		t.Fatal("closing should release the waiters of queued reads")
+	}
+	if entry := q.next(); entry != nil {
+		t.Fatalf("read %q after closing, want nothing", entry.repoPath)
+	}
+	select {
+	case <-q.push("late", domain.StatusPriorityNormal):
+	default:
+		t.Fatal("a read queued after closing should not be waited on")
+	}
+}
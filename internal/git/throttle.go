@@ -0,0 +1,113 @@
+package git
+
+import (
+	"context"
+	"os/exec"
+	"strings"
+	"sync"
+	"time"
+
+	"github.com/darksworm/gitagrip/internal/config"
+)
+
+// fetchBackoff is the wait before the second attempt of a fetch; it doubles after each
+// further failure
+const fetchBackoff = 2 * time.Second
+
+// transientFailures are what git prints when the network, not the repository or the
+// credentials, made a command fail. Such fetches are tried again after a pause.
+var transientFailures = []string{
+	"Could not resolve host",
+	"Temporary failure in name resolution",
+	"Connection timed out",
+	"Operation timed out",
+	"Connection reset by peer",
+	"Connection refused",
+	"The remote end hung up unexpectedly",
+	"early EOF",
+	"RPC failed",
+	"The requested URL returned error: 429",
+	"The requested URL returned error: 502",
+	"The requested URL returned error: 503",
+	"The requested URL returned error: 504",
+	"Too many requests",
+}
+
+// isTransientFailure reports whether a failed command is worth trying again
+func isTransientFailure(output string) bool {
+	if authFailureReason(output) != "" {
+		return false
+	}
+	for _, marker := range transientFailures {
+		if strings.Contains(output, marker) {
+			return true
+		}
+	}
+	return false
+}
+
+// hostSlots limits how many fetches run at once against each remote host
+type hostSlots struct {
+	mu    sync.Mutex
+	slots map[string]chan struct{}
+}
+
+// acquire waits for a free slot for host and returns the function that frees it. Local
+// remotes (host "") are not limited.
+func (h *hostSlots) acquire(ctx context.Context, host string, limit int) (func(), error) {
+	if host == "" {
+		return func() {}, nil
+	}
+	h.mu.Lock()
+	if h.slots == nil {
+		h.slots = make(map[string]chan struct{})
+	}
+	slot, ok := h.slots[host]
+	if !ok {
+		slot = make(chan struct{}, limit)
+		h.slots[host] = slot
+	}
+	h.mu.Unlock()
+
+	select {
+	case slot <- struct{}{}:
+		return func() { <-slot }, nil
+	case <-ctx.Done():
+		return nil, ctx.Err()
+	}
+}
+
+// fetchSettings returns the [fetch] limits, or the defaults without a config
+func (gs *gitService) fetchSettings() config.FetchSettings {
+	cfg := gs.config()
+	if cfg == nil {
+		return config.FetchSettings{}
+	}
+	return cfg.Fetch
+}
+
+// remoteHost returns the host a repository fetches from: the host of origin, or of its
+// first remote ("" for local remotes and repositories without any)
+func (gs *gitService) remoteHost(ctx context.Context, repoPath string) string {
+	cmd := exec.CommandContext(ctx, "git", "config", "--get-regexp", `^remote\..*\.url$`)
+	cmd.Dir = repoPath
+	output, err := cmd.Output()
+	if err != nil {
+		return ""
+	}
+	host := ""
+	for _, line := range strings.Split(strings.TrimSpace(string(output)), "\n") {
+		key, value, _ := strings.Cut(line, " ")
+		parsed, ok := ParseRemoteURL(value)
+		if !ok {
+			continue
+		}
+		if key == "remote.origin.url" {
+			return parsed.Host
+		}
+		if host == "" {
+			host = parsed.Host
+		}
+	}
+	return host
+}
@@ -0,0 +1,102 @@
+package git
+
+import (
+	"context"
+	"log"
+	"os/exec"
+	"strconv"
+	"strings"
+
+	"github.com/darksworm/gitagrip/internal/domain"
+	"github.com/darksworm/gitagrip/internal/eventbus"
+)
+
+// maxWatchedCommits caps how many new commits are reported per watched branch
+const maxWatchedCommits = 50
+
+// checkWatches reports the commits that arrived on the watched branches of a repository
+// since they were last acknowledged. The first time a branch is seen only its current
+// tip is recorded, so existing history is not reported as new.
+func (gs *gitService) checkWatches(ctx context.Context, repoPath string) {
+	cfg := gs.config()
+	if gs.watches == nil || cfg == nil {
+		return
+	}
+
+	for _, branch := range cfg.WatchedBranches(repoPath) {
+		ref := gs.remoteRefFor(ctx, repoPath, branch)
+		if ref == "" {
+			continue
+		}
+		tip := gs.revParse(ctx, repoPath, ref)
+		if tip == "" {
+			continue
+		}
+
+		acked := gs.watches.Acknowledged(repoPath, branch)
+		if acked == "" {
+			gs.watches.Acknowledge(repoPath, branch, tip)
+			continue
+		}
+
+		commits, err := gs.commitsBetween(ctx, repoPath, acked, ref)
+		if err != nil {
+			// The acknowledged commit is gone (e.g. force-pushed away); start over from the tip
+			log.Printf("Resetting watch of %s in %s: %v", branch, repoPath, err)
+			gs.watches.Acknowledge(repoPath, branch, tip)
+			commits = nil
+		}
+
+		gs.bus.Publish(eventbus.WatchUpdatedEvent{
+			RepoPath: repoPath,
+			Branch:   branch,
+			Commits:  commits,
+		})
+	}
+}
+
+// acknowledgeWatches marks watched branches as seen and persists the state
+func (gs *gitService) acknowledgeWatches(acks []domain.WatchAck) {
+	if gs.watches == nil {
+		return
+	}
+	for _, ack := range acks {
+		gs.watches.Acknowledge(ack.RepoPath, ack.Branch, ack.Commit)
+	}
+	if err := gs.watches.Flush(); err != nil {
+		log.Printf("Failed to save watch state: %v", err)
+	}
+}
+
+// revParse resolves a ref to a commit hash, returning "" if it doesn't exist
+func (gs *gitService) revParse(ctx context.Context, repoPath string, ref string) string {
+	cmd := exec.CommandContext(ctx, "git", "rev-parse", "--verify", "--quiet", ref+"^{commit}")
+	cmd.Dir = repoPath
+
+	output, err := cmd.Output()
+	if err != nil {
+		return ""
+	}
+	return strings.TrimSpace(string(output))
+}
+
+// commitsBetween lists the commits reachable from ref but not from base, newest first
+func (gs *gitService) commitsBetween(ctx context.Context, repoPath string, base string, ref string) ([]domain.WatchedCommit, error) {
+	cmd := exec.CommandContext(ctx, "git", "log", "--format=%H%x09%s", "-n", strconv.Itoa(maxWatchedCommits), base+".."+ref)
+	cmd.Dir = repoPath
+
+	output, err := cmd.Output()
+	if err != nil {
+		return nil, err
+	}
+
+	var commits []domain.WatchedCommit
+	for _, line := range strings.Split(strings.TrimSpace(string(output)), "\n") {
+		hash, subject, ok := strings.Cut(line, "\t")
+		if !ok {
+			continue
+		}
+		commits = append(commits, domain.WatchedCommit{Hash: hash, Subject: subject})
+	}
+	return commits, nil
+}
@@ -4,8 +4,8 @@ import (
 	"fmt"
 	"sync"
 
-	"gitagrip/internal/domain"
-	"gitagrip/internal/eventbus"
+	"github.com/darksworm/gitagrip/internal/domain"
+	"github.com/darksworm/gitagrip/internal/eventbus"
 )
 
 // GroupManager manages repository grouping
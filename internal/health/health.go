@@ -0,0 +1,266 @@
+// Package health runs lightweight checks of a repository's .git directory: lock files
+// left behind by crashed git commands, its size and object count, shallow clones and,
+// on request, git fsck. Each problem comes with what to do about it.
+package health
+
+import (
+	"context"
+	"fmt"
+	"io/fs"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"sort"
+	"strconv"
+	"strings"
+	"time"
+)
+
+// Levels of a finding, from harmless to broken
+const (
+	LevelInfo = "info"
+	LevelWarn = "warn"
+	LevelFail = "fail"
+)
+
+// Thresholds above which a repository is reported
+const (
+	// StaleLockAge is how old a lock file must be before it counts as left behind; a
+	// running git command holds its locks for a moment only
+	StaleLockAge = 10 * time.Minute
+	// LargeGitDir is the size of the object database worth a look
+	LargeGitDir = 1 << 30
+	// ManyLooseObjects is the loose object count after which git gets slow (git gc --auto
+	// starts at 6700)
+	ManyLooseObjects = 5000
+)
+
+// Finding is one problem of a repository
+type Finding struct {
+	Check  string `json:"check"` // "lock", "size", "objects", "shallow" or "fsck"
+	Level  string `json:"level"`
+	Detail string `json:"detail"`
+	Fix    string `json:"fix,omitempty"` // suggested remediation
+}
+
+// Report is the outcome of checking one repository
+type Report struct {
+	Repo      string    `json:"repo"`
+	GitDir    string    `json:"git_dir"`
+	SizeBytes int64     `json:"size_bytes"` // object database, packed and loose
+	Objects   int       `json:"objects"`    // packed and loose
+	Shallow   bool      `json:"shallow"`
+	Locks     []string  `json:"locks,omitempty"` // lock files older than StaleLockAge
+	Fsck      bool      `json:"fsck"`            // whether git fsck ran
+	Findings  []Finding `json:"findings"`
+}
+
+// Level returns the worst level of the findings ("" when there are none)
+func (r Report) Level() string {
+	level := ""
+	for _, f := range r.Findings {
+		switch {
+		case f.Level == LevelFail:
+			return LevelFail
+		case f.Level == LevelWarn:
+			level = LevelWarn
+		case level == "":
+			level = LevelInfo
+		}
+	}
+	return level
+}
+
+// Options select the optional checks
+type Options struct {
+	Fsck bool // run git fsck --no-full, which reads every pack index
+}
+
+// Check runs the checks on one repository. Checks that can't run are reported as findings.
+func Check(ctx context.Context, repoPath string, opts Options) Report {
+	report := Report{Repo: repoPath, Findings: []Finding{}}
+
+	out, err := git(ctx, repoPath, "rev-parse", "--absolute-git-dir", "--is-shallow-repository")
+	lines := strings.Fields(out)
+	if err != nil || len(lines) != 2 {
+		report.Findings = append(report.Findings, Finding{
+			Check:  "git",
+			Level:  LevelFail,
+			Detail: "not readable as a git repository: " + firstLine(out, err),
+			Fix:    "check that the .git directory is intact",
+		})
+		return report
+	}
+	report.GitDir = lines[0]
+	report.Shallow = lines[1] == "true"
+
+	checkLocks(&report, time.Now())
+	checkObjects(ctx, &report)
+	if report.Shallow {
+		report.Findings = append(report.Findings, Finding{
+			Check:  "shallow",
+			Level:  LevelInfo,
+			Detail: "shallow clone: history is cut off, so logs and merges may be incomplete",
+			Fix:    "git fetch --unshallow",
+		})
+	}
+	if opts.Fsck {
+		checkFsck(ctx, &report)
+	}
+	return report
+}
+
+// checkLocks looks for lock files that no running git command holds anymore. They make
+// every later command fail with "Unable to create '...lock': File exists".
+func checkLocks(report *Report, now time.Time) {
+	candidates := []string{"index.lock", "HEAD.lock", "config.lock", "packed-refs.lock", "shallow.lock"}
+	var locks []string
+	for _, name := range candidates {
+		path := filepath.Join(report.GitDir, name)
+		if info, err := os.Stat(path); err == nil && now.Sub(info.ModTime()) > StaleLockAge {
+			locks = append(locks, path)
+		}
+	}
+	_ = filepath.WalkDir(filepath.Join(report.GitDir, "refs"), func(path string, d fs.DirEntry, err error) error {
+		if err != nil || d.IsDir() || !strings.HasSuffix(path, ".lock") {
+			return nil
+		}
+		if info, err := d.Info(); err == nil && now.Sub(info.ModTime()) > StaleLockAge {
+			locks = append(locks, path)
+		}
+		return nil
+	})
+	sort.Strings(locks)
+	report.Locks = locks
+
+	for _, lock := range locks {
+		rel, err := filepath.Rel(report.GitDir, lock)
+		if err != nil {
+			rel = lock
+		}
+		report.Findings = append(report.Findings, Finding{
+			Check:  "lock",
+			Level:  LevelFail,
+			Detail: fmt.Sprintf("leftover %s blocks git commands", rel),
+			Fix:    "make sure no git command runs in the repository, then remove " + lock,
+		})
+	}
+}
+
+// checkObjects counts the objects and their size with git count-objects
+func checkObjects(ctx context.Context, report *Report) {
+	out, err := git(ctx, report.Repo, "count-objects", "-v")
+	if err != nil {
+		report.Findings = append(report.Findings, Finding{
+			Check:  "objects",
+			Level:  LevelWarn,
+			Detail: "cannot count objects: " + firstLine(out, err),
+		})
+		return
+	}
+	counts := make(map[string]int64)
+	for _, line := range strings.Split(out, "\n") {
+		key, value, ok := strings.Cut(line, ": ")
+		if !ok {
+			continue
+		}
+		n, err := strconv.ParseInt(strings.TrimSpace(value), 10, 64)
+		if err == nil {
+			counts[key] = n
+		}
+	}
+	loose := counts["count"]
+	report.Objects = int(loose + counts["in-pack"])
+	report.SizeBytes = (counts["size"] + counts["size-pack"] + counts["size-garbage"]) * 1024
+
+	if report.SizeBytes > LargeGitDir {
+		report.Findings = append(report.Findings, Finding{
+			Check:  "size",
+			Level:  LevelWarn,
+			Detail: fmt.Sprintf("objects take %s", formatSize(report.SizeBytes)),
+			Fix:    "git gc --aggressive; move large files to Git LFS (git lfs migrate) if they are in the history",
+		})
+	}
+	if loose > ManyLooseObjects {
+		report.Findings = append(report.Findings, Finding{
+			Check:  "objects",
+			Level:  LevelWarn,
+			Detail: fmt.Sprintf("%d loose objects slow git down", loose),
+			Fix:    "git gc",
+		})
+	}
+	if garbage := counts["garbage"]; garbage > 0 {
+		report.Findings = append(report.Findings, Finding{
+			Check:  "objects",
+			Level:  LevelWarn,
+			Detail: fmt.Sprintf("%d garbage files in the object database", garbage),
+			Fix:    "git gc --prune=now",
+		})
+	}
+}
+
+// checkFsck verifies the connectivity and validity of the objects with git fsck
+func checkFsck(ctx context.Context, report *Report) {
+	report.Fsck = true
+	out, err := git(ctx, report.Repo, "fsck", "--no-full", "--no-progress", "--no-dangling")
+	if err == nil {
+		return
+	}
+	report.Findings = append(report.Findings, Finding{
+		Check:  "fsck",
+		Level:  LevelFail,
+		Detail: "git fsck: " + firstLine(out, err),
+		Fix:    "run git fsck --full for the details; re-clone if objects are missing or corrupt",
+	})
+}
+
+// RemoveLocks deletes the leftover lock files of a report, once they are still there and
+// still untouched
+func RemoveLocks(report Report) error {
+	for _, lock := range report.Locks {
+		info, err := os.Stat(lock)
+		if err != nil {
+			continue
+		}
+		if time.Since(info.ModTime()) <= StaleLockAge {
+			return fmt.Errorf("%s was touched again; a git command may be running", lock)
+		}
+		if err := os.Remove(lock); err != nil {
+			return err
+		}
+	}
+	return nil
+}
+
+// git runs a git command in a repository and returns its combined output
+func git(ctx context.Context, repoPath string, args ...string) (string, error) {
+	cmd := exec.CommandContext(ctx, "git", args...)
+	cmd.Dir = repoPath
+	out, err := cmd.CombinedOutput()
+	return strings.TrimSpace(string(out)), err
+}
+
+// firstLine returns the first line of output, or the error when there is none
+func firstLine(output string, err error) string {
+	if line, _, _ := strings.Cut(output, "\n"); line != "" {
+		return line
+	}
+	if err != nil {
+		return err.Error()
+	}
+	return ""
+}
+
+// formatSize formats a byte count using binary units (e.g. "1.5 GB")
+func formatSize(n int64) string {
+	const unit = 1024
+	if n < unit {
+		return fmt.Sprintf("%d B", n)
+	}
+	div, exp := int64(unit), 0
+	for v := n / unit; v >= unit; v /= unit {
+		div *= unit
+		exp++
+	}
+	return fmt.Sprintf("%.1f %cB", float64(n)/float64(div), "KMGTPE"[exp])
+}
@@ -0,0 +1,86 @@
+package health
+
+import (
+	"encoding/json"
+	"fmt"
+	"io"
+	"path/filepath"
+	"text/tabwriter"
+)
+
+// Report formats accepted by Write
+const (
+	FormatText = "text"
+	FormatJSON = "json"
+)
+
+// Summary is the outcome of checking the repositories of a workspace
+type Summary struct {
+	BaseDir   string   `json:"base_dir"`
+	Total     int      `json:"total"`
+	Unhealthy int      `json:"unhealthy"` // repositories with warn or fail findings
+	Repos     []Report `json:"repos"`
+}
+
+// Summarize collects reports into a summary
+func Summarize(baseDir string, reports []Report) *Summary {
+	summary := &Summary{BaseDir: baseDir, Total: len(reports), Repos: reports}
+	for _, report := range reports {
+		if level := report.Level(); level == LevelWarn || level == LevelFail {
+			summary.Unhealthy++
+		}
+	}
+	return summary
+}
+
+// Write renders the summary in the given format
+func Write(w io.Writer, summary *Summary, format string) error {
+	switch format {
+	case FormatText, "":
+		return writeText(w, summary)
+	case FormatJSON:
+		enc := json.NewEncoder(w)
+		enc.SetIndent("", "  ")
+		return enc.Encode(summary)
+	default:
+		return fmt.Errorf("unknown format %q (want text or json)", format)
+	}
+}
+
+// writeText prints one line per finding with its fix below, then the totals
+func writeText(w io.Writer, summary *Summary) error {
+	tw := tabwriter.NewWriter(w, 0, 4, 2, ' ', 0)
+	for _, report := range summary.Repos {
+		repo, err := filepath.Rel(summary.BaseDir, report.Repo)
+		if err != nil {
+			repo = report.Repo
+		}
+		if len(report.Findings) == 0 {
+			_, _ = fmt.Fprintf(tw, "OK\t%s\t%s, %d objects\n", repo, formatSize(report.SizeBytes), report.Objects)
+			continue
+		}
+		for _, finding := range report.Findings {
+			_, _ = fmt.Fprintf(tw, "%s\t%s\t%s\n", levelLabel(finding.Level), repo, finding.Detail)
+			if finding.Fix != "" {
+				_, _ = fmt.Fprintf(tw, "\t\tfix: %s\n", finding.Fix)
+			}
+		}
+	}
+	if err := tw.Flush(); err != nil {
+		return err
+	}
+
+	_, err := fmt.Fprintf(w, "\n%d of %d repositories healthy\n", summary.Total-summary.Unhealthy, summary.Total)
+	return err
+}
+
+func levelLabel(level string) string {
+	switch level {
+	case LevelFail:
+		return "FAIL"
+	case LevelWarn:
+		return "WARN"
+	default:
+		return "INFO"
+	}
+}
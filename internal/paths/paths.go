@@ -0,0 +1,160 @@
+// Package paths gives repository and directory paths one spelling per platform, so that
+// paths from the config, from scans and from the command line compare equal however they
+// were written: with either separator, a trailing one, and on Windows in any case, with
+// the \\?\ prefix of long paths or on a UNC share (\\server\share).
+package paths
+
+import (
+	"path"
+	"runtime"
+	"strings"
+	"unicode/utf8"
+)
+
+// windows is whether paths follow Windows rules: drive letters and UNC shares, both
+// separators and no difference between upper and lower case
+var windows = runtime.GOOS == "windows"
+
+// Normalize returns the canonical spelling of a path on this platform: cleaned, without a
+// trailing separator, and on Windows with backslashes only, an upper-case drive letter
+// and no \\?\ prefix. Case is kept; "" stays "".
+func Normalize(p string) string {
+	return normalize(p, windows)
+}
+
+// Key returns the form of a path that is the same for every spelling of it: its
+// normalized form, lower-cased on Windows
+func Key(p string) string {
+	return key(p, windows)
+}
+
+// Equal reports whether two paths name the same directory
+func Equal(a, b string) bool {
+	return Key(a) == Key(b)
+}
+
+// IgnoresCase reports whether paths differing only in case name the same directory here
+func IgnoresCase() bool {
+	return windows
+}
+
+// Within reports whether p is dir or lies inside it
+func Within(p, dir string) bool {
+	return within(p, dir, windows)
+}
+
+func normalize(p string, windows bool) string {
+	if p == "" {
+		return ""
+	}
+	if !windows {
+		return path.Clean(p)
+	}
+	volume, rest := splitVolume(strings.ReplaceAll(p, "/", `\`))
+	if rest == "" {
+		if strings.HasPrefix(volume, `\\`) {
+			return volume + `\`
+		}
+		return volume
+	}
+	cleaned := strings.ReplaceAll(path.Clean(strings.ReplaceAll(rest, `\`, "/")), "/", `\`)
+	if cleaned == "." && volume != "" {
+		cleaned = ""
+	}
+	return volume + cleaned
+}
+
+// splitVolume splits a Windows path with backslashes into its volume, a drive ("C:") or
+// a UNC share (\\server\share), and the rest
+func splitVolume(p string) (volume, rest string) {
+	// Long paths: \\?\C:\dir and \\?\UNC\server\share\dir
+	if unc, ok := strings.CutPrefix(p, `\\?\UNC\`); ok {
+		p = `\\` + unc
+	} else if long, ok := strings.CutPrefix(p, `\\?\`); ok {
+		p = long
+	}
+	if len(p) >= 2 && p[1] == ':' && isLetter(p[0]) {
+		return strings.ToUpper(p[:1]) + ":", p[2:]
+	}
+	if share, ok := strings.CutPrefix(p, `\\`); ok {
+		parts := strings.SplitN(share, `\`, 3)
+		if len(parts) >= 2 && parts[0] != "" && parts[1] != "" {
+			volume = `\\` + parts[0] + `\` + parts[1]
+			if len(parts) == 3 {
+				rest = `\` + parts[2]
+			}
+			return volume, rest
+		}
+	}
+	return "", p
+}
+
+func isLetter(c byte) bool {
+	return 'a' <= c && c <= 'z' || 'A' <= c && c <= 'Z'
+}
+
+func key(p string, windows bool) string {
+	p = normalize(p, windows)
+	if windows {
+		return strings.ToLower(p)
+	}
+	return p
+}
+
+func within(p, dir string, windows bool) bool {
+	p, dir = key(p, windows), key(dir, windows)
+	if p == dir {
+		return true
+	}
+	sep := "/"
+	if windows {
+		sep = `\`
+	}
+	if !strings.HasSuffix(dir, sep) {
+		dir += sep
+	}
+	return strings.HasPrefix(p, dir)
+}
+
+// Shorten fits a path in width characters by putting ellipsis in place of directories in
+// its middle. It keeps the volume (the drive or UNC share) or first directory and as many
+// of the last elements as fit; a last element too long by itself loses its start.
+func Shorten(p string, width int, ellipsis string) string {
+	if utf8.RuneCountInString(p) <= width || width <= 0 {
+		return p
+	}
+	sep := "/"
+	if strings.Contains(p, `\`) && !strings.Contains(p, "/") {
+		sep = `\`
+	}
+	elements := strings.Split(p, sep)
+	head := 1
+	switch {
+	case sep == `\` && strings.HasPrefix(p, `\\`):
+		head = min(4, len(elements)) // "", "", server, share
+	case elements[0] == "":
+		head = min(2, len(elements)) // rooted: the first directory
+	}
+	head = min(head, len(elements)-1)
+	prefix := strings.Join(elements[:head], sep) + sep + ellipsis
+
+	tail := elements[len(elements)-1]
+	for i := len(elements) - 2; i >= head; i-- {
+		longer := elements[i] + sep + tail
+		if utf8.RuneCountInString(prefix+sep+longer) > width {
+			break
+		}
+		tail = longer
+	}
+	if shortened := prefix + sep + tail; utf8.RuneCountInString(shortened) <= width {
+		return shortened
+	}
+
+	// Not even the last element fits after the volume
+	runes := []rune(tail)
+	keep := max(0, width-utf8.RuneCountInString(ellipsis))
+	if keep < len(runes) {
+		runes = runes[len(runes)-keep:]
+	}
+	return ellipsis + string(runes)
+}
@@ -0,0 +1,96 @@
+package paths
+
+import "testing"
+
+func TestNormalizeWindowsPaths(t *testing.T) {
+	for _, tc := range []struct{ in, want string }{
+		{`c:\Code\API`, `C:\Code\API`},
+		{`C:/Code/API/`, `C:\Code\API`},
+		{`C:\Code/team\..\API\.\`, `C:\Code\API`},
+		{`c:/`, `C:\`},
+		{`C:`, `C:`},
+		{`\\fileserver\share\Code\API\`, `\\fileserver\share\Code\API`},
+		{`//fileserver/share/Code/API`, `\\fileserver\share\Code\API`},
+		{`\\fileserver\share`, `\\fileserver\share\`},
+		{`\\?\C:\very\long\path`, `C:\very\long\path`},
+		{`\\?\UNC\fileserver\share\Code`, `\\fileserver\share\Code`},
+		{`code\api\`, `code\api`},
+		{"", ""},
+	} {
+		if got := normalize(tc.in, true); got != tc.want {
+			t.Fatalf("normalize(%q) = %q, want %q", tc.in, got, tc.want)
+		}
+	}
+}
+
+func TestNormalizeUnixPaths(t *testing.T) {
+	for _, tc := range []struct{ in, want string }{
+		{"/home/me/code/api/", "/home/me/code/api"},
+		{"/home/me//code/./team/../api", "/home/me/code/api"},
+		{`/home/me/odd\name`, `/home/me/odd\name`}, // a backslash is just a character here
+		{"", ""},
+	} {
+		if got := normalize(tc.in, false); got != tc.want {
+			t.Fatalf("normalize(%q) = %q, want %q", tc.in, got, tc.want)
+		}
+	}
+}
+
+func TestWindowsSpellingsOfAPathMatch(t *testing.T) {
+	same := [][2]string{
+		{`C:\Code\API`, `c:/code/api/`},
+		{`\\FileServer\Share\Code`, `//fileserver/share/code`},
+		{`\\?\C:\Code\API`, `c:\code\api`},
+		{`\\?\UNC\fileserver\share\x`, `\\FILESERVER\share\X`},
+	}
+	for _, pair := range same {
+		if key(pair[0], true) != key(pair[1], true) {
+			t.Fatalf("%q and %q should be the same path", pair[0], pair[1])
+		}
+	}
+	if key(`C:\Code\API`, true) == key(`D:\Code\API`, true) {
+		t.Fatal("paths on different drives should differ")
+	}
+	if key("/code/API", false) == key("/code/api", false) {
+		t.Fatal("paths differing in case should differ outside Windows")
+	}
+}
+
+func TestWithin(t *testing.T) {
+	for _, tc := range []struct {
+		path, dir string
+		windows   bool
+		want      bool
+	}{
+		{`C:\Code\API`, `c:/code`, true, true},
+		{`C:\Code`, `C:\Code\`, true, true},
+		{`C:\Codebase\API`, `C:\Code`, true, false},
+		{`\\srv\share\team\api`, `//SRV/share`, true, true},
+		{`C:\anything`, `C:\`, true, true},
+		{"/home/me/code/api", "/home/me/code/", false, true},
+		{"/home/me/codebase", "/home/me/code", false, false},
+	} {
+		if got := within(tc.path, tc.dir, tc.windows); got != tc.want {
+			t.Fatalf("within(%q, %q) = %t, want %t", tc.path, tc.dir, got, tc.want)
+		}
+	}
+}
+
+func TestShortenKeepsVolumeAndTail(t *testing.T) {
+	for _, tc := range []struct {
+		in    string
+		width int
+		want  string
+	}{
+		{`C:\Code\API`, 40, `C:\Code\API`},
+		{`C:\Users\someone\source\repos\platform\services\payments-api`, 30, `C:\…\services\payments-api`},
+		{`\\fileserver\engineering\teams\platform\services\payments-api`, 45, `\\fileserver\engineering\…\payments-api`},
+		{"/home/someone/code/platform/services/payments-api", 30, "/home/…/services/payments-api"},
+		{`C:\a\an-extremely-long-repository-directory-name`, 20, "…tory-directory-name"},
+	} {
+		got := Shorten(tc.in, tc.width, "…")
+		if got != tc.want {
+			t.Fatalf("Shorten(%q, %d) = %q, want %q", tc.in, tc.width, got, tc.want)
+		}
+	}
+}
@@ -0,0 +1,59 @@
+// Package session stores where the UI was left (cursor, scroll position, collapsed
+// groups, search and filter) in a JSON file next to the status cache, so that the
+// next run of the same workspace resumes there.
+package session
+
+import (
+	"crypto/sha1"
+	"encoding/hex"
+	"encoding/json"
+	"os"
+	"path/filepath"
+)
+
+// State is the UI state kept between runs
+type State struct {
+	Cursor    string   `json:"cursor,omitempty"`    // item under the cursor: "repo:<path>" or "group:<name>"
+	Offset    int      `json:"offset,omitempty"`    // first list row shown
+	Collapsed []string `json:"collapsed,omitempty"` // groups folded shut
+	Search    string   `json:"search,omitempty"`
+	Filter    string   `json:"filter,omitempty"`
+
+	RecentScans []string `json:"recent_scans,omitempty"` // directories scanned with +, newest first
+}
+
+// DefaultPath returns the session file used for a workspace directory
+func DefaultPath(baseDir string) (string, error) {
+	cacheDir, err := os.UserCacheDir()
+	if err != nil {
+		return "", err
+	}
+	sum := sha1.Sum([]byte(baseDir))
+	return filepath.Join(cacheDir, "gitagrip", "session-"+hex.EncodeToString(sum[:])[:16]+".json"), nil
+}
+
+// Load reads the session at path. A missing or unreadable file yields an empty session.
+func Load(path string) State {
+	var s State
+	if data, err := os.ReadFile(path); err == nil {
+		_ = json.Unmarshal(data, &s)
+	}
+	return s
+}
+
+// Save writes the session to path
+func Save(path string, s State) error {
+	data, err := json.Marshal(s)
+	if err != nil {
+		return err
+	}
+	if err := os.MkdirAll(filepath.Dir(path), 0755); err != nil {
+		return err
+	}
+	// Write atomically so a crash never leaves a truncated session behind
+	tmp := path + ".tmp"
+	if err := os.WriteFile(tmp, data, 0644); err != nil {
+		return err
+	}
+	return os.Rename(tmp, path)
+}
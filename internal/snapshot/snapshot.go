@@ -0,0 +1,164 @@
+// Package snapshot keeps the repositories found in a workspace together with their last
+// statuses, projected from the discovery and status events of a run. The next run shows
+// them right away while its scan catches up; repositories that turn out to be gone are
+// dropped again when a RepoRemovedEvent reports them.
+package snapshot
+
+import (
+	"crypto/sha1"
+	"encoding/hex"
+	"encoding/json"
+	"os"
+	"path/filepath"
+	"sort"
+	"sync"
+
+	"github.com/darksworm/gitagrip/internal/domain"
+	"github.com/darksworm/gitagrip/internal/eventbus"
+)
+
+// entry is a repository as kept in the snapshot file
+type entry struct {
+	Name        string            `json:"name"`
+	DisplayName string            `json:"display_name,omitempty"`
+	WorktreeOf  string            `json:"worktree_of,omitempty"`
+	Tags        []string          `json:"tags,omitempty"`
+	Status      domain.RepoStatus `json:"status"`
+}
+
+// Projection is a domain.Snapshot backed by a JSON file and kept current from events
+type Projection struct {
+	path    string
+	mu      sync.Mutex
+	entries map[string]entry // repo path -> repository
+	dirty   bool
+}
+
+var _ domain.Snapshot = (*Projection)(nil)
+
+// DefaultPath returns the snapshot file used for a workspace directory
+func DefaultPath(baseDir string) (string, error) {
+	cacheDir, err := os.UserCacheDir()
+	if err != nil {
+		return "", err
+	}
+	sum := sha1.Sum([]byte(baseDir))
+	return filepath.Join(cacheDir, "gitagrip", "snapshot-"+hex.EncodeToString(sum[:])[:16]+".json"), nil
+}
+
+// Open loads the snapshot at path and keeps it current from the bus. A missing or
+// unreadable file yields an empty snapshot.
+func Open(bus eventbus.EventBus, path string) *Projection {
+	p := &Projection{path: path, entries: make(map[string]entry)}
+	if data, err := os.ReadFile(path); err == nil {
+		_ = json.Unmarshal(data, &p.entries)
+	}
+
+	bus.Subscribe(eventbus.EventRepoDiscovered, func(e eventbus.DomainEvent) {
+		if event, ok := e.(eventbus.RepoDiscoveredEvent); ok {
+			p.discovered(event.Repo)
+		}
+	})
+	bus.Subscribe(eventbus.EventStatusUpdated, func(e eventbus.DomainEvent) {
+		if event, ok := e.(eventbus.StatusUpdatedEvent); ok {
+			p.statusUpdated(event.RepoPath, event.Status)
+		}
+	})
+	bus.Subscribe(eventbus.EventRepoRemoved, func(e eventbus.DomainEvent) {
+		if event, ok := e.(eventbus.RepoRemovedEvent); ok {
+			p.removed(event.RepoPath)
+		}
+	})
+	return p
+}
+
+// Repos returns the repositories of the snapshot by path, their statuses marked stale
+func (p *Projection) Repos() []domain.Repository {
+	p.mu.Lock()
+	defer p.mu.Unlock()
+
+	repos := make([]domain.Repository, 0, len(p.entries))
+	for path, e := range p.entries {
+		status := e.Status
+		status.Stale = true
+		repos = append(repos, domain.Repository{
+			Path:        path,
+			Name:        e.Name,
+			DisplayName: e.DisplayName,
+			WorktreeOf:  e.WorktreeOf,
+			Tags:        e.Tags,
+			Status:      status,
+		})
+	}
+	sort.Slice(repos, func(i, j int) bool { return repos[i].Path < repos[j].Path })
+	return repos
+}
+
+// discovered adds a repository, keeping the status it had in the snapshot
+func (p *Projection) discovered(repo domain.Repository) {
+	p.mu.Lock()
+	defer p.mu.Unlock()
+	e := p.entries[repo.Path]
+	e.Name = repo.Name
+	e.DisplayName = repo.DisplayName
+	e.WorktreeOf = repo.WorktreeOf
+	e.Tags = repo.Tags
+	p.entries[repo.Path] = e
+	p.dirty = true
+}
+
+// statusUpdated records the latest status of a known repository. Failed status checks
+// keep the last good status.
+func (p *Projection) statusUpdated(repoPath string, status domain.RepoStatus) {
+	if status.Error != "" {
+		return
+	}
+	p.mu.Lock()
+	defer p.mu.Unlock()
+	e, ok := p.entries[repoPath]
+	if !ok {
+		return
+	}
+	status.Stale = false
+	status.Duration = 0
+	e.Status = status
+	p.entries[repoPath] = e
+	p.dirty = true
+}
+
+// removed drops a repository that no longer exists
+func (p *Projection) removed(repoPath string) {
+	p.mu.Lock()
+	defer p.mu.Unlock()
+	if _, ok := p.entries[repoPath]; ok {
+		delete(p.entries, repoPath)
+		p.dirty = true
+	}
+}
+
+// Flush writes the snapshot file if anything changed
+func (p *Projection) Flush() error {
+	p.mu.Lock()
+	defer p.mu.Unlock()
+	if !p.dirty {
+		return nil
+	}
+
+	data, err := json.Marshal(p.entries)
+	if err != nil {
+		return err
+	}
+	if err := os.MkdirAll(filepath.Dir(p.path), 0755); err != nil {
+		return err
+	}
+	// Write atomically so a crash never leaves a truncated snapshot behind
+	tmp := p.path + ".tmp"
+	if err := os.WriteFile(tmp, data, 0644); err != nil {
+		return err
+	}
+	if err := os.Rename(tmp, p.path); err != nil {
+		return err
+	}
+	p.dirty = false
+	return nil
+}
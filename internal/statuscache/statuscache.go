@@ -0,0 +1,198 @@
+// Package statuscache stores last-known repository statuses in a JSON file,
+// keyed by repository path and validated against HEAD and the index mtime.
+package statuscache
+
+import (
+	"bufio"
+	"crypto/sha1"
+	"encoding/hex"
+	"encoding/json"
+	"os"
+	"path/filepath"
+	"strings"
+	"sync"
+
+	"github.com/darksworm/gitagrip/internal/domain"
+)
+
+// entry is a cached status together with the repository state it was computed for
+type entry struct {
+	Head       string            `json:"head"`
+	IndexMtime int64             `json:"index_mtime"`
+	Status     domain.RepoStatus `json:"status"`
+}
+
+// FileCache is a domain.StatusCache backed by a JSON file
+type FileCache struct {
+	path    string
+	mu      sync.Mutex
+	entries map[string]entry
+	dirty   bool
+}
+
+var _ domain.StatusCache = (*FileCache)(nil)
+
+// DefaultPath returns the cache file used for a workspace directory
+func DefaultPath(baseDir string) (string, error) {
+	cacheDir, err := os.UserCacheDir()
+	if err != nil {
+		return "", err
+	}
+	sum := sha1.Sum([]byte(baseDir))
+	return filepath.Join(cacheDir, "gitagrip", "status-"+hex.EncodeToString(sum[:])[:16]+".json"), nil
+}
+
+// Open loads the cache at path. A missing or unreadable file yields an empty cache.
+func Open(path string) *FileCache {
+	c := &FileCache{path: path, entries: make(map[string]entry)}
+	if data, err := os.ReadFile(path); err == nil {
+		_ = json.Unmarshal(data, &c.entries)
+	}
+	return c
+}
+
+// Load returns the cached status if HEAD and the index are unchanged since it was stored
+func (c *FileCache) Load(repoPath string) (domain.RepoStatus, bool) {
+	c.mu.Lock()
+	e, ok := c.entries[repoPath]
+	c.mu.Unlock()
+	if !ok {
+		return domain.RepoStatus{}, false
+	}
+
+	head, indexMtime, ok := fingerprint(repoPath)
+	if !ok || head != e.Head || indexMtime != e.IndexMtime {
+		return domain.RepoStatus{}, false
+	}
+	return e.Status, true
+}
+
+// Store records a freshly computed status. Failed status checks are not cached.
+func (c *FileCache) Store(repoPath string, status domain.RepoStatus) {
+	if status.Error != "" {
+		return
+	}
+	head, indexMtime, ok := fingerprint(repoPath)
+	if !ok {
+		return
+	}
+	status.Stale = false
+	status.Duration = 0
+
+	c.mu.Lock()
+	c.entries[repoPath] = entry{Head: head, IndexMtime: indexMtime, Status: status}
+	c.dirty = true
+	c.mu.Unlock()
+}
+
+// Flush writes the cache file if anything changed
+func (c *FileCache) Flush() error {
+	c.mu.Lock()
+	defer c.mu.Unlock()
+	if !c.dirty {
+		return nil
+	}
+
+	data, err := json.Marshal(c.entries)
+	if err != nil {
+		return err
+	}
+	if err := os.MkdirAll(filepath.Dir(c.path), 0755); err != nil {
+		return err
+	}
+	// Write atomically so a crash never leaves a truncated cache behind
+	tmp := c.path + ".tmp"
+	if err := os.WriteFile(tmp, data, 0644); err != nil {
+		return err
+	}
+	if err := os.Rename(tmp, c.path); err != nil {
+		return err
+	}
+	c.dirty = false
+	return nil
+}
+
+// fingerprint identifies the repository state a status depends on: the checked out
+// ref and commit, and the modification time of the index. It reads .git directly so
+// validating the cache is much cheaper than running git.
+func fingerprint(repoPath string) (head string, indexMtime int64, ok bool) {
+	gitDir, commonDir, ok := gitDirs(repoPath)
+	if !ok {
+		return "", 0, false
+	}
+
+	data, err := os.ReadFile(filepath.Join(gitDir, "HEAD"))
+	if err != nil {
+		return "", 0, false
+	}
+	head = strings.TrimSpace(string(data))
+	if ref, isRef := strings.CutPrefix(head, "ref: "); isRef {
+		head = ref + "@" + resolveRef(gitDir, commonDir, ref)
+	}
+
+	if info, err := os.Stat(filepath.Join(gitDir, "index")); err == nil {
+		indexMtime = info.ModTime().UnixNano()
+	}
+	return head, indexMtime, true
+}
+
+// gitDirs returns the repository's git directory and the common directory that holds
+// refs (they differ for linked worktrees)
+func gitDirs(repoPath string) (gitDir, commonDir string, ok bool) {
+	dotGit := filepath.Join(repoPath, ".git")
+	info, err := os.Stat(dotGit)
+	if err != nil {
+		return "", "", false
+	}
+
+	gitDir = dotGit
+	if !info.IsDir() {
+		data, err := os.ReadFile(dotGit)
+		if err != nil {
+			return "", "", false
+		}
+		target, found := strings.CutPrefix(strings.TrimSpace(string(data)), "gitdir: ")
+		if !found {
+			return "", "", false
+		}
+		if !filepath.IsAbs(target) {
+			target = filepath.Join(repoPath, target)
+		}
+		gitDir = target
+	}
+
+	commonDir = gitDir
+	if data, err := os.ReadFile(filepath.Join(gitDir, "commondir")); err == nil {
+		common := strings.TrimSpace(string(data))
+		if !filepath.IsAbs(common) {
+			common = filepath.Join(gitDir, common)
+		}
+		commonDir = common
+	}
+	return gitDir, commonDir, true
+}
+
+// resolveRef returns the commit a ref points to from loose refs or packed-refs ("" if unborn)
+func resolveRef(gitDir, commonDir, ref string) string {
+	for _, dir := range []string{gitDir, commonDir} {
+		if data, err := os.ReadFile(filepath.Join(dir, filepath.FromSlash(ref))); err == nil {
+			return strings.TrimSpace(string(data))
+		}
+	}
+
+	f, err := os.Open(filepath.Join(commonDir, "packed-refs"))
+	if err != nil {
+		return ""
+	}
+	defer func() {
+		_ = f.Close()
+	}()
+	scanner := bufio.NewScanner(f)
+	for scanner.Scan() {
+		line := scanner.Text()
+		if sha, name, found := strings.Cut(line, " "); found && name == ref {
+			return sha
+		}
+	}
+	return ""
+}
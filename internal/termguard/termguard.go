@@ -0,0 +1,76 @@
+// Package termguard puts the terminal back the way gitagrip found it when the app
+// panics outside of the UI loop (which restores it itself): raw mode off, the alternate
+// screen left, mouse reporting off and the cursor shown again
+package termguard
+
+import (
+	"fmt"
+	"io"
+	"log"
+	"os"
+	"runtime/debug"
+	"sync"
+
+	"github.com/charmbracelet/x/term"
+)
+
+// resetSequences undo what the UI turns on: mouse reporting (cell motion, all motion,
+// SGR), bracketed paste and the hidden cursor, and leave the alternate screen
+const resetSequences = "\x1b[?1002l\x1b[?1003l\x1b[?1006l\x1b[?2004l\x1b[?25h\x1b[?1049l"
+
+var (
+	mu    sync.Mutex
+	fd    uintptr
+	state *term.State // terminal mode before the UI started; nil when not a terminal
+	out   io.Writer   // where the UI draws; the sequences go there
+)
+
+// Install remembers the mode of the terminal on in, and that the UI draws to uiOut
+func Install(in *os.File, uiOut io.Writer) {
+	mu.Lock()
+	defer mu.Unlock()
+	out = uiOut
+	if !term.IsTerminal(in.Fd()) {
+		return
+	}
+	if s, err := term.GetState(in.Fd()); err == nil {
+		fd, state = in.Fd(), s
+	}
+}
+
+// Restore puts the terminal back into the mode it had when Install was called
+func Restore() {
+	mu.Lock()
+	defer mu.Unlock()
+	if state != nil {
+		_ = term.Restore(fd, state)
+	}
+}
+
+// Recover is deferred at the top of main and of the goroutines it starts. On a panic it
+// restores the terminal, prints the panic and its stack to stderr and exits with 2.
+func Recover() {
+	r := recover()
+	if r == nil {
+		return
+	}
+	stack := debug.Stack()
+	Restore()
+	mu.Lock()
+	if out != nil {
+		_, _ = io.WriteString(out, resetSequences)
+	}
+	mu.Unlock()
+
+	log.Printf("panic: %v\n%s", r, stack)
+	fmt.Fprintf(os.Stderr, "\ngitagrip crashed: %v\n\n%s\n", r, stack)
+	os.Exit(2)
+}
+
+// Go runs f in a goroutine whose panics go through Recover
+func Go(f func()) {
+	go func() {
+		defer Recover()
+		f()
+	}()
+}
@@ -0,0 +1,76 @@
+package ui
+
+// listAnchors records what the cursor and the drag and visual range anchors point at
+// as stable keys (a repository path or a group name) instead of list indices
+type listAnchors struct {
+	cursor string
+	drag   string
+	visual string
+}
+
+// listItemKeys returns one key per list index in display order: "repo:<path>" for
+// repositories, "group:<name>" for group headers and "" for the gaps between groups
+func (m *Model) listItemKeys() []string {
+	var keys []string
+	orderedGroups := m.store.GetOrderedGroups()
+	for i, groupName := range orderedGroups {
+		keys = append(keys, "group:"+groupName)
+		if m.store.IsGroupExpanded(groupName) {
+			group, _ := m.store.GetGroup(groupName)
+			for _, repoPath := range group.Repos {
+				keys = append(keys, "repo:"+repoPath)
+			}
+		}
+		// Gap after group unless it's the hidden group at the end
+		if groupName != HiddenGroupName || i != len(orderedGroups)-1 {
+			keys = append(keys, "")
+		}
+	}
+	for _, repoPath := range m.getUngroupedRepos() {
+		keys = append(keys, "repo:"+repoPath)
+	}
+	return keys
+}
+
+// captureListAnchors remembers the items under the cursor and the range anchors
+func (m *Model) captureListAnchors() listAnchors {
+	keys := m.listItemKeys()
+	at := func(index int) string {
+		if index >= 0 && index < len(keys) {
+			return keys[index]
+		}
+		return ""
+	}
+	return listAnchors{
+		cursor: at(m.state.SelectedIndex),
+		drag:   at(m.state.DragAnchor),
+		visual: at(m.state.VisualAnchor),
+	}
+}
+
+// restoreListAnchors moves the cursor and the range anchors back onto the items they
+// pointed at before the list changed (repositories discovered, re-sorted or regrouped),
+// so the cursor never silently lands on a different repository. Items that are gone
+// leave their index as is, clamped to the list.
+func (m *Model) restoreListAnchors(a listAnchors) {
+	keys := m.listItemKeys()
+	indexOf := make(map[string]int, len(keys))
+	for i, key := range keys {
+		if key != "" {
+			indexOf[key] = i
+		}
+	}
+
+	if index, ok := indexOf[a.cursor]; ok {
+		m.state.SelectedIndex = index
+	} else if maxIndex := m.getMaxIndex(); m.state.SelectedIndex > maxIndex {
+		m.state.SelectedIndex = maxIndex
+	}
+	if index, ok := indexOf[a.drag]; ok {
+		m.state.DragAnchor = index
+	}
+	if index, ok := indexOf[a.visual]; ok {
+		m.state.VisualAnchor = index
+	}
+	m.ensureSelectedVisible()
+}
@@ -0,0 +1,240 @@
+package ui
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/charmbracelet/lipgloss/v2"
+
+	"github.com/darksworm/gitagrip/internal/eventbus"
+	"github.com/darksworm/gitagrip/internal/ui/state"
+)
+
+// repoGroup returns the group a repository is in ("" when ungrouped)
+func (m *Model) repoGroup(repoPath string) string {
+	for name, group := range m.state.Groups {
+		for _, path := range group.Repos {
+			if path == repoPath {
+				return name
+			}
+		}
+	}
+	return ""
+}
+
+// forgetRepos takes the selected repositories (or the current one) out of the list and
+// the config and keeps later scans from finding them. Nothing on disk is touched.
+func (m *Model) forgetRepos() {
+	var removed []state.RemovedRepo
+	for _, path := range m.forgetTargets() {
+		if repo, ok := m.state.Repositories[path]; ok {
+			removed = append(removed, state.RemovedRepo{Repo: repo, Group: m.repoGroup(path)})
+			m.config.Forget(path)
+		}
+	}
+	if len(removed) == 0 {
+		return
+	}
+	m.removeRepos(removed)
+
+	if len(removed) == 1 {
+		m.state.StatusMessage = fmt.Sprintf("Forgot %s (ctrl+z to undo)", removed[0].Repo.Name)
+	} else {
+		m.state.StatusMessage = fmt.Sprintf("Forgot %d repos (ctrl+z to undo)", len(removed))
+	}
+}
+
+// forgetTargets returns the repositories forgetting applies to: the selected ones, or the
+// one under the cursor
+func (m *Model) forgetTargets() []string {
+	var paths []string
+	if m.store.GetSelectionCount() > 0 {
+		for path := range m.store.GetSelectedRepositories() {
+			paths = append(paths, path)
+		}
+	} else if path := m.getRepoPathAtIndex(m.state.SelectedIndex); path != "" {
+		paths = []string{path}
+	}
+	sort.Strings(paths)
+	return paths
+}
+
+// removeRepos takes repositories out of the list and their groups, remembers them for
+// undo and saves the config
+func (m *Model) removeRepos(removed []state.RemovedRepo) {
+	for _, r := range removed {
+		m.state.MoveRepoToGroup(r.Repo.Path, r.Group, "")
+		m.state.RemoveRepository(r.Repo.Path)
+	}
+	m.state.Removed = append(m.state.Removed, removed)
+
+	m.updateOrderedLists()
+	if maxIndex := m.getMaxIndex(); m.state.SelectedIndex > maxIndex {
+		m.state.SelectedIndex = max(0, maxIndex)
+	}
+	m.ensureSelectedVisible()
+	// Discovery skips forgotten repos, so it gets the new list along with the saver
+	m.configChanged()
+}
+
+// undoRemove brings back the repositories forgotten or archived last, moving archived
+// ones back from the archive directory into their group
+func (m *Model) undoRemove() {
+	if len(m.state.Removed) == 0 {
+		m.state.StatusMessage = "Nothing to undo"
+		return
+	}
+	removed := m.state.Removed[len(m.state.Removed)-1]
+
+	for _, r := range removed {
+		if r.ArchivedTo == "" {
+			continue
+		}
+		if _, err := os.Stat(r.Repo.Path); err == nil {
+			m.state.StatusMessage = fmt.Sprintf("Cannot undo: %s exists again", r.Repo.Path)
+			return
+		}
+		if err := os.Rename(r.ArchivedTo, r.Repo.Path); err != nil {
+			m.state.StatusMessage = fmt.Sprintf("Cannot undo: %v", err)
+			return
+		}
+		_ = os.Remove(r.Bundle)
+	}
+	m.state.Removed = m.state.Removed[:len(m.state.Removed)-1]
+
+	var archived []string
+	for _, r := range removed {
+		m.config.Unforget(r.Repo.Path)
+		m.state.AddRepository(r.Repo)
+		// The group may have been deleted meanwhile; the repository is ungrouped then
+		m.state.MoveRepoToGroup(r.Repo.Path, "", r.Group)
+		if r.ArchivedTo != "" {
+			archived = append(archived, r.Repo.Path)
+		}
+	}
+	m.updateOrderedLists()
+	m.configChanged()
+	if len(archived) > 0 && m.bus != nil {
+		m.bus.Publish(eventbus.StatusRefreshRequestedEvent{RepoPaths: archived})
+	}
+
+	if len(removed) == 1 {
+		m.state.StatusMessage = fmt.Sprintf("Restored %s", removed[0].Repo.Name)
+	} else {
+		m.state.StatusMessage = fmt.Sprintf("Restored %d repos", len(removed))
+	}
+}
+
+// archivePaths returns where a repository and its bundle go in the archive directory,
+// keeping its path below base_dir so that repositories of the same name don't collide
+func (m *Model) archivePaths(repoPath string) (string, string) {
+	rel, err := filepath.Rel(m.config.BaseDir, repoPath)
+	if err != nil || rel == "." || strings.HasPrefix(rel, "..") {
+		rel = filepath.Base(repoPath)
+	}
+	dest := filepath.Join(m.config.ArchiveDir(), rel)
+	return dest, dest + ".bundle"
+}
+
+// archiveRefusal explains why a repository can't be archived ("" when it can)
+func (m *Model) archiveRefusal(repoPath string) string {
+	repo, ok := m.state.Repositories[repoPath]
+	if !ok {
+		return "unknown repository"
+	}
+	dest, bundle := m.archivePaths(repoPath)
+	switch {
+	case repo.WorktreeOf != "":
+		return "it is a linked worktree; archive " + repo.WorktreeOf + " instead"
+	case repo.Status.Branch == "⋯":
+		return "its status is still loading"
+	case repo.Status.Error != "":
+		return "its status failed: " + repo.Status.Error
+	case repo.Status.IsDirty || repo.Status.HasUntracked:
+		return "it has uncommitted changes, which a bundle would not keep"
+	case repo.Status.Mode != "":
+		return "its status mode " + repo.Status.Mode + " doesn't show whether it has uncommitted changes"
+	case m.store.IsRepositoryFetching(repoPath) || m.store.IsRepositoryPulling(repoPath):
+		return "an operation is running on it"
+	}
+	for path, other := range m.state.Repositories {
+		if other.WorktreeOf == repoPath {
+			return "it has a linked worktree at " + path + ", which would lose its link to it"
+		}
+	}
+	for _, path := range []string{dest, bundle} {
+		if _, err := os.Stat(path); err == nil {
+			return path + " already exists"
+		}
+	}
+	return ""
+}
+
+// buildArchivePreview renders what archiving a repository will do, or why it won't
+func (m *Model) buildArchivePreview(repoPath, refusal string) string {
+	bold := lipgloss.NewStyle().Bold(true)
+	faint := lipgloss.NewStyle().Faint(true)
+	name := repoPath
+	if repo, ok := m.state.Repositories[repoPath]; ok {
+		name = repo.Name
+	}
+
+	var b strings.Builder
+	b.WriteString(bold.Render("Archive " + name))
+	b.WriteString("\n\n")
+	if refusal != "" {
+		b.WriteString(fmt.Sprintf("Cannot archive: %s\n\n", refusal))
+		b.WriteString(faint.Render("esc close"))
+		return b.String()
+	}
+	dest, bundle := m.archivePaths(repoPath)
+	b.WriteString(fmt.Sprintf("1. git bundle create %s --all\n", bundle))
+	b.WriteString(fmt.Sprintf("2. move %s\n     to %s\n", repoPath, dest))
+	b.WriteString("\nIt leaves the list and the config; ctrl+z moves it back.\n\n")
+	b.WriteString(faint.Render("y archive • n cancel"))
+	return b.String()
+}
+
+// archiveRepo returns a command that bundles a repository and moves it to the archive
+// directory. The bundle is removed again when the move fails.
+func (m *Model) archiveRepo(repoPath string) tea.Cmd {
+	dest, bundle := m.archivePaths(repoPath)
+	return func() tea.Msg {
+		msg := archiveMsg{repoPath: repoPath, archivedTo: dest, bundle: bundle}
+		if err := os.MkdirAll(filepath.Dir(dest), 0755); err != nil {
+			msg.err = err
+			return msg
+		}
+		if err := m.gitOps.CreateBundle(repoPath, bundle); err != nil {
+			msg.err = err
+			return msg
+		}
+		if err := os.Rename(repoPath, dest); err != nil {
+			_ = os.Remove(bundle)
+			msg.err = err
+		}
+		return msg
+	}
+}
+
+// finishArchive takes an archived repository out of the list
+func (m *Model) finishArchive(msg archiveMsg) {
+	repo, ok := m.state.Repositories[msg.repoPath]
+	if msg.err != nil || !ok {
+		if msg.err != nil {
+			m.state.StatusMessage = fmt.Sprintf("Archive failed: %v", msg.err)
+		}
+		return
+	}
+	m.removeRepos([]state.RemovedRepo{{
+		Repo:       repo,
+		Group:      m.repoGroup(msg.repoPath),
+		ArchivedTo: msg.archivedTo,
+		Bundle:     msg.bundle,
+	}})
+	m.state.StatusMessage = fmt.Sprintf("Archived %s to %s (ctrl+z to undo)", repo.Name, msg.archivedTo)
+}
@@ -0,0 +1,64 @@
+package ui
+
+import (
+	"fmt"
+	"strings"
+
+	"github.com/charmbracelet/lipgloss/v2"
+)
+
+// maxBranchOverviewNames is how many repository names are listed per branch
+const maxBranchOverviewNames = 8
+
+// buildBranchOverviewContent renders every checked out branch with the number of
+// repositories on it and their names, the most common branch first
+func (m *Model) buildBranchOverviewContent() string {
+	theme := m.renderer.Theme()
+	faint := lipgloss.NewStyle().Faint(true)
+
+	var b strings.Builder
+	b.WriteString(lipgloss.NewStyle().Bold(true).Render("Branches"))
+	b.WriteString("\n\n")
+
+	branches := m.state.RepositoriesByBranch()
+	if len(branches) == 0 {
+		b.WriteString("No repository status known yet\n\n")
+		b.WriteString(faint.Render("esc close"))
+		return b.String()
+	}
+
+	for _, branch := range branches {
+		color := theme.BranchColor(branch.Branch)
+		if branch.Branch == "detached" {
+			color = theme.Error
+		}
+		name := lipgloss.NewStyle().Foreground(lipgloss.Color(color)).Render(branch.Branch)
+		unit := "repos"
+		if len(branch.Repos) == 1 {
+			unit = "repo"
+		}
+		b.WriteString(fmt.Sprintf("%s: %d %s\n", name, len(branch.Repos), unit))
+
+		names := make([]string, 0, maxBranchOverviewNames)
+		for _, path := range branch.Repos {
+			if len(names) == maxBranchOverviewNames {
+				break
+			}
+			repo := m.state.Repositories[path]
+			if repo.DisplayName != "" {
+				names = append(names, repo.DisplayName)
+			} else {
+				names = append(names, repo.Name)
+			}
+		}
+		line := strings.Join(names, ", ")
+		if more := len(branch.Repos) - len(names); more > 0 {
+			line += fmt.Sprintf(" and %d more", more)
+		}
+		b.WriteString("    " + faint.Render(line) + "\n")
+	}
+
+	b.WriteString("\n")
+	b.WriteString(faint.Render("esc close"))
+	return b.String()
+}
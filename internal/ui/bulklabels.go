@@ -0,0 +1,216 @@
+package ui
+
+import (
+	"fmt"
+	"sort"
+	"strings"
+
+	"github.com/charmbracelet/lipgloss/v2"
+
+	"github.com/darksworm/gitagrip/internal/ui/state"
+)
+
+// openBulkLabels lists every label in use with how many of the selected repositories have it
+func (m *Model) openBulkLabels() {
+	var repos []string
+	for path := range m.store.GetSelectedRepositories() {
+		if _, ok := m.state.Repositories[path]; ok {
+			repos = append(repos, path)
+		}
+	}
+	sort.Strings(repos)
+
+	selected := make(map[string]bool, len(repos))
+	for _, path := range repos {
+		selected[path] = true
+	}
+	counts := make(map[string]int)
+	for path, repo := range m.state.Repositories {
+		for _, tag := range repo.Tags {
+			if selected[path] {
+				counts[tag]++
+			} else if _, ok := counts[tag]; !ok {
+				counts[tag] = 0
+			}
+		}
+	}
+
+	items := make([]state.BulkLabelItem, 0, len(counts))
+	for name, count := range counts {
+		items = append(items, state.BulkLabelItem{Name: name, Count: count})
+	}
+	sort.Slice(items, func(i, j int) bool {
+		return strings.ToLower(items[i].Name) < strings.ToLower(items[j].Name)
+	})
+
+	m.state.BulkLabels = &state.BulkLabels{Repos: repos, Items: items}
+	m.state.ShowInfo = true
+	m.state.InfoContent = m.buildBulkLabelsContent()
+}
+
+// toggleBulkLabel cycles the pending change of the label under the cursor through the
+// changes that would do something: adding it where it's missing, removing it where it's set
+func (m *Model) toggleBulkLabel() {
+	editor := m.state.BulkLabels
+	if editor == nil || len(editor.Items) == 0 {
+		return
+	}
+	item := &editor.Items[editor.Index]
+	switch {
+	case item.Change == 0 && item.Count < len(editor.Repos):
+		item.Change = 1
+	case item.Change >= 0 && item.Count > 0:
+		item.Change = -1
+	default:
+		item.Change = 0
+	}
+	m.state.InfoContent = m.buildBulkLabelsContent()
+}
+
+// applyBulkLabels adds and removes the pending labels on every selected repository and
+// saves the config once
+func (m *Model) applyBulkLabels() {
+	editor := m.state.BulkLabels
+	if editor == nil {
+		return
+	}
+
+	var add, remove []string
+	for _, item := range editor.Items {
+		switch item.Change {
+		case 1:
+			add = append(add, item.Name)
+		case -1:
+			remove = append(remove, item.Name)
+		}
+	}
+	if len(add) == 0 && len(remove) == 0 {
+		return
+	}
+
+	changed := m.changeRepoTags(editor.Repos, add, remove)
+	m.state.StatusMessage = fmt.Sprintf("Updated labels on %d repos", changed)
+}
+
+// addTagsToSelection adds tags to every selected repository
+func (m *Model) addTagsToSelection(tags []string) {
+	if len(tags) == 0 {
+		return
+	}
+	var repos []string
+	for path := range m.store.GetSelectedRepositories() {
+		repos = append(repos, path)
+	}
+	changed := m.changeRepoTags(repos, tags, nil)
+	m.state.StatusMessage = fmt.Sprintf("Tagged %d repos: %s", changed, strings.Join(tags, ", "))
+}
+
+// changeRepoTags adds and removes tags on the given repositories, keeping the order of the
+// tags they already have, and saves the config once. It returns how many repositories changed.
+func (m *Model) changeRepoTags(repoPaths []string, add, remove []string) int {
+	changed := 0
+	for _, path := range repoPaths {
+		repo, ok := m.state.Repositories[path]
+		if !ok {
+			continue
+		}
+
+		tags := make([]string, 0, len(repo.Tags)+len(add))
+		for _, tag := range repo.Tags {
+			if !containsFold(remove, tag) {
+				tags = append(tags, tag)
+			}
+		}
+		for _, tag := range add {
+			if !containsFold(tags, tag) {
+				tags = append(tags, tag)
+			}
+		}
+		if strings.Join(tags, ",") == strings.Join(repo.Tags, ",") {
+			continue
+		}
+
+		repo.Tags = tags
+		m.config.SetLabels(path, tags)
+		changed++
+	}
+
+	// One copy of the config for the whole batch reaches the saver and the services
+	if changed > 0 {
+		m.configChanged()
+	}
+	return changed
+}
+
+// containsFold reports whether list contains s, ignoring case
+func containsFold(list []string, s string) bool {
+	for _, item := range list {
+		if strings.EqualFold(item, s) {
+			return true
+		}
+	}
+	return false
+}
+
+// buildBulkLabelsContent renders the label editor: each label with how many selected
+// repositories have it and the pending change
+func (m *Model) buildBulkLabelsContent() string {
+	editor := m.state.BulkLabels
+	if editor == nil {
+		return ""
+	}
+	theme := m.renderer.Theme()
+	faint := lipgloss.NewStyle().Faint(true)
+	cursorStyle := lipgloss.NewStyle().Bold(true).Foreground(lipgloss.Color(theme.Warning))
+	addStyle := lipgloss.NewStyle().Foreground(lipgloss.Color(theme.Success))
+	removeStyle := lipgloss.NewStyle().Foreground(lipgloss.Color(theme.Error))
+
+	var b strings.Builder
+	b.WriteString(lipgloss.NewStyle().Bold(true).Render(
+		fmt.Sprintf("Labels for %d selected repos", len(editor.Repos))))
+	b.WriteString("\n\n")
+
+	if len(editor.Items) == 0 {
+		b.WriteString("No labels yet\n")
+	}
+
+	nameWidth := 0
+	for _, item := range editor.Items {
+		nameWidth = max(nameWidth, lipgloss.Width(item.Name))
+	}
+	total := len(editor.Repos)
+	for i, item := range editor.Items {
+		marker := "  "
+		if i == editor.Index {
+			marker = cursorStyle.Render("> ")
+		}
+
+		// The box shows what the selection will look like once applied
+		count := item.Count
+		switch item.Change {
+		case 1:
+			count = total
+		case -1:
+			count = 0
+		}
+		box := "[-]"
+		if count == total {
+			box = "[x]"
+		} else if count == 0 {
+			box = "[ ]"
+		}
+
+		line := fmt.Sprintf("%s %-*s  %d/%d", box, nameWidth, item.Name, item.Count, total)
+		switch item.Change {
+		case 1:
+			line += addStyle.Render(fmt.Sprintf("  + add to %d", total-item.Count))
+		case -1:
+			line += removeStyle.Render(fmt.Sprintf("  - remove from %d", item.Count))
+		}
+		b.WriteString(marker + line + "\n")
+	}
+
+	b.WriteString("\n")
+	b.WriteString(faint.Render("space toggle • enter apply • n new label • esc cancel"))
+	return b.String()
+}
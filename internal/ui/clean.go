@@ -0,0 +1,97 @@
+package ui
+
+import (
+	"fmt"
+	"path/filepath"
+	"sort"
+	"strings"
+
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/charmbracelet/lipgloss/v2"
+)
+
+// maxCleanPreviewEntries limits how many paths are listed per repository in the dry run
+const maxCleanPreviewEntries = 8
+
+// cleanTargets returns the selected repositories, or the one under the cursor
+func (m *Model) cleanTargets() []string {
+	var paths []string
+	if m.store.GetSelectionCount() > 0 {
+		for path := range m.store.GetSelectedRepositories() {
+			paths = append(paths, path)
+		}
+	} else if rp := m.getRepoPathAtIndex(m.state.SelectedIndex); rp != "" {
+		paths = []string{rp}
+	}
+	sort.Strings(paths)
+	return paths
+}
+
+// runCleanDryRun returns a command that runs git clean -fdxn in each repository
+func (m *Model) runCleanDryRun(repoPaths []string) tea.Cmd {
+	return func() tea.Msg {
+		results := make([]cleanDryRunResult, 0, len(repoPaths))
+		for _, path := range repoPaths {
+			entries, err := m.gitOps.CleanDryRun(path)
+			results = append(results, cleanDryRunResult{repoPath: path, entries: entries, err: err})
+		}
+		return cleanDryRunMsg{results: results}
+	}
+}
+
+// cleanablePaths returns the repositories whose dry run listed something to remove
+func cleanablePaths(results []cleanDryRunResult) []string {
+	var paths []string
+	for _, res := range results {
+		if res.err == nil && len(res.entries) > 0 {
+			paths = append(paths, res.repoPath)
+		}
+	}
+	return paths
+}
+
+// buildCleanPreview renders the dry run results shown before cleaning
+func (m *Model) buildCleanPreview(results []cleanDryRunResult) string {
+	var b strings.Builder
+	b.WriteString(lipgloss.NewStyle().Bold(true).Render(
+		fmt.Sprintf("git clean -fdx dry run (%d repos)", len(results))))
+	b.WriteString("\n\n")
+
+	faint := lipgloss.NewStyle().Faint(true)
+	total := 0
+	for _, res := range results {
+		name := filepath.Base(res.repoPath)
+		if repo, ok := m.state.Repositories[res.repoPath]; ok && repo.DisplayName != "" {
+			name = repo.DisplayName
+		}
+
+		if res.err != nil {
+			b.WriteString(fmt.Sprintf("%s: %v\n", name, res.err))
+			continue
+		}
+		if len(res.entries) == 0 {
+			b.WriteString(faint.Render(fmt.Sprintf("%s: nothing to remove", name)))
+			b.WriteString("\n")
+			continue
+		}
+
+		total += len(res.entries)
+		b.WriteString(fmt.Sprintf("%s: would remove %d\n", name, len(res.entries)))
+		for i, entry := range res.entries {
+			if i == maxCleanPreviewEntries {
+				b.WriteString(faint.Render(fmt.Sprintf("    ... and %d more", len(res.entries)-i)))
+				b.WriteString("\n")
+				break
+			}
+			b.WriteString("    " + entry + "\n")
+		}
+	}
+
+	b.WriteString("\n")
+	if total == 0 {
+		b.WriteString(faint.Render("Nothing to clean • esc close"))
+	} else {
+		b.WriteString(faint.Render(fmt.Sprintf("y remove %d entries • n/esc cancel", total)))
+	}
+	return b.String()
+}
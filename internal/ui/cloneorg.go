@@ -0,0 +1,244 @@
+package ui
+
+import (
+	"context"
+	"fmt"
+	"os"
+	"path/filepath"
+	"slices"
+	"strings"
+
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/charmbracelet/lipgloss/v2"
+
+	"github.com/darksworm/gitagrip/internal/eventbus"
+	"github.com/darksworm/gitagrip/internal/forge"
+	"github.com/darksworm/gitagrip/internal/ui/state"
+)
+
+// cloneOrgRows is how many repositories the clone wizard shows at once
+const cloneOrgRows = 15
+
+// cloneOrgListedMsg carries the repositories of an organization, or why they couldn't be listed
+type cloneOrgListedMsg struct {
+	host, org string
+	repos     []forge.Repo
+	err       error
+}
+
+// startCloneOrg opens the clone wizard on the organization typed, e.g. "myorg" or
+// "gitlab:mygroup", and lists its repositories in the background
+func (m *Model) startCloneOrg(text string) tea.Cmd {
+	host, org := forge.ParseOrg(text)
+	m.state.CloneOrg = &state.CloneOrgPicker{Host: host, Org: org, Loading: true, Picked: make(map[int]bool)}
+	client, err := forge.New(host, m.config.CloneOrg.APIURL(host))
+	if err == nil && org == "" {
+		err = fmt.Errorf("no organization given")
+	}
+	if err != nil {
+		m.state.CloneOrg.Loading = false
+		m.state.CloneOrg.Err = err.Error()
+		return nil
+	}
+	return func() tea.Msg {
+		repos, err := client.List(context.Background(), org)
+		return cloneOrgListedMsg{host: host, org: org, repos: repos, err: err}
+	}
+}
+
+// openCloneOrg shows the clone wizard
+func (m *Model) openCloneOrg() {
+	if m.state.CloneOrg == nil {
+		return
+	}
+	m.state.ShowInfo = true
+	m.state.InfoContent = m.buildCloneOrgContent()
+}
+
+// listedCloneOrg fills the wizard with the organization's repositories. The ones not on
+// disk yet are picked, unless archived or forks.
+func (m *Model) listedCloneOrg(msg cloneOrgListedMsg) {
+	picker := m.state.CloneOrg
+	if picker == nil || picker.Host != msg.host || picker.Org != msg.org {
+		return // closed, or another organization asked for since
+	}
+	picker.Loading = false
+	if msg.err != nil {
+		picker.Err = msg.err.Error()
+	}
+	picker.Repos = msg.repos
+	dest := m.cloneOrgDest(picker.Org)
+	for i, repo := range picker.Repos {
+		if _, err := os.Stat(filepath.Join(dest, filepath.FromSlash(repo.Name))); os.IsNotExist(err) && !repo.Archived && !repo.Fork {
+			picker.Picked[i] = true
+		}
+	}
+	m.state.InfoContent = m.buildCloneOrgContent()
+}
+
+// navigateCloneOrg moves the cursor, stopping at either end
+func (m *Model) navigateCloneOrg(delta int) {
+	picker := m.state.CloneOrg
+	if len(picker.Repos) == 0 {
+		return
+	}
+	picker.Index = max(0, min(len(picker.Repos)-1, picker.Index+delta))
+	m.state.InfoContent = m.buildCloneOrgContent()
+}
+
+// toggleCloneOrg picks or unpicks the repository under the cursor; with all, picks every
+// repository unless all of them are picked already, then unpicks them
+func (m *Model) toggleCloneOrg(all bool) {
+	picker := m.state.CloneOrg
+	if len(picker.Repos) == 0 {
+		return
+	}
+	if !all {
+		picker.Picked[picker.Index] = !picker.Picked[picker.Index]
+	} else {
+		pick := m.cloneOrgPickedCount() < len(picker.Repos)
+		for i := range picker.Repos {
+			picker.Picked[i] = pick
+		}
+	}
+	m.state.InfoContent = m.buildCloneOrgContent()
+}
+
+// cloneOrgPickedCount counts the picked repositories
+func (m *Model) cloneOrgPickedCount() int {
+	count := 0
+	for _, picked := range m.state.CloneOrg.Picked {
+		if picked {
+			count++
+		}
+	}
+	return count
+}
+
+// cloneOrgDest returns the directory an organization's repositories are cloned into
+func (m *Model) cloneOrgDest(org string) string {
+	return filepath.Join(m.config.BaseDir, filepath.FromSlash(org))
+}
+
+// cloneOrg records the picked repositories in [repos] with their url, puts them in a group
+// named after the organization and clones them, as clone-missing would. They show up in
+// the group as their clones finish.
+func (m *Model) cloneOrg() tea.Cmd {
+	picker := m.state.CloneOrg
+	if picker == nil || m.cloneOrgPickedCount() == 0 {
+		m.state.StatusMessage = "No repositories picked to clone"
+		return nil
+	}
+	group, dest := picker.Org, m.cloneOrgDest(picker.Org)
+	var repoPaths []string
+	for i, repo := range picker.Repos {
+		if !picker.Picked[i] {
+			continue
+		}
+		dir := filepath.Join(dest, filepath.FromSlash(repo.Name))
+		m.config.AddClone(dir, repo.CloneURL(m.config.CloneOrg.SSH), group)
+		repoPaths = append(repoPaths, m.config.CanonicalPath(dir))
+	}
+
+	if _, exists := m.state.Groups[group]; !exists {
+		m.state.AddGroup(group, []string{})
+		if m.bus != nil {
+			m.bus.Publish(eventbus.GroupAddedEvent{Name: group})
+		}
+	}
+	for _, repoPath := range repoPaths {
+		from := ""
+		for name, g := range m.state.Groups {
+			if slices.Contains(g.Repos, repoPath) {
+				from = name
+			}
+		}
+		if from == group {
+			continue
+		}
+		m.state.MoveRepoToGroup(repoPath, from, group)
+		if m.bus != nil {
+			m.bus.Publish(eventbus.RepoMovedEvent{RepoPath: repoPath, FromGroup: from, ToGroup: group})
+		}
+	}
+	m.updateOrderedLists()
+
+	m.state.StatusMessage = fmt.Sprintf("Cloning %d repos of %s into %s", len(repoPaths), picker.Org, dest)
+	// The git service clones from the new [repos] entries, so it gets them first
+	m.configChanged()
+	if m.bus != nil {
+		m.bus.Publish(eventbus.CloneMissingRequestedEvent{RepoPaths: repoPaths})
+	}
+	return nil
+}
+
+// buildCloneOrgContent renders the organization's repositories around the cursor, the
+// picked ones checked
+func (m *Model) buildCloneOrgContent() string {
+	picker := m.state.CloneOrg
+	bold := lipgloss.NewStyle().Bold(true)
+	faint := lipgloss.NewStyle().Faint(true)
+	dest := m.cloneOrgDest(picker.Org)
+
+	var b strings.Builder
+	b.WriteString(bold.Render(fmt.Sprintf("Clone from %s:%s", picker.Host, picker.Org)))
+	b.WriteString("\n")
+	b.WriteString(faint.Render("into " + dest + ", group " + picker.Org))
+	b.WriteString("\n\n")
+	switch {
+	case picker.Loading:
+		b.WriteString("Listing repositories…\n")
+	case picker.Err != "":
+		b.WriteString("Could not list them: " + picker.Err + "\n")
+	case len(picker.Repos) == 0:
+		b.WriteString(faint.Render("No repositories"))
+		b.WriteString("\n")
+	}
+
+	start := max(0, min(picker.Index-cloneOrgRows/2, len(picker.Repos)-cloneOrgRows))
+	end := min(len(picker.Repos), start+cloneOrgRows)
+	if start > 0 {
+		b.WriteString(faint.Render(fmt.Sprintf("  ↑ %d more", start)))
+		b.WriteString("\n")
+	}
+	for i := start; i < end; i++ {
+		repo := picker.Repos[i]
+		marker := "  "
+		if i == picker.Index {
+			marker = "› "
+		}
+		check := "[ ] "
+		if picker.Picked[i] {
+			check = "[x] "
+		}
+		line := marker + check + repo.Name
+		var notes []string
+		if _, err := os.Stat(filepath.Join(dest, filepath.FromSlash(repo.Name))); err == nil {
+			notes = append(notes, "present")
+		}
+		if repo.Archived {
+			notes = append(notes, "archived")
+		}
+		if repo.Fork {
+			notes = append(notes, "fork")
+		}
+		if repo.Description != "" {
+			notes = append(notes, truncateCells(repo.Description, 50))
+		}
+		if len(notes) > 0 {
+			line += faint.Render("  " + strings.Join(notes, " · "))
+		}
+		b.WriteString(line + "\n")
+	}
+	if end < len(picker.Repos) {
+		b.WriteString(faint.Render(fmt.Sprintf("  ↓ %d more", len(picker.Repos)-end)))
+		b.WriteString("\n")
+	}
+
+	b.WriteString("\n")
+	if len(picker.Repos) > 0 {
+		b.WriteString(fmt.Sprintf("%d of %d picked\n", m.cloneOrgPickedCount(), len(picker.Repos)))
+	}
+	b.WriteString(faint.Render("j/k move • space pick • a pick all/none • enter clone • esc close"))
+	return b.String()
+}
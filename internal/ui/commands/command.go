@@ -5,8 +5,9 @@ import (
 
 	tea "github.com/charmbracelet/bubbletea/v2"
 
-	"gitagrip/internal/eventbus"
-	"gitagrip/internal/ui/state"
+	"github.com/darksworm/gitagrip/internal/domain"
+	"github.com/darksworm/gitagrip/internal/eventbus"
+	"github.com/darksworm/gitagrip/internal/ui/state"
 )
 
 // Command represents an executable action
@@ -16,8 +17,9 @@ type Command interface {
 
 // CommandContext provides context for command execution
 type CommandContext struct {
-	State *state.AppState
-	Bus   eventbus.EventBus
+	State         *state.AppState
+	Bus           eventbus.EventBus
+	ConfigChanged func() // takes changed groups into the config and saves it (may be nil)
 }
 
 // RefreshCommand refreshes repository status
@@ -51,13 +53,15 @@ func (c *RefreshCommand) Execute() tea.Cmd {
 type FetchCommand struct {
 	ctx       *CommandContext
 	repoPaths []string
+	options   domain.FetchOptions
 }
 
 // NewFetchCommand creates a new fetch command
-func NewFetchCommand(ctx *CommandContext, repoPaths []string) *FetchCommand {
+func NewFetchCommand(ctx *CommandContext, repoPaths []string, options domain.FetchOptions) *FetchCommand {
 	return &FetchCommand{
 		ctx:       ctx,
 		repoPaths: repoPaths,
+		options:   options,
 	}
 }
 
@@ -68,12 +72,37 @@ func (c *FetchCommand) Execute() tea.Cmd {
 		if c.ctx.Bus != nil {
 			c.ctx.Bus.Publish(eventbus.FetchRequestedEvent{
 				RepoPaths: c.repoPaths,
+				Options:   c.options,
 			})
 		}
 	}
 	return nil
 }
 
+// PushCommand pushes the current branch of repositories
+type PushCommand struct {
+	ctx       *CommandContext
+	repoPaths []string
+}
+
+// NewPushCommand creates a new push command
+func NewPushCommand(ctx *CommandContext, repoPaths []string) *PushCommand {
+	return &PushCommand{
+		ctx:       ctx,
+		repoPaths: repoPaths,
+	}
+}
+
+// Execute performs the push operation
+func (c *PushCommand) Execute() tea.Cmd {
+	if len(c.repoPaths) > 0 && c.ctx.Bus != nil {
+		c.ctx.Bus.Publish(eventbus.PushRequestedEvent{
+			RepoPaths: c.repoPaths,
+		})
+	}
+	return nil
+}
+
 // PullCommand pulls from remote repositories
 type PullCommand struct {
 	ctx       *CommandContext
@@ -163,6 +192,75 @@ func (c *SwitchBranchCommand) Execute() tea.Cmd {
 	return nil
 }
 
+// SetRemoteURLCommand rewrites a remote's URL in a repository
+type SetRemoteURLCommand struct {
+	ctx      *CommandContext
+	repoPath string
+	remote   string
+	url      string
+}
+
+func NewSetRemoteURLCommand(ctx *CommandContext, repoPath, remote, url string) *SetRemoteURLCommand {
+	return &SetRemoteURLCommand{ctx: ctx, repoPath: repoPath, remote: remote, url: url}
+}
+
+func (c *SetRemoteURLCommand) Execute() tea.Cmd {
+	if c.ctx.Bus != nil && c.url != "" {
+		c.ctx.Bus.Publish(eventbus.RemoteURLChangeRequestedEvent{RepoPath: c.repoPath, Remote: c.remote, URL: c.url})
+	}
+	return nil
+}
+
+// CleanCommand removes untracked and ignored files from repositories
+type CleanCommand struct {
+	ctx       *CommandContext
+	repoPaths []string
+}
+
+func NewCleanCommand(ctx *CommandContext, repoPaths []string) *CleanCommand {
+	return &CleanCommand{ctx: ctx, repoPaths: repoPaths}
+}
+
+func (c *CleanCommand) Execute() tea.Cmd {
+	if c.ctx.Bus != nil && len(c.repoPaths) > 0 {
+		c.ctx.Bus.Publish(eventbus.CleanRequestedEvent{RepoPaths: c.repoPaths})
+	}
+	return nil
+}
+
+// CancelOperationsCommand asks running bulk operations to stop after the repos in flight
+type CancelOperationsCommand struct {
+	ctx *CommandContext
+}
+
+func NewCancelOperationsCommand(ctx *CommandContext) *CancelOperationsCommand {
+	return &CancelOperationsCommand{ctx: ctx}
+}
+
+func (c *CancelOperationsCommand) Execute() tea.Cmd {
+	if c.ctx.Bus != nil {
+		c.ctx.Bus.Publish(eventbus.CancelOperationsRequestedEvent{})
+	}
+	return nil
+}
+
+// AcknowledgeWatchesCommand marks the commits on watched branches as seen
+type AcknowledgeWatchesCommand struct {
+	ctx  *CommandContext
+	acks []domain.WatchAck
+}
+
+func NewAcknowledgeWatchesCommand(ctx *CommandContext, acks []domain.WatchAck) *AcknowledgeWatchesCommand {
+	return &AcknowledgeWatchesCommand{ctx: ctx, acks: acks}
+}
+
+func (c *AcknowledgeWatchesCommand) Execute() tea.Cmd {
+	if len(c.acks) > 0 && c.ctx.Bus != nil {
+		c.ctx.Bus.Publish(eventbus.WatchAcknowledgeRequestedEvent{Acks: c.acks})
+	}
+	return nil
+}
+
 // ToggleSelectionCommand toggles repository selection
 type ToggleSelectionCommand struct {
 	ctx      *CommandContext
@@ -185,27 +283,20 @@ func (c *ToggleSelectionCommand) Execute() tea.Cmd {
 	return nil
 }
 
-// SelectAllCommand toggles select all repositories
+// SelectAllCommand selects every repository
 type SelectAllCommand struct {
-	ctx        *CommandContext
-	totalRepos int
+	ctx *CommandContext
 }
 
 // NewSelectAllCommand creates a new select all command
-func NewSelectAllCommand(ctx *CommandContext, totalRepos int) *SelectAllCommand {
-	return &SelectAllCommand{
-		ctx:        ctx,
-		totalRepos: totalRepos,
-	}
+func NewSelectAllCommand(ctx *CommandContext) *SelectAllCommand {
+	return &SelectAllCommand{ctx: ctx}
 }
 
-// Execute toggles select all
+// Execute selects all repositories
 func (c *SelectAllCommand) Execute() tea.Cmd {
-	if len(c.ctx.State.SelectedRepos) == c.totalRepos {
-		c.ctx.State.ClearSelection()
-	} else {
-		c.ctx.State.SelectAll()
-	}
+	c.ctx.State.SelectAll()
+	c.ctx.State.StatusMessage = fmt.Sprintf("Selected %d repositories", len(c.ctx.State.SelectedRepos))
 	return nil
 }
 
@@ -245,14 +336,17 @@ func (c *MoveToGroupCommand) Execute() tea.Cmd {
 	}
 
 	if movedCount > 0 {
-		c.ctx.State.StatusMessage = fmt.Sprintf("Moved %d repos to '%s'", movedCount, c.toGroup)
 		c.ctx.State.ClearSelection()
 
 		if c.ctx.Bus != nil {
-			c.ctx.Bus.Publish(eventbus.ConfigChangedEvent{
-				Groups: c.ctx.State.GetGroupsMap(),
+			c.ctx.Bus.Publish(eventbus.AppMessageEvent{
+				Level: domain.MessageSuccess,
+				Text:  fmt.Sprintf("Moved %d repos to '%s'", movedCount, c.toGroup),
 			})
 		}
+		if c.ctx.ConfigChanged != nil {
+			c.ctx.ConfigChanged()
+		}
 	}
 
 	return nil
@@ -3,8 +3,9 @@ package commands
 import (
 	tea "github.com/charmbracelet/bubbletea/v2"
 
-	"gitagrip/internal/eventbus"
-	"gitagrip/internal/ui/state"
+	"github.com/darksworm/gitagrip/internal/domain"
+	"github.com/darksworm/gitagrip/internal/eventbus"
+	"github.com/darksworm/gitagrip/internal/ui/state"
 )
 
 // Executor handles command execution
@@ -12,12 +13,14 @@ type Executor struct {
 	ctx *CommandContext
 }
 
-// NewExecutor creates a new command executor
-func NewExecutor(state *state.AppState, bus eventbus.EventBus) *Executor {
+// NewExecutor creates a new command executor; configChanged is called after commands
+// that change the groups
+func NewExecutor(state *state.AppState, bus eventbus.EventBus, configChanged func()) *Executor {
 	return &Executor{
 		ctx: &CommandContext{
-			State: state,
-			Bus:   bus,
+			State:         state,
+			Bus:           bus,
+			ConfigChanged: configChanged,
 		},
 	}
 }
@@ -30,7 +33,13 @@ func (e *Executor) ExecuteRefresh(repoPaths []string) tea.Cmd {
 
 // ExecuteFetch creates and executes a fetch command
 func (e *Executor) ExecuteFetch(repoPaths []string) tea.Cmd {
-	cmd := NewFetchCommand(e.ctx, repoPaths)
+	cmd := NewFetchCommand(e.ctx, repoPaths, domain.FetchOptions{})
+	return cmd.Execute()
+}
+
+// ExecuteFetchWithOptions creates and executes a fetch command limited by options
+func (e *Executor) ExecuteFetchWithOptions(repoPaths []string, options domain.FetchOptions) tea.Cmd {
+	cmd := NewFetchCommand(e.ctx, repoPaths, options)
 	return cmd.Execute()
 }
 
@@ -40,6 +49,12 @@ func (e *Executor) ExecutePull(repoPaths []string) tea.Cmd {
 	return cmd.Execute()
 }
 
+// ExecutePush creates and executes a push command
+func (e *Executor) ExecutePush(repoPaths []string) tea.Cmd {
+	cmd := NewPushCommand(e.ctx, repoPaths)
+	return cmd.Execute()
+}
+
 // ExecuteCreateBranch creates a branch on the given repositories
 func (e *Executor) ExecuteCreateBranch(repoPaths []string, name string) tea.Cmd {
 	cmd := NewCreateBranchCommand(e.ctx, repoPaths, name)
@@ -52,6 +67,30 @@ func (e *Executor) ExecuteSwitchBranch(repoPaths []string, name string) tea.Cmd
 	return cmd.Execute()
 }
 
+// ExecuteSetRemoteURL rewrites a remote's URL in a repository
+func (e *Executor) ExecuteSetRemoteURL(repoPath, remote, url string) tea.Cmd {
+	cmd := NewSetRemoteURLCommand(e.ctx, repoPath, remote, url)
+	return cmd.Execute()
+}
+
+// ExecuteClean runs git clean -fdx on the given repositories
+func (e *Executor) ExecuteClean(repoPaths []string) tea.Cmd {
+	cmd := NewCleanCommand(e.ctx, repoPaths)
+	return cmd.Execute()
+}
+
+// ExecuteCancelOperations skips the queued part of running bulk operations
+func (e *Executor) ExecuteCancelOperations() tea.Cmd {
+	cmd := NewCancelOperationsCommand(e.ctx)
+	return cmd.Execute()
+}
+
+// ExecuteAcknowledgeWatches marks the commits on watched branches as seen
+func (e *Executor) ExecuteAcknowledgeWatches(acks []domain.WatchAck) tea.Cmd {
+	cmd := NewAcknowledgeWatchesCommand(e.ctx, acks)
+	return cmd.Execute()
+}
+
 // ExecuteFullScan creates and executes a full scan command
 func (e *Executor) ExecuteFullScan(scanPath string) tea.Cmd {
 	cmd := NewFullScanCommand(e.ctx, scanPath)
@@ -65,8 +104,8 @@ func (e *Executor) ExecuteToggleSelection(repoPath string) tea.Cmd {
 }
 
 // ExecuteSelectAll creates and executes a select all command
-func (e *Executor) ExecuteSelectAll(totalRepos int) tea.Cmd {
-	cmd := NewSelectAllCommand(e.ctx, totalRepos)
+func (e *Executor) ExecuteSelectAll() tea.Cmd {
+	cmd := NewSelectAllCommand(e.ctx)
 	return cmd.Execute()
 }
 
@@ -0,0 +1,187 @@
+package ui
+
+import (
+	"fmt"
+	"strings"
+
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/charmbracelet/lipgloss/v2"
+
+	inputtypes "github.com/darksworm/gitagrip/internal/ui/input/types"
+	"github.com/darksworm/gitagrip/internal/ui/state"
+)
+
+// commitLogRanges are the date ranges d cycles through, with the git date each starts at
+var commitLogRanges = []struct{ label, since string }{
+	{"any time", ""},
+	{"past week", "1 week ago"},
+	{"past month", "1 month ago"},
+	{"past 3 months", "3 months ago"},
+	{"past year", "1 year ago"},
+}
+
+// openCommitLog opens the commit log of the repository under the cursor and reads it in
+// the background
+func (m *Model) openCommitLog() tea.Cmd {
+	repoPath := m.getRepoPathAtIndex(m.state.SelectedIndex)
+	repo, ok := m.state.Repositories[repoPath]
+	if !ok {
+		return m.enterMode(inputtypes.ModeNormal)
+	}
+	// git log fails on a repository without commits
+	if repo.Status.NoCommits {
+		m.state.StatusMessage = fmt.Sprintf("%s has no commits yet", repo.Name)
+		return m.enterMode(inputtypes.ModeNormal)
+	}
+	m.state.CommitLog = &state.CommitLog{Repo: repoPath}
+	return m.reloadCommitLog()
+}
+
+// reloadCommitLog reads the commit log again after its filter changed
+func (m *Model) reloadCommitLog() tea.Cmd {
+	view := m.state.CommitLog
+	view.Filter = commitLogFilter(view)
+	view.Loading = true
+	view.Offset = 0
+	m.state.ShowInfo = true
+	m.state.InfoContent = m.buildCommitLogContent()
+
+	repoPath, filter := view.Repo, view.Filter
+	return func() tea.Msg {
+		commits, err := m.gitOps.CommitLog(repoPath, filter)
+		return commitLogMsg{repo: repoPath, filter: filter, commits: commits, err: err}
+	}
+}
+
+// finishCommitLog shows the commits read, unless the view was closed or filtered
+// differently meanwhile
+func (m *Model) finishCommitLog(msg commitLogMsg) {
+	view := m.state.CommitLog
+	if view == nil || view.Repo != msg.repo || view.Filter != msg.filter {
+		return
+	}
+	view.Loading = false
+	view.Commits = msg.commits
+	view.Err = ""
+	if msg.err != nil {
+		view.Err = msg.err.Error()
+	}
+	m.state.InfoContent = m.buildCommitLogContent()
+}
+
+// commitLogFilter turns the view's query, date range and branch toggle into what git log
+// is asked for. since: and until: in the query override the date range.
+func commitLogFilter(view *state.CommitLog) state.LogFilter {
+	filter := state.LogFilter{Since: commitLogRanges[view.Range].since, AllBranches: view.All}
+	var words []string
+	for _, word := range strings.Fields(view.Query) {
+		if author, ok := strings.CutPrefix(word, "author:"); ok {
+			filter.Author = author
+		} else if since, ok := strings.CutPrefix(word, "since:"); ok {
+			filter.Since = since
+		} else if until, ok := strings.CutPrefix(word, "until:"); ok {
+			filter.Until = until
+		} else {
+			words = append(words, word)
+		}
+	}
+	filter.Message = strings.Join(words, " ")
+	return filter
+}
+
+// scrollCommitLog moves the first commit shown, keeping a page of them in view
+func (m *Model) scrollCommitLog(delta int) {
+	view := m.state.CommitLog
+	if view == nil {
+		return
+	}
+	view.Offset = max(0, min(view.Offset+delta, len(view.Commits)-m.commitLogRows()))
+	m.state.InfoContent = m.buildCommitLogContent()
+}
+
+// commitLogRows is how many commits fit in the popup
+func (m *Model) commitLogRows() int {
+	return max(5, m.height-14)
+}
+
+// buildCommitLogContent renders the commit log: which commits it shows, the filter and
+// a page of commits with their refs, author and age
+func (m *Model) buildCommitLogContent() string {
+	view := m.state.CommitLog
+	theme := m.renderer.Theme()
+	bold := lipgloss.NewStyle().Bold(true)
+	faint := lipgloss.NewStyle().Faint(true)
+	hash := lipgloss.NewStyle().Foreground(lipgloss.Color(theme.Warning))
+	refs := lipgloss.NewStyle().Foreground(lipgloss.Color(theme.Accent))
+
+	var b strings.Builder
+	b.WriteString(bold.Render("Commit log: " + m.compareName(view.Repo)))
+	branches := "current branch"
+	if view.Filter.AllBranches {
+		branches = "all branches"
+	}
+	dates := commitLogRanges[view.Range].label
+	if view.Filter.Since != commitLogRanges[view.Range].since || view.Filter.Until != "" {
+		dates = fmt.Sprintf("since %s until %s", orDash(view.Filter.Since), orDash(view.Filter.Until))
+	}
+	b.WriteString(faint.Render(fmt.Sprintf("  (%s, %s)", branches, dates)))
+	b.WriteString("\n")
+	switch {
+	case view.Input != "":
+		b.WriteString("Filter: " + view.Input + "\n")
+	case view.Query != "":
+		b.WriteString("Filter: " + view.Query + "\n")
+	}
+	b.WriteString("\n")
+
+	width := max(40, m.width-14)
+	switch {
+	case view.Loading:
+		b.WriteString(faint.Render("Reading commits..."))
+		b.WriteString("\n")
+	case view.Err != "":
+		b.WriteString(lipgloss.NewStyle().Foreground(lipgloss.Color(theme.Error)).Render(truncateCells(view.Err, width)))
+		b.WriteString("\n")
+	case len(view.Commits) == 0:
+		b.WriteString(faint.Render("No commits match"))
+		b.WriteString("\n")
+	}
+
+	end := min(len(view.Commits), view.Offset+m.commitLogRows())
+	for _, commit := range view.Commits[view.Offset:end] {
+		meta := fmt.Sprintf(" %s, %s", commit.Author, commit.Date)
+		line := hash.Render(commit.Hash) + " "
+		room := width - lipgloss.Width(commit.Hash) - 1 - lipgloss.Width(meta)
+		if commit.Refs != "" {
+			decoration := truncateCells("("+commit.Refs+")", max(10, room/3))
+			line += refs.Render(decoration) + " "
+			room -= lipgloss.Width(decoration) + 1
+		}
+		line += truncateCells(commit.Subject, max(10, room))
+		b.WriteString(line + faint.Render(meta) + "\n")
+	}
+	if len(view.Commits) > 0 {
+		more := ""
+		if len(view.Commits) >= maxLogCommits {
+			more = "+"
+		}
+		b.WriteString(faint.Render(fmt.Sprintf("%d-%d of %d%s commits", view.Offset+1, end, len(view.Commits), more)))
+		b.WriteString("\n")
+	}
+
+	b.WriteString("\n")
+	if view.Input != "" {
+		b.WriteString(faint.Render("words match the message • author:name • since:/until:date • enter apply • esc cancel"))
+	} else {
+		b.WriteString(faint.Render("j/k scroll • / filter • a all branches • d date range • c clear filter • esc close"))
+	}
+	return b.String()
+}
+
+// orDash returns s, or "-" when it is empty
+func orDash(s string) string {
+	if s == "" {
+		return "-"
+	}
+	return s
+}
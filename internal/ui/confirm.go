@@ -0,0 +1,96 @@
+package ui
+
+import (
+	"fmt"
+	"strings"
+
+	tea "github.com/charmbracelet/bubbletea/v2"
+
+	"github.com/darksworm/gitagrip/internal/ui/input"
+	inputtypes "github.com/darksworm/gitagrip/internal/ui/input/types"
+)
+
+// confirm asks before running risky actions: it opens the confirmation dialog, and the
+// actions run once it is answered with y. With ui.skip_confirmations they run right away.
+func (m *Model) confirm(actions []inputtypes.Action) tea.Cmd {
+	if m.config.UISettings.SkipConfirmations {
+		return m.runActions(actions)
+	}
+	question := m.confirmQuestion(actions)
+	if question == "" {
+		return nil
+	}
+	cmd := m.enterMode(inputtypes.ModeConfirm)
+	m.confirming = actions
+	m.state.Confirm = question
+	return cmd
+}
+
+// resolveConfirm closes the confirmation dialog, running what it asked about when confirmed
+func (m *Model) resolveConfirm(confirmed bool) tea.Cmd {
+	actions := m.confirming
+	m.confirming = nil
+	m.state.Confirm = ""
+	if !confirmed {
+		return nil
+	}
+	return m.runActions(actions)
+}
+
+// confirmQuestion describes what the actions are about to do ("" when there is nothing to ask)
+func (m *Model) confirmQuestion(actions []inputtypes.Action) string {
+	var lines []string
+	for _, action := range actions {
+		switch a := action.(type) {
+		case inputtypes.DeleteGroupAction:
+			group, ok := m.state.Groups[a.GroupName]
+			switch {
+			case !ok:
+				continue
+			case len(group.Repos) > 0:
+				lines = append(lines, fmt.Sprintf("Delete group '%s' and move its %d repos to Ungrouped?", a.GroupName, len(group.Repos)))
+			default:
+				lines = append(lines, fmt.Sprintf("Delete group '%s'?", a.GroupName))
+			}
+		case inputtypes.ForgetReposAction:
+			targets := m.forgetTargets()
+			switch len(targets) {
+			case 0:
+				continue
+			case 1:
+				lines = append(lines, fmt.Sprintf("Forget %s?", m.state.Repositories[targets[0]].Name))
+			default:
+				lines = append(lines, fmt.Sprintf("Forget %d repos?", len(targets)))
+			}
+			lines = append(lines, "They stay on disk; ctrl+z brings them back.")
+		case inputtypes.SwitchBranchAction:
+			lines = append(lines, fmt.Sprintf("Check out '%s' in %d repos?", a.Name, len(a.RepoPaths)))
+		default:
+			lines = append(lines, fmt.Sprintf("Run %s?", strings.ReplaceAll(action.Type(), "_", " ")))
+		}
+	}
+	return strings.Join(lines, "\n")
+}
+
+// runActions processes actions in order, as if they came from a key press
+func (m *Model) runActions(actions []inputtypes.Action) tea.Cmd {
+	cmds := make([]tea.Cmd, 0, len(actions))
+	for _, action := range actions {
+		cmds = append(cmds, m.processAction(action))
+	}
+	return tea.Batch(cmds...)
+}
+
+// enterMode switches the input mode without a key press and processes what leaving the
+// old mode and entering the new one asks for
+func (m *Model) enterMode(mode inputtypes.Mode) tea.Cmd {
+	ctx := &input.ModelContext{
+		State:          m.state,
+		Store:          m.store,
+		Navigator:      m.navigator,
+		CurrentSort:    m.currentSort,
+		UngroupedRepos: m.getUngroupedRepos(),
+	}
+	actions, cmd := m.inputHandler.EnterMode(mode, ctx)
+	return tea.Batch(cmd, m.runActions(actions))
+}
@@ -0,0 +1,137 @@
+package ui
+
+import (
+	"errors"
+	"fmt"
+	"slices"
+	"sort"
+	"strings"
+	"time"
+
+	tea "github.com/charmbracelet/bubbletea/v2"
+
+	"github.com/darksworm/gitagrip/internal/control"
+	"github.com/darksworm/gitagrip/internal/paths"
+)
+
+// ControlMsg is a command from the control socket. Its result goes to Reply.
+type ControlMsg struct {
+	Request control.Request
+	Reply   chan<- ControlResult
+}
+
+// ControlResult is what a control command did, or why it failed
+type ControlResult struct {
+	Message string
+	Err     error
+}
+
+// ControlHandler carries out the control socket's commands in the program that send
+// delivers messages to
+func ControlHandler(send func(tea.Msg)) control.Handler {
+	return func(req control.Request) (string, error) {
+		reply := make(chan ControlResult, 1)
+		send(ControlMsg{Request: req, Reply: reply})
+		select {
+		case result := <-reply:
+			return result.Message, result.Err
+		case <-time.After(5 * time.Second):
+			return "", errors.New("gitagrip did not answer in time")
+		}
+	}
+}
+
+// handleControl carries out a control command the way the matching keys would
+func (m *Model) handleControl(req control.Request) (string, tea.Cmd, error) {
+	repoPaths, err := m.controlTargets(req.Target)
+	if err != nil {
+		return "", nil, err
+	}
+
+	switch req.Action {
+	case control.ActionRefresh:
+		m.state.StatusMessage = fmt.Sprintf("Refreshing %d repos", len(repoPaths))
+		return fmt.Sprintf("refreshing %d repositories", len(repoPaths)), m.cmdExecutor.ExecuteRefresh(repoPaths), nil
+	case control.ActionFetch:
+		m.state.StatusMessage = fmt.Sprintf("Fetching %d repos", len(repoPaths))
+		return fmt.Sprintf("fetching %d repositories", len(repoPaths)), m.cmdExecutor.ExecuteFetch(repoPaths), nil
+	case control.ActionJump:
+		if m.resume != nil {
+			m.finishResume()
+		}
+		wanted := map[string]bool{"group:" + m.controlGroup(req.Target.Name): true}
+		if req.Target.Kind == "repo" {
+			wanted = make(map[string]bool)
+			for _, repoPath := range repoPaths {
+				wanted["repo:"+repoPath] = true
+				// Repositories in collapsed groups are shown first
+				for name, group := range m.state.Groups {
+					if name != HiddenGroupName && slices.Contains(group.Repos, repoPath) {
+						m.state.ExpandedGroups[name] = true
+					}
+				}
+			}
+		}
+		// The first match in the list when several repositories have the name
+		for index, item := range m.listItemKeys() {
+			if wanted[item] {
+				m.state.SelectedIndex = index
+				m.ensureSelectedVisible()
+				return "", nil, nil
+			}
+		}
+		return "", nil, fmt.Errorf("%s is not in the list", req.Target.Name)
+	}
+	return "", nil, fmt.Errorf("unknown command %q", req.Action)
+}
+
+// controlTargets returns the repositories a control command applies to: all of them, a
+// group's, or the ones with a name or path
+func (m *Model) controlTargets(target control.Target) ([]string, error) {
+	var repoPaths []string
+	switch target.Kind {
+	case "group":
+		name := m.controlGroup(target.Name)
+		group, ok := m.state.Groups[name]
+		if !ok {
+			return nil, fmt.Errorf("no group %q", target.Name)
+		}
+		repoPaths = append(repoPaths, group.Repos...)
+		if len(repoPaths) == 0 {
+			return nil, fmt.Errorf("group %q is empty", name)
+		}
+		return repoPaths, nil
+	case "repo":
+		for path, repo := range m.state.Repositories {
+			if repo.Name == target.Name || repo.DisplayName == target.Name || paths.Equal(path, target.Name) {
+				repoPaths = append(repoPaths, path)
+			}
+		}
+		if len(repoPaths) == 0 {
+			return nil, fmt.Errorf("no repository %q", target.Name)
+		}
+	default:
+		for path := range m.state.Repositories {
+			repoPaths = append(repoPaths, path)
+		}
+		if len(repoPaths) == 0 {
+			return nil, errors.New("no repositories yet")
+		}
+	}
+	sort.Strings(repoPaths)
+	return repoPaths, nil
+}
+
+// controlGroup returns the name of the group a control command means: the one with
+// that name, or else the one with that name in another case
+func (m *Model) controlGroup(name string) string {
+	if _, ok := m.state.Groups[name]; ok {
+		return name
+	}
+	for groupName := range m.state.Groups {
+		if strings.EqualFold(groupName, name) {
+			return groupName
+		}
+	}
+	return name
+}
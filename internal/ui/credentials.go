@@ -0,0 +1,49 @@
+package ui
+
+import (
+	"strings"
+
+	tea "github.com/charmbracelet/bubbletea/v2"
+
+	"github.com/darksworm/gitagrip/internal/eventbus"
+	inputtypes "github.com/darksworm/gitagrip/internal/ui/input/types"
+)
+
+// credentialPrompt is what git or ssh asked in the credential request shown, with the
+// repository it is for
+func (m *Model) credentialPrompt() string {
+	if len(m.state.CredentialRequests) == 0 {
+		return ""
+	}
+	request := m.state.CredentialRequests[0]
+	prompt := strings.TrimSpace(request.Prompt) + " "
+	if repo, ok := m.state.Repositories[request.RepoPath]; ok {
+		prompt = repo.Name + ": " + prompt
+	}
+	return prompt
+}
+
+// showCredentialPrompt opens the prompt of the first waiting credential request, unless
+// another prompt or popup has the keyboard; it opens once that one is closed
+func (m *Model) showCredentialPrompt() tea.Cmd {
+	if len(m.state.CredentialRequests) == 0 || m.inputHandler.CurrentMode() != inputtypes.ModeNormal {
+		return nil
+	}
+	return m.enterMode(inputtypes.ModeCredential)
+}
+
+// answerCredential sends the answer (or refusal) to the credential request shown. The
+// command that asked fails when it is refused.
+func (m *Model) answerCredential(a inputtypes.AnswerCredentialAction) {
+	if len(m.state.CredentialRequests) == 0 {
+		return
+	}
+	request := m.state.CredentialRequests[0]
+	m.state.CredentialRequests = m.state.CredentialRequests[1:]
+	if m.bus != nil {
+		m.bus.Publish(eventbus.CredentialProvidedEvent{ID: request.ID, Value: a.Value, Cancelled: a.Declined})
+	}
+	if a.Declined {
+		m.state.StatusMessage = "Declined: " + strings.TrimSpace(request.Prompt)
+	}
+}
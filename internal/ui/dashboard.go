@@ -0,0 +1,146 @@
+package ui
+
+import (
+	"fmt"
+	"strings"
+	"time"
+
+	"github.com/charmbracelet/lipgloss/v2"
+
+	"github.com/darksworm/gitagrip/internal/domain"
+)
+
+// statusCounts aggregates the status of a set of repositories
+type statusCounts struct {
+	Repos    int
+	Dirty    int
+	Ahead    int
+	Behind   int
+	Detached int
+	Errored  int
+}
+
+func (c *statusCounts) add(repo *domain.Repository) {
+	c.Repos++
+	status := repo.Status
+	if status.IsDirty {
+		c.Dirty++
+	}
+	if status.AheadCount > 0 {
+		c.Ahead++
+	}
+	if status.BehindCount > 0 {
+		c.Behind++
+	}
+	if strings.HasPrefix(status.Branch, "detached") {
+		c.Detached++
+	}
+	if status.Error != "" || repo.HasError {
+		c.Errored++
+	}
+}
+
+// countRepos aggregates the repositories among paths that are known
+func (m *Model) countRepos(paths []string) statusCounts {
+	var counts statusCounts
+	for _, path := range paths {
+		if repo, ok := m.state.Repositories[path]; ok {
+			counts.add(repo)
+		}
+	}
+	return counts
+}
+
+// buildDashboardContent renders the aggregate status of every repository, a per-group
+// breakdown and how long the latest status refreshes took
+func (m *Model) buildDashboardContent() string {
+	theme := m.renderer.Theme()
+	bold := lipgloss.NewStyle().Bold(true)
+	faint := lipgloss.NewStyle().Faint(true)
+	warn := lipgloss.NewStyle().Foreground(lipgloss.Color(theme.Warning))
+	bad := lipgloss.NewStyle().Foreground(lipgloss.Color(theme.Error))
+
+	var total statusCounts
+	for _, repo := range m.state.Repositories {
+		total.add(repo)
+	}
+
+	var b strings.Builder
+	b.WriteString(bold.Render("Dashboard"))
+	b.WriteString("\n\n")
+
+	// Totals, with the numbers that need attention highlighted
+	figure := func(n int, label string, style lipgloss.Style) string {
+		text := fmt.Sprintf("%d %s", n, label)
+		if n == 0 {
+			return faint.Render(text)
+		}
+		return style.Render(text)
+	}
+	b.WriteString(strings.Join([]string{
+		bold.Render(fmt.Sprintf("%d repos", total.Repos)),
+		figure(total.Dirty, "dirty", warn),
+		figure(total.Behind, "behind", warn),
+		figure(total.Ahead, "ahead", warn),
+		figure(total.Detached, "detached", bad),
+		figure(total.Errored, "errored", bad),
+	}, "  "))
+	b.WriteString("\n\n")
+
+	// Per-group breakdown; groups without repositories are left out
+	type row struct {
+		name   string
+		counts statusCounts
+	}
+	var rows []row
+	for _, name := range m.store.GetOrderedGroups() {
+		if group, ok := m.state.Groups[name]; ok {
+			if counts := m.countRepos(group.Repos); counts.Repos > 0 {
+				rows = append(rows, row{name: name, counts: counts})
+			}
+		}
+	}
+	if counts := m.countRepos(m.getUngroupedRepos()); counts.Repos > 0 {
+		rows = append(rows, row{name: "Ungrouped", counts: counts})
+	}
+
+	nameWidth := len("Group")
+	for _, r := range rows {
+		nameWidth = max(nameWidth, min(lipgloss.Width(r.name), 24))
+	}
+	line := func(name string, cells ...any) string {
+		if lipgloss.Width(name) > nameWidth {
+			name = string([]rune(name)[:nameWidth-1]) + "…"
+		}
+		return fmt.Sprintf("%-*s "+strings.Repeat(" %8v", len(cells)), append([]any{nameWidth, name}, cells...)...)
+	}
+	b.WriteString(bold.Render(line("Group", "Repos", "Dirty", "Behind", "Ahead", "Detached", "Errors")))
+	b.WriteString("\n")
+	for _, r := range rows {
+		c := r.counts
+		b.WriteString(line(r.name, c.Repos, c.Dirty, c.Behind, c.Ahead, c.Detached, c.Errored))
+		b.WriteString("\n")
+	}
+	b.WriteString("\n")
+
+	// Latest status refresh durations
+	durations := m.state.RefreshDurations
+	if len(durations) == 0 {
+		b.WriteString(faint.Render("Refresh times: none yet"))
+	} else {
+		values := make([]float64, len(durations))
+		var sum, longest time.Duration
+		for i, d := range durations {
+			values[i] = float64(d)
+			sum += d
+			longest = max(longest, d)
+		}
+		average := sum / time.Duration(len(durations))
+		b.WriteString(fmt.Sprintf("Refresh times  %s  avg %s, max %s",
+			m.renderer.Sparkline(values), average.Round(time.Millisecond), longest.Round(time.Millisecond)))
+	}
+	b.WriteString("\n\n")
+
+	b.WriteString(faint.Render("esc close"))
+	return b.String()
+}
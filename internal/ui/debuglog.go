@@ -0,0 +1,76 @@
+package ui
+
+import (
+	"fmt"
+	"strings"
+
+	"github.com/charmbracelet/lipgloss/v2"
+
+	"github.com/darksworm/gitagrip/internal/applog"
+	"github.com/darksworm/gitagrip/internal/ui/state"
+)
+
+// debugLogLines is how much of the log the view reads
+const debugLogLines = 200
+
+// openDebugLog reads the end of the log file and shows it scrolled to the bottom
+func (m *Model) openDebugLog() {
+	view := &state.DebugLog{Path: applog.Path()}
+	lines, err := applog.Tail(view.Path, debugLogLines)
+	if err != nil {
+		view.Err = err.Error()
+	}
+	view.Lines = lines
+	view.Offset = max(0, len(lines)-m.debugLogRows())
+	m.state.DebugLog = view
+	m.state.ShowInfo = true
+	m.state.InfoContent = m.buildDebugLogContent()
+}
+
+// scrollDebugLog moves the first line shown, keeping a page of lines in view
+func (m *Model) scrollDebugLog(delta int) {
+	view := m.state.DebugLog
+	if view == nil {
+		return
+	}
+	view.Offset = max(0, min(view.Offset+delta, len(view.Lines)-m.debugLogRows()))
+	m.state.InfoContent = m.buildDebugLogContent()
+}
+
+// debugLogRows is how many log lines fit in the popup
+func (m *Model) debugLogRows() int {
+	return max(5, m.height-12)
+}
+
+// buildDebugLogContent renders a page of log lines, cut to the popup's width
+func (m *Model) buildDebugLogContent() string {
+	view := m.state.DebugLog
+	bold := lipgloss.NewStyle().Bold(true)
+	faint := lipgloss.NewStyle().Faint(true)
+
+	var b strings.Builder
+	b.WriteString(bold.Render("Debug log"))
+	b.WriteString(faint.Render("  (" + view.Path + ")"))
+	b.WriteString("\n\n")
+	switch {
+	case view.Err != "":
+		b.WriteString("Could not read the log: " + view.Err + "\n")
+	case len(view.Lines) == 0:
+		b.WriteString(faint.Render("Nothing logged yet"))
+		b.WriteString("\n")
+	}
+
+	width := max(40, m.width-14)
+	end := min(len(view.Lines), view.Offset+m.debugLogRows())
+	for _, line := range view.Lines[view.Offset:end] {
+		b.WriteString(truncateCells(line, width) + "\n")
+	}
+	if len(view.Lines) > 0 {
+		b.WriteString(faint.Render(fmt.Sprintf("lines %d-%d of the last %d", view.Offset+1, end, len(view.Lines))))
+		b.WriteString("\n")
+	}
+
+	b.WriteString("\n")
+	b.WriteString(faint.Render("j/k scroll • g/G top/bottom • r reload • esc close"))
+	return b.String()
+}
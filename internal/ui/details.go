@@ -0,0 +1,129 @@
+package ui
+
+import (
+	"fmt"
+	"strings"
+
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/charmbracelet/lipgloss/v2"
+
+	inputtypes "github.com/darksworm/gitagrip/internal/ui/input/types"
+	"github.com/darksworm/gitagrip/internal/ui/state"
+)
+
+// maxDetailTags is how many of the newest tags the info popup lists, and maxDetailCommits
+// how many commits the details pane does
+const (
+	maxDetailTags    = 5
+	maxDetailCommits = 5
+)
+
+// loadRepoDetails returns a command that reads the remotes, stashes, tags, worktrees and
+// newest commits of a repository for the info popup and the details pane
+func (m *Model) loadRepoDetails(repoPath string) tea.Cmd {
+	// git log fails on a repository without commits
+	repo, ok := m.state.Repositories[repoPath]
+	readCommits := ok && !repo.Status.NoCommits
+	return func() tea.Msg {
+		var details state.RepoDetails
+		var errs []string
+		var err error
+		if details.Remotes, err = m.gitOps.Remotes(repoPath); err != nil {
+			errs = append(errs, "remotes: "+err.Error())
+		}
+		if details.Stashes, err = m.gitOps.StashCount(repoPath); err != nil {
+			errs = append(errs, "stashes: "+err.Error())
+		}
+		if details.Tags, err = m.gitOps.RecentTags(repoPath, maxDetailTags); err != nil {
+			errs = append(errs, "tags: "+err.Error())
+		}
+		if details.Worktrees, err = m.gitOps.Worktrees(repoPath); err != nil {
+			errs = append(errs, "worktrees: "+err.Error())
+		}
+		if readCommits {
+			if details.Commits, err = m.gitOps.CommitLog(repoPath, state.LogFilter{Limit: maxDetailCommits}); err != nil {
+				errs = append(errs, "commits: "+err.Error())
+			}
+		}
+		if len(errs) > 0 {
+			details.Err = errs[0]
+		}
+		return repoDetailsMsg{path: repoPath, details: details}
+	}
+}
+
+// finishRepoDetails caches the details read and shows them if the repository's info is
+// still open
+func (m *Model) finishRepoDetails(msg repoDetailsMsg) {
+	if m.state.Details == nil {
+		m.state.Details = make(map[string]state.RepoDetails)
+	}
+	m.state.Details[msg.path] = msg.details
+	if msg.path == m.pane.repo {
+		m.pane.loading, m.pane.fresh = false, true
+	}
+
+	if !m.state.ShowInfo || m.state.InfoRepo != msg.path || m.inputHandler.CurrentMode() != inputtypes.ModeNormal {
+		return
+	}
+	if repo, ok := m.state.Repositories[msg.path]; ok {
+		m.state.InfoContent = m.buildRepoInfo(repo)
+	}
+}
+
+// writeRepoDetails adds the remotes, stash count, newest tags and other worktrees of a
+// repository to its info, as last read (they are re-read each time the info opens)
+func (m *Model) writeRepoDetails(info *strings.Builder, repoPath string) {
+	faint := lipgloss.NewStyle().Faint(true)
+	details, ok := m.state.Details[repoPath]
+	if !ok {
+		info.WriteString(faint.Render("  Reading remotes, stashes, tags and worktrees..."))
+		info.WriteString("\n")
+		return
+	}
+
+	info.WriteString("  Remotes:")
+	if len(details.Remotes) == 0 {
+		info.WriteString(faint.Render(" none"))
+	}
+	info.WriteString("\n")
+	nameWidth := 0
+	for _, remote := range details.Remotes {
+		nameWidth = max(nameWidth, len(remote.Name))
+	}
+	for _, remote := range details.Remotes {
+		info.WriteString(fmt.Sprintf("    %-*s  %s", nameWidth, remote.Name, remote.FetchURL))
+		if remote.PushURL != "" && remote.PushURL != remote.FetchURL {
+			info.WriteString(faint.Render(" (push: " + remote.PushURL + ")"))
+		}
+		info.WriteString("\n")
+	}
+
+	if details.Stashes > 0 {
+		info.WriteString(fmt.Sprintf("  Stashes: %d\n", details.Stashes))
+	} else {
+		info.WriteString("  Stashes: " + faint.Render("none") + "\n")
+	}
+
+	if len(details.Tags) > 0 {
+		info.WriteString(fmt.Sprintf("  Latest tags: %s\n", strings.Join(details.Tags, ", ")))
+	} else {
+		info.WriteString("  Latest tags: " + faint.Render("none") + "\n")
+	}
+
+	if len(details.Worktrees) > 0 {
+		info.WriteString("  Worktrees:\n")
+		for _, wt := range details.Worktrees {
+			branch := wt.Branch
+			if branch == "" {
+				branch = "detached"
+			}
+			info.WriteString(fmt.Sprintf("    %s %s\n", wt.Path, faint.Render("("+branch+")")))
+		}
+	}
+
+	if details.Err != "" {
+		info.WriteString(lipgloss.NewStyle().Foreground(lipgloss.Color(m.renderer.Theme().Error)).Render("  Could not read "+details.Err))
+		info.WriteString("\n")
+	}
+}
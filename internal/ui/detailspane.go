@@ -0,0 +1,142 @@
+package ui
+
+import (
+	"fmt"
+	"strings"
+	"time"
+
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/charmbracelet/lipgloss/v2"
+
+	"github.com/darksworm/gitagrip/internal/paths"
+	"github.com/darksworm/gitagrip/internal/ui/views"
+)
+
+const (
+	// defaultTwoPaneWidth is the terminal width from which the details pane shows beside
+	// the list, unless ui.two_pane_width says otherwise
+	defaultTwoPaneWidth = 160
+
+	// paneSettle is how long the cursor rests on a repository before its details are read,
+	// so that scrolling through the list doesn't start git for every row passed
+	paneSettle = 150 * time.Millisecond
+)
+
+// detailsPane tracks the repository the details pane shows, to keep its details current
+type detailsPane struct {
+	repo    string    // repository under the cursor ("" for a group header or none)
+	since   time.Time // when the cursor got to it
+	loading bool      // its details are being read
+	fresh   bool      // its details were read since the cursor got to it and its status last changed
+}
+
+// twoPane reports whether the terminal is wide enough for the details pane
+func (m *Model) twoPane() bool {
+	threshold := m.config.UISettings.TwoPaneWidth
+	if threshold == 0 {
+		threshold = defaultTwoPaneWidth
+	}
+	return threshold > 0 && m.width >= threshold
+}
+
+// warmDetailsPane follows the cursor on every tick: once it has rested on a repository,
+// the repository's details are read again, and again after each change of its status
+func (m *Model) warmDetailsPane(now time.Time) tea.Cmd {
+	if !m.twoPane() {
+		m.pane = detailsPane{}
+		return nil
+	}
+	repoPath := m.getRepoPathAtIndex(m.state.SelectedIndex)
+	if repoPath != m.pane.repo {
+		m.pane = detailsPane{repo: repoPath, since: now}
+	}
+	if m.pane.repo == "" || m.pane.loading || m.pane.fresh || now.Sub(m.pane.since) < paneSettle {
+		return nil
+	}
+	m.pane.loading = true
+	return m.loadRepoDetails(m.pane.repo)
+}
+
+// staleDetailsPane has the details of the repository in the pane read again after its
+// status changed (a commit, a fetch, a branch switch)
+func (m *Model) staleDetailsPane(repoPath string) {
+	if repoPath == m.pane.repo {
+		m.pane.fresh = false
+	}
+}
+
+// buildDetailsPane builds the details pane: the status of the repository under the cursor,
+// its newest commits and its remotes, stashes, tags and worktrees as last read
+func (m *Model) buildDetailsPane() string {
+	faint := lipgloss.NewStyle().Faint(true)
+	repo, ok := m.state.Repositories[m.getRepoPathAtIndex(m.state.SelectedIndex)]
+	if !ok {
+		return faint.Render("Move to a repository to see its details")
+	}
+	theme := m.renderer.Theme()
+	var info strings.Builder
+
+	info.WriteString(lipgloss.NewStyle().Bold(true).Render(repo.Name))
+	info.WriteString("\n")
+	_, paneWidth := views.PaneWidths(m.width)
+	info.WriteString(faint.Render(paths.Shorten(repo.Path, paneWidth-1, m.renderer.Ellipsis())))
+	info.WriteString("\n\n")
+
+	// Branch, with how far it is from its upstream
+	branchStyle := lipgloss.NewStyle().Foreground(lipgloss.Color(theme.BranchColor(repo.Status.Branch)))
+	branch := repo.Status.Branch
+	switch {
+	case repo.Status.NoCommits:
+		branch = "empty"
+	case repo.Status.HeadCommit != "":
+		branch = views.DetachedLabel(repo.Status)
+	case branch == "":
+		branch = "unknown"
+	}
+	info.WriteString("Branch: " + branchStyle.Render(branch))
+	if repo.Status.AheadCount > 0 || repo.Status.BehindCount > 0 {
+		glyphs := m.renderer.Glyphs()
+		info.WriteString(fmt.Sprintf(" %s%d %s%d", glyphs.Ahead, repo.Status.AheadCount, glyphs.Behind, repo.Status.BehindCount))
+	}
+	info.WriteString("\n")
+	if repo.Status.Upstream != "" {
+		info.WriteString("Upstream: " + repo.Status.Upstream + "\n")
+	} else {
+		info.WriteString("Upstream: " + faint.Render("none") + "\n")
+	}
+
+	// Working tree
+	info.WriteString("State: ")
+	switch changes := views.ChangeCountsText(repo.Status); {
+	case repo.Status.Error != "":
+		info.WriteString(lipgloss.NewStyle().Foreground(lipgloss.Color(theme.Error)).Render(repo.Status.Error))
+	case changes != "":
+		info.WriteString(lipgloss.NewStyle().Foreground(lipgloss.Color(theme.Warning)).Render(changes))
+	case repo.Status.IsDirty || repo.Status.HasUntracked:
+		info.WriteString(lipgloss.NewStyle().Foreground(lipgloss.Color(theme.Warning)).Render("Dirty"))
+	case repo.Status.Stale || repo.Status.Branch == "":
+		info.WriteString(faint.Render("reading..."))
+	default:
+		info.WriteString(lipgloss.NewStyle().Foreground(lipgloss.Color(theme.Success)).Render("Clean"))
+	}
+	info.WriteString("\n")
+	if badge := views.OperationBadge(repo.Status.InProgress); badge != "" {
+		info.WriteString("In progress: " + lipgloss.NewStyle().Foreground(lipgloss.Color(theme.Error)).Bold(true).Render(badge) + "\n")
+	}
+
+	// Newest commits, read with the rest of the details
+	if details, ok := m.state.Details[repo.Path]; ok && len(details.Commits) > 0 {
+		info.WriteString("\n")
+		info.WriteString(lipgloss.NewStyle().Bold(true).Render("Recent commits:"))
+		info.WriteString("\n")
+		for _, commit := range details.Commits {
+			info.WriteString(fmt.Sprintf("  %s %s %s\n", faint.Render(commit.Hash), commit.Subject, faint.Render("("+commit.Date+")")))
+		}
+	}
+
+	info.WriteString("\n")
+	info.WriteString(lipgloss.NewStyle().Bold(true).Render("Details:"))
+	info.WriteString("\n")
+	m.writeRepoDetails(&info, repo.Path)
+	return strings.TrimRight(info.String(), "\n")
+}
@@ -0,0 +1,182 @@
+package ui
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/charmbracelet/lipgloss/v2"
+
+	"github.com/darksworm/gitagrip/internal/ui/state"
+)
+
+// dirBrowserRows is how many entries the directory browser shows at once
+const dirBrowserRows = 15
+
+// maxRecentScans is how many scanned directories are remembered for the browser's quick pick
+const maxRecentScans = 5
+
+// openDirBrowser opens the directory browser at the base directory
+func (m *Model) openDirBrowser() {
+	m.state.DirBrowser = &state.DirBrowser{Recent: append([]string(nil), m.recentScans...)}
+	start := m.config.BaseDir
+	if start == "" {
+		start, _ = os.Getwd()
+	}
+	m.browseDir(start)
+	m.state.ShowInfo = true
+}
+
+// browseDir lists the subdirectories of dir, hidden ones left out
+func (m *Model) browseDir(dir string) {
+	browser := m.state.DirBrowser
+	browser.Dir = dir
+	browser.Entries = nil
+	browser.Index = 0
+	browser.Err = ""
+	entries, err := os.ReadDir(dir)
+	if err != nil {
+		browser.Err = err.Error()
+	}
+	for _, entry := range entries {
+		name := entry.Name()
+		if !entry.IsDir() || strings.HasPrefix(name, ".") {
+			continue
+		}
+		_, err := os.Stat(filepath.Join(dir, name, ".git"))
+		browser.Entries = append(browser.Entries, state.DirEntry{Name: name, IsRepo: err == nil})
+	}
+	sort.Slice(browser.Entries, func(i, j int) bool {
+		return strings.ToLower(browser.Entries[i].Name) < strings.ToLower(browser.Entries[j].Name)
+	})
+	m.state.InfoContent = m.buildDirBrowserContent()
+}
+
+// navigateDirBrowser moves the cursor, stopping at either end
+func (m *Model) navigateDirBrowser(delta int) {
+	browser := m.state.DirBrowser
+	if len(browser.Entries) == 0 {
+		return
+	}
+	browser.Index = max(0, min(len(browser.Entries)-1, browser.Index+delta))
+	m.state.InfoContent = m.buildDirBrowserContent()
+}
+
+// descendDirBrowser opens the directory under the cursor
+func (m *Model) descendDirBrowser() {
+	browser := m.state.DirBrowser
+	if len(browser.Entries) == 0 {
+		return
+	}
+	m.browseDir(filepath.Join(browser.Dir, browser.Entries[browser.Index].Name))
+}
+
+// parentDirBrowser goes up a directory, with the cursor on the one it came from
+func (m *Model) parentDirBrowser() {
+	browser := m.state.DirBrowser
+	parent := filepath.Dir(browser.Dir)
+	if parent == browser.Dir {
+		return
+	}
+	from := filepath.Base(browser.Dir)
+	m.browseDir(parent)
+	for i, entry := range browser.Entries {
+		if entry.Name == from {
+			browser.Index = i
+		}
+	}
+	m.state.InfoContent = m.buildDirBrowserContent()
+}
+
+// scanDir scans a directory for repositories, adding the ones found to the list, and
+// remembers it for the quick pick and rescans
+func (m *Model) scanDir(dir string) tea.Cmd {
+	m.recentScans = prependUnique(m.recentScans, dir, maxRecentScans)
+	if dir != m.config.BaseDir {
+		m.scannedDirs = prependUnique(m.scannedDirs, dir, 0)
+	}
+	cmd := m.cmdExecutor.ExecuteFullScan(dir)
+	m.state.StatusMessage = "Scanning " + dir
+	return cmd
+}
+
+// scanRecent scans the directory of the quick pick's index
+func (m *Model) scanRecent(index int) tea.Cmd {
+	if index >= len(m.recentScans) {
+		m.state.StatusMessage = fmt.Sprintf("No recent directory %d", index+1)
+		return nil
+	}
+	return m.scanDir(m.recentScans[index])
+}
+
+// prependUnique puts value first in list, dropping its earlier place and, with limit
+// above 0, whatever goes past limit entries
+func prependUnique(list []string, value string, limit int) []string {
+	result := []string{value}
+	for _, item := range list {
+		if item != value {
+			result = append(result, item)
+		}
+	}
+	if limit > 0 && len(result) > limit {
+		result = result[:limit]
+	}
+	return result
+}
+
+// buildDirBrowserContent renders the subdirectories around the cursor, repositories marked
+func (m *Model) buildDirBrowserContent() string {
+	browser := m.state.DirBrowser
+	bold := lipgloss.NewStyle().Bold(true)
+	faint := lipgloss.NewStyle().Faint(true)
+
+	var b strings.Builder
+	b.WriteString(bold.Render("Scan a directory"))
+	b.WriteString("\n")
+	b.WriteString(browser.Dir)
+	b.WriteString("\n\n")
+	if len(browser.Recent) > 0 {
+		b.WriteString("Recent:\n")
+		for i, dir := range browser.Recent {
+			b.WriteString(fmt.Sprintf("  %d %s\n", i+1, dir))
+		}
+		b.WriteString("\n")
+	}
+	switch {
+	case browser.Err != "":
+		b.WriteString("Could not read it: " + browser.Err + "\n")
+	case len(browser.Entries) == 0:
+		b.WriteString(faint.Render("No subdirectories"))
+		b.WriteString("\n")
+	}
+
+	start := max(0, min(browser.Index-dirBrowserRows/2, len(browser.Entries)-dirBrowserRows))
+	end := min(len(browser.Entries), start+dirBrowserRows)
+	if start > 0 {
+		b.WriteString(faint.Render(fmt.Sprintf("  ↑ %d more", start)))
+		b.WriteString("\n")
+	}
+	for i := start; i < end; i++ {
+		entry := browser.Entries[i]
+		marker := "  "
+		if i == browser.Index {
+			marker = "› "
+		}
+		line := marker + entry.Name + string(filepath.Separator)
+		if entry.IsRepo {
+			line += faint.Render("  (repo)")
+		}
+		b.WriteString(line + "\n")
+	}
+	if end < len(browser.Entries) {
+		b.WriteString(faint.Render(fmt.Sprintf("  ↓ %d more", len(browser.Entries)-end)))
+		b.WriteString("\n")
+	}
+
+	b.WriteString("\n")
+	b.WriteString(faint.Render("j/k move • enter open • h/backspace up • s scan this directory • 1-5 scan a recent one • esc close"))
+	return b.String()
+}
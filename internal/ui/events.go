@@ -0,0 +1,175 @@
+package ui
+
+import (
+	"sync"
+	"time"
+
+	tea "github.com/charmbracelet/bubbletea/v2"
+
+	"github.com/darksworm/gitagrip/internal/eventbus"
+	inputtypes "github.com/darksworm/gitagrip/internal/ui/input/types"
+	"github.com/darksworm/gitagrip/internal/ui/logic"
+)
+
+// eventFrame is the shortest time between two batches of events, so that the UI updates at
+// most 30 times a second while a scan or bulk operation streams events in
+const eventFrame = time.Second / 30
+
+// EventQueue hands domain events to the UI in the order the bus delivers them, without
+// dropping any or blocking. The bus calls Push from its dispatcher one event at a time, so
+// a UI busy with a frame must not hold it up, and losing a credential prompt, a saved
+// config or a removed repository would leave the UI wrong.
+type EventQueue struct {
+	mu      sync.Mutex
+	pending []eventbus.DomainEvent
+	closed  bool
+	wake    chan struct{}
+	out     chan eventbus.DomainEvent
+}
+
+// NewEventQueue creates an empty queue; Run moves its events to Events
+func NewEventQueue() *EventQueue {
+	return &EventQueue{
+		wake: make(chan struct{}, 1),
+		out:  make(chan eventbus.DomainEvent, 100),
+	}
+}
+
+// Push queues an event; it never blocks. Events pushed after Close are dropped.
+func (q *EventQueue) Push(event eventbus.DomainEvent) {
+	q.mu.Lock()
+	defer q.mu.Unlock()
+	if q.closed {
+		return
+	}
+	q.pending = append(q.pending, event)
+	select {
+	case q.wake <- struct{}{}:
+	default:
+	}
+}
+
+// Close stops the queue: Run hands out what is pending, then closes Events
+func (q *EventQueue) Close() {
+	q.mu.Lock()
+	defer q.mu.Unlock()
+	if !q.closed {
+		q.closed = true
+		close(q.wake)
+	}
+}
+
+// Events returns the channel the queued events come out of, in order
+func (q *EventQueue) Events() <-chan eventbus.DomainEvent {
+	return q.out
+}
+
+// Run feeds the queued events to Events as fast as they are read, until Close
+func (q *EventQueue) Run() {
+	defer close(q.out)
+	for range q.wake {
+		for {
+			q.mu.Lock()
+			pending := q.pending
+			q.pending = nil
+			q.mu.Unlock()
+			if len(pending) == 0 {
+				break
+			}
+			for _, event := range pending {
+				q.out <- event
+			}
+		}
+	}
+}
+
+// ForwardEvents sends the events to the UI until the channel is closed, coalescing those
+// that arrive within a frame into one EventBatchMsg. A burst then costs one update and one
+// redraw per frame instead of one per event, which keeps the terminal responsive over slow
+// links; a lone event after a quiet spell goes out right away.
+func ForwardEvents(events <-chan eventbus.DomainEvent, send func(tea.Msg)) {
+	var lastSend time.Time
+	for event := range events {
+		batch := []eventbus.DomainEvent{event}
+
+		// Keep collecting until the frame since the last batch is over
+		wait := time.NewTimer(time.Until(lastSend.Add(eventFrame)))
+		for waiting := true; waiting; {
+			select {
+			case e, ok := <-events:
+				if !ok {
+					waiting = false
+					break
+				}
+				batch = append(batch, e)
+			case <-wait.C:
+				waiting = false
+			}
+		}
+		wait.Stop()
+		// Take along whatever is still pending
+		for len(events) > 0 {
+			if e, ok := <-events; ok {
+				batch = append(batch, e)
+			}
+		}
+
+		send(EventBatchMsg{Events: batch})
+		lastSend = time.Now()
+	}
+}
+
+// handleEvents applies domain events to the list, keeping the cursor on the same item, and
+// refreshes an open overview once for all of them
+func (m *Model) handleEvents(events []eventbus.DomainEvent) tea.Cmd {
+	anchors := m.captureListAnchors()
+	var cmds []tea.Cmd
+	resort := false
+	for _, event := range events {
+		cmds = append(cmds, m.eventHandler.HandleEvent(event))
+		switch e := event.(type) {
+		case eventbus.RepoDiscoveredEvent:
+			m.applyGroupRules(e.Repo.Path)
+		case eventbus.StatusUpdatedEvent:
+			m.applyGroupRules(e.RepoPath)
+			m.staleDetailsPane(e.RepoPath)
+			// Every sort but by name depends on the status
+			resort = resort || m.currentSort != logic.SortByName
+		case eventbus.OperationProgressEvent:
+			m.trackStatusChanges(e)
+		case eventbus.ConfigChangedEvent:
+			// Without autosave the changes wait for w
+			if !m.config.UISettings.AutosaveOnExit {
+				m.state.ConfigDirty = true
+			}
+		case eventbus.ScanCompletedEvent:
+			m.finishScan(e)
+		case eventbus.CredentialRequestedEvent:
+			m.state.CredentialRequests = append(m.state.CredentialRequests, e)
+		case eventbus.ConfigSavedEvent:
+			// The saver reports the outcome as a toast
+			if e.Err == nil {
+				m.state.ConfigDirty = false
+			}
+		}
+	}
+	if resort {
+		m.updateOrderedLists()
+	}
+	cmds = append(cmds, m.showCredentialPrompt())
+	m.restoreListAnchors(anchors)
+	m.resumeCursor()
+
+	// Keep an open dashboard, branch overview or group comparison current
+	switch m.inputHandler.CurrentMode() {
+	case inputtypes.ModeDashboard:
+		m.state.InfoContent = m.buildDashboardContent()
+	case inputtypes.ModeBranchOverview:
+		m.state.InfoContent = m.buildBranchOverviewContent()
+	case inputtypes.ModeGroupCompare:
+		m.state.InfoContent = m.buildGroupCompareContent()
+	case inputtypes.ModeStatusChanges:
+		m.state.InfoContent = m.buildStatusChangesContent()
+	}
+	return tea.Batch(cmds...)
+}
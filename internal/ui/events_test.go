@@ -0,0 +1,31 @@
+package ui
+
+import (
+	"testing"
+
+	"github.com/darksworm/gitagrip/internal/eventbus"
+)
+
+func TestEventQueueKeepsEveryEvent(t *testing.T) {
+	queue := NewEventQueue()
+
+	// Far more than the channel holds, pushed before anyone reads
+	const count = 1000
+	for i := 0; i < count; i++ {
+		queue.Push(eventbus.AppMessageEvent{Text: "scanning"})
+	}
+	queue.Push(eventbus.CredentialRequestedEvent{})
+	queue.Close()
+	go queue.Run()
+
+	var received []eventbus.DomainEvent
+	for event := range queue.Events() {
+		received = append(received, event)
+	}
+	if len(received) != count+1 {
+		t.Fatalf("received %d events, want %d", len(received), count+1)
+	}
+	if _, ok := received[count].(eventbus.CredentialRequestedEvent); !ok {
+		t.Fatalf("last event is %T, want the credential request", received[count])
+	}
+}
@@ -0,0 +1,120 @@
+package ui
+
+import (
+	"fmt"
+	"slices"
+	"sort"
+	"strings"
+
+	"github.com/charmbracelet/lipgloss/v2"
+
+	"github.com/darksworm/gitagrip/internal/domain"
+	"github.com/darksworm/gitagrip/internal/git"
+	"github.com/darksworm/gitagrip/internal/ui/state"
+)
+
+// fetchTagModes and fetchDepths are what t and d cycle through in the fetch options
+var (
+	fetchTagModes = []string{domain.FetchTagsAuto, domain.FetchTagsAll, domain.FetchTagsNone}
+	fetchDepths   = []int{0, 1, 10, 100}
+)
+
+// openFetchOptions shows the fetch options for what a fetch would apply to now, with the
+// remotes of those repositories to choose from
+func (m *Model) openFetchOptions() {
+	repoPaths, group := m.targetRepos()
+	popup := &state.FetchOptions{RepoPaths: repoPaths, Options: domain.FetchOptions{Tags: domain.FetchTagsAuto}}
+	switch {
+	case group != "":
+		popup.Scope = fmt.Sprintf("all repos in '%s'", group)
+	case len(repoPaths) == 1:
+		popup.Scope = m.compareName(repoPaths[0])
+	default:
+		popup.Scope = fmt.Sprintf("%d selected repos", len(repoPaths))
+	}
+
+	seen := make(map[string]bool)
+	for _, path := range repoPaths {
+		names, _ := m.gitOps.RemoteNames(path)
+		for _, name := range names {
+			if !seen[name] {
+				seen[name] = true
+				popup.Remotes = append(popup.Remotes, name)
+			}
+		}
+	}
+	sort.Strings(popup.Remotes)
+
+	m.state.FetchOptions = popup
+	m.state.ShowInfo = true
+	m.state.InfoContent = m.buildFetchOptionsContent()
+}
+
+// cycleFetchOption moves one of the fetch options to its next value, wrapping around
+func (m *Model) cycleFetchOption(option string) {
+	opts := &m.state.FetchOptions.Options
+	switch option {
+	case "remote":
+		// Every remote first, then each one on its own
+		choices := append([]string{""}, m.state.FetchOptions.Remotes...)
+		opts.Remote = choices[(slices.Index(choices, opts.Remote)+1)%len(choices)]
+	case "tags":
+		opts.Tags = fetchTagModes[(slices.Index(fetchTagModes, opts.Tags)+1)%len(fetchTagModes)]
+	case "depth":
+		opts.Depth = fetchDepths[(slices.Index(fetchDepths, opts.Depth)+1)%len(fetchDepths)]
+	}
+}
+
+// describeFetchOptions sums up fetch options in a few words, e.g. "origin, no tags, depth 1"
+func describeFetchOptions(opts domain.FetchOptions) string {
+	parts := []string{"all remotes"}
+	if opts.Remote != "" {
+		parts[0] = opts.Remote
+	}
+	switch opts.Tags {
+	case domain.FetchTagsAll:
+		parts = append(parts, "all tags")
+	case domain.FetchTagsNone:
+		parts = append(parts, "no tags")
+	}
+	if opts.Depth > 0 {
+		parts = append(parts, fmt.Sprintf("depth %d", opts.Depth))
+	}
+	return strings.Join(parts, ", ")
+}
+
+// buildFetchOptionsContent renders the fetch options and the git command they make
+func (m *Model) buildFetchOptionsContent() string {
+	popup := m.state.FetchOptions
+	if popup == nil {
+		return ""
+	}
+	bold := lipgloss.NewStyle().Bold(true)
+	faint := lipgloss.NewStyle().Faint(true)
+	opts := popup.Options
+
+	remote := "all remotes"
+	if opts.Remote != "" {
+		remote = opts.Remote
+	}
+	tags := opts.Tags
+	if tags == "" {
+		tags = domain.FetchTagsAuto
+	}
+	depth := "full history"
+	if opts.Depth > 0 {
+		depth = fmt.Sprintf("%d commits", opts.Depth)
+	}
+
+	var b strings.Builder
+	b.WriteString(bold.Render("Fetch " + popup.Scope))
+	b.WriteString("\n\n")
+	b.WriteString(fmt.Sprintf("r  remote  %s\n", remote))
+	b.WriteString(fmt.Sprintf("t  tags    %s\n", tags))
+	b.WriteString(fmt.Sprintf("d  depth   %s\n", depth))
+	b.WriteString("\n")
+	b.WriteString(faint.Render("git " + strings.Join(git.FetchArgs(opts), " ")))
+	b.WriteString("\n\n")
+	b.WriteString(faint.Render("r/t/d change • enter fetch • esc cancel"))
+	return b.String()
+}
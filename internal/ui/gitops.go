@@ -1,20 +1,30 @@
 package ui
 
 import (
+	"context"
+	"errors"
 	"fmt"
 	"io"
 	"os"
 	"os/exec"
+	"path/filepath"
 	"strings"
 	"time"
 
 	tea "github.com/charmbracelet/bubbletea/v2"
 	"github.com/charmbracelet/lipgloss/v2"
+
+	"github.com/darksworm/gitagrip/internal/devenv"
+	"github.com/darksworm/gitagrip/internal/domain"
+	"github.com/darksworm/gitagrip/internal/git"
+	"github.com/darksworm/gitagrip/internal/paths"
+	"github.com/darksworm/gitagrip/internal/ui/state"
 )
 
 // GitOps handles git operations like log and diff
 type GitOps struct {
-	program *tea.Program // reference to Bubble Tea program for terminal management
+	program   *tea.Program // reference to Bubble Tea program for terminal management
+	activator string       // environment activator for launched programs (see devenv)
 }
 
 // NewGitOps creates a new GitOps instance
@@ -27,6 +37,11 @@ func (g *GitOps) SetProgram(p *tea.Program) {
 	g.program = p
 }
 
+// SetEnvironmentActivator configures how launched programs get the repository's environment
+func (g *GitOps) SetEnvironmentActivator(activator string) {
+	g.activator = activator
+}
+
 // FetchGitLog fetches git log for a repository with branch/tag decorations
 func (g *GitOps) FetchGitLog(repoPath string) (string, error) {
 	// Run git log command with decorations for branch/tag info
@@ -81,6 +96,64 @@ func (g *GitOps) FetchGitLog(repoPath string) (string, error) {
 	return strings.Join(lines, "\n"), nil
 }
 
+// maxLogCommits is how many commits the commit log reads at most
+const maxLogCommits = 500
+
+// CommitLog lists the newest commits of a repository matching a filter, which is passed
+// on to git log
+func (g *GitOps) CommitLog(repoPath string, filter state.LogFilter) ([]state.Commit, error) {
+	limit := maxLogCommits
+	if filter.Limit > 0 {
+		limit = filter.Limit
+	}
+	args := []string{"log", fmt.Sprintf("-n%d", limit), "--format=%h%x00%an%x00%ar%x00%s%x00%D",
+		"--regexp-ignore-case", "--fixed-strings"}
+	if filter.AllBranches {
+		args = append(args, "--all")
+	}
+	if filter.Message != "" {
+		args = append(args, "--grep="+filter.Message)
+	}
+	if filter.Author != "" {
+		args = append(args, "--author="+filter.Author)
+	}
+	if filter.Since != "" {
+		args = append(args, "--since="+filter.Since)
+	}
+	if filter.Until != "" {
+		args = append(args, "--until="+filter.Until)
+	}
+	switch {
+	case filter.Range != "":
+		args = append(args, filter.Range)
+	case filter.NotOnRemotes:
+		args = append(args, "HEAD")
+	}
+	if filter.NotOnRemotes {
+		args = append(args, "--not", "--remotes")
+	}
+	cmd := exec.Command("git", args...)
+	cmd.Dir = repoPath
+
+	output, err := cmd.Output()
+	if err != nil {
+		var exitErr *exec.ExitError
+		if errors.As(err, &exitErr) && len(exitErr.Stderr) > 0 {
+			return nil, errors.New(strings.TrimSpace(string(exitErr.Stderr)))
+		}
+		return nil, err
+	}
+	var commits []state.Commit
+	for _, line := range strings.Split(strings.TrimSpace(string(output)), "\n") {
+		fields := strings.Split(line, "\x00")
+		if len(fields) != 5 {
+			continue
+		}
+		commits = append(commits, state.Commit{Hash: fields[0], Author: fields[1], Date: fields[2], Subject: fields[3], Refs: fields[4]})
+	}
+	return commits, nil
+}
+
 // FetchGitDiff fetches git diff for a repository
 func (g *GitOps) FetchGitDiff(repoPath string) (string, error) {
 	// Run git diff command to show uncommitted changes
@@ -102,6 +175,205 @@ func (g *GitOps) FetchGitDiff(repoPath string) (string, error) {
 	return string(output), nil
 }
 
+// CleanDryRun lists the files and directories git clean -fdx would remove
+func (g *GitOps) CleanDryRun(repoPath string) ([]string, error) {
+	cmd := exec.Command("git", "clean", "-fdxn")
+	cmd.Dir = repoPath
+
+	output, err := cmd.Output()
+	if err != nil {
+		return nil, err
+	}
+
+	var entries []string
+	for _, line := range strings.Split(string(output), "\n") {
+		if entry := strings.TrimPrefix(line, "Would remove "); entry != "" {
+			entries = append(entries, entry)
+		}
+	}
+	return entries, nil
+}
+
+// LatestTag returns the most recent tag reachable from HEAD ("" if there is none)
+func (g *GitOps) LatestTag(repoPath string) string {
+	cmd := exec.Command("git", "describe", "--tags", "--abbrev=0")
+	cmd.Dir = repoPath
+
+	output, err := cmd.Output()
+	if err != nil {
+		return ""
+	}
+	return strings.TrimSpace(string(output))
+}
+
+// RemoteNames lists the remotes configured in a repository
+func (g *GitOps) RemoteNames(repoPath string) ([]string, error) {
+	cmd := exec.Command("git", "remote")
+	cmd.Dir = repoPath
+
+	output, err := cmd.Output()
+	if err != nil {
+		return nil, err
+	}
+	return strings.Fields(string(output)), nil
+}
+
+// Remotes lists the remotes of a repository by name, with their fetch and push URLs
+func (g *GitOps) Remotes(repoPath string) ([]domain.Remote, error) {
+	cmd := exec.Command("git", "remote", "-v")
+	cmd.Dir = repoPath
+
+	output, err := cmd.Output()
+	if err != nil {
+		return nil, err
+	}
+	var remotes []domain.Remote
+	index := make(map[string]int)
+	for _, line := range strings.Split(strings.TrimSpace(string(output)), "\n") {
+		// origin	git@host:org/repo.git (fetch)
+		fields := strings.Fields(line)
+		if len(fields) != 3 {
+			continue
+		}
+		i, ok := index[fields[0]]
+		if !ok {
+			i = len(remotes)
+			index[fields[0]] = i
+			remotes = append(remotes, domain.Remote{Name: fields[0]})
+		}
+		if fields[2] == "(push)" {
+			remotes[i].PushURL = fields[1]
+		} else {
+			remotes[i].FetchURL = fields[1]
+		}
+	}
+	return remotes, nil
+}
+
+// StashCount returns how many entries the stash of a repository has
+func (g *GitOps) StashCount(repoPath string) (int, error) {
+	cmd := exec.Command("git", "stash", "list")
+	cmd.Dir = repoPath
+
+	output, err := cmd.Output()
+	if err != nil {
+		return 0, err
+	}
+	list := strings.TrimSpace(string(output))
+	if list == "" {
+		return 0, nil
+	}
+	return strings.Count(list, "\n") + 1, nil
+}
+
+// RecentTags returns the newest tags of a repository, at most limit of them
+func (g *GitOps) RecentTags(repoPath string, limit int) ([]string, error) {
+	cmd := exec.Command("git", "for-each-ref", "--sort=-creatordate", fmt.Sprintf("--count=%d", limit),
+		"--format=%(refname:short)", "refs/tags")
+	cmd.Dir = repoPath
+
+	output, err := cmd.Output()
+	if err != nil {
+		return nil, err
+	}
+	return strings.Fields(string(output)), nil
+}
+
+// Worktrees lists the worktrees of a repository other than repoPath itself: the linked
+// ones, and the main one when repoPath is linked
+func (g *GitOps) Worktrees(repoPath string) ([]state.Worktree, error) {
+	cmd := exec.Command("git", "worktree", "list", "--porcelain")
+	cmd.Dir = repoPath
+
+	output, err := cmd.Output()
+	if err != nil {
+		return nil, err
+	}
+	self := repoPath
+	if resolved, err := filepath.EvalSymlinks(repoPath); err == nil {
+		self = resolved
+	}
+	var worktrees []state.Worktree
+	for _, block := range strings.Split(strings.TrimSpace(string(output)), "\n\n") {
+		var wt state.Worktree
+		for _, line := range strings.Split(block, "\n") {
+			if path, ok := strings.CutPrefix(line, "worktree "); ok {
+				wt.Path = path
+			} else if branch, ok := strings.CutPrefix(line, "branch "); ok {
+				wt.Branch = strings.TrimPrefix(branch, "refs/heads/")
+			}
+		}
+		// git prints forward slashes on Windows
+		if wt.Path == "" || paths.Equal(wt.Path, self) || paths.Equal(wt.Path, repoPath) {
+			continue
+		}
+		worktrees = append(worktrees, wt)
+	}
+	return worktrees, nil
+}
+
+// CommitDays counts the commits reachable from HEAD per author day ("2006-01-02") since the given time
+func (g *GitOps) CommitDays(repoPath string, since time.Time) (map[string]int, error) {
+	cmd := exec.Command("git", "log", "--since="+since.Format(time.RFC3339), "--format=%ad", "--date=short")
+	cmd.Dir = repoPath
+
+	output, err := cmd.Output()
+	if err != nil {
+		return nil, err
+	}
+	days := make(map[string]int)
+	for _, day := range strings.Fields(string(output)) {
+		days[day]++
+	}
+	return days, nil
+}
+
+// PullOutlook tells whether pulling upstream into HEAD would fast-forward, need a merge or
+// conflict, from the merge base and a trial merge (git.TrialMerge). It goes by the last
+// fetch, and conflicts are a merge's estimate of what the rebase runs into.
+func (g *GitOps) PullOutlook(repoPath, upstream string) state.PullOutlook {
+	if upstream == "" {
+		return state.PullOutlook{Verdict: state.PullNoUpstream}
+	}
+	cmd := exec.Command("git", "rev-list", "--left-right", "--count", "HEAD..."+upstream)
+	cmd.Dir = repoPath
+	output, err := cmd.Output()
+	if err != nil {
+		return state.PullOutlook{Err: err.Error()}
+	}
+	var outlook state.PullOutlook
+	if _, err := fmt.Sscan(string(output), &outlook.Ahead, &outlook.Behind); err != nil {
+		return state.PullOutlook{Err: err.Error()}
+	}
+	switch {
+	case outlook.Behind == 0:
+		outlook.Verdict = state.PullUpToDate
+		return outlook
+	case outlook.Ahead == 0:
+		outlook.Verdict = state.PullFastForward
+		return outlook
+	}
+
+	// git older than 2.38 can't trial-merge: the merge is all that is known then
+	outlook.Verdict = state.PullMerge
+	if conflicts := git.TrialMerge(context.Background(), repoPath, upstream); len(conflicts) > 0 {
+		outlook.Verdict = state.PullConflict
+		outlook.Conflicts = conflicts
+	}
+	return outlook
+}
+
+// CreateBundle writes every ref of a repository to a bundle file
+func (g *GitOps) CreateBundle(repoPath, bundlePath string) error {
+	cmd := exec.Command("git", "bundle", "create", bundlePath, "--all")
+	cmd.Dir = repoPath
+
+	if output, err := cmd.CombinedOutput(); err != nil {
+		return fmt.Errorf("git bundle: %s", strings.TrimSpace(string(output)))
+	}
+	return nil
+}
+
 // HasUncommittedChanges checks if a repository has uncommitted changes
 func (g *GitOps) HasUncommittedChanges(repoPath string) (bool, error) {
 	cmd := exec.Command("git", "diff", "--quiet")
@@ -140,17 +412,30 @@ func (g *GitOps) IsLazygitAvailable() bool {
 	return err == nil
 }
 
-// RunLazygit launches the lazygit TUI for the given repository
-func (g *GitOps) RunLazygit(repoPath string) error {
-	if g.program == nil {
-		return fmt.Errorf("program not set")
-	}
-
+// RunLazygit launches the lazygit TUI for the given repository with extra env vars
+func (g *GitOps) RunLazygit(repoPath string, env []string) error {
 	// Determine binary
 	bin := os.Getenv("GITAGRIP_LAZYGIT_BIN")
 	if bin == "" {
 		bin = "lazygit"
 	}
+	return g.runInteractive(repoPath, env, bin)
+}
+
+// RunShell opens the user's shell in the given repository with extra env vars
+func (g *GitOps) RunShell(repoPath string, env []string) error {
+	shell := os.Getenv("SHELL")
+	if shell == "" {
+		shell = "/bin/sh"
+	}
+	return g.runInteractive(repoPath, env, shell)
+}
+
+// runInteractive hands the terminal to an external program running in repoPath
+func (g *GitOps) runInteractive(repoPath string, env []string, name string, args ...string) error {
+	if g.program == nil {
+		return fmt.Errorf("program not set")
+	}
 
 	// Release terminal control to run external program
 	if err := g.program.ReleaseTerminal(); err != nil {
@@ -163,9 +448,11 @@ func (g *GitOps) RunLazygit(repoPath string) error {
 		_ = g.program.RestoreTerminal()
 	}()
 
-	// Spawn lazygit with working directory set to repo
-	cmd := exec.Command(bin)
-	cmd.Dir = repoPath
+	// Spawn the program in the repo, through direnv/mise when the repo uses them
+	cmd := devenv.Command(repoPath, g.activator, name, args...)
+	if len(env) > 0 {
+		cmd.Env = append(os.Environ(), env...)
+	}
 	// Inherit stdio so it fully takes over the terminal
 	cmd.Stdout = os.Stdout
 	cmd.Stdin = os.Stdin
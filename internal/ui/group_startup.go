@@ -0,0 +1,15 @@
+package ui
+
+import (
+	"github.com/darksworm/gitagrip/internal/domain"
+)
+
+// collapseLazyGroups starts lazy groups ([group_startup] status_priority = "lazy")
+// collapsed, since their repositories' statuses aren't read until they come into view
+func (m *Model) collapseLazyGroups() {
+	for name, startup := range m.config.GroupStartup {
+		if _, exists := m.state.Groups[name]; exists && startup.StatusPriority == domain.StatusPriorityLazy {
+			m.state.ExpandedGroups[name] = false
+		}
+	}
+}
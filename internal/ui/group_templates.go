@@ -0,0 +1,138 @@
+package ui
+
+import (
+	"fmt"
+	"sort"
+
+	tea "github.com/charmbracelet/bubbletea/v2"
+
+	"github.com/darksworm/gitagrip/internal/config"
+	"github.com/darksworm/gitagrip/internal/eventbus"
+	inputtypes "github.com/darksworm/gitagrip/internal/ui/input/types"
+)
+
+// cycleGroupTemplate steps through "no template" and every group template while a new
+// group is being named
+func (m *Model) cycleGroupTemplate(reset bool) {
+	if reset {
+		m.groupTemplate = ""
+	} else {
+		names := m.config.GroupTemplateNames()
+		next := ""
+		for i, name := range names {
+			if name == m.groupTemplate {
+				if i+1 < len(names) {
+					next = names[i+1]
+				}
+				break
+			}
+		}
+		if m.groupTemplate == "" && len(names) > 0 {
+			next = names[0]
+		}
+		m.groupTemplate = next
+	}
+	m.viewModel.SetGroupTemplate(m.groupTemplate)
+}
+
+// createGroup creates a group holding the selected repositories. With a template picked,
+// ungrouped repositories matching its naming rules join too, and its labels, environment
+// and watches are recorded for the group.
+func (m *Model) createGroup(name string) tea.Cmd {
+	if name == "" {
+		return nil
+	}
+
+	var template config.GroupTemplate
+	templateName := m.groupTemplate
+	if templateName != "" {
+		template, _ = m.config.GroupTemplate(templateName)
+	}
+
+	fromGroups := make(map[string]string)
+	for repoPath := range m.store.GetSelectedRepositories() {
+		fromGroups[repoPath] = ""
+		for _, group := range m.state.Groups {
+			for _, path := range group.Repos {
+				if path == repoPath {
+					fromGroups[repoPath] = group.Name
+				}
+			}
+		}
+	}
+	if templateName != "" {
+		for _, repoPath := range m.getUngroupedRepos() {
+			if repo, ok := m.state.Repositories[repoPath]; ok && template.Matches(repo.Name) {
+				fromGroups[repoPath] = ""
+			}
+		}
+	}
+	repoPaths := make([]string, 0, len(fromGroups))
+	for repoPath := range fromGroups {
+		repoPaths = append(repoPaths, repoPath)
+	}
+	sort.Strings(repoPaths)
+
+	if _, exists := m.state.Groups[name]; !exists {
+		m.state.AddGroup(name, []string{})
+		if m.bus != nil {
+			m.bus.Publish(eventbus.GroupAddedEvent{Name: name})
+		}
+	}
+	for _, repoPath := range repoPaths {
+		m.state.MoveRepoToGroup(repoPath, fromGroups[repoPath], name)
+		if m.bus != nil {
+			m.bus.Publish(eventbus.RepoMovedEvent{
+				RepoPath:  repoPath,
+				FromGroup: fromGroups[repoPath],
+				ToGroup:   name,
+			})
+		}
+	}
+	m.state.ClearSelection()
+	m.updateOrderedLists()
+	if index := m.getCurrentIndexForGroup(name); index >= 0 {
+		m.state.SelectedIndex = index
+		m.ensureSelectedVisible()
+	}
+
+	if templateName == "" {
+		m.state.StatusMessage = fmt.Sprintf("Created group '%s' with %d repo(s)", name, len(repoPaths))
+	} else {
+		m.config.ApplyGroupTemplate(name, template, repoPaths)
+		m.refreshRepoTags(repoPaths)
+		m.state.StatusMessage = fmt.Sprintf("Created group '%s' from template '%s' with %d repo(s)", name, templateName, len(repoPaths))
+	}
+
+	// Save config
+	m.configChanged()
+
+	if template.Fetch && len(repoPaths) > 0 {
+		return m.cmdExecutor.ExecuteFetch(repoPaths)
+	}
+	return nil
+}
+
+// moveToGroup moves the selected repositories (or the one under the cursor) to a group,
+// creating it if needed. "Ungrouped" takes them out of their groups.
+func (m *Model) moveToGroup(name string) tea.Cmd {
+	if name == "" {
+		return nil
+	}
+
+	// The new group shifts the list, so keep the cursor on its repository
+	anchors := m.captureListAnchors()
+	if _, exists := m.state.Groups[name]; !exists && name != "Ungrouped" {
+		m.state.AddGroup(name, []string{})
+		if m.bus != nil {
+			m.bus.Publish(eventbus.GroupAddedEvent{Name: name})
+		}
+		m.updateOrderedLists()
+		m.restoreListAnchors(anchors)
+	}
+
+	cmd := m.processAction(inputtypes.MoveToGroupAction{GroupName: name})
+	m.updateOrderedLists()
+	m.restoreListAnchors(anchors)
+	return cmd
+}
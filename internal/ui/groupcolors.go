@@ -0,0 +1,67 @@
+package ui
+
+import (
+	"fmt"
+	"strings"
+
+	"github.com/charmbracelet/lipgloss/v2"
+
+	"github.com/darksworm/gitagrip/internal/ui/views"
+)
+
+// openGroupColor shows the palette to pick the group's color from
+func (m *Model) openGroupColor(group string) {
+	m.state.ColorGroup = group
+	m.state.ShowInfo = true
+	m.state.InfoContent = m.buildGroupColorContent()
+}
+
+// setGroupColor gives the group of the picker the index-th color of the palette (from 1),
+// or takes its color away for 0, and saves it in [group_colors]
+func (m *Model) setGroupColor(index int) {
+	group := m.state.ColorGroup
+	if group == "" || index < 0 || index > len(views.GroupColorPalette) {
+		return
+	}
+	if index == 0 {
+		if _, ok := m.config.GroupColors[group]; !ok {
+			return
+		}
+		delete(m.config.GroupColors, group)
+		m.state.StatusMessage = fmt.Sprintf("Removed the color of group '%s'", group)
+	} else {
+		color := views.GroupColorPalette[index-1]
+		if m.config.GroupColors == nil {
+			m.config.GroupColors = make(map[string]string)
+		}
+		m.config.GroupColors[group] = color.Name
+		m.state.StatusMessage = fmt.Sprintf("Colored group '%s' %s", group, color.Name)
+	}
+
+	// Save config
+	m.configChanged()
+}
+
+// buildGroupColorContent renders the palette, each color's name in that color, with the
+// group's current color marked
+func (m *Model) buildGroupColorContent() string {
+	group := m.state.ColorGroup
+	current, _ := views.ResolveGroupColors(map[string]string{group: m.config.GroupColors[group]})
+	faint := lipgloss.NewStyle().Faint(true)
+
+	var b strings.Builder
+	b.WriteString(lipgloss.NewStyle().Bold(true).Render("Color of " + group))
+	b.WriteString("\n\n")
+	for i, color := range views.GroupColorPalette {
+		marker := "  "
+		if current[group] == color.Color {
+			marker = "› "
+		}
+		swatch := lipgloss.NewStyle().Foreground(lipgloss.Color(color.Color)).Bold(true)
+		b.WriteString(fmt.Sprintf("%s%d %s\n", marker, i+1, swatch.Render(m.renderer.Glyphs().Gutter+" "+color.Name)))
+	}
+	b.WriteString(fmt.Sprintf("  0 %s\n", faint.Render("no color")))
+	b.WriteString("\n")
+	b.WriteString(faint.Render("1-8 pick • 0 clears • esc cancel"))
+	return b.String()
+}
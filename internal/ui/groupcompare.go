@@ -0,0 +1,154 @@
+package ui
+
+import (
+	"fmt"
+	"sort"
+	"strings"
+
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/charmbracelet/lipgloss/v2"
+
+	"github.com/darksworm/gitagrip/internal/domain"
+	"github.com/darksworm/gitagrip/internal/ui/views"
+)
+
+// compareColumnSeparator is drawn between the two groups of a comparison
+const compareColumnSeparator = " │ "
+
+// groupMembers returns the known repositories of a group ("Ungrouped" included), ordered by name
+func (m *Model) groupMembers(name string) []string {
+	var paths []string
+	if name == "Ungrouped" {
+		paths = m.getUngroupedRepos()
+	} else if group, ok := m.state.Groups[name]; ok {
+		paths = group.Repos
+	}
+
+	members := make([]string, 0, len(paths))
+	for _, path := range paths {
+		if _, ok := m.state.Repositories[path]; ok {
+			members = append(members, path)
+		}
+	}
+	sort.Slice(members, func(i, j int) bool {
+		return m.compareName(members[i]) < m.compareName(members[j])
+	})
+	return members
+}
+
+// compareName returns the name a repository is shown with
+func (m *Model) compareName(path string) string {
+	repo := m.state.Repositories[path]
+	if repo.DisplayName != "" {
+		return repo.DisplayName
+	}
+	return repo.Name
+}
+
+// loadCompareTags returns a command that looks up the latest tag of each repository
+func (m *Model) loadCompareTags(repoPaths []string) tea.Cmd {
+	return func() tea.Msg {
+		tags := make(map[string]string, len(repoPaths))
+		for _, path := range repoPaths {
+			tags[path] = m.gitOps.LatestTag(path)
+		}
+		return compareTagsMsg{tags: tags}
+	}
+}
+
+// buildGroupCompareContent renders the two compared groups side by side: their aggregate
+// status, then each member with its branch, latest tag and labels
+func (m *Model) buildGroupCompareContent() string {
+	bold := lipgloss.NewStyle().Bold(true)
+	faint := lipgloss.NewStyle().Faint(true)
+
+	// Leave room for the popup border and padding
+	width := max(20, (m.width-10-lipgloss.Width(compareColumnSeparator))/2)
+	left := m.compareColumn(m.state.ComparedGroups[0], width)
+	right := m.compareColumn(m.state.ComparedGroups[1], width)
+
+	var b strings.Builder
+	b.WriteString(bold.Render("Compare groups"))
+	b.WriteString("\n\n")
+	for i := 0; i < max(len(left), len(right)); i++ {
+		var l, r string
+		if i < len(left) {
+			l = left[i]
+		}
+		if i < len(right) {
+			r = right[i]
+		}
+		padding := strings.Repeat(" ", max(0, width-lipgloss.Width(l)))
+		b.WriteString(l + padding + faint.Render(compareColumnSeparator) + r + "\n")
+	}
+	b.WriteString("\n")
+	b.WriteString(faint.Render("esc close"))
+	return b.String()
+}
+
+// compareColumn renders one side of a group comparison as lines at most width cells wide
+func (m *Model) compareColumn(name string, width int) []string {
+	theme := m.renderer.Theme()
+	glyphs := m.renderer.Glyphs()
+	bold := lipgloss.NewStyle().Bold(true)
+	faint := lipgloss.NewStyle().Faint(true)
+
+	members := m.groupMembers(name)
+	counts := m.countRepos(members)
+	lines := []string{
+		bold.Render(truncateCells(name, width)),
+		truncateCells(fmt.Sprintf("%d repos", counts.Repos), width),
+		faint.Render(truncateCells(fmt.Sprintf("%d dirty, %d behind, %d ahead, %d errors",
+			counts.Dirty, counts.Behind, counts.Ahead, counts.Errored), width)),
+		"",
+	}
+
+	nameWidth := 0
+	for _, path := range members {
+		nameWidth = max(nameWidth, min(lipgloss.Width(m.compareName(path)), 20))
+	}
+	for _, path := range members {
+		repo := m.state.Repositories[path]
+		glyph, color := compareGlyph(repo, glyphs, theme)
+
+		fields := []string{fmt.Sprintf("%-*s", nameWidth, truncateCells(m.compareName(path), nameWidth)), repo.Status.Branch}
+		if tag := m.state.CompareTags[path]; tag != "" {
+			fields = append(fields, tag)
+		}
+		for _, label := range repo.Tags {
+			fields = append(fields, "#"+label)
+		}
+		row := truncateCells(strings.Join(fields, "  "), width-2)
+		lines = append(lines, lipgloss.NewStyle().Foreground(lipgloss.Color(color)).Render(glyph)+" "+row)
+	}
+	if len(members) == 0 {
+		lines = append(lines, faint.Render("no repositories"))
+	}
+	return lines
+}
+
+// compareGlyph picks the status glyph and its color for a repository row
+func compareGlyph(repo *domain.Repository, glyphs views.Glyphs, theme views.Theme) (string, string) {
+	switch {
+	case repo.Status.Error != "" || repo.HasError:
+		return glyphs.Error, theme.Error
+	case repo.Status.Branch == "" || repo.Status.Branch == "⋯":
+		return glyphs.Unknown, theme.Muted
+	case repo.Status.IsDirty:
+		return glyphs.Dirty, theme.Warning
+	default:
+		return glyphs.Clean, theme.Success
+	}
+}
+
+// truncateCells shortens plain text to at most width terminal cells, ending in "…" when cut
+func truncateCells(s string, width int) string {
+	if lipgloss.Width(s) <= width {
+		return s
+	}
+	runes := []rune(s)
+	for len(runes) > 0 && lipgloss.Width(string(runes))+1 > width {
+		runes = runes[:len(runes)-1]
+	}
+	return string(runes) + "…"
+}
@@ -3,15 +3,16 @@ package handlers
 import (
 	"fmt"
 	"log"
+	"sort"
 	"strings"
 	"time"
 
 	tea "github.com/charmbracelet/bubbletea/v2"
 
-	"gitagrip/internal/domain"
-	"gitagrip/internal/eventbus"
-	"gitagrip/internal/ui/logic"
-	"gitagrip/internal/ui/state"
+	"github.com/darksworm/gitagrip/internal/domain"
+	"github.com/darksworm/gitagrip/internal/eventbus"
+	"github.com/darksworm/gitagrip/internal/ui/logic"
+	"github.com/darksworm/gitagrip/internal/ui/state"
 )
 
 // TickMsg is a tick message for animations
@@ -37,17 +38,33 @@ func NewEventHandler(appState *state.AppState, updateOrderedLists func()) *Event
 func (h *EventHandler) HandleEvent(event eventbus.DomainEvent) tea.Cmd {
 	switch e := event.(type) {
 	case eventbus.RepoDiscoveredEvent:
-		// Add or update repository
+		// Add or update repository; one shown from the last run keeps its status and history
+		if known, ok := h.state.Repositories[e.Repo.Path]; ok && e.Repo.Status.Branch == "⋯" {
+			e.Repo.Status = known.Status
+			e.Repo.CommandLogs = known.CommandLogs
+		}
 		h.state.AddRepository(&e.Repo)
 		h.updateOrderedLists()
 		// Update searchFilter with new repositories
 		h.searchFilter = logic.NewSearchFilter(h.state.Repositories)
 
+	case eventbus.RepoRemovedEvent:
+		h.state.RemoveRepository(e.RepoPath)
+		h.updateOrderedLists()
+		h.searchFilter = logic.NewSearchFilter(h.state.Repositories)
+
 	case eventbus.StatusUpdatedEvent:
 		// Update repository status
 		if repo, ok := h.state.Repositories[e.RepoPath]; ok {
+			// A cached status must never replace a fresh one that arrived first
+			if e.Status.Stale && !repo.Status.Stale && repo.Status.Branch != "⋯" {
+				break
+			}
 			repo.Status = e.Status
 		}
+		if e.Status.Duration > 0 {
+			h.state.RecordRefreshDuration(e.Status.Duration)
+		}
 		// Clear operation states
 		h.state.ClearOperationState(e.RepoPath)
 
@@ -62,7 +79,11 @@ func (h *EventHandler) HandleEvent(event eventbus.DomainEvent) tea.Cmd {
 		} else {
 			log.Printf("Error: %s", e.Message)
 		}
-		// Note: log package is imported at top via fmt; use tea/log? We keep silent here.
+		// A toast says something went wrong without taking over the status bar
+		h.state.AddToast(domain.MessageError, e.Message, time.Now())
+
+	case eventbus.AppMessageEvent:
+		h.state.AddToast(e.Level, e.Text, time.Now())
 
 	case eventbus.GroupAddedEvent:
 		if _, exists := h.state.Groups[e.Name]; !exists {
@@ -93,6 +114,10 @@ func (h *EventHandler) HandleEvent(event eventbus.DomainEvent) tea.Cmd {
 		h.state.Scanning = false
 		h.state.StatusMessage = fmt.Sprintf("Scan complete. Found %d repositories.", e.ReposFound)
 
+	case eventbus.FetchRequestedEvent:
+		// Fetches started elsewhere, such as by groups that fetch on start
+		h.state.SetFetching(e.RepoPaths, true)
+
 	case eventbus.FetchCompletedEvent:
 		// Clear fetching state for this repo
 		h.state.SetFetching([]string{e.RepoPath}, false)
@@ -118,17 +143,67 @@ func (h *EventHandler) HandleEvent(event eventbus.DomainEvent) tea.Cmd {
 			log.Printf("Pull failed for %s: %v", e.RepoPath, e.Error)
 		}
 
+	case eventbus.PushCompletedEvent:
+		run := h.state.Push
+		if run == nil || run.Pending == 0 {
+			break
+		}
+		if !e.Success {
+			reason := e.Reason
+			if reason == "" && e.Error != nil {
+				reason = e.Error.Error()
+			}
+			run.Rejections[e.RepoPath] = reason
+			log.Printf("Push failed for %s: %v", e.RepoPath, e.Error)
+		}
+		run.Pending--
+		if run.Pending == 0 {
+			h.state.StatusMessage = h.pushSummary(run)
+		}
+
+	case eventbus.WatchUpdatedEvent:
+		// Only announce commits that weren't already pending
+		before := h.state.WatchedCommitCount(e.RepoPath)
+		h.state.SetWatchedChange(e.RepoPath, e.Branch, e.Commits)
+		if after := h.state.WatchedCommitCount(e.RepoPath); after > before {
+			name := e.RepoPath
+			if repo, ok := h.state.Repositories[e.RepoPath]; ok {
+				name = repo.Name
+			}
+			h.state.StatusMessage = fmt.Sprintf("%d new commits on %s in %s", len(e.Commits), e.Branch, name)
+		}
+
+	case eventbus.OperationProgressEvent:
+		switch {
+		case e.Cancelled:
+			delete(h.state.BulkProgress, e.Operation)
+			// Queued repositories never started; drop their spinners
+			if e.Operation == "fetch" {
+				h.state.SetFetching(e.Skipped, false)
+			} else if e.Operation == "pull" {
+				h.state.SetPulling(e.Skipped, false)
+			} else if e.Operation == "push" && h.state.Push != nil {
+				// Skipped pushes never report back
+				h.state.Push.Pending = max(0, h.state.Push.Pending-len(e.Skipped))
+			}
+			h.state.StatusMessage = fmt.Sprintf("Cancelled %s: %d of %d repos skipped", e.Operation, len(e.Skipped), e.Total)
+		case e.Done >= e.Total:
+			delete(h.state.BulkProgress, e.Operation)
+		default:
+			h.state.BulkProgress[e.Operation] = state.BulkProgress{Done: e.Done, Total: e.Total}
+		}
+
 	case eventbus.CommandExecutedEvent:
 		// Store command log in the repository
 		if repo, ok := h.state.Repositories[e.RepoPath]; ok {
 			// Add the command log
 			log := domain.CommandLog{
-				Timestamp: time.Now().Format("2006-01-02 15:04:05"),
-				Command:   e.Command,
-				Success:   e.Success,
-				Output:    e.Output,
-				Error:     e.Error,
-				Duration:  e.Duration,
+				Time:     time.Now(),
+				Command:  e.Command,
+				Success:  e.Success,
+				Output:   e.Output,
+				Error:    e.Error,
+				Duration: e.Duration,
 			}
 			repo.CommandLogs = append(repo.CommandLogs, log)
 
@@ -140,14 +215,16 @@ func (h *EventHandler) HandleEvent(event eventbus.DomainEvent) tea.Cmd {
 			// Update error state
 			if !e.Success {
 				repo.HasError = true
-				// Use the actual git output as the error message if available
-				if e.Output != "" {
+				// Prefer what to do about it, then the actual git output
+				if e.Reason != "" {
+					repo.LastError = e.Reason
+				} else if e.Output != "" {
 					repo.LastError = strings.TrimSpace(e.Output)
 				} else {
 					repo.LastError = e.Error
 				}
-			} else if e.Command == "fetch" || e.Command == "pull" || e.Command == "checkout" || e.Command == "checkout -b" {
-				// Clear error state on successful fetch/pull
+			} else if e.Command == "fetch" || e.Command == "pull" || e.Command == "push" || e.Command == "checkout" || e.Command == "checkout -b" {
+				// Clear error state on successful fetch/pull/push
 				repo.HasError = false
 				repo.LastError = ""
 			}
@@ -166,3 +243,25 @@ func (h *EventHandler) GetSearchFilter() *logic.SearchFilter {
 func (h *EventHandler) UpdateSearchFilter() {
 	h.searchFilter = logic.NewSearchFilter(h.state.Repositories)
 }
+
+// pushSummary reports a finished push: how many repositories went through, and each one
+// that didn't with why
+func (h *EventHandler) pushSummary(run *state.PushRun) string {
+	if len(run.Rejections) == 0 {
+		return fmt.Sprintf("Pushed %d of %d repos", run.Total, run.Total)
+	}
+	paths := make([]string, 0, len(run.Rejections))
+	for path := range run.Rejections {
+		paths = append(paths, path)
+	}
+	sort.Strings(paths)
+	failures := make([]string, 0, len(paths))
+	for _, path := range paths {
+		name := path
+		if repo, ok := h.state.Repositories[path]; ok {
+			name = repo.Name
+		}
+		failures = append(failures, fmt.Sprintf("%s %s", name, run.Rejections[path]))
+	}
+	return fmt.Sprintf("Pushed %d of %d repos · %s", run.Total-len(paths), run.Total, strings.Join(failures, "; "))
+}
@@ -0,0 +1,178 @@
+package ui
+
+import (
+	"context"
+	"fmt"
+	"sort"
+	"strings"
+
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/charmbracelet/lipgloss/v2"
+
+	"github.com/darksworm/gitagrip/internal/health"
+	"github.com/darksworm/gitagrip/internal/ui/state"
+	"github.com/darksworm/gitagrip/internal/ui/views"
+)
+
+// openHealth checks the selected repositories, or all of them, in the background
+func (m *Model) openHealth() tea.Cmd {
+	var paths []string
+	if m.store.GetSelectionCount() > 0 {
+		for path := range m.store.GetSelectedRepositories() {
+			paths = append(paths, path)
+		}
+	} else {
+		for path := range m.state.Repositories {
+			paths = append(paths, path)
+		}
+	}
+	sort.Slice(paths, func(i, j int) bool {
+		return m.compareName(paths[i]) < m.compareName(paths[j])
+	})
+
+	m.state.Health = &state.Health{Repos: paths, Reports: make(map[string]health.Report), Loading: true}
+	m.state.ShowInfo = true
+	m.state.InfoContent = m.buildHealthContent()
+	return checkHealth(paths, health.Options{})
+}
+
+// checkHealth returns a command that checks repositories one after the other
+func checkHealth(paths []string, opts health.Options) tea.Cmd {
+	return func() tea.Msg {
+		reports := make([]health.Report, 0, len(paths))
+		for _, path := range paths {
+			reports = append(reports, health.Check(context.Background(), path, opts))
+		}
+		return healthMsg{reports: reports}
+	}
+}
+
+// finishHealth stores reports that arrived, unless the view was closed meanwhile
+func (m *Model) finishHealth(msg healthMsg) {
+	view := m.state.Health
+	if view == nil {
+		return
+	}
+	for _, report := range msg.reports {
+		view.Reports[report.Repo] = report
+	}
+	view.Loading = false
+	if msg.err != nil {
+		m.state.StatusMessage = fmt.Sprintf("Error: %v", msg.err)
+	}
+	view.Index = min(view.Index, max(len(m.unhealthyRepos())-1, 0))
+	m.state.InfoContent = m.buildHealthContent()
+}
+
+// unhealthyRepos returns the checked repositories that have findings, in list order
+func (m *Model) unhealthyRepos() []string {
+	var paths []string
+	for _, path := range m.state.Health.Repos {
+		if len(m.state.Health.Reports[path].Findings) > 0 {
+			paths = append(paths, path)
+		}
+	}
+	return paths
+}
+
+// healthCursorRepo returns the repository with findings under the cursor ("" when none)
+func (m *Model) healthCursorRepo() string {
+	paths := m.unhealthyRepos()
+	if m.state.Health.Index >= len(paths) {
+		return ""
+	}
+	return paths[m.state.Health.Index]
+}
+
+// navigateHealth moves the cursor between the repositories with findings
+func (m *Model) navigateHealth(delta int) {
+	view := m.state.Health
+	if count := len(m.unhealthyRepos()); count > 0 {
+		view.Index = max(0, min(view.Index+delta, count-1))
+	}
+	m.state.InfoContent = m.buildHealthContent()
+}
+
+// fsckHealthRepo checks the repository under the cursor again, with git fsck
+func (m *Model) fsckHealthRepo() tea.Cmd {
+	repoPath := m.healthCursorRepo()
+	if repoPath == "" || m.state.Health.Loading {
+		return nil
+	}
+	m.state.Health.Loading = true
+	m.state.InfoContent = m.buildHealthContent()
+	return checkHealth([]string{repoPath}, health.Options{Fsck: true})
+}
+
+// removeHealthLocks removes the leftover lock files of the repository under the cursor and
+// checks it again
+func (m *Model) removeHealthLocks() tea.Cmd {
+	repoPath := m.healthCursorRepo()
+	if repoPath == "" || m.state.Health.Loading {
+		return nil
+	}
+	report := m.state.Health.Reports[repoPath]
+	if len(report.Locks) == 0 {
+		m.state.StatusMessage = "No leftover lock files in " + m.compareName(repoPath)
+		return nil
+	}
+	m.state.Health.Loading = true
+	m.state.InfoContent = m.buildHealthContent()
+	return func() tea.Msg {
+		err := health.RemoveLocks(report)
+		recheck := health.Check(context.Background(), repoPath, health.Options{Fsck: report.Fsck})
+		return healthMsg{reports: []health.Report{recheck}, err: err}
+	}
+}
+
+// buildHealthContent renders the findings of each repository with their suggested fixes
+func (m *Model) buildHealthContent() string {
+	view := m.state.Health
+	if view == nil {
+		return ""
+	}
+	bold := lipgloss.NewStyle().Bold(true)
+	faint := lipgloss.NewStyle().Faint(true)
+
+	var b strings.Builder
+	b.WriteString(bold.Render(fmt.Sprintf("Health of %d repos", len(view.Repos))))
+	b.WriteString("\n\n")
+	if view.Loading {
+		b.WriteString(faint.Render("Checking...") + "\n\n")
+	}
+
+	var size int64
+	for _, report := range view.Reports {
+		size += report.SizeBytes
+	}
+	unhealthy := m.unhealthyRepos()
+	if len(view.Reports) > 0 {
+		b.WriteString(fmt.Sprintf("%d of %d repos have findings · %s of objects\n", len(unhealthy), len(view.Reports), views.FormatBytes(size)))
+	}
+
+	for i, path := range unhealthy {
+		report := view.Reports[path]
+		marker := "  "
+		if i == view.Index {
+			marker = "› "
+		}
+		name := m.compareName(path)
+		if report.Fsck {
+			name += faint.Render("  (fsck ran)")
+		}
+		b.WriteString("\n" + marker + bold.Render(name) + "\n")
+		for _, finding := range report.Findings {
+			b.WriteString(fmt.Sprintf("    %-4s  %s\n", strings.ToUpper(finding.Level), finding.Detail))
+			if finding.Fix != "" {
+				b.WriteString(faint.Render("          → "+finding.Fix) + "\n")
+			}
+		}
+	}
+	if !view.Loading && len(view.Reports) > 0 && len(unhealthy) == 0 {
+		b.WriteString("\nNo findings: no leftover locks, large .git dirs or shallow clones\n")
+	}
+
+	b.WriteString("\n")
+	b.WriteString(faint.Render("j/k move • f fsck • x remove locks • esc close"))
+	return b.String()
+}
@@ -0,0 +1,193 @@
+package ui
+
+import (
+	"fmt"
+	"sort"
+	"strings"
+	"time"
+
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/charmbracelet/lipgloss/v2"
+
+	"github.com/darksworm/gitagrip/internal/ui/state"
+)
+
+// heatmapWeeks is how many week columns the heatmap shows, the current one included
+const heatmapWeeks = 53
+
+// heatmapStart returns the Monday the heatmap starts on, so that it ends with the current week
+func heatmapStart(now time.Time) time.Time {
+	today := time.Date(now.Year(), now.Month(), now.Day(), 0, 0, 0, 0, now.Location())
+	weekday := (int(today.Weekday()) + 6) % 7 // Monday = 0
+	return today.AddDate(0, 0, -weekday-(heatmapWeeks-1)*7)
+}
+
+// heatmapRepos returns the repositories a heatmap counts: a group's members, or all of them
+func (m *Model) heatmapRepos(group string) []string {
+	if group != "" {
+		return m.groupMembers(group)
+	}
+	paths := make([]string, 0, len(m.state.Repositories))
+	for path := range m.state.Repositories {
+		paths = append(paths, path)
+	}
+	sort.Slice(paths, func(i, j int) bool {
+		return m.compareName(paths[i]) < m.compareName(paths[j])
+	})
+	return paths
+}
+
+// openHeatmap shows the heatmap of a group ("" for every repository) and loads the activity
+// of repositories that haven't been counted yet or got new commits since
+func (m *Model) openHeatmap(group string) tea.Cmd {
+	m.state.Heatmap = &state.Heatmap{Group: group}
+
+	stale := make(map[string]time.Time) // repo path -> last commit
+	for _, path := range m.heatmapRepos(group) {
+		lastCommit := m.state.Repositories[path].Status.LastCommitTime
+		if activity, ok := m.state.Activity[path]; !ok || !activity.LastCommit.Equal(lastCommit) {
+			stale[path] = lastCommit
+		}
+	}
+
+	m.state.ShowInfo = true
+	if len(stale) == 0 {
+		m.state.InfoContent = m.buildHeatmapContent()
+		return nil
+	}
+	m.state.Heatmap.Loading = true
+	m.state.InfoContent = m.buildHeatmapContent()
+	return m.loadActivity(stale)
+}
+
+// loadActivity returns a command that counts the commits per day of each repository in the
+// background. Repositories whose log can't be read count as having no commits.
+func (m *Model) loadActivity(lastCommits map[string]time.Time) tea.Cmd {
+	since := heatmapStart(time.Now())
+	return func() tea.Msg {
+		activity := make(map[string]state.RepoActivity, len(lastCommits))
+		for path, lastCommit := range lastCommits {
+			days, _ := m.gitOps.CommitDays(path, since)
+			activity[path] = state.RepoActivity{LastCommit: lastCommit, Days: days}
+		}
+		return activityMsg{activity: activity}
+	}
+}
+
+// buildHeatmapContent renders the commits per day over the past year as a calendar, one
+// column per week, followed by the totals and the busiest repositories
+func (m *Model) buildHeatmapContent() string {
+	heatmap := m.state.Heatmap
+	if heatmap == nil {
+		return ""
+	}
+	bold := lipgloss.NewStyle().Bold(true)
+	faint := lipgloss.NewStyle().Faint(true)
+
+	scope := "all repositories"
+	if heatmap.Group != "" {
+		scope = heatmap.Group
+	}
+
+	// Add up the days of every counted repository in scope
+	repos := m.heatmapRepos(heatmap.Group)
+	days := make(map[string]int)
+	perRepo := make(map[string]int)
+	for _, path := range repos {
+		for day, count := range m.state.Activity[path].Days {
+			days[day] += count
+			perRepo[path] += count
+		}
+	}
+
+	start := heatmapStart(time.Now())
+	today := time.Now().Format("2006-01-02")
+	total, highest, busiestDay := 0, 0, ""
+	for day, count := range days {
+		if day < start.Format("2006-01-02") {
+			continue
+		}
+		total += count
+		if count > highest || (count == highest && day > busiestDay) {
+			highest, busiestDay = count, day
+		}
+	}
+
+	var b strings.Builder
+	b.WriteString(bold.Render("Commit activity: " + scope))
+	b.WriteString("\n\n")
+
+	// Month names above the first week of each month
+	months := []rune(strings.Repeat(" ", heatmapWeeks+3))
+	for week := 0; week < heatmapWeeks; week++ {
+		monday := start.AddDate(0, 0, week*7)
+		if monday.Day() <= 7 && (week == 0 || months[week-1] == ' ') {
+			copy(months[week:], []rune(monday.Format("Jan")))
+		}
+	}
+	b.WriteString("    " + strings.TrimRight(string(months), " ") + "\n")
+
+	weekdays := []string{"Mon", "", "Wed", "", "Fri", "", ""}
+	for weekday, label := range weekdays {
+		b.WriteString(fmt.Sprintf("%-4s", label))
+		for week := 0; week < heatmapWeeks; week++ {
+			day := start.AddDate(0, 0, week*7+weekday).Format("2006-01-02")
+			if day > today {
+				break
+			}
+			b.WriteString(m.renderer.HeatCell(days[day], highest))
+		}
+		b.WriteString("\n")
+	}
+	b.WriteString("    " + faint.Render("less ") + m.renderer.HeatCell(0, 4))
+	for level := 1; level <= 4; level++ {
+		b.WriteString(m.renderer.HeatCell(level, 4))
+	}
+	b.WriteString(faint.Render(" more") + "\n\n")
+
+	if heatmap.Loading {
+		b.WriteString(faint.Render("Counting commits...") + "\n")
+	}
+	active := 0
+	for _, count := range perRepo {
+		if count > 0 {
+			active++
+		}
+	}
+	b.WriteString(fmt.Sprintf("%d commits in the past year, in %d of %d repos\n", total, active, len(repos)))
+	if busiestDay != "" {
+		b.WriteString(fmt.Sprintf("Busiest day: %s (%d commits)\n", busiestDay, highest))
+	}
+
+	// Where the effort went
+	busiest := make([]string, 0, active)
+	for path, count := range perRepo {
+		if count > 0 {
+			busiest = append(busiest, path)
+		}
+	}
+	sort.Slice(busiest, func(i, j int) bool {
+		if perRepo[busiest[i]] != perRepo[busiest[j]] {
+			return perRepo[busiest[i]] > perRepo[busiest[j]]
+		}
+		return m.compareName(busiest[i]) < m.compareName(busiest[j])
+	})
+	if len(busiest) > 5 {
+		busiest = busiest[:5]
+	}
+	if len(busiest) > 0 {
+		b.WriteString("\n" + bold.Render("Busiest repos") + "\n")
+		nameWidth := 0
+		for _, path := range busiest {
+			nameWidth = max(nameWidth, min(lipgloss.Width(m.compareName(path)), 30))
+		}
+		for _, path := range busiest {
+			name := truncateCells(m.compareName(path), nameWidth)
+			b.WriteString(fmt.Sprintf("%-*s  %d\n", nameWidth, name, perRepo[path]))
+		}
+	}
+
+	b.WriteString("\n")
+	b.WriteString(faint.Render("esc close"))
+	return b.String()
+}
@@ -1,6 +1,172 @@
 package ui
 
+import (
+	"fmt"
+	"strings"
+
+	"github.com/charmbracelet/lipgloss/v2"
+
+	inputtypes "github.com/darksworm/gitagrip/internal/ui/input/types"
+)
+
 // helpPagerMsg contains the result of a help pager command
 type helpPagerMsg struct {
 	err error
 }
+
+// helpSection is a titled block of actions in the help popup
+type helpSection struct {
+	title   string
+	actions []string
+}
+
+// helpSections orders the remappable actions in the help popup. Actions missing here are
+// listed under "Other", so a new binding always shows up.
+var helpSections = []helpSection{
+	{"Navigation", []string{"up", "down", "collapse", "expand", "bottom", "open", "toggle_group"}},
+	{"Selection", []string{"select", "select_group", "select_all", "clear_selection", "visual"}},
+	{"Repositories", []string{"refresh", "scan", "clone_org", "rescan", "retry", "fetch", "fetch_options", "pull", "push", "unpushed", "log", "git_log", "diff", "info", "logs", "shell",
+		"switch_branch", "new_branch", "tags", "clean", "migrate_remotes", "forget", "archive", "undo"}},
+	{"Groups", []string{"new_group", "move", "rename_group", "group_color", "delete_group", "move_group_up", "move_group_down", "compare_groups", "save"}},
+	{"Search & Filter", []string{"search", "next_match", "filter", "show_all", "only_dirty", "only_unsynced", "only_errors", "sort", "cycle_sort"}},
+	{"Overviews", []string{"dashboard", "branches", "watches", "changes", "heatmap", "health"}},
+}
+
+// helpContextActions lists the actions that apply to what is under the cursor, with a
+// title naming it
+func (m *Model) helpContextActions() (string, []string) {
+	if count := m.store.GetSelectionCount(); count > 0 {
+		return fmt.Sprintf("With %d selected", count),
+			[]string{"fetch", "fetch_options", "pull", "push", "move", "tags", "switch_branch", "new_branch", "forget", "clear_selection"}
+	}
+	if group := m.getGroupAtIndex(m.state.SelectedIndex); group != "" {
+		return "On group " + group,
+			[]string{"toggle_group", "refresh", "fetch", "pull", "select_group", "rename_group", "group_color", "delete_group", "move_group_up", "move_group_down", "compare_groups", "push", "unpushed", "heatmap"}
+	}
+	if path := m.getRepoPathAtIndex(m.state.SelectedIndex); path != "" {
+		return "On " + m.compareName(path),
+			[]string{"open", "diff", "log", "git_log", "unpushed", "info", "shell", "refresh", "fetch", "pull", "archive"}
+	}
+	return "", nil
+}
+
+// buildHelpContent renders the key bindings in effect, remappings and the quick bar
+// included: first what applies under the cursor, then every action by topic. Sections
+// flow into as many columns as the terminal's height needs.
+func (m *Model) buildHelpContent() string {
+	bold := lipgloss.NewStyle().Bold(true)
+	faint := lipgloss.NewStyle().Faint(true)
+
+	descriptions := make(map[string]string, len(inputtypes.DefaultKeyBindings))
+	for _, b := range inputtypes.DefaultKeyBindings {
+		descriptions[b.Action] = b.Description
+	}
+
+	type entry struct{ keys, desc string }
+	var blocks [][]entry
+	var titles []string
+	addBlock := func(title string, entries []entry) {
+		if len(entries) > 0 {
+			titles = append(titles, title)
+			blocks = append(blocks, entries)
+		}
+	}
+	bound := func(actions []string) []entry {
+		var entries []entry
+		for _, action := range actions {
+			if keys := m.keys.Keys(action); len(keys) > 0 {
+				entries = append(entries, entry{strings.Join(keys, " "), descriptions[action]})
+			}
+		}
+		return entries
+	}
+
+	if title, actions := m.helpContextActions(); title != "" {
+		addBlock(title, bound(actions))
+	}
+	listed := make(map[string]bool)
+	for _, section := range helpSections {
+		entries := bound(section.actions)
+		if section.title == "Navigation" {
+			// Built-in keys that can't be remapped
+			entries = append(entries,
+				entry{"arrows", "Move, collapse/expand groups"},
+				entry{"gg", "Go to top"},
+				entry{"pgup pgdown", "Page up/down"})
+		}
+		addBlock(section.title, entries)
+		for _, action := range section.actions {
+			listed[action] = true
+		}
+	}
+	var others []string
+	for _, b := range inputtypes.DefaultKeyBindings {
+		if !listed[b.Action] {
+			others = append(others, b.Action)
+		}
+	}
+	other := bound(others)
+	for _, slot := range m.keys.QuickBar() {
+		other = append(other, entry{slot.Key, "Quick bar: " + descriptions[slot.Action]})
+	}
+	other = append(other,
+		entry{"esc", "Cancel the running fetch/pull/push"},
+		entry{"ctrl+c", "Quit right away"})
+	addBlock("Other", other)
+
+	// Flow the blocks into columns no taller than the popup can be
+	maxRows := max(10, m.height-10)
+	var columns [][]int // block indices per column
+	rows := 0
+	for i, entries := range blocks {
+		height := 1 + len(entries)
+		if len(columns) == 0 || rows+1+height > maxRows {
+			columns = append(columns, nil)
+			rows = -1
+		}
+		columns[len(columns)-1] = append(columns[len(columns)-1], i)
+		rows += 1 + height
+	}
+
+	gap := "   "
+	columnWidth := 0
+	if m.width > 0 {
+		columnWidth = (m.width - 12 - len(gap)*(len(columns)-1)) / len(columns)
+	}
+	fit := func(line string) string {
+		if columnWidth > 10 {
+			return truncateCells(line, columnWidth)
+		}
+		return line
+	}
+
+	parts := make([]string, 0, 2*len(columns))
+	for c, column := range columns {
+		var lines []string
+		for n, i := range column {
+			if n > 0 {
+				lines = append(lines, "")
+			}
+			keyWidth := 0
+			for _, e := range blocks[i] {
+				keyWidth = max(keyWidth, lipgloss.Width(e.keys))
+			}
+			lines = append(lines, bold.Render(fit(titles[i])))
+			for _, e := range blocks[i] {
+				lines = append(lines, fit(fmt.Sprintf("  %-*s  %s", keyWidth, e.keys, e.desc)))
+			}
+		}
+		if c > 0 {
+			parts = append(parts, gap)
+		}
+		parts = append(parts, strings.Join(lines, "\n"))
+	}
+
+	var b strings.Builder
+	b.WriteString(bold.Render("Keys"))
+	b.WriteString("\n\n")
+	b.WriteString(lipgloss.JoinHorizontal(lipgloss.Top, parts...))
+	b.WriteString("\n\n")
+	b.WriteString(faint.Render("Remap keys in the [keys] section of .gitagrip.toml • esc close"))
+	return b.String()
+}
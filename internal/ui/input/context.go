@@ -1,42 +1,69 @@
 package input
 
 import (
-	"gitagrip/internal/ui/logic"
-	"gitagrip/internal/ui/repositories"
-	"gitagrip/internal/ui/state"
+	"github.com/darksworm/gitagrip/internal/ui/logic"
+	"github.com/darksworm/gitagrip/internal/ui/repositories"
+	"github.com/darksworm/gitagrip/internal/ui/state"
 )
 
 // ModelContext implements the Context interface for the input handler
 type ModelContext struct {
-	State       *state.AppState
-	Store       repositories.RepositoryStore
-	Navigator   *logic.Navigator
-	CurrentSort logic.SortMode
+	State          *state.AppState
+	Store          repositories.RepositoryStore
+	Navigator      *logic.Navigator
+	CurrentSort    logic.SortMode
+	UngroupedRepos []string // repositories outside any group, in display order
 }
 
-// CurrentIndex returns the current selected index
-func (c *ModelContext) CurrentIndex() int {
-	return c.State.SelectedIndex
+// hiddenGroupName is the group kept at the end of the list without a gap after it
+const hiddenGroupName = "_Hidden"
+
+// listItem is one row of the repository list: a group header, a repository or the
+// gap between two groups (neither set)
+type listItem struct {
+	groupName string
+	repoPath  string
 }
 
-// TotalItems returns the total number of visible items
-func (c *ModelContext) TotalItems() int {
-	totalRepos := 0
-	for _, group := range c.State.OrderedGroups {
-		totalRepos++ // Count the group itself
-		if c.State.ExpandedGroups[group] {
-			groupData := c.State.Groups[group]
-			if groupData != nil {
-				totalRepos += len(groupData.Repos)
+// items lays out the list the way the renderer draws it: each group's header, its
+// repositories when expanded and a gap unless it is the hidden group at the end, then
+// the ungrouped repositories without a header
+func (c *ModelContext) items() []listItem {
+	var items []listItem
+	for i, groupName := range c.State.OrderedGroups {
+		items = append(items, listItem{groupName: groupName})
+		if group := c.State.Groups[groupName]; group != nil && c.State.ExpandedGroups[groupName] {
+			for _, repoPath := range group.Repos {
+				items = append(items, listItem{repoPath: repoPath})
 			}
 		}
+		if groupName != hiddenGroupName || i != len(c.State.OrderedGroups)-1 {
+			items = append(items, listItem{})
+		}
+	}
+	for _, repoPath := range c.UngroupedRepos {
+		items = append(items, listItem{repoPath: repoPath})
 	}
-	// Add ungrouped repos if they exist
-	ungrouped := c.State.Groups["Ungrouped"]
-	if ungrouped != nil && len(ungrouped.Repos) > 0 && c.State.ExpandedGroups["Ungrouped"] {
-		totalRepos += len(ungrouped.Repos)
+	return items
+}
+
+// itemAt returns the list row at an index (the zero item when out of range)
+func (c *ModelContext) itemAt(index int) listItem {
+	items := c.items()
+	if index < 0 || index >= len(items) {
+		return listItem{}
 	}
-	return totalRepos
+	return items[index]
+}
+
+// CurrentIndex returns the current selected index
+func (c *ModelContext) CurrentIndex() int {
+	return c.State.SelectedIndex
+}
+
+// TotalItems returns the number of rows in the list, gaps included
+func (c *ModelContext) TotalItems() int {
+	return len(c.items())
 }
 
 // HasSelection returns true if any items are selected
@@ -54,136 +81,47 @@ func (c *ModelContext) CurrentRepositoryPath() string {
 	return c.GetRepoPathAtIndex(c.CurrentIndex())
 }
 
-// GetRepoPathAtIndex returns the repo path at the given index
+// GetRepoPathAtIndex returns the repo path at the given index ("" on headers and gaps)
 func (c *ModelContext) GetRepoPathAtIndex(index int) string {
-	currentIdx := 0
-
-	// Check grouped repos
-	for i, groupName := range c.State.OrderedGroups {
-		if currentIdx == index {
-			// On a group header
-			return ""
-		}
-		currentIdx++
-
-		if c.State.ExpandedGroups[groupName] {
-			group := c.State.Groups[groupName]
-			if group != nil {
-				for _, repoPath := range group.Repos {
-					if currentIdx == index {
-						return repoPath
-					}
-					currentIdx++
-				}
-			}
-		}
-
-		// Add gap after group unless it's the hidden group at the end
-		isLastGroup := i == len(c.State.OrderedGroups)-1
-		isHiddenGroup := groupName == "_Hidden"
-		if !isHiddenGroup || !isLastGroup {
-			if currentIdx == index {
-				// On a gap
-				return ""
-			}
-			currentIdx++ // Gap after group
-		}
-	}
-
-	// Check ungrouped repos
-	ungrouped := c.State.Groups["Ungrouped"]
-	if ungrouped != nil && len(ungrouped.Repos) > 0 && c.State.ExpandedGroups["Ungrouped"] {
-		if currentIdx == index {
-			// On ungrouped header
-			return ""
-		}
-		currentIdx++
-
-		for _, repoPath := range ungrouped.Repos {
-			if currentIdx == index {
-				return repoPath
-			}
-			currentIdx++
-		}
-	}
-
-	return ""
+	return c.itemAt(index).repoPath
 }
 
 // IsOnGroup returns true if the current selection is on a group header
 func (c *ModelContext) IsOnGroup() bool {
-	currentIdx := 0
-	targetIdx := c.CurrentIndex()
-
-	// Check grouped repos
-	for i, groupName := range c.State.OrderedGroups {
-		if currentIdx == targetIdx {
-			return true // On a group header
-		}
-		currentIdx++
-
-		if c.State.ExpandedGroups[groupName] {
-			group := c.State.Groups[groupName]
-			if group != nil {
-				currentIdx += len(group.Repos)
-			}
-		}
-
-		// Add gap after group unless it's the hidden group at the end
-		isLastGroup := i == len(c.State.OrderedGroups)-1
-		isHiddenGroup := groupName == "_Hidden"
-		if !isHiddenGroup || !isLastGroup {
-			currentIdx++ // Gap after group
-		}
-	}
-
-	// Check ungrouped header
-	ungrouped := c.State.Groups["Ungrouped"]
-	if ungrouped != nil && len(ungrouped.Repos) > 0 && c.State.ExpandedGroups["Ungrouped"] {
-		if currentIdx == targetIdx {
-			return true // On ungrouped header
-		}
-	}
-
-	return false
+	return c.itemAt(c.CurrentIndex()).groupName != ""
 }
 
-// CurrentGroupName returns the name of the group at the current index
+// CurrentGroupName returns the name of the group whose header is at the current index
 func (c *ModelContext) CurrentGroupName() string {
-	currentIdx := 0
-	targetIdx := c.CurrentIndex()
+	return c.itemAt(c.CurrentIndex()).groupName
+}
 
-	// Check grouped repos
-	for i, groupName := range c.State.OrderedGroups {
-		if currentIdx == targetIdx {
-			return groupName // On a group header
-		}
-		currentIdx++
+// CurrentRepositoryTags returns the tags of the repository at the current index
+func (c *ModelContext) CurrentRepositoryTags() []string {
+	if repo, ok := c.State.Repositories[c.CurrentRepositoryPath()]; ok {
+		return repo.Tags
+	}
+	return nil
+}
 
-		if c.State.ExpandedGroups[groupName] {
-			group := c.State.Groups[groupName]
-			if group != nil {
-				currentIdx += len(group.Repos)
-			}
-		}
+// HasBulkOperation returns true while a bulk fetch, pull or push is running
+func (c *ModelContext) HasBulkOperation() bool {
+	return len(c.State.BulkProgress) > 0
+}
 
-		// Add gap after group unless it's the hidden group at the end
-		isLastGroup := i == len(c.State.OrderedGroups)-1
-		isHiddenGroup := groupName == "_Hidden"
-		if !isHiddenGroup || !isLastGroup {
-			currentIdx++ // Gap after group
-		}
-	}
+// CompareGroup returns the group marked for a side-by-side comparison ("" if none)
+func (c *ModelContext) CompareGroup() string {
+	return c.State.CompareGroup
+}
 
-	// Check ungrouped header
-	ungrouped := c.State.Groups["Ungrouped"]
-	if ungrouped != nil && len(ungrouped.Repos) > 0 && c.State.ExpandedGroups["Ungrouped"] {
-		if currentIdx == targetIdx {
-			return "Ungrouped"
-		}
-	}
+// CredentialSecret reports whether the credential prompt shown asks for a secret
+func (c *ModelContext) CredentialSecret() bool {
+	return len(c.State.CredentialRequests) > 0 && c.State.CredentialRequests[0].Secret
+}
 
-	return ""
+// CleanReady reports whether the git clean dry run has listed something to remove
+func (c *ModelContext) CleanReady() bool {
+	return len(c.State.CleanTargets) > 0
 }
 
 // SearchQuery returns the current search query
@@ -191,16 +129,7 @@ func (c *ModelContext) SearchQuery() string {
 	return c.State.SearchQuery
 }
 
-// GetCurrentSort returns the current sort mode
+// GetCurrentSort returns the current sort mode by its config name
 func (c *ModelContext) GetCurrentSort() string {
-	switch c.CurrentSort {
-	case logic.SortByName:
-		return "name"
-	case logic.SortByStatus:
-		return "status"
-	case logic.SortByBranch:
-		return "branch"
-	default:
-		return "name"
-	}
+	return c.CurrentSort.String()
 }
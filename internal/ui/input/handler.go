@@ -1,10 +1,10 @@
 package input
 
 import (
-	"gitagrip/internal/ui/input/modes"
-	"gitagrip/internal/ui/input/types"
 	"github.com/charmbracelet/bubbles/v2/textinput"
 	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/darksworm/gitagrip/internal/ui/input/modes"
+	"github.com/darksworm/gitagrip/internal/ui/input/types"
 )
 
 type Handler struct {
@@ -14,6 +14,11 @@ type Handler struct {
 }
 
 func New() *Handler {
+	return NewWithKeyMap(types.DefaultKeyMap())
+}
+
+// NewWithKeyMap creates a handler whose normal mode uses the given key bindings
+func NewWithKeyMap(keys *types.KeyMap) *Handler {
 	ti := textinput.New()
 
 	h := &Handler{
@@ -23,17 +28,46 @@ func New() *Handler {
 	}
 
 	// Register all mode handlers
-	h.modes[types.ModeNormal] = modes.NewNormalMode()
+	normal := modes.NewNormalModeWithKeys(keys)
+	h.modes[types.ModeNormal] = normal
 	h.modes[types.ModeSearch] = modes.NewSearchMode(h.textInput)
 	h.modes[types.ModeFilter] = modes.NewFilterMode(h.textInput)
 	h.modes[types.ModeNewGroup] = modes.NewNewGroupMode(h.textInput)
 	h.modes[types.ModeMoveToGroup] = modes.NewMoveToGroupMode(h.textInput)
-	h.modes[types.ModeDeleteConfirm] = modes.NewConfirmMode()
+	h.modes[types.ModeConfirm] = modes.NewConfirmMode()
 	h.modes[types.ModeSort] = modes.NewSortSelectMode()
 	// Branch operations (text modes)
 	h.modes[types.ModeNewBranch] = modes.NewNewBranchMode(h.textInput)
 	h.modes[types.ModeSwitchBranch] = modes.NewSwitchBranchMode(h.textInput)
 	h.modes[types.ModeRenameGroup] = modes.NewRenameGroupMode(h.textInput)
+	h.modes[types.ModeRemoteMigration] = modes.NewRemoteMigrationMode()
+	h.modes[types.ModeCleanConfirm] = modes.NewCleanConfirmMode()
+	h.modes[types.ModeCancelConfirm] = modes.NewCancelConfirmMode()
+	h.modes[types.ModeVisual] = modes.NewVisualMode(keys)
+	h.modes[types.ModeWatchedChanges] = modes.NewWatchedChangesMode()
+	h.modes[types.ModeEditTags] = modes.NewEditTagsMode(h.textInput)
+	h.modes[types.ModeDashboard] = modes.NewDashboardMode()
+	h.modes[types.ModeBranchOverview] = modes.NewBranchOverviewMode()
+	h.modes[types.ModeGroupCompare] = modes.NewGroupCompareMode()
+	h.modes[types.ModeBulkLabels] = modes.NewBulkLabelsMode()
+	h.modes[types.ModeStatusChanges] = modes.NewStatusChangesMode()
+	h.modes[types.ModeHeatmap] = modes.NewHeatmapMode()
+	h.modes[types.ModeArchiveConfirm] = modes.NewArchiveConfirmMode()
+	h.modes[types.ModeHelp] = modes.NewHelpMode(keys)
+	h.modes[types.ModeCredential] = modes.NewCredentialMode(h.textInput)
+	h.modes[types.ModeFetchOptions] = modes.NewFetchOptionsMode()
+	h.modes[types.ModePushConfirm] = modes.NewPushConfirmMode()
+	h.modes[types.ModeHealth] = modes.NewHealthMode()
+	h.modes[types.ModePalette] = modes.NewPaletteMode(h.textInput, keys, normal)
+	h.modes[types.ModeCommitLog] = modes.NewCommitLogMode()
+	h.modes[types.ModeUnpushed] = modes.NewUnpushedMode()
+	h.modes[types.ModePullPreview] = modes.NewPullPreviewMode()
+	h.modes[types.ModeProfiles] = modes.NewProfilesMode()
+	h.modes[types.ModeDirBrowser] = modes.NewDirBrowserMode()
+	h.modes[types.ModeDebugLog] = modes.NewDebugLogMode()
+	h.modes[types.ModeGroupColor] = modes.NewGroupColorMode()
+	h.modes[types.ModeCloneOrg] = modes.NewCloneOrgMode(h.textInput)
+	h.modes[types.ModeCloneOrgPick] = modes.NewCloneOrgPickMode()
 
 	return h
 }
@@ -57,29 +91,10 @@ func (h *Handler) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Action,
 	// Handle mode changes
 	for _, action := range actions {
 		if changeMode, ok := action.(types.ChangeModeAction); ok {
-			// Exit current mode
-			if h.modes[h.currentMode] != nil {
-				exitActions := h.modes[h.currentMode].Exit(ctx)
-				allActions = append(allActions, exitActions...)
-			}
-
-			// Change mode
-			oldMode := h.currentMode
-			h.currentMode = changeMode.Mode
-
-			// Enter new mode
-			if h.modes[h.currentMode] != nil {
-				enterActions := h.modes[h.currentMode].Enter(ctx)
-				allActions = append(allActions, enterActions...)
-			}
-
-			// Handle text input focus
-			if h.isTextMode(h.currentMode) {
-				h.textInput.Reset()
-				h.textInput.Focus()
-				cmd = textinput.Blink
-			} else if h.isTextMode(oldMode) {
-				h.textInput.Blur()
+			modeActions, modeCmd := h.EnterMode(changeMode.Mode, ctx)
+			allActions = append(allActions, modeActions...)
+			if modeCmd != nil {
+				cmd = modeCmd
 			}
 		} else {
 			allActions = append(allActions, action)
@@ -98,6 +113,37 @@ func (h *Handler) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Action,
 	return allActions, cmd
 }
 
+// EnterMode leaves the current mode for another one, also without a key press (e.g. to
+// show a prompt for something running in the background)
+func (h *Handler) EnterMode(mode types.Mode, ctx types.Context) ([]types.Action, tea.Cmd) {
+	var actions []types.Action
+	var cmd tea.Cmd
+
+	// Exit current mode
+	if h.modes[h.currentMode] != nil {
+		actions = append(actions, h.modes[h.currentMode].Exit(ctx)...)
+	}
+
+	// Change mode
+	oldMode := h.currentMode
+	h.currentMode = mode
+
+	// Handle text input focus before entering, so modes can pre-fill the input
+	if h.isTextMode(h.currentMode) {
+		h.textInput.Reset()
+		h.textInput.Focus()
+		cmd = textinput.Blink
+	} else if h.isTextMode(oldMode) {
+		h.textInput.Blur()
+	}
+
+	// Enter new mode
+	if h.modes[h.currentMode] != nil {
+		actions = append(actions, h.modes[h.currentMode].Enter(ctx)...)
+	}
+	return actions, cmd
+}
+
 func (h *Handler) CurrentMode() types.Mode {
 	return h.currentMode
 }
@@ -115,7 +161,8 @@ func (h *Handler) RegisterMode(mode types.Mode, handler types.ModeHandler) {
 
 func (h *Handler) isTextMode(mode types.Mode) bool {
 	switch mode {
-	case types.ModeSearch, types.ModeFilter, types.ModeNewGroup, types.ModeMoveToGroup, types.ModeSort, types.ModeRenameGroup:
+	case types.ModeSearch, types.ModeFilter, types.ModeNewGroup, types.ModeMoveToGroup, types.ModeSort, types.ModeRenameGroup, types.ModeEditTags,
+		types.ModeNewBranch, types.ModeSwitchBranch, types.ModeCredential, types.ModePalette, types.ModeCloneOrg:
 		return true
 	default:
 		return false
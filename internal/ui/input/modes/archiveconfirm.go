@@ -0,0 +1,44 @@
+package modes
+
+import (
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/darksworm/gitagrip/internal/ui/input/types"
+)
+
+// ArchiveConfirmMode previews archiving the current repository and waits for confirmation
+type ArchiveConfirmMode struct{}
+
+func NewArchiveConfirmMode() *ArchiveConfirmMode {
+	return &ArchiveConfirmMode{}
+}
+
+func (m *ArchiveConfirmMode) Name() string {
+	return "archive-confirm"
+}
+
+func (m *ArchiveConfirmMode) Enter(ctx types.Context) []types.Action {
+	return []types.Action{types.PromptArchiveAction{RepoPath: ctx.CurrentRepositoryPath()}}
+}
+
+func (m *ArchiveConfirmMode) Exit(ctx types.Context) []types.Action {
+	return []types.Action{types.PromptArchiveAction{}}
+}
+
+func (m *ArchiveConfirmMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Action, bool) {
+	switch msg.String() {
+	case "ctrl+c":
+		return []types.Action{types.QuitAction{Force: true}}, true
+	case "y", "Y":
+		// Confirm archive
+		return []types.Action{
+			types.ArchiveRepoAction{},
+			types.ChangeModeAction{Mode: types.ModeNormal},
+		}, true
+	case "n", "N", "esc", "q":
+		// Cancel archive
+		return []types.Action{types.ChangeModeAction{Mode: types.ModeNormal}}, true
+	}
+
+	// Swallow everything else while the preview is open
+	return nil, true
+}
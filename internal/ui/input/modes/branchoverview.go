@@ -0,0 +1,37 @@
+package modes
+
+import (
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/darksworm/gitagrip/internal/ui/input/types"
+)
+
+// BranchOverviewMode lists every checked out branch with its repositories until it is closed
+type BranchOverviewMode struct{}
+
+func NewBranchOverviewMode() *BranchOverviewMode {
+	return &BranchOverviewMode{}
+}
+
+func (m *BranchOverviewMode) Name() string {
+	return "branch-overview"
+}
+
+func (m *BranchOverviewMode) Enter(ctx types.Context) []types.Action {
+	return []types.Action{types.OpenBranchOverviewAction{}}
+}
+
+func (m *BranchOverviewMode) Exit(ctx types.Context) []types.Action {
+	return []types.Action{types.CloseBranchOverviewAction{}}
+}
+
+func (m *BranchOverviewMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Action, bool) {
+	switch msg.String() {
+	case "ctrl+c":
+		return []types.Action{types.QuitAction{Force: true}}, true
+	case "esc", "q", "B", "enter":
+		return []types.Action{types.ChangeModeAction{Mode: types.ModeNormal}}, true
+	}
+
+	// Swallow everything else while the overview is open
+	return nil, true
+}
@@ -0,0 +1,55 @@
+package modes
+
+import (
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/darksworm/gitagrip/internal/ui/input/types"
+)
+
+// BulkLabelsMode toggles labels across every selected repository
+type BulkLabelsMode struct{}
+
+func NewBulkLabelsMode() *BulkLabelsMode {
+	return &BulkLabelsMode{}
+}
+
+func (m *BulkLabelsMode) Name() string {
+	return "bulk-labels"
+}
+
+func (m *BulkLabelsMode) Enter(ctx types.Context) []types.Action {
+	return []types.Action{types.OpenBulkLabelsAction{}}
+}
+
+func (m *BulkLabelsMode) Exit(ctx types.Context) []types.Action {
+	return []types.Action{types.CloseBulkLabelsAction{}}
+}
+
+func (m *BulkLabelsMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Action, bool) {
+	switch msg.String() {
+	case "ctrl+c":
+		return []types.Action{types.QuitAction{Force: true}}, true
+	case "esc", "q":
+		// Discard pending changes
+		return []types.Action{types.ChangeModeAction{Mode: types.ModeNormal}}, true
+	case "up", "k":
+		return []types.Action{types.BulkLabelsNavigateAction{Delta: -1}}, true
+	case "down", "j":
+		return []types.Action{types.BulkLabelsNavigateAction{Delta: 1}}, true
+	case " ", "x":
+		return []types.Action{types.ToggleBulkLabelAction{}}, true
+	case "enter":
+		return []types.Action{
+			types.ApplyBulkLabelsAction{},
+			types.ChangeModeAction{Mode: types.ModeNormal},
+		}, true
+	case "n":
+		// Apply what is pending, then prompt for new labels to add to the selection
+		return []types.Action{
+			types.ApplyBulkLabelsAction{},
+			types.ChangeModeAction{Mode: types.ModeEditTags},
+		}, true
+	}
+
+	// Swallow everything else while the editor is open
+	return nil, true
+}
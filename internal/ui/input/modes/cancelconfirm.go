@@ -0,0 +1,43 @@
+package modes
+
+import (
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/darksworm/gitagrip/internal/ui/input/types"
+)
+
+// CancelConfirmMode asks whether to cancel the queued part of running bulk operations
+type CancelConfirmMode struct{}
+
+func NewCancelConfirmMode() *CancelConfirmMode {
+	return &CancelConfirmMode{}
+}
+
+func (m *CancelConfirmMode) Name() string {
+	return "cancel-confirm"
+}
+
+func (m *CancelConfirmMode) Enter(ctx types.Context) []types.Action {
+	return nil
+}
+
+func (m *CancelConfirmMode) Exit(ctx types.Context) []types.Action {
+	return nil
+}
+
+func (m *CancelConfirmMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Action, bool) {
+	switch msg.String() {
+	case "ctrl+c":
+		return []types.Action{types.QuitAction{Force: true}}, true
+	case "y", "Y":
+		// Skip repositories that have not started; in-flight ones finish normally
+		return []types.Action{
+			types.CancelOperationsAction{},
+			types.ChangeModeAction{Mode: types.ModeNormal},
+		}, true
+	case "n", "N", "esc":
+		// Keep going
+		return []types.Action{types.ChangeModeAction{Mode: types.ModeNormal}}, true
+	}
+
+	return nil, false
+}
@@ -0,0 +1,47 @@
+package modes
+
+import (
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/darksworm/gitagrip/internal/ui/input/types"
+)
+
+// CleanConfirmMode shows a git clean dry run and waits for confirmation
+type CleanConfirmMode struct{}
+
+func NewCleanConfirmMode() *CleanConfirmMode {
+	return &CleanConfirmMode{}
+}
+
+func (m *CleanConfirmMode) Name() string {
+	return "clean-confirm"
+}
+
+func (m *CleanConfirmMode) Enter(ctx types.Context) []types.Action {
+	return []types.Action{types.CleanDryRunAction{}}
+}
+
+func (m *CleanConfirmMode) Exit(ctx types.Context) []types.Action {
+	return []types.Action{types.CloseCleanPreviewAction{}}
+}
+
+func (m *CleanConfirmMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Action, bool) {
+	switch msg.String() {
+	case "ctrl+c":
+		return []types.Action{types.QuitAction{Force: true}}, true
+	case "y", "Y":
+		// Confirm clean once the dry run has listed what would be removed
+		if !ctx.CleanReady() {
+			return nil, true
+		}
+		return []types.Action{
+			types.CleanReposAction{},
+			types.ChangeModeAction{Mode: types.ModeNormal},
+		}, true
+	case "n", "N", "esc", "q":
+		// Cancel clean
+		return []types.Action{types.ChangeModeAction{Mode: types.ModeNormal}}, true
+	}
+
+	// Swallow everything else while the preview is open
+	return nil, true
+}
@@ -0,0 +1,90 @@
+package modes
+
+import (
+	"github.com/charmbracelet/bubbles/v2/textinput"
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/darksworm/gitagrip/internal/ui/input/types"
+)
+
+// CloneOrgMode asks for the organization to clone repositories from, e.g. "myorg" (on
+// GitHub) or "gitlab:mygroup"; its repositories are then listed to pick from
+type CloneOrgMode struct {
+	textInputMode TextInputMode
+}
+
+func NewCloneOrgMode(ti *textinput.Model) *CloneOrgMode {
+	return &CloneOrgMode{
+		textInputMode: NewTextInputMode(types.ModeCloneOrg, "clone-org", "Clone from organization: ", ti),
+	}
+}
+
+func (m *CloneOrgMode) Name() string {
+	return m.textInputMode.Name()
+}
+
+func (m *CloneOrgMode) Enter(ctx types.Context) []types.Action {
+	return m.textInputMode.Enter(ctx)
+}
+
+func (m *CloneOrgMode) Exit(ctx types.Context) []types.Action {
+	return m.textInputMode.Exit(ctx)
+}
+
+func (m *CloneOrgMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Action, bool) {
+	// Enter lists the organization's repositories rather than going back to the list
+	if msg.String() == "enter" && m.textInputMode.textInput != nil {
+		return []types.Action{
+			types.SubmitTextAction{Text: m.textInputMode.textInput.Value(), Mode: types.ModeCloneOrg},
+			types.ChangeModeAction{Mode: types.ModeCloneOrgPick},
+		}, true
+	}
+	return m.textInputMode.HandleKey(msg, ctx)
+}
+
+// CloneOrgPickMode lists an organization's repositories to pick the ones to clone
+type CloneOrgPickMode struct{}
+
+func NewCloneOrgPickMode() *CloneOrgPickMode {
+	return &CloneOrgPickMode{}
+}
+
+func (m *CloneOrgPickMode) Name() string {
+	return "clone-org-pick"
+}
+
+func (m *CloneOrgPickMode) Enter(ctx types.Context) []types.Action {
+	return []types.Action{types.OpenCloneOrgAction{}}
+}
+
+func (m *CloneOrgPickMode) Exit(ctx types.Context) []types.Action {
+	return []types.Action{types.CloseCloneOrgAction{}}
+}
+
+func (m *CloneOrgPickMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Action, bool) {
+	switch msg.String() {
+	case "ctrl+c":
+		return []types.Action{types.QuitAction{Force: true}}, true
+	case "esc", "q":
+		return []types.Action{types.ChangeModeAction{Mode: types.ModeNormal}}, true
+	case "up", "k":
+		return []types.Action{types.CloneOrgNavigateAction{Delta: -1}}, true
+	case "down", "j":
+		return []types.Action{types.CloneOrgNavigateAction{Delta: 1}}, true
+	case "pgup", "ctrl+u":
+		return []types.Action{types.CloneOrgNavigateAction{Delta: -10}}, true
+	case "pgdown", "ctrl+d":
+		return []types.Action{types.CloneOrgNavigateAction{Delta: 10}}, true
+	case " ":
+		return []types.Action{types.CloneOrgToggleAction{}}, true
+	case "a":
+		return []types.Action{types.CloneOrgToggleAction{All: true}}, true
+	case "enter":
+		return []types.Action{
+			types.CloneOrgAction{},
+			types.ChangeModeAction{Mode: types.ModeNormal},
+		}, true
+	}
+
+	// Swallow everything else while the list is open
+	return nil, true
+}
@@ -0,0 +1,103 @@
+package modes
+
+import (
+	"github.com/charmbracelet/bubbles/v2/textinput"
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/darksworm/gitagrip/internal/ui/input/types"
+)
+
+// CommitLogMode browses the commit log of a repository. '/' types a filter into its own
+// text input, so the view stays open while filtering.
+type CommitLogMode struct {
+	filter  textinput.Model
+	applied string // filter the log shows
+	editing bool
+}
+
+func NewCommitLogMode() *CommitLogMode {
+	filter := textinput.New()
+	filter.Prompt = ""
+	filter.Placeholder = "words in the message, author:name, since:2024-01-01, until:yesterday"
+	return &CommitLogMode{filter: filter}
+}
+
+func (m *CommitLogMode) Name() string {
+	return "commit-log"
+}
+
+func (m *CommitLogMode) Enter(ctx types.Context) []types.Action {
+	m.filter.Reset()
+	m.applied = ""
+	m.editing = false
+	return []types.Action{types.OpenCommitLogAction{}}
+}
+
+func (m *CommitLogMode) Exit(ctx types.Context) []types.Action {
+	m.filter.Blur()
+	m.editing = false
+	return []types.Action{types.CloseCommitLogAction{}}
+}
+
+func (m *CommitLogMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Action, bool) {
+	if m.editing {
+		return m.handleFilterKey(msg)
+	}
+
+	switch msg.String() {
+	case "ctrl+c":
+		return []types.Action{types.QuitAction{Force: true}}, true
+	case "esc", "q", "H":
+		return []types.Action{types.ChangeModeAction{Mode: types.ModeNormal}}, true
+	case "up", "k":
+		return []types.Action{types.CommitLogScrollAction{Delta: -1}}, true
+	case "down", "j":
+		return []types.Action{types.CommitLogScrollAction{Delta: 1}}, true
+	case "pgup", "ctrl+u":
+		return []types.Action{types.CommitLogScrollAction{Delta: -10}}, true
+	case "pgdown", "ctrl+d":
+		return []types.Action{types.CommitLogScrollAction{Delta: 10}}, true
+	case "/":
+		// Edit the filter in place, starting from the one applied
+		m.editing = true
+		m.filter.Focus()
+		m.filter.CursorEnd()
+		return []types.Action{m.showFilter()}, true
+	case "a":
+		return []types.Action{types.ToggleCommitLogBranchesAction{}}, true
+	case "d":
+		return []types.Action{types.CycleCommitLogRangeAction{}}, true
+	case "c":
+		// Clear the filter
+		m.filter.Reset()
+		m.applied = ""
+		return []types.Action{types.FilterCommitLogAction{}}, true
+	}
+
+	// Swallow everything else while the log is open
+	return nil, true
+}
+
+// handleFilterKey edits the filter; enter applies it, esc goes back to the one applied
+func (m *CommitLogMode) handleFilterKey(msg tea.KeyMsg) ([]types.Action, bool) {
+	switch msg.String() {
+	case "ctrl+c":
+		return []types.Action{types.QuitAction{Force: true}}, true
+	case "enter":
+		m.editing = false
+		m.filter.Blur()
+		m.applied = m.filter.Value()
+		return []types.Action{m.showFilter(), types.FilterCommitLogAction{Query: m.applied}}, true
+	case "esc":
+		m.editing = false
+		m.filter.Blur()
+		m.filter.SetValue(m.applied)
+		return []types.Action{m.showFilter()}, true
+	}
+	m.filter, _ = m.filter.Update(msg)
+	return []types.Action{m.showFilter()}, true
+}
+
+// showFilter reports the filter input for display
+func (m *CommitLogMode) showFilter() types.Action {
+	return types.EditCommitLogFilterAction{Input: m.filter.View(), Editing: m.editing}
+}
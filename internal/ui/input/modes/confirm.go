@@ -1,52 +1,46 @@
 package modes
 
 import (
-	"gitagrip/internal/ui/input/types"
 	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/darksworm/gitagrip/internal/ui/input/types"
 )
 
-type ConfirmMode struct {
-	groupName string
-}
+// ConfirmMode holds the keyboard while the confirmation dialog is open. The model keeps
+// what is asked about; this mode only collects the answer.
+type ConfirmMode struct{}
 
 func NewConfirmMode() *ConfirmMode {
 	return &ConfirmMode{}
 }
 
 func (m *ConfirmMode) Name() string {
-	return "delete-confirm"
+	return "confirm"
 }
 
 func (m *ConfirmMode) Enter(ctx types.Context) []types.Action {
-	// Store the group name when entering the mode
-	if ctx.IsOnGroup() {
-		m.groupName = ctx.CurrentGroupName()
-	}
 	return nil
 }
 
 func (m *ConfirmMode) Exit(ctx types.Context) []types.Action {
-	return nil
+	// Closing the dialog without a yes drops the pending actions
+	return []types.Action{types.ResolveConfirmAction{}}
 }
 
 func (m *ConfirmMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Action, bool) {
 	switch msg.String() {
 	case "ctrl+c":
 		return []types.Action{types.QuitAction{Force: true}}, true
-	case "esc":
-		// Cancel and return to normal mode
-		return []types.Action{types.ChangeModeAction{Mode: types.ModeNormal}}, true
 	case "y", "Y":
-		// Confirm deletion
+		// Confirm
 		return []types.Action{
-			types.DeleteGroupAction{GroupName: m.groupName},
+			types.ResolveConfirmAction{Confirmed: true},
 			types.ChangeModeAction{Mode: types.ModeNormal},
 		}, true
-
-	case "n", "N":
-		// Cancel deletion
+	case "n", "N", "esc", "q":
+		// Cancel
 		return []types.Action{types.ChangeModeAction{Mode: types.ModeNormal}}, true
 	}
 
-	return nil, false
+	// Swallow everything else while the dialog is open
+	return nil, true
 }
@@ -0,0 +1,59 @@
+package modes
+
+import (
+	"github.com/charmbracelet/bubbles/v2/textinput"
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/darksworm/gitagrip/internal/ui/input/types"
+)
+
+// CredentialMode answers a password, passphrase or host key prompt of git or ssh. Secret
+// answers are masked while typed.
+type CredentialMode struct {
+	textInput *textinput.Model
+}
+
+func NewCredentialMode(ti *textinput.Model) *CredentialMode {
+	return &CredentialMode{textInput: ti}
+}
+
+func (m *CredentialMode) Name() string {
+	return "credential"
+}
+
+func (m *CredentialMode) Enter(ctx types.Context) []types.Action {
+	if m.textInput != nil && ctx.CredentialSecret() {
+		m.textInput.EchoMode = textinput.EchoPassword
+	}
+	return nil
+}
+
+func (m *CredentialMode) Exit(ctx types.Context) []types.Action {
+	if m.textInput != nil {
+		m.textInput.EchoMode = textinput.EchoNormal
+		m.textInput.Reset()
+	}
+	return nil
+}
+
+func (m *CredentialMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Action, bool) {
+	switch msg.String() {
+	case "ctrl+c":
+		return []types.Action{types.QuitAction{Force: true}}, true
+	case "esc":
+		return []types.Action{
+			types.AnswerCredentialAction{Declined: true},
+			types.ChangeModeAction{Mode: types.ModeNormal},
+		}, true
+	case "enter":
+		value := ""
+		if m.textInput != nil {
+			value = m.textInput.Value()
+		}
+		return []types.Action{
+			types.AnswerCredentialAction{Value: value},
+			types.ChangeModeAction{Mode: types.ModeNormal},
+		}, true
+	default:
+		return nil, false
+	}
+}
@@ -0,0 +1,37 @@
+package modes
+
+import (
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/darksworm/gitagrip/internal/ui/input/types"
+)
+
+// DashboardMode shows aggregate repository status until it is closed
+type DashboardMode struct{}
+
+func NewDashboardMode() *DashboardMode {
+	return &DashboardMode{}
+}
+
+func (m *DashboardMode) Name() string {
+	return "dashboard"
+}
+
+func (m *DashboardMode) Enter(ctx types.Context) []types.Action {
+	return []types.Action{types.OpenDashboardAction{}}
+}
+
+func (m *DashboardMode) Exit(ctx types.Context) []types.Action {
+	return []types.Action{types.CloseDashboardAction{}}
+}
+
+func (m *DashboardMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Action, bool) {
+	switch msg.String() {
+	case "ctrl+c":
+		return []types.Action{types.QuitAction{Force: true}}, true
+	case "esc", "q", "#", "enter":
+		return []types.Action{types.ChangeModeAction{Mode: types.ModeNormal}}, true
+	}
+
+	// Swallow everything else while the dashboard is open
+	return nil, true
+}
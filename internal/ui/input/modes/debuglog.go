@@ -0,0 +1,55 @@
+package modes
+
+import (
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/darksworm/gitagrip/internal/ui/input/types"
+)
+
+// DebugLogMode tails gitagrip's own log, for troubleshooting. It has no remappable
+// binding and isn't listed in the help: ctrl+l opens it.
+type DebugLogMode struct{}
+
+func NewDebugLogMode() *DebugLogMode {
+	return &DebugLogMode{}
+}
+
+func (m *DebugLogMode) Name() string {
+	return "debug-log"
+}
+
+func (m *DebugLogMode) Enter(ctx types.Context) []types.Action {
+	return []types.Action{types.OpenDebugLogAction{}}
+}
+
+func (m *DebugLogMode) Exit(ctx types.Context) []types.Action {
+	return []types.Action{types.CloseDebugLogAction{}}
+}
+
+func (m *DebugLogMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Action, bool) {
+	switch msg.String() {
+	case "ctrl+c":
+		return []types.Action{types.QuitAction{Force: true}}, true
+	case "esc", "q", "ctrl+l":
+		return []types.Action{types.ChangeModeAction{Mode: types.ModeNormal}}, true
+	case "up", "k":
+		return []types.Action{types.DebugLogScrollAction{Delta: -1}}, true
+	case "down", "j":
+		return []types.Action{types.DebugLogScrollAction{Delta: 1}}, true
+	case "pgup":
+		return []types.Action{types.DebugLogScrollAction{Delta: -10}}, true
+	case "pgdown":
+		return []types.Action{types.DebugLogScrollAction{Delta: 10}}, true
+	case "g":
+		return []types.Action{types.DebugLogScrollAction{Delta: -maxDebugLogLines}}, true
+	case "G":
+		return []types.Action{types.DebugLogScrollAction{Delta: maxDebugLogLines}}, true
+	case "r":
+		return []types.Action{types.OpenDebugLogAction{}}, true
+	}
+
+	// Swallow everything else while the log is open
+	return nil, true
+}
+
+// maxDebugLogLines scrolls from one end of the tail to the other
+const maxDebugLogLines = 200
@@ -0,0 +1,60 @@
+package modes
+
+import (
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/darksworm/gitagrip/internal/ui/input/types"
+)
+
+// DirBrowserMode browses directories to pick one to scan for repositories, so that
+// long paths don't have to be typed
+type DirBrowserMode struct{}
+
+func NewDirBrowserMode() *DirBrowserMode {
+	return &DirBrowserMode{}
+}
+
+func (m *DirBrowserMode) Name() string {
+	return "dir-browser"
+}
+
+func (m *DirBrowserMode) Enter(ctx types.Context) []types.Action {
+	return []types.Action{types.OpenDirBrowserAction{}}
+}
+
+func (m *DirBrowserMode) Exit(ctx types.Context) []types.Action {
+	return []types.Action{types.CloseDirBrowserAction{}}
+}
+
+func (m *DirBrowserMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Action, bool) {
+	switch msg.String() {
+	case "ctrl+c":
+		return []types.Action{types.QuitAction{Force: true}}, true
+	case "esc", "q":
+		return []types.Action{types.ChangeModeAction{Mode: types.ModeNormal}}, true
+	case "up", "k":
+		return []types.Action{types.DirBrowserNavigateAction{Delta: -1}}, true
+	case "down", "j":
+		return []types.Action{types.DirBrowserNavigateAction{Delta: 1}}, true
+	case "pgup", "ctrl+u":
+		return []types.Action{types.DirBrowserNavigateAction{Delta: -10}}, true
+	case "pgdown", "ctrl+d":
+		return []types.Action{types.DirBrowserNavigateAction{Delta: 10}}, true
+	case "enter", "right", "l":
+		return []types.Action{types.DirBrowserDescendAction{}}, true
+	case "left", "h", "backspace":
+		return []types.Action{types.DirBrowserParentAction{}}, true
+	case "s":
+		return []types.Action{
+			types.ScanBrowsedDirAction{},
+			types.ChangeModeAction{Mode: types.ModeNormal},
+		}, true
+	case "1", "2", "3", "4", "5":
+		return []types.Action{
+			types.ScanRecentAction{Index: int(msg.String()[0] - '1')},
+			types.ChangeModeAction{Mode: types.ModeNormal},
+		}, true
+	}
+
+	// Swallow everything else while the browser is open
+	return nil, true
+}
@@ -0,0 +1,48 @@
+package modes
+
+import (
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/darksworm/gitagrip/internal/ui/input/types"
+)
+
+// FetchOptionsMode picks the remote, tag mode and depth of a fetch before running it
+type FetchOptionsMode struct{}
+
+func NewFetchOptionsMode() *FetchOptionsMode {
+	return &FetchOptionsMode{}
+}
+
+func (m *FetchOptionsMode) Name() string {
+	return "fetch-options"
+}
+
+func (m *FetchOptionsMode) Enter(ctx types.Context) []types.Action {
+	return []types.Action{types.OpenFetchOptionsAction{}}
+}
+
+func (m *FetchOptionsMode) Exit(ctx types.Context) []types.Action {
+	return []types.Action{types.CloseFetchOptionsAction{}}
+}
+
+func (m *FetchOptionsMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Action, bool) {
+	switch msg.String() {
+	case "ctrl+c":
+		return []types.Action{types.QuitAction{Force: true}}, true
+	case "esc", "q", "O":
+		return []types.Action{types.ChangeModeAction{Mode: types.ModeNormal}}, true
+	case "r":
+		return []types.Action{types.CycleFetchOptionAction{Option: "remote"}}, true
+	case "t":
+		return []types.Action{types.CycleFetchOptionAction{Option: "tags"}}, true
+	case "d":
+		return []types.Action{types.CycleFetchOptionAction{Option: "depth"}}, true
+	case "enter", "f":
+		return []types.Action{
+			types.FetchWithOptionsAction{},
+			types.ChangeModeAction{Mode: types.ModeNormal},
+		}, true
+	}
+
+	// Swallow everything else while the options are open
+	return nil, true
+}
@@ -0,0 +1,47 @@
+package modes
+
+import (
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/darksworm/gitagrip/internal/ui/input/types"
+)
+
+// GroupColorMode picks the color of a group from a short palette: a digit picks a color,
+// 0 takes the group's color away
+type GroupColorMode struct{}
+
+func NewGroupColorMode() *GroupColorMode {
+	return &GroupColorMode{}
+}
+
+func (m *GroupColorMode) Name() string {
+	return "group-color"
+}
+
+func (m *GroupColorMode) Enter(ctx types.Context) []types.Action {
+	return []types.Action{types.OpenGroupColorAction{Group: ctx.CurrentGroupName()}}
+}
+
+func (m *GroupColorMode) Exit(ctx types.Context) []types.Action {
+	return []types.Action{types.CloseGroupColorAction{}}
+}
+
+func (m *GroupColorMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Action, bool) {
+	key := msg.String()
+	switch key {
+	case "ctrl+c":
+		return []types.Action{types.QuitAction{Force: true}}, true
+	case "esc", "q", "L":
+		return []types.Action{types.ChangeModeAction{Mode: types.ModeNormal}}, true
+	case "backspace", "delete":
+		key = "0"
+	}
+	if len(key) == 1 && key[0] >= '0' && key[0] <= '9' {
+		return []types.Action{
+			types.SetGroupColorAction{Index: int(key[0] - '0')},
+			types.ChangeModeAction{Mode: types.ModeNormal},
+		}, true
+	}
+
+	// Swallow everything else while the picker is open
+	return nil, true
+}
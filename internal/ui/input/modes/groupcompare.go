@@ -0,0 +1,37 @@
+package modes
+
+import (
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/darksworm/gitagrip/internal/ui/input/types"
+)
+
+// GroupCompareMode shows the marked group and the group under the cursor side by side
+type GroupCompareMode struct{}
+
+func NewGroupCompareMode() *GroupCompareMode {
+	return &GroupCompareMode{}
+}
+
+func (m *GroupCompareMode) Name() string {
+	return "group-compare"
+}
+
+func (m *GroupCompareMode) Enter(ctx types.Context) []types.Action {
+	return []types.Action{types.OpenGroupCompareAction{Left: ctx.CompareGroup(), Right: ctx.CurrentGroupName()}}
+}
+
+func (m *GroupCompareMode) Exit(ctx types.Context) []types.Action {
+	return []types.Action{types.CloseGroupCompareAction{}}
+}
+
+func (m *GroupCompareMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Action, bool) {
+	switch msg.String() {
+	case "ctrl+c":
+		return []types.Action{types.QuitAction{Force: true}}, true
+	case "esc", "q", "C", "enter":
+		return []types.Action{types.ChangeModeAction{Mode: types.ModeNormal}}, true
+	}
+
+	// Swallow everything else while the comparison is open
+	return nil, true
+}
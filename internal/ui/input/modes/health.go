@@ -0,0 +1,45 @@
+package modes
+
+import (
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/darksworm/gitagrip/internal/ui/input/types"
+)
+
+// HealthMode shows the health checks of repositories with suggested fixes until it is closed
+type HealthMode struct{}
+
+func NewHealthMode() *HealthMode {
+	return &HealthMode{}
+}
+
+func (m *HealthMode) Name() string {
+	return "health"
+}
+
+func (m *HealthMode) Enter(ctx types.Context) []types.Action {
+	return []types.Action{types.OpenHealthAction{}}
+}
+
+func (m *HealthMode) Exit(ctx types.Context) []types.Action {
+	return []types.Action{types.CloseHealthAction{}}
+}
+
+func (m *HealthMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Action, bool) {
+	switch msg.String() {
+	case "ctrl+c":
+		return []types.Action{types.QuitAction{Force: true}}, true
+	case "esc", "q", "T":
+		return []types.Action{types.ChangeModeAction{Mode: types.ModeNormal}}, true
+	case "up", "k":
+		return []types.Action{types.HealthNavigateAction{Delta: -1}}, true
+	case "down", "j":
+		return []types.Action{types.HealthNavigateAction{Delta: 1}}, true
+	case "f":
+		return []types.Action{types.HealthFsckAction{}}, true
+	case "x":
+		return []types.Action{types.RemoveHealthLocksAction{}}, true
+	}
+
+	// Swallow everything else while the checks are open
+	return nil, true
+}
@@ -0,0 +1,42 @@
+package modes
+
+import (
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/darksworm/gitagrip/internal/ui/input/types"
+)
+
+// HeatmapMode shows commit activity per day over the past year until it is closed
+type HeatmapMode struct{}
+
+func NewHeatmapMode() *HeatmapMode {
+	return &HeatmapMode{}
+}
+
+func (m *HeatmapMode) Name() string {
+	return "heatmap"
+}
+
+func (m *HeatmapMode) Enter(ctx types.Context) []types.Action {
+	// On a group header only that group's repositories count
+	group := ""
+	if ctx.IsOnGroup() {
+		group = ctx.CurrentGroupName()
+	}
+	return []types.Action{types.OpenHeatmapAction{GroupName: group}}
+}
+
+func (m *HeatmapMode) Exit(ctx types.Context) []types.Action {
+	return []types.Action{types.CloseHeatmapAction{}}
+}
+
+func (m *HeatmapMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Action, bool) {
+	switch msg.String() {
+	case "ctrl+c":
+		return []types.Action{types.QuitAction{Force: true}}, true
+	case "esc", "q", "Y", "enter":
+		return []types.Action{types.ChangeModeAction{Mode: types.ModeNormal}}, true
+	}
+
+	// Swallow everything else while the heatmap is open
+	return nil, true
+}
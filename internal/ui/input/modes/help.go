@@ -0,0 +1,43 @@
+package modes
+
+import (
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/darksworm/gitagrip/internal/ui/input/types"
+)
+
+// HelpMode shows the key bindings in effect until it is closed
+type HelpMode struct {
+	keys *types.KeyMap
+}
+
+func NewHelpMode(keys *types.KeyMap) *HelpMode {
+	return &HelpMode{keys: keys}
+}
+
+func (m *HelpMode) Name() string {
+	return "help"
+}
+
+func (m *HelpMode) Enter(ctx types.Context) []types.Action {
+	return []types.Action{types.OpenHelpAction{}}
+}
+
+func (m *HelpMode) Exit(ctx types.Context) []types.Action {
+	return []types.Action{types.CloseHelpAction{}}
+}
+
+func (m *HelpMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Action, bool) {
+	switch msg.String() {
+	case "ctrl+c":
+		return []types.Action{types.QuitAction{Force: true}}, true
+	case "esc", "q", "enter":
+		return []types.Action{types.ChangeModeAction{Mode: types.ModeNormal}}, true
+	}
+	// The help key closes it again, wherever it was moved
+	if m.keys.Resolve(msg.String()) == "?" {
+		return []types.Action{types.ChangeModeAction{Mode: types.ModeNormal}}, true
+	}
+
+	// Swallow everything else while the help is open
+	return nil, true
+}
@@ -1,9 +1,9 @@
 package modes
 
 import (
-	"gitagrip/internal/ui/input/types"
 	"github.com/charmbracelet/bubbles/v2/textinput"
 	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/darksworm/gitagrip/internal/ui/input/types"
 )
 
 type NewGroupMode struct {
@@ -25,10 +25,15 @@ func (m *NewGroupMode) Enter(ctx types.Context) []types.Action {
 }
 
 func (m *NewGroupMode) Exit(ctx types.Context) []types.Action {
-	return m.textInputMode.Exit(ctx)
+	return append(m.textInputMode.Exit(ctx), types.CycleGroupTemplateAction{Reset: true})
 }
 
 func (m *NewGroupMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Action, bool) {
+	// Tab cycles through the group templates
+	if msg.String() == "tab" {
+		return []types.Action{types.CycleGroupTemplateAction{}}, true
+	}
+
 	// Let the base TextInputMode handle all keys including Enter
 	// It will send a SubmitTextAction when Enter is pressed
 	return m.textInputMode.HandleKey(msg, ctx)
@@ -1,18 +1,24 @@
 package modes
 
 import (
-	"gitagrip/internal/ui/input/types"
 	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/darksworm/gitagrip/internal/ui/input/types"
 	"time"
 )
 
 type NormalMode struct {
+	keys        *types.KeyMap
 	lastKeyWasG bool
 	lastGTime   time.Time
 }
 
 func NewNormalMode() *NormalMode {
-	return &NormalMode{}
+	return NewNormalModeWithKeys(types.DefaultKeyMap())
+}
+
+// NewNormalModeWithKeys creates a normal mode that honors remapped keys
+func NewNormalModeWithKeys(keys *types.KeyMap) *NormalMode {
+	return &NormalMode{keys: keys}
 }
 
 func (m *NormalMode) Name() string {
@@ -28,12 +34,22 @@ func (m *NormalMode) Exit(ctx types.Context) []types.Action {
 }
 
 func (m *NormalMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Action, bool) {
-	// Handle string keys
-	switch msg.String() {
+	// Translate remapped keys to the defaults handled by Run
+	return m.Run(m.keys.Resolve(msg.String()), ctx)
+}
+
+// Run performs what the given default key does (see types.DefaultKeyBindings), whatever
+// key it is bound to; the command palette runs actions through it. It reports false when
+// the action doesn't apply to what is under the cursor.
+func (m *NormalMode) Run(key string, ctx types.Context) ([]types.Action, bool) {
+	switch key {
 	case "ctrl+c":
 		return []types.Action{types.QuitAction{Force: true}}, true
 	case "esc":
-		// In normal mode, Esc doesn't do anything
+		// Offer to cancel a running bulk fetch/pull; otherwise Esc doesn't do anything here
+		if ctx.HasBulkOperation() {
+			return []types.Action{types.ChangeModeAction{Mode: types.ModeCancelConfirm}}, true
+		}
 		return nil, false
 	case "up":
 		return []types.Action{types.NavigateAction{Direction: "up"}}, true
@@ -114,17 +130,39 @@ func (m *NormalMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Actio
 		}
 		return []types.Action{types.SelectAction{Index: -1}}, true
 
-	case "a", "A":
-		// Toggle select all
+	case "V":
+		// Anchor a visual range at the cursor
+		if ctx.TotalItems() > 0 {
+			return []types.Action{types.ChangeModeAction{Mode: types.ModeVisual}}, true
+		}
+		return nil, false
+
+	case "a":
+		// Toggle selection of every repo in the group under the cursor
+		if ctx.TotalItems() > 0 {
+			return []types.Action{types.SelectGroupAction{}}, true
+		}
+		return nil, false
+
+	case "A":
+		// Select every repository
+		return []types.Action{types.SelectAllAction{}}, true
+
+	case "u":
+		// Clear the selection
 		if ctx.HasSelection() {
 			return []types.Action{types.DeselectAllAction{}}, true
 		}
-		return []types.Action{types.SelectAllAction{}}, true
+		return nil, false
 
 	case "r":
-		// Refresh status
+		// Refresh selected repos, current repo, or all repos in group
 		return []types.Action{types.RefreshAction{All: false}}, true
 
+	case "E":
+		// Refresh only the repositories marked with an error
+		return []types.Action{types.RetryFailedAction{}}, true
+
 	case "R":
 		// Rename group (only if on a group)
 		if ctx.IsOnGroup() {
@@ -135,6 +173,20 @@ func (m *NormalMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Actio
 		}
 		return nil, false
 
+	case "c":
+		// Pick a color for the group under the cursor
+		if ctx.IsOnGroup() && ctx.CurrentGroupName() != "Ungrouped" {
+			return []types.Action{types.ChangeModeAction{Mode: types.ModeGroupColor}}, true
+		}
+		return nil, false
+
+	case "L":
+		// Open git log for the current repo in the pager
+		if ctx.CurrentRepositoryPath() != "" && !ctx.IsOnGroup() {
+			return []types.Action{types.OpenLogAction{}}, true
+		}
+		return nil, false
+
 	case "f":
 		// Fetch selected repos, current repo, or all repos in group
 		if ctx.HasSelection() || ctx.CurrentRepositoryPath() != "" || ctx.IsOnGroup() {
@@ -142,13 +194,24 @@ func (m *NormalMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Actio
 		}
 		return nil, false
 
-	case "p", "P":
+	case "O":
+		// Fetch with a chosen remote, tag mode or depth
+		if ctx.HasSelection() || ctx.CurrentRepositoryPath() != "" || ctx.IsOnGroup() {
+			return []types.Action{types.ChangeModeAction{Mode: types.ModeFetchOptions}}, true
+		}
+		return nil, false
+
+	case "p":
 		// Pull selected repos, current repo, or all repos in group
 		if ctx.HasSelection() || ctx.CurrentRepositoryPath() != "" || ctx.IsOnGroup() {
 			return []types.Action{types.PullAction{}}, true
 		}
 		return nil, false
 
+	case "P":
+		// Push the ahead repos among the selection, the group or everything (confirmed first)
+		return []types.Action{types.ChangeModeAction{Mode: types.ModePushConfirm}}, true
+
 	case "/":
 		// Enter search mode
 		return []types.Action{types.ChangeModeAction{Mode: types.ModeSearch}}, true
@@ -157,6 +220,16 @@ func (m *NormalMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Actio
 		// Enter filter mode
 		return []types.Action{types.ChangeModeAction{Mode: types.ModeFilter}}, true
 
+	case "1":
+		// Status filters: everything, dirty, ahead/behind, errored
+		return []types.Action{types.StatusFilterAction{}}, true
+	case "2":
+		return []types.Action{types.StatusFilterAction{Status: "dirty"}}, true
+	case "3":
+		return []types.Action{types.StatusFilterAction{Status: "unsynced"}}, true
+	case "4":
+		return []types.Action{types.StatusFilterAction{Status: "error"}}, true
+
 	case "n":
 		// Navigate to next search result
 		if ctx.SearchQuery() != "" {
@@ -165,15 +238,12 @@ func (m *NormalMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Actio
 		return nil, true // Consume the key even if no action
 
 	case "N":
-		// New group (only if selection)
-		if ctx.HasSelection() {
-			return []types.Action{types.ChangeModeAction{Mode: types.ModeNewGroup}}, true
-		}
-		// Otherwise, navigate to previous search result
-		if ctx.SearchQuery() != "" {
+		// Previous search result while searching without a selection
+		if !ctx.HasSelection() && ctx.SearchQuery() != "" {
 			return []types.Action{types.SearchNavigateAction{Direction: "prev"}}, true
 		}
-		return nil, true // Consume the key even if no action
+		// Otherwise, new group (with the selection, or empty / from a template)
+		return []types.Action{types.ChangeModeAction{Mode: types.ModeNewGroup}}, true
 
 	case "m":
 		// Move to group (only if selection or on repo)
@@ -183,21 +253,103 @@ func (m *NormalMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Actio
 		return nil, false
 
 	case "H":
-		// Open commit history (git log) for the current repository
+		// Open the commit log view (filterable) for the current repository
 		if ctx.CurrentRepositoryPath() != "" && !ctx.IsOnGroup() {
-			return []types.Action{types.OpenLogAction{}}, true
+			return []types.Action{types.ChangeModeAction{Mode: types.ModeCommitLog}}, true
+		}
+		return nil, false
+
+	case "v":
+		// Commits not pushed yet (selected repos, the group under the cursor, or current repo)
+		if ctx.HasSelection() || ctx.IsOnGroup() || ctx.CurrentRepositoryPath() != "" {
+			return []types.Action{types.ChangeModeAction{Mode: types.ModeUnpushed}}, true
 		}
 		return nil, false
 
+	case "M":
+		// Migrate origin remotes between HTTPS and SSH (selected repos, or all)
+		return []types.Action{types.ChangeModeAction{Mode: types.ModeRemoteMigration}}, true
+
+	case "W":
+		// Review new upstream commits on watched branches
+		return []types.Action{types.ChangeModeAction{Mode: types.ModeWatchedChanges}}, true
+
+	case "U":
+		// Repositories whose status the last fetch or pull changed
+		return []types.Action{types.ChangeModeAction{Mode: types.ModeStatusChanges}}, true
+
+	case "Y":
+		// Commit activity over the past year (of the group under the cursor, or everything)
+		return []types.Action{types.ChangeModeAction{Mode: types.ModeHeatmap}}, true
+
+	case "T":
+		// Health checks: leftover locks, .git size, shallow clones (selected repos, or all)
+		return []types.Action{types.ChangeModeAction{Mode: types.ModeHealth}}, true
+
+	case "!":
+		// Open a shell in the current repository (with group env vars and direnv/mise)
+		if ctx.CurrentRepositoryPath() != "" && !ctx.IsOnGroup() {
+			return []types.Action{types.OpenShellAction{}}, true
+		}
+		return nil, false
+
+	case "X":
+		// Clean untracked/ignored files (selected repos, or current one) after a dry run
+		if ctx.HasSelection() || (ctx.CurrentRepositoryPath() != "" && !ctx.IsOnGroup()) {
+			return []types.Action{types.ChangeModeAction{Mode: types.ModeCleanConfirm}}, true
+		}
+		return nil, false
+
+	case "delete":
+		// Forget the selected repos (or the current one): gone from the list, left on disk
+		if ctx.HasSelection() || (ctx.CurrentRepositoryPath() != "" && !ctx.IsOnGroup()) {
+			return []types.Action{types.ConfirmAction{Actions: []types.Action{types.ForgetReposAction{}}}}, true
+		}
+		return nil, false
+
+	case "Z":
+		// Bundle the current repository and move it to the archive directory (confirmed first)
+		if ctx.CurrentRepositoryPath() != "" && !ctx.IsOnGroup() {
+			return []types.Action{types.ChangeModeAction{Mode: types.ModeArchiveConfirm}}, true
+		}
+		return nil, false
+
+	case "ctrl+p":
+		// Find any action by name instead of by key
+		return []types.Action{types.ChangeModeAction{Mode: types.ModePalette}}, true
+
+	case "ctrl+z":
+		// Bring back what was forgotten or archived last
+		return []types.Action{types.UndoRemoveAction{}}, true
+
 	case "d":
-		// Delete group (only if on a group)
-		if ctx.IsOnGroup() {
-			return []types.Action{types.ChangeModeAction{Mode: types.ModeDeleteConfirm}}, true
+		// Delete the group under the cursor (Ungrouped is not a real group)
+		if ctx.IsOnGroup() && ctx.CurrentGroupName() != "Ungrouped" {
+			deleteGroup := types.DeleteGroupAction{GroupName: ctx.CurrentGroupName()}
+			return []types.Action{types.ConfirmAction{Actions: []types.Action{deleteGroup}}}, true
 		}
 		return nil, false
 
+	case "#":
+		// Aggregate status of every repository, per group
+		return []types.Action{types.ChangeModeAction{Mode: types.ModeDashboard}}, true
+
+	case "C":
+		// Mark a group, then compare it side by side with the next group C is pressed on
+		if !ctx.IsOnGroup() {
+			return nil, false
+		}
+		if marked := ctx.CompareGroup(); marked != "" && marked != ctx.CurrentGroupName() {
+			return []types.Action{types.ChangeModeAction{Mode: types.ModeGroupCompare}}, true
+		}
+		return []types.Action{types.MarkCompareGroupAction{GroupName: ctx.CurrentGroupName()}}, true
+
+	case "B":
+		// Which repositories are on which branch
+		return []types.Action{types.ChangeModeAction{Mode: types.ModeBranchOverview}}, true
+
 	case "D":
-		// Show git diff for current repo
+		// Show git diff (uncommitted changes) for current repo
 		if ctx.CurrentRepositoryPath() != "" && !ctx.IsOnGroup() {
 			return []types.Action{types.OpenDiffAction{}}, true
 		}
@@ -210,13 +362,31 @@ func (m *NormalMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Actio
 		}
 		return nil, false
 
+	case "t":
+		// Toggle labels across the selection, or edit the tags of the current repo
+		if ctx.HasSelection() {
+			return []types.Action{types.ChangeModeAction{Mode: types.ModeBulkLabels}}, true
+		}
+		if ctx.CurrentRepositoryPath() != "" && !ctx.IsOnGroup() {
+			return []types.Action{types.ChangeModeAction{Mode: types.ModeEditTags}}, true
+		}
+		return nil, false
+
 	case "S":
 		// Sort mode moved to Shift+S
 		return []types.Action{types.ChangeModeAction{Mode: types.ModeSort}}, true
 
+	case "ctrl+s":
+		// Cycle the sort: name, dirty first, ahead/behind, last commit, branch
+		return []types.Action{types.CycleSortAction{}}, true
+
+	case "w":
+		// Write group changes that aren't saved automatically
+		return []types.Action{types.SaveConfigAction{}}, true
+
 	case "?":
-		// Toggle help
-		return []types.Action{types.ToggleHelpAction{}}, true
+		// Key bindings, as remapped
+		return []types.Action{types.ChangeModeAction{Mode: types.ModeHelp}}, true
 
 	case "i":
 		// Toggle info
@@ -236,12 +406,25 @@ func (m *NormalMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Actio
 		}
 		return nil, false
 
-	case "L":
-		// Open log for current repo
-		if ctx.CurrentRepositoryPath() != "" && !ctx.IsOnGroup() {
-			return []types.Action{types.OpenLogAction{}}, true
-		}
-		return nil, false
+	case "ctrl+r":
+		// Scan the base directory (and others scanned) again, dropping repos that are gone
+		return []types.Action{types.RescanAction{}}, true
+
+	case "+":
+		// Browse to another directory and scan it for repositories
+		return []types.Action{types.ChangeModeAction{Mode: types.ModeDirBrowser}}, true
+
+	case "o":
+		// List an organization's repositories to clone
+		return []types.Action{types.ChangeModeAction{Mode: types.ModeCloneOrg}}, true
+
+	case "ctrl+l":
+		// Tail gitagrip's own log (not remappable, for troubleshooting)
+		return []types.Action{types.ChangeModeAction{Mode: types.ModeDebugLog}}, true
+
+	case "Q":
+		// Switch to another workspace profile
+		return []types.Action{types.ChangeModeAction{Mode: types.ModeProfiles}}, true
 
 	case "q":
 		// Quit
@@ -266,7 +449,7 @@ func (m *NormalMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Actio
 
 	default:
 		// Any other key cancels the 'g' prefix
-		if m.lastKeyWasG && msg.String() != "g" {
+		if m.lastKeyWasG && key != "g" {
 			m.lastKeyWasG = false
 		}
 		// Also cancel if too much time has passed since first 'g'
@@ -0,0 +1,156 @@
+package modes
+
+import (
+	"sort"
+	"strings"
+	"unicode"
+
+	"github.com/charmbracelet/bubbles/v2/textinput"
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/darksworm/gitagrip/internal/ui/input/types"
+)
+
+// paletteSkipped are the bindings the palette leaves out: moving the cursor by name is
+// no use, and the palette doesn't open itself
+var paletteSkipped = map[string]bool{
+	"up": true, "down": true, "collapse": true, "expand": true, "bottom": true, "palette": true,
+}
+
+// PaletteMode lists every action that applies under the cursor, narrowed down by a fuzzy
+// search as you type, and runs the chosen one as if its key had been pressed
+type PaletteMode struct {
+	TextInputMode
+	keys    *types.KeyMap
+	normal  *NormalMode
+	matches []types.KeyBinding
+	index   int
+}
+
+func NewPaletteMode(ti *textinput.Model, keys *types.KeyMap, normal *NormalMode) *PaletteMode {
+	return &PaletteMode{
+		TextInputMode: NewTextInputMode(types.ModePalette, "palette", "> ", ti),
+		keys:          keys,
+		normal:        normal,
+	}
+}
+
+func (m *PaletteMode) Enter(ctx types.Context) []types.Action {
+	m.TextInputMode.Enter(ctx)
+	return []types.Action{m.update(ctx)}
+}
+
+func (m *PaletteMode) Exit(ctx types.Context) []types.Action {
+	m.TextInputMode.Exit(ctx)
+	m.matches = nil
+	return []types.Action{types.ClosePaletteAction{}}
+}
+
+func (m *PaletteMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Action, bool) {
+	switch msg.String() {
+	case "ctrl+c":
+		return []types.Action{types.QuitAction{Force: true}}, true
+	case "esc":
+		return []types.Action{types.ChangeModeAction{Mode: types.ModeNormal}}, true
+	case "up", "ctrl+k", "ctrl+p":
+		m.index = max(0, m.index-1)
+		return []types.Action{m.show()}, true
+	case "down", "ctrl+j", "ctrl+n":
+		m.index = min(len(m.matches)-1, m.index+1)
+		return []types.Action{m.show()}, true
+	case "enter":
+		if len(m.matches) == 0 {
+			return []types.Action{m.show()}, true
+		}
+		// Close the palette first: the action may open a prompt of its own
+		actions, _ := m.normal.Run(m.matches[m.index].Key, ctx)
+		return append([]types.Action{types.ChangeModeAction{Mode: types.ModeNormal}}, actions...), true
+	}
+
+	// Typing narrows the list down and starts over at the best match
+	if m.textInput != nil {
+		*m.textInput, _ = m.textInput.Update(msg)
+	}
+	return []types.Action{m.update(ctx)}, true
+}
+
+// update matches the query against the actions that apply under the cursor
+func (m *PaletteMode) update(ctx types.Context) types.Action {
+	query := ""
+	if m.textInput != nil {
+		query = m.textInput.Value()
+	}
+
+	type scored struct {
+		binding types.KeyBinding
+		score   int
+	}
+	var matches []scored
+	for _, b := range types.DefaultKeyBindings {
+		if paletteSkipped[b.Action] || len(m.keys.Keys(b.Action)) == 0 {
+			continue
+		}
+		if _, applies := m.normal.Run(b.Key, ctx); !applies {
+			continue
+		}
+		// The name counts for more than the description
+		score, ok := fuzzyScore(query, strings.ReplaceAll(b.Action, "_", " "))
+		if ok {
+			score += 10
+		}
+		if s, found := fuzzyScore(query, b.Description); found && (!ok || s > score) {
+			score, ok = s, true
+		}
+		if ok {
+			matches = append(matches, scored{b, score})
+		}
+	}
+	sort.SliceStable(matches, func(i, j int) bool { return matches[i].score > matches[j].score })
+
+	m.matches = m.matches[:0]
+	for _, match := range matches {
+		m.matches = append(m.matches, match.binding)
+	}
+	m.index = 0
+	return m.show()
+}
+
+// show reports the matches and the cursor for display
+func (m *PaletteMode) show() types.Action {
+	entries := make([]types.PaletteEntry, 0, len(m.matches))
+	for _, b := range m.matches {
+		entries = append(entries, types.PaletteEntry{
+			Action:      b.Action,
+			Keys:        strings.Join(m.keys.Keys(b.Action), " "),
+			Description: b.Description,
+		})
+	}
+	return types.ShowPaletteAction{Entries: entries, Index: m.index}
+}
+
+// fuzzyScore reports whether every character of the query appears in text in order
+// (ignoring case and spaces), and how well: consecutive characters and characters
+// starting a word score higher
+func fuzzyScore(query, text string) (int, bool) {
+	query = strings.ToLower(strings.ReplaceAll(query, " ", ""))
+	if query == "" {
+		return 0, true
+	}
+	want := []rune(query)
+	score, matched, last := 0, 0, -2
+	prev := ' '
+	for i, r := range []rune(strings.ToLower(text)) {
+		if matched < len(want) && r == want[matched] {
+			score++
+			if i == last+1 {
+				score += 2
+			}
+			if !unicode.IsLetter(prev) && !unicode.IsDigit(prev) {
+				score += 3
+			}
+			matched++
+			last = i
+		}
+		prev = r
+	}
+	return score, matched == len(want)
+}
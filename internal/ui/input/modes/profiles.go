@@ -0,0 +1,43 @@
+package modes
+
+import (
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/darksworm/gitagrip/internal/ui/input/types"
+)
+
+// ProfilesMode lists the workspace profiles; picking one restarts gitagrip with it
+type ProfilesMode struct{}
+
+func NewProfilesMode() *ProfilesMode {
+	return &ProfilesMode{}
+}
+
+func (m *ProfilesMode) Name() string {
+	return "profiles"
+}
+
+func (m *ProfilesMode) Enter(ctx types.Context) []types.Action {
+	return []types.Action{types.OpenProfilesAction{}}
+}
+
+func (m *ProfilesMode) Exit(ctx types.Context) []types.Action {
+	return []types.Action{types.CloseProfilesAction{}}
+}
+
+func (m *ProfilesMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Action, bool) {
+	switch msg.String() {
+	case "ctrl+c":
+		return []types.Action{types.QuitAction{Force: true}}, true
+	case "esc", "q", "Q":
+		return []types.Action{types.ChangeModeAction{Mode: types.ModeNormal}}, true
+	case "up", "k":
+		return []types.Action{types.ProfilesNavigateAction{Delta: -1}}, true
+	case "down", "j":
+		return []types.Action{types.ProfilesNavigateAction{Delta: 1}}, true
+	case "enter":
+		return []types.Action{types.SwitchProfileAction{}}, true
+	}
+
+	// Swallow everything else while the list is open
+	return nil, true
+}
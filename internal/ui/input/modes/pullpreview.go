@@ -0,0 +1,50 @@
+package modes
+
+import (
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/darksworm/gitagrip/internal/ui/input/types"
+)
+
+// PullPreviewMode shows what a bulk pull would do to each repository and lets repositories
+// be left out before it runs
+type PullPreviewMode struct{}
+
+func NewPullPreviewMode() *PullPreviewMode {
+	return &PullPreviewMode{}
+}
+
+func (m *PullPreviewMode) Name() string {
+	return "pull-preview"
+}
+
+func (m *PullPreviewMode) Enter(ctx types.Context) []types.Action {
+	// The pull that opens the preview has already set it up
+	return nil
+}
+
+func (m *PullPreviewMode) Exit(ctx types.Context) []types.Action {
+	return []types.Action{types.ClosePullPreviewAction{}}
+}
+
+func (m *PullPreviewMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Action, bool) {
+	switch msg.String() {
+	case "ctrl+c":
+		return []types.Action{types.QuitAction{Force: true}}, true
+	case "up", "k":
+		return []types.Action{types.PullPreviewNavigateAction{Delta: -1}}, true
+	case "down", "j":
+		return []types.Action{types.PullPreviewNavigateAction{Delta: 1}}, true
+	case " ":
+		return []types.Action{types.TogglePullPreviewAction{}}, true
+	case "y", "Y", "enter":
+		return []types.Action{
+			types.PullPreviewedAction{},
+			types.ChangeModeAction{Mode: types.ModeNormal},
+		}, true
+	case "n", "N", "esc", "q":
+		return []types.Action{types.ChangeModeAction{Mode: types.ModeNormal}}, true
+	}
+
+	// Swallow everything else while the dry run is open
+	return nil, true
+}
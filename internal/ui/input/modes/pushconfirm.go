@@ -0,0 +1,44 @@
+package modes
+
+import (
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/darksworm/gitagrip/internal/ui/input/types"
+)
+
+// PushConfirmMode lists the repositories and branches a push would send and waits for confirmation
+type PushConfirmMode struct{}
+
+func NewPushConfirmMode() *PushConfirmMode {
+	return &PushConfirmMode{}
+}
+
+func (m *PushConfirmMode) Name() string {
+	return "push-confirm"
+}
+
+func (m *PushConfirmMode) Enter(ctx types.Context) []types.Action {
+	return []types.Action{types.PromptPushAction{Open: true}}
+}
+
+func (m *PushConfirmMode) Exit(ctx types.Context) []types.Action {
+	return []types.Action{types.PromptPushAction{}}
+}
+
+func (m *PushConfirmMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Action, bool) {
+	switch msg.String() {
+	case "ctrl+c":
+		return []types.Action{types.QuitAction{Force: true}}, true
+	case "y", "Y":
+		// Confirm push
+		return []types.Action{
+			types.PushAction{},
+			types.ChangeModeAction{Mode: types.ModeNormal},
+		}, true
+	case "n", "N", "esc", "q":
+		// Cancel push
+		return []types.Action{types.ChangeModeAction{Mode: types.ModeNormal}}, true
+	}
+
+	// Swallow everything else while the list is open
+	return nil, true
+}
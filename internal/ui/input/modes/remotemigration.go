@@ -0,0 +1,49 @@
+package modes
+
+import (
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/darksworm/gitagrip/internal/ui/input/types"
+)
+
+// RemoteMigrationMode drives the HTTPS/SSH remote migration preview
+type RemoteMigrationMode struct{}
+
+func NewRemoteMigrationMode() *RemoteMigrationMode {
+	return &RemoteMigrationMode{}
+}
+
+func (m *RemoteMigrationMode) Name() string {
+	return "remote-migration"
+}
+
+func (m *RemoteMigrationMode) Enter(ctx types.Context) []types.Action {
+	return []types.Action{types.OpenRemoteMigrationAction{}}
+}
+
+func (m *RemoteMigrationMode) Exit(ctx types.Context) []types.Action {
+	return []types.Action{types.CloseRemoteMigrationAction{}}
+}
+
+func (m *RemoteMigrationMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Action, bool) {
+	switch msg.String() {
+	case "ctrl+c":
+		return []types.Action{types.QuitAction{Force: true}}, true
+	case "esc", "q", "M":
+		return []types.Action{types.ChangeModeAction{Mode: types.ModeNormal}}, true
+	case "up", "k":
+		return []types.Action{types.RemoteMigrationNavigateAction{Delta: -1}}, true
+	case "down", "j":
+		return []types.Action{types.RemoteMigrationNavigateAction{Delta: 1}}, true
+	case "enter", " ":
+		// Apply the repository under the cursor
+		return []types.Action{types.ApplyRemoteMigrationAction{All: false}}, true
+	case "A":
+		// Apply every pending repository
+		return []types.Action{types.ApplyRemoteMigrationAction{All: true}}, true
+	case "t":
+		return []types.Action{types.ToggleRemoteMigrationTargetAction{}}, true
+	}
+
+	// Swallow everything else while the preview is open
+	return nil, true
+}
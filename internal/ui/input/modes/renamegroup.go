@@ -1,9 +1,11 @@
 package modes
 
 import (
-	"gitagrip/internal/ui/input/types"
+	"strings"
+
 	"github.com/charmbracelet/bubbles/v2/textinput"
 	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/darksworm/gitagrip/internal/ui/input/types"
 )
 
 type RenameGroupMode struct {
@@ -64,6 +66,7 @@ func (m *RenameGroupMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.
 		}
 
 		// Only rename if the name changed and is not empty
+		newName = strings.TrimSpace(newName)
 		if newName != "" && newName != m.oldName {
 			return []types.Action{
 				types.RenameGroupAction{OldName: m.oldName, NewName: newName},
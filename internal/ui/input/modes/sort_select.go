@@ -1,8 +1,8 @@
 package modes
 
 import (
-	"gitagrip/internal/ui/input/types"
 	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/darksworm/gitagrip/internal/ui/input/types"
 )
 
 // SortOptions available for sorting
@@ -14,6 +14,8 @@ var SortOptions = []struct {
 	{"name", "Name", "Sort by repository name"},
 	{"status", "Status", "Sort by status (dirty, clean)"},
 	{"branch", "Branch", "Sort by branch name"},
+	{"ahead-behind", "Ahead/behind", "Sort by commits ahead of and behind the upstream"},
+	{"last-commit", "Last commit", "Sort by the newest commit, most recent first"},
 }
 
 type SortSelectMode struct {
@@ -0,0 +1,37 @@
+package modes
+
+import (
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/darksworm/gitagrip/internal/ui/input/types"
+)
+
+// StatusChangesMode lists what the last fetch or pull changed until it is closed
+type StatusChangesMode struct{}
+
+func NewStatusChangesMode() *StatusChangesMode {
+	return &StatusChangesMode{}
+}
+
+func (m *StatusChangesMode) Name() string {
+	return "status-changes"
+}
+
+func (m *StatusChangesMode) Enter(ctx types.Context) []types.Action {
+	return []types.Action{types.OpenStatusChangesAction{}}
+}
+
+func (m *StatusChangesMode) Exit(ctx types.Context) []types.Action {
+	return []types.Action{types.CloseStatusChangesAction{}}
+}
+
+func (m *StatusChangesMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Action, bool) {
+	switch msg.String() {
+	case "ctrl+c":
+		return []types.Action{types.QuitAction{Force: true}}, true
+	case "esc", "q", "U", "enter":
+		return []types.Action{types.ChangeModeAction{Mode: types.ModeNormal}}, true
+	}
+
+	// Swallow everything else while the list is open
+	return nil, true
+}
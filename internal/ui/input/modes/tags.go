@@ -0,0 +1,46 @@
+package modes
+
+import (
+	"strings"
+
+	"github.com/charmbracelet/bubbles/v2/textinput"
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/darksworm/gitagrip/internal/ui/input/types"
+)
+
+// EditTagsMode edits the tags of the repository under the cursor as a comma-separated list.
+// With a selection it adds the entered tags to every selected repository instead.
+type EditTagsMode struct {
+	textInputMode TextInputMode
+}
+
+func NewEditTagsMode(ti *textinput.Model) *EditTagsMode {
+	return &EditTagsMode{
+		textInputMode: NewTextInputMode(types.ModeEditTags, "edit-tags", "Tags: ", ti),
+	}
+}
+
+func (m *EditTagsMode) Name() string {
+	return m.textInputMode.Name()
+}
+
+func (m *EditTagsMode) Enter(ctx types.Context) []types.Action {
+	actions := m.textInputMode.Enter(ctx)
+	// Pre-fill with the current tags
+	if ti := m.textInputMode.textInput; ti != nil && !ctx.HasSelection() {
+		if tags := ctx.CurrentRepositoryTags(); len(tags) > 0 {
+			ti.SetValue(strings.Join(tags, ", "))
+			ti.CursorEnd()
+		}
+	}
+	return actions
+}
+
+func (m *EditTagsMode) Exit(ctx types.Context) []types.Action {
+	return m.textInputMode.Exit(ctx)
+}
+
+func (m *EditTagsMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Action, bool) {
+	// Enter submits the list, even when emptied to remove every tag
+	return m.textInputMode.HandleKey(msg, ctx)
+}
@@ -0,0 +1,46 @@
+package modes
+
+import (
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/darksworm/gitagrip/internal/ui/input/types"
+)
+
+// UnpushedMode lists the commits that repositories have but their upstream doesn't, until
+// it is closed
+type UnpushedMode struct{}
+
+func NewUnpushedMode() *UnpushedMode {
+	return &UnpushedMode{}
+}
+
+func (m *UnpushedMode) Name() string {
+	return "unpushed"
+}
+
+func (m *UnpushedMode) Enter(ctx types.Context) []types.Action {
+	return []types.Action{types.OpenUnpushedAction{}}
+}
+
+func (m *UnpushedMode) Exit(ctx types.Context) []types.Action {
+	return []types.Action{types.CloseUnpushedAction{}}
+}
+
+func (m *UnpushedMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Action, bool) {
+	switch msg.String() {
+	case "ctrl+c":
+		return []types.Action{types.QuitAction{Force: true}}, true
+	case "esc", "q", "v":
+		return []types.Action{types.ChangeModeAction{Mode: types.ModeNormal}}, true
+	case "up", "k":
+		return []types.Action{types.UnpushedScrollAction{Delta: -1}}, true
+	case "down", "j":
+		return []types.Action{types.UnpushedScrollAction{Delta: 1}}, true
+	case "pgup", "ctrl+u":
+		return []types.Action{types.UnpushedScrollAction{Delta: -10}}, true
+	case "pgdown", "ctrl+d":
+		return []types.Action{types.UnpushedScrollAction{Delta: 10}}, true
+	}
+
+	// Swallow everything else while the list is open
+	return nil, true
+}
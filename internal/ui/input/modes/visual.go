@@ -0,0 +1,64 @@
+package modes
+
+import (
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/darksworm/gitagrip/internal/ui/input/types"
+)
+
+// VisualMode extends a range from the anchor to the cursor; space or m then
+// apply to every repository in the range at once
+type VisualMode struct {
+	keys *types.KeyMap
+}
+
+func NewVisualMode(keys *types.KeyMap) *VisualMode {
+	return &VisualMode{keys: keys}
+}
+
+func (m *VisualMode) Name() string {
+	return "visual"
+}
+
+func (m *VisualMode) Enter(ctx types.Context) []types.Action {
+	return []types.Action{types.StartVisualAction{}}
+}
+
+func (m *VisualMode) Exit(ctx types.Context) []types.Action {
+	return []types.Action{types.EndVisualAction{}}
+}
+
+func (m *VisualMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Action, bool) {
+	// Honor remapped movement, select and move keys
+	switch m.keys.Resolve(msg.String()) {
+	case "ctrl+c":
+		return []types.Action{types.QuitAction{Force: true}}, true
+	case "esc", "V", "q":
+		return []types.Action{types.ChangeModeAction{Mode: types.ModeNormal}}, true
+	case "up", "k":
+		return []types.Action{types.NavigateAction{Direction: "up"}}, true
+	case "down", "j":
+		return []types.Action{types.NavigateAction{Direction: "down"}}, true
+	case "pgup":
+		return []types.Action{types.NavigateAction{Direction: "pageup"}}, true
+	case "pgdown":
+		return []types.Action{types.NavigateAction{Direction: "pagedown"}}, true
+	case "home":
+		return []types.Action{types.NavigateAction{Direction: "home"}}, true
+	case "end", "G":
+		return []types.Action{types.NavigateAction{Direction: "end"}}, true
+	case " ":
+		return []types.Action{
+			types.VisualSelectAction{},
+			types.ChangeModeAction{Mode: types.ModeNormal},
+		}, true
+	case "m":
+		// Select the range, then ask for the target group
+		return []types.Action{
+			types.VisualSelectAction{Add: true},
+			types.ChangeModeAction{Mode: types.ModeMoveToGroup},
+		}, true
+	}
+
+	// Swallow everything else so stray keys don't trigger normal-mode actions
+	return nil, true
+}
@@ -0,0 +1,43 @@
+package modes
+
+import (
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/darksworm/gitagrip/internal/ui/input/types"
+)
+
+// WatchedChangesMode shows the new commits on watched branches until they are acknowledged
+type WatchedChangesMode struct{}
+
+func NewWatchedChangesMode() *WatchedChangesMode {
+	return &WatchedChangesMode{}
+}
+
+func (m *WatchedChangesMode) Name() string {
+	return "watched-changes"
+}
+
+func (m *WatchedChangesMode) Enter(ctx types.Context) []types.Action {
+	return []types.Action{types.OpenWatchedChangesAction{}}
+}
+
+func (m *WatchedChangesMode) Exit(ctx types.Context) []types.Action {
+	return []types.Action{types.CloseWatchedChangesAction{}}
+}
+
+func (m *WatchedChangesMode) HandleKey(msg tea.KeyMsg, ctx types.Context) ([]types.Action, bool) {
+	switch msg.String() {
+	case "ctrl+c":
+		return []types.Action{types.QuitAction{Force: true}}, true
+	case "esc", "q", "W":
+		return []types.Action{types.ChangeModeAction{Mode: types.ModeNormal}}, true
+	case "a", "enter":
+		// Acknowledge everything listed and close
+		return []types.Action{
+			types.AcknowledgeWatchedChangesAction{},
+			types.ChangeModeAction{Mode: types.ModeNormal},
+		}, true
+	}
+
+	// Swallow everything else while the list is open
+	return nil, true
+}
@@ -23,7 +23,7 @@ type DeselectAllAction struct{}
 func (a DeselectAllAction) Type() string { return "deselect_all" }
 
 type SelectGroupAction struct {
-	GroupName string
+	GroupName string // empty for the group of the repo or header under the cursor
 }
 
 func (a SelectGroupAction) Type() string { return "select_group" }
@@ -54,6 +54,14 @@ type CancelTextAction struct{}
 
 func (a CancelTextAction) Type() string { return "cancel_text" }
 
+// AnswerCredentialAction replies to the credential prompt git or ssh is waiting on
+type AnswerCredentialAction struct {
+	Value    string
+	Declined bool
+}
+
+func (a AnswerCredentialAction) Type() string { return "answer_credential" }
+
 // Command actions
 type RefreshAction struct {
 	All bool // true for full scan, false for status refresh
@@ -61,18 +69,291 @@ type RefreshAction struct {
 
 func (a RefreshAction) Type() string { return "refresh" }
 
+// RetryFailedAction refreshes the repositories whose status could not be read
+type RetryFailedAction struct{}
+
+func (a RetryFailedAction) Type() string { return "retry_failed" }
+
 type FetchAction struct{}
 
 func (a FetchAction) Type() string { return "fetch" }
 
+// OpenFetchOptionsAction shows the fetch options for the selection, group or repository
+type OpenFetchOptionsAction struct{}
+
+func (a OpenFetchOptionsAction) Type() string { return "open_fetch_options" }
+
+// CloseFetchOptionsAction hides the fetch options
+type CloseFetchOptionsAction struct{}
+
+func (a CloseFetchOptionsAction) Type() string { return "close_fetch_options" }
+
+// CycleFetchOptionAction moves a fetch option ("remote", "tags" or "depth") to its next value
+type CycleFetchOptionAction struct {
+	Option string
+}
+
+func (a CycleFetchOptionAction) Type() string { return "cycle_fetch_option" }
+
+// FetchWithOptionsAction fetches the repositories of the fetch options with the chosen ones
+type FetchWithOptionsAction struct{}
+
+func (a FetchWithOptionsAction) Type() string { return "fetch_with_options" }
+
 type PullAction struct{}
 
 func (a PullAction) Type() string { return "pull" }
 
+// StatusFilterAction shows only the repositories with a status, as the filter is:<Status>
+// would ("" shows everything again)
+type StatusFilterAction struct {
+	Status string
+}
+
+func (a StatusFilterAction) Type() string { return "status_filter" }
+
+// PullPreviewNavigateAction moves the cursor of the pull dry run by Delta repositories
+type PullPreviewNavigateAction struct {
+	Delta int
+}
+
+func (a PullPreviewNavigateAction) Type() string { return "pull_preview_navigate" }
+
+// TogglePullPreviewAction includes the repository under the cursor in the pull, or leaves it out
+type TogglePullPreviewAction struct{}
+
+func (a TogglePullPreviewAction) Type() string { return "toggle_pull_preview" }
+
+// PullPreviewedAction pulls the repositories the dry run includes
+type PullPreviewedAction struct{}
+
+func (a PullPreviewedAction) Type() string { return "pull_previewed" }
+
+// ClosePullPreviewAction closes the pull dry run
+type ClosePullPreviewAction struct{}
+
+func (a ClosePullPreviewAction) Type() string { return "close_pull_preview" }
+
+// OpenProfilesAction lists the workspace profiles to switch to
+type OpenProfilesAction struct{}
+
+func (a OpenProfilesAction) Type() string { return "open_profiles" }
+
+// ProfilesNavigateAction moves the cursor of the profile list by Delta entries
+type ProfilesNavigateAction struct {
+	Delta int
+}
+
+func (a ProfilesNavigateAction) Type() string { return "profiles_navigate" }
+
+// SwitchProfileAction quits and restarts with the profile under the cursor
+type SwitchProfileAction struct{}
+
+func (a SwitchProfileAction) Type() string { return "switch_profile" }
+
+// CloseProfilesAction closes the profile list
+type CloseProfilesAction struct{}
+
+func (a CloseProfilesAction) Type() string { return "close_profiles" }
+
+// OpenGroupColorAction opens the color picker for the group under the cursor
+type OpenGroupColorAction struct {
+	Group string
+}
+
+func (a OpenGroupColorAction) Type() string { return "open_group_color" }
+
+// SetGroupColorAction gives the group of the color picker the Index-th color of the
+// palette, counting from 1; 0 takes its color away
+type SetGroupColorAction struct {
+	Index int
+}
+
+func (a SetGroupColorAction) Type() string { return "set_group_color" }
+
+// CloseGroupColorAction closes the color picker
+type CloseGroupColorAction struct{}
+
+func (a CloseGroupColorAction) Type() string { return "close_group_color" }
+
+// OpenCloneOrgAction shows the repositories of the organization being listed for cloning
+type OpenCloneOrgAction struct{}
+
+func (a OpenCloneOrgAction) Type() string { return "open_clone_org" }
+
+// CloneOrgNavigateAction moves the cursor of the organization's repositories by Delta
+type CloneOrgNavigateAction struct {
+	Delta int
+}
+
+func (a CloneOrgNavigateAction) Type() string { return "clone_org_navigate" }
+
+// CloneOrgToggleAction picks or unpicks the repository under the cursor, or all of them
+type CloneOrgToggleAction struct {
+	All bool
+}
+
+func (a CloneOrgToggleAction) Type() string { return "clone_org_toggle" }
+
+// CloneOrgAction clones the picked repositories into a group named after the organization
+type CloneOrgAction struct{}
+
+func (a CloneOrgAction) Type() string { return "clone_org" }
+
+// CloseCloneOrgAction closes the organization's repositories
+type CloseCloneOrgAction struct{}
+
+func (a CloseCloneOrgAction) Type() string { return "close_clone_org" }
+
+// OpenDirBrowserAction opens the directory browser at the base directory
+type OpenDirBrowserAction struct{}
+
+func (a OpenDirBrowserAction) Type() string { return "open_dir_browser" }
+
+// DirBrowserNavigateAction moves the cursor of the directory browser by Delta entries
+type DirBrowserNavigateAction struct {
+	Delta int
+}
+
+func (a DirBrowserNavigateAction) Type() string { return "dir_browser_navigate" }
+
+// DirBrowserDescendAction opens the directory under the cursor
+type DirBrowserDescendAction struct{}
+
+func (a DirBrowserDescendAction) Type() string { return "dir_browser_descend" }
+
+// DirBrowserParentAction goes up to the parent directory
+type DirBrowserParentAction struct{}
+
+func (a DirBrowserParentAction) Type() string { return "dir_browser_parent" }
+
+// ScanBrowsedDirAction scans the directory being browsed for repositories
+type ScanBrowsedDirAction struct{}
+
+func (a ScanBrowsedDirAction) Type() string { return "scan_browsed_dir" }
+
+// ScanRecentAction scans the directory at Index of the browser's recent scans
+type ScanRecentAction struct {
+	Index int
+}
+
+func (a ScanRecentAction) Type() string { return "scan_recent" }
+
+// RescanAction scans the base directory and the directories scanned since start again,
+// dropping the repositories that are gone
+type RescanAction struct{}
+
+func (a RescanAction) Type() string { return "rescan" }
+
+// CloseDirBrowserAction closes the directory browser
+type CloseDirBrowserAction struct{}
+
+func (a CloseDirBrowserAction) Type() string { return "close_dir_browser" }
+
+// OpenDebugLogAction shows the end of gitagrip's log file, reading it again when open
+type OpenDebugLogAction struct{}
+
+func (a OpenDebugLogAction) Type() string { return "open_debug_log" }
+
+// DebugLogScrollAction scrolls the log view by Delta lines
+type DebugLogScrollAction struct {
+	Delta int
+}
+
+func (a DebugLogScrollAction) Type() string { return "debug_log_scroll" }
+
+// CloseDebugLogAction closes the log view
+type CloseDebugLogAction struct{}
+
+func (a CloseDebugLogAction) Type() string { return "close_debug_log" }
+
+// PromptPushAction lists what a push would send (Open false when the prompt closes)
+type PromptPushAction struct {
+	Open bool
+}
+
+func (a PromptPushAction) Type() string { return "prompt_push" }
+
+// PushAction pushes the repositories listed by the push prompt
+type PushAction struct{}
+
+func (a PushAction) Type() string { return "push" }
+
+// SwitchBranchAction checks out a branch in the given repositories
+type SwitchBranchAction struct {
+	RepoPaths []string
+	Name      string
+}
+
+func (a SwitchBranchAction) Type() string { return "switch_branch" }
+
 type OpenLogAction struct{}
 
 func (a OpenLogAction) Type() string { return "open_log" }
 
+// OpenCommitLogAction opens the commit log view of the current repository
+type OpenCommitLogAction struct{}
+
+func (a OpenCommitLogAction) Type() string { return "open_commit_log" }
+
+// CloseCommitLogAction closes the commit log view
+type CloseCommitLogAction struct{}
+
+func (a CloseCommitLogAction) Type() string { return "close_commit_log" }
+
+// CommitLogScrollAction scrolls the commit log by Delta commits
+type CommitLogScrollAction struct {
+	Delta int
+}
+
+func (a CommitLogScrollAction) Type() string { return "commit_log_scroll" }
+
+// EditCommitLogFilterAction shows the filter being typed in the commit log (Editing false
+// once it is applied or cancelled)
+type EditCommitLogFilterAction struct {
+	Input   string // rendered text input
+	Editing bool
+}
+
+func (a EditCommitLogFilterAction) Type() string { return "edit_commit_log_filter" }
+
+// FilterCommitLogAction reloads the commit log with a filter query: words match the
+// message, author:<name> the author, since:/until:<date> limit the dates
+type FilterCommitLogAction struct {
+	Query string
+}
+
+func (a FilterCommitLogAction) Type() string { return "filter_commit_log" }
+
+// ToggleCommitLogBranchesAction switches the commit log between the current branch and all branches
+type ToggleCommitLogBranchesAction struct{}
+
+func (a ToggleCommitLogBranchesAction) Type() string { return "toggle_commit_log_branches" }
+
+// CycleCommitLogRangeAction steps the commit log through its date ranges (any time, past
+// week, month, 3 months, year)
+type CycleCommitLogRangeAction struct{}
+
+func (a CycleCommitLogRangeAction) Type() string { return "cycle_commit_log_range" }
+
+// OpenUnpushedAction opens the commits not pushed yet of the selection, the group under
+// the cursor or the current repository
+type OpenUnpushedAction struct{}
+
+func (a OpenUnpushedAction) Type() string { return "open_unpushed" }
+
+// CloseUnpushedAction closes the unpushed commits view
+type CloseUnpushedAction struct{}
+
+func (a CloseUnpushedAction) Type() string { return "close_unpushed" }
+
+// UnpushedScrollAction scrolls the unpushed commits by Delta lines
+type UnpushedScrollAction struct {
+	Delta int
+}
+
+func (a UnpushedScrollAction) Type() string { return "unpushed_scroll" }
+
 type OpenDiffAction struct{}
 
 func (a OpenDiffAction) Type() string { return "open_diff" }
@@ -82,6 +363,11 @@ type OpenLazygitAction struct{}
 
 func (a OpenLazygitAction) Type() string { return "open_lazygit" }
 
+// OpenShellAction opens a shell in the current repository
+type OpenShellAction struct{}
+
+func (a OpenShellAction) Type() string { return "open_shell" }
+
 // OpenRepoLogsAction opens the command logs for the current repository
 type OpenRepoLogsAction struct{}
 
@@ -91,9 +377,15 @@ type ToggleInfoAction struct{}
 
 func (a ToggleInfoAction) Type() string { return "toggle_info" }
 
-type ToggleHelpAction struct{}
+// OpenHelpAction shows the key bindings in effect
+type OpenHelpAction struct{}
+
+func (a OpenHelpAction) Type() string { return "open_help" }
+
+// CloseHelpAction closes the key bindings popup
+type CloseHelpAction struct{}
 
-func (a ToggleHelpAction) Type() string { return "toggle_help" }
+func (a CloseHelpAction) Type() string { return "close_help" }
 
 type CreateGroupAction struct {
 	Name string
@@ -101,6 +393,14 @@ type CreateGroupAction struct {
 
 func (a CreateGroupAction) Type() string { return "create_group" }
 
+// CycleGroupTemplateAction picks the next group template for the group being created;
+// Reset goes back to no template
+type CycleGroupTemplateAction struct {
+	Reset bool
+}
+
+func (a CycleGroupTemplateAction) Type() string { return "cycle_group_template" }
+
 type MoveToGroupAction struct {
 	GroupName string
 }
@@ -113,6 +413,22 @@ type DeleteGroupAction struct {
 
 func (a DeleteGroupAction) Type() string { return "delete_group" }
 
+// ConfirmAction asks before running risky actions, in a dialog answered with y or n (they
+// run right away when confirmations are turned off)
+type ConfirmAction struct {
+	Actions []Action
+}
+
+func (a ConfirmAction) Type() string { return "confirm" }
+
+// ResolveConfirmAction answers the confirmation dialog: the actions asked about run when
+// Confirmed, and are dropped otherwise
+type ResolveConfirmAction struct {
+	Confirmed bool
+}
+
+func (a ResolveConfirmAction) Type() string { return "resolve_confirm" }
+
 type RenameGroupAction struct {
 	OldName string
 	NewName string
@@ -136,6 +452,231 @@ type MoveGroupDownAction struct{}
 
 func (a MoveGroupDownAction) Type() string { return "move_group_down" }
 
+// OpenRemoteMigrationAction opens the HTTPS/SSH remote migration preview
+type OpenRemoteMigrationAction struct{}
+
+func (a OpenRemoteMigrationAction) Type() string { return "open_remote_migration" }
+
+// RemoteMigrationNavigateAction moves the cursor in the migration preview
+type RemoteMigrationNavigateAction struct {
+	Delta int
+}
+
+func (a RemoteMigrationNavigateAction) Type() string { return "remote_migration_navigate" }
+
+// ApplyRemoteMigrationAction rewrites the remote under the cursor, or all of them
+type ApplyRemoteMigrationAction struct {
+	All bool
+}
+
+func (a ApplyRemoteMigrationAction) Type() string { return "apply_remote_migration" }
+
+// ToggleRemoteMigrationTargetAction switches the target transport between SSH and HTTPS
+type ToggleRemoteMigrationTargetAction struct{}
+
+func (a ToggleRemoteMigrationTargetAction) Type() string { return "toggle_remote_migration_target" }
+
+// CloseRemoteMigrationAction closes the migration preview
+type CloseRemoteMigrationAction struct{}
+
+func (a CloseRemoteMigrationAction) Type() string { return "close_remote_migration" }
+
+// OpenWatchedChangesAction opens the list of new commits on watched branches
+type OpenWatchedChangesAction struct{}
+
+func (a OpenWatchedChangesAction) Type() string { return "open_watched_changes" }
+
+// AcknowledgeWatchedChangesAction marks every listed commit as seen
+type AcknowledgeWatchedChangesAction struct{}
+
+func (a AcknowledgeWatchedChangesAction) Type() string { return "acknowledge_watched_changes" }
+
+// CloseWatchedChangesAction closes the watched changes list
+type CloseWatchedChangesAction struct{}
+
+func (a CloseWatchedChangesAction) Type() string { return "close_watched_changes" }
+
+// OpenDashboardAction opens the status summary dashboard
+type OpenDashboardAction struct{}
+
+func (a OpenDashboardAction) Type() string { return "open_dashboard" }
+
+// CloseDashboardAction closes the status summary dashboard
+type CloseDashboardAction struct{}
+
+func (a CloseDashboardAction) Type() string { return "close_dashboard" }
+
+// OpenBranchOverviewAction opens the list of branches and the repositories on them
+type OpenBranchOverviewAction struct{}
+
+func (a OpenBranchOverviewAction) Type() string { return "open_branch_overview" }
+
+// CloseBranchOverviewAction closes the branch overview
+type CloseBranchOverviewAction struct{}
+
+func (a CloseBranchOverviewAction) Type() string { return "close_branch_overview" }
+
+// OpenStatusChangesAction opens the list of repositories the last fetch or pull changed
+type OpenStatusChangesAction struct{}
+
+func (a OpenStatusChangesAction) Type() string { return "open_status_changes" }
+
+// CloseStatusChangesAction closes the list of changed repositories
+type CloseStatusChangesAction struct{}
+
+func (a CloseStatusChangesAction) Type() string { return "close_status_changes" }
+
+// OpenHeatmapAction opens the commit activity heatmap of a group ("" for every repository)
+type OpenHeatmapAction struct {
+	GroupName string
+}
+
+func (a OpenHeatmapAction) Type() string { return "open_heatmap" }
+
+// CloseHeatmapAction closes the commit activity heatmap
+type CloseHeatmapAction struct{}
+
+func (a CloseHeatmapAction) Type() string { return "close_heatmap" }
+
+// OpenHealthAction opens the health checks of the selected repositories (or all of them)
+type OpenHealthAction struct{}
+
+func (a OpenHealthAction) Type() string { return "open_health" }
+
+// CloseHealthAction closes the health checks
+type CloseHealthAction struct{}
+
+func (a CloseHealthAction) Type() string { return "close_health" }
+
+// HealthNavigateAction moves the cursor between the repositories with findings
+type HealthNavigateAction struct {
+	Delta int
+}
+
+func (a HealthNavigateAction) Type() string { return "health_navigate" }
+
+// HealthFsckAction runs git fsck on the repository under the health cursor
+type HealthFsckAction struct{}
+
+func (a HealthFsckAction) Type() string { return "health_fsck" }
+
+// RemoveHealthLocksAction removes the leftover lock files of the repository under the
+// health cursor
+type RemoveHealthLocksAction struct{}
+
+func (a RemoveHealthLocksAction) Type() string { return "remove_health_locks" }
+
+// PaletteEntry is an action offered by the command palette
+type PaletteEntry struct {
+	Action      string // name as in [keys]
+	Keys        string // keys bound to it, for display
+	Description string
+}
+
+// ShowPaletteAction shows the command palette's matches for the query, with the cursor
+// on Entries[Index]
+type ShowPaletteAction struct {
+	Entries []PaletteEntry
+	Index   int
+}
+
+func (a ShowPaletteAction) Type() string { return "show_palette" }
+
+// ClosePaletteAction closes the command palette
+type ClosePaletteAction struct{}
+
+func (a ClosePaletteAction) Type() string { return "close_palette" }
+
+// ForgetReposAction removes the selected repositories (or the current one) from the list
+// and the config, leaving them on disk
+type ForgetReposAction struct{}
+
+func (a ForgetReposAction) Type() string { return "forget_repos" }
+
+// PromptArchiveAction previews archiving a repository ("" when the prompt closes)
+type PromptArchiveAction struct {
+	RepoPath string
+}
+
+func (a PromptArchiveAction) Type() string { return "prompt_archive" }
+
+// ArchiveRepoAction bundles the previewed repository and moves it to the archive directory
+type ArchiveRepoAction struct{}
+
+func (a ArchiveRepoAction) Type() string { return "archive_repo" }
+
+// UndoRemoveAction brings back the repositories forgotten or archived last
+type UndoRemoveAction struct{}
+
+func (a UndoRemoveAction) Type() string { return "undo_remove" }
+
+// MarkCompareGroupAction marks a group (or unmarks it) to be compared with another one
+type MarkCompareGroupAction struct {
+	GroupName string
+}
+
+func (a MarkCompareGroupAction) Type() string { return "mark_compare_group" }
+
+// OpenGroupCompareAction shows two groups side by side
+type OpenGroupCompareAction struct {
+	Left  string
+	Right string
+}
+
+func (a OpenGroupCompareAction) Type() string { return "open_group_compare" }
+
+// CloseGroupCompareAction closes the group comparison
+type CloseGroupCompareAction struct{}
+
+func (a CloseGroupCompareAction) Type() string { return "close_group_compare" }
+
+// OpenBulkLabelsAction opens the label editor for the selected repositories
+type OpenBulkLabelsAction struct{}
+
+func (a OpenBulkLabelsAction) Type() string { return "open_bulk_labels" }
+
+// BulkLabelsNavigateAction moves the cursor in the label editor
+type BulkLabelsNavigateAction struct {
+	Delta int
+}
+
+func (a BulkLabelsNavigateAction) Type() string { return "bulk_labels_navigate" }
+
+// ToggleBulkLabelAction cycles the pending change of the label under the cursor
+type ToggleBulkLabelAction struct{}
+
+func (a ToggleBulkLabelAction) Type() string { return "toggle_bulk_label" }
+
+// ApplyBulkLabelsAction applies the pending label changes to every selected repository
+type ApplyBulkLabelsAction struct{}
+
+func (a ApplyBulkLabelsAction) Type() string { return "apply_bulk_labels" }
+
+// CloseBulkLabelsAction closes the label editor
+type CloseBulkLabelsAction struct{}
+
+func (a CloseBulkLabelsAction) Type() string { return "close_bulk_labels" }
+
+// CleanDryRunAction previews what git clean -fdx would remove
+type CleanDryRunAction struct{}
+
+func (a CleanDryRunAction) Type() string { return "clean_dry_run" }
+
+// CleanReposAction runs git clean -fdx after the dry run was confirmed
+type CleanReposAction struct{}
+
+func (a CleanReposAction) Type() string { return "clean_repos" }
+
+// CloseCleanPreviewAction closes the clean dry run preview
+type CloseCleanPreviewAction struct{}
+
+func (a CloseCleanPreviewAction) Type() string { return "close_clean_preview" }
+
+// CancelOperationsAction skips the queued part of running bulk operations
+type CancelOperationsAction struct{}
+
+func (a CancelOperationsAction) Type() string { return "cancel_operations" }
+
 type SearchNavigateAction struct {
 	Direction string // "next" or "prev"
 }
@@ -164,3 +705,28 @@ type UpdateSortIndexAction struct {
 }
 
 func (a UpdateSortIndexAction) Type() string { return "update_sort_index" }
+
+// CycleSortAction switches to the next sort mode
+type CycleSortAction struct{}
+
+func (a CycleSortAction) Type() string { return "cycle_sort" }
+
+// SaveConfigAction writes unsaved group changes to the config
+type SaveConfigAction struct{}
+
+func (a SaveConfigAction) Type() string { return "save_config" }
+
+// Visual range selection
+type StartVisualAction struct{}
+
+func (a StartVisualAction) Type() string { return "start_visual" }
+
+type EndVisualAction struct{}
+
+func (a EndVisualAction) Type() string { return "end_visual" }
+
+type VisualSelectAction struct {
+	Add bool // only add the range to the selection instead of toggling it
+}
+
+func (a VisualSelectAction) Type() string { return "visual_select" }
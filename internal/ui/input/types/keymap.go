@@ -0,0 +1,260 @@
+package types
+
+import (
+	"errors"
+	"fmt"
+	"sort"
+	"strings"
+)
+
+// KeyBinding is a normal-mode action that can be remapped in the [keys] config section
+type KeyBinding struct {
+	Action      string // name used in config, e.g. "fetch"
+	Key         string // default key; normal mode dispatches on this key
+	Description string
+}
+
+// DefaultKeyBindings lists the remappable actions with their default keys
+var DefaultKeyBindings = []KeyBinding{
+	{Action: "up", Key: "k", Description: "Move up"},
+	{Action: "down", Key: "j", Description: "Move down"},
+	{Action: "collapse", Key: "h", Description: "Collapse group"},
+	{Action: "expand", Key: "l", Description: "Expand group"},
+	{Action: "bottom", Key: "G", Description: "Go to bottom"},
+	{Action: "open", Key: "enter", Description: "Open lazygit / toggle group"},
+	{Action: "toggle_group", Key: "z", Description: "Toggle group"},
+	{Action: "move_group_down", Key: "J", Description: "Move group down"},
+	{Action: "move_group_up", Key: "K", Description: "Move group up"},
+	{Action: "select", Key: " ", Description: "Toggle selection"},
+	{Action: "select_group", Key: "a", Description: "Select/deselect all repos in the current group"},
+	{Action: "select_all", Key: "A", Description: "Select all repos"},
+	{Action: "clear_selection", Key: "u", Description: "Clear selection"},
+	{Action: "visual", Key: "V", Description: "Visual range selection"},
+	{Action: "refresh", Key: "r", Description: "Refresh repository status (the whole group on a group header)"},
+	{Action: "scan", Key: "+", Description: "Scan another directory (browse to it, s scans, 1-5 picks a recent one)"},
+	{Action: "clone_org", Key: "o", Description: "Clone repositories of a GitHub or GitLab organization into a group"},
+	{Action: "rescan", Key: "ctrl+r", Description: "Rescan the base directory and the others scanned, dropping repos that are gone"},
+	{Action: "retry", Key: "E", Description: "Retry repositories whose status failed"},
+	{Action: "rename_group", Key: "R", Description: "Rename group"},
+	{Action: "group_color", Key: "c", Description: "Color the group's header and the marker beside its repos (1-8 pick, 0 clears)"},
+	{Action: "fetch", Key: "f", Description: "Fetch from remote (the whole group on a group header)"},
+	{Action: "fetch_options", Key: "O", Description: "Fetch with options (one remote, tags, depth)"},
+	{Action: "pull", Key: "p", Description: "Pull from remote (the whole group on a group header)"},
+	{Action: "push", Key: "P", Description: "Push ahead repos (selection, group or all; confirm the list first)"},
+	{Action: "search", Key: "/", Description: "Search repositories"},
+	{Action: "filter", Key: "F", Description: "Filter repositories"},
+	{Action: "next_match", Key: "n", Description: "Next search result"},
+	{Action: "new_group", Key: "N", Description: "New group (tab picks a template) / previous search result"},
+	{Action: "move", Key: "m", Description: "Move to group"},
+	{Action: "log", Key: "H", Description: "Commit log (/ filters by message or author, a all branches, d date range)"},
+	{Action: "git_log", Key: "L", Description: "Open git log in the pager"},
+	{Action: "unpushed", Key: "v", Description: "Commits not pushed yet (selection, group or current repo)"},
+	{Action: "diff", Key: "D", Description: "View git diff (changes)"},
+	{Action: "info", Key: "i", Description: "Show repository info"},
+	{Action: "logs", Key: "I", Description: "View repository command logs"},
+	{Action: "shell", Key: "!", Description: "Open shell in repository"},
+	{Action: "migrate_remotes", Key: "M", Description: "Migrate origin remotes (HTTPS/SSH)"},
+	{Action: "dashboard", Key: "#", Description: "Status summary dashboard"},
+	{Action: "compare_groups", Key: "C", Description: "Compare two groups side by side (press on both headers)"},
+	{Action: "branches", Key: "B", Description: "Branch overview across all repos"},
+	{Action: "watches", Key: "W", Description: "Review new commits on watched branches"},
+	{Action: "changes", Key: "U", Description: "Show what the last fetch/pull changed"},
+	{Action: "heatmap", Key: "Y", Description: "Commit activity heatmap of the past year (group under the cursor, or all)"},
+	{Action: "health", Key: "T", Description: "Health checks: leftover locks, .git size, shallow clones, fsck (selection or all)"},
+	{Action: "clean", Key: "X", Description: "Clean untracked/ignored files (dry run first)"},
+	{Action: "delete_group", Key: "d", Description: "Delete group"},
+	{Action: "forget", Key: "delete", Description: "Forget repos (remove from the list and config, keep on disk)"},
+	{Action: "archive", Key: "Z", Description: "Archive repo (bundle it and move it to the archive directory)"},
+	{Action: "undo", Key: "ctrl+z", Description: "Undo the last forget or archive"},
+	{Action: "switch_branch", Key: "s", Description: "Switch branch"},
+	{Action: "new_branch", Key: "b", Description: "Create branch"},
+	{Action: "tags", Key: "t", Description: "Edit repository tags (toggle labels across the selection)"},
+	{Action: "show_all", Key: "1", Description: "Show every repo (clear the filter)"},
+	{Action: "only_dirty", Key: "2", Description: "Show only repos with uncommitted changes"},
+	{Action: "only_unsynced", Key: "3", Description: "Show only repos ahead of or behind their upstream"},
+	{Action: "only_errors", Key: "4", Description: "Show only repos whose status failed"},
+	{Action: "sort", Key: "S", Description: "Sort options"},
+	{Action: "cycle_sort", Key: "ctrl+s", Description: "Cycle sort (name, dirty first, ahead/behind, last commit, branch)"},
+	{Action: "save", Key: "w", Description: "Save group changes to the config (when autosave_on_exit is off)"},
+	{Action: "profiles", Key: "Q", Description: "Switch workspace profile (restarts with its base dirs and groups)"},
+	{Action: "palette", Key: "ctrl+p", Description: "Command palette: find an action by name and run it"},
+	{Action: "help", Key: "?", Description: "Show help"},
+	{Action: "quit", Key: "q", Description: "Quit"},
+}
+
+// DefaultQuickBar lists the actions pinned to the footer when ui.quick_bar is not set
+var DefaultQuickBar = []string{"fetch", "pull", "search", "move", "help"}
+
+// quickBarDigits are the keys quick bar slots take, in order, skipping digits bound to an action
+var quickBarDigits = []string{"1", "2", "3", "4", "5", "6", "7", "8", "9"}
+
+// QuickBarSlot is an action pinned to the footer and the digit key that triggers it
+type QuickBarSlot struct {
+	Key    string
+	Action string
+}
+
+// reservedKeys always keep their built-in meaning and cannot be bound
+var reservedKeys = map[string]bool{
+	"ctrl+c": true, "esc": true, "up": true, "down": true, "left": true, "right": true, "g": true,
+}
+
+// KeyMap translates pressed keys into the default keys normal mode handles
+type KeyMap struct {
+	keys    map[string][]string // action -> bound keys
+	resolve map[string]string   // bound key -> default key of its action
+	unbound map[string]bool     // default keys whose action was moved to another key
+	quick   []QuickBarSlot      // actions pinned to the footer
+}
+
+// DefaultKeyMap returns the key map without any overrides
+func DefaultKeyMap() *KeyMap {
+	km, _ := NewKeyMap(nil)
+	return km
+}
+
+// NewKeyMap builds a key map from [keys] overrides (action -> space-separated keys,
+// "space" for the space bar). All problems are reported together: unknown actions,
+// empty bindings, reserved keys and keys bound to more than one action.
+func NewKeyMap(overrides map[string]string) (*KeyMap, error) {
+	km := &KeyMap{
+		keys:    make(map[string][]string),
+		resolve: make(map[string]string),
+		unbound: make(map[string]bool),
+	}
+
+	var errs []error
+	known := make(map[string]bool, len(DefaultKeyBindings))
+	for _, b := range DefaultKeyBindings {
+		known[b.Action] = true
+	}
+	actions := make([]string, 0, len(overrides))
+	for action := range overrides {
+		actions = append(actions, action)
+	}
+	sort.Strings(actions)
+	for _, action := range actions {
+		if !known[action] {
+			errs = append(errs, fmt.Errorf("keys: unknown action %q", action))
+		}
+	}
+
+	owner := make(map[string]string) // key -> action
+	for _, b := range DefaultKeyBindings {
+		keys := []string{b.Key}
+		if value, ok := overrides[b.Action]; ok {
+			keys = parseKeys(value)
+			if len(keys) == 0 {
+				errs = append(errs, fmt.Errorf("keys: no key given for %s", b.Action))
+				continue
+			}
+			km.unbound[b.Key] = true
+		}
+
+		for _, key := range keys {
+			if reservedKeys[key] {
+				errs = append(errs, fmt.Errorf("keys: %q is reserved and cannot be bound to %s", key, b.Action))
+				continue
+			}
+			if other, taken := owner[key]; taken {
+				errs = append(errs, fmt.Errorf("keys: %q is bound to both %s and %s", displayKey(key), other, b.Action))
+				continue
+			}
+			owner[key] = b.Action
+			km.resolve[key] = b.Key
+			km.keys[b.Action] = append(km.keys[b.Action], key)
+		}
+	}
+
+	if len(errs) > 0 {
+		return nil, errors.Join(errs...)
+	}
+	return km, nil
+}
+
+// NewKeyMapWithQuickBar builds a key map from [keys] overrides and pins the given actions
+// to the quick bar (the defaults when empty). Slots take the digits no action is bound to,
+// in order: 5 to 9 while the status filters keep 1 to 4.
+func NewKeyMapWithQuickBar(overrides map[string]string, quickBar []string) (*KeyMap, error) {
+	km, err := NewKeyMap(overrides)
+	if err != nil {
+		return nil, err
+	}
+
+	if len(quickBar) == 0 {
+		quickBar = DefaultQuickBar
+	}
+	var digits []string
+	for _, digit := range quickBarDigits {
+		if _, bound := km.resolve[digit]; !bound {
+			digits = append(digits, digit)
+		}
+	}
+	if len(quickBar) > len(digits) {
+		return nil, fmt.Errorf("ui.quick_bar: at most %d actions can be pinned (digits %s are free), got %d",
+			len(digits), strings.Join(digits, " "), len(quickBar))
+	}
+
+	defaultKeys := make(map[string]string, len(DefaultKeyBindings))
+	for _, b := range DefaultKeyBindings {
+		defaultKeys[b.Action] = b.Key
+	}
+	var errs []error
+	for i, action := range quickBar {
+		if _, known := defaultKeys[action]; !known {
+			errs = append(errs, fmt.Errorf("ui.quick_bar: unknown action %q", action))
+			continue
+		}
+		km.quick = append(km.quick, QuickBarSlot{Key: digits[i], Action: action})
+		km.resolve[digits[i]] = defaultKeys[action]
+	}
+
+	if len(errs) > 0 {
+		return nil, errors.Join(errs...)
+	}
+	return km, nil
+}
+
+// QuickBar returns the actions pinned to the footer in slot order
+func (k *KeyMap) QuickBar() []QuickBarSlot {
+	return k.quick
+}
+
+// Resolve returns the default key to dispatch for a pressed key, or "" when the key's
+// default action was moved elsewhere. Keys outside the key map pass through unchanged.
+func (k *KeyMap) Resolve(key string) string {
+	if target, ok := k.resolve[key]; ok {
+		return target
+	}
+	if k.unbound[key] {
+		return ""
+	}
+	return key
+}
+
+// Keys returns the keys bound to an action, formatted for display
+func (k *KeyMap) Keys(action string) []string {
+	keys := make([]string, 0, len(k.keys[action]))
+	for _, key := range k.keys[action] {
+		keys = append(keys, displayKey(key))
+	}
+	return keys
+}
+
+func parseKeys(value string) []string {
+	var keys []string
+	for _, key := range strings.Fields(value) {
+		if key == "space" {
+			key = " "
+		}
+		keys = append(keys, key)
+	}
+	return keys
+}
+
+func displayKey(key string) string {
+	if key == " " {
+		return "space"
+	}
+	return key
+}
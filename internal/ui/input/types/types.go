@@ -11,11 +11,39 @@ const (
 	ModeFilter
 	ModeNewGroup
 	ModeMoveToGroup
-	ModeDeleteConfirm
+	ModeConfirm
 	ModeSort
 	ModeNewBranch
 	ModeSwitchBranch
 	ModeRenameGroup
+	ModeRemoteMigration
+	ModeCleanConfirm
+	ModeCancelConfirm
+	ModeVisual
+	ModeWatchedChanges
+	ModeEditTags
+	ModeDashboard
+	ModeBranchOverview
+	ModeGroupCompare
+	ModeBulkLabels
+	ModeStatusChanges
+	ModeHeatmap
+	ModeArchiveConfirm
+	ModeHelp
+	ModeCredential
+	ModeFetchOptions
+	ModePushConfirm
+	ModeHealth
+	ModePalette
+	ModeCommitLog
+	ModeUnpushed
+	ModePullPreview
+	ModeProfiles
+	ModeDirBrowser
+	ModeDebugLog
+	ModeGroupColor
+	ModeCloneOrg
+	ModeCloneOrgPick
 )
 
 // Action represents a command the model should execute
@@ -33,8 +61,13 @@ type Context interface {
 	GetRepoPathAtIndex(index int) string
 	IsOnGroup() bool
 	CurrentGroupName() string
+	CurrentRepositoryTags() []string
 	SearchQuery() string
 	GetCurrentSort() string
+	HasBulkOperation() bool
+	CompareGroup() string
+	CredentialSecret() bool
+	CleanReady() bool
 }
 
 // ModeHandler handles input for a specific mode
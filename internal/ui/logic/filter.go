@@ -3,7 +3,7 @@ package logic
 import (
 	"strings"
 
-	"gitagrip/internal/domain"
+	"github.com/darksworm/gitagrip/internal/domain"
 )
 
 // SearchFilter handles search and filter operations
@@ -26,12 +26,16 @@ func (sf *SearchFilter) MatchesFilter(repo *domain.Repository, groupName string,
 
 	query := strings.ToLower(filterQuery)
 
-	// Check if it's a status filter
-	if strings.HasPrefix(query, "status:") {
-		statusFilter := strings.TrimPrefix(query, "status:")
+	// Check if it's a status filter (status:dirty or is:dirty)
+	if statusFilter, ok := CutStatusPrefix(query); ok {
 		return sf.MatchesStatusFilter(repo, statusFilter)
 	}
 
+	// Check if it's a tag filter
+	if tag, ok := strings.CutPrefix(query, "tag:"); ok {
+		return repo.HasTag(tag)
+	}
+
 	// Regular filter - check name, path, branch, group
 	return strings.Contains(strings.ToLower(repo.Name), query) ||
 		strings.Contains(strings.ToLower(repo.Path), query) ||
@@ -45,8 +49,8 @@ func (sf *SearchFilter) MatchesGroupFilter(groupName string, filterQuery string)
 		return true
 	}
 
-	// Status filters don't match group names
-	if strings.HasPrefix(filterQuery, "status:") {
+	// Status and tag filters don't match group names
+	if _, ok := CutStatusPrefix(filterQuery); ok || strings.HasPrefix(filterQuery, "tag:") {
 		return false
 	}
 
@@ -54,6 +58,14 @@ func (sf *SearchFilter) MatchesGroupFilter(groupName string, filterQuery string)
 	return strings.Contains(strings.ToLower(groupName), query)
 }
 
+// CutStatusPrefix strips the "status:" or "is:" prefix of a status filter
+func CutStatusPrefix(query string) (string, bool) {
+	if filter, ok := strings.CutPrefix(query, "status:"); ok {
+		return filter, true
+	}
+	return strings.CutPrefix(query, "is:")
+}
+
 // MatchesStatusFilter checks if a repo matches the given status filter
 func (sf *SearchFilter) MatchesStatusFilter(repo *domain.Repository, filter string) bool {
 	switch filter {
@@ -69,8 +81,12 @@ func (sf *SearchFilter) MatchesStatusFilter(repo *domain.Repository, filter stri
 		return repo.Status.BehindCount > 0
 	case "diverged":
 		return repo.Status.AheadCount > 0 && repo.Status.BehindCount > 0
+	case "unsynced":
+		return repo.Status.AheadCount > 0 || repo.Status.BehindCount > 0
 	case "error":
 		return repo.Status.Error != ""
+	case "stale":
+		return repo.Status.StaleReason != ""
 	default:
 		// Check if it's a branch name
 		return strings.Contains(strings.ToLower(repo.Status.Branch), filter)
@@ -98,12 +114,7 @@ func (sf *SearchFilter) PerformSearch(query string, orderedGroups []string, grou
 	currentIndex := 0
 
 	// Check if it's a status filter
-	isStatusFilter := false
-	statusFilter := ""
-	if strings.HasPrefix(lowerQuery, "status:") {
-		isStatusFilter = true
-		statusFilter = strings.TrimPrefix(lowerQuery, "status:")
-	}
+	statusFilter, isStatusFilter := CutStatusPrefix(lowerQuery)
 
 	// Search in groups first
 	for _, groupName := range orderedGroups {
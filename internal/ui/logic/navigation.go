@@ -1,7 +1,7 @@
 package logic
 
 import (
-	"gitagrip/internal/domain"
+	"github.com/darksworm/gitagrip/internal/domain"
 )
 
 // Navigator handles navigation and viewport management
@@ -57,23 +57,7 @@ func (n *Navigator) SetSelectedIndex(index int) (int, int) {
 
 // GetMaxIndex returns the maximum selectable index
 func (n *Navigator) GetMaxIndex(ungroupedReposCount int) int {
-	count := len(n.orderedGroups) + ungroupedReposCount
-	for groupName, group := range n.groups {
-		if n.expandedGroups[groupName] {
-			count += len(group.Repos)
-		}
-	}
-
-	// Add gaps after each group (except hidden at the end)
-	nonHiddenGroups := 0
-	for _, groupName := range n.orderedGroups {
-		if groupName != "_Hidden" {
-			nonHiddenGroups++
-		}
-	}
-	count += nonHiddenGroups // One gap per non-hidden group
-
-	return count - 1
+	return n.countItems(ungroupedReposCount) - 1
 }
 
 // EnsureSelectedVisible adjusts the viewport to keep the selected item visible
@@ -156,22 +140,25 @@ func (n *Navigator) ensureSelectedVisible() {
 
 // calculateTotalItems calculates the total number of visible items
 func (n *Navigator) calculateTotalItems() int {
+	return n.countItems(n.ungroupedRepoCount)
+}
+
+// countItems counts the list lines: each group's header, its repositories when expanded
+// and a gap (except after the hidden group at the end), then the ungrouped repositories
+func (n *Navigator) countItems(ungroupedReposCount int) int {
 	totalItems := 0
-	// Groups first
-	for _, groupName := range n.orderedGroups {
+	for i, groupName := range n.orderedGroups {
 		totalItems++ // Group header
 		if n.expandedGroups[groupName] {
-			group := n.groups[groupName]
-			totalItems += len(group.Repos)
+			if group, ok := n.groups[groupName]; ok {
+				totalItems += len(group.Repos)
+			}
 		}
-		// Account for gap after group (except hidden at the end)
-		if groupName != "_Hidden" {
+		if groupName != "_Hidden" || i != len(n.orderedGroups)-1 {
 			totalItems++ // Gap after group
 		}
 	}
-	// Add ungrouped repos
-	totalItems += n.ungroupedRepoCount
-	return totalItems
+	return totalItems + ungroupedReposCount
 }
 
 // CalculateTotalItemsWithUngrouped calculates total items including ungrouped repos
@@ -1,10 +1,11 @@
 package logic
 
 import (
+	"fmt"
 	"sort"
 	"strings"
 
-	"gitagrip/internal/domain"
+	"github.com/darksworm/gitagrip/internal/domain"
 )
 
 // SortMode represents different sort modes
@@ -14,8 +15,66 @@ const (
 	SortByName SortMode = iota
 	SortByStatus
 	SortByBranch
+	SortByAheadBehind
+	SortByLastCommit
 )
 
+// sortModes lists the sort modes in the order 'S' cycles through them, with their
+// names in the config (ui.sort) and their labels on screen
+var sortModes = []struct {
+	mode  SortMode
+	key   string
+	label string
+}{
+	{SortByName, "name", "name"},
+	{SortByStatus, "status", "dirty first"},
+	{SortByAheadBehind, "ahead-behind", "ahead/behind"},
+	{SortByLastCommit, "last-commit", "last commit"},
+	{SortByBranch, "branch", "branch"},
+}
+
+// ParseSortMode looks up a sort mode by its config name
+func ParseSortMode(key string) (SortMode, error) {
+	keys := make([]string, 0, len(sortModes))
+	for _, m := range sortModes {
+		if m.key == strings.ToLower(key) {
+			return m.mode, nil
+		}
+		keys = append(keys, m.key)
+	}
+	return SortByName, fmt.Errorf("unknown sort %q (available: %s)", key, strings.Join(keys, ", "))
+}
+
+// String returns the config name of the sort mode
+func (s SortMode) String() string {
+	for _, m := range sortModes {
+		if m.mode == s {
+			return m.key
+		}
+	}
+	return "name"
+}
+
+// Label describes the sort mode for the status line
+func (s SortMode) Label() string {
+	for _, m := range sortModes {
+		if m.mode == s {
+			return m.label
+		}
+	}
+	return "name"
+}
+
+// Next returns the sort mode 'S' switches to from this one
+func (s SortMode) Next() SortMode {
+	for i, m := range sortModes {
+		if m.mode == s {
+			return sortModes[(i+1)%len(sortModes)].mode
+		}
+	}
+	return SortByName
+}
+
 // RepositorySorter handles repository sorting logic
 type RepositorySorter struct {
 	repositories map[string]*domain.Repository
@@ -37,6 +96,10 @@ func (s *RepositorySorter) SortRepositories(repoPaths []string, mode SortMode) {
 		s.sortByStatus(repoPaths)
 	case SortByBranch:
 		s.sortByBranch(repoPaths)
+	case SortByAheadBehind:
+		s.sortByAheadBehind(repoPaths)
+	case SortByLastCommit:
+		s.sortByLastCommit(repoPaths)
 	default:
 		// Default to alphabetical by path
 		sort.Strings(repoPaths)
@@ -97,6 +160,41 @@ func (s *RepositorySorter) sortByBranch(repoPaths []string) {
 	})
 }
 
+// sortByAheadBehind puts the repositories furthest from their upstream first
+func (s *RepositorySorter) sortByAheadBehind(repoPaths []string) {
+	sort.Slice(repoPaths, func(i, j int) bool {
+		repoI, okI := s.repositories[repoPaths[i]]
+		repoJ, okJ := s.repositories[repoPaths[j]]
+		if !okI || !okJ {
+			return !okI
+		}
+		driftI := repoI.Status.AheadCount + repoI.Status.BehindCount
+		driftJ := repoJ.Status.AheadCount + repoJ.Status.BehindCount
+		if driftI != driftJ {
+			return driftI > driftJ
+		}
+		return strings.ToLower(repoI.Name) < strings.ToLower(repoJ.Name)
+	})
+}
+
+// sortByLastCommit puts the most recently committed-to repositories first
+// (repositories without commits or without a status yet go last)
+func (s *RepositorySorter) sortByLastCommit(repoPaths []string) {
+	sort.Slice(repoPaths, func(i, j int) bool {
+		repoI, okI := s.repositories[repoPaths[i]]
+		repoJ, okJ := s.repositories[repoPaths[j]]
+		if !okI || !okJ {
+			return !okI
+		}
+		timeI := repoI.Status.LastCommitTime
+		timeJ := repoJ.Status.LastCommitTime
+		if !timeI.Equal(timeJ) {
+			return timeI.After(timeJ)
+		}
+		return strings.ToLower(repoI.Name) < strings.ToLower(repoJ.Name)
+	})
+}
+
 // GetStatusPriority returns a priority value for sorting by status
 func GetStatusPriority(repo *domain.Repository) int {
 	if repo.Status.Error != "" {
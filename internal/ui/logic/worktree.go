@@ -0,0 +1,43 @@
+package logic
+
+import (
+	"github.com/darksworm/gitagrip/internal/domain"
+)
+
+// NestWorktrees reorders repoPaths so that linked worktrees directly follow their
+// main repository, keeping the relative order of everything else. Worktrees whose
+// main repository is not in the list keep their position.
+func NestWorktrees(repoPaths []string, repositories map[string]*domain.Repository) []string {
+	inList := make(map[string]bool, len(repoPaths))
+	for _, path := range repoPaths {
+		inList[path] = true
+	}
+
+	// Collect nested worktrees per main repository, in current order
+	worktrees := make(map[string][]string)
+	for _, path := range repoPaths {
+		if repo, ok := repositories[path]; ok && repo.WorktreeOf != "" && inList[repo.WorktreeOf] {
+			worktrees[repo.WorktreeOf] = append(worktrees[repo.WorktreeOf], path)
+		}
+	}
+	if len(worktrees) == 0 {
+		return repoPaths
+	}
+
+	nested := make([]string, 0, len(repoPaths))
+	for _, path := range repoPaths {
+		if IsNestedWorktree(path, repositories, inList) {
+			continue
+		}
+		nested = append(nested, path)
+		nested = append(nested, worktrees[path]...)
+	}
+	return nested
+}
+
+// IsNestedWorktree reports whether path is a linked worktree whose main repository
+// is part of the same list (as given by inList)
+func IsNestedWorktree(path string, repositories map[string]*domain.Repository, inList map[string]bool) bool {
+	repo, ok := repositories[path]
+	return ok && repo.WorktreeOf != "" && inList[repo.WorktreeOf]
+}
@@ -3,7 +3,9 @@ package ui
 import (
 	"time"
 
-	"gitagrip/internal/eventbus"
+	"github.com/darksworm/gitagrip/internal/eventbus"
+	"github.com/darksworm/gitagrip/internal/health"
+	"github.com/darksworm/gitagrip/internal/ui/state"
 )
 
 // EventMsg wraps a domain event for the UI
@@ -11,6 +13,11 @@ type EventMsg struct {
 	Event eventbus.DomainEvent
 }
 
+// EventBatchMsg carries the domain events that arrived within one frame, in order
+type EventBatchMsg struct {
+	Events []eventbus.DomainEvent
+}
+
 // tickMsg is sent on a timer for animations
 type tickMsg time.Time
 
@@ -40,12 +47,81 @@ type gitDiffPagerMsg struct {
 	err      error
 }
 
+// cleanDryRunMsg contains the result of a git clean dry run across repositories
+type cleanDryRunMsg struct {
+	results []cleanDryRunResult
+}
+
+// cleanDryRunResult lists what git clean -fdx would remove from one repository
+type cleanDryRunResult struct {
+	repoPath string
+	entries  []string
+	err      error
+}
+
+// compareTagsMsg contains the latest tag of each repository in a group comparison
+type compareTagsMsg struct {
+	tags map[string]string // repo path -> tag ("" if untagged)
+}
+
+// pullPreviewMsg contains what pulling each repository of a bulk pull would do
+type pullPreviewMsg struct {
+	outlooks map[string]state.PullOutlook // repo path -> outlook
+}
+
+// unpushedMsg contains the unpushed commits of repositories, and why reading them failed
+// for some
+type unpushedMsg struct {
+	commits map[string][]state.Commit // repo path -> commits
+	errs    map[string]string         // repo path -> error
+}
+
+// commitLogMsg contains the commits of a repository read for the commit log view
+type commitLogMsg struct {
+	repo    string
+	filter  state.LogFilter
+	commits []state.Commit
+	err     error
+}
+
+// repoDetailsMsg contains the details of a repository read for the info popup
+type repoDetailsMsg struct {
+	path    string
+	details state.RepoDetails
+}
+
+// activityMsg contains the commits per day of repositories for the activity heatmap
+type activityMsg struct {
+	activity map[string]state.RepoActivity // repo path -> activity
+}
+
+// healthMsg contains health reports of repositories, and the error of removing lock files
+// before checking again
+type healthMsg struct {
+	reports []health.Report
+	err     error
+}
+
+// archiveMsg contains the result of archiving a repository
+type archiveMsg struct {
+	repoPath   string
+	archivedTo string
+	bundle     string
+	err        error
+}
+
 // lazygitExitMsg contains the result of launching lazygit
 type lazygitExitMsg struct {
 	repoPath string
 	err      error
 }
 
+// shellExitMsg contains the result of opening a shell in a repository
+type shellExitMsg struct {
+	repoPath string
+	err      error
+}
+
 // quitMsg signals that the application should quit
 type quitMsg struct {
 	saveConfig bool
@@ -1,8 +1,10 @@
 package ui
 
 import (
+	"errors"
 	"fmt"
 	"log"
+	"os"
 	"sort"
 	"strings"
 	"time"
@@ -12,18 +14,24 @@ import (
 	tea "github.com/charmbracelet/bubbletea/v2"
 	"github.com/charmbracelet/lipgloss/v2"
 
-	"gitagrip/internal/config"
-	"gitagrip/internal/domain"
-	"gitagrip/internal/eventbus"
-	"gitagrip/internal/ui/commands"
-	"gitagrip/internal/ui/handlers"
-	"gitagrip/internal/ui/input"
-	inputtypes "gitagrip/internal/ui/input/types"
-	"gitagrip/internal/ui/logic"
-	"gitagrip/internal/ui/repositories"
-	"gitagrip/internal/ui/state"
-	"gitagrip/internal/ui/viewmodels"
-	"gitagrip/internal/ui/views"
+	"github.com/darksworm/gitagrip/internal/config"
+	"github.com/darksworm/gitagrip/internal/datefmt"
+	"github.com/darksworm/gitagrip/internal/devenv"
+	"github.com/darksworm/gitagrip/internal/domain"
+	"github.com/darksworm/gitagrip/internal/eventbus"
+	"github.com/darksworm/gitagrip/internal/git"
+	"github.com/darksworm/gitagrip/internal/paths"
+	"github.com/darksworm/gitagrip/internal/session"
+	"github.com/darksworm/gitagrip/internal/ui/commands"
+	"github.com/darksworm/gitagrip/internal/ui/handlers"
+	"github.com/darksworm/gitagrip/internal/ui/input"
+	inputtypes "github.com/darksworm/gitagrip/internal/ui/input/types"
+	"github.com/darksworm/gitagrip/internal/ui/logic"
+	"github.com/darksworm/gitagrip/internal/ui/repositories"
+	"github.com/darksworm/gitagrip/internal/ui/state"
+	"github.com/darksworm/gitagrip/internal/ui/termcaps"
+	"github.com/darksworm/gitagrip/internal/ui/viewmodels"
+	"github.com/darksworm/gitagrip/internal/ui/views"
 )
 
 // Special group name for hidden repositories
@@ -42,10 +50,15 @@ type Model struct {
 	width  int
 	height int
 	help   help.Model
-	// Removed: inputMode, textInput, deleteTarget - now handled by input handler
+	keys   *inputtypes.KeyMap // key bindings after [keys] and ui.quick_bar
+	// Removed: inputMode, textInput - now handled by input handler
 	currentSort logic.SortMode // current sort mode
 	// Removed: useNewInput - fully migrated to new input handler
 	inPagerMode bool // tracks if we're currently in pager mode
+	// Template picked with tab while naming a new group
+	groupTemplate string
+	// Actions waiting for an answer in the confirmation dialog
+	confirming []inputtypes.Action
 
 	// Handlers
 	searchFilter *logic.SearchFilter          // search and filter handler
@@ -57,29 +70,216 @@ type Model struct {
 	cmdExecutor  *commands.Executor           // command executor
 	inputHandler *input.Handler               // input handling
 	gitOps       *GitOps                      // git operations handler
+	dates        *datefmt.Formatter           // formats dates per [ui.dates] and locale
 
 	// Program reference for terminal management
 	program *tea.Program
+
+	// `gitagrip jump`: enter picks a repository and quits instead of opening lazygit
+	jump     bool
+	jumpPath string
+
+	// Workspace profile in use, and the one picked to restart with
+	profile     string
+	nextProfile string
+
+	// Session of the previous run whose cursor waits for its item to be discovered (nil once placed)
+	resume *session.State
+
+	// Directories scanned with + (newest first, kept between runs), the ones scanned in this
+	// run, and the directories of the rescan under way (nil when none is)
+	recentScans []string
+	scannedDirs []string
+	rescanRoots []string
+
+	// What View draws, shared by the frames drawn until the next update changes the state:
+	// the repositories outside any group, the view state and the list layout (nil when stale)
+	ungrouped []string
+	viewState *views.ViewState
+	rows      []views.ListRow
+
+	// Repositories last reported as on screen and under the cursor, and whether any report
+	// went out yet
+	visible         []string
+	visibleCursor   string
+	visibleReported bool
+
+	// Repository shown in the details pane on wide terminals
+	pane detailsPane
+
+	// Hands a copy of each changed config to the saver and the services (nil when nobody
+	// else reads it). The model is the only one that changes the config.
+	shareConfig func(*config.Config)
+}
+
+// ValidateConfig reports configuration problems that should stop startup,
+// such as conflicting key bindings or an unknown theme palette
+func ValidateConfig(cfg *config.Config) error {
+	_, keysErr := inputtypes.NewKeyMapWithQuickBar(cfg.Keys, cfg.UISettings.QuickBar)
+	_, themeErr := resolveTheme(cfg)
+	_, datesErr := datefmt.New(cfg.UISettings.Dates, os.Environ())
+	_, sortErr := parseSort(cfg.UISettings.Sort)
+	_, staleErr := cfg.Stale.MaxAge()
+	_, backendErr := cfg.Git.BackendName()
+	statusErr := cfg.Status.Validate()
+	_, columnsErr := views.ParseColumns(cfg.UISettings.Columns)
+	_, groupColorsErr := views.ResolveGroupColors(cfg.GroupColors)
+	iconsErr := views.CheckIcons(cfg.UISettings.Icons)
+	scanErr := cfg.Scan.Validate()
+	startupErr := cfg.ValidateGroupStartup()
+	return errors.Join(keysErr, themeErr, datesErr, sortErr, staleErr, backendErr, statusErr, columnsErr, groupColorsErr, iconsErr, scanErr, startupErr)
+}
+
+// resolveTheme builds the color theme from [theme]
+func resolveTheme(cfg *config.Config) (views.Theme, error) {
+	t := cfg.Theme
+	return views.ResolveTheme(t.Palette, views.Theme{
+		Title:          t.Title,
+		Accent:         t.Accent,
+		Heading:        t.Heading,
+		Key:            t.Key,
+		Text:           t.Text,
+		Muted:          t.Muted,
+		Border:         t.Border,
+		PopupBorder:    t.PopupBorder,
+		Faded:          t.Faded,
+		Error:          t.Error,
+		Warning:        t.Warning,
+		Success:        t.Success,
+		Info:           t.Info,
+		Highlight:      t.Highlight,
+		Cursor:         t.Cursor,
+		Selected:       t.Selected,
+		CursorSelected: t.CursorSelected,
+		MainBranch:     t.MainBranch,
+		DevBranch:      t.DevBranch,
+		BranchColors:   t.BranchColors,
+	})
+}
+
+// themeFromConfig builds the color theme, logging problems and keeping whatever resolved
+func themeFromConfig(cfg *config.Config) views.Theme {
+	theme, err := resolveTheme(cfg)
+	if err != nil {
+		log.Printf("Ignoring invalid theme settings: %v", err)
+	}
+	return theme
+}
+
+// datesFromConfig builds the date formatter from [ui.dates], ignoring invalid settings
+func datesFromConfig(cfg *config.Config) *datefmt.Formatter {
+	dates, err := datefmt.New(cfg.UISettings.Dates, os.Environ())
+	if err != nil {
+		log.Printf("Ignoring invalid date settings: %v", err)
+	}
+	return dates
+}
+
+// parseSort reads ui.sort (by name when unset)
+func parseSort(name string) (logic.SortMode, error) {
+	if name == "" {
+		return logic.SortByName, nil
+	}
+	mode, err := logic.ParseSortMode(name)
+	if err != nil {
+		return mode, fmt.Errorf("ui.sort: %w", err)
+	}
+	return mode, nil
+}
+
+// sortFromConfig returns the sort saved in ui.sort, logging an invalid one
+func sortFromConfig(cfg *config.Config) logic.SortMode {
+	mode, err := parseSort(cfg.UISettings.Sort)
+	if err != nil {
+		log.Printf("Ignoring invalid sort: %v", err)
+	}
+	return mode
+}
+
+// sortLabel is what the title bar shows about the sort ("" when sorting by name)
+func sortLabel(mode logic.SortMode) string {
+	if mode == logic.SortByName {
+		return ""
+	}
+	return mode.Label()
+}
+
+// keyMapFromConfig builds the key map from [keys] and ui.quick_bar, falling back to the
+// defaults if it is invalid
+func keyMapFromConfig(cfg *config.Config) *inputtypes.KeyMap {
+	keys, err := inputtypes.NewKeyMapWithQuickBar(cfg.Keys, cfg.UISettings.QuickBar)
+	if err != nil {
+		log.Printf("Ignoring invalid key bindings: %v", err)
+		keys, _ = inputtypes.NewKeyMapWithQuickBar(nil, nil)
+	}
+	return keys
+}
+
+// quickBarItems labels the quick bar slots for the footer
+func quickBarItems(keys *inputtypes.KeyMap) []views.QuickAction {
+	var items []views.QuickAction
+	for _, slot := range keys.QuickBar() {
+		items = append(items, views.QuickAction{Key: slot.Key, Label: strings.ReplaceAll(slot.Action, "_", " ")})
+	}
+	return items
 }
 
 // NewModel creates a new UI model
-func NewModel(bus eventbus.EventBus, cfg *config.Config) *Model {
+func NewModel(bus eventbus.EventBus, cfg *config.Config, caps termcaps.Capabilities) *Model {
 	appState := state.NewAppState()
+	keys := keyMapFromConfig(cfg)
 
 	m := &Model{
 		bus:    bus,
 		config: cfg,
 		state:  appState,
 		help:   help.New(),
+		keys:   keys,
 		// Removed: textInput - now handled by input handler
 		// Removed: inputMode - now handled by input handler
-		currentSort:  logic.SortByName,
+		currentSort:  sortFromConfig(cfg),
 		searchFilter: logic.NewSearchFilter(nil), // Will be updated when repos are added
 		navigator:    logic.NewNavigator(),
-		renderer:     views.NewRenderer(cfg.UISettings.ShowAheadBehind),
-		inputHandler: input.New(),
+		renderer:     views.NewRenderer(cfg.UISettings.ShowAheadBehind, themeFromConfig(cfg)),
+		inputHandler: input.NewWithKeyMap(keys),
+		dates:        datesFromConfig(cfg),
 	}
 
+	// Fall back to ASCII glyphs when the terminal can't render Unicode,
+	// unless a preset or icon set was chosen explicitly
+	glyphCfg := cfg.UISettings.Glyphs
+	preset := glyphCfg.Preset
+	if preset == "" {
+		preset = cfg.UISettings.Icons
+	}
+	if preset == "" && !caps.Unicode {
+		preset = "ascii"
+	}
+	m.renderer.SetTerminalCapabilities(caps)
+	m.renderer.SetShowLastCommit(cfg.UISettings.ShowLastCommit)
+	if columns, err := views.ParseColumns(cfg.UISettings.Columns); err != nil {
+		log.Printf("Ignoring invalid columns: %v", err)
+	} else {
+		m.renderer.SetColumns(columns)
+	}
+	m.renderer.SetGlyphs(views.ResolveGlyphs(preset, views.Glyphs{
+		Dirty:   glyphCfg.Dirty,
+		Clean:   glyphCfg.Clean,
+		Loading: glyphCfg.Loading,
+		Unknown: glyphCfg.Unknown,
+		Ahead:   glyphCfg.Ahead,
+		Behind:  glyphCfg.Behind,
+
+		Staged:     glyphCfg.Staged,
+		Modified:   glyphCfg.Modified,
+		Untracked:  glyphCfg.Untracked,
+		Conflicted: glyphCfg.Conflicted,
+		Stashed:    glyphCfg.Stashed,
+
+		Gutter: glyphCfg.Gutter,
+		Branch: glyphCfg.Branch,
+	}))
+
 	// Create event handler with reference to updateOrderedLists method
 	m.eventHandler = handlers.NewEventHandler(appState, m.updateOrderedLists)
 
@@ -87,15 +287,19 @@ func NewModel(bus eventbus.EventBus, cfg *config.Config) *Model {
 	m.store = repositories.NewStateRepositoryStore(appState)
 
 	// Create command executor
-	m.cmdExecutor = commands.NewExecutor(appState, bus)
+	m.cmdExecutor = commands.NewExecutor(appState, bus, m.configChanged)
 
 	// Create git operations handler
 	m.gitOps = NewGitOps()
+	m.gitOps.SetEnvironmentActivator(cfg.Environment.Activator)
 
 	// Create view model with a placeholder text input (actual one is in input handler)
 	placeholderTextInput := textinput.New()
 	m.viewModel = viewmodels.NewViewModel(appState, cfg, placeholderTextInput)
 	m.viewModel.SetHelp(m.help)
+	m.viewModel.SetQuickBar(quickBarItems(keys))
+
+	m.state.SortLabel = sortLabel(m.currentSort)
 
 	// Initialize groups from config
 	for name, repoPaths := range cfg.Groups {
@@ -130,7 +334,9 @@ func NewModel(bus eventbus.EventBus, cfg *config.Config) *Model {
 	if _, exists := m.state.Groups[HiddenGroupName]; exists {
 		m.state.ExpandedGroups[HiddenGroupName] = false
 	}
+	m.collapseLazyGroups()
 	m.updateOrderedLists()
+	m.reportVisibleRepos()
 
 	// Update searchFilter with the actual repositories map
 	m.searchFilter = logic.NewSearchFilter(m.state.Repositories)
@@ -147,6 +353,39 @@ func (m *Model) SetProgram(p *tea.Program) {
 	}
 }
 
+// jumpHint is the status line of jump mode
+const jumpHint = "enter: pick a repository to cd into • q: cancel"
+
+// SetJumpMode turns the TUI into a repository picker for `gitagrip jump`: enter on a
+// repository quits and leaves its path in JumpPath
+func (m *Model) SetJumpMode(jump bool) {
+	m.jump = jump
+	if jump {
+		m.state.StatusMessage = jumpHint
+	}
+}
+
+// ShareConfig makes every change to the config reach share as a copy of it, which the
+// saver and the services read on their own goroutines
+func (m *Model) ShareConfig(share func(*config.Config)) {
+	m.shareConfig = share
+}
+
+// JumpPath returns the repository picked in jump mode ("" when none was)
+func (m *Model) JumpPath() string {
+	return m.jumpPath
+}
+
+// SetProfile names the workspace profile in use ("" when started without one)
+func (m *Model) SetProfile(name string) {
+	m.profile = name
+}
+
+// NextProfile returns the profile picked in the switcher, to restart with ("" when none was)
+func (m *Model) NextProfile() string {
+	return m.nextProfile
+}
+
 // syncNavigatorState updates the navigator with current model state
 func (m *Model) syncNavigatorState() {
 	ungroupedCount := len(m.getUngroupedRepos())
@@ -173,14 +412,50 @@ func (m *Model) Init() tea.Cmd {
 
 // Update handles messages
 func (m *Model) Update(msg tea.Msg) (tea.Model, tea.Cmd) {
+	model, cmd := m.update(msg)
+	// Any message but the redraw tick may have scrolled the list or changed what it shows
+	if _, ok := msg.(tickMsg); !ok {
+		m.reportVisibleRepos()
+	}
+	return model, cmd
+}
+
+// update applies a message to the model
+func (m *Model) update(msg tea.Msg) (tea.Model, tea.Cmd) {
+	// Any message but the redraw tick may change what the list shows
+	if _, ok := msg.(tickMsg); !ok {
+		m.dropViewCache()
+	}
+
 	switch msg := msg.(type) {
 	case tea.WindowSizeMsg:
 		m.width = msg.Width
 		m.height = msg.Height
 		m.help.Width = msg.Width
 		m.updateViewportHeight()
+		m.resumeCursor()
+		// The help's columns depend on the terminal's size
+		if m.inputHandler.CurrentMode() == inputtypes.ModeHelp {
+			m.state.InfoContent = m.buildHelpContent()
+		}
+
+	case ControlMsg:
+		message, cmd, err := m.handleControl(msg.Request)
+		msg.Reply <- ControlResult{Message: message, Err: err}
+		return m, cmd
+
+	case tea.MouseMsg:
+		if m.resume != nil {
+			m.finishResume()
+		}
+		return m, m.handleMouse(msg)
 
 	case tea.KeyMsg:
+		// Once the user moves on, the cursor of the previous run no longer matters
+		if m.resume != nil {
+			m.finishResume()
+		}
+
 		// Handle log/info/help popups first
 		if m.state.ShowLog {
 			switch msg.String() {
@@ -191,7 +466,7 @@ func (m *Model) Update(msg tea.Msg) (tea.Model, tea.Cmd) {
 			}
 		}
 
-		if m.state.ShowInfo {
+		if m.state.ShowInfo && m.inputHandler.CurrentMode() == inputtypes.ModeNormal {
 			switch msg.String() {
 			case "esc", "i", "q":
 				m.state.ShowInfo = false
@@ -202,10 +477,11 @@ func (m *Model) Update(msg tea.Msg) (tea.Model, tea.Cmd) {
 
 		// Create context for input handler
 		ctx := &input.ModelContext{
-			State:       m.state,
-			Store:       m.store,
-			Navigator:   m.navigator,
-			CurrentSort: m.currentSort,
+			State:          m.state,
+			Store:          m.store,
+			Navigator:      m.navigator,
+			CurrentSort:    m.currentSort,
+			UngroupedRepos: m.getUngroupedRepos(),
 		}
 
 		// Handle input through the new handler
@@ -222,6 +498,10 @@ func (m *Model) Update(msg tea.Msg) (tea.Model, tea.Cmd) {
 				cmds = append(cmds, actionCmd)
 			}
 		}
+		// A credential prompt that waited for the keyboard gets it now
+		if promptCmd := m.showCredentialPrompt(); promptCmd != nil {
+			cmds = append(cmds, promptCmd)
+		}
 
 		// Update text input in view model if in text mode
 		if m.inputHandler.TextInput() != nil {
@@ -258,10 +538,57 @@ func (m *Model) View() string {
 	if m.width == 0 {
 		return "Loading..."
 	}
+	viewState := m.cachedViewState()
+	// A prompt makes the header taller; keep the cursor within the rows that are left
+	if height := m.renderer.ListHeight(viewState); height != m.state.ViewportHeight {
+		m.state.ViewportHeight = height
+		m.ensureSelectedVisible()
+		m.dropViewCache()
+		viewState = m.cachedViewState()
+	}
+	if m.twoPane() {
+		viewState.DetailsPane = m.buildDetailsPane()
+	}
+	return m.renderer.Render(viewState)
+}
+
+// cachedViewState returns the view state with its list rows, built once after each update
+// that may change them, so that the frames in between don't go over every repository
+func (m *Model) cachedViewState() views.ViewState {
+	if m.viewState == nil {
+		if m.ungrouped == nil {
+			m.ungrouped = m.getUngroupedRepos()
+			if m.ungrouped == nil {
+				m.ungrouped = []string{}
+			}
+		}
+		viewState := m.viewStateWith(m.ungrouped)
+		m.viewState = &viewState
+	}
+	if m.rows == nil {
+		m.rows = m.renderer.BuildRows(*m.viewState)
+	}
+	viewState := *m.viewState
+	viewState.Rows = m.rows
+	return viewState
+}
 
+// dropViewCache makes the next frame rebuild the view state and the list rows
+func (m *Model) dropViewCache() {
+	m.ungrouped = nil
+	m.viewState = nil
+	m.rows = nil
+}
+
+// buildViewState gathers the current UI state for rendering
+func (m *Model) buildViewState() views.ViewState {
+	return m.viewStateWith(m.getUngroupedRepos())
+}
+
+// viewStateWith gathers the current UI state for rendering with the given ungrouped repositories
+func (m *Model) viewStateWith(ungrouped []string) views.ViewState {
 	// Update view model with current UI state
 	m.viewModel.SetDimensions(m.width, m.height)
-	// deleteTarget now handled by input handler
 
 	// Use input handler's state
 	if m.inputHandler != nil {
@@ -275,16 +602,35 @@ func (m *Model) View() string {
 			viewModelMode = viewmodels.InputModeSearch
 		case inputtypes.ModeFilter:
 			viewModelMode = viewmodels.InputModeFilter
+		case inputtypes.ModeSort:
+			viewModelMode = viewmodels.InputModeSort
 		case inputtypes.ModeNewGroup:
 			viewModelMode = viewmodels.InputModeNewGroup
 		case inputtypes.ModeMoveToGroup:
 			viewModelMode = viewmodels.InputModeMoveToGroup
-		case inputtypes.ModeDeleteConfirm:
-			viewModelMode = viewmodels.InputModeDeleteConfirm
-		case inputtypes.ModeSort:
-			viewModelMode = viewmodels.InputModeSort
+		case inputtypes.ModeConfirm:
+			viewModelMode = viewmodels.InputModeConfirm
 		case inputtypes.ModeRenameGroup:
 			viewModelMode = viewmodels.InputModeRenameGroup
+		case inputtypes.ModeCleanConfirm:
+			viewModelMode = viewmodels.InputModeCleanConfirm
+		case inputtypes.ModeCancelConfirm:
+			viewModelMode = viewmodels.InputModeCancelConfirm
+		case inputtypes.ModeArchiveConfirm:
+			viewModelMode = viewmodels.InputModeArchiveConfirm
+		case inputtypes.ModeVisual:
+			viewModelMode = viewmodels.InputModeVisual
+		case inputtypes.ModeEditTags:
+			viewModelMode = viewmodels.InputModeEditTags
+		case inputtypes.ModeNewBranch:
+			viewModelMode = viewmodels.InputModeNewBranch
+		case inputtypes.ModeSwitchBranch:
+			viewModelMode = viewmodels.InputModeSwitchBranch
+		case inputtypes.ModeCredential:
+			viewModelMode = viewmodels.InputModeCredential
+			m.viewModel.SetCredentialPrompt(m.credentialPrompt())
+		case inputtypes.ModeCloneOrg:
+			viewModelMode = viewmodels.InputModeCloneOrg
 		}
 		m.viewModel.SetInputMode(viewModelMode)
 
@@ -294,11 +640,10 @@ func (m *Model) View() string {
 		}
 	}
 
-	m.viewModel.SetUngroupedRepos(m.getUngroupedRepos())
+	m.viewModel.SetUngroupedRepos(ungrouped)
 
-	// Build view state and render
-	state := m.viewModel.BuildViewState()
-	return m.renderer.Render(state)
+	// Build view state
+	return m.viewModel.BuildViewState()
 }
 
 // updateOrderedLists updates the ordered lists for display
@@ -310,59 +655,8 @@ func (m *Model) updateOrderedLists() {
 	}
 
 	// Sort repositories based on current sort mode
-	switch m.currentSort {
-	case logic.SortByName:
-		sort.Slice(m.state.OrderedRepos, func(i, j int) bool {
-			repoI, okI := m.state.Repositories[m.state.OrderedRepos[i]]
-			repoJ, okJ := m.state.Repositories[m.state.OrderedRepos[j]]
-			if !okI || !okJ {
-				return !okI
-			}
-			return strings.ToLower(repoI.Name) < strings.ToLower(repoJ.Name)
-		})
-
-	case logic.SortByStatus:
-		sort.Slice(m.state.OrderedRepos, func(i, j int) bool {
-			repoI, okI := m.state.Repositories[m.state.OrderedRepos[i]]
-			repoJ, okJ := m.state.Repositories[m.state.OrderedRepos[j]]
-			if !okI || !okJ {
-				return !okI
-			}
-			// Order: error, dirty, clean
-			statusI := logic.GetStatusPriority(repoI)
-			statusJ := logic.GetStatusPriority(repoJ)
-			if statusI != statusJ {
-				return statusI > statusJ // Higher priority first
-			}
-			return strings.ToLower(repoI.Name) < strings.ToLower(repoJ.Name)
-		})
-
-	case logic.SortByBranch:
-		sort.Slice(m.state.OrderedRepos, func(i, j int) bool {
-			repoI, okI := m.state.Repositories[m.state.OrderedRepos[i]]
-			repoJ, okJ := m.state.Repositories[m.state.OrderedRepos[j]]
-			if !okI || !okJ {
-				return !okI
-			}
-			branchI := strings.ToLower(repoI.Status.Branch)
-			branchJ := strings.ToLower(repoJ.Status.Branch)
-			if branchI != branchJ {
-				// Put main/master first
-				if branchI == "main" || branchI == "master" {
-					return true
-				}
-				if branchJ == "main" || branchJ == "master" {
-					return false
-				}
-				return branchI < branchJ
-			}
-			return strings.ToLower(repoI.Name) < strings.ToLower(repoJ.Name)
-		})
-
-	default:
-		// Default to alphabetical by path
-		sort.Strings(m.state.OrderedRepos)
-	}
+	sorter := logic.NewRepositorySorter(m.state.Repositories)
+	sorter.SortRepositories(m.state.OrderedRepos, m.currentSort)
 
 	// Update ordered groups - always use creation order
 	m.state.OrderedGroups = make([]string, 0, len(m.state.GroupCreationOrder))
@@ -380,111 +674,51 @@ func (m *Model) updateOrderedLists() {
 		m.state.OrderedGroups = append(m.state.OrderedGroups, HiddenGroupName)
 	}
 
+	// Show linked worktrees in the group of their main repository
+	m.attachWorktreesToGroups()
+
 	// Update ungrouped repos cache
 	m.state.UngroupedRepos = m.getUngroupedRepos()
 
 	// Detect and handle duplicate repository names
 	m.updateDuplicateRepoNames()
 
-	// Sort ungrouped repos if needed
-	if m.currentSort != logic.SortByName {
-		// Apply the same sort to ungrouped repos
-		switch m.currentSort {
-		case logic.SortByStatus:
-			sort.Slice(m.state.UngroupedRepos, func(i, j int) bool {
-				repoI, okI := m.store.GetRepository(m.state.UngroupedRepos[i])
-				repoJ, okJ := m.store.GetRepository(m.state.UngroupedRepos[j])
-				if !okI || !okJ {
-					return !okI
-				}
-				statusI := logic.GetStatusPriority(repoI)
-				statusJ := logic.GetStatusPriority(repoJ)
-				if statusI != statusJ {
-					return statusI > statusJ
-				}
-				return strings.ToLower(repoI.Name) < strings.ToLower(repoJ.Name)
-			})
-
-		case logic.SortByBranch:
-			sort.Slice(m.state.UngroupedRepos, func(i, j int) bool {
-				repoI, okI := m.store.GetRepository(m.state.UngroupedRepos[i])
-				repoJ, okJ := m.store.GetRepository(m.state.UngroupedRepos[j])
-				if !okI || !okJ {
-					return !okI
-				}
-				branchI := strings.ToLower(repoI.Status.Branch)
-				branchJ := strings.ToLower(repoJ.Status.Branch)
-				if branchI != branchJ {
-					if branchI == "main" || branchI == "master" {
-						return true
-					}
-					if branchJ == "main" || branchJ == "master" {
-						return false
-					}
-					return branchI < branchJ
-				}
-				return strings.ToLower(repoI.Name) < strings.ToLower(repoJ.Name)
-			})
-		}
-	}
+	m.state.UngroupedRepos = logic.NestWorktrees(m.state.UngroupedRepos, m.state.Repositories)
 
 	// Sort repositories within each group
 	for _, group := range m.state.Groups {
 		// Create a copy of the repo paths to sort
 		sortedRepos := make([]string, len(group.Repos))
 		copy(sortedRepos, group.Repos)
+		sorter.SortRepositories(sortedRepos, m.currentSort)
 
-		switch m.currentSort {
-		case logic.SortByName:
-			sort.Slice(sortedRepos, func(i, j int) bool {
-				repoI, okI := m.state.Repositories[sortedRepos[i]]
-				repoJ, okJ := m.state.Repositories[sortedRepos[j]]
-				if !okI || !okJ {
-					return !okI
-				}
-				return strings.ToLower(repoI.Name) < strings.ToLower(repoJ.Name)
-			})
-
-		case logic.SortByStatus:
-			sort.Slice(sortedRepos, func(i, j int) bool {
-				repoI, okI := m.state.Repositories[sortedRepos[i]]
-				repoJ, okJ := m.state.Repositories[sortedRepos[j]]
-				if !okI || !okJ {
-					return !okI
-				}
-				statusI := logic.GetStatusPriority(repoI)
-				statusJ := logic.GetStatusPriority(repoJ)
-				if statusI != statusJ {
-					return statusI > statusJ // Higher priority first
-				}
-				return strings.ToLower(repoI.Name) < strings.ToLower(repoJ.Name)
-			})
+		// Update the group's repo list with sorted order, worktrees under their main repo
+		group.Repos = logic.NestWorktrees(sortedRepos, m.state.Repositories)
+	}
+}
 
-		case logic.SortByBranch:
-			sort.Slice(sortedRepos, func(i, j int) bool {
-				repoI, okI := m.state.Repositories[sortedRepos[i]]
-				repoJ, okJ := m.state.Repositories[sortedRepos[j]]
-				if !okI || !okJ {
-					return !okI
-				}
-				branchI := strings.ToLower(repoI.Status.Branch)
-				branchJ := strings.ToLower(repoJ.Status.Branch)
-				if branchI != branchJ {
-					// Put main/master first
-					if branchI == "main" || branchI == "master" {
-						return true
-					}
-					if branchJ == "main" || branchJ == "master" {
-						return false
-					}
-					return branchI < branchJ
-				}
-				return strings.ToLower(repoI.Name) < strings.ToLower(repoJ.Name)
-			})
+// attachWorktreesToGroups adds ungrouped linked worktrees to the group of their
+// main repository so they are displayed beneath it
+func (m *Model) attachWorktreesToGroups() {
+	groupOf := make(map[string]string)
+	for name, group := range m.state.Groups {
+		for _, path := range group.Repos {
+			groupOf[path] = name
 		}
+	}
 
-		// Update the group's repo list with sorted order
-		group.Repos = sortedRepos
+	for path, repo := range m.state.Repositories {
+		if repo.WorktreeOf == "" {
+			continue
+		}
+		if _, grouped := groupOf[path]; grouped {
+			continue
+		}
+		if mainGroup, ok := groupOf[repo.WorktreeOf]; ok {
+			group := m.state.Groups[mainGroup]
+			group.Repos = append(group.Repos, path)
+			groupOf[path] = mainGroup
+		}
 	}
 }
 
@@ -515,16 +749,8 @@ func (m *Model) getMaxIndex() int {
 
 // updateViewportHeight calculates the available height for the repository list
 func (m *Model) updateViewportHeight() {
-	// Account for title (2 lines), status (2 lines), help (1 line), and padding
-	reservedLines := 7
-
-	// Account for input field when active
-	// Input mode lines handled by input handler
-
-	m.state.ViewportHeight = m.height - reservedLines
-	if m.state.ViewportHeight < 1 {
-		m.state.ViewportHeight = 1
-	}
+	// Whatever the header and footer leave of the terminal's height, as rendered
+	m.state.ViewportHeight = m.renderer.ListHeight(m.buildViewState())
 
 	// Ensure viewport offset is still valid
 	m.ensureSelectedVisible()
@@ -583,6 +809,27 @@ func (m *Model) isOnGap(index int) bool {
 	return false
 }
 
+// targetRepos returns the repositories fetch, pull and refresh apply to: the selection, every
+// repository of the group under the cursor (named in the second result) or the current
+// repository
+func (m *Model) targetRepos() ([]string, string) {
+	var repoPaths []string
+	if m.store.GetSelectionCount() > 0 {
+		for path := range m.store.GetSelectedRepositories() {
+			repoPaths = append(repoPaths, path)
+		}
+		sort.Strings(repoPaths)
+		return repoPaths, ""
+	}
+	if groupName := m.getSelectedGroup(); groupName != "" {
+		return m.groupMembers(groupName), groupName
+	}
+	if repoPath := m.getRepoPathAtIndex(m.state.SelectedIndex); repoPath != "" {
+		repoPaths = []string{repoPath}
+	}
+	return repoPaths, ""
+}
+
 // getSelectedGroup returns the group name if a group header is selected
 func (m *Model) getSelectedGroup() string {
 	currentIndex := 0
@@ -674,7 +921,7 @@ func (m *Model) getRepoPathAtIndex(index int) string {
 	currentIndex := 0
 
 	// Check groups first (since they're displayed first now)
-	for _, groupName := range m.store.GetOrderedGroups() {
+	for i, groupName := range m.store.GetOrderedGroups() {
 		// Group header itself is not a repo
 		if currentIndex == index {
 			return "" // This is a group header, not a repo
@@ -694,7 +941,7 @@ func (m *Model) getRepoPathAtIndex(index int) string {
 		}
 
 		// Account for gap after group (except hidden group at the end)
-		if groupName != HiddenGroupName || currentIndex < index {
+		if groupName != HiddenGroupName || i != len(m.store.GetOrderedGroups())-1 {
 			if currentIndex == index {
 				return "" // This is a gap, not a repo
 			}
@@ -721,13 +968,19 @@ func (m *Model) getRepoPathAtIndex(index int) string {
 // buildRepoInfo builds detailed information about a repository
 func (m *Model) buildRepoInfo(repo *domain.Repository) string {
 	var info strings.Builder
+	theme := m.renderer.Theme()
 
 	// Repository name and path
 	info.WriteString(lipgloss.NewStyle().Bold(true).Render(repo.Name))
 	info.WriteString("\n\n")
 
 	// Path
-	info.WriteString(fmt.Sprintf("Path: %s\n", repo.Path))
+	// Long paths (UNC shares, deep trees) lose their middle rather than widen the popup
+	// past the screen: its margin, border and padding and the label take 18 columns
+	info.WriteString(fmt.Sprintf("Path: %s\n", paths.Shorten(repo.Path, m.width-18, m.renderer.Ellipsis())))
+	if repo.WorktreeOf != "" {
+		info.WriteString(fmt.Sprintf("Worktree of: %s\n", repo.WorktreeOf))
+	}
 
 	// Group
 	groupName := "Ungrouped"
@@ -739,36 +992,99 @@ func (m *Model) buildRepoInfo(repo *domain.Repository) string {
 			}
 		}
 	}
-	info.WriteString(fmt.Sprintf("Group: %s\n\n", groupName))
+	info.WriteString(fmt.Sprintf("Group: %s\n", groupName))
+	if repo.Project != "" {
+		info.WriteString(fmt.Sprintf("Project: %s\n", repo.Project))
+	}
+	if len(repo.Tags) > 0 {
+		info.WriteString(fmt.Sprintf("Tags: %s\n", strings.Join(repo.Tags, ", ")))
+	}
+
+	// Environment activator used for lazygit and other launched programs
+	if activator := devenv.Resolve(repo.Path, m.config.Environment.Activator); activator != devenv.ActivatorNone {
+		info.WriteString(fmt.Sprintf("Environment: %s\n", activator))
+	}
+	info.WriteString("\n")
 
 	// Status information
 	info.WriteString(lipgloss.NewStyle().Bold(true).Render("Status:"))
+	if repo.Status.Stale {
+		info.WriteString(lipgloss.NewStyle().Faint(true).Render(" (cached, refreshing...)"))
+	}
 	info.WriteString("\n")
 	// Colorize branch like in list view
-	branchColor := views.GetBranchColor(repo.Status.Branch)
+	branchColor := theme.BranchColor(repo.Status.Branch)
 	branchStyled := lipgloss.NewStyle().Foreground(lipgloss.Color(branchColor))
 	// Make main/master bold for emphasis
 	if repo.Status.Branch == "main" || repo.Status.Branch == "master" {
 		branchStyled = branchStyled.Bold(true)
 	}
 	info.WriteString("  Branch: ")
-	info.WriteString(branchStyled.Render(repo.Status.Branch))
+	if repo.Status.HeadCommit != "" {
+		info.WriteString(branchStyled.Render(fmt.Sprintf("detached @ %s", repo.Status.HeadCommit)))
+		if repo.Status.Describe != "" {
+			info.WriteString(fmt.Sprintf(" (%s)", repo.Status.Describe))
+		}
+	} else {
+		info.WriteString(branchStyled.Render(repo.Status.Branch))
+	}
+	if repo.Status.NoCommits {
+		info.WriteString(lipgloss.NewStyle().Faint(true).Render(" (empty, no commits yet)"))
+	}
 	info.WriteString("\n")
 
+	// Last commit
+	if !repo.Status.LastCommitTime.IsZero() {
+		info.WriteString(fmt.Sprintf("  Last commit: %s", m.dates.Format(repo.Status.LastCommitTime)))
+		if repo.Status.LastCommitBy != "" {
+			info.WriteString(fmt.Sprintf(" by %s", repo.Status.LastCommitBy))
+		}
+		info.WriteString("\n")
+	}
+
+	// Upstream the ahead/behind counts refer to
+	if repo.Status.Upstream != "" {
+		info.WriteString(fmt.Sprintf("  Upstream: %s\n", repo.Status.Upstream))
+	} else {
+		info.WriteString("  Upstream: ")
+		info.WriteString(lipgloss.NewStyle().Faint(true).Render("none"))
+		info.WriteString("\n")
+	}
+
+	// Unfinished merge/rebase/bisect
+	if badge := views.OperationBadge(repo.Status.InProgress); badge != "" {
+		info.WriteString("  In progress: ")
+		info.WriteString(lipgloss.NewStyle().Foreground(lipgloss.Color(theme.Error)).Bold(true).Render(badge))
+		info.WriteString("\n")
+	}
+
+	// Looks abandoned ([stale])
+	if repo.Status.StaleReason != "" {
+		info.WriteString("  Stale: ")
+		info.WriteString(lipgloss.NewStyle().Foreground(lipgloss.Color(theme.Warning)).Render(repo.Status.StaleReason))
+		info.WriteString("\n")
+	}
+
 	// Clean/Dirty status
 	if repo.Status.IsDirty {
 		// Yellow for changes
 		info.WriteString("  State: ")
-		info.WriteString(lipgloss.NewStyle().Foreground(lipgloss.Color("214")).Render("Dirty (uncommitted changes)"))
+		info.WriteString(lipgloss.NewStyle().Foreground(lipgloss.Color(theme.Warning)).Render("Dirty (uncommitted changes)"))
 		info.WriteString("\n")
 	} else if repo.Status.HasUntracked {
 		info.WriteString("  State: ")
-		info.WriteString(lipgloss.NewStyle().Foreground(lipgloss.Color("214")).Render("Has untracked files"))
+		info.WriteString(lipgloss.NewStyle().Foreground(lipgloss.Color(theme.Warning)).Render("Has untracked files"))
 		info.WriteString("\n")
+	} else if repo.Status.Mode == domain.StatusModeBranchOnly {
+		info.WriteString("  State: Not read (status mode branch-only)\n")
+	} else if repo.Status.Mode == domain.StatusModeFast {
+		info.WriteString("  State: ")
+		info.WriteString(lipgloss.NewStyle().Foreground(lipgloss.Color(theme.Success)).Render("Clean"))
+		info.WriteString(" (untracked files not checked, status mode fast)\n")
 	} else {
 		// Green for clean
 		info.WriteString("  State: ")
-		info.WriteString(lipgloss.NewStyle().Foreground(lipgloss.Color("78")).Render("Clean"))
+		info.WriteString(lipgloss.NewStyle().Foreground(lipgloss.Color(theme.Success)).Render("Clean"))
 		info.WriteString("\n")
 	}
 
@@ -778,11 +1094,34 @@ func (m *Model) buildRepoInfo(repo *domain.Repository) string {
 		info.WriteString(fmt.Sprintf("  Behind: %d commits\n", repo.Status.BehindCount))
 	}
 
+	// What the changes are, as counted by git status
+	if changes := views.ChangeCountsText(repo.Status); changes != "" {
+		info.WriteString("  Changes: " + changes + "\n")
+	}
+
+	// Untracked/ignored stats (show_cleanup_stats)
+	if repo.Status.UntrackedFiles > 0 {
+		info.WriteString(fmt.Sprintf("  Untracked files: %d\n", repo.Status.UntrackedFiles))
+	}
+	if repo.Status.IgnoredBytes > 0 {
+		info.WriteString(fmt.Sprintf("  Ignored on disk: %s\n", views.FormatBytes(repo.Status.IgnoredBytes)))
+	}
+
 	// Error
 	if repo.Status.Error != "" {
-		errorStyle := lipgloss.NewStyle().Foreground(lipgloss.Color("203"))
+		errorStyle := lipgloss.NewStyle().Foreground(lipgloss.Color(theme.Error))
 		info.WriteString(fmt.Sprintf("  Error: %s\n", errorStyle.Render(repo.Status.Error)))
 	}
+	if repo.HasError && repo.LastError != "" {
+		errorStyle := lipgloss.NewStyle().Foreground(lipgloss.Color(theme.Error))
+		info.WriteString(fmt.Sprintf("  Last command failed: %s\n", errorStyle.Render(repo.LastError)))
+	}
+
+	// Remotes, stashes, tags and worktrees, read when the info opens
+	info.WriteString("\n")
+	info.WriteString(lipgloss.NewStyle().Bold(true).Render("Details:"))
+	info.WriteString("\n")
+	m.writeRepoDetails(&info, repo.Path)
 
 	// Command logs
 	if len(repo.CommandLogs) > 0 {
@@ -800,11 +1139,11 @@ func (m *Model) buildRepoInfo(repo *domain.Repository) string {
 			log := repo.CommandLogs[i]
 
 			// Format timestamp and command on same line
-			info.WriteString(fmt.Sprintf("\n[%s] ", log.Timestamp))
+			info.WriteString(fmt.Sprintf("\n[%s] ", m.dates.Format(log.Time)))
 
 			// Command name with appropriate styling
 			if !log.Success {
-				cmdStyle := lipgloss.NewStyle().Foreground(lipgloss.Color("203")).Bold(true)
+				cmdStyle := lipgloss.NewStyle().Foreground(lipgloss.Color(theme.Error)).Bold(true)
 				info.WriteString(cmdStyle.Render(log.Command))
 			} else {
 				info.WriteString(log.Command)
@@ -817,7 +1156,7 @@ func (m *Model) buildRepoInfo(repo *domain.Repository) string {
 			if !log.Success {
 				if log.Output != "" {
 					// Show the actual git output which contains the real error message
-					errorStyle := lipgloss.NewStyle().Foreground(lipgloss.Color("203"))
+					errorStyle := lipgloss.NewStyle().Foreground(lipgloss.Color(theme.Error))
 					output := strings.TrimSpace(log.Output)
 					// Replace any error: prefix to avoid duplication
 					output = strings.TrimPrefix(output, "error: ")
@@ -826,7 +1165,7 @@ func (m *Model) buildRepoInfo(repo *domain.Repository) string {
 					info.WriteString("\n")
 				} else if log.Error != "" {
 					// Fallback to error field if no output
-					errorStyle := lipgloss.NewStyle().Foreground(lipgloss.Color("203"))
+					errorStyle := lipgloss.NewStyle().Foreground(lipgloss.Color(theme.Error))
 					info.WriteString("  Error: ")
 					info.WriteString(errorStyle.Render(log.Error))
 					info.WriteString("\n")
@@ -849,7 +1188,7 @@ func (m *Model) buildRepoInfo(repo *domain.Repository) string {
 // buildRepoLogsContent generates a plain text log report for the repository suitable for pager display
 func (m *Model) buildRepoLogsContent(repo *domain.Repository) string {
 	var b strings.Builder
-	title := lipgloss.NewStyle().Bold(true).Foreground(lipgloss.Color("99")).Render("Repository Logs")
+	title := lipgloss.NewStyle().Bold(true).Foreground(lipgloss.Color(m.renderer.Theme().Title)).Render("Repository Logs")
 	b.WriteString(title)
 	b.WriteString("\n\n")
 	b.WriteString(fmt.Sprintf("Name: %s\n", repo.Name))
@@ -868,7 +1207,7 @@ func (m *Model) buildRepoLogsContent(repo *domain.Repository) string {
 		if !entry.Success {
 			status = "FAIL"
 		}
-		b.WriteString(fmt.Sprintf("[%s] %s (%dms) — %s\n", entry.Timestamp, entry.Command, entry.Duration, status))
+		b.WriteString(fmt.Sprintf("[%s] %s (%dms) — %s\n", m.dates.Format(entry.Time), entry.Command, entry.Duration, status))
 		if entry.Output != "" {
 			b.WriteString("Output:\n")
 			b.WriteString(strings.TrimSpace(entry.Output))
@@ -983,11 +1322,13 @@ func (m *Model) fetchHelpPager(helpContent string) tea.Cmd {
 
 // fetchLazygit returns a command that runs lazygit for the given repo, pausing and resuming rendering
 func (m *Model) fetchLazygit(repoPath string) tea.Cmd {
+	// Commands run on their own goroutine, away from the config the model changes
+	env := m.config.GroupEnvFor(repoPath)
 	return func() tea.Msg {
 		// Pause rendering while external TUI is active
 		m.program.Send(pauseRenderingMsg{})
 
-		err := m.gitOps.RunLazygit(repoPath)
+		err := m.gitOps.RunLazygit(repoPath, env)
 
 		// Resume rendering afterwards
 		m.program.Send(resumeRenderingMsg{})
@@ -996,6 +1337,20 @@ func (m *Model) fetchLazygit(repoPath string) tea.Cmd {
 	}
 }
 
+// fetchShell returns a command that opens a shell in the given repo, pausing and resuming rendering
+func (m *Model) fetchShell(repoPath string) tea.Cmd {
+	env := m.config.GroupEnvFor(repoPath)
+	return func() tea.Msg {
+		m.program.Send(pauseRenderingMsg{})
+
+		err := m.gitOps.RunShell(repoPath, env)
+
+		m.program.Send(resumeRenderingMsg{})
+
+		return shellExitMsg{repoPath: repoPath, err: err}
+	}
+}
+
 // processAction processes an action from the input handler
 func (m *Model) processAction(action inputtypes.Action) tea.Cmd {
 	log.Printf("processAction: %T", action)
@@ -1058,11 +1413,22 @@ func (m *Model) processAction(action inputtypes.Action) tea.Cmd {
 		}
 
 	case inputtypes.SelectGroupAction:
+		groupName := a.GroupName
+		if groupName == "" {
+			groupName = m.getGroupAtIndex(m.state.SelectedIndex)
+		}
+		var repos []string
+		if group, ok := m.store.GetGroup(groupName); ok {
+			repos = group.Repos
+		} else if groupName == "Ungrouped" {
+			repos = m.getUngroupedRepos()
+		}
+
 		// Toggle selection for all repos in the group
-		if group, ok := m.store.GetGroup(a.GroupName); ok {
+		if len(repos) > 0 {
 			// Check if all repos in group are already selected
 			allSelected := true
-			for _, repoPath := range group.Repos {
+			for _, repoPath := range repos {
 				if !m.state.SelectedRepos[repoPath] {
 					allSelected = false
 					break
@@ -1070,7 +1436,7 @@ func (m *Model) processAction(action inputtypes.Action) tea.Cmd {
 			}
 
 			// Toggle selection for all repos in group
-			for _, repoPath := range group.Repos {
+			for _, repoPath := range repos {
 				if allSelected {
 					// Deselect all
 					delete(m.state.SelectedRepos, repoPath)
@@ -1082,9 +1448,9 @@ func (m *Model) processAction(action inputtypes.Action) tea.Cmd {
 
 			// Update status message
 			if allSelected {
-				m.state.StatusMessage = fmt.Sprintf("Deselected all repos in '%s'", a.GroupName)
+				m.state.StatusMessage = fmt.Sprintf("Deselected all repos in '%s'", groupName)
 			} else {
-				m.state.StatusMessage = fmt.Sprintf("Selected all repos in '%s'", a.GroupName)
+				m.state.StatusMessage = fmt.Sprintf("Selected all repos in '%s'", groupName)
 			}
 		}
 
@@ -1119,96 +1485,200 @@ func (m *Model) processAction(action inputtypes.Action) tea.Cmd {
 		}
 
 	case inputtypes.SelectAllAction:
-		totalRepos := m.countVisibleRepos()
-		return m.cmdExecutor.ExecuteSelectAll(totalRepos)
+		return m.cmdExecutor.ExecuteSelectAll()
 
 	case inputtypes.DeselectAllAction:
 		m.state.ClearSelection()
+		m.state.StatusMessage = "Selection cleared"
 
 	case inputtypes.RefreshAction:
 		if a.All {
 			// Full scan
 			return m.cmdExecutor.ExecuteFullScan(m.config.BaseDir)
 		} else {
-			// Refresh status
-			var repoPaths []string
-			if m.store.GetSelectionCount() > 0 {
-				// Refresh selected repos
-				for path := range m.store.GetSelectedRepositories() {
-					repoPaths = append(repoPaths, path)
-				}
-			} else {
-				// Check if we're on a group header
-				groupName := m.getSelectedGroup()
-				if groupName != "" && groupName != "Ungrouped" {
-					// Refresh all repos in the group
-					if group, ok := m.store.GetGroup(groupName); ok {
-						repoPaths = append(repoPaths, group.Repos...)
-						m.state.StatusMessage = fmt.Sprintf("Refreshing all repos in '%s'", groupName)
-					}
-				} else {
-					// Refresh current repository
-					if repoPath := m.getRepoPathAtIndex(m.state.SelectedIndex); repoPath != "" {
-						repoPaths = []string{repoPath}
-					}
-				}
+			// Refresh the selection, the group under the cursor or the current repository
+			repoPaths, group := m.targetRepos()
+			if group != "" {
+				m.state.StatusMessage = fmt.Sprintf("Refreshing all repos in '%s'", group)
 			}
 			return m.cmdExecutor.ExecuteRefresh(repoPaths)
 		}
 
+	case inputtypes.RetryFailedAction:
+		repoPaths := m.state.FailedRepositories()
+		if len(repoPaths) == 0 {
+			m.state.StatusMessage = "No failed repos"
+			return nil
+		}
+		m.state.StatusMessage = fmt.Sprintf("Retrying %d failed repos", len(repoPaths))
+		return m.cmdExecutor.ExecuteRefresh(repoPaths)
+
 	case inputtypes.FetchAction:
-		var repoPaths []string
-		if m.store.GetSelectionCount() > 0 {
-			// Fetch selected repos
-			for path := range m.store.GetSelectedRepositories() {
-				repoPaths = append(repoPaths, path)
-			}
-		} else {
-			// Check if we're on a group header
-			groupName := m.getSelectedGroup()
-			if groupName != "" && groupName != "Ungrouped" {
-				// Fetch all repos in the group
-				if group, ok := m.store.GetGroup(groupName); ok {
-					repoPaths = append(repoPaths, group.Repos...)
-					m.state.StatusMessage = fmt.Sprintf("Fetching all repos in '%s'", groupName)
-				}
-			} else {
-				// Fetch current repository
-				if repoPath := m.getRepoPathAtIndex(m.state.SelectedIndex); repoPath != "" {
-					repoPaths = []string{repoPath}
-				}
-			}
+		repoPaths, group := m.targetRepos()
+		if group != "" {
+			m.state.StatusMessage = fmt.Sprintf("Fetching all repos in '%s'", group)
 		}
 		return m.cmdExecutor.ExecuteFetch(repoPaths)
 
+	case inputtypes.OpenFetchOptionsAction:
+		m.openFetchOptions()
+
+	case inputtypes.CycleFetchOptionAction:
+		if m.state.FetchOptions != nil {
+			m.cycleFetchOption(a.Option)
+			m.state.InfoContent = m.buildFetchOptionsContent()
+		}
+
+	case inputtypes.FetchWithOptionsAction:
+		if popup := m.state.FetchOptions; popup != nil {
+			m.state.StatusMessage = fmt.Sprintf("Fetching %s (%s)", popup.Scope, describeFetchOptions(popup.Options))
+			return m.cmdExecutor.ExecuteFetchWithOptions(popup.RepoPaths, popup.Options)
+		}
+
+	case inputtypes.CloseFetchOptionsAction:
+		m.state.FetchOptions = nil
+		m.state.ShowInfo = false
+		m.state.InfoContent = ""
+
 	case inputtypes.PullAction:
-		var repoPaths []string
-		if m.store.GetSelectionCount() > 0 {
-			// Pull selected repos
-			for path := range m.store.GetSelectedRepositories() {
-				repoPaths = append(repoPaths, path)
-			}
-		} else {
-			// Check if we're on a group header
-			groupName := m.getSelectedGroup()
-			if groupName != "" && groupName != "Ungrouped" {
-				// Pull all repos in the group
-				if group, ok := m.store.GetGroup(groupName); ok {
-					repoPaths = append(repoPaths, group.Repos...)
-					m.state.StatusMessage = fmt.Sprintf("Pulling all repos in '%s'", groupName)
-				}
-			} else {
-				// Pull current repository
-				if repoPath := m.getRepoPathAtIndex(m.state.SelectedIndex); repoPath != "" {
-					repoPaths = []string{repoPath}
-				}
+		// Pull the selection, the group under the cursor or the current repository
+		repoPaths, group := m.targetRepos()
+		// A bulk pull shows its dry run first
+		if len(repoPaths) > 1 && !m.config.UISettings.SkipConfirmations {
+			scope := "the selected repos"
+			if group != "" {
+				scope = "the repos in " + group
 			}
+			return m.previewPull(repoPaths, scope)
+		}
+		if group != "" {
+			m.state.StatusMessage = fmt.Sprintf("Pulling all repos in '%s'", group)
 		}
 		return m.cmdExecutor.ExecutePull(repoPaths)
 
+	case inputtypes.PullPreviewNavigateAction:
+		if m.state.PullPreview != nil {
+			m.navigatePullPreview(a.Delta)
+		}
+
+	case inputtypes.TogglePullPreviewAction:
+		if m.state.PullPreview != nil {
+			m.togglePullPreview()
+		}
+
+	case inputtypes.PullPreviewedAction:
+		if m.state.PullPreview != nil {
+			return m.pullPreviewed()
+		}
+
+	case inputtypes.OpenProfilesAction:
+		m.openProfiles()
+
+	case inputtypes.ProfilesNavigateAction:
+		if m.state.Profiles != nil {
+			m.navigateProfiles(a.Delta)
+		}
+
+	case inputtypes.SwitchProfileAction:
+		if m.state.Profiles != nil {
+			return m.switchProfile()
+		}
+
+	case inputtypes.OpenCloneOrgAction:
+		m.openCloneOrg()
+
+	case inputtypes.CloneOrgNavigateAction:
+		if m.state.CloneOrg != nil {
+			m.navigateCloneOrg(a.Delta)
+		}
+
+	case inputtypes.CloneOrgToggleAction:
+		if m.state.CloneOrg != nil {
+			m.toggleCloneOrg(a.All)
+		}
+
+	case inputtypes.CloneOrgAction:
+		return m.cloneOrg()
+
+	case inputtypes.OpenDirBrowserAction:
+		m.openDirBrowser()
+
+	case inputtypes.DirBrowserNavigateAction:
+		if m.state.DirBrowser != nil {
+			m.navigateDirBrowser(a.Delta)
+		}
+
+	case inputtypes.DirBrowserDescendAction:
+		if m.state.DirBrowser != nil {
+			m.descendDirBrowser()
+		}
+
+	case inputtypes.DirBrowserParentAction:
+		if m.state.DirBrowser != nil {
+			m.parentDirBrowser()
+		}
+
+	case inputtypes.ScanBrowsedDirAction:
+		if browser := m.state.DirBrowser; browser != nil {
+			return m.scanDir(browser.Dir)
+		}
+
+	case inputtypes.ScanRecentAction:
+		return m.scanRecent(a.Index)
+
+	case inputtypes.RescanAction:
+		return m.rescan()
+
+	case inputtypes.OpenDebugLogAction:
+		m.openDebugLog()
+
+	case inputtypes.DebugLogScrollAction:
+		m.scrollDebugLog(a.Delta)
+
+	case inputtypes.CloseDebugLogAction:
+		m.state.DebugLog = nil
+		m.state.ShowInfo = false
+		m.state.InfoContent = ""
+
+	case inputtypes.CloseDirBrowserAction:
+		m.state.DirBrowser = nil
+		m.state.ShowInfo = false
+		m.state.InfoContent = ""
+
+	case inputtypes.OpenGroupColorAction:
+		m.openGroupColor(a.Group)
+
+	case inputtypes.SetGroupColorAction:
+		m.setGroupColor(a.Index)
+
+	case inputtypes.CloseGroupColorAction:
+		m.state.ColorGroup = ""
+		m.state.ShowInfo = false
+		m.state.InfoContent = ""
+
+	case inputtypes.CloseProfilesAction:
+		m.state.Profiles = nil
+		m.state.ShowInfo = false
+		m.state.InfoContent = ""
+
+	case inputtypes.CloseCloneOrgAction:
+		m.state.CloneOrg = nil
+		m.state.ShowInfo = false
+		m.state.InfoContent = ""
+
+	case inputtypes.ClosePullPreviewAction:
+		m.state.PullPreview = nil
+		m.state.ShowInfo = false
+		m.state.InfoContent = ""
+
 	case inputtypes.OpenLogAction:
 		// Show git log for current repo
 		if repoPath := m.getRepoPathAtIndex(m.state.SelectedIndex); repoPath != "" {
+			// git log fails on a repository without commits
+			if repo, ok := m.state.Repositories[repoPath]; ok && repo.Status.NoCommits {
+				m.state.StatusMessage = fmt.Sprintf("%s has no commits yet", repo.Name)
+				return nil
+			}
 			// Try pager first if available, fall back to popup
 			if m.gitOps.IsOvAvailable() {
 				return m.fetchGitLogPager(repoPath)
@@ -1255,8 +1725,10 @@ func (m *Model) processAction(action inputtypes.Action) tea.Cmd {
 			log.Printf("ToggleInfoAction: ShowInfo=%v, repoPath=%s", m.state.ShowInfo, repoPath)
 			if repoPath != "" {
 				if repo, ok := m.state.Repositories[repoPath]; ok {
+					m.state.InfoRepo = repoPath
 					m.state.InfoContent = m.buildRepoInfo(repo)
 					log.Printf("Built info content, length=%d", len(m.state.InfoContent))
+					return m.loadRepoDetails(repoPath)
 				} else {
 					log.Printf("Repository not found for path: %s", repoPath)
 				}
@@ -1264,14 +1736,275 @@ func (m *Model) processAction(action inputtypes.Action) tea.Cmd {
 				log.Printf("No repo path at index %d", m.state.SelectedIndex)
 			}
 		} else {
+			m.state.InfoRepo = ""
+			m.state.InfoContent = ""
+		}
+
+	case inputtypes.OpenRemoteMigrationAction:
+		m.openRemoteMigration(git.TransportSSH)
+
+	case inputtypes.RemoteMigrationNavigateAction:
+		if mig := m.state.RemoteMigration; mig != nil && len(mig.Items) > 0 {
+			mig.Index = (mig.Index + a.Delta + len(mig.Items)) % len(mig.Items)
+			m.state.InfoContent = m.buildRemoteMigrationContent()
+		}
+
+	case inputtypes.ApplyRemoteMigrationAction:
+		return m.applyRemoteMigration(a.All)
+
+	case inputtypes.ToggleRemoteMigrationTargetAction:
+		if mig := m.state.RemoteMigration; mig != nil {
+			target := git.TransportHTTPS
+			if mig.Target == git.TransportHTTPS {
+				target = git.TransportSSH
+			}
+			m.openRemoteMigration(target)
+		}
+
+	case inputtypes.CloseRemoteMigrationAction:
+		m.state.RemoteMigration = nil
+		m.state.ShowInfo = false
+		m.state.InfoContent = ""
+
+	case inputtypes.OpenWatchedChangesAction:
+		m.state.ShowInfo = true
+		m.state.InfoContent = m.buildWatchedChangesContent()
+
+	case inputtypes.AcknowledgeWatchedChangesAction:
+		return m.acknowledgeWatchedChanges()
+
+	case inputtypes.CloseWatchedChangesAction:
+		m.state.ShowInfo = false
+		m.state.InfoContent = ""
+
+	case inputtypes.OpenDashboardAction:
+		m.state.ShowInfo = true
+		m.state.InfoContent = m.buildDashboardContent()
+
+	case inputtypes.CloseDashboardAction:
+		m.state.ShowInfo = false
+		m.state.InfoContent = ""
+
+	case inputtypes.OpenBranchOverviewAction:
+		m.state.ShowInfo = true
+		m.state.InfoContent = m.buildBranchOverviewContent()
+
+	case inputtypes.CloseBranchOverviewAction:
+		m.state.ShowInfo = false
+		m.state.InfoContent = ""
+
+	case inputtypes.OpenStatusChangesAction:
+		m.state.ShowInfo = true
+		m.state.InfoContent = m.buildStatusChangesContent()
+
+	case inputtypes.CloseStatusChangesAction:
+		m.state.ShowInfo = false
+		m.state.InfoContent = ""
+
+	case inputtypes.OpenHeatmapAction:
+		return m.openHeatmap(a.GroupName)
+
+	case inputtypes.CloseHeatmapAction:
+		m.state.Heatmap = nil
+		m.state.ShowInfo = false
+		m.state.InfoContent = ""
+
+	case inputtypes.OpenCommitLogAction:
+		return m.openCommitLog()
+
+	case inputtypes.CloseCommitLogAction:
+		m.state.CommitLog = nil
+		m.state.ShowInfo = false
+		m.state.InfoContent = ""
+
+	case inputtypes.CommitLogScrollAction:
+		m.scrollCommitLog(a.Delta)
+
+	case inputtypes.EditCommitLogFilterAction:
+		if view := m.state.CommitLog; view != nil {
+			view.Input = ""
+			if a.Editing {
+				view.Input = a.Input
+			}
+			m.state.InfoContent = m.buildCommitLogContent()
+		}
+
+	case inputtypes.FilterCommitLogAction:
+		if view := m.state.CommitLog; view != nil {
+			view.Query = strings.TrimSpace(a.Query)
+			return m.reloadCommitLog()
+		}
+
+	case inputtypes.ToggleCommitLogBranchesAction:
+		if view := m.state.CommitLog; view != nil {
+			view.All = !view.All
+			return m.reloadCommitLog()
+		}
+
+	case inputtypes.CycleCommitLogRangeAction:
+		if view := m.state.CommitLog; view != nil {
+			view.Range = (view.Range + 1) % len(commitLogRanges)
+			return m.reloadCommitLog()
+		}
+
+	case inputtypes.OpenUnpushedAction:
+		return m.openUnpushed()
+
+	case inputtypes.CloseUnpushedAction:
+		m.state.Unpushed = nil
+		m.state.ShowInfo = false
+		m.state.InfoContent = ""
+
+	case inputtypes.UnpushedScrollAction:
+		m.scrollUnpushed(a.Delta)
+
+	case inputtypes.ShowPaletteAction:
+		m.state.ShowInfo = true
+		m.state.InfoContent = m.buildPaletteContent(a)
+
+	case inputtypes.ClosePaletteAction:
+		m.state.ShowInfo = false
+		m.state.InfoContent = ""
+
+	case inputtypes.OpenHealthAction:
+		return m.openHealth()
+
+	case inputtypes.CloseHealthAction:
+		m.state.Health = nil
+		m.state.ShowInfo = false
+		m.state.InfoContent = ""
+
+	case inputtypes.HealthNavigateAction:
+		if m.state.Health != nil {
+			m.navigateHealth(a.Delta)
+		}
+
+	case inputtypes.HealthFsckAction:
+		if m.state.Health != nil {
+			return m.fsckHealthRepo()
+		}
+
+	case inputtypes.RemoveHealthLocksAction:
+		if m.state.Health != nil {
+			return m.removeHealthLocks()
+		}
+
+	case inputtypes.OpenBulkLabelsAction:
+		m.openBulkLabels()
+
+	case inputtypes.BulkLabelsNavigateAction:
+		if editor := m.state.BulkLabels; editor != nil && len(editor.Items) > 0 {
+			editor.Index = (editor.Index + a.Delta + len(editor.Items)) % len(editor.Items)
+			m.state.InfoContent = m.buildBulkLabelsContent()
+		}
+
+	case inputtypes.ToggleBulkLabelAction:
+		m.toggleBulkLabel()
+
+	case inputtypes.ApplyBulkLabelsAction:
+		m.applyBulkLabels()
+
+	case inputtypes.CloseBulkLabelsAction:
+		m.state.BulkLabels = nil
+		m.state.ShowInfo = false
+		m.state.InfoContent = ""
+
+	case inputtypes.MarkCompareGroupAction:
+		if m.state.CompareGroup == a.GroupName {
+			m.state.CompareGroup = ""
+			m.state.StatusMessage = "Group comparison cancelled"
+		} else {
+			m.state.CompareGroup = a.GroupName
+			m.state.StatusMessage = fmt.Sprintf("Press C on another group to compare it with '%s'", a.GroupName)
+		}
+
+	case inputtypes.OpenGroupCompareAction:
+		m.state.ComparedGroups = [2]string{a.Left, a.Right}
+		m.state.CompareTags = nil
+		m.state.ShowInfo = true
+		m.state.InfoContent = m.buildGroupCompareContent()
+		return m.loadCompareTags(append(m.groupMembers(a.Left), m.groupMembers(a.Right)...))
+
+	case inputtypes.CloseGroupCompareAction:
+		m.state.CompareGroup = ""
+		m.state.ComparedGroups = [2]string{}
+		m.state.CompareTags = nil
+		m.state.ShowInfo = false
+		m.state.InfoContent = ""
+
+	case inputtypes.ForgetReposAction:
+		m.forgetRepos()
+
+	case inputtypes.PromptArchiveAction:
+		if a.RepoPath == "" {
+			m.state.ArchiveTarget = ""
+			m.state.ShowInfo = false
 			m.state.InfoContent = ""
+			return nil
+		}
+		refusal := m.archiveRefusal(a.RepoPath)
+		if refusal == "" {
+			m.state.ArchiveTarget = a.RepoPath
+		}
+		m.state.ShowInfo = true
+		m.state.InfoContent = m.buildArchivePreview(a.RepoPath, refusal)
+
+	case inputtypes.PromptPushAction:
+		if !a.Open {
+			m.state.PushTargets = nil
+			m.state.ShowInfo = false
+			m.state.InfoContent = ""
+			return nil
+		}
+		m.promptPush()
+		if m.config.UISettings.SkipConfirmations && len(m.state.PushTargets) > 0 {
+			// Push without the list; leaving the prompt closes it
+			return tea.Batch(m.pushTargets(), m.enterMode(inputtypes.ModeNormal))
+		}
+
+	case inputtypes.PushAction:
+		return m.pushTargets()
+
+	case inputtypes.SwitchBranchAction:
+		return m.cmdExecutor.ExecuteSwitchBranch(a.RepoPaths, a.Name)
+
+	case inputtypes.ArchiveRepoAction:
+		if target := m.state.ArchiveTarget; target != "" {
+			m.state.StatusMessage = fmt.Sprintf("Archiving %s", m.state.Repositories[target].Name)
+			return m.archiveRepo(target)
+		}
+
+	case inputtypes.UndoRemoveAction:
+		m.undoRemove()
+
+	case inputtypes.CleanDryRunAction:
+		m.state.CleanTargets = nil
+		m.state.ShowInfo = true
+		m.state.InfoContent = "Running git clean dry run..."
+		return m.runCleanDryRun(m.cleanTargets())
+
+	case inputtypes.CleanReposAction:
+		if len(m.state.CleanTargets) > 0 {
+			m.state.StatusMessage = fmt.Sprintf("Cleaning %d repos", len(m.state.CleanTargets))
+			return m.cmdExecutor.ExecuteClean(m.state.CleanTargets)
 		}
 
-	case inputtypes.ToggleHelpAction:
-		// Generate plain text help content for pager
-		helpContent := m.renderer.RenderHelpContentPlain()
-		// Show help using ov pager
-		return m.fetchHelpPager(helpContent)
+	case inputtypes.CancelOperationsAction:
+		m.state.StatusMessage = "Cancelling remaining operations..."
+		return m.cmdExecutor.ExecuteCancelOperations()
+
+	case inputtypes.CloseCleanPreviewAction:
+		m.state.CleanTargets = nil
+		m.state.ShowInfo = false
+		m.state.InfoContent = ""
+
+	case inputtypes.OpenHelpAction:
+		m.state.ShowInfo = true
+		m.state.InfoContent = m.buildHelpContent()
+
+	case inputtypes.CloseHelpAction:
+		m.state.ShowInfo = false
+		m.state.InfoContent = ""
 
 	case inputtypes.OpenRepoLogsAction:
 		// Build logs content for the current repo and show in pager
@@ -1286,6 +2019,10 @@ func (m *Model) processAction(action inputtypes.Action) tea.Cmd {
 	case inputtypes.OpenLazygitAction:
 		// Open lazygit for current repo (if available)
 		if repoPath := m.getRepoPathAtIndex(m.state.SelectedIndex); repoPath != "" {
+			if m.jump {
+				m.jumpPath = repoPath
+				return tea.Quit
+			}
 			if m.gitOps.IsLazygitAvailable() {
 				return m.fetchLazygit(repoPath)
 			}
@@ -1295,6 +2032,22 @@ func (m *Model) processAction(action inputtypes.Action) tea.Cmd {
 		}
 		return nil
 
+	case inputtypes.StartVisualAction:
+		m.state.Visual = true
+		m.state.VisualAnchor = m.state.SelectedIndex
+
+	case inputtypes.EndVisualAction:
+		m.state.Visual = false
+
+	case inputtypes.VisualSelectAction:
+		m.applyVisualRange(a.Add)
+
+	case inputtypes.OpenShellAction:
+		if repoPath := m.getRepoPathAtIndex(m.state.SelectedIndex); repoPath != "" {
+			return m.fetchShell(repoPath)
+		}
+		return nil
+
 	case inputtypes.ExpandAllGroupsAction:
 		// Expand all groups (except hidden)
 		for groupName := range m.state.Groups {
@@ -1385,12 +2138,7 @@ func (m *Model) processAction(action inputtypes.Action) tea.Cmd {
 		}
 
 		// Publish config changed event
-		if m.bus != nil {
-			m.bus.Publish(eventbus.ConfigChangedEvent{
-				Groups:     m.getGroupsMap(),
-				GroupOrder: m.getGroupOrder(),
-			})
-		}
+		m.configChanged()
 
 	case inputtypes.MoveToGroupAction:
 		var repoPaths []string
@@ -1430,11 +2178,15 @@ func (m *Model) processAction(action inputtypes.Action) tea.Cmd {
 
 	case inputtypes.RenameGroupAction:
 		if a.OldName != "" && a.NewName != "" && a.OldName != a.NewName {
-			// Check if new name already exists
-			if _, exists := m.state.Groups[a.NewName]; exists {
+			// Check if new name already exists (or is reserved)
+			if _, exists := m.state.Groups[a.NewName]; exists || a.NewName == "Ungrouped" || a.NewName == HiddenGroupName {
 				m.state.StatusMessage = fmt.Sprintf("Group '%s' already exists", a.NewName)
 				return nil
 			}
+			if a.OldName == HiddenGroupName {
+				m.state.StatusMessage = fmt.Sprintf("Group '%s' cannot be renamed", a.OldName)
+				return nil
+			}
 
 			// Get the old group
 			oldGroup, exists := m.state.Groups[a.OldName]
@@ -1474,21 +2226,29 @@ func (m *Model) processAction(action inputtypes.Action) tea.Cmd {
 				}
 			}
 
+			// Carry config keyed by group name over to the new name
+			m.config.RenameGroupSettings(a.OldName, a.NewName)
+
 			m.state.StatusMessage = fmt.Sprintf("Renamed group '%s' to '%s'", a.OldName, a.NewName)
 
 			// Save config
-			if m.bus != nil {
-				m.bus.Publish(eventbus.ConfigChangedEvent{
-					Groups:     m.getGroupsMap(),
-					GroupOrder: m.getGroupOrder(),
-				})
-			}
+			m.configChanged()
 		}
 
+	case inputtypes.CycleGroupTemplateAction:
+		m.cycleGroupTemplate(a.Reset)
+
+	case inputtypes.ConfirmAction:
+		return m.confirm(a.Actions)
+
+	case inputtypes.ResolveConfirmAction:
+		return m.resolveConfirm(a.Confirmed)
+
 	case inputtypes.DeleteGroupAction:
-		if a.GroupName != "" && a.GroupName != "Ungrouped" {
-			// Remove the group
-			delete(m.state.Groups, a.GroupName)
+		if group, ok := m.state.Groups[a.GroupName]; ok && a.GroupName != "Ungrouped" {
+			// Remove the group; its repositories become ungrouped
+			moved := len(group.Repos)
+			m.state.RemoveGroup(a.GroupName)
 
 			// Remove from ordered groups
 			newOrderedGroups := []string{}
@@ -1498,21 +2258,32 @@ func (m *Model) processAction(action inputtypes.Action) tea.Cmd {
 				}
 			}
 			m.state.OrderedGroups = newOrderedGroups
+			if maxIndex := m.getMaxIndex(); m.state.SelectedIndex > maxIndex {
+				m.state.SelectedIndex = maxIndex
+			}
+			m.ensureSelectedVisible()
+			// A group created again under the same name starts without a color
+			delete(m.config.GroupColors, a.GroupName)
 
-			m.state.StatusMessage = fmt.Sprintf("Deleted group '%s'", a.GroupName)
+			if moved > 0 {
+				m.state.StatusMessage = fmt.Sprintf("Deleted group '%s', moved %d repos to Ungrouped", a.GroupName, moved)
+			} else {
+				m.state.StatusMessage = fmt.Sprintf("Deleted group '%s'", a.GroupName)
+			}
 
 			// Publish config changed event
 			if m.bus != nil {
-				m.bus.Publish(eventbus.ConfigChangedEvent{
-					Groups:     m.getGroupsMap(),
-					GroupOrder: m.getGroupOrder(),
-				})
+				m.bus.Publish(eventbus.GroupRemovedEvent{Name: a.GroupName})
 			}
+			m.configChanged()
 		}
 
 	case inputtypes.SubmitTextAction:
 		// Handle text submission based on mode
 		switch a.Mode {
+		case inputtypes.ModeCloneOrg:
+			return m.startCloneOrg(a.Text)
+
 		case inputtypes.ModeNewBranch:
 			name := strings.TrimSpace(a.Text)
 			if name == "" {
@@ -1544,10 +2315,22 @@ func (m *Model) processAction(action inputtypes.Action) tea.Cmd {
 			} else if rp := m.getRepoPathAtIndex(m.state.SelectedIndex); rp != "" {
 				repos = []string{rp}
 			}
-			if len(repos) > 0 {
+			switch {
+			case len(repos) == 1:
 				return m.cmdExecutor.ExecuteSwitchBranch(repos, name)
+			case len(repos) > 1:
+				// Checking out across repos is asked first
+				return m.confirm([]inputtypes.Action{inputtypes.SwitchBranchAction{RepoPaths: repos, Name: name}})
+			}
+			return nil
+		case inputtypes.ModeEditTags:
+			if m.store.GetSelectionCount() > 0 {
+				m.addTagsToSelection(parseTags(a.Text))
+			} else if repoPath := m.getRepoPathAtIndex(m.state.SelectedIndex); repoPath != "" {
+				m.setRepoTags(repoPath, parseTags(a.Text))
 			}
 			return nil
+
 		case inputtypes.ModeSearch:
 			m.state.SearchQuery = a.Text
 			m.performSearch()
@@ -1576,51 +2359,15 @@ func (m *Model) processAction(action inputtypes.Action) tea.Cmd {
 			m.handleSortInput(a.Text)
 		case inputtypes.ModeNewGroup:
 			log.Printf("New group input: %s", a.Text)
-			groupName := strings.TrimSpace(a.Text)
-			if groupName != "" {
-				// Create the new group
-				if m.bus != nil {
-					m.bus.Publish(eventbus.GroupAddedEvent{
-						Name: groupName,
-					})
-				}
-
-				// Move selected repos to the new group
-				movedCount := 0
-				for repoPath := range m.store.GetSelectedRepositories() {
-					// Find current group (if any)
-					var fromGroup string
-					for _, group := range m.state.Groups {
-						for _, path := range group.Repos {
-							if path == repoPath {
-								fromGroup = group.Name
-								break
-							}
-						}
-					}
-
-					// Publish move event
-					m.bus.Publish(eventbus.RepoMovedEvent{
-						RepoPath:  repoPath,
-						FromGroup: fromGroup,
-						ToGroup:   groupName,
-					})
-					movedCount++
-				}
-
-				// Clear selection
-				if movedCount > 0 {
-					m.state.ClearSelection()
-				}
-
-				// Update status message
-				m.state.StatusMessage = fmt.Sprintf("Created group '%s' with %d repo(s)", groupName, movedCount)
-			}
+			return m.createGroup(strings.TrimSpace(a.Text))
 
 		case inputtypes.ModeMoveToGroup:
-			// TODO: Implement move to group
+			return m.moveToGroup(strings.TrimSpace(a.Text))
 		}
 
+	case inputtypes.AnswerCredentialAction:
+		m.answerCredential(a)
+
 	case inputtypes.CancelTextAction:
 		// Clear any partial input
 		m.state.SearchQuery = ""
@@ -1631,12 +2378,31 @@ func (m *Model) processAction(action inputtypes.Action) tea.Cmd {
 	case inputtypes.UpdateTextAction:
 		// Update text in view model is handled in the main Update method
 
+	case inputtypes.StatusFilterAction:
+		m.state.FilterQuery = ""
+		if a.Status != "" {
+			m.state.FilterQuery = "is:" + a.Status
+		}
+		m.state.IsFiltered = a.Status != ""
+		m.updateOrderedLists()
+		m.ensureSelectedVisible()
+
 	case inputtypes.SortByAction:
 		m.handleSortInput(a.Criteria)
 
 	case inputtypes.UpdateSortIndexAction:
 		m.state.SortOptionIndex = a.Index
 
+	case inputtypes.CycleSortAction:
+		m.setSort(m.currentSort.Next())
+
+	case inputtypes.SaveConfigAction:
+		if !m.state.ConfigDirty {
+			m.state.StatusMessage = "No unsaved group changes"
+		} else if m.bus != nil {
+			m.bus.Publish(eventbus.ConfigSaveRequestedEvent{})
+		}
+
 	case inputtypes.HideAction:
 		// Ensure hidden group exists
 		if _, exists := m.state.Groups[HiddenGroupName]; !exists {
@@ -1724,11 +2490,7 @@ func (m *Model) processAction(action inputtypes.Action) tea.Cmd {
 				}
 
 				// Save config
-				if m.bus != nil {
-					m.bus.Publish(eventbus.ConfigChangedEvent{
-						Groups: m.getGroupsMap(),
-					})
-				}
+				m.configChanged()
 			}
 		}
 
@@ -1770,20 +2532,13 @@ func (m *Model) processAction(action inputtypes.Action) tea.Cmd {
 				}
 
 				// Save config
-				if m.bus != nil {
-					m.bus.Publish(eventbus.ConfigChangedEvent{
-						Groups: m.getGroupsMap(),
-					})
-				}
+				m.configChanged()
 			}
 		}
 
 	case inputtypes.QuitAction:
-		if !a.Force && m.config.UISettings.AutosaveOnExit && m.bus != nil {
-			m.bus.Publish(eventbus.ConfigChangedEvent{
-				Groups:     m.getGroupsMap(),
-				GroupOrder: m.getGroupOrder(),
-			})
+		if !a.Force && m.config.UISettings.AutosaveOnExit {
+			m.configChanged()
 		}
 		return tea.Quit
 	}
@@ -1796,9 +2551,10 @@ func (m *Model) handleNonKeyboardMsg(msg tea.Msg) (tea.Model, tea.Cmd) {
 	log.Printf("handleNonKeyboardMsg: %T", msg)
 	switch msg := msg.(type) {
 	case EventMsg:
-		// Process domain events
-		cmd := m.eventHandler.HandleEvent(msg.Event)
-		return m, cmd
+		return m, m.handleEvents([]eventbus.DomainEvent{msg.Event})
+
+	case EventBatchMsg:
+		return m, m.handleEvents(msg.Events)
 
 	case tickMsg:
 		// Don't clear loading state automatically - let scan completion handle it
@@ -1806,7 +2562,10 @@ func (m *Model) handleNonKeyboardMsg(msg tea.Msg) (tea.Model, tea.Cmd) {
 		if m.inPagerMode {
 			return m, nil
 		}
-		return m, tick()
+		if m.state.ExpireToasts(time.Time(msg)) {
+			m.viewState = nil
+		}
+		return m, tea.Batch(tick(), m.warmDetailsPane(time.Time(msg)))
 
 	case gitLogMsg:
 		if msg.err != nil {
@@ -1823,6 +2582,64 @@ func (m *Model) handleNonKeyboardMsg(msg tea.Msg) (tea.Model, tea.Cmd) {
 		}
 		return m, nil
 
+	case commitLogMsg:
+		m.finishCommitLog(msg)
+		return m, nil
+
+	case pullPreviewMsg:
+		m.finishPullPreview(msg)
+		return m, nil
+
+	case unpushedMsg:
+		m.finishUnpushed(msg)
+		return m, nil
+
+	case repoDetailsMsg:
+		m.finishRepoDetails(msg)
+		return m, nil
+
+	case compareTagsMsg:
+		// Ignore tags that arrive after the comparison was closed
+		if m.inputHandler.CurrentMode() == inputtypes.ModeGroupCompare {
+			m.state.CompareTags = msg.tags
+			m.state.InfoContent = m.buildGroupCompareContent()
+		}
+		return m, nil
+
+	case archiveMsg:
+		m.finishArchive(msg)
+		return m, nil
+
+	case activityMsg:
+		// Keep the counts for the session, even when the heatmap was closed meanwhile
+		if m.state.Activity == nil {
+			m.state.Activity = make(map[string]state.RepoActivity)
+		}
+		for path, activity := range msg.activity {
+			m.state.Activity[path] = activity
+		}
+		if m.inputHandler.CurrentMode() == inputtypes.ModeHeatmap && m.state.Heatmap != nil {
+			m.state.Heatmap.Loading = false
+			m.state.InfoContent = m.buildHeatmapContent()
+		}
+		return m, nil
+
+	case healthMsg:
+		m.finishHealth(msg)
+		return m, nil
+
+	case cloneOrgListedMsg:
+		m.listedCloneOrg(msg)
+		return m, nil
+
+	case cleanDryRunMsg:
+		// Ignore results that arrive after the preview was cancelled
+		if m.inputHandler.CurrentMode() == inputtypes.ModeCleanConfirm {
+			m.state.InfoContent = m.buildCleanPreview(msg.results)
+			m.state.CleanTargets = cleanablePaths(msg.results)
+		}
+		return m, nil
+
 	case gitDiffMsg:
 		if msg.err != nil {
 			// Log error and mark repository error state; do not show in status bar
@@ -1876,6 +2693,13 @@ func (m *Model) handleNonKeyboardMsg(msg tea.Msg) (tea.Model, tea.Cmd) {
 		}
 		return m, nil
 
+	case shellExitMsg:
+		if msg.err != nil {
+			m.state.StatusMessage = fmt.Sprintf("Shell exited with error: %v", msg.err)
+			return m, tea.Tick(3*time.Second, func(t time.Time) tea.Msg { return clearStatusMsg{} })
+		}
+		return m, nil
+
 	case pauseRenderingMsg:
 		// Signal that rendering should be paused for external pager
 		m.inPagerMode = true
@@ -1893,11 +2717,8 @@ func (m *Model) handleNonKeyboardMsg(msg tea.Msg) (tea.Model, tea.Cmd) {
 		return m, nil
 
 	case quitMsg:
-		if msg.saveConfig && m.bus != nil {
-			m.bus.Publish(eventbus.ConfigChangedEvent{
-				Groups:     m.getGroupsMap(),
-				GroupOrder: m.getGroupOrder(),
-			})
+		if msg.saveConfig {
+			m.configChanged()
 		}
 		return m, tea.Quit
 
@@ -1907,30 +2728,41 @@ func (m *Model) handleNonKeyboardMsg(msg tea.Msg) (tea.Model, tea.Cmd) {
 	}
 }
 
-// handleSortInput processes sort criteria input
+// handleSortInput processes sort criteria input: a sort's config name, or n, s, b or m
 func (m *Model) handleSortInput(criteria string) {
 	criteria = strings.ToLower(strings.TrimSpace(criteria))
 	switch criteria {
-	case "name", "n":
-		m.currentSort = logic.SortByName
-		m.state.StatusMessage = "Sorting by name"
-	case "status", "s":
-		m.currentSort = logic.SortByStatus
-		m.state.StatusMessage = "Sorting by status"
-	case "branch", "b":
-		m.currentSort = logic.SortByBranch
-		m.state.StatusMessage = "Sorting by branch"
-	case "modified", "m":
-		m.currentSort = logic.SortByStatus
-		m.state.StatusMessage = "Sorting by status"
-	default:
+	case "n":
+		criteria = "name"
+	case "s", "m", "modified":
+		criteria = "status"
+	case "b":
+		criteria = "branch"
+	}
+	mode, err := logic.ParseSortMode(criteria)
+	if err != nil {
 		m.state.StatusMessage = fmt.Sprintf("Unknown sort criteria: %s", criteria)
 		return
 	}
+	m.setSort(mode)
+}
+
+// setSort re-sorts the list (keeping the cursor on the same item) and saves the
+// choice as ui.sort
+func (m *Model) setSort(mode logic.SortMode) {
+	m.currentSort = mode
+	m.state.SortLabel = sortLabel(mode)
+	m.state.StatusMessage = "Sorting by " + mode.Label()
 
-	// Update the sort order
+	anchors := m.captureListAnchors()
 	m.updateOrderedLists()
-	m.ensureSelectedVisible()
+	m.restoreListAnchors(anchors)
+
+	m.config.UISettings.Sort = ""
+	if mode != logic.SortByName {
+		m.config.UISettings.Sort = mode.String()
+	}
+	m.configChanged()
 }
 
 // ensureSelectedVisible ensures the selected item is visible in the viewport
@@ -1981,11 +2813,6 @@ func (m *Model) pageDown() {
 	m.ensureSelectedVisible()
 }
 
-// countVisibleRepos counts the total number of repositories
-func (m *Model) countVisibleRepos() int {
-	return len(m.state.Repositories)
-}
-
 // tick returns a command that sends a tick message after a delay
 func tick() tea.Cmd {
 	return tea.Tick(time.Millisecond*100, func(t time.Time) tea.Msg {
@@ -1993,6 +2820,24 @@ func tick() tea.Cmd {
 	})
 }
 
+// configChanged takes the groups as shown into the config, hands a copy of it to whoever
+// shares it and publishes a ConfigChangedEvent, which saves it when autosaving. Call it
+// after every change to the config.
+func (m *Model) configChanged() {
+	m.config.Groups = m.getGroupsMap()
+	m.config.GroupOrder = m.getGroupOrder()
+	snapshot := m.config.Clone()
+	if m.shareConfig != nil {
+		m.shareConfig(snapshot)
+	}
+	if m.bus != nil {
+		m.bus.Publish(eventbus.ConfigChangedEvent{
+			Groups:     snapshot.Groups,
+			GroupOrder: snapshot.GroupOrder,
+		})
+	}
+}
+
 // getGroupsMap returns a map of group names to repository paths
 func (m *Model) getGroupsMap() map[string][]string {
 	return m.state.GetGroupsMap()
@@ -2063,6 +2908,21 @@ func (m *Model) performSearch() {
 	query := strings.ToLower(m.state.SearchQuery)
 	currentIdx := 0
 
+	// "tag:" searches match the repositories carrying a tag instead of names,
+	// "is:"/"status:" searches the repositories in a state (e.g. is:stale)
+	tag, isTagSearch := strings.CutPrefix(query, "tag:")
+	statusFilter, isStatusSearch := logic.CutStatusPrefix(query)
+	statusMatcher := logic.NewSearchFilter(m.state.Repositories)
+	matchesRepo := func(repo *domain.Repository) bool {
+		if isTagSearch {
+			return repo.HasTag(tag)
+		}
+		if isStatusSearch {
+			return statusMatcher.MatchesStatusFilter(repo, statusFilter)
+		}
+		return strings.Contains(strings.ToLower(repo.Name), query)
+	}
+
 	// Search through ALL repositories in the display order
 	// This should match exactly what the UI renders
 
@@ -2074,7 +2934,7 @@ func (m *Model) performSearch() {
 		}
 
 		// Check if group name matches
-		if strings.Contains(strings.ToLower(groupName), query) {
+		if !isTagSearch && !isStatusSearch && strings.Contains(strings.ToLower(groupName), query) {
 			m.state.SearchMatches = append(m.state.SearchMatches, currentIdx)
 			log.Printf("Search match found at index %d: Group %s", currentIdx, groupName)
 		}
@@ -2086,7 +2946,7 @@ func (m *Model) performSearch() {
 			for _, repoPath := range group.Repos {
 				// Get repository from the main repositories map
 				if repo, exists := m.state.Repositories[repoPath]; exists {
-					if strings.Contains(strings.ToLower(repo.Name), query) {
+					if matchesRepo(repo) {
 						m.state.SearchMatches = append(m.state.SearchMatches, currentIdx)
 						log.Printf("Search match found at index %d: %s (in group %s)", currentIdx, repo.Name, groupName)
 					}
@@ -2115,7 +2975,7 @@ func (m *Model) performSearch() {
 
 		if hasUngroupedHeader {
 			// Check ungrouped header
-			if strings.Contains(strings.ToLower("Ungrouped"), query) {
+			if !isTagSearch && !isStatusSearch && strings.Contains(strings.ToLower("Ungrouped"), query) {
 				m.state.SearchMatches = append(m.state.SearchMatches, currentIdx)
 				log.Printf("Search match found at index %d: Ungrouped", currentIdx)
 			}
@@ -2124,7 +2984,7 @@ func (m *Model) performSearch() {
 			for _, repoPath := range ungroupedRepos {
 				// Get repository from the main repositories map
 				if repo, exists := m.state.Repositories[repoPath]; exists {
-					if strings.Contains(strings.ToLower(repo.Name), query) {
+					if matchesRepo(repo) {
 						m.state.SearchMatches = append(m.state.SearchMatches, currentIdx)
 						log.Printf("Search match found at index %d: %s (ungrouped)", currentIdx, repo.Name)
 					}
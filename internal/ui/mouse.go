@@ -0,0 +1,103 @@
+package ui
+
+import (
+	"fmt"
+
+	tea "github.com/charmbracelet/bubbletea/v2"
+
+	inputtypes "github.com/darksworm/gitagrip/internal/ui/input/types"
+	"github.com/darksworm/gitagrip/internal/ui/views"
+)
+
+// wheelStep is how many rows a scroll wheel notch moves the cursor
+const wheelStep = 3
+
+// handleMouse routes mouse input in normal mode: clicking a repository moves the
+// cursor to it, clicking a group header collapses or expands the group, dragging
+// across repositories selects them and the wheel scrolls the list
+func (m *Model) handleMouse(msg tea.MouseMsg) tea.Cmd {
+	if m.inputHandler.CurrentMode() != inputtypes.ModeNormal || m.state.ShowLog || m.state.ShowInfo {
+		return nil
+	}
+
+	mouse := msg.Mouse()
+	switch msg.(type) {
+	case tea.MouseWheelMsg:
+		direction := ""
+		switch mouse.Button {
+		case tea.MouseWheelUp:
+			direction = "up"
+		case tea.MouseWheelDown:
+			direction = "down"
+		}
+		if direction != "" {
+			for i := 0; i < wheelStep; i++ {
+				m.processAction(inputtypes.NavigateAction{Direction: direction})
+			}
+		}
+
+	case tea.MouseClickMsg:
+		if mouse.Button != tea.MouseLeft {
+			return nil
+		}
+		// Clicks on the details pane (2 columns of padding, then the list) are left alone
+		if list, _ := views.PaneWidths(m.width); m.twoPane() && mouse.X >= 2+list {
+			return nil
+		}
+		index, ok := m.listIndexAt(mouse.Y)
+		if !ok {
+			return nil
+		}
+		m.state.SelectedIndex = index
+		if groupName := m.getSelectedGroup(); groupName != "" {
+			m.state.ExpandedGroups[groupName] = !m.state.ExpandedGroups[groupName]
+		} else {
+			m.state.Dragging = true
+			m.state.DragAnchor = index
+		}
+		m.ensureSelectedVisible()
+
+	case tea.MouseMotionMsg:
+		if !m.state.Dragging || mouse.Button != tea.MouseLeft {
+			return nil
+		}
+		index, ok := m.listIndexAt(mouse.Y)
+		if !ok || index == m.state.SelectedIndex {
+			return nil
+		}
+		m.state.SelectedIndex = index
+		m.selectRange(m.state.DragAnchor, index)
+		m.ensureSelectedVisible()
+
+	case tea.MouseReleaseMsg:
+		m.state.Dragging = false
+	}
+
+	return nil
+}
+
+// listIndexAt returns the list index of the repository or group header on screen row y
+func (m *Model) listIndexAt(y int) (int, bool) {
+	index, ok := m.renderer.ListRowIndex(m.buildViewState(), y)
+	if !ok || index > m.getMaxIndex() || m.isOnGap(index) {
+		return 0, false
+	}
+	return index, true
+}
+
+// selectRange adds every repository between two list indices (inclusive) to the selection
+func (m *Model) selectRange(from, to int) {
+	if from > to {
+		from, to = to, from
+	}
+	count := 0
+	for i := from; i <= to; i++ {
+		if repoPath := m.getRepoPathAtIndex(i); repoPath != "" {
+			m.state.SelectedRepos[repoPath] = true
+			count++
+		}
+	}
+	if count > 0 {
+		m.state.StatusMessage = fmt.Sprintf("Selected %d repositories", len(m.state.SelectedRepos))
+	}
+}
@@ -0,0 +1,342 @@
+package ui
+
+import (
+	"fmt"
+	"reflect"
+	"sort"
+	"strings"
+	"testing"
+
+	tea "github.com/charmbracelet/bubbletea/v2"
+
+	"github.com/darksworm/gitagrip/internal/config"
+	"github.com/darksworm/gitagrip/internal/domain"
+	"github.com/darksworm/gitagrip/internal/eventbus"
+	"github.com/darksworm/gitagrip/internal/ui/input"
+	inputtypes "github.com/darksworm/gitagrip/internal/ui/input/types"
+	"github.com/darksworm/gitagrip/internal/ui/termcaps"
+)
+
+// organizeKeys are the keys the fuzzer presses: navigation, folding, selection, group
+// editing and prompt answers, plus a couple of letters that only matter when typed into
+// a prompt. Keys that run git or open other programs are left out.
+var organizeKeys = []string{
+	"j", "k", "g", "G", "h", "l", "z", " ", "a", "A", "u", "V",
+	"m", "N", "d", "r", "R", "J", "K", "S", "y", "n", "x", "w",
+	"enter", "esc", "backspace",
+}
+
+// organizePrompts are the text prompts reachable with organizeKeys, where any letter is typed
+var organizePrompts = map[inputtypes.Mode]bool{
+	inputtypes.ModeNewGroup:    true,
+	inputtypes.ModeMoveToGroup: true,
+	inputtypes.ModeRenameGroup: true,
+	inputtypes.ModeSort:        true,
+}
+
+// organizeRepos are the repositories discovered in every run, grouped as in organizeConfig
+var organizeRepos = []string{
+	"/work/api/users",
+	"/work/api/billing",
+	"/work/web/site",
+	"/work/old/legacy",
+	"/work/tool",
+	"/work/notes",
+}
+
+func organizeConfig() *config.Config {
+	return &config.Config{
+		Version: 1,
+		BaseDir: "/work",
+		Groups: map[string][]string{
+			"api":           {"/work/api/users", "/work/api/billing"},
+			"web":           {"/work/web/site"},
+			HiddenGroupName: {"/work/old/legacy"},
+		},
+		GroupOrder: []string{"api", "web"},
+	}
+}
+
+// recordingBus keeps the config saves requested by the model instead of dispatching events
+type recordingBus struct {
+	saves []eventbus.ConfigChangedEvent
+}
+
+func (b *recordingBus) Publish(event eventbus.DomainEvent) {
+	if e, ok := event.(eventbus.ConfigChangedEvent); ok {
+		b.saves = append(b.saves, e)
+	}
+}
+
+func (b *recordingBus) Subscribe(eventbus.EventType, eventbus.EventHandler) func() {
+	return func() {}
+}
+
+// FuzzOrganize drives random key sequences through the organize commands (moving,
+// creating, renaming, deleting and reordering groups, folding, selecting) and checks
+// after every key that the groups still partition the repositories and that every
+// index-based lookup agrees with the list as it is laid out on screen.
+// Run it with: go test -run='^$' -fuzz=FuzzOrganize ./internal/ui
+func FuzzOrganize(f *testing.F) {
+	f.Add("jjjjjjjjjjkkkkkkkkkkGgg")
+	f.Add("zjzjjzhl")
+	f.Add("jj mnew\r")
+	f.Add("Gmapi\rgg")
+	f.Add("G k Nteam\rjjJJKK")
+	f.Add("ArRenamed\rjjjjjjdy")
+	f.Add("dyjdyjdyGmx\r")
+	f.Add("jVjjjmweb\rG")
+	f.Add("Gm_Hidden\rGkm_Hidden\r")
+	f.Add("jjma\x1bmUngrouped\r")
+	f.Add("Sname\rjjam\x7f\x7fw\r")
+
+	f.Fuzz(func(t *testing.T, keys string) {
+		bus := &recordingBus{}
+		m := NewModel(bus, organizeConfig(), termcaps.Capabilities{})
+		m.Update(tea.WindowSizeMsg{Width: 100, Height: 14})
+		for _, path := range organizeRepos {
+			name := path[strings.LastIndex(path, "/")+1:]
+			m.Update(EventMsg{Event: eventbus.RepoDiscoveredEvent{Repo: domain.Repository{Path: path, Name: name, DisplayName: name}}})
+		}
+		if err := checkOrganizeInvariants(m, nil); err != nil {
+			t.Fatalf("initial state: %v", err)
+		}
+
+		for i := 0; i < len(keys); i++ {
+			mode := m.inputHandler.CurrentMode()
+			key := organizeKey(keys[i], organizePrompts[mode])
+			if key == "enter" && (mode == inputtypes.ModeNormal || mode == inputtypes.ModeVisual) {
+				continue // opens lazygit
+			}
+
+			saved := len(bus.saves)
+			m.Update(keyPress(key))
+			_ = m.View()
+
+			var save *eventbus.ConfigChangedEvent
+			if len(bus.saves) > saved {
+				save = &bus.saves[len(bus.saves)-1]
+			}
+			if err := checkOrganizeInvariants(m, save); err != nil {
+				t.Fatalf("after %q (key %d of %q): %v", key, i, keys, err)
+			}
+		}
+	})
+}
+
+// organizeKey maps a fuzz byte to a key. \r, ESC, DEL and the keys of organizeKeys (any
+// letter while typing into a prompt) stand for themselves so seeds stay readable.
+func organizeKey(b byte, typing bool) string {
+	switch b {
+	case '\r':
+		return "enter"
+	case 0x1b:
+		return "esc"
+	case 0x7f:
+		return "backspace"
+	}
+	if typing && (b == '_' || (b >= 'a' && b <= 'z') || (b >= 'A' && b <= 'Z')) {
+		return string(b)
+	}
+	for _, key := range organizeKeys {
+		if key == string(b) {
+			return key
+		}
+	}
+	return organizeKeys[int(b)%len(organizeKeys)]
+}
+
+func keyPress(key string) tea.KeyPressMsg {
+	switch key {
+	case "enter":
+		return tea.KeyPressMsg{Code: tea.KeyEnter}
+	case "esc":
+		return tea.KeyPressMsg{Code: tea.KeyEscape}
+	case "backspace":
+		return tea.KeyPressMsg{Code: tea.KeyBackspace}
+	}
+	return tea.KeyPressMsg{Code: rune(key[0]), Text: key}
+}
+
+// organizeRow is one line of the reference layout: a group header, a repository (with
+// the group it is listed under, "" when ungrouped) or a gap
+type organizeRow struct {
+	header string
+	repo   string
+	group  string
+}
+
+// referenceLayout lays out the list from the groups and their fold state: each group's
+// header, its repositories when expanded and a gap unless it is the hidden group at the
+// end, then the ungrouped repositories in display order
+func referenceLayout(m *Model) []organizeRow {
+	var rows []organizeRow
+	grouped := make(map[string]bool)
+	for i, name := range m.state.OrderedGroups {
+		rows = append(rows, organizeRow{header: name})
+		for _, path := range m.state.Groups[name].Repos {
+			grouped[path] = true
+			if m.state.ExpandedGroups[name] {
+				rows = append(rows, organizeRow{repo: path, group: name})
+			}
+		}
+		if name != HiddenGroupName || i != len(m.state.OrderedGroups)-1 {
+			rows = append(rows, organizeRow{})
+		}
+	}
+	for _, path := range m.state.OrderedRepos {
+		if !grouped[path] {
+			rows = append(rows, organizeRow{repo: path})
+		}
+	}
+	return rows
+}
+
+// checkOrganizeInvariants compares the model against the reference layout and, when the
+// last key saved the config, the saved groups against the ones on screen
+func checkOrganizeInvariants(m *Model, save *eventbus.ConfigChangedEvent) error {
+	// Every repository is listed exactly once: in one group or ungrouped
+	discovered := make(map[string]bool, len(organizeRepos))
+	for _, path := range organizeRepos {
+		discovered[path] = true
+	}
+	if got := sortedCopy(m.state.OrderedRepos); !reflect.DeepEqual(got, sortedCopy(organizeRepos)) {
+		return fmt.Errorf("ordered repos %v, want %v", got, sortedCopy(organizeRepos))
+	}
+	owner := make(map[string]string)
+	for name, group := range m.state.Groups {
+		for _, path := range group.Repos {
+			if !discovered[path] {
+				return fmt.Errorf("group %q holds unknown repo %s", name, path)
+			}
+			if other, ok := owner[path]; ok {
+				return fmt.Errorf("repo %s is in both %q and %q", path, other, name)
+			}
+			owner[path] = name
+		}
+	}
+	ungrouped := m.getUngroupedRepos()
+	for _, path := range ungrouped {
+		if _, ok := owner[path]; ok {
+			return fmt.Errorf("repo %s is ungrouped but in group %q", path, owner[path])
+		}
+	}
+	if len(owner)+len(ungrouped) != len(organizeRepos) {
+		return fmt.Errorf("%d grouped + %d ungrouped repos, want %d", len(owner), len(ungrouped), len(organizeRepos))
+	}
+
+	// Groups are listed once each, the hidden one last
+	if got, want := sortedCopy(m.state.OrderedGroups), sortedKeys(m.state.Groups); !reflect.DeepEqual(got, want) {
+		return fmt.Errorf("ordered groups %v, want %v", m.state.OrderedGroups, want)
+	}
+	for i, name := range m.state.OrderedGroups {
+		if name == HiddenGroupName && i != len(m.state.OrderedGroups)-1 {
+			return fmt.Errorf("hidden group is not last in %v", m.state.OrderedGroups)
+		}
+	}
+
+	for path := range m.state.SelectedRepos {
+		if !discovered[path] {
+			return fmt.Errorf("unknown repo %s is selected", path)
+		}
+	}
+
+	// The cursor stays on the list and every lookup agrees with the layout
+	rows := referenceLayout(m)
+	if got := m.getMaxIndex(); got != len(rows)-1 {
+		return fmt.Errorf("max index %d, want %d", got, len(rows)-1)
+	}
+	if m.state.SelectedIndex < 0 || m.state.SelectedIndex >= len(rows) {
+		return fmt.Errorf("cursor %d outside the %d list rows", m.state.SelectedIndex, len(rows))
+	}
+
+	ctx := &input.ModelContext{
+		State:          m.state,
+		Store:          m.store,
+		Navigator:      m.navigator,
+		CurrentSort:    m.currentSort,
+		UngroupedRepos: ungrouped,
+	}
+	if got := ctx.TotalItems(); got != len(rows) {
+		return fmt.Errorf("input context counts %d rows, want %d", got, len(rows))
+	}
+	keys := m.listItemKeys()
+	if len(keys) != len(rows) {
+		return fmt.Errorf("%d anchor keys, want %d", len(keys), len(rows))
+	}
+
+	for i, row := range rows {
+		isGap := row.header == "" && row.repo == ""
+		if got := m.getRepoPathAtIndex(i); got != row.repo {
+			return fmt.Errorf("row %d: model repo %q, want %q", i, got, row.repo)
+		}
+		if got := ctx.GetRepoPathAtIndex(i); got != row.repo {
+			return fmt.Errorf("row %d: input context repo %q, want %q", i, got, row.repo)
+		}
+		if got := m.isOnGap(i); got != isGap {
+			return fmt.Errorf("row %d: gap %v, want %v", i, got, isGap)
+		}
+
+		wantGroup := row.header
+		if row.repo != "" {
+			wantGroup = row.group
+			if wantGroup == "" {
+				wantGroup = "Ungrouped"
+			}
+		}
+		if got := m.getGroupAtIndex(i); got != wantGroup {
+			return fmt.Errorf("row %d: group %q, want %q", i, got, wantGroup)
+		}
+
+		var wantKey string
+		switch {
+		case row.header != "":
+			wantKey = "group:" + row.header
+			if got := m.getCurrentIndexForGroup(row.header); got != i {
+				return fmt.Errorf("group %q: header index %d, want %d", row.header, got, i)
+			}
+		case row.repo != "":
+			wantKey = "repo:" + row.repo
+		}
+		if keys[i] != wantKey {
+			return fmt.Errorf("row %d: anchor key %q, want %q", i, keys[i], wantKey)
+		}
+	}
+
+	cursor := rows[m.state.SelectedIndex]
+	if got := m.getSelectedGroup(); got != cursor.header {
+		return fmt.Errorf("cursor %d: selected group %q, want %q", m.state.SelectedIndex, got, cursor.header)
+	}
+	if got := ctx.IsOnGroup(); got != (cursor.header != "") {
+		return fmt.Errorf("cursor %d: input context on group %v", m.state.SelectedIndex, got)
+	}
+	if got := ctx.CurrentGroupName(); got != cursor.header {
+		return fmt.Errorf("cursor %d: input context group %q, want %q", m.state.SelectedIndex, got, cursor.header)
+	}
+
+	// What gets saved is what is shown
+	if save != nil {
+		if !reflect.DeepEqual(save.Groups, m.getGroupsMap()) {
+			return fmt.Errorf("saved groups %v, shown %v", save.Groups, m.getGroupsMap())
+		}
+		if !reflect.DeepEqual(save.GroupOrder, m.getGroupOrder()) {
+			return fmt.Errorf("saved group order %v, shown %v", save.GroupOrder, m.getGroupOrder())
+		}
+	}
+	return nil
+}
+
+func sortedCopy(values []string) []string {
+	sorted := append([]string(nil), values...)
+	sort.Strings(sorted)
+	return sorted
+}
+
+func sortedKeys[V any](m map[string]V) []string {
+	keys := make([]string, 0, len(m))
+	for key := range m {
+		keys = append(keys, key)
+	}
+	sort.Strings(keys)
+	return keys
+}
@@ -0,0 +1,62 @@
+package ui
+
+import (
+	"fmt"
+	"strings"
+
+	"github.com/charmbracelet/lipgloss/v2"
+
+	inputtypes "github.com/darksworm/gitagrip/internal/ui/input/types"
+)
+
+// buildPaletteContent renders the command palette: the query being typed and the actions
+// matching it, best first, with the keys that run them directly
+func (m *Model) buildPaletteContent(a inputtypes.ShowPaletteAction) string {
+	bold := lipgloss.NewStyle().Bold(true)
+	faint := lipgloss.NewStyle().Faint(true)
+
+	var b strings.Builder
+	b.WriteString(bold.Render("Command Palette"))
+	b.WriteString("\n\n> ")
+	if ti := m.inputHandler.TextInput(); ti != nil {
+		b.WriteString(ti.View())
+	}
+	b.WriteString("\n\n")
+
+	if len(a.Entries) == 0 {
+		b.WriteString(faint.Render("No matching actions"))
+		b.WriteString("\n")
+	}
+
+	// Show a window of entries around the cursor when they don't all fit
+	visible := max(5, m.height-14)
+	start := 0
+	if a.Index >= visible {
+		start = a.Index - visible + 1
+	}
+	end := min(len(a.Entries), start+visible)
+
+	descWidth := 0
+	for _, entry := range a.Entries[start:end] {
+		descWidth = max(descWidth, min(lipgloss.Width(entry.Description), 60))
+	}
+	for i := start; i < end; i++ {
+		entry := a.Entries[i]
+		desc := truncateCells(entry.Description, descWidth)
+		desc += strings.Repeat(" ", descWidth-lipgloss.Width(desc))
+		cursor := "  "
+		if i == a.Index {
+			cursor = "▸ "
+			desc = bold.Render(desc)
+		}
+		b.WriteString(fmt.Sprintf("%s%s  %s\n", cursor, desc, faint.Render(entry.Keys+"  "+entry.Action)))
+	}
+	if end < len(a.Entries) {
+		b.WriteString(faint.Render(fmt.Sprintf("  … %d more", len(a.Entries)-end)))
+		b.WriteString("\n")
+	}
+
+	b.WriteString("\n")
+	b.WriteString(faint.Render("type to search • ↑/↓ move • enter run • esc close"))
+	return b.String()
+}
@@ -0,0 +1,89 @@
+package ui
+
+import (
+	"strings"
+
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/charmbracelet/lipgloss/v2"
+
+	"github.com/darksworm/gitagrip/internal/config"
+	inputtypes "github.com/darksworm/gitagrip/internal/ui/input/types"
+	"github.com/darksworm/gitagrip/internal/ui/state"
+)
+
+// openProfiles lists the workspace profiles, with the cursor on the one in use
+func (m *Model) openProfiles() {
+	picker := &state.ProfilePicker{Current: m.profile}
+	names, err := config.ListProfiles()
+	if err != nil {
+		picker.Err = err.Error()
+	}
+	picker.Names = names
+	for i, name := range names {
+		if name == m.profile {
+			picker.Index = i
+		}
+	}
+	m.state.Profiles = picker
+	m.state.ShowInfo = true
+	m.state.InfoContent = m.buildProfilesContent()
+}
+
+// navigateProfiles moves the cursor of the profile list, stopping at either end
+func (m *Model) navigateProfiles(delta int) {
+	picker := m.state.Profiles
+	if len(picker.Names) == 0 {
+		return
+	}
+	picker.Index = max(0, min(len(picker.Names)-1, picker.Index+delta))
+	m.state.InfoContent = m.buildProfilesContent()
+}
+
+// switchProfile quits like q does, leaving the picked profile for main to restart with
+func (m *Model) switchProfile() tea.Cmd {
+	picker := m.state.Profiles
+	if len(picker.Names) == 0 {
+		return nil
+	}
+	name := picker.Names[picker.Index]
+	if name == picker.Current {
+		m.state.StatusMessage = "Already using profile " + name
+		return nil
+	}
+	m.nextProfile = name
+	return m.processAction(inputtypes.QuitAction{Force: false})
+}
+
+// buildProfilesContent renders the profile list with the one in use marked
+func (m *Model) buildProfilesContent() string {
+	picker := m.state.Profiles
+	bold := lipgloss.NewStyle().Bold(true)
+	faint := lipgloss.NewStyle().Faint(true)
+
+	var b strings.Builder
+	b.WriteString(bold.Render("Workspace profiles"))
+	b.WriteString(faint.Render("  (" + config.ProfilesDir() + ")"))
+	b.WriteString("\n\n")
+	switch {
+	case picker.Err != "":
+		b.WriteString("Could not list profiles: " + picker.Err + "\n")
+	case len(picker.Names) == 0:
+		b.WriteString(faint.Render("No profiles yet; create one with gitagrip -profile <name> -d <dir>"))
+		b.WriteString("\n")
+	}
+	for i, name := range picker.Names {
+		marker := "  "
+		if i == picker.Index {
+			marker = "› "
+		}
+		line := marker + name
+		if name == picker.Current {
+			line += faint.Render("  (in use)")
+		}
+		b.WriteString(line + "\n")
+	}
+
+	b.WriteString("\n")
+	b.WriteString(faint.Render("j/k move • enter switch • esc close"))
+	return b.String()
+}
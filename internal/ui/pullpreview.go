@@ -0,0 +1,169 @@
+package ui
+
+import (
+	"fmt"
+	"sort"
+	"strings"
+
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/charmbracelet/lipgloss/v2"
+
+	inputtypes "github.com/darksworm/gitagrip/internal/ui/input/types"
+	"github.com/darksworm/gitagrip/internal/ui/state"
+)
+
+// previewPull opens the dry run of a bulk pull and checks in the background whether each
+// repository would fast-forward, need a merge or conflict
+func (m *Model) previewPull(repoPaths []string, scope string) tea.Cmd {
+	paths := append([]string(nil), repoPaths...)
+	sort.Slice(paths, func(i, j int) bool {
+		return m.compareName(paths[i]) < m.compareName(paths[j])
+	})
+	upstreams := make(map[string]string, len(paths))
+	for _, path := range paths {
+		upstreams[path] = m.state.Repositories[path].Status.Upstream
+	}
+
+	m.state.PullPreview = &state.PullPreview{
+		Scope:    scope,
+		Repos:    paths,
+		Outlooks: make(map[string]state.PullOutlook),
+		Included: make(map[string]bool),
+		Loading:  true,
+	}
+	m.state.ShowInfo = true
+	m.state.InfoContent = m.buildPullPreviewContent()
+
+	return tea.Batch(m.enterMode(inputtypes.ModePullPreview), func() tea.Msg {
+		outlooks := make(map[string]state.PullOutlook, len(upstreams))
+		for path, upstream := range upstreams {
+			outlooks[path] = m.gitOps.PullOutlook(path, upstream)
+		}
+		return pullPreviewMsg{outlooks: outlooks}
+	})
+}
+
+// finishPullPreview shows the dry run and includes the repositories that pull cleanly,
+// unless the preview was closed meanwhile
+func (m *Model) finishPullPreview(msg pullPreviewMsg) {
+	view := m.state.PullPreview
+	if view == nil {
+		return
+	}
+	view.Outlooks = msg.outlooks
+	for path, outlook := range msg.outlooks {
+		view.Included[path] = outlook.Verdict == state.PullFastForward || outlook.Verdict == state.PullUpToDate
+	}
+	view.Loading = false
+	m.state.InfoContent = m.buildPullPreviewContent()
+}
+
+// navigatePullPreview moves the cursor between the repositories of the dry run
+func (m *Model) navigatePullPreview(delta int) {
+	view := m.state.PullPreview
+	view.Index = max(0, min(view.Index+delta, len(view.Repos)-1))
+	m.state.InfoContent = m.buildPullPreviewContent()
+}
+
+// togglePullPreview includes the repository under the cursor in the pull, or leaves it out
+func (m *Model) togglePullPreview() {
+	view := m.state.PullPreview
+	if view.Loading || len(view.Repos) == 0 {
+		return
+	}
+	path := view.Repos[view.Index]
+	view.Included[path] = !view.Included[path]
+	m.state.InfoContent = m.buildPullPreviewContent()
+}
+
+// pullPreviewed pulls the repositories the dry run includes
+func (m *Model) pullPreviewed() tea.Cmd {
+	view := m.state.PullPreview
+	if view.Loading {
+		return nil
+	}
+	var repoPaths []string
+	for _, path := range view.Repos {
+		if view.Included[path] {
+			repoPaths = append(repoPaths, path)
+		}
+	}
+	if len(repoPaths) == 0 {
+		m.state.StatusMessage = "Nothing pulled: every repo was left out"
+		return nil
+	}
+	m.state.StatusMessage = fmt.Sprintf("Pulling %d of %d repos", len(repoPaths), len(view.Repos))
+	return m.cmdExecutor.ExecutePull(repoPaths)
+}
+
+// buildPullPreviewContent renders the verdict of each repository with a checkbox showing
+// whether it is pulled
+func (m *Model) buildPullPreviewContent() string {
+	view := m.state.PullPreview
+	theme := m.renderer.Theme()
+	bold := lipgloss.NewStyle().Bold(true)
+	faint := lipgloss.NewStyle().Faint(true)
+	verdictStyles := map[string]lipgloss.Style{
+		state.PullFastForward: lipgloss.NewStyle().Foreground(lipgloss.Color(theme.Success)),
+		state.PullMerge:       lipgloss.NewStyle().Foreground(lipgloss.Color(theme.Warning)),
+		state.PullConflict:    lipgloss.NewStyle().Foreground(lipgloss.Color(theme.Error)),
+	}
+
+	nameWidth := 0
+	for _, path := range view.Repos {
+		nameWidth = max(nameWidth, min(lipgloss.Width(m.compareName(path)), 30))
+	}
+
+	var b strings.Builder
+	included := 0
+	for _, path := range view.Repos {
+		if view.Included[path] {
+			included++
+		}
+	}
+	b.WriteString(bold.Render(fmt.Sprintf("Pull %d of %s", included, view.Scope)))
+	b.WriteString(faint.Render("  (dry run against the last fetch; conflicts estimated by a trial merge)"))
+	b.WriteString("\n\n")
+	if view.Loading {
+		b.WriteString(faint.Render("Checking merge bases..."))
+		b.WriteString("\n\n")
+		b.WriteString(faint.Render("n cancel"))
+		return b.String()
+	}
+
+	width := max(40, m.width-14)
+	for i, path := range view.Repos {
+		marker := "  "
+		if i == view.Index {
+			marker = "› "
+		}
+		box := "[ ]"
+		if view.Included[path] {
+			box = "[x]"
+		}
+		outlook := view.Outlooks[path]
+		verdict := outlook.Verdict
+		switch {
+		case outlook.Err != "":
+			verdict = "error: " + outlook.Err
+		case verdict == state.PullFastForward:
+			verdict = fmt.Sprintf("%s %d commits", verdict, outlook.Behind)
+		case verdict == state.PullMerge:
+			verdict = fmt.Sprintf("%s (ahead %d, behind %d)", verdict, outlook.Ahead, outlook.Behind)
+		case verdict == state.PullConflict:
+			verdict = fmt.Sprintf("%s in %s", verdict, strings.Join(outlook.Conflicts, ", "))
+		}
+		verdict = truncateCells(verdict, max(10, width-nameWidth-8))
+		if style, ok := verdictStyles[outlook.Verdict]; ok && outlook.Err == "" {
+			verdict = style.Render(verdict)
+		} else {
+			verdict = faint.Render(verdict)
+		}
+		name := fmt.Sprintf("%-*s", nameWidth, truncateCells(m.compareName(path), nameWidth))
+		b.WriteString(fmt.Sprintf("%s%s %s  %s\n", marker, box, name, verdict))
+	}
+
+	b.WriteString("\n")
+	b.WriteString(faint.Render("j/k move • space include/leave out • y pull • n cancel"))
+	return b.String()
+}
@@ -0,0 +1,137 @@
+package ui
+
+import (
+	"fmt"
+	"sort"
+	"strings"
+
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/charmbracelet/lipgloss/v2"
+
+	"github.com/darksworm/gitagrip/internal/ui/state"
+)
+
+// pushCandidates returns the repositories a push looks at, ordered by name: the selection,
+// the group under the cursor or every repository, and what they are
+func (m *Model) pushCandidates() ([]string, string) {
+	var paths []string
+	scope := "all repos"
+	switch {
+	case m.store.GetSelectionCount() > 0:
+		for path := range m.store.GetSelectedRepositories() {
+			paths = append(paths, path)
+		}
+		scope = "the selected repos"
+	case m.getGroupAtIndex(m.state.SelectedIndex) != "":
+		group := m.getGroupAtIndex(m.state.SelectedIndex)
+		paths = m.groupMembers(group)
+		scope = "the repos in " + group
+	default:
+		for path := range m.state.Repositories {
+			paths = append(paths, path)
+		}
+	}
+	sort.Slice(paths, func(i, j int) bool {
+		return m.compareName(paths[i]) < m.compareName(paths[j])
+	})
+	return paths, scope
+}
+
+// pushRefusal explains why a repository with unpushed commits is left out of a push
+// ("" when it is pushed)
+func (m *Model) pushRefusal(repoPath string) string {
+	repo := m.state.Repositories[repoPath]
+	label := m.config.Guards.ProtectedLabelName()
+	switch {
+	case m.config.HasLabel(repoPath, label):
+		return fmt.Sprintf("labeled %q", label)
+	case repo.Status.HeadCommit != "":
+		return "HEAD is detached"
+	case repo.Status.InProgress != "":
+		return string(repo.Status.InProgress) + " in progress"
+	case repo.Status.BehindCount > 0:
+		return fmt.Sprintf("behind %d, pull first", repo.Status.BehindCount)
+	case m.store.IsRepositoryFetching(repoPath) || m.store.IsRepositoryPulling(repoPath):
+		return "an operation is running on it"
+	}
+	return ""
+}
+
+// promptPush lists the repositories with unpushed commits that a push would send, and
+// those it leaves out with why
+func (m *Model) promptPush() {
+	candidates, scope := m.pushCandidates()
+	m.state.PushTargets = nil
+	refused := make(map[string]string)
+	for _, path := range candidates {
+		repo := m.state.Repositories[path]
+		if repo.Status.AheadCount == 0 || repo.Status.Error != "" || repo.Status.Branch == "⋯" {
+			continue
+		}
+		if refusal := m.pushRefusal(path); refusal != "" {
+			refused[path] = refusal
+			continue
+		}
+		m.state.PushTargets = append(m.state.PushTargets, path)
+	}
+	m.state.ShowInfo = true
+	m.state.InfoContent = m.buildPushPreview(scope, candidates, refused)
+}
+
+// buildPushPreview renders which branch of each repository is pushed where, followed by
+// the ahead repositories left out
+func (m *Model) buildPushPreview(scope string, candidates []string, refused map[string]string) string {
+	bold := lipgloss.NewStyle().Bold(true)
+	faint := lipgloss.NewStyle().Faint(true)
+
+	nameWidth := 0
+	for _, path := range candidates {
+		if m.state.Repositories[path].Status.AheadCount > 0 {
+			nameWidth = max(nameWidth, min(lipgloss.Width(m.compareName(path)), 30))
+		}
+	}
+	name := func(path string) string {
+		return fmt.Sprintf("%-*s", nameWidth, truncateCells(m.compareName(path), nameWidth))
+	}
+
+	var b strings.Builder
+	if len(m.state.PushTargets) == 0 {
+		b.WriteString(bold.Render("Push " + scope))
+		b.WriteString("\n\n")
+		b.WriteString("Nothing to push: no repo has commits its upstream lacks\n")
+	} else {
+		b.WriteString(bold.Render(fmt.Sprintf("Push %d of %s", len(m.state.PushTargets), scope)))
+		b.WriteString("\n\n")
+		for _, path := range m.state.PushTargets {
+			status := m.state.Repositories[path].Status
+			b.WriteString(fmt.Sprintf("%s  %s → %s  %d commits\n", name(path), status.Branch, status.Upstream, status.AheadCount))
+		}
+	}
+	if len(refused) > 0 {
+		b.WriteString("\n" + bold.Render("Not pushed") + "\n")
+		for _, path := range candidates {
+			if reason, ok := refused[path]; ok {
+				b.WriteString(fmt.Sprintf("%s  %s\n", name(path), reason))
+			}
+		}
+	}
+
+	b.WriteString("\n")
+	if len(m.state.PushTargets) == 0 {
+		b.WriteString(faint.Render("esc close"))
+	} else {
+		b.WriteString(faint.Render("y push • n cancel"))
+	}
+	return b.String()
+}
+
+// pushTargets sends the listed repositories off to be pushed
+func (m *Model) pushTargets() tea.Cmd {
+	targets := m.state.PushTargets
+	if len(targets) == 0 {
+		return nil
+	}
+	m.state.Push = &state.PushRun{Total: len(targets), Pending: len(targets), Rejections: make(map[string]string)}
+	m.state.StatusMessage = fmt.Sprintf("Pushing %d repos", len(targets))
+	return m.cmdExecutor.ExecutePush(targets)
+}
@@ -0,0 +1,129 @@
+package ui
+
+import (
+	"fmt"
+	"sort"
+	"strings"
+
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/charmbracelet/lipgloss/v2"
+
+	"github.com/darksworm/gitagrip/internal/git"
+	"github.com/darksworm/gitagrip/internal/ui/state"
+)
+
+// openRemoteMigration builds the migration preview for the selected repositories
+// (or all repositories when nothing is selected) targeting the given transport
+func (m *Model) openRemoteMigration(target string) {
+	var paths []string
+	if m.store.GetSelectionCount() > 0 {
+		for path := range m.store.GetSelectedRepositories() {
+			paths = append(paths, path)
+		}
+	} else {
+		for path := range m.state.Repositories {
+			paths = append(paths, path)
+		}
+	}
+
+	templates := m.config.Remotes.Templates
+	var items []state.RemoteMigrationItem
+	for _, path := range paths {
+		repo, ok := m.state.Repositories[path]
+		if !ok || repo.Status.RemoteURL == "" {
+			continue
+		}
+		converted, ok := git.ConvertRemoteURL(repo.Status.RemoteURL, target, templates)
+		if !ok || converted == repo.Status.RemoteURL {
+			continue
+		}
+		name := repo.DisplayName
+		if name == "" {
+			name = repo.Name
+		}
+		items = append(items, state.RemoteMigrationItem{
+			RepoPath: path,
+			Name:     name,
+			From:     repo.Status.RemoteURL,
+			To:       converted,
+		})
+	}
+	sort.Slice(items, func(i, j int) bool {
+		return strings.ToLower(items[i].Name) < strings.ToLower(items[j].Name)
+	})
+
+	m.state.RemoteMigration = &state.RemoteMigration{Target: target, Items: items}
+	m.state.ShowInfo = true
+	m.state.InfoContent = m.buildRemoteMigrationContent()
+}
+
+// applyRemoteMigration rewrites origin for the item under the cursor, or every pending item
+func (m *Model) applyRemoteMigration(all bool) tea.Cmd {
+	mig := m.state.RemoteMigration
+	if mig == nil || len(mig.Items) == 0 {
+		return nil
+	}
+
+	var cmds []tea.Cmd
+	for i := range mig.Items {
+		if !all && i != mig.Index {
+			continue
+		}
+		item := &mig.Items[i]
+		if item.Applied {
+			continue
+		}
+		cmds = append(cmds, m.cmdExecutor.ExecuteSetRemoteURL(item.RepoPath, "origin", item.To))
+		item.Applied = true
+	}
+
+	if len(cmds) == 1 {
+		m.state.StatusMessage = "Updated origin remote"
+	} else if len(cmds) > 1 {
+		m.state.StatusMessage = fmt.Sprintf("Updated origin remote in %d repos", len(cmds))
+	}
+	m.state.InfoContent = m.buildRemoteMigrationContent()
+	return tea.Batch(cmds...)
+}
+
+// buildRemoteMigrationContent renders the migration preview popup
+func (m *Model) buildRemoteMigrationContent() string {
+	mig := m.state.RemoteMigration
+	if mig == nil {
+		return ""
+	}
+
+	targetName, otherName := "SSH", "HTTPS"
+	if mig.Target == git.TransportHTTPS {
+		targetName, otherName = "HTTPS", "SSH"
+	}
+
+	var b strings.Builder
+	b.WriteString(lipgloss.NewStyle().Bold(true).Render(
+		fmt.Sprintf("Migrate origin remotes to %s (%d)", targetName, len(mig.Items))))
+	b.WriteString("\n\n")
+
+	if len(mig.Items) == 0 {
+		b.WriteString(fmt.Sprintf("No origin remotes to convert to %s\n", targetName))
+	}
+
+	faint := lipgloss.NewStyle().Faint(true)
+	cursorStyle := lipgloss.NewStyle().Bold(true).Foreground(lipgloss.Color(m.renderer.Theme().Warning))
+	for i, item := range mig.Items {
+		marker := "  "
+		if i == mig.Index {
+			marker = cursorStyle.Render("> ")
+		}
+		line := fmt.Sprintf("%s\n    %s\n    -> %s", item.Name, item.From, item.To)
+		if item.Applied {
+			line = faint.Render(fmt.Sprintf("%s (applied)\n    %s", item.Name, item.To))
+		}
+		b.WriteString(marker)
+		b.WriteString(line)
+		b.WriteString("\n")
+	}
+
+	b.WriteString("\n")
+	b.WriteString(faint.Render(fmt.Sprintf("enter apply • A apply all • t switch to %s • esc close", otherName)))
+	return b.String()
+}
@@ -1,8 +1,8 @@
 package repositories
 
 import (
-	"gitagrip/internal/domain"
-	"gitagrip/internal/ui/state"
+	"github.com/darksworm/gitagrip/internal/domain"
+	"github.com/darksworm/gitagrip/internal/ui/state"
 )
 
 // RepositoryStore provides access to repository data
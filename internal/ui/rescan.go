@@ -0,0 +1,135 @@
+package ui
+
+import (
+	"fmt"
+	"path/filepath"
+	"slices"
+	"sort"
+	"strings"
+
+	tea "github.com/charmbracelet/bubbletea/v2"
+
+	"github.com/darksworm/gitagrip/internal/eventbus"
+	"github.com/darksworm/gitagrip/internal/paths"
+)
+
+// rescan scans the base directory and the directories scanned since start again; the
+// repositories under them that it doesn't find are dropped (see discovery.Reconciler)
+func (m *Model) rescan() tea.Cmd {
+	var roots []string
+	if m.config.BaseDir != "" {
+		roots = append(roots, m.config.BaseDir)
+	}
+	roots = append(roots, m.scannedDirs...)
+	if len(roots) == 0 || m.bus == nil {
+		m.state.StatusMessage = "Nothing to rescan"
+		return nil
+	}
+	m.rescanRoots = roots
+	m.state.Scanning = true
+	m.state.StatusMessage = fmt.Sprintf("Rescanning %d directories...", len(roots))
+	if len(roots) == 1 {
+		m.state.StatusMessage = "Rescanning " + roots[0] + "..."
+	}
+	m.bus.Publish(eventbus.ScanRequestedEvent{Paths: roots})
+	return nil
+}
+
+// finishScan ends a scan, warning when it stopped at its limits and reporting how many
+// repositories a rescan found gone
+func (m *Model) finishScan(e eventbus.ScanCompletedEvent) {
+	m.state.ScanWarning = scanWarning(e)
+	if m.jump {
+		m.state.StatusMessage = jumpHint
+	}
+	if m.rescanRoots == nil || !slices.Equal(e.Roots, m.rescanRoots) {
+		return
+	}
+	m.rescanRoots = nil
+	if e.Partial {
+		m.state.StatusMessage = "Rescan incomplete, no repos dropped"
+		return
+	}
+
+	// The repos not found are dropped by the reconciler's events, which follow this one
+
+	found := make(map[string]bool, len(e.Repos))
+	for _, path := range e.Repos {
+		found[path] = true
+	}
+	gone := 0
+	for path := range m.state.Repositories {
+		if !found[path] && underAny(path, e.Roots) {
+			gone++
+		}
+	}
+	m.state.StatusMessage = fmt.Sprintf("Rescan complete: %d repos, %d gone", len(e.Repos), gone)
+}
+
+// underAny reports whether path is one of the directories or inside one
+func underAny(path string, dirs []string) bool {
+	for _, dir := range dirs {
+		if paths.Within(path, dir) {
+			return true
+		}
+	}
+	return false
+}
+
+// maxScanSuggestions is how many of the directories with the most repositories a scan
+// stopped at scan.max_repos suggests excluding
+const maxScanSuggestions = 3
+
+// scanWarning explains why a scan stopped short of what it was pointed at: scan.max_repos
+// reached, suggesting the directories holding most of the repositories for scan.exclude,
+// or directories outside repositories left below scan.max_depth
+func scanWarning(e eventbus.ScanCompletedEvent) string {
+	if e.RepoLimit > 0 {
+		warning := fmt.Sprintf("Scan stopped at %d repos (scan.max_repos).", e.RepoLimit)
+		if busiest := busiestDirs(e.Repos, e.Roots, maxScanSuggestions); len(busiest) > 0 {
+			warning += " Most are under " + strings.Join(busiest, ", ") + "; add those to scan.exclude or point base_dir at your code."
+		} else {
+			warning += " Add directories to scan.exclude or point base_dir at your code."
+		}
+		return warning
+	}
+	if e.DeepDirs > 0 {
+		return fmt.Sprintf("Directories deeper than scan.max_depth (%d) were not scanned: %d. Raise it or point base_dir at your code.", e.MaxDepth, e.DeepDirs)
+	}
+	return ""
+}
+
+// busiestDirs returns the directories right below the roots that hold the most of the
+// repositories, with their counts, most first; repositories right below a root don't count
+func busiestDirs(repos, roots []string, limit int) []string {
+	counts := make(map[string]int)
+	for _, path := range repos {
+		for _, root := range roots {
+			rel, err := filepath.Rel(root, path)
+			if err != nil || rel == "." || strings.HasPrefix(rel, "..") {
+				continue
+			}
+			if top, _, nested := strings.Cut(rel, string(filepath.Separator)); nested {
+				counts[filepath.Join(root, top)]++
+			}
+			break
+		}
+	}
+	dirs := make([]string, 0, len(counts))
+	for dir := range counts {
+		dirs = append(dirs, dir)
+	}
+	sort.Slice(dirs, func(i, j int) bool {
+		if counts[dirs[i]] != counts[dirs[j]] {
+			return counts[dirs[i]] > counts[dirs[j]]
+		}
+		return dirs[i] < dirs[j]
+	})
+	if len(dirs) > limit {
+		dirs = dirs[:limit]
+	}
+	for i, dir := range dirs {
+		dirs[i] = fmt.Sprintf("%s (%d)", dir, counts[dir])
+	}
+	return dirs
+}
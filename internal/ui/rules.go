@@ -0,0 +1,41 @@
+package ui
+
+import (
+	"github.com/darksworm/gitagrip/internal/eventbus"
+)
+
+// applyGroupRules places a repository that is not in any group into the group chosen
+// by the [[rules]] in the config, creating the group if needed. Called when a repository
+// is discovered and again when its status (and so its remote) arrives.
+func (m *Model) applyGroupRules(repoPath string) {
+	if len(m.config.Rules) == 0 {
+		return
+	}
+	repo, ok := m.state.Repositories[repoPath]
+	if !ok {
+		return
+	}
+	for _, group := range m.state.Groups {
+		for _, path := range group.Repos {
+			if path == repoPath {
+				return // manual organization always wins
+			}
+		}
+	}
+
+	groupName, ok := m.config.RuleGroupFor(repoPath, repo.Status.RemoteURL)
+	if !ok {
+		return
+	}
+	if _, exists := m.state.Groups[groupName]; !exists {
+		m.state.AddGroup(groupName, []string{})
+		if m.bus != nil {
+			m.bus.Publish(eventbus.GroupAddedEvent{Name: groupName})
+		}
+	}
+	m.state.MoveRepoToGroup(repoPath, "", groupName)
+	m.updateOrderedLists()
+	if m.bus != nil {
+		m.bus.Publish(eventbus.RepoMovedEvent{RepoPath: repoPath, ToGroup: groupName})
+	}
+}
@@ -0,0 +1,73 @@
+package ui
+
+import (
+	"github.com/darksworm/gitagrip/internal/session"
+)
+
+// RestoreSession puts back the collapsed groups, search and filter of the previous run.
+// The cursor and scroll position follow once the item the cursor was on is discovered,
+// unless a key or the mouse has been used by then.
+func (m *Model) RestoreSession(s session.State) {
+	for _, name := range s.Collapsed {
+		if _, ok := m.state.Groups[name]; ok {
+			m.state.ExpandedGroups[name] = false
+		}
+	}
+	m.state.SearchQuery = s.Search
+	m.state.FilterQuery = s.Filter
+	m.state.IsFiltered = s.Filter != ""
+	m.recentScans = s.RecentScans
+	m.updateOrderedLists()
+
+	if s.Cursor != "" {
+		m.resume = &s
+	}
+}
+
+// resumeCursor moves the cursor back onto the item it was on in the previous run as soon
+// as that item is listed and the list's height is known
+func (m *Model) resumeCursor() {
+	if m.resume == nil || m.height == 0 {
+		return
+	}
+	for index, key := range m.listItemKeys() {
+		if key == m.resume.Cursor {
+			m.state.SelectedIndex = index
+			m.state.ViewportOffset = max(0, min(m.resume.Offset, index))
+			m.ensureSelectedVisible()
+			m.finishResume()
+			return
+		}
+	}
+}
+
+// finishResume stops waiting for the cursor's item and finds the matches of the restored search
+func (m *Model) finishResume() {
+	m.resume = nil
+	if m.state.SearchQuery != "" {
+		m.performSearch()
+	}
+}
+
+// Session returns the UI state to restore on the next run
+func (m *Model) Session() session.State {
+	s := session.State{
+		Offset: m.state.ViewportOffset,
+		Search: m.state.SearchQuery,
+		Filter: m.state.FilterQuery,
+
+		RecentScans: m.recentScans,
+	}
+	if m.resume != nil {
+		// The cursor never got back onto its item; keep looking for it next time
+		s.Cursor, s.Offset = m.resume.Cursor, m.resume.Offset
+	} else {
+		s.Cursor = m.captureListAnchors().cursor
+	}
+	for _, name := range m.state.OrderedGroups {
+		if name != HiddenGroupName && !m.state.ExpandedGroups[name] {
+			s.Collapsed = append(s.Collapsed, name)
+		}
+	}
+	return s
+}
@@ -0,0 +1,19 @@
+package ui
+
+import (
+	"github.com/darksworm/gitagrip/internal/domain"
+)
+
+// RestoreSnapshot lists the repositories of the last run with their last statuses, marked
+// stale, before the scan has found them again. Forgotten repositories are left out.
+func (m *Model) RestoreSnapshot(repos []domain.Repository) {
+	for i := range repos {
+		repo := repos[i]
+		if m.config.IsForgotten(repo.Path) {
+			continue
+		}
+		m.state.AddRepository(&repo)
+		m.applyGroupRules(repo.Path)
+	}
+	m.updateOrderedLists()
+}
@@ -1,9 +1,18 @@
 package state
 
 import (
-	"gitagrip/internal/domain"
+	"sort"
+	"strings"
+	"time"
+
+	"github.com/darksworm/gitagrip/internal/domain"
+	"github.com/darksworm/gitagrip/internal/forge"
+	"github.com/darksworm/gitagrip/internal/health"
 )
 
+// maxRefreshDurations is how many status refresh durations are kept for the dashboard
+const maxRefreshDurations = 40
+
 // AppState contains all the application state
 type AppState struct {
 	// Repository data
@@ -20,11 +29,16 @@ type AppState struct {
 	// Selection state
 	SelectedIndex int             // currently selected item
 	SelectedRepos map[string]bool // selected repository paths
+	Dragging      bool            // a mouse drag-select is in progress
+	DragAnchor    int             // list index where the drag started
+	Visual        bool            // a visual range selection (V) is active
+	VisualAnchor  int             // list index where the visual range starts
 
 	// Operation states
-	RefreshingRepos map[string]bool // repositories currently being refreshed
-	FetchingRepos   map[string]bool // repositories currently being fetched
-	PullingRepos    map[string]bool // repositories currently being pulled
+	RefreshingRepos map[string]bool         // repositories currently being refreshed
+	FetchingRepos   map[string]bool         // repositories currently being fetched
+	PullingRepos    map[string]bool         // repositories currently being pulled
+	BulkProgress    map[string]BulkProgress // running bulk operations by name ("fetch", "pull")
 
 	// UI state
 	ViewportOffset int  // offset for scrolling
@@ -35,22 +49,477 @@ type AppState struct {
 	LogContent     string
 	ShowInfo       bool
 	InfoContent    string
-	StatusMessage  string // status bar message
-	LoadingState   string // current loading state description
-	LoadingCount   int    // count for loading progress
+	StatusMessage  string  // status bar message
+	Toasts         []Toast // brief notifications over the list, oldest first
+	ConfigDirty    bool    // group changes wait for w (autosave_on_exit is off)
+	ScanWarning    string  // why the last scan stopped short, shown above the list until the next one
+	LoadingState   string  // current loading state description
+	LoadingCount   int     // count for loading progress
 
 	// Search and filter state
 	SearchQuery     string // current search query
 	SearchMatches   []int  // indices of matching items
 	SearchIndex     int    // current match index
 	SortOptionIndex int    // current selected sort option in sort mode
+	SortLabel       string // current sort, shown in the title bar unless sorting by name
 	FilterQuery     string // current filter query
 	IsFiltered      bool   // whether filter is active
 
+	// Remote migration assistant (nil when closed)
+	RemoteMigration *RemoteMigration
+
+	// Fetch options popup (nil when closed)
+	FetchOptions *FetchOptions
+
+	// Label editor for the selected repositories (nil when closed)
+	BulkLabels *BulkLabels
+
+	// New upstream commits on watched branches, in arrival order
+	WatchedChanges []WatchedChange
+
+	// Statuses before the last fetch or pull, to show what it changed (nil before the first)
+	StatusChanges *StatusChanges
+
+	// Commit activity heatmap (nil when closed), and the commits per day of each repository
+	// loaded for it, kept for the session
+	Heatmap  *Heatmap
+	Activity map[string]RepoActivity
+
+	// Commit log view (nil when closed)
+	CommitLog *CommitLog
+
+	// Unpushed commits view (nil when closed)
+	Unpushed *Unpushed
+
+	// Workspace profile switcher (nil when closed)
+	Profiles *ProfilePicker
+
+	// Directory browser picking a directory to scan (nil when closed)
+	DirBrowser *DirBrowser
+
+	// Repositories of an organization to pick for cloning (nil when closed)
+	CloneOrg *CloneOrgPicker
+
+	// Tail of gitagrip's log file (nil when closed)
+	DebugLog *DebugLog
+
+	// Group whose color is being picked ("" when the picker is closed)
+	ColorGroup string
+
+	// Repository shown by the info popup ("" when closed), and the remotes, stashes, tags
+	// and worktrees of each repository read when its info was opened, kept for the session
+	InfoRepo string
+	Details  map[string]RepoDetails
+
+	// Health checks of repositories (nil when closed)
+	Health *Health
+
+	// Repositories whose dry run listed files for git clean -fdx, waiting for confirmation
+	// (empty until the dry run results arrive)
+	CleanTargets []string
+
+	// Question of the open confirmation dialog ("" when closed)
+	Confirm string
+
+	// Repository waiting for archive confirmation ("" when archiving it is refused)
+	ArchiveTarget string
+
+	// Repositories waiting for push confirmation, and the running or last push
+	PushTargets []string
+	Push        *PushRun
+
+	// Bulk pull waiting for its dry run to be reviewed (nil when closed)
+	PullPreview *PullPreview
+
+	// Prompts of network commands waiting for a password, passphrase or confirmation;
+	// the first one is shown
+	CredentialRequests []domain.CredentialRequestedEvent
+
+	// Forgotten and archived repositories that ctrl+z brings back, latest last
+	Removed [][]RemovedRepo
+
+	// How long the latest status refreshes took, oldest first (dashboard sparkline)
+	RefreshDurations []time.Duration
+
+	// Groups compared side by side: CompareGroup is marked with C, ComparedGroups is the open comparison
+	CompareGroup   string
+	ComparedGroups [2]string
+	CompareTags    map[string]string // latest git tag per repository, loaded when the comparison opens
+
 	// Cached data
 	UngroupedRepos []string // cached ungrouped repos
 }
 
+// BulkProgress tracks how far a bulk fetch or pull across repositories has got
+type BulkProgress struct {
+	Done  int
+	Total int
+}
+
+// RemoteMigration holds the preview of origin URLs to rewrite to another transport
+type RemoteMigration struct {
+	Target string // transport remotes are converted to ("ssh" or "https")
+	Items  []RemoteMigrationItem
+	Index  int // item under the cursor
+}
+
+// RemoteMigrationItem is a single repository in the migration preview
+type RemoteMigrationItem struct {
+	RepoPath string
+	Name     string
+	From     string
+	To       string
+	Applied  bool
+}
+
+// FetchOptions is a fetch waiting for its remote, tag mode and depth to be picked
+type FetchOptions struct {
+	RepoPaths []string // repositories to fetch
+	Scope     string   // what they are, e.g. "3 selected repos"
+	Remotes   []string // remotes configured in any of them
+	Options   domain.FetchOptions
+}
+
+// PushRun collects the results of a push as they come in
+type PushRun struct {
+	Total      int
+	Pending    int               // pushes that have not reported back yet
+	Rejections map[string]string // repositories that could not be pushed, with why
+}
+
+// PullPreview is a bulk pull checked before it runs: what pulling each repository would do,
+// and which of them are pulled
+type PullPreview struct {
+	Scope    string                 // what the repositories are, e.g. "the selected repos"
+	Repos    []string               // repository paths, ordered by name
+	Outlooks map[string]PullOutlook // repo path -> what pulling it would do
+	Included map[string]bool        // repositories pulled on confirmation
+	Index    int                    // repository under the cursor
+	Loading  bool
+}
+
+// Verdicts of a pull dry run
+const (
+	PullUpToDate    = "up to date"
+	PullFastForward = "fast-forward"
+	PullMerge       = "needs merge"
+	PullConflict    = "would conflict"
+	PullNoUpstream  = "no upstream"
+)
+
+// PullOutlook is what pulling a repository would do, judged from its last fetch
+type PullOutlook struct {
+	Verdict   string
+	Ahead     int      // local commits the upstream lacks
+	Behind    int      // upstream commits HEAD lacks
+	Conflicts []string // files a merge would conflict in
+	Err       string
+}
+
+// BulkLabels is the label editor for a selection of repositories
+type BulkLabels struct {
+	Repos []string // selected repository paths
+	Items []BulkLabelItem
+	Index int // item under the cursor
+}
+
+// BulkLabelItem is one label in the bulk label editor
+type BulkLabelItem struct {
+	Name   string
+	Count  int // selected repositories that have the label
+	Change int // pending change: 1 adds it to every selected repo, -1 removes it, 0 leaves it
+}
+
+// StatusChanges remembers the repository statuses from when a bulk fetch or pull started
+type StatusChanges struct {
+	Operation string                       // "fetch" or "pull"
+	Before    map[string]domain.RepoStatus // status per repository path when it started
+	Running   bool
+}
+
+// Heatmap is the open commit activity heatmap
+type Heatmap struct {
+	Group   string // group whose repositories count ("" for all of them)
+	Loading bool
+}
+
+// Health is the health checks view: the checked repositories and their reports
+type Health struct {
+	Repos   []string                 // checked repository paths, ordered by name
+	Reports map[string]health.Report // repo path -> report
+	Index   int                      // repository with findings under the cursor
+	Loading bool
+}
+
+// RepoActivity counts the commits of a repository per day ("2006-01-02") over the past year
+type RepoActivity struct {
+	LastCommit time.Time // last commit when counted; a newer one makes the counts stale
+	Days       map[string]int
+}
+
+// CommitLog is the commit log view of a repository with its filter
+type CommitLog struct {
+	Repo    string
+	Query   string // filter query as typed
+	Range   int    // date range cycled with d (index into the ranges the view offers)
+	All     bool   // all branches instead of the current one
+	Filter  LogFilter
+	Commits []Commit
+	Offset  int    // first commit shown
+	Input   string // filter input while it is edited ("" otherwise)
+	Loading bool
+	Err     string
+}
+
+// LogFilter narrows down a commit log. git applies it, so large histories are never read whole.
+type LogFilter struct {
+	Message      string // substring of the message, ignoring case
+	Author       string // substring of the author name or email, ignoring case
+	Since        string // git date, e.g. "2024-01-31" or "1 week ago" ("" for no limit)
+	Until        string
+	AllBranches  bool
+	Range        string // revision range, e.g. "origin/main..HEAD" ("" for the current branch)
+	NotOnRemotes bool   // leave out commits that any remote-tracking branch contains
+	Limit        int    // most commits read (0 for the commit log's maximum)
+}
+
+// Unpushed is the unpushed commits view: what each repository has that its upstream doesn't
+type Unpushed struct {
+	Scope   string              // which repositories, e.g. "the selected repos"
+	Repos   []string            // repository paths, ordered by name
+	Targets map[string]string   // repo path -> upstream compared with ("" when there is none)
+	Commits map[string][]Commit // repo path -> commits not pushed, newest first
+	Errors  map[string]string   // repo path -> why its commits couldn't be read
+	Offset  int                 // first line shown
+	Loading bool
+}
+
+// ProfilePicker lists the workspace profiles to restart with
+type ProfilePicker struct {
+	Names   []string // profile names, sorted
+	Current string   // profile in use ("" when started without one)
+	Index   int      // cursor
+	Err     string   // why the profiles couldn't be listed
+}
+
+// CloneOrgPicker lists the repositories of an organization to pick the ones to clone
+type CloneOrgPicker struct {
+	Host    string       // "github" or "gitlab"
+	Org     string       // organization or group
+	Repos   []forge.Repo // sorted by name, once listed
+	Picked  map[int]bool // indexes into Repos
+	Index   int          // cursor
+	Loading bool         // the forge hasn't answered yet
+	Err     string       // why the repositories couldn't be listed
+}
+
+// DirBrowser is the directory browser: the subdirectories of Dir with a cursor
+type DirBrowser struct {
+	Dir     string
+	Entries []DirEntry
+	Index   int
+	Err     string   // why Dir couldn't be read
+	Recent  []string // directories scanned last, newest first, picked with 1-5
+}
+
+// DebugLog is the end of gitagrip's log file, scrolled by Offset
+type DebugLog struct {
+	Path   string
+	Lines  []string
+	Offset int    // first line shown
+	Err    string // why the file couldn't be read
+}
+
+// DirEntry is a subdirectory listed by the directory browser
+type DirEntry struct {
+	Name   string
+	IsRepo bool // it is a git repository
+}
+
+// Commit is one entry of a commit log
+type Commit struct {
+	Hash    string
+	Author  string
+	Date    string // relative, e.g. "3 days ago"
+	Subject string
+	Refs    string // branches and tags pointing at it
+}
+
+// RepoDetails is what the info popup and the details pane show beyond the status: read
+// with separate git commands, when the popup opens or the cursor rests on the repository
+type RepoDetails struct {
+	Remotes   []domain.Remote
+	Stashes   int
+	Tags      []string   // newest first
+	Worktrees []Worktree // other worktrees of the same repository
+	Commits   []Commit   // newest commits on HEAD, for the details pane
+	Err       string     // first command that failed
+}
+
+// Worktree is a checkout of a repository's branch in another directory
+type Worktree struct {
+	Path   string
+	Branch string // "" when detached
+}
+
+// RemovedRepo is a repository taken out of the list by forgetting or archiving it, with
+// what is needed to put it back
+type RemovedRepo struct {
+	Repo       *domain.Repository
+	Group      string // group it was in ("" when ungrouped)
+	ArchivedTo string // directory it was moved to ("" when forgotten)
+	Bundle     string // bundle written before archiving
+}
+
+// WatchedChange lists the unacknowledged commits on a watched branch of a repository
+type WatchedChange struct {
+	RepoPath string
+	Branch   string
+	Commits  []domain.WatchedCommit // newest first
+}
+
+// SetWatchedChange records the pending commits of a watched branch, dropping the
+// entry when there are none
+func (s *AppState) SetWatchedChange(repoPath, branch string, commits []domain.WatchedCommit) {
+	kept := s.WatchedChanges[:0]
+	for _, change := range s.WatchedChanges {
+		if change.RepoPath != repoPath || change.Branch != branch {
+			kept = append(kept, change)
+		}
+	}
+	s.WatchedChanges = kept
+	if len(commits) > 0 {
+		s.WatchedChanges = append(s.WatchedChanges, WatchedChange{RepoPath: repoPath, Branch: branch, Commits: commits})
+	}
+}
+
+// Toast is a brief notification shown until it expires
+type Toast struct {
+	Level   string // domain.MessageInfo, MessageSuccess, MessageWarning or MessageError
+	Text    string
+	Expires time.Time
+}
+
+// maxToasts is how many notifications are shown at once; older ones make way
+const maxToasts = 4
+
+// toastDuration is how long a notification stays; problems stay longer
+func toastDuration(level string) time.Duration {
+	switch level {
+	case domain.MessageError:
+		return 8 * time.Second
+	case domain.MessageWarning:
+		return 6 * time.Second
+	}
+	return 4 * time.Second
+}
+
+// AddToast shows a notification, replacing a still visible one with the same text
+func (s *AppState) AddToast(level, text string, now time.Time) {
+	kept := s.Toasts[:0]
+	for _, toast := range s.Toasts {
+		if toast.Text != text {
+			kept = append(kept, toast)
+		}
+	}
+	s.Toasts = append(kept, Toast{Level: level, Text: text, Expires: now.Add(toastDuration(level))})
+	if len(s.Toasts) > maxToasts {
+		s.Toasts = s.Toasts[len(s.Toasts)-maxToasts:]
+	}
+}
+
+// ExpireToasts drops the notifications whose time is up and reports whether any were
+func (s *AppState) ExpireToasts(now time.Time) bool {
+	kept := s.Toasts[:0]
+	for _, toast := range s.Toasts {
+		if now.Before(toast.Expires) {
+			kept = append(kept, toast)
+		}
+	}
+	expired := len(kept) < len(s.Toasts)
+	s.Toasts = kept
+	return expired
+}
+
+// RecordRefreshDuration remembers how long a status refresh took, keeping the latest ones
+func (s *AppState) RecordRefreshDuration(d time.Duration) {
+	s.RefreshDurations = append(s.RefreshDurations, d)
+	if len(s.RefreshDurations) > maxRefreshDurations {
+		s.RefreshDurations = s.RefreshDurations[len(s.RefreshDurations)-maxRefreshDurations:]
+	}
+}
+
+// BranchRepos lists the repositories that have the same branch checked out
+type BranchRepos struct {
+	Branch string   // branch name, "detached" for a detached HEAD
+	Repos  []string // repository paths, ordered by name
+}
+
+// RepositoriesByBranch groups repositories by their current branch, the most common
+// branch first. Repositories whose status is not known yet are left out.
+func (s *AppState) RepositoriesByBranch() []BranchRepos {
+	byBranch := make(map[string][]string)
+	for path, repo := range s.Repositories {
+		branch := repo.Status.Branch
+		if branch == "" || branch == "⋯" {
+			continue
+		}
+		if strings.HasPrefix(branch, "detached") {
+			branch = "detached"
+		}
+		byBranch[branch] = append(byBranch[branch], path)
+	}
+
+	result := make([]BranchRepos, 0, len(byBranch))
+	for branch, paths := range byBranch {
+		sort.Slice(paths, func(i, j int) bool {
+			return s.repoName(paths[i]) < s.repoName(paths[j])
+		})
+		result = append(result, BranchRepos{Branch: branch, Repos: paths})
+	}
+	sort.Slice(result, func(i, j int) bool {
+		if len(result[i].Repos) != len(result[j].Repos) {
+			return len(result[i].Repos) > len(result[j].Repos)
+		}
+		return result[i].Branch < result[j].Branch
+	})
+	return result
+}
+
+// FailedRepositories returns the repositories whose last status refresh failed, ordered by name
+func (s *AppState) FailedRepositories() []string {
+	var paths []string
+	for path, repo := range s.Repositories {
+		if repo.Status.Error != "" {
+			paths = append(paths, path)
+		}
+	}
+	sort.Slice(paths, func(i, j int) bool {
+		return s.repoName(paths[i]) < s.repoName(paths[j])
+	})
+	return paths
+}
+
+// repoName returns the name a repository is shown with
+func (s *AppState) repoName(path string) string {
+	repo := s.Repositories[path]
+	if repo.DisplayName != "" {
+		return repo.DisplayName
+	}
+	return repo.Name
+}
+
+// WatchedCommitCount returns how many unacknowledged watched commits a repository has
+func (s *AppState) WatchedCommitCount(repoPath string) int {
+	count := 0
+	for _, change := range s.WatchedChanges {
+		if change.RepoPath == repoPath {
+			count += len(change.Commits)
+		}
+	}
+	return count
+}
+
 // NewAppState creates a new application state
 func NewAppState() *AppState {
 	return &AppState{
@@ -65,6 +534,7 @@ func NewAppState() *AppState {
 		RefreshingRepos:    make(map[string]bool),
 		FetchingRepos:      make(map[string]bool),
 		PullingRepos:       make(map[string]bool),
+		BulkProgress:       make(map[string]BulkProgress),
 		UngroupedRepos:     make([]string, 0),
 		ViewportHeight:     20, // Default
 	}
@@ -229,7 +699,31 @@ func (s *AppState) ClearOperationState(repoPath string) {
 func (s *AppState) GetGroupsMap() map[string][]string {
 	groups := make(map[string][]string)
 	for name, group := range s.Groups {
-		groups[name] = append([]string(nil), group.Repos...) // Copy slice
+		inGroup := make(map[string]bool, len(group.Repos))
+		for _, path := range group.Repos {
+			inGroup[path] = true
+		}
+
+		repos := make([]string, 0, len(group.Repos))
+		for _, path := range group.Repos {
+			// Worktrees shown under their main repository follow it implicitly
+			if repo, ok := s.Repositories[path]; ok && repo.WorktreeOf != "" && inGroup[repo.WorktreeOf] {
+				continue
+			}
+			repos = append(repos, path)
+		}
+		groups[name] = repos
 	}
 	return groups
 }
+
+// GetGroupOrder returns the display order of groups, without the hidden group
+func (s *AppState) GetGroupOrder() []string {
+	order := make([]string, 0, len(s.OrderedGroups))
+	for _, name := range s.OrderedGroups {
+		if name != "_Hidden" {
+			order = append(order, name)
+		}
+	}
+	return order
+}
@@ -0,0 +1,156 @@
+package ui
+
+import (
+	"fmt"
+	"sort"
+	"strings"
+
+	"github.com/charmbracelet/lipgloss/v2"
+
+	"github.com/darksworm/gitagrip/internal/domain"
+	"github.com/darksworm/gitagrip/internal/eventbus"
+	"github.com/darksworm/gitagrip/internal/git"
+	"github.com/darksworm/gitagrip/internal/ui/state"
+)
+
+// statusChange is a repository whose status differs from before the last fetch or pull
+type statusChange struct {
+	path    string
+	changes []string // e.g. "behind 3 → 0", "clean → dirty"
+}
+
+// trackStatusChanges remembers every status when a fetch or pull starts, and points at
+// the list of changes once it has finished
+func (m *Model) trackStatusChanges(e eventbus.OperationProgressEvent) {
+	if e.Operation != git.BulkFetch && e.Operation != git.BulkPull {
+		return
+	}
+
+	switch {
+	case e.Done == 0 && !e.Cancelled:
+		if e.Total == 0 {
+			return
+		}
+		before := make(map[string]domain.RepoStatus, len(m.state.Repositories))
+		for path, repo := range m.state.Repositories {
+			before[path] = repo.Status
+		}
+		m.state.StatusChanges = &state.StatusChanges{Operation: e.Operation, Before: before, Running: true}
+
+	case e.Cancelled || e.Done >= e.Total:
+		changes := m.state.StatusChanges
+		if changes == nil || !changes.Running || changes.Operation != e.Operation {
+			return
+		}
+		changes.Running = false
+		// Keep what the operation reported (e.g. that it was cancelled) and add the hint
+		hint := "press U to see what changed"
+		if m.state.StatusMessage == "" {
+			m.state.StatusMessage = fmt.Sprintf("Finished %s of %d repos, %s", e.Operation, e.Total, hint)
+		} else {
+			m.state.StatusMessage += " · " + hint
+		}
+	}
+}
+
+// changedRepos compares the statuses from before the last fetch or pull with the current
+// ones, ordered by name. Repositories discovered meanwhile or still loading are left out.
+func (m *Model) changedRepos() []statusChange {
+	snapshot := m.state.StatusChanges
+	if snapshot == nil {
+		return nil
+	}
+
+	var changed []statusChange
+	for path, repo := range m.state.Repositories {
+		before, ok := snapshot.Before[path]
+		if !ok || repo.Status.Branch == "⋯" {
+			continue
+		}
+		if changes := describeStatusChange(before, repo.Status); len(changes) > 0 {
+			changed = append(changed, statusChange{path: path, changes: changes})
+		}
+	}
+	sort.Slice(changed, func(i, j int) bool {
+		return m.compareName(changed[i].path) < m.compareName(changed[j].path)
+	})
+	return changed
+}
+
+// describeStatusChange lists how the branch, ahead/behind counts, working tree state and
+// errors differ between two statuses of a repository
+func describeStatusChange(before, after domain.RepoStatus) []string {
+	var changes []string
+	if before.Branch != after.Branch && before.Branch != "⋯" {
+		changes = append(changes, fmt.Sprintf("branch %s → %s", before.Branch, after.Branch))
+	}
+	if before.BehindCount != after.BehindCount {
+		changes = append(changes, fmt.Sprintf("behind %d → %d", before.BehindCount, after.BehindCount))
+	}
+	if before.AheadCount != after.AheadCount {
+		changes = append(changes, fmt.Sprintf("ahead %d → %d", before.AheadCount, after.AheadCount))
+	}
+	if from, to := workingTreeState(before), workingTreeState(after); from != to {
+		changes = append(changes, fmt.Sprintf("%s → %s", from, to))
+	}
+	switch {
+	case before.Error == "" && after.Error != "":
+		changes = append(changes, "failed: "+after.Error)
+	case before.Error != "" && after.Error == "":
+		changes = append(changes, "error cleared")
+	}
+	return changes
+}
+
+// workingTreeState names the state of a working tree in one word
+func workingTreeState(status domain.RepoStatus) string {
+	switch {
+	case status.IsDirty:
+		return "dirty"
+	case status.HasUntracked:
+		return "untracked"
+	default:
+		return "clean"
+	}
+}
+
+// buildStatusChangesContent renders the repositories the last fetch or pull changed, with
+// what changed in each
+func (m *Model) buildStatusChangesContent() string {
+	bold := lipgloss.NewStyle().Bold(true)
+	faint := lipgloss.NewStyle().Faint(true)
+
+	var b strings.Builder
+	snapshot := m.state.StatusChanges
+	switch {
+	case snapshot == nil:
+		b.WriteString(bold.Render("What changed"))
+		b.WriteString("\n\n")
+		b.WriteString("No fetch or pull yet\n")
+	case snapshot.Running:
+		b.WriteString(bold.Render(fmt.Sprintf("What the running %s changed so far", snapshot.Operation)))
+		b.WriteString("\n\n")
+	default:
+		b.WriteString(bold.Render(fmt.Sprintf("What the last %s changed", snapshot.Operation)))
+		b.WriteString("\n\n")
+	}
+
+	if snapshot != nil {
+		changed := m.changedRepos()
+		if len(changed) == 0 {
+			b.WriteString("Nothing changed\n")
+		}
+		nameWidth := 0
+		for _, c := range changed {
+			nameWidth = max(nameWidth, min(lipgloss.Width(m.compareName(c.path)), 30))
+		}
+		for _, c := range changed {
+			name := truncateCells(m.compareName(c.path), nameWidth)
+			b.WriteString(fmt.Sprintf("%-*s  %s\n", nameWidth, name, strings.Join(c.changes, ", ")))
+		}
+	}
+
+	b.WriteString("\n")
+	b.WriteString(faint.Render("esc close"))
+	return b.String()
+}
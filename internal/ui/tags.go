@@ -0,0 +1,50 @@
+package ui
+
+import (
+	"fmt"
+	"strings"
+)
+
+// parseTags splits a comma- or space-separated tag list, dropping a leading '#' and
+// repeated tags
+func parseTags(text string) []string {
+	var tags []string
+	seen := make(map[string]bool)
+	for _, field := range strings.FieldsFunc(text, func(r rune) bool { return r == ',' || r == ' ' }) {
+		tag := strings.TrimPrefix(field, "#")
+		if tag == "" || seen[strings.ToLower(tag)] {
+			continue
+		}
+		seen[strings.ToLower(tag)] = true
+		tags = append(tags, tag)
+	}
+	return tags
+}
+
+// setRepoTags replaces the tags of a repository and saves them as its labels in [repos]
+func (m *Model) setRepoTags(repoPath string, tags []string) {
+	repo, ok := m.state.Repositories[repoPath]
+	if !ok {
+		return
+	}
+	repo.Tags = tags
+	m.config.SetLabels(repoPath, tags)
+
+	if len(tags) == 0 {
+		m.state.StatusMessage = fmt.Sprintf("Removed the tags of %s", repo.Name)
+	} else {
+		m.state.StatusMessage = fmt.Sprintf("Tagged %s: %s", repo.Name, strings.Join(tags, ", "))
+	}
+
+	// The git service matches guards against labels, so it gets the new ones too
+	m.configChanged()
+}
+
+// refreshRepoTags reloads the tags of repositories whose labels changed in the config
+func (m *Model) refreshRepoTags(repoPaths []string) {
+	for _, repoPath := range repoPaths {
+		if repo, ok := m.state.Repositories[repoPath]; ok {
+			repo.Tags = append([]string(nil), m.config.Repos[repoPath].Labels...)
+		}
+	}
+}
@@ -0,0 +1,103 @@
+package termcaps
+
+import (
+	"strings"
+
+	"github.com/charmbracelet/colorprofile"
+
+	"github.com/darksworm/gitagrip/internal/config"
+)
+
+// Capabilities describes what the attached terminal can render
+type Capabilities struct {
+	Color   colorprofile.Profile
+	Unicode bool
+	Italics bool
+}
+
+// Detect inspects the environment (as returned by os.Environ) to guess the
+// terminal's capabilities
+func Detect(environ []string) Capabilities {
+	env := make(map[string]string, len(environ))
+	for _, kv := range environ {
+		if k, v, ok := strings.Cut(kv, "="); ok {
+			env[k] = v
+		}
+	}
+
+	term := strings.ToLower(env["TERM"])
+	limited := term == "dumb" || term == "linux" || term == "vt100" || term == "vt220" || term == "ansi"
+
+	return Capabilities{
+		Color:   detectColor(env, term),
+		Unicode: detectUnicode(env, limited),
+		Italics: !limited,
+	}
+}
+
+// detectColor determines the color profile from NO_COLOR, COLORTERM and TERM
+func detectColor(env map[string]string, term string) colorprofile.Profile {
+	if _, ok := env["NO_COLOR"]; ok {
+		return colorprofile.Ascii
+	}
+	if term == "dumb" {
+		return colorprofile.Ascii
+	}
+
+	colorTerm := strings.ToLower(env["COLORTERM"])
+	if colorTerm == "truecolor" || colorTerm == "24bit" || strings.Contains(term, "direct") {
+		return colorprofile.TrueColor
+	}
+	if strings.Contains(term, "256color") {
+		return colorprofile.ANSI256
+	}
+	if term == "linux" || term == "vt100" || term == "vt220" || term == "ansi" || term == "xterm" {
+		return colorprofile.ANSI
+	}
+	return colorprofile.ANSI256
+}
+
+// detectUnicode checks the locale for UTF-8 support. The Linux console and
+// similar limited terminals never get Unicode glyphs.
+func detectUnicode(env map[string]string, limited bool) bool {
+	if limited {
+		return false
+	}
+
+	// LC_ALL overrides LC_CTYPE, which overrides LANG
+	locale := env["LC_ALL"]
+	if locale == "" {
+		locale = env["LC_CTYPE"]
+	}
+	if locale == "" {
+		locale = env["LANG"]
+	}
+	if locale == "" {
+		// No locale information; assume a modern terminal
+		return true
+	}
+
+	locale = strings.ToLower(locale)
+	return strings.Contains(locale, "utf-8") || strings.Contains(locale, "utf8")
+}
+
+// WithOverrides applies the [ui.terminal] config settings on top of detected capabilities
+func (c Capabilities) WithOverrides(settings config.TerminalSettings) Capabilities {
+	switch strings.ToLower(settings.Color) {
+	case "truecolor", "24bit":
+		c.Color = colorprofile.TrueColor
+	case "256":
+		c.Color = colorprofile.ANSI256
+	case "16":
+		c.Color = colorprofile.ANSI
+	case "none":
+		c.Color = colorprofile.Ascii
+	}
+	if settings.Unicode != nil {
+		c.Unicode = *settings.Unicode
+	}
+	if settings.Italics != nil {
+		c.Italics = *settings.Italics
+	}
+	return c
+}
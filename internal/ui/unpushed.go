@@ -0,0 +1,190 @@
+package ui
+
+import (
+	"fmt"
+	"sort"
+	"strings"
+
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/charmbracelet/lipgloss/v2"
+
+	"github.com/darksworm/gitagrip/internal/ui/state"
+)
+
+// openUnpushed lists the commits not pushed yet of the selected repositories, the group
+// under the cursor or the current repository, read in the background
+func (m *Model) openUnpushed() tea.Cmd {
+	var paths []string
+	scope := ""
+	switch {
+	case m.store.GetSelectionCount() > 0:
+		for path := range m.store.GetSelectedRepositories() {
+			paths = append(paths, path)
+		}
+		scope = "the selected repos"
+	case m.getGroupAtIndex(m.state.SelectedIndex) != "":
+		group := m.getGroupAtIndex(m.state.SelectedIndex)
+		paths = m.groupMembers(group)
+		scope = "the repos in " + group
+	default:
+		if path := m.getRepoPathAtIndex(m.state.SelectedIndex); path != "" {
+			paths = append(paths, path)
+			scope = m.compareName(path)
+		}
+	}
+	sort.Slice(paths, func(i, j int) bool {
+		return m.compareName(paths[i]) < m.compareName(paths[j])
+	})
+
+	view := &state.Unpushed{
+		Scope:   scope,
+		Repos:   paths,
+		Targets: make(map[string]string),
+		Commits: make(map[string][]state.Commit),
+		Errors:  make(map[string]string),
+		Loading: true,
+	}
+	filters := make(map[string]state.LogFilter)
+	for _, path := range paths {
+		repo := m.state.Repositories[path]
+		// git log fails on a repository without commits, which has nothing to push anyway
+		if repo.Status.NoCommits {
+			continue
+		}
+		view.Targets[path] = repo.Status.Upstream
+		if repo.Status.Upstream != "" {
+			filters[path] = state.LogFilter{Range: repo.Status.Upstream + "..HEAD"}
+		} else {
+			// Without an upstream, whatever no remote has is unpushed
+			filters[path] = state.LogFilter{NotOnRemotes: true}
+		}
+	}
+	m.state.Unpushed = view
+	m.state.ShowInfo = true
+	m.state.InfoContent = m.buildUnpushedContent()
+
+	return func() tea.Msg {
+		msg := unpushedMsg{commits: make(map[string][]state.Commit), errs: make(map[string]string)}
+		for path, filter := range filters {
+			commits, err := m.gitOps.CommitLog(path, filter)
+			if err != nil {
+				msg.errs[path] = err.Error()
+				continue
+			}
+			msg.commits[path] = commits
+		}
+		return msg
+	}
+}
+
+// finishUnpushed shows the commits read, unless the view was closed meanwhile
+func (m *Model) finishUnpushed(msg unpushedMsg) {
+	view := m.state.Unpushed
+	if view == nil {
+		return
+	}
+	view.Commits = msg.commits
+	view.Errors = msg.errs
+	view.Loading = false
+	m.state.InfoContent = m.buildUnpushedContent()
+}
+
+// scrollUnpushed moves the first line shown, keeping a page of lines in view
+func (m *Model) scrollUnpushed(delta int) {
+	view := m.state.Unpushed
+	if view == nil {
+		return
+	}
+	view.Offset = max(0, min(view.Offset+delta, len(m.unpushedLines())-m.commitLogRows()))
+	m.state.InfoContent = m.buildUnpushedContent()
+}
+
+// unpushedLines renders every repository with unpushed commits, and its commits below it
+func (m *Model) unpushedLines() []string {
+	view := m.state.Unpushed
+	theme := m.renderer.Theme()
+	bold := lipgloss.NewStyle().Bold(true)
+	faint := lipgloss.NewStyle().Faint(true)
+	hash := lipgloss.NewStyle().Foreground(lipgloss.Color(theme.Warning))
+	errStyle := lipgloss.NewStyle().Foreground(lipgloss.Color(theme.Error))
+
+	width := max(40, m.width-14)
+	var lines []string
+	for _, path := range view.Repos {
+		if err, failed := view.Errors[path]; failed {
+			lines = append(lines, bold.Render(m.compareName(path))+" "+errStyle.Render(truncateCells(err, width/2)))
+			continue
+		}
+		commits := view.Commits[path]
+		if len(commits) == 0 {
+			continue
+		}
+		target := "not on any remote"
+		if upstream := view.Targets[path]; upstream != "" {
+			target = "not in " + upstream
+		}
+		count := fmt.Sprintf("%d", len(commits))
+		if len(commits) >= maxLogCommits {
+			count += "+"
+		}
+		branch := m.state.Repositories[path].Status.Branch
+		lines = append(lines, bold.Render(m.compareName(path))+faint.Render(fmt.Sprintf("  %s, %s commits %s", branch, count, target)))
+		for _, commit := range commits {
+			meta := fmt.Sprintf(" %s, %s", commit.Author, commit.Date)
+			room := width - 2 - lipgloss.Width(commit.Hash) - 1 - lipgloss.Width(meta)
+			lines = append(lines, "  "+hash.Render(commit.Hash)+" "+truncateCells(commit.Subject, max(10, room))+faint.Render(meta))
+		}
+	}
+	return lines
+}
+
+// buildUnpushedContent renders the unpushed commits view: totals, then a page of the
+// repositories with unpushed commits and their commits
+func (m *Model) buildUnpushedContent() string {
+	view := m.state.Unpushed
+	bold := lipgloss.NewStyle().Bold(true)
+	faint := lipgloss.NewStyle().Faint(true)
+
+	var b strings.Builder
+	b.WriteString(bold.Render("Unpushed commits: " + view.Scope))
+	b.WriteString("\n\n")
+
+	if view.Loading {
+		b.WriteString(faint.Render("Reading commits..."))
+		b.WriteString("\n\n")
+		b.WriteString(faint.Render("esc close"))
+		return b.String()
+	}
+
+	commits, repos := 0, 0
+	for _, path := range view.Repos {
+		if n := len(view.Commits[path]); n > 0 {
+			commits += n
+			repos++
+		}
+	}
+	pushed := len(view.Repos) - repos - len(view.Errors)
+	if commits == 0 && len(view.Errors) == 0 {
+		b.WriteString("Everything is pushed")
+	} else {
+		b.WriteString(fmt.Sprintf("%d commits in %d repos", commits, repos))
+	}
+	if pushed > 0 && commits > 0 {
+		b.WriteString(faint.Render(fmt.Sprintf("  (%d more repos have nothing to push)", pushed)))
+	}
+	b.WriteString("\n\n")
+
+	lines := m.unpushedLines()
+	end := min(len(lines), view.Offset+m.commitLogRows())
+	for _, line := range lines[min(view.Offset, end):end] {
+		b.WriteString(line + "\n")
+	}
+	if len(lines) > end-view.Offset {
+		b.WriteString(faint.Render(fmt.Sprintf("lines %d-%d of %d", view.Offset+1, end, len(lines))))
+		b.WriteString("\n")
+	}
+
+	b.WriteString("\n")
+	b.WriteString(faint.Render("j/k scroll • esc close"))
+	return b.String()
+}
@@ -0,0 +1,47 @@
+package ui
+
+import (
+	"fmt"
+	"testing"
+	"time"
+
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/charmbracelet/colorprofile"
+
+	"github.com/darksworm/gitagrip/internal/config"
+	"github.com/darksworm/gitagrip/internal/domain"
+	"github.com/darksworm/gitagrip/internal/eventbus"
+	"github.com/darksworm/gitagrip/internal/ui/termcaps"
+)
+
+// BenchmarkView draws frames of a 2,000 repository list between updates, as the redraw
+// tick does. Run it with: go test -run='^$' -bench=View ./internal/ui
+func BenchmarkView(b *testing.B) {
+	// Half of the repositories in groups of 25, the rest ungrouped
+	paths := make([]string, 2000)
+	cfg := config.DefaultConfig()
+	cfg.BaseDir = "/code"
+	cfg.Groups = make(map[string][]string)
+	for i := range paths {
+		paths[i] = fmt.Sprintf("/code/group-%02d/repo-%04d", i/25, i)
+		if i < len(paths)/2 {
+			group := fmt.Sprintf("group-%02d", i/25)
+			cfg.Groups[group] = append(cfg.Groups[group], paths[i])
+		}
+	}
+
+	m := NewModel(&recordingBus{}, cfg, termcaps.Capabilities{Color: colorprofile.TrueColor, Unicode: true})
+	m.Update(tea.WindowSizeMsg{Width: 120, Height: 40})
+	for _, path := range paths {
+		name := path[len(path)-9:]
+		m.Update(EventMsg{Event: eventbus.RepoDiscoveredEvent{Repo: domain.Repository{Path: path, Name: name, DisplayName: name}}})
+		m.Update(EventMsg{Event: eventbus.StatusUpdatedEvent{RepoPath: path, Status: domain.RepoStatus{Branch: "main"}}})
+	}
+	_ = m.View()
+
+	b.ResetTimer()
+	for i := 0; i < b.N; i++ {
+		m.Update(tickMsg(time.Now()))
+		_ = m.View()
+	}
+}
@@ -11,17 +11,28 @@ const (
 	InputModeNormal InputMode = iota
 	InputModeNewGroup
 	InputModeMoveToGroup
-	InputModeDeleteConfirm
+	InputModeConfirm
 	InputModeSearch
 	InputModeFilter
 	InputModeSort
 	InputModeRenameGroup
+	InputModeCleanConfirm
+	InputModeCancelConfirm
+	InputModeVisual
+	InputModeEditTags
+	InputModeArchiveConfirm
+	InputModeNewBranch
+	InputModeSwitchBranch
+	InputModeCredential
+	InputModeCloneOrg
 )
 
 // InputTransformer handles input mode transformations
 type InputTransformer struct {
-	mode      InputMode
-	textInput textinput.Model
+	mode          InputMode
+	textInput     textinput.Model
+	groupTemplate string
+	credential    string // prompt of the credential request shown
 }
 
 // NewInputTransformer creates a new input transformer
@@ -37,6 +48,16 @@ func (it *InputTransformer) SetMode(mode InputMode) {
 	it.mode = mode
 }
 
+// SetGroupTemplate sets the template shown while naming a new group
+func (it *InputTransformer) SetGroupTemplate(name string) {
+	it.groupTemplate = name
+}
+
+// SetCredentialPrompt sets what git or ssh asked, shown while answering it
+func (it *InputTransformer) SetCredentialPrompt(prompt string) {
+	it.credential = prompt
+}
+
 // GetInputText returns the current text input string for the view
 func (it *InputTransformer) GetInputText() string {
 	if it.mode == InputModeNormal {
@@ -44,10 +65,13 @@ func (it *InputTransformer) GetInputText() string {
 	}
 
 	switch it.mode {
-	case InputModeDeleteConfirm:
-		return "Disband group? (y/n): "
+	case InputModeConfirm:
+		return ""
 	case InputModeNewGroup:
-		return "Enter new group name: " + it.textInput.View()
+		if it.groupTemplate != "" {
+			return "Enter new group name [template: " + it.groupTemplate + ", tab to change]: " + it.textInput.View()
+		}
+		return "Enter new group name [tab for a template]: " + it.textInput.View()
 	case InputModeMoveToGroup:
 		return "Move to group: " + it.textInput.View()
 	case InputModeSearch:
@@ -59,6 +83,20 @@ func (it *InputTransformer) GetInputText() string {
 		return ""
 	case InputModeRenameGroup:
 		return "Rename group to: " + it.textInput.View()
+	case InputModeCleanConfirm:
+		return "Run git clean -fdx? (y/n): "
+	case InputModeCancelConfirm:
+		return "Cancel remaining operations? (y/n): "
+	case InputModeArchiveConfirm:
+		return "Archive repository? (y/n): "
+	case InputModeEditTags:
+		return "Tags (comma-separated): " + it.textInput.View()
+	case InputModeCredential:
+		return it.credential + it.textInput.View()
+	case InputModeCloneOrg:
+		return "Clone from organization (myorg, github:myorg or gitlab:mygroup): " + it.textInput.View()
+	case InputModeVisual:
+		return "-- VISUAL -- move to extend, space to select, m to move to group, esc to cancel"
 	default:
 		return it.textInput.View()
 	}
@@ -73,8 +111,8 @@ func (it *InputTransformer) GetInputModeString() string {
 		return "new-group"
 	case InputModeMoveToGroup:
 		return "move-to-group"
-	case InputModeDeleteConfirm:
-		return "delete-confirm"
+	case InputModeConfirm:
+		return "confirm"
 	case InputModeSearch:
 		return "search"
 	case InputModeFilter:
@@ -83,6 +121,24 @@ func (it *InputTransformer) GetInputModeString() string {
 		return "sort"
 	case InputModeRenameGroup:
 		return "rename-group"
+	case InputModeCleanConfirm:
+		return "clean-confirm"
+	case InputModeCancelConfirm:
+		return "cancel-confirm"
+	case InputModeArchiveConfirm:
+		return "archive-confirm"
+	case InputModeVisual:
+		return "visual"
+	case InputModeEditTags:
+		return "edit-tags"
+	case InputModeNewBranch:
+		return "new-branch"
+	case InputModeSwitchBranch:
+		return "switch-branch"
+	case InputModeCredential:
+		return "credential"
+	case InputModeCloneOrg:
+		return "clone-org"
 	default:
 		return ""
 	}
@@ -4,9 +4,9 @@ import (
 	"github.com/charmbracelet/bubbles/v2/help"
 	"github.com/charmbracelet/bubbles/v2/textinput"
 
-	"gitagrip/internal/config"
-	"gitagrip/internal/ui/state"
-	"gitagrip/internal/ui/views"
+	"github.com/darksworm/gitagrip/internal/config"
+	"github.com/darksworm/gitagrip/internal/ui/state"
+	"github.com/darksworm/gitagrip/internal/ui/views"
 )
 
 // ViewModel transforms application state into view-ready data
@@ -16,9 +16,9 @@ type ViewModel struct {
 	width            int
 	height           int
 	help             help.Model
-	deleteTarget     string
 	ungroupedRepos   []string
 	inputTransformer *InputTransformer
+	quickBar         []views.QuickAction
 }
 
 // NewViewModel creates a new view model
@@ -41,9 +41,9 @@ func (vm *ViewModel) SetHelp(helpModel help.Model) {
 	vm.help = helpModel
 }
 
-// SetDeleteTarget sets the current delete target
-func (vm *ViewModel) SetDeleteTarget(target string) {
-	vm.deleteTarget = target
+// SetQuickBar sets the actions pinned to the footer
+func (vm *ViewModel) SetQuickBar(items []views.QuickAction) {
+	vm.quickBar = items
 }
 
 // SetInputMode sets the current input mode
@@ -51,6 +51,16 @@ func (vm *ViewModel) SetInputMode(mode InputMode) {
 	vm.inputTransformer.SetMode(mode)
 }
 
+// SetCredentialPrompt sets the prompt shown while answering a credential request
+func (vm *ViewModel) SetCredentialPrompt(prompt string) {
+	vm.inputTransformer.SetCredentialPrompt(prompt)
+}
+
+// SetGroupTemplate sets the template shown while naming a new group
+func (vm *ViewModel) SetGroupTemplate(name string) {
+	vm.inputTransformer.SetGroupTemplate(name)
+}
+
 // UpdateTextInput updates the text input model
 func (vm *ViewModel) UpdateTextInput(textInput textinput.Model) {
 	vm.inputTransformer.textInput = textInput
@@ -89,12 +99,55 @@ func (vm *ViewModel) BuildViewState() views.ViewState {
 		IsFiltered:      vm.state.IsFiltered,
 		ShowAheadBehind: vm.config.UISettings.ShowAheadBehind,
 		HelpModel:       vm.help,
-		DeleteTarget:    vm.deleteTarget,
+		QuickBar:        vm.quickBar,
 		TextInput:       vm.inputTransformer.GetInputText(),
 		InputMode:       vm.inputTransformer.GetInputModeString(),
 		UngroupedRepos:  vm.ungroupedRepos,
 		SortOptionIndex: vm.state.SortOptionIndex,
+		SortLabel:       vm.state.SortLabel,
 		LoadingState:    vm.state.LoadingState,
 		LoadingCount:    vm.state.LoadingCount,
+		GroupColors:     vm.groupColors(),
+		FetchProgress:   views.Progress(vm.state.BulkProgress["fetch"]),
+		PullProgress:    views.Progress(vm.state.BulkProgress["pull"]),
+		PushProgress:    views.Progress(vm.state.BulkProgress["push"]),
+		Visual:          vm.state.Visual,
+		VisualFrom:      min(vm.state.VisualAnchor, vm.state.SelectedIndex),
+		VisualTo:        max(vm.state.VisualAnchor, vm.state.SelectedIndex),
+		WatchedCommits:  vm.watchedCommits(),
+		ConfigDirty:     vm.state.ConfigDirty,
+		ScanWarning:     vm.state.ScanWarning,
+		Toasts:          vm.toasts(),
+		Confirm:         vm.state.Confirm,
+	}
+}
+
+// groupColors resolves the colors of [group_colors]; ValidateConfig reports invalid ones
+func (vm *ViewModel) groupColors() map[string]string {
+	if len(vm.config.GroupColors) == 0 {
+		return nil
+	}
+	colors, _ := views.ResolveGroupColors(vm.config.GroupColors)
+	return colors
+}
+
+// toasts returns the notifications to show
+func (vm *ViewModel) toasts() []views.Toast {
+	toasts := make([]views.Toast, 0, len(vm.state.Toasts))
+	for _, toast := range vm.state.Toasts {
+		toasts = append(toasts, views.Toast{Level: toast.Level, Text: toast.Text})
+	}
+	return toasts
+}
+
+// watchedCommits counts the unacknowledged watched commits of each repository
+func (vm *ViewModel) watchedCommits() map[string]int {
+	if len(vm.state.WatchedChanges) == 0 {
+		return nil
+	}
+	counts := make(map[string]int)
+	for _, change := range vm.state.WatchedChanges {
+		counts[change.RepoPath] += len(change.Commits)
 	}
+	return counts
 }
@@ -0,0 +1,257 @@
+package views
+
+import (
+	"fmt"
+	"strings"
+	"time"
+
+	"github.com/charmbracelet/lipgloss/v2"
+	"github.com/charmbracelet/x/ansi"
+
+	"github.com/darksworm/gitagrip/internal/domain"
+)
+
+// Columns of the repository list selectable via ui.columns
+const (
+	ColumnName        = "name"
+	ColumnBranch      = "branch"
+	ColumnAheadBehind = "ahead_behind"
+	ColumnChanges     = "changes"
+	ColumnLastCommit  = "last_commit"
+)
+
+var columnNames = []string{ColumnName, ColumnBranch, ColumnAheadBehind, ColumnChanges, ColumnLastCommit}
+
+// Column widths: the name and branch columns are cut to their cap, and shrink down to
+// their minimum before columns are dropped on narrow terminals
+const (
+	columnGap       = 2
+	nameColumnCap   = 40
+	nameColumnMin   = 10
+	branchColumnCap = 30
+	branchColumnMin = 6
+)
+
+// ParseColumns checks the column names of ui.columns. The name column is always shown and
+// comes first when it isn't listed. No columns ([] or unset) keeps the one-line format.
+func ParseColumns(names []string) ([]string, error) {
+	if len(names) == 0 {
+		return nil, nil
+	}
+	seen := make(map[string]bool, len(names))
+	var columns []string
+	for _, name := range names {
+		name = strings.ToLower(strings.TrimSpace(name))
+		known := false
+		for _, column := range columnNames {
+			known = known || column == name
+		}
+		if !known {
+			return nil, fmt.Errorf("ui.columns: unknown column %q (use %s)", name, strings.Join(columnNames, ", "))
+		}
+		if seen[name] {
+			return nil, fmt.Errorf("ui.columns: %q is listed twice", name)
+		}
+		seen[name] = true
+		columns = append(columns, name)
+	}
+	if !seen[ColumnName] {
+		columns = append([]string{ColumnName}, columns...)
+	}
+	return columns, nil
+}
+
+// ColumnLayout is the width of each column shown, shared by every row of the list
+type ColumnLayout struct {
+	Columns  []string
+	Widths   []int
+	Ellipsis string // marks text cut to fit
+}
+
+// layoutColumns sizes the columns to their widest cell among the rows, then fits them in
+// the width of the list: the name and branch columns shrink first, then columns are
+// dropped from the right
+func (r *Renderer) layoutColumns(state ViewState, rows []ListRow) ColumnLayout {
+	layout := ColumnLayout{Columns: r.columns, Widths: make([]int, len(r.columns)), Ellipsis: r.Ellipsis()}
+	for _, row := range rows {
+		repo := state.Repositories[row.RepoPath]
+		if row.Header || repo == nil {
+			continue
+		}
+		for i, column := range layout.Columns {
+			width := lipgloss.Width(r.repoRender.cellText(repo, column))
+			if column == ColumnName {
+				width += 2 * row.Indent
+			}
+			layout.Widths[i] = max(layout.Widths[i], width)
+		}
+	}
+	for i, column := range layout.Columns {
+		switch column {
+		case ColumnName:
+			layout.Widths[i] = min(layout.Widths[i], nameColumnCap)
+		case ColumnBranch:
+			layout.Widths[i] = min(layout.Widths[i], branchColumnCap)
+		}
+	}
+
+	// The list's container pads it by 2 on each side; the status glyph and a space lead
+	room := state.Width - 4 - r.glyphs.StatusWidth() - 1
+	if room <= 0 {
+		return layout
+	}
+	total := func() int {
+		sum := 0
+		for _, width := range layout.Widths {
+			sum += width
+		}
+		return sum + columnGap*(len(layout.Widths)-1)
+	}
+	for total() > room {
+		if r.shrinkColumn(&layout, total()-room) {
+			continue
+		}
+		last := len(layout.Columns) - 1
+		if last == 0 {
+			break
+		}
+		layout.Columns = layout.Columns[:last]
+		layout.Widths = layout.Widths[:last]
+	}
+	return layout
+}
+
+// shrinkColumn takes up to excess cells from the name or branch column, whichever has
+// more to give above its minimum. It reports false when neither can shrink.
+func (r *Renderer) shrinkColumn(layout *ColumnLayout, excess int) bool {
+	best, spare := -1, 0
+	for i, column := range layout.Columns {
+		minimum := 0
+		switch column {
+		case ColumnName:
+			minimum = nameColumnMin
+		case ColumnBranch:
+			minimum = branchColumnMin
+		default:
+			continue
+		}
+		if s := layout.Widths[i] - minimum; s > spare {
+			best, spare = i, s
+		}
+	}
+	if best < 0 {
+		return false
+	}
+	layout.Widths[best] -= min(excess, spare)
+	return true
+}
+
+// cellText is the unstyled text of a repository's cell in a column
+func (r *RepositoryRenderer) cellText(repo *domain.Repository, column string) string {
+	switch column {
+	case ColumnName:
+		name := repo.DisplayName
+		if name == "" {
+			name = repo.Name
+		}
+		if icon := r.glyphs.ProjectIcon(repo.Project); icon != "" {
+			name = icon + " " + name
+		}
+		return name
+	case ColumnBranch:
+		branch := repo.Status.Branch
+		if branch == "" {
+			branch = "no branch"
+		}
+		if r.glyphs.Branch != "" {
+			return r.glyphs.Branch + " " + branchLabel(repo.Status, branch)
+		}
+		return branchLabel(repo.Status, branch)
+	case ColumnAheadBehind:
+		return r.getAheadBehindText(repo.Status.AheadCount, repo.Status.BehindCount)
+	case ColumnChanges:
+		return ansi.Strip(r.changeCounts(repo.Status, ""))
+	case ColumnLastCommit:
+		if repo.Status.LastCommitTime.IsZero() {
+			return ""
+		}
+		text := CommitAge(repo.Status.LastCommitTime, time.Now())
+		if initials := AuthorInitials(repo.Status.LastCommitBy); initials != "" {
+			text += " " + initials
+		}
+		return text
+	}
+	return ""
+}
+
+// RenderRepositoryColumns renders a repository as a row of cells laid out by layout, each
+// cut to its column's width, followed by the badges that have no column
+func (r *RepositoryRenderer) RenderRepositoryColumns(repo *domain.Repository, isSelected bool, indent int, gutterColor string,
+	isMultiSelect bool, isFetching bool, isRefreshing bool, isPulling bool,
+	searchQuery string, isRepoSelected bool, watchedCommits int, width int, layout ColumnLayout) string {
+	if repo == nil {
+		return ""
+	}
+	bgColor := r.rowBackground(isSelected, isRepoSelected, isMultiSelect)
+	plain := lipgloss.NewStyle().Background(lipgloss.Color(bgColor))
+
+	statusStyle := r.getStatusStyle(repo, isFetching, isRefreshing)
+	if repo.Status.Stale {
+		statusStyle = statusStyle.Faint(true)
+	}
+	if bgColor != "" {
+		statusStyle = statusStyle.Background(lipgloss.Color(bgColor))
+	}
+	status := r.getStatusIcon(repo, isFetching, isRefreshing, isPulling)
+	parts := []string{statusStyle.Render(r.glyphs.PadStatus(status)), plain.Render(" ")}
+
+	for i, column := range layout.Columns {
+		if i > 0 {
+			parts = append(parts, plain.Render(strings.Repeat(" ", columnGap)))
+		}
+		cellWidth := layout.Widths[i]
+		if column == ColumnName && indent > 0 {
+			parts = append(parts, r.indentation(indent, bgColor, gutterColor))
+			cellWidth = max(0, cellWidth-2*indent)
+		}
+		text := r.cellText(repo, column)
+		if column != ColumnChanges {
+			text = ansi.Truncate(text, cellWidth, layout.Ellipsis)
+		}
+
+		var cell string
+		switch column {
+		case ColumnName:
+			cell = plain.Render(text)
+			if searchQuery != "" && strings.Contains(strings.ToLower(text), strings.ToLower(searchQuery)) {
+				highlight := plain.Foreground(lipgloss.Color(r.styles.Theme.Highlight))
+				cell = r.highlightMatch(text, searchQuery, highlight, plain)
+			}
+		case ColumnBranch:
+			cell = r.branchStyle(repo.Status, bgColor).Render(text)
+		case ColumnChanges:
+			cell = r.changeCounts(repo.Status, bgColor)
+		case ColumnLastCommit:
+			cell = plain.Faint(true).Render(text)
+		default:
+			cell = plain.Render(text)
+		}
+		parts = append(parts, cell)
+		if pad := cellWidth - lipgloss.Width(cell); pad > 0 {
+			parts = append(parts, plain.Render(strings.Repeat(" ", pad)))
+		}
+	}
+
+	for _, badge := range r.badges(repo, watchedCommits, bgColor, false) {
+		parts = append(parts, plain.Render(" "), badge)
+	}
+
+	line := strings.Join(parts, "")
+	if width > 0 {
+		line = ansi.Truncate(line, width, "")
+		if pad := width - lipgloss.Width(line); bgColor != "" && pad > 0 {
+			line += plain.Render(strings.Repeat(" ", pad))
+		}
+	}
+	return line
+}
@@ -0,0 +1,50 @@
+package views
+
+import (
+	"strings"
+
+	"github.com/charmbracelet/lipgloss/v2"
+	"github.com/charmbracelet/x/ansi"
+)
+
+// PaneWidths splits the width inside the view's padding between the list and the details
+// pane beside it, with a column for the divider between them
+func PaneWidths(width int) (list, details int) {
+	inner := width - 4
+	list = inner * 11 / 20
+	return list, inner - list - 1
+}
+
+// renderTwoPane draws the list in the left part of the view and the details pane, as tall
+// as the list, to its right
+func (r *Renderer) renderTwoPane(state ViewState) string {
+	listWidth, paneWidth := PaneWidths(state.Width)
+	listState := state
+	listState.Width = listWidth + 4 // rows leave room for the view's padding
+	list := strings.Split(r.renderRepositoryList(listState), "\n")
+	pane := strings.Split(state.DetailsPane, "\n")
+
+	divider, ellipsis := "│", r.Ellipsis()
+	if !r.unicode {
+		divider = "|"
+	}
+	divider = lipgloss.NewStyle().Foreground(lipgloss.Color(r.styles.Theme.Border)).Render(divider)
+
+	height := max(len(list), state.ViewportHeight)
+	lines := make([]string, height)
+	for i := range lines {
+		left := ""
+		if i < len(list) {
+			left = ansi.Truncate(list[i], listWidth, "")
+		}
+		if pad := listWidth - lipgloss.Width(left); pad > 0 {
+			left += strings.Repeat(" ", pad)
+		}
+		right := ""
+		if i < len(pane) {
+			right = ansi.Truncate(pane[i], paneWidth-1, ellipsis)
+		}
+		lines[i] = left + divider + " " + right
+	}
+	return strings.Join(lines, "\n")
+}
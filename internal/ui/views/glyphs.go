@@ -0,0 +1,202 @@
+package views
+
+import (
+	"fmt"
+	"strings"
+
+	"github.com/charmbracelet/lipgloss/v2"
+)
+
+// Glyphs holds the symbols used for repository status indicators
+type Glyphs struct {
+	Dirty   string
+	Clean   string
+	Loading string
+	Unknown string
+	Ahead   string
+	Behind  string
+	Error   string
+	Warning string
+
+	// Change counts next to the branch, e.g. "●3 ✚1 ⚑2"
+	Staged     string
+	Modified   string
+	Untracked  string
+	Conflicted string
+	Stashed    string
+
+	// Group header arrows
+	Expanded  string
+	Collapsed string
+
+	// Marker beside the repositories of a group with a color
+	Gutter string
+
+	// Icon before the branch, and before the name by the kind of project at the root of
+	// the repository (see discovery.DetectProject); none in the unicode and ascii sets
+	Branch   string
+	Projects map[string]string
+}
+
+// IconSets are the names ui.icons accepts
+var IconSets = []string{"unicode", "nerdfont", "ascii"}
+
+// glyphPresets are the built-in glyph sets selectable via ui.glyphs.preset
+var glyphPresets = map[string]Glyphs{
+	"unicode": {
+		Dirty:   "●",
+		Clean:   "✓",
+		Loading: "⟳",
+		Unknown: "?",
+		Ahead:   "↑",
+		Behind:  "↓",
+		Error:   "✗",
+		Warning: "⚠",
+
+		Staged:     "●",
+		Modified:   "✚",
+		Untracked:  "…",
+		Conflicted: "✖",
+		Stashed:    "⚑",
+
+		Expanded:  "▼",
+		Collapsed: "▶",
+
+		Gutter: "▎",
+	},
+	// Requires a Nerd Font patched terminal font
+	"nerdfont": {
+		Dirty:   "\uf111", // nf-fa-circle
+		Clean:   "\uf00c", // nf-fa-check
+		Loading: "\uf021", // nf-fa-refresh
+		Unknown: "\uf128", // nf-fa-question
+		Ahead:   "\uf062", // nf-fa-arrow_up
+		Behind:  "\uf063", // nf-fa-arrow_down
+		Error:   "\uf00d", // nf-fa-times
+		Warning: "\uf071", // nf-fa-warning
+
+		Staged:     "\uf055", // nf-fa-plus_circle
+		Modified:   "\uf040", // nf-fa-pencil
+		Untracked:  "\uf128", // nf-fa-question
+		Conflicted: "\uf126", // nf-fa-code_fork
+		Stashed:    "\uf187", // nf-fa-archive
+
+		Expanded:  "\uf0d7", // nf-fa-caret_down
+		Collapsed: "\uf0da", // nf-fa-caret_right
+
+		Gutter: "▎",
+
+		Branch: "\ue0a0", // nf-pl-branch
+		Projects: map[string]string{
+			"Go":         "\ue627", // nf-seti-go
+			"Rust":       "\ue7a8", // nf-dev-rust
+			"JavaScript": "\ue74e", // nf-dev-javascript
+			"TypeScript": "\ue628", // nf-seti-typescript
+			"Python":     "\ue73c", // nf-dev-python
+			"Ruby":       "\ue739", // nf-dev-ruby
+			"Java":       "\ue738", // nf-dev-java
+			"PHP":        "\ue73d", // nf-dev-php
+			"Elixir":     "\ue62d", // nf-seti-elixir
+			"Swift":      "\ue755", // nf-dev-swift
+			"Dart":       "\ue798", // nf-dev-dart
+			"C/C++":      "\ue61d", // nf-custom-cpp
+			"C#":         "\ue648", // nf-seti-c_sharp
+			"Nix":        "\uf313", // nf-linux-nixos
+		},
+	},
+	// Plain ASCII for terminals without Unicode support (e.g. the Linux console)
+	"ascii": {
+		Dirty:   "*",
+		Clean:   "+",
+		Loading: "~",
+		Unknown: "?",
+		Ahead:   "^",
+		Behind:  "v",
+		Error:   "x",
+		Warning: "!",
+
+		Staged:     "+",
+		Modified:   "~",
+		Untracked:  "?",
+		Conflicted: "=",
+		Stashed:    "$",
+
+		Expanded:  "v",
+		Collapsed: ">",
+
+		Gutter: "|",
+	},
+}
+
+// DefaultGlyphs returns the built-in unicode glyph set
+func DefaultGlyphs() Glyphs {
+	return glyphPresets["unicode"]
+}
+
+// ResolveGlyphs builds a glyph set from a preset name and applies any non-empty overrides.
+// Unknown preset names fall back to the unicode set.
+func ResolveGlyphs(preset string, overrides Glyphs) Glyphs {
+	g, ok := glyphPresets[strings.ToLower(strings.TrimSpace(preset))]
+	if !ok {
+		g = DefaultGlyphs()
+	}
+
+	apply := func(dst *string, src string) {
+		if src != "" {
+			*dst = src
+		}
+	}
+	apply(&g.Dirty, overrides.Dirty)
+	apply(&g.Clean, overrides.Clean)
+	apply(&g.Loading, overrides.Loading)
+	apply(&g.Unknown, overrides.Unknown)
+	apply(&g.Ahead, overrides.Ahead)
+	apply(&g.Behind, overrides.Behind)
+	apply(&g.Error, overrides.Error)
+	apply(&g.Warning, overrides.Warning)
+	apply(&g.Staged, overrides.Staged)
+	apply(&g.Modified, overrides.Modified)
+	apply(&g.Untracked, overrides.Untracked)
+	apply(&g.Conflicted, overrides.Conflicted)
+	apply(&g.Stashed, overrides.Stashed)
+	apply(&g.Expanded, overrides.Expanded)
+	apply(&g.Collapsed, overrides.Collapsed)
+	apply(&g.Gutter, overrides.Gutter)
+	apply(&g.Branch, overrides.Branch)
+
+	return g
+}
+
+// CheckIcons checks the icon set named by ui.icons ("" keeps the default)
+func CheckIcons(name string) error {
+	name = strings.ToLower(strings.TrimSpace(name))
+	if _, ok := glyphPresets[name]; name != "" && !ok {
+		return fmt.Errorf("ui.icons: unknown icon set %q (use %s)", name, strings.Join(IconSets, ", "))
+	}
+	return nil
+}
+
+// ProjectIcon returns the icon for a kind of project, or "" when the set has none
+func (g Glyphs) ProjectIcon(project string) string {
+	return g.Projects[project]
+}
+
+// StatusWidth returns the cell width all status glyphs are padded to, so that
+// repository names stay aligned even when glyphs of different widths are mixed
+func (g Glyphs) StatusWidth() int {
+	width := 1
+	for _, glyph := range []string{g.Dirty, g.Clean, g.Loading, g.Unknown, g.Error, g.Warning} {
+		if w := lipgloss.Width(glyph); w > width {
+			width = w
+		}
+	}
+	return width
+}
+
+// PadStatus pads a status glyph with trailing spaces up to StatusWidth
+func (g Glyphs) PadStatus(glyph string) string {
+	if w := lipgloss.Width(glyph); w < g.StatusWidth() {
+		return glyph + strings.Repeat(" ", g.StatusWidth()-w)
+	}
+	return glyph
+}
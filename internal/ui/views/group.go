@@ -6,49 +6,76 @@ import (
 
 	"github.com/charmbracelet/lipgloss/v2"
 
-	"gitagrip/internal/domain"
+	"github.com/darksworm/gitagrip/internal/domain"
 )
 
 // GroupRenderer handles rendering of group headers
 type GroupRenderer struct {
 	styles *Styles
+	glyphs Glyphs
 }
 
 // NewGroupRenderer creates a new group renderer
 func NewGroupRenderer(styles *Styles) *GroupRenderer {
 	return &GroupRenderer{
 		styles: styles,
+		glyphs: DefaultGlyphs(),
 	}
 }
 
-// RenderGroupHeader renders a group header
+// SetGlyphs replaces the glyph set used for the expand/collapse arrows
+func (g *GroupRenderer) SetGlyphs(glyphs Glyphs) {
+	g.glyphs = glyphs
+}
+
+// RenderGroupHeader renders a group header, its arrow and name in the group's color if it
+// has one
 func (g *GroupRenderer) RenderGroupHeader(group *domain.Group, isExpanded bool, isSelected bool,
-	searchQuery string, repoCount int, width int, groupIsFullySelected bool) string {
+	searchQuery string, repoCount int, total int, width int, groupIsFullySelected bool, color string) string {
 
 	// Determine arrow
-	arrow := "▶"
+	arrow := g.glyphs.Collapsed
 	if isExpanded {
-		arrow = "▼"
+		arrow = g.glyphs.Expanded
+	}
+
+	// Apply background color based on selection state
+	var bgColor string
+	if isSelected && groupIsFullySelected {
+		bgColor = g.styles.Theme.CursorSelected // cursor on fully selected group
+	} else if isSelected {
+		bgColor = g.styles.Theme.Cursor // cursor selection
+	} else if groupIsFullySelected {
+		bgColor = g.styles.Theme.Selected // all repos are selected
 	}
 
 	// Build group name with search highlighting
 	groupName := group.Name
-	if searchQuery != "" && strings.Contains(strings.ToLower(groupName), strings.ToLower(searchQuery)) {
+	highlighted := searchQuery != "" && strings.Contains(strings.ToLower(groupName), strings.ToLower(searchQuery))
+	if highlighted {
 		groupName = g.highlightMatch(groupName, searchQuery, g.styles.Highlight, lipgloss.NewStyle())
 	}
 
-	// Format the complete line
-	line := fmt.Sprintf("%s %s (%d)", arrow, groupName, repoCount)
+	// Format the complete line; a filter shows how many of the group's repos match
+	count := fmt.Sprint(repoCount)
+	if repoCount != total {
+		count = fmt.Sprintf("%d/%d", repoCount, total)
+	}
 
-	// Apply background color based on selection state
-	var bgColor string
-	if isSelected && groupIsFullySelected {
-		bgColor = "33" // Blue background for cursor on fully selected group
-	} else if isSelected {
-		bgColor = "238" // Darker background for cursor selection
-	} else if groupIsFullySelected {
-		bgColor = "240" // Lighter background when all repos are selected
+	// A colored group's arrow and name are styled apart from the rest of the line
+	if color != "" {
+		base := lipgloss.NewStyle().Background(lipgloss.Color(bgColor))
+		colored := base.Foreground(lipgloss.Color(color))
+		if !highlighted {
+			groupName = colored.Bold(true).Render(groupName)
+		}
+		line := colored.Render(arrow) + base.Render(" ") + groupName + base.Render(fmt.Sprintf(" (%s)", count))
+		if pad := width - lipgloss.Width(line); bgColor != "" && pad > 0 {
+			line += base.Render(strings.Repeat(" ", pad))
+		}
+		return line
 	}
+	line := fmt.Sprintf("%s %s (%s)", arrow, groupName, count)
 
 	// Apply background if needed
 	if bgColor != "" {
@@ -0,0 +1,54 @@
+package views
+
+import (
+	"errors"
+	"fmt"
+	"sort"
+	"strings"
+)
+
+// GroupColor is a color the group color picker offers, by name
+type GroupColor struct {
+	Name  string
+	Color string // ANSI 256 color number
+}
+
+// GroupColorPalette lists the colors of the picker, in the order of its digit keys
+var GroupColorPalette = []GroupColor{
+	{"red", "203"},
+	{"orange", "214"},
+	{"yellow", "221"},
+	{"green", "114"},
+	{"cyan", "80"},
+	{"blue", "75"},
+	{"purple", "141"},
+	{"pink", "211"},
+}
+
+// ResolveGroupColors turns [group_colors] into colors the renderer can use: palette names
+// become their color, ANSI numbers and hex colors are kept. Invalid colors are reported
+// and left out.
+func ResolveGroupColors(colors map[string]string) (map[string]string, error) {
+	groups := make([]string, 0, len(colors))
+	for group := range colors {
+		groups = append(groups, group)
+	}
+	sort.Strings(groups)
+
+	resolved := make(map[string]string, len(colors))
+	var errs []error
+	for _, group := range groups {
+		color := strings.TrimSpace(colors[group])
+		for _, c := range GroupColorPalette {
+			if strings.EqualFold(color, c.Name) {
+				color = c.Color
+			}
+		}
+		if !validColor(color) {
+			errs = append(errs, fmt.Errorf("group_colors: invalid color %q for %s", colors[group], group))
+			continue
+		}
+		resolved[group] = color
+	}
+	return resolved, errors.Join(errs...)
+}
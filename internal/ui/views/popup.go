@@ -38,7 +38,7 @@ func (pr *PopupRenderer) RenderPopupOverlay(mainContent, popupContent string, he
 
 	// Base greyscale layer, but keep the target repository line colored
 	targetName := extractTitlePlain(popupContent)
-	grayBase := desaturateKeeping(mainContent, targetName)
+	grayBase := desaturateKeeping(mainContent, targetName, pr.styles.Theme.Faded)
 	baseLayer := lipgloss.NewLayer(grayBase)
 
 	// Modal layer on top (only its bounding box, not whole lines)
@@ -52,10 +52,10 @@ func (pr *PopupRenderer) RenderPopupOverlay(mainContent, popupContent string, he
 // ANSI escape sequence regex to strip styles/colors
 var ansiRE = regexp.MustCompile(`\x1b\[[0-9;]*m`)
 
-// desaturateANSI strips ANSI color/style codes and recolors text dim gray
-func desaturateANSI(s string) string {
+// desaturateANSI strips ANSI color/style codes and recolors text in the faded color
+func desaturateANSI(s, faded string) string {
 	plain := ansiRE.ReplaceAllString(s, "")
-	return lipgloss.NewStyle().Foreground(lipgloss.Color("245")).Render(plain)
+	return lipgloss.NewStyle().Foreground(lipgloss.Color(faded)).Render(plain)
 }
 
 // extractTitlePlain returns the first line of popup content without ANSI (repo name in Info modal)
@@ -70,9 +70,9 @@ func extractTitlePlain(popup string) string {
 }
 
 // desaturateKeeping turns everything greyscale except lines containing keepSubstr (plain text match)
-func desaturateKeeping(s, keepSubstr string) string {
+func desaturateKeeping(s, keepSubstr, faded string) string {
 	if keepSubstr == "" {
-		return desaturateANSI(s)
+		return desaturateANSI(s, faded)
 	}
 	lines := strings.Split(s, "\n")
 	out := make([]string, len(lines))
@@ -82,7 +82,7 @@ func desaturateKeeping(s, keepSubstr string) string {
 			// keep original colored line
 			out[i] = line
 		} else {
-			out[i] = lipgloss.NewStyle().Foreground(lipgloss.Color("245")).Render(plain)
+			out[i] = lipgloss.NewStyle().Foreground(lipgloss.Color(faded)).Render(plain)
 		}
 	}
 	return strings.Join(out, "\n")
@@ -2,17 +2,22 @@ package views
 
 import (
 	"fmt"
+	"regexp"
 	"strings"
+	"time"
+	"unicode"
 
 	"github.com/charmbracelet/lipgloss/v2"
 
-	"gitagrip/internal/domain"
+	"github.com/darksworm/gitagrip/internal/domain"
 )
 
 // RepositoryRenderer handles rendering of repository items
 type RepositoryRenderer struct {
 	styles          *Styles
 	showAheadBehind bool
+	showLastCommit  bool // age and author initials of the last commit
+	glyphs          Glyphs
 }
 
 // NewRepositoryRenderer creates a new repository renderer
@@ -20,74 +25,65 @@ func NewRepositoryRenderer(styles *Styles, showAheadBehind bool) *RepositoryRend
 	return &RepositoryRenderer{
 		styles:          styles,
 		showAheadBehind: showAheadBehind,
+		glyphs:          DefaultGlyphs(),
 	}
 }
 
+// SetGlyphs replaces the glyph set used for status indicators
+func (r *RepositoryRenderer) SetGlyphs(glyphs Glyphs) {
+	r.glyphs = glyphs
+}
+
+// SetShowLastCommit shows the age and author initials of each repository's last commit
+func (r *RepositoryRenderer) SetShowLastCommit(show bool) {
+	r.showLastCommit = show
+}
+
 // RenderRepository renders a repository item
-func (r *RepositoryRenderer) RenderRepository(repo *domain.Repository, isSelected bool, indent int,
+func (r *RepositoryRenderer) RenderRepository(repo *domain.Repository, isSelected bool, indent int, gutterColor string,
 	isMultiSelect bool, isFetching bool, isRefreshing bool, isPulling bool,
-	searchQuery string, isRepoSelected bool, width int) string {
+	searchQuery string, isRepoSelected bool, watchedCommits int, width int) string {
 	if repo == nil {
 		return ""
 	}
 
 	// Background color for selection
-	bgColor := ""
-	if isSelected && isRepoSelected && isMultiSelect {
-		// Cursor on selected item - use distinct color
-		bgColor = "33" // Blue background for cursor on selected item
-	} else if isSelected {
-		// Cursor on unselected item
-		bgColor = "238"
-	} else if isRepoSelected && isMultiSelect {
-		// Selected item without cursor
-		bgColor = "240"
-	}
+	theme := r.styles.Theme
+	bgColor := r.rowBackground(isSelected, isRepoSelected, isMultiSelect)
 
 	// Get status components
 	status := r.getStatusIcon(repo, isFetching, isRefreshing, isPulling)
-	branchName := r.formatBranchName(repo.Status.Branch)
+	branchName := branchLabel(repo.Status, r.formatBranchName(repo.Status.Branch))
+	if r.glyphs.Branch != "" {
+		branchName = r.glyphs.Branch + " " + branchName
+	}
 
 	// Apply styles
 	statusStyle := r.getStatusStyle(repo, isFetching, isRefreshing)
+	if repo.Status.Stale {
+		// Cached status from a previous run; dim it until the refresh lands
+		statusStyle = statusStyle.Faint(true)
+	}
 	if bgColor != "" {
 		statusStyle = statusStyle.Background(lipgloss.Color(bgColor))
 	}
 
 	// Branch styling
-	branchColor := GetBranchColor(repo.Status.Branch)
-	branchStyle := lipgloss.NewStyle().Foreground(lipgloss.Color(branchColor))
-
-	// Make main/master branches bold
-	if repo.Status.Branch == "main" || repo.Status.Branch == "master" {
-		branchStyle = branchStyle.Bold(true)
-	}
-
-	// Apply background color if selected
-	if bgColor != "" {
-		branchStyle = branchStyle.Background(lipgloss.Color(bgColor))
-	}
-	coloredBranch := branchStyle.Render(branchName)
+	coloredBranch := r.branchStyle(repo.Status, bgColor).Render(branchName)
 
 	// Build the repository line
 	var parts []string
 
 	// Indentation
 	if indent > 0 {
-		indentText := strings.Repeat("  ", indent)
-		if bgColor != "" {
-			indentStyle := lipgloss.NewStyle().Background(lipgloss.Color(bgColor))
-			parts = append(parts, indentStyle.Render(indentText))
-		} else {
-			parts = append(parts, indentText)
-		}
+		parts = append(parts, r.indentation(indent, bgColor, gutterColor))
 	}
 
 	// No checkbox needed - we use background color to indicate selection
 
 	// Status icon
 	if status != "" {
-		parts = append(parts, statusStyle.Render(status))
+		parts = append(parts, statusStyle.Render(r.glyphs.PadStatus(status)))
 		if bgColor != "" {
 			spacerStyle := lipgloss.NewStyle().Background(lipgloss.Color(bgColor))
 			parts = append(parts, spacerStyle.Render(" "))
@@ -102,9 +98,12 @@ func (r *RepositoryRenderer) RenderRepository(repo *domain.Repository, isSelecte
 		repoName = repo.Name // Fallback to Name if DisplayName not set
 	}
 	nameStyle := lipgloss.NewStyle().Background(lipgloss.Color(bgColor))
+	if icon := r.glyphs.ProjectIcon(repo.Project); icon != "" {
+		parts = append(parts, nameStyle.Render(icon+" "))
+	}
 	if searchQuery != "" && strings.Contains(strings.ToLower(repoName), strings.ToLower(searchQuery)) {
 		highlightStyle := nameStyle
-		highlightStyle = highlightStyle.Foreground(lipgloss.Color("226"))
+		highlightStyle = highlightStyle.Foreground(lipgloss.Color(theme.Highlight))
 		repoName = r.highlightMatch(repoName, searchQuery, highlightStyle, nameStyle)
 	}
 	parts = append(parts, nameStyle.Render(repoName))
@@ -126,6 +125,17 @@ func (r *RepositoryRenderer) RenderRepository(repo *domain.Repository, isSelecte
 
 	parts = append(parts, parenStyle.Render(")"))
 
+	// Staged, modified, untracked and conflicted files and stashes, prompt style
+	if counts := r.changeCounts(repo.Status, bgColor); counts != "" {
+		parts = append(parts, parenStyle.Render(" "))
+		parts = append(parts, counts)
+	}
+
+	// Badges: in-progress operation, stale, last commit, cleanup stats, watches and tags
+	for _, badge := range r.badges(repo, watchedCommits, bgColor, r.showLastCommit) {
+		parts = append(parts, parenStyle.Render(" "), badge)
+	}
+
 	// Join the parts
 	line := strings.Join(parts, "")
 
@@ -143,25 +153,166 @@ func (r *RepositoryRenderer) RenderRepository(repo *domain.Repository, isSelecte
 	return line
 }
 
+// badges renders what follows the branch: an operation in progress, stale, the last
+// commit (when lastCommit), cleanup stats, new commits on watched branches and tags
+func (r *RepositoryRenderer) badges(repo *domain.Repository, watchedCommits int, bgColor string, lastCommit bool) []string {
+	theme := r.styles.Theme
+	var badges []string
+
+	// In-progress merge/rebase/bisect badge
+	if badge := OperationBadge(repo.Status.InProgress); badge != "" {
+		badgeStyle := r.styles.StatusError.Bold(true)
+		if bgColor != "" {
+			badgeStyle = badgeStyle.Background(lipgloss.Color(bgColor))
+		}
+		badges = append(badges, badgeStyle.Render(badge))
+	}
+
+	// Repositories that look abandoned ([stale])
+	if repo.Status.StaleReason != "" {
+		staleStyle := lipgloss.NewStyle().Foreground(lipgloss.Color(theme.Warning)).Faint(true)
+		if bgColor != "" {
+			staleStyle = staleStyle.Background(lipgloss.Color(bgColor))
+		}
+		badges = append(badges, staleStyle.Render("stale"))
+	}
+
+	// Age and author of the last commit (ui.show_last_commit)
+	if lastCommit && !repo.Status.LastCommitTime.IsZero() {
+		commitStyle := lipgloss.NewStyle().Faint(true)
+		if bgColor != "" {
+			commitStyle = commitStyle.Background(lipgloss.Color(bgColor))
+		}
+		text := CommitAge(repo.Status.LastCommitTime, time.Now())
+		if initials := AuthorInitials(repo.Status.LastCommitBy); initials != "" {
+			text += " " + initials
+		}
+		badges = append(badges, commitStyle.Render(text))
+	}
+
+	// Untracked/ignored stats (only populated when cleanup stats are enabled)
+	if stats := CleanupStatsText(repo.Status); stats != "" {
+		statsStyle := lipgloss.NewStyle().Faint(true)
+		if bgColor != "" {
+			statsStyle = statsStyle.Background(lipgloss.Color(bgColor))
+		}
+		badges = append(badges, statsStyle.Render("["+stats+"]"))
+	}
+
+	// New upstream commits on watched branches
+	if watchedCommits > 0 {
+		watchStyle := lipgloss.NewStyle().Foreground(lipgloss.Color(theme.Info)).Bold(true)
+		if bgColor != "" {
+			watchStyle = watchStyle.Background(lipgloss.Color(bgColor))
+		}
+		badges = append(badges, watchStyle.Render(fmt.Sprintf("[%d new]", watchedCommits)))
+	}
+
+	// Tag badges
+	if len(repo.Tags) > 0 {
+		tagStyle := lipgloss.NewStyle().Foreground(lipgloss.Color(theme.Muted))
+		if bgColor != "" {
+			tagStyle = tagStyle.Background(lipgloss.Color(bgColor))
+		}
+		badges = append(badges, tagStyle.Render(TagBadges(repo.Tags)))
+	}
+	return badges
+}
+
+// indentation renders a repository's indent; in a group with a color, the gutter marker
+// takes its first cell
+func (r *RepositoryRenderer) indentation(indent int, bgColor, gutterColor string) string {
+	indentText := strings.Repeat("  ", indent)
+	style := lipgloss.NewStyle()
+	if bgColor != "" {
+		style = style.Background(lipgloss.Color(bgColor))
+	}
+	if gutterColor == "" {
+		if bgColor == "" {
+			return indentText
+		}
+		return style.Render(indentText)
+	}
+	marker := style.Foreground(lipgloss.Color(gutterColor)).Render(r.glyphs.Gutter)
+	rest := max(0, len(indentText)-lipgloss.Width(r.glyphs.Gutter))
+	return marker + style.Render(strings.Repeat(" ", rest))
+}
+
+// rowBackground returns the background of a row under the cursor or selected ("" for none)
+func (r *RepositoryRenderer) rowBackground(isSelected, isRepoSelected, isMultiSelect bool) string {
+	theme := r.styles.Theme
+	if isSelected && isRepoSelected && isMultiSelect {
+		// Cursor on selected item - use distinct color
+		return theme.CursorSelected
+	} else if isSelected {
+		// Cursor on unselected item
+		return theme.Cursor
+	} else if isRepoSelected && isMultiSelect {
+		// Selected item without cursor
+		return theme.Selected
+	}
+	return ""
+}
+
+// branchStyle colors a branch by name, bold for main/master and faint while the status is
+// cached or there are no commits
+func (r *RepositoryRenderer) branchStyle(status domain.RepoStatus, bgColor string) lipgloss.Style {
+	branchColor := r.styles.Theme.BranchColor(status.Branch)
+	style := lipgloss.NewStyle().Foreground(lipgloss.Color(branchColor))
+
+	// Make main/master branches bold
+	if status.Branch == "main" || status.Branch == "master" {
+		style = style.Bold(true)
+	}
+	if status.Stale || status.NoCommits {
+		style = style.Faint(true)
+	}
+
+	// Apply background color if selected
+	if bgColor != "" {
+		style = style.Background(lipgloss.Color(bgColor))
+	}
+	return style
+}
+
+// branchLabel is what stands for the branch: "empty" without commits, the detached label
+// on a detached HEAD, else the branch name as given
+func branchLabel(status domain.RepoStatus, branch string) string {
+	if status.NoCommits {
+		return "empty"
+	} else if status.HeadCommit != "" {
+		return DetachedLabel(status)
+	}
+	return branch
+}
+
 // getStatusIcon returns the appropriate status icon for a repository
 func (r *RepositoryRenderer) getStatusIcon(repo *domain.Repository, isFetching, isRefreshing, isPulling bool) string {
 	if isFetching {
-		return "⟳"
+		return r.glyphs.Loading
 	}
 	if isRefreshing || isPulling {
-		return "⟳"
+		return r.glyphs.Loading
 	}
 	// Check for command errors (red danger sign)
 	if repo.HasError {
-		return "⚠"
+		return r.glyphs.Warning
 	}
 	if repo.Status.Error != "" {
-		return "✗"
+		return r.glyphs.Error
+	}
+	// Status not loaded yet (discovery placeholder branch)
+	if repo.Status.Branch == "⋯" {
+		return r.glyphs.Unknown
 	}
 	if repo.Status.IsDirty || repo.Status.HasUntracked {
-		return "●"
+		return r.glyphs.Dirty
 	}
-	return "✓"
+	// The working tree wasn't read ([status] mode = "branch-only")
+	if repo.Status.Mode == domain.StatusModeBranchOnly {
+		return r.glyphs.Unknown
+	}
+	return r.glyphs.Clean
 }
 
 // getStatusStyle returns the appropriate style for a repository status
@@ -200,15 +351,167 @@ func (r *RepositoryRenderer) formatBranchName(branch string) string {
 // getAheadBehindText formats ahead/behind counts
 func (r *RepositoryRenderer) getAheadBehindText(ahead, behind int) string {
 	if ahead > 0 && behind > 0 {
-		return fmt.Sprintf("↑%d ↓%d", ahead, behind)
+		return fmt.Sprintf("%s%d %s%d", r.glyphs.Ahead, ahead, r.glyphs.Behind, behind)
 	} else if ahead > 0 {
-		return fmt.Sprintf("↑%d", ahead)
+		return fmt.Sprintf("%s%d", r.glyphs.Ahead, ahead)
 	} else if behind > 0 {
-		return fmt.Sprintf("↓%d", behind)
+		return fmt.Sprintf("%s%d", r.glyphs.Behind, behind)
+	}
+	return ""
+}
+
+// changeCounts renders the change counts as "●3 ✚1 …2 ✖1 ⚑2", each in its own color and
+// left out when zero
+func (r *RepositoryRenderer) changeCounts(status domain.RepoStatus, bgColor string) string {
+	theme := r.styles.Theme
+	counts := []struct {
+		glyph string
+		n     int
+		color string
+	}{
+		{r.glyphs.Staged, status.Staged, theme.Success},
+		{r.glyphs.Modified, status.Modified, theme.Warning},
+		{r.glyphs.Untracked, status.Untracked, theme.Muted},
+		{r.glyphs.Conflicted, status.Conflicted, theme.Error},
+		{r.glyphs.Stashed, status.Stashes, theme.Info},
+	}
+	var parts []string
+	for _, c := range counts {
+		if c.n == 0 {
+			continue
+		}
+		style := lipgloss.NewStyle().Foreground(lipgloss.Color(c.color))
+		if bgColor != "" {
+			style = style.Background(lipgloss.Color(bgColor))
+		}
+		parts = append(parts, style.Render(fmt.Sprintf("%s%d", c.glyph, c.n)))
+	}
+	space := lipgloss.NewStyle().Background(lipgloss.Color(bgColor)).Render(" ")
+	return strings.Join(parts, space)
+}
+
+// ChangeCountsText describes the change counts in words ("3 staged, 1 modified, 2
+// stashes"; "" when there are none)
+func ChangeCountsText(status domain.RepoStatus) string {
+	var parts []string
+	add := func(n int, what string) {
+		if n > 0 {
+			parts = append(parts, fmt.Sprintf("%d %s", n, what))
+		}
+	}
+	add(status.Staged, "staged")
+	add(status.Modified, "modified")
+	add(status.Untracked, "untracked")
+	add(status.Conflicted, "conflicted")
+	if status.Stashes == 1 {
+		parts = append(parts, "1 stash")
+	} else {
+		add(status.Stashes, "stashes")
+	}
+	return strings.Join(parts, ", ")
+}
+
+// DetachedLabel describes a detached HEAD as "detached @ a1b2c3 · v1.4.2-5-g…": the commit and
+// its distance from the nearest tag, with describe's own hash elided since the commit is shown
+func DetachedLabel(status domain.RepoStatus) string {
+	label := "detached @ " + status.HeadCommit
+	if status.Describe == "" {
+		return label
+	}
+	return label + " · " + describeHashRE.ReplaceAllString(status.Describe, "$1-g…")
+}
+
+// describeHashRE matches the "-<commits>-g<hash>" suffix git describe adds past a tag
+var describeHashRE = regexp.MustCompile(`(-\d+)-g[0-9a-f]+$`)
+
+// OperationBadge returns the badge text for an in-progress git operation ("" if none)
+func OperationBadge(op domain.Operation) string {
+	switch op {
+	case domain.OperationMerge:
+		return "MERGING"
+	case domain.OperationRebase:
+		return "REBASING"
+	case domain.OperationBisect:
+		return "BISECTING"
+	case domain.OperationCherryPick:
+		return "CHERRY-PICKING"
+	case domain.OperationRevert:
+		return "REVERTING"
 	}
 	return ""
 }
 
+// CommitAge formats how long ago a commit was made in one compact unit ("5m", "3d", "2y")
+func CommitAge(t, now time.Time) string {
+	age := now.Sub(t)
+	switch {
+	case age < time.Minute:
+		return "now"
+	case age < time.Hour:
+		return fmt.Sprintf("%dm", int(age.Minutes()))
+	case age < 24*time.Hour:
+		return fmt.Sprintf("%dh", int(age.Hours()))
+	case age < 14*24*time.Hour:
+		return fmt.Sprintf("%dd", int(age.Hours()/24))
+	case age < 60*24*time.Hour:
+		return fmt.Sprintf("%dw", int(age.Hours()/(24*7)))
+	case age < 365*24*time.Hour:
+		return fmt.Sprintf("%dmo", int(age.Hours()/(24*30)))
+	}
+	return fmt.Sprintf("%dy", int(age.Hours()/(24*365)))
+}
+
+// AuthorInitials returns the uppercase initials of the first and last word of a name
+// ("Jane van Doe" is "JD")
+func AuthorInitials(name string) string {
+	words := strings.Fields(name)
+	if len(words) == 0 {
+		return ""
+	}
+	first := []rune(words[0])[0]
+	initials := string(unicode.ToUpper(first))
+	if len(words) > 1 {
+		last := []rune(words[len(words)-1])[0]
+		initials += string(unicode.ToUpper(last))
+	}
+	return initials
+}
+
+// TagBadges renders tags as "#rust #oncall"
+func TagBadges(tags []string) string {
+	badges := make([]string, len(tags))
+	for i, tag := range tags {
+		badges[i] = "#" + tag
+	}
+	return strings.Join(badges, " ")
+}
+
+// CleanupStatsText summarizes untracked files and ignored size ("" if there is nothing to report)
+func CleanupStatsText(status domain.RepoStatus) string {
+	var parts []string
+	if status.UntrackedFiles > 0 {
+		parts = append(parts, fmt.Sprintf("%d untracked", status.UntrackedFiles))
+	}
+	if status.IgnoredBytes > 0 {
+		parts = append(parts, FormatBytes(status.IgnoredBytes)+" ignored")
+	}
+	return strings.Join(parts, ", ")
+}
+
+// FormatBytes formats a byte count using binary units (e.g. "1.5 MB")
+func FormatBytes(n int64) string {
+	const unit = 1024
+	if n < unit {
+		return fmt.Sprintf("%d B", n)
+	}
+	div, exp := int64(unit), 0
+	for v := n / unit; v >= unit; v /= unit {
+		div *= unit
+		exp++
+	}
+	return fmt.Sprintf("%.1f %cB", float64(n)/float64(div), "KMGTPE"[exp])
+}
+
 // highlightMatch highlights matching text within a string
 func (r *RepositoryRenderer) highlightMatch(text, query string, highlightStyle, normalStyle lipgloss.Style) string {
 	lowerText := strings.ToLower(text)
@@ -0,0 +1,243 @@
+package views
+
+import (
+	"errors"
+	"fmt"
+	"regexp"
+	"strconv"
+	"strings"
+)
+
+// Theme holds the colors used by view rendering. Colors are ANSI 256 numbers
+// ("214") or hex values ("#ff8800").
+type Theme struct {
+	Title          string   // logo, status messages and popup titles
+	Accent         string   // scan progress
+	Heading        string   // help section headings
+	Key            string   // key names in help
+	Text           string   // descriptions in help
+	Muted          string   // secondary text such as scroll hints and loading icons
+	Border         string   // log popup border
+	PopupBorder    string   // info popup border
+	Faded          string   // content behind a popup
+	Error          string   // errors and detached HEAD
+	Warning        string   // dirty repositories, filters and fetching icons
+	Success        string   // clean repositories
+	Info           string   // refreshing icons
+	Highlight      string   // search matches
+	Cursor         string   // background of the line under the cursor
+	Selected       string   // background of selected lines
+	CursorSelected string   // background of a selected line under the cursor
+	MainBranch     string   // main/master
+	DevBranch      string   // develop/dev
+	BranchColors   []string // other branches, picked by a hash of the name
+}
+
+// DefaultThemeName is the palette used when none is configured
+const DefaultThemeName = "dark"
+
+// themePalettes are the built-in palettes selectable by name
+var themePalettes = map[string]Theme{
+	"dark": {
+		Title:          "99",
+		Accent:         "33",
+		Heading:        "39",
+		Key:            "220",
+		Text:           "252",
+		Muted:          "241",
+		Border:         "244",
+		PopupBorder:    "51",
+		Faded:          "245",
+		Error:          "203",
+		Warning:        "214",
+		Success:        "78",
+		Info:           "51",
+		Highlight:      "226",
+		Cursor:         "238",
+		Selected:       "240",
+		CursorSelected: "33",
+		MainBranch:     "78",
+		DevBranch:      "33",
+		BranchColors: []string{
+			"39", "41", "43", "45", "50", "51", "75", "84", "87", "99",
+			"111", "117", "120", "123", "135", "141", "147", "156", "159", "165",
+			"171", "177", "183", "189", "198", "201", "204", "207", "208", "209",
+			"213", "214", "219", "220", "221", "222", "225", "226", "227", "228",
+			"229",
+		},
+	},
+	"light": {
+		Title:          "91",
+		Accent:         "25",
+		Heading:        "25",
+		Key:            "130",
+		Text:           "236",
+		Muted:          "244",
+		Border:         "247",
+		PopupBorder:    "30",
+		Faded:          "249",
+		Error:          "160",
+		Warning:        "130",
+		Success:        "28",
+		Info:           "30",
+		Highlight:      "166",
+		Cursor:         "254",
+		Selected:       "252",
+		CursorSelected: "153",
+		MainBranch:     "28",
+		DevBranch:      "25",
+		BranchColors: []string{
+			"18", "19", "20", "22", "23", "24", "25", "26", "29", "30",
+			"31", "53", "54", "55", "57", "58", "88", "89", "90", "91",
+			"94", "125", "127", "129", "130", "131", "133", "136", "166", "172",
+		},
+	},
+	"solarized": {
+		Title:          "#6c71c4",
+		Accent:         "#268bd2",
+		Heading:        "#268bd2",
+		Key:            "#b58900",
+		Text:           "#93a1a1",
+		Muted:          "#586e75",
+		Border:         "#586e75",
+		PopupBorder:    "#2aa198",
+		Faded:          "#586e75",
+		Error:          "#dc322f",
+		Warning:        "#b58900",
+		Success:        "#859900",
+		Info:           "#2aa198",
+		Highlight:      "#cb4b16",
+		Cursor:         "#073642",
+		Selected:       "#0f4756",
+		CursorSelected: "#1c5f8a",
+		MainBranch:     "#859900",
+		DevBranch:      "#268bd2",
+		BranchColors: []string{
+			"#b58900", "#cb4b16", "#d33682", "#6c71c4", "#268bd2", "#2aa198", "#859900",
+		},
+	},
+}
+
+// ThemeNames lists the built-in palettes
+func ThemeNames() []string {
+	return []string{"dark", "light", "solarized"}
+}
+
+// DefaultTheme returns the dark palette, matching gitagrip's original colors
+func DefaultTheme() Theme {
+	return themePalettes[DefaultThemeName]
+}
+
+var hexColorRE = regexp.MustCompile(`^#([0-9a-fA-F]{3}|[0-9a-fA-F]{6})$`)
+
+// validColor reports whether c is an ANSI 256 color number or a hex color
+func validColor(c string) bool {
+	if hexColorRE.MatchString(c) {
+		return true
+	}
+	n, err := strconv.Atoi(c)
+	return err == nil && n >= 0 && n <= 255
+}
+
+// ResolveTheme returns the named palette with non-empty overrides applied on top.
+// An unknown palette or malformed color is reported as an error, together with
+// the default palette (with the valid overrides applied) so callers can carry on.
+func ResolveTheme(palette string, overrides Theme) (Theme, error) {
+	var errs []error
+
+	name := strings.ToLower(strings.TrimSpace(palette))
+	if name == "" {
+		name = DefaultThemeName
+	}
+	base, ok := themePalettes[name]
+	if !ok {
+		errs = append(errs, fmt.Errorf("theme: unknown palette %q (available: %s)", palette, strings.Join(ThemeNames(), ", ")))
+		base = DefaultTheme()
+	}
+	t := base
+	t.BranchColors = append([]string(nil), base.BranchColors...)
+
+	apply := func(element string, dst *string, src string) {
+		if src == "" {
+			return
+		}
+		if !validColor(src) {
+			errs = append(errs, fmt.Errorf("theme: invalid color %q for %s", src, element))
+			return
+		}
+		*dst = src
+	}
+	apply("title", &t.Title, overrides.Title)
+	apply("accent", &t.Accent, overrides.Accent)
+	apply("heading", &t.Heading, overrides.Heading)
+	apply("key", &t.Key, overrides.Key)
+	apply("text", &t.Text, overrides.Text)
+	apply("muted", &t.Muted, overrides.Muted)
+	apply("border", &t.Border, overrides.Border)
+	apply("popup_border", &t.PopupBorder, overrides.PopupBorder)
+	apply("faded", &t.Faded, overrides.Faded)
+	apply("error", &t.Error, overrides.Error)
+	apply("warning", &t.Warning, overrides.Warning)
+	apply("success", &t.Success, overrides.Success)
+	apply("info", &t.Info, overrides.Info)
+	apply("highlight", &t.Highlight, overrides.Highlight)
+	apply("cursor", &t.Cursor, overrides.Cursor)
+	apply("selected", &t.Selected, overrides.Selected)
+	apply("cursor_selected", &t.CursorSelected, overrides.CursorSelected)
+	apply("main_branch", &t.MainBranch, overrides.MainBranch)
+	apply("dev_branch", &t.DevBranch, overrides.DevBranch)
+
+	if len(overrides.BranchColors) > 0 {
+		var colors []string
+		for _, c := range overrides.BranchColors {
+			if !validColor(c) {
+				errs = append(errs, fmt.Errorf("theme: invalid color %q in branch_colors", c))
+				continue
+			}
+			colors = append(colors, c)
+		}
+		if len(colors) > 0 {
+			t.BranchColors = colors
+		}
+	}
+
+	return t, errors.Join(errs...)
+}
+
+// BranchColor returns the color for a git branch name
+func (t Theme) BranchColor(branchName string) string {
+	switch branchName {
+	case "main", "master":
+		return t.MainBranch // production branches
+	case "develop", "dev":
+		return t.DevBranch // development branches
+	default:
+		if branchName == "" || branchName == "HEAD" {
+			return t.Error // detached HEAD or error
+		}
+		// Compute a color based on branch name hash
+		return t.computeBranchColor(branchName)
+	}
+}
+
+// computeBranchColor picks a consistent color for a branch name from the palette
+func (t Theme) computeBranchColor(branchName string) string {
+	if len(t.BranchColors) == 0 {
+		return t.Text
+	}
+
+	// Simple hash: sum of character codes
+	hash := 0
+	for _, ch := range branchName {
+		hash += int(ch)
+		hash = hash * 17 // multiply by prime for better distribution
+	}
+
+	// Use modulo to pick a color
+	colorIndex := hash % len(t.BranchColors)
+	if colorIndex < 0 {
+		colorIndex = -colorIndex
+	}
+
+	return t.BranchColors[colorIndex]
+}
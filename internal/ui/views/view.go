@@ -7,9 +7,12 @@ import (
 
 	"github.com/charmbracelet/bubbles/v2/help"
 	"github.com/charmbracelet/lipgloss/v2"
+	"github.com/charmbracelet/x/ansi"
 
-	"gitagrip/internal/domain"
-	"gitagrip/internal/ui/input/modes"
+	"github.com/darksworm/gitagrip/internal/domain"
+	"github.com/darksworm/gitagrip/internal/ui/input/modes"
+	"github.com/darksworm/gitagrip/internal/ui/logic"
+	"github.com/darksworm/gitagrip/internal/ui/termcaps"
 )
 
 // ViewState contains all the state needed for rendering
@@ -39,37 +42,286 @@ type ViewState struct {
 	IsFiltered      bool
 	ShowAheadBehind bool
 	HelpModel       help.Model
-	DeleteTarget    string
 	TextInput       string
 	InputMode       string
 	UngroupedRepos  []string
 	SortOptionIndex int
+	SortLabel       string
 	LoadingState    string
 	LoadingCount    int
+	GroupColors     map[string]string
+	FetchProgress   Progress       // bulk fetch progress (zero when none is running)
+	PullProgress    Progress       // bulk pull progress (zero when none is running)
+	PushProgress    Progress       // bulk push progress (zero when none is running)
+	Visual          bool           // a visual range selection is active
+	VisualFrom      int            // first list index of the visual range
+	VisualTo        int            // last list index of the visual range
+	WatchedCommits  map[string]int // unacknowledged commits on watched branches per repo
+	QuickBar        []QuickAction  // actions pinned to the footer
+	ConfigDirty     bool           // group changes are not saved yet
+	ScanWarning     string         // why the last scan stopped short ("" when it didn't)
+	Toasts          []Toast        // brief notifications, oldest first
+	Confirm         string         // question of the confirmation dialog ("" when closed)
+	Rows            []ListRow      // list layout from BuildRows (built on demand when nil)
+	DetailsPane     string         // details shown beside the list on wide terminals ("" for the list alone)
+}
+
+// QuickAction is a quick bar entry: the digit that triggers it and what it does
+type QuickAction struct {
+	Key   string
+	Label string
+}
+
+// Toast is a brief notification shown in the top right corner
+type Toast struct {
+	Level string // domain.MessageInfo, MessageSuccess, MessageWarning or MessageError
+	Text  string
+}
+
+// Progress is the completion of a bulk operation across repositories
+type Progress struct {
+	Done  int
+	Total int
 }
 
 // Renderer handles all view rendering
 type Renderer struct {
 	styles      *Styles
+	glyphs      Glyphs
+	unicode     bool
+	columns     []string // ui.columns; none draws each repository as one line of text
 	repoRender  *RepositoryRenderer
 	groupRender *GroupRenderer
 	popupRender *PopupRenderer
 }
 
-// NewRenderer creates a new renderer
-func NewRenderer(showAheadBehind bool) *Renderer {
-	styles := NewStyles()
+// NewRenderer creates a new renderer using the given color theme
+func NewRenderer(showAheadBehind bool, theme Theme) *Renderer {
+	styles := NewStyles(theme)
 	return &Renderer{
 		styles:      styles,
+		glyphs:      DefaultGlyphs(),
+		unicode:     true,
 		repoRender:  NewRepositoryRenderer(styles, showAheadBehind),
 		groupRender: NewGroupRenderer(styles),
 		popupRender: NewPopupRenderer(styles),
 	}
 }
 
+// SetGlyphs configures the glyph set used for status indicators and group arrows
+func (r *Renderer) SetGlyphs(glyphs Glyphs) {
+	r.glyphs = glyphs
+	r.repoRender.SetGlyphs(glyphs)
+	r.groupRender.SetGlyphs(glyphs)
+}
+
+// SetShowLastCommit shows the age and author initials of each repository's last commit
+func (r *Renderer) SetShowLastCommit(show bool) {
+	r.repoRender.SetShowLastCommit(show)
+}
+
+// SetColumns lays the repositories out in the given columns (see ParseColumns), or as
+// one line of text when there are none
+func (r *Renderer) SetColumns(columns []string) {
+	r.columns = columns
+}
+
+// Glyphs returns the glyph set used for status indicators
+func (r *Renderer) Glyphs() Glyphs {
+	return r.glyphs
+}
+
+// Ellipsis returns what marks text cut to fit: "…", or "~" without Unicode
+func (r *Renderer) Ellipsis() string {
+	if !r.unicode {
+		return "~"
+	}
+	return "…"
+}
+
+// Theme returns the color theme used for rendering
+func (r *Renderer) Theme() Theme {
+	return r.styles.Theme
+}
+
+// SetTerminalCapabilities degrades styles that the terminal cannot render
+func (r *Renderer) SetTerminalCapabilities(caps termcaps.Capabilities) {
+	r.unicode = caps.Unicode
+	if !caps.Unicode {
+		r.styles.LogBox = r.styles.LogBox.Border(lipgloss.ASCIIBorder())
+		r.styles.InfoBox = r.styles.InfoBox.Border(lipgloss.ASCIIBorder())
+	}
+	if !caps.Italics {
+		r.styles.Scroll = r.styles.Scroll.Italic(false)
+	}
+}
+
+// progressGauge renders "done/total" followed by a small bar
+func (r *Renderer) progressGauge(p Progress) string {
+	const width = 10
+	filled, empty := "█", "░"
+	if !r.unicode {
+		filled, empty = "#", "-"
+	}
+	n := 0
+	if p.Total > 0 {
+		n = p.Done * width / p.Total
+	}
+	return fmt.Sprintf("%d/%d %s%s", p.Done, p.Total, strings.Repeat(filled, n), strings.Repeat(empty, width-n))
+}
+
+// Sparkline draws one bar per value, scaled to the largest one
+func (r *Renderer) Sparkline(values []float64) string {
+	levels := []rune("▁▂▃▄▅▆▇█")
+	if !r.unicode {
+		levels = []rune("_.-=+*#")
+	}
+	highest := 0.0
+	for _, v := range values {
+		highest = max(highest, v)
+	}
+	var b strings.Builder
+	for _, v := range values {
+		i := 0
+		if highest > 0 {
+			i = int(v / highest * float64(len(levels)-1))
+		}
+		b.WriteRune(levels[i])
+	}
+	return b.String()
+}
+
+// HeatCell draws one day of an activity heatmap, shaded by its share of the busiest day
+func (r *Renderer) HeatCell(count, highest int) string {
+	levels := []string{"·", "░", "▒", "▓", "█"}
+	if !r.unicode {
+		levels = []string{".", ":", "+", "*", "#"}
+	}
+	if count == 0 || highest == 0 {
+		return r.styles.Dim.Render(levels[0])
+	}
+	i := 1 + (count-1)*(len(levels)-1)/highest
+	return lipgloss.NewStyle().Foreground(lipgloss.Color(r.styles.Theme.Success)).Render(levels[min(i, len(levels)-1)])
+}
+
 // Render produces the complete view
 func (r *Renderer) Render(state ViewState) string {
 	content := &strings.Builder{}
+	content.WriteString(r.renderHeader(state))
+
+	// Main content
+	mainContent := ""
+	if state.Scanning && len(state.Repositories) == 0 {
+		// Don't show duplicate scanning message - it's already in the title
+		mainContent = r.styles.Dim.Render("Looking for repositories...")
+	} else if len(state.Repositories) == 0 {
+		mainContent = r.styles.Dim.Render("No repositories found. Press F for full scan.")
+	} else if state.DetailsPane != "" {
+		mainContent = r.renderTwoPane(state)
+	} else {
+		mainContent = r.renderRepositoryList(state)
+	}
+
+	// Add main content
+	content.WriteString(mainContent)
+
+	// Calculate help text (shown at bottom when no popups are visible)
+	helpText := ""
+	if !state.ShowLog && !state.ShowInfo {
+		if hints := footerHints(state.InputMode); hints != nil {
+			helpText = r.renderQuickBar(hints)
+		} else {
+			helpText = r.renderQuickBar(state.QuickBar)
+		}
+	}
+
+	// If we have help text, add padding to push it to the bottom
+	if helpText != "" {
+		// Count current lines
+		currentContent := content.String()
+		currentLines := strings.Count(currentContent, "\n") + 1
+
+		// Account for container padding (1 top, 1 bottom from Padding(1, 2))
+		availableLines := state.Height - 2
+		if availableLines <= 0 {
+			availableLines = 22 // Default terminal height minus padding
+		}
+
+		// Help takes 1 line
+		helpLines := 1
+
+		// Calculate padding needed
+		paddingNeeded := availableLines - currentLines - helpLines
+
+		// Add padding
+		if paddingNeeded > 0 {
+			content.WriteString(strings.Repeat("\n", paddingNeeded))
+		}
+
+		// Add help
+		content.WriteString("\n")
+		content.WriteString(helpText)
+	}
+
+	// Apply main container style
+	mainStyle := r.styles.Main.MaxHeight(state.Height)
+	finalContent := mainStyle.Render(content.String())
+
+	// Overlay popups on top of main content, and notifications on top of everything
+	if state.ShowLog && state.LogContent != "" {
+		finalContent = r.popupRender.RenderPopupOverlay(finalContent, state.LogContent, state.Height, state.Width, r.styles.LogBox)
+	} else if state.ShowInfo && state.InfoContent != "" {
+		finalContent = r.popupRender.RenderPopupOverlay(finalContent, state.InfoContent, state.Height, state.Width, r.styles.InfoBox)
+	}
+	if state.Confirm != "" {
+		// The confirmation dialog goes over any popup, whatever it shows
+		dialog := r.styles.Confirm.Render(state.Confirm) + "\n\n" + r.styles.Help.Render("y confirm • n/esc cancel")
+		finalContent = r.popupRender.RenderPopupOverlay(finalContent, dialog, state.Height, state.Width, r.styles.ConfirmBox)
+	}
+
+	return r.renderToasts(finalContent, state.Toasts, state.Width)
+}
+
+// renderToasts stacks notifications in the top right corner, newest at the bottom, each in
+// a box colored by its level
+func (r *Renderer) renderToasts(content string, toasts []Toast, width int) string {
+	if len(toasts) == 0 {
+		return content
+	}
+	lines := make([]string, 0, len(toasts))
+	for _, toast := range toasts {
+		color, icon := r.styles.Theme.Info, "ℹ"
+		switch toast.Level {
+		case domain.MessageSuccess:
+			color, icon = r.styles.Theme.Success, "✓"
+		case domain.MessageWarning:
+			color, icon = r.styles.Theme.Warning, "!"
+		case domain.MessageError:
+			color, icon = r.styles.Theme.Error, "✗"
+		}
+		if !r.unicode {
+			icon = map[string]string{"ℹ": "i", "✓": "+", "!": "!", "✗": "x"}[icon]
+		}
+		body := lipgloss.NewStyle().Foreground(lipgloss.Color(color)).Render(icon) + " " + toast.Text
+		box := lipgloss.NewStyle().
+			Border(lipgloss.RoundedBorder()).
+			BorderForeground(lipgloss.Color(color)).
+			Padding(0, 1)
+		// Long messages wrap instead of covering the whole list
+		if limit := max(10, min(60, width/2)); lipgloss.Width(body) > limit {
+			box = box.Width(limit)
+		}
+		lines = append(lines, box.Render(body))
+	}
+	stack := lipgloss.JoinVertical(lipgloss.Right, lines...)
+	x := max(0, width-lipgloss.Width(stack)-1)
+	canvas := lipgloss.NewCanvas(lipgloss.NewLayer(content), lipgloss.NewLayer(stack).X(x).Y(1).Z(2))
+	return canvas.Render()
+}
+
+// renderHeader renders the title line and any active prompt above the repository list
+func (r *Renderer) renderHeader(state ViewState) string {
+	header := &strings.Builder{}
 
 	// Title with loading indicator
 	logo := r.styles.Title.Render("gitagrip")
@@ -79,25 +331,36 @@ func (r *Renderer) Render(state ViewState) string {
 
 	if state.Scanning {
 		spinner := []string{"⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"}
+		if !r.unicode {
+			spinner = []string{"|", "/", "-", "\\"}
+		}
 		frame := int(time.Now().UnixMilli()/80) % len(spinner)
 		loadingIndicators = append(loadingIndicators, fmt.Sprintf("%s Scanning", spinner[frame]))
 	}
 
 	if len(state.RefreshingRepos) > 0 {
-		loadingIndicators = append(loadingIndicators, fmt.Sprintf("↻ Refreshing %d", len(state.RefreshingRepos)))
+		loadingIndicators = append(loadingIndicators, fmt.Sprintf("%s Refreshing %d", r.glyphs.Loading, len(state.RefreshingRepos)))
 	}
 
-	if len(state.FetchingRepos) > 0 {
-		loadingIndicators = append(loadingIndicators, fmt.Sprintf("↓ Fetching %d", len(state.FetchingRepos)))
+	if state.FetchProgress.Total > 1 {
+		loadingIndicators = append(loadingIndicators, fmt.Sprintf("%s Fetching %s", r.glyphs.Behind, r.progressGauge(state.FetchProgress)))
+	} else if len(state.FetchingRepos) > 0 {
+		loadingIndicators = append(loadingIndicators, fmt.Sprintf("%s Fetching %d", r.glyphs.Behind, len(state.FetchingRepos)))
 	}
 
-	if len(state.PullingRepos) > 0 {
-		loadingIndicators = append(loadingIndicators, fmt.Sprintf("↓ Pulling %d", len(state.PullingRepos)))
+	if state.PullProgress.Total > 1 {
+		loadingIndicators = append(loadingIndicators, fmt.Sprintf("%s Pulling %s", r.glyphs.Behind, r.progressGauge(state.PullProgress)))
+	} else if len(state.PullingRepos) > 0 {
+		loadingIndicators = append(loadingIndicators, fmt.Sprintf("%s Pulling %d", r.glyphs.Behind, len(state.PullingRepos)))
+	}
+
+	if state.PushProgress.Total > 0 {
+		loadingIndicators = append(loadingIndicators, fmt.Sprintf("%s Pushing %s", r.glyphs.Ahead, r.progressGauge(state.PushProgress)))
 	}
 
 	// Build the title line with right-aligned indicators
 	var titleLine string
-	if len(loadingIndicators) > 0 || state.FilterQuery != "" || state.StatusMessage != "" {
+	if len(loadingIndicators) > 0 || state.SortLabel != "" || state.FilterQuery != "" || state.StatusMessage != "" || state.ConfigDirty {
 		// Calculate widths
 		logoWidth := lipgloss.Width(logo)
 
@@ -106,6 +369,14 @@ func (r *Renderer) Render(state ViewState) string {
 		if len(loadingIndicators) > 0 {
 			rightContent = r.styles.Dim.Render(strings.Join(loadingIndicators, " | "))
 		}
+		if state.SortLabel != "" {
+			sortText := r.styles.Dim.Render(fmt.Sprintf("[Sort: %s]", state.SortLabel))
+			if rightContent != "" {
+				rightContent = fmt.Sprintf("%s  %s", rightContent, sortText)
+			} else {
+				rightContent = sortText
+			}
+		}
 		if state.FilterQuery != "" {
 			filterText := r.styles.Filter.Render(fmt.Sprintf("[Filter: %s]", state.FilterQuery))
 			if rightContent != "" {
@@ -114,6 +385,14 @@ func (r *Renderer) Render(state ViewState) string {
 				rightContent = filterText
 			}
 		}
+		if state.ConfigDirty {
+			unsavedText := r.styles.Filter.Render("[Unsaved]")
+			if rightContent != "" {
+				rightContent = fmt.Sprintf("%s  %s", rightContent, unsavedText)
+			} else {
+				rightContent = unsavedText
+			}
+		}
 		// (Error icon moved to per-repo rows; avoid heavy global counting during render)
 		if state.StatusMessage != "" {
 			statusText := r.styles.Title.Render(fmt.Sprintf("💬 %s", state.StatusMessage))
@@ -145,216 +424,218 @@ func (r *Renderer) Render(state ViewState) string {
 		titleLine = logo
 	}
 
-	content.WriteString(titleLine)
-	content.WriteString("\n")
+	header.WriteString(titleLine)
+	header.WriteString("\n")
 
-	// Delete confirmation
-	if state.DeleteTarget != "" {
-		content.WriteString(r.styles.Confirm.Render(fmt.Sprintf("Delete group '%s'? (y/n): ", state.DeleteTarget)))
-		content.WriteString("\n")
-	} else if state.InputMode != "" {
+	// A scan cut short by its limits, cut to one line
+	if state.ScanWarning != "" {
+		warning := r.glyphs.Warning + " " + state.ScanWarning
+		if state.Width > 4 {
+			warning = ansi.Truncate(warning, state.Width-4, "")
+		}
+		header.WriteString(r.styles.StatusWarning.Render(warning))
+		header.WriteString("\n")
+	}
+
+	// Prompt in progress (the confirmation dialog is drawn over the list instead)
+	if state.InputMode != "" && state.InputMode != "confirm" {
 		if state.InputMode == "sort" {
-			content.WriteString(r.renderSortOptions(state))
+			header.WriteString(r.renderSortOptions(state))
 		} else if state.InputMode == "filter" {
-			content.WriteString("Filter: ")
-			content.WriteString(state.TextInput)
+			header.WriteString("Filter: ")
+			header.WriteString(state.TextInput)
 		} else if state.InputMode == "search" {
-			content.WriteString("Search: ")
-			content.WriteString(state.TextInput)
+			header.WriteString("Search: ")
+			header.WriteString(state.TextInput)
 		} else if state.InputMode == "new-branch" {
-			content.WriteString("New branch name: ")
-			content.WriteString(state.TextInput)
+			header.WriteString("New branch name: ")
+			header.WriteString(state.TextInput)
 		} else if state.InputMode == "switch-branch" {
-			content.WriteString("Switch to branch: ")
-			content.WriteString(state.TextInput)
+			header.WriteString("Switch to branch: ")
+			header.WriteString(state.TextInput)
 		} else {
-			content.WriteString(state.TextInput)
+			header.WriteString(state.TextInput)
 		}
-		content.WriteString("\n")
-		content.WriteString("\n")
+		header.WriteString("\n")
+		header.WriteString("\n")
 	}
 
-	// Main content
-	mainContent := ""
-	if state.Scanning && len(state.Repositories) == 0 {
-		// Don't show duplicate scanning message - it's already in the title
-		mainContent = r.styles.Dim.Render("Looking for repositories...")
-	} else if len(state.Repositories) == 0 {
-		mainContent = r.styles.Dim.Render("No repositories found. Press F for full scan.")
-	} else {
-		mainContent = r.renderRepositoryList(state)
-	}
-
-	// Add main content
-	content.WriteString(mainContent)
-
-	// Calculate help text (shown at bottom when no popups are visible)
-	helpText := ""
-	if !state.ShowLog && !state.ShowInfo {
-		helpText = r.styles.Help.Render("Press ? for help")
-	}
-
-	// If we have help text, add padding to push it to the bottom
-	if helpText != "" {
-		// Count current lines
-		currentContent := content.String()
-		currentLines := strings.Count(currentContent, "\n") + 1
-
-		// Account for container padding (1 top, 1 bottom from Padding(1, 2))
-		availableLines := state.Height - 2
-		if availableLines <= 0 {
-			availableLines = 22 // Default terminal height minus padding
-		}
-
-		// Help takes 1 line
-		helpLines := 1
-
-		// Calculate padding needed
-		paddingNeeded := availableLines - currentLines - helpLines
-
-		// Add padding
-		if paddingNeeded > 0 {
-			content.WriteString(strings.Repeat("\n", paddingNeeded))
-		}
-
-		// Add help
-		content.WriteString("\n")
-		content.WriteString(helpText)
-	}
+	return header.String()
+}
 
-	// Apply main container style
-	mainStyle := r.styles.Main.MaxHeight(state.Height)
-	finalContent := mainStyle.Render(content.String())
+// ListHeight returns how many rows the repository list gets: the terminal's height minus
+// the container padding, the header (taller while a prompt is open) and the footer
+func (r *Renderer) ListHeight(state ViewState) int {
+	return max(1, state.Height-2-strings.Count(r.renderHeader(state), "\n")-1)
+}
 
-	// Overlay popups on top of main content
-	if state.ShowLog && state.LogContent != "" {
-		return r.popupRender.RenderPopupOverlay(finalContent, state.LogContent, state.Height, state.Width, r.styles.LogBox)
+// ListRowIndex maps a screen row to the list index rendered on it, for mouse
+// handling. ok is false for rows outside the list, such as the header, the
+// scroll indicators and the padding around the view.
+func (r *Renderer) ListRowIndex(state ViewState, y int) (index int, ok bool) {
+	// One row of container padding, then the header
+	row := y - 1 - strings.Count(r.renderHeader(state), "\n")
+	if state.ViewportOffset > 0 {
+		row-- // "more above" indicator
 	}
-
-	if state.ShowInfo && state.InfoContent != "" {
-		return r.popupRender.RenderPopupOverlay(finalContent, state.InfoContent, state.Height, state.Width, r.styles.InfoBox)
+	if row < 0 || row >= state.ViewportHeight {
+		return 0, false
 	}
-
-	return finalContent
+	return state.ViewportOffset + row, true
 }
 
-// renderRepositoryList renders the list of repositories with groups
-func (r *Renderer) renderRepositoryList(state ViewState) string {
-	var lines []string
-	currentIndex := 0
-
-	// Track which items are visible
-	visibleLines := make([]string, 0)
+// ListRow is one row of the repository list: a group header, a repository or the gap
+// after a group
+type ListRow struct {
+	Header    bool   // group header
+	Group     string // group of the header or repository ("" for ungrouped repositories and gaps)
+	RepoPath  string // "" for group headers and gaps
+	Indent    int    // repository indent; worktrees sit beneath their main repository
+	RepoCount int    // repositories a group header counts
+	Total     int    // repositories of the group, the filter aside
+	Selected  bool   // every repository a group header counts is selected
+}
 
-	// Groups first
-	for _, groupName := range state.OrderedGroups {
+// BuildRows lays out the whole list: groups in order with their repositories when
+// expanded, then the ungrouped repositories. It is only needed again when repositories,
+// groups, the filter or the selection change, not for every frame.
+func (r *Renderer) BuildRows(state ViewState) []ListRow {
+	var rows []ListRow
+	for i, groupName := range state.OrderedGroups {
 		group := state.Groups[groupName]
-		isSelected := currentIndex == state.SelectedIndex
 		isExpanded := state.ExpandedGroups[groupName]
 
-		// Check if group is in viewport
-		if currentIndex >= state.ViewportOffset {
-			repoCount := 0
-			allReposSelected := true
-			hasSelectedRepos := false
-
-			if isExpanded {
-				// Count visible repos in group and check selection
-				for _, repoPath := range group.Repos {
-					if repo, ok := state.Repositories[repoPath]; ok {
-						if r.matchesFilter(repo, groupName, state.FilterQuery) {
-							repoCount++
-							if state.SelectedRepos[repoPath] {
-								hasSelectedRepos = true
-							} else {
-								allReposSelected = false
-							}
+		header := ListRow{Header: true, Group: groupName}
+		allReposSelected := true
+		hasSelectedRepos := false
+		if isExpanded {
+			// Count visible repos in group and check selection
+			for _, repoPath := range group.Repos {
+				if repo, ok := state.Repositories[repoPath]; ok {
+					if r.matchesFilter(repo, groupName, state.FilterQuery) {
+						header.RepoCount++
+						if state.SelectedRepos[repoPath] {
+							hasSelectedRepos = true
+						} else {
+							allReposSelected = false
 						}
 					}
 				}
-			} else {
-				// For collapsed groups, check all repos
-				repoCount = len(group.Repos)
-				for _, repoPath := range group.Repos {
-					if state.SelectedRepos[repoPath] {
-						hasSelectedRepos = true
-					} else {
-						allReposSelected = false
+			}
+		} else {
+			// For collapsed groups, check all repos
+			header.RepoCount = len(group.Repos)
+			for _, repoPath := range group.Repos {
+				if state.SelectedRepos[repoPath] {
+					hasSelectedRepos = true
+				} else {
+					allReposSelected = false
+				}
+			}
+		}
+		// A filter keeps the group's size next to the repositories that match
+		header.Total = header.RepoCount
+		if state.IsFiltered {
+			header.RepoCount, header.Total = 0, 0
+			for _, repoPath := range group.Repos {
+				if repo, ok := state.Repositories[repoPath]; ok {
+					header.Total++
+					if r.matchesFilter(repo, groupName, state.FilterQuery) {
+						header.RepoCount++
 					}
 				}
 			}
-
-			// Only highlight if there are repos and all are selected
-			groupIsFullySelected := repoCount > 0 && allReposSelected && hasSelectedRepos
-
-			header := r.groupRender.RenderGroupHeader(group, isExpanded, isSelected, state.SearchQuery, repoCount, state.Width, groupIsFullySelected)
-			visibleLines = append(visibleLines, header)
 		}
-		currentIndex++
+		// Only highlight if there are repos and all are selected
+		header.Selected = header.RepoCount > 0 && allReposSelected && hasSelectedRepos
+		rows = append(rows, header)
 
-		// Render repos in group if expanded
 		if isExpanded {
+			inGroup := pathSet(group.Repos)
 			for _, repoPath := range group.Repos {
 				repo, ok := state.Repositories[repoPath]
 				if !ok || (state.IsFiltered && !r.matchesFilter(repo, groupName, state.FilterQuery)) {
 					continue
 				}
-
-				isRepoSelected := currentIndex == state.SelectedIndex
-				if currentIndex >= state.ViewportOffset {
-					line := r.repoRender.RenderRepository(
-						repo, isRepoSelected, 1,
-						len(state.SelectedRepos) > 0,
-						state.FetchingRepos[repoPath],
-						state.RefreshingRepos[repoPath],
-						state.PullingRepos[repoPath],
-						state.SearchQuery,
-						state.SelectedRepos[repoPath],
-						state.Width,
-					)
-					visibleLines = append(visibleLines, line)
+				indent := 1
+				if logic.IsNestedWorktree(repoPath, state.Repositories, inGroup) {
+					indent++
 				}
-				currentIndex++
+				rows = append(rows, ListRow{Group: groupName, RepoPath: repoPath, Indent: indent})
 			}
 		}
 
 		// Add gap after group (except for hidden group at the end)
-		if groupName != "_Hidden" || currentIndex < state.SelectedIndex {
-			if currentIndex >= state.ViewportOffset && len(visibleLines) > 0 {
-				visibleLines = append(visibleLines, "") // Empty line for gap
-			}
-			currentIndex++ // Count the gap in index
+		if groupName != "_Hidden" || i != len(state.OrderedGroups)-1 {
+			rows = append(rows, ListRow{})
 		}
 	}
 
-	// Ungrouped repos
+	ungrouped := pathSet(state.UngroupedRepos)
 	for _, repoPath := range state.UngroupedRepos {
 		repo, ok := state.Repositories[repoPath]
 		if !ok || (state.IsFiltered && !r.matchesFilter(repo, "", state.FilterQuery)) {
 			continue
 		}
-
-		isRepoSelected := currentIndex == state.SelectedIndex
-		if currentIndex >= state.ViewportOffset {
-			line := r.repoRender.RenderRepository(
-				repo, isRepoSelected, 0,
-				len(state.SelectedRepos) > 0,
-				state.FetchingRepos[repoPath],
-				state.RefreshingRepos[repoPath],
-				state.PullingRepos[repoPath],
-				state.SearchQuery,
-				state.SelectedRepos[repoPath],
-				state.Width,
-			)
-			visibleLines = append(visibleLines, line)
+		indent := 0
+		if logic.IsNestedWorktree(repoPath, state.Repositories, ungrouped) {
+			indent++
 		}
-		currentIndex++
+		rows = append(rows, ListRow{RepoPath: repoPath, Indent: indent})
+	}
+	return rows
+}
+
+// renderRow draws a single list row, in columns when layout is set
+func (r *Renderer) renderRow(state ViewState, row ListRow, index int, layout *ColumnLayout) string {
+	switch {
+	case row.Header:
+		return r.groupRender.RenderGroupHeader(state.Groups[row.Group], state.ExpandedGroups[row.Group], index == state.SelectedIndex,
+			state.SearchQuery, row.RepoCount, row.Total, state.Width, row.Selected, state.GroupColors[row.Group])
+	case row.RepoPath != "" && layout != nil:
+		return r.repoRender.RenderRepositoryColumns(
+			state.Repositories[row.RepoPath], index == state.SelectedIndex, row.Indent, state.GroupColors[row.Group],
+			len(state.SelectedRepos) > 0 || state.Visual,
+			state.FetchingRepos[row.RepoPath],
+			state.RefreshingRepos[row.RepoPath],
+			state.PullingRepos[row.RepoPath],
+			state.SearchQuery,
+			state.SelectedRepos[row.RepoPath] || inVisualRange(state, index),
+			state.WatchedCommits[row.RepoPath],
+			state.Width,
+			*layout,
+		)
+	case row.RepoPath != "":
+		return r.repoRender.RenderRepository(
+			state.Repositories[row.RepoPath], index == state.SelectedIndex, row.Indent, state.GroupColors[row.Group],
+			len(state.SelectedRepos) > 0 || state.Visual,
+			state.FetchingRepos[row.RepoPath],
+			state.RefreshingRepos[row.RepoPath],
+			state.PullingRepos[row.RepoPath],
+			state.SearchQuery,
+			state.SelectedRepos[row.RepoPath] || inVisualRange(state, index),
+			state.WatchedCommits[row.RepoPath],
+			state.Width,
+		)
+	default:
+		return "" // gap between groups
 	}
+}
+
+// renderRepositoryList renders the rows of the list that fit in the viewport, with
+// indicators for the rows scrolled out of view
+func (r *Renderer) renderRepositoryList(state ViewState) string {
+	rows := state.Rows
+	if rows == nil {
+		rows = r.BuildRows(state)
+	}
+	totalItems := len(rows)
+	var lines []string
 
 	// Calculate effective height
 	effectiveHeight := state.ViewportHeight
 	needsTopIndicator := state.ViewportOffset > 0
-	needsBottomIndicator := len(visibleLines) > effectiveHeight || currentIndex > state.ViewportOffset+state.ViewportHeight
+	needsBottomIndicator := totalItems > state.ViewportOffset+state.ViewportHeight
 
 	if needsTopIndicator {
 		effectiveHeight--
@@ -365,29 +646,84 @@ func (r *Renderer) renderRepositoryList(state ViewState) string {
 
 	// Add scroll indicators
 	if needsTopIndicator {
-		lines = append(lines, r.styles.Scroll.Render(fmt.Sprintf("↑ %d more above ↑", state.ViewportOffset)))
+		lines = append(lines, r.styles.Scroll.Render(fmt.Sprintf("%s %d more above %s", r.glyphs.Ahead, state.ViewportOffset, r.glyphs.Ahead)))
 	}
 
-	// Add visible lines (up to effective height)
-	for i := 0; i < effectiveHeight && i < len(visibleLines); i++ {
-		lines = append(lines, visibleLines[i])
+	// Columns are as wide as their widest cell in the whole list, so they stay put while scrolling
+	var layout *ColumnLayout
+	if len(r.columns) > 0 {
+		l := r.layoutColumns(state, rows)
+		layout = &l
+	}
+
+	// Only the rows in view are drawn
+	for i := state.ViewportOffset; i < state.ViewportOffset+effectiveHeight && i < totalItems; i++ {
+		lines = append(lines, r.renderRow(state, rows[i], i, layout))
 	}
 
 	// Add bottom scroll indicator
 	if needsBottomIndicator {
-		// Calculate how many items are below the current viewport
-		// currentIndex is the total number of items
-		// state.ViewportOffset + effectiveHeight is what we're showing
-		itemsBelow := currentIndex - (state.ViewportOffset + effectiveHeight)
-		if itemsBelow < 0 {
-			itemsBelow = 0
-		}
-		lines = append(lines, r.styles.Scroll.Render(fmt.Sprintf("↓ %d more below ↓", itemsBelow)))
+		itemsBelow := max(0, totalItems-(state.ViewportOffset+effectiveHeight))
+		lines = append(lines, r.styles.Scroll.Render(fmt.Sprintf("%s %d more below %s", r.glyphs.Behind, itemsBelow, r.glyphs.Behind)))
 	}
 
+	if needsTopIndicator || needsBottomIndicator {
+		lines = r.addScrollbar(lines, state, totalItems)
+	}
 	return strings.Join(lines, "\n")
 }
 
+// addScrollbar draws a scrollbar along the right edge of the list rows: the thumb's size
+// is the share of the items that fit, its position how far the list is scrolled
+func (r *Renderer) addScrollbar(lines []string, state ViewState, totalItems int) []string {
+	height := state.ViewportHeight
+	width := state.Width - 4 - 2 // container padding, then a space before the bar
+	if width < 10 || height < 3 || totalItems <= height {
+		return lines
+	}
+	for len(lines) < height {
+		lines = append(lines, "")
+	}
+
+	thumb := max(1, height*height/totalItems)
+	top := 0
+	if maxOffset := totalItems - height; maxOffset > 0 {
+		top = min(height-thumb, (min(state.ViewportOffset, maxOffset)*(height-thumb)+maxOffset/2)/maxOffset)
+	}
+
+	track, bar := "│", "┃"
+	if !r.unicode {
+		track, bar = "|", "#"
+	}
+	clip := lipgloss.NewStyle().MaxWidth(width)
+	for i := range lines {
+		line := clip.Render(lines[i])
+		if pad := width - lipgloss.Width(line); pad > 0 {
+			line += strings.Repeat(" ", pad)
+		}
+		glyph := r.styles.Dim.Render(track)
+		if i >= top && i < top+thumb {
+			glyph = r.styles.Scroll.Render(bar)
+		}
+		lines[i] = line + " " + glyph
+	}
+	return lines
+}
+
+// inVisualRange reports whether a list index lies in the active visual range
+func inVisualRange(state ViewState, index int) bool {
+	return state.Visual && index >= state.VisualFrom && index <= state.VisualTo
+}
+
+// pathSet builds a lookup set from a list of repository paths
+func pathSet(paths []string) map[string]bool {
+	set := make(map[string]bool, len(paths))
+	for _, path := range paths {
+		set[path] = true
+	}
+	return set
+}
+
 // matchesFilter checks if a repo matches the filter (simplified for now)
 func (r *Renderer) matchesFilter(repo *domain.Repository, groupName string, filterQuery string) bool {
 	if filterQuery == "" {
@@ -396,12 +732,16 @@ func (r *Renderer) matchesFilter(repo *domain.Repository, groupName string, filt
 
 	query := strings.ToLower(filterQuery)
 
-	// Check if it's a status filter
-	if strings.HasPrefix(query, "status:") {
-		statusFilter := strings.TrimPrefix(query, "status:")
+	// Check if it's a status filter (status:dirty or is:dirty)
+	if statusFilter, ok := logic.CutStatusPrefix(query); ok {
 		return r.matchesStatusFilter(repo, statusFilter)
 	}
 
+	// Check if it's a tag filter
+	if tag, ok := strings.CutPrefix(query, "tag:"); ok {
+		return repo.HasTag(tag)
+	}
+
 	// Regular filter
 	return strings.Contains(strings.ToLower(repo.Name), query) ||
 		strings.Contains(strings.ToLower(repo.Path), query) ||
@@ -424,14 +764,37 @@ func (r *Renderer) matchesStatusFilter(repo *domain.Repository, filter string) b
 		return repo.Status.BehindCount > 0
 	case "diverged":
 		return repo.Status.AheadCount > 0 && repo.Status.BehindCount > 0
+	case "unsynced":
+		return repo.Status.AheadCount > 0 || repo.Status.BehindCount > 0
 	case "error":
 		return repo.Status.Error != ""
+	case "stale":
+		return repo.Status.StaleReason != ""
 	default:
 		// Check if it's a branch name
 		return strings.Contains(strings.ToLower(repo.Status.Branch), filter)
 	}
 }
 
+// footerHints lists the keys of the prompt or range selection in progress, shown in the
+// footer instead of the quick bar (nil in normal mode)
+func footerHints(inputMode string) []QuickAction {
+	switch inputMode {
+	case "":
+		return nil
+	case "visual":
+		return []QuickAction{{"j/k", "extend"}, {"space", "select"}, {"m", "move to group"}, {"esc", "cancel"}}
+	case "confirm", "clean-confirm", "cancel-confirm", "archive-confirm":
+		return []QuickAction{{"y", "confirm"}, {"n", "cancel"}}
+	case "new-group":
+		return []QuickAction{{"enter", "create"}, {"tab", "template"}, {"esc", "cancel"}}
+	case "search":
+		return []QuickAction{{"enter", "jump to first match"}, {"esc", "cancel"}}
+	default:
+		return []QuickAction{{"enter", "apply"}, {"esc", "cancel"}}
+	}
+}
+
 // renderSortOptions renders the sort mode selection interface
 func (r *Renderer) renderSortOptions(state ViewState) string {
 	// Show only the current sort option
@@ -444,95 +807,14 @@ func (r *Renderer) renderSortOptions(state ViewState) string {
 	return ""
 }
 
-// RenderHelpContentPlain generates help content with colors for pager
-func (r *Renderer) RenderHelpContentPlain() string {
-	titleStyle := lipgloss.NewStyle().
-		Bold(true).
-		Foreground(lipgloss.Color("99"))
-
-	sectionStyle := lipgloss.NewStyle().
-		Bold(true).
-		Foreground(lipgloss.Color("39"))
-
-	keyStyle := lipgloss.NewStyle().
-		Foreground(lipgloss.Color("220"))
-
-	descStyle := lipgloss.NewStyle().
-		Foreground(lipgloss.Color("252"))
-
-	var help strings.Builder
-
-	// Title
-	help.WriteString(titleStyle.Render("GitaGrip Help"))
-	help.WriteString("\n\n")
-
-	// Navigation section
-	help.WriteString(sectionStyle.Render("Navigation"))
-	help.WriteString("\n")
-	help.WriteString(fmt.Sprintf("  %s  %s\n", keyStyle.Render("↑/↓, j/k"), descStyle.Render("Navigate up/down")))
-	help.WriteString(fmt.Sprintf("  %s  %s\n", keyStyle.Render("←/→, h/l"), descStyle.Render("Collapse/expand groups")))
-	help.WriteString(fmt.Sprintf("  %s    %s\n", keyStyle.Render("PgUp/PgDn"), descStyle.Render("Page up/down")))
-	help.WriteString(fmt.Sprintf("  %s       %s\n", keyStyle.Render("gg/G"), descStyle.Render("Go to top/bottom")))
-	help.WriteString(fmt.Sprintf("  %s   %s\n", keyStyle.Render("Ctrl+F/B"), descStyle.Render("Page down/up")))
-	help.WriteString(fmt.Sprintf("  %s   %s\n", keyStyle.Render("Ctrl+D/U"), descStyle.Render("Half page down/up")))
-	help.WriteString(fmt.Sprintf("  %s         %s\n", keyStyle.Render("/"), descStyle.Render("Search")))
-	help.WriteString(fmt.Sprintf("  %s         %s\n", keyStyle.Render("n/N"), descStyle.Render("Next/previous result")))
-	help.WriteString(fmt.Sprintf("  %s         %s\n", keyStyle.Render("0/$"), descStyle.Render("Go to line start/end")))
-	help.WriteString(fmt.Sprintf("  %s         %s\n", keyStyle.Render("b/w"), descStyle.Render("Word backward/forward")))
-	help.WriteString(fmt.Sprintf("  %s         %s\n", keyStyle.Render("q"), descStyle.Render("Close pager")))
-	help.WriteString("\n")
-
-	// Selection section
-	help.WriteString(sectionStyle.Render("Selection"))
-	help.WriteString("\n")
-	help.WriteString(fmt.Sprintf("  %s        %s\n", keyStyle.Render("Space"), descStyle.Render("Toggle selection")))
-	help.WriteString(fmt.Sprintf("  %s          %s\n", keyStyle.Render("a/A"), descStyle.Render("Select/deselect all")))
-	help.WriteString(fmt.Sprintf("  %s          %s\n", keyStyle.Render("Esc"), descStyle.Render("Clear selection")))
-	help.WriteString("\n")
-
-	// Repository actions section
-	help.WriteString(sectionStyle.Render("Repository Actions"))
-	help.WriteString("\n")
-	help.WriteString(fmt.Sprintf("  %s            %s\n", keyStyle.Render("Enter"), descStyle.Render("Open lazygit for repository (requires lazygit)")))
-	help.WriteString(fmt.Sprintf("  %s            %s\n", keyStyle.Render("H"), descStyle.Render("View git log")))
-	help.WriteString(fmt.Sprintf("  %s            %s\n", keyStyle.Render("D"), descStyle.Render("View git diff")))
-	help.WriteString(fmt.Sprintf("  %s            %s\n", keyStyle.Render("r"), descStyle.Render("Refresh repository status")))
-	help.WriteString(fmt.Sprintf("  %s            %s\n", keyStyle.Render("f"), descStyle.Render("Fetch from remote")))
-	help.WriteString(fmt.Sprintf("  %s            %s\n", keyStyle.Render("p"), descStyle.Render("Pull from remote")))
-	help.WriteString(fmt.Sprintf("  %s            %s\n", keyStyle.Render("i"), descStyle.Render("Show repository info")))
-	help.WriteString(fmt.Sprintf("  %s            %s\n", keyStyle.Render("I"), descStyle.Render("View repository command logs")))
-	help.WriteString("\n")
-
-	// Group management section
-	help.WriteString(sectionStyle.Render("Group Management"))
-	help.WriteString("\n")
-	help.WriteString(fmt.Sprintf("  %s            %s\n", keyStyle.Render("z"), descStyle.Render("Toggle group")))
-	help.WriteString(fmt.Sprintf("  %s            %s\n", keyStyle.Render("N"), descStyle.Render("Create new group (when repos selected)")))
-	help.WriteString(fmt.Sprintf("  %s            %s\n", keyStyle.Render("m"), descStyle.Render("Move to group")))
-	help.WriteString(fmt.Sprintf("  %s            %s\n", keyStyle.Render("R"), descStyle.Render("Rename group")))
-	help.WriteString(fmt.Sprintf("  %s      %s\n", keyStyle.Render("Shift+J/K"), descStyle.Render("Move group up/down")))
-	help.WriteString("\n")
-
-	// Search & filter section
-	help.WriteString(sectionStyle.Render("Search & Filter"))
-	help.WriteString("\n")
-	help.WriteString(fmt.Sprintf("  %s            %s\n", keyStyle.Render("/"), descStyle.Render("Search repositories")))
-	help.WriteString(fmt.Sprintf("  %s            %s\n", keyStyle.Render("n"), descStyle.Render("Next search result")))
-	help.WriteString(fmt.Sprintf("  %s            %s\n", keyStyle.Render("N"), descStyle.Render("Previous search result (when searching)")))
-	help.WriteString(fmt.Sprintf("  %s            %s\n", keyStyle.Render("F"), descStyle.Render("Filter repositories")))
-	help.WriteString(fmt.Sprintf("  %s            %s\n", keyStyle.Render("s"), descStyle.Render("Sort options")))
-	help.WriteString("\n")
-
-	// Filter examples (using italic style)
-	filterStyle := lipgloss.NewStyle().Italic(true).Foreground(lipgloss.Color("241"))
-	help.WriteString(filterStyle.Render("  Filter examples: status:dirty, status:clean, status:ahead"))
-	help.WriteString("\n\n")
-
-	// Other section
-	help.WriteString(sectionStyle.Render("Other"))
-	help.WriteString("\n")
-	help.WriteString(fmt.Sprintf("  %s            %s\n", keyStyle.Render("?"), descStyle.Render("Toggle this help")))
-	help.WriteString(fmt.Sprintf("  %s            %s", keyStyle.Render("q"), descStyle.Render("Quit")))
-
-	return help.String()
+// renderQuickBar renders the footer: the pinned actions with their digits
+func (r *Renderer) renderQuickBar(items []QuickAction) string {
+	if len(items) == 0 {
+		return r.styles.Help.Render("Press ? for help")
+	}
+	parts := make([]string, 0, len(items))
+	for _, item := range items {
+		parts = append(parts, r.styles.HelpKey.Render(item.Key)+" "+r.styles.Help.Render(item.Label))
+	}
+	return strings.Join(parts, r.styles.Help.Render("  "))
 }
@@ -0,0 +1,38 @@
+package views
+
+import (
+	"fmt"
+	"strings"
+	"testing"
+
+	"github.com/darksworm/gitagrip/internal/domain"
+)
+
+func TestRepositoryListDrawsOnlyTheRowsInView(t *testing.T) {
+	state := ViewState{
+		Width:          120,
+		Height:         40,
+		Repositories:   make(map[string]*domain.Repository),
+		ViewportOffset: 100,
+		ViewportHeight: 10,
+	}
+	for i := 0; i < 2000; i++ {
+		path := fmt.Sprintf("/code/repo-%04d", i)
+		state.Repositories[path] = &domain.Repository{Path: path, Name: fmt.Sprintf("repo-%04d", i)}
+		state.UngroupedRepos = append(state.UngroupedRepos, path)
+	}
+
+	r := NewRenderer(false, DefaultTheme())
+	list := r.renderRepositoryList(state)
+
+	// Ten lines less the "more above" and "more below" indicators leave rows 100 to 107
+	for i := 90; i < 120; i++ {
+		name := fmt.Sprintf("repo-%04d", i)
+		if drawn, want := strings.Contains(list, name), i >= 100 && i < 108; drawn != want {
+			t.Errorf("%s drawn = %t, want %t", name, drawn, want)
+		}
+	}
+	if lines := strings.Count(list, "\n") + 1; lines != state.ViewportHeight {
+		t.Errorf("list has %d lines, want %d", lines, state.ViewportHeight)
+	}
+}
@@ -0,0 +1,42 @@
+package ui
+
+import (
+	"slices"
+
+	"github.com/darksworm/gitagrip/internal/eventbus"
+)
+
+// visibleRepos returns the repositories of the list rows on screen, top to bottom, and the
+// one under the cursor ("" when the cursor is on a group header)
+func (m *Model) visibleRepos() (repoPaths []string, cursor string) {
+	if m.state.ViewportHeight == 0 {
+		return nil, "" // not started yet
+	}
+	m.cachedViewState()
+	start := min(m.state.ViewportOffset, len(m.rows))
+	end := min(start+m.state.ViewportHeight, len(m.rows))
+	for _, row := range m.rows[start:end] {
+		if row.RepoPath != "" {
+			repoPaths = append(repoPaths, row.RepoPath)
+		}
+	}
+	if index := m.state.SelectedIndex; index >= 0 && index < len(m.rows) {
+		cursor = m.rows[index].RepoPath
+	}
+	return repoPaths, cursor
+}
+
+// reportVisibleRepos tells the git service which repositories are on screen and which one
+// is under the cursor whenever they change. Their status reads go first, and the ones
+// whose status is deferred are read as they come into view. The first report goes out
+// before any repository is discovered.
+func (m *Model) reportVisibleRepos() {
+	repoPaths, cursor := m.visibleRepos()
+	if m.visibleReported && slices.Equal(repoPaths, m.visible) && cursor == m.visibleCursor {
+		return
+	}
+	m.visible, m.visibleCursor, m.visibleReported = repoPaths, cursor, true
+	if m.bus != nil {
+		m.bus.Publish(eventbus.ReposVisibleEvent{RepoPaths: repoPaths, Cursor: cursor})
+	}
+}
@@ -0,0 +1,48 @@
+package ui
+
+import "fmt"
+
+// visualRangeRepos returns the repositories between the visual anchor and the cursor
+func (m *Model) visualRangeRepos() []string {
+	from, to := m.state.VisualAnchor, m.state.SelectedIndex
+	if from > to {
+		from, to = to, from
+	}
+	var paths []string
+	for i := from; i <= to; i++ {
+		if repoPath := m.getRepoPathAtIndex(i); repoPath != "" {
+			paths = append(paths, repoPath)
+		}
+	}
+	return paths
+}
+
+// applyVisualRange selects every repository in the visual range. Unless add is set,
+// a range that is already fully selected is deselected instead, like Space on a group.
+func (m *Model) applyVisualRange(add bool) {
+	paths := m.visualRangeRepos()
+	if len(paths) == 0 {
+		return
+	}
+
+	allSelected := true
+	for _, path := range paths {
+		if !m.state.SelectedRepos[path] {
+			allSelected = false
+			break
+		}
+	}
+
+	if allSelected && !add {
+		for _, path := range paths {
+			delete(m.state.SelectedRepos, path)
+		}
+		m.state.StatusMessage = fmt.Sprintf("Deselected %d repositories", len(paths))
+		return
+	}
+
+	for _, path := range paths {
+		m.state.SelectedRepos[path] = true
+	}
+	m.state.StatusMessage = fmt.Sprintf("Selected %d repositories", len(paths))
+}
@@ -0,0 +1,69 @@
+package ui
+
+import (
+	"fmt"
+	"strings"
+
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/charmbracelet/lipgloss/v2"
+
+	"github.com/darksworm/gitagrip/internal/domain"
+)
+
+// acknowledgeWatchedChanges marks every pending watched commit as seen
+func (m *Model) acknowledgeWatchedChanges() tea.Cmd {
+	if len(m.state.WatchedChanges) == 0 {
+		return nil
+	}
+
+	acks := make([]domain.WatchAck, 0, len(m.state.WatchedChanges))
+	count := 0
+	for _, change := range m.state.WatchedChanges {
+		acks = append(acks, domain.WatchAck{
+			RepoPath: change.RepoPath,
+			Branch:   change.Branch,
+			Commit:   change.Commits[0].Hash,
+		})
+		count += len(change.Commits)
+	}
+	m.state.WatchedChanges = nil
+	m.state.StatusMessage = fmt.Sprintf("Acknowledged %d watched commits", count)
+	return m.cmdExecutor.ExecuteAcknowledgeWatches(acks)
+}
+
+// buildWatchedChangesContent renders the new commits on watched branches, per repository
+func (m *Model) buildWatchedChangesContent() string {
+	var b strings.Builder
+	b.WriteString(lipgloss.NewStyle().Bold(true).Render("Watched branches"))
+	b.WriteString("\n\n")
+
+	faint := lipgloss.NewStyle().Faint(true)
+	if len(m.state.WatchedChanges) == 0 {
+		b.WriteString("No new commits on watched branches\n\n")
+		b.WriteString(faint.Render("esc close"))
+		return b.String()
+	}
+
+	hashStyle := lipgloss.NewStyle().Foreground(lipgloss.Color(m.renderer.Theme().Warning))
+	for _, change := range m.state.WatchedChanges {
+		name := change.RepoPath
+		if repo, ok := m.state.Repositories[change.RepoPath]; ok {
+			name = repo.Name
+			if repo.DisplayName != "" {
+				name = repo.DisplayName
+			}
+		}
+		b.WriteString(fmt.Sprintf("%s  %s (%d new)\n", name, change.Branch, len(change.Commits)))
+		for _, commit := range change.Commits {
+			hash := commit.Hash
+			if len(hash) > 7 {
+				hash = hash[:7]
+			}
+			b.WriteString(fmt.Sprintf("    %s %s\n", hashStyle.Render(hash), commit.Subject))
+		}
+		b.WriteString("\n")
+	}
+
+	b.WriteString(faint.Render("a/enter acknowledge all • esc close"))
+	return b.String()
+}
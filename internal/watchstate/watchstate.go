@@ -0,0 +1,89 @@
+// Package watchstate stores, per repository and watched branch, the last commit
+// the user acknowledged, in a JSON file next to the status cache.
+package watchstate
+
+import (
+	"crypto/sha1"
+	"encoding/hex"
+	"encoding/json"
+	"os"
+	"path/filepath"
+	"sync"
+
+	"github.com/darksworm/gitagrip/internal/domain"
+)
+
+// FileState is a domain.WatchState backed by a JSON file
+type FileState struct {
+	path  string
+	mu    sync.Mutex
+	acks  map[string]map[string]string // repo path -> branch -> acknowledged commit
+	dirty bool
+}
+
+var _ domain.WatchState = (*FileState)(nil)
+
+// DefaultPath returns the watch state file used for a workspace directory
+func DefaultPath(baseDir string) (string, error) {
+	cacheDir, err := os.UserCacheDir()
+	if err != nil {
+		return "", err
+	}
+	sum := sha1.Sum([]byte(baseDir))
+	return filepath.Join(cacheDir, "gitagrip", "watches-"+hex.EncodeToString(sum[:])[:16]+".json"), nil
+}
+
+// Open loads the state at path. A missing or unreadable file yields an empty state.
+func Open(path string) *FileState {
+	s := &FileState{path: path, acks: make(map[string]map[string]string)}
+	if data, err := os.ReadFile(path); err == nil {
+		_ = json.Unmarshal(data, &s.acks)
+	}
+	return s
+}
+
+// Acknowledged returns the last acknowledged commit of a watched branch
+func (s *FileState) Acknowledged(repoPath, branch string) string {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	return s.acks[repoPath][branch]
+}
+
+// Acknowledge records the commit a watched branch has been seen up to
+func (s *FileState) Acknowledge(repoPath, branch, commit string) {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	if s.acks[repoPath] == nil {
+		s.acks[repoPath] = make(map[string]string)
+	}
+	if s.acks[repoPath][branch] != commit {
+		s.acks[repoPath][branch] = commit
+		s.dirty = true
+	}
+}
+
+// Flush writes the state file if anything changed
+func (s *FileState) Flush() error {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+	if !s.dirty {
+		return nil
+	}
+
+	data, err := json.Marshal(s.acks)
+	if err != nil {
+		return err
+	}
+	if err := os.MkdirAll(filepath.Dir(s.path), 0755); err != nil {
+		return err
+	}
+	tmp := s.path + ".tmp"
+	if err := os.WriteFile(tmp, data, 0644); err != nil {
+		return err
+	}
+	if err := os.Rename(tmp, s.path); err != nil {
+		return err
+	}
+	s.dirty = false
+	return nil
+}
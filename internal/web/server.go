@@ -0,0 +1,215 @@
+//go:build web
+
+// Package web is a browser frontend for gitagrip, built with -tags web. It drives the
+// same engine as the TUI and shows the repository list with live status updates.
+package web
+
+import (
+	_ "embed"
+	"encoding/json"
+	"fmt"
+	"mime"
+	"net/http"
+	"sort"
+	"sync"
+
+	"github.com/darksworm/gitagrip/engine"
+)
+
+//go:embed index.html
+var indexHTML []byte
+
+// repoView is a repository as sent to the browser
+type repoView struct {
+	Path       string `json:"path"`
+	Name       string `json:"name"`
+	Group      string `json:"group"`
+	Branch     string `json:"branch"`
+	Ahead      int    `json:"ahead"`
+	Behind     int    `json:"behind"`
+	Dirty      bool   `json:"dirty"`
+	Empty      bool   `json:"empty,omitempty"` // no commits yet
+	InProgress string `json:"in_progress,omitempty"`
+	Error      string `json:"error,omitempty"`
+	Loaded     bool   `json:"loaded"`
+}
+
+// Server serves the frontend and relays engine events to connected browsers
+type Server struct {
+	engine *engine.Engine
+	addr   string            // host:port the server listens on; other Host headers are refused
+	groups map[string]string // repository path -> group name
+
+	mu      sync.Mutex
+	repos   map[string]*repoView
+	clients map[chan []byte]bool
+}
+
+// NewServer creates a server that shows the engine's repositories, grouped as in cfg,
+// to browsers that reach it at addr
+func NewServer(eng *engine.Engine, cfg *engine.Config, addr string) *Server {
+	s := &Server{
+		engine:  eng,
+		addr:    addr,
+		groups:  make(map[string]string),
+		repos:   make(map[string]*repoView),
+		clients: make(map[chan []byte]bool),
+	}
+	for name, paths := range cfg.Groups {
+		for _, path := range paths {
+			s.groups[path] = name
+		}
+	}
+
+	eng.Subscribe(engine.EventRepoDiscovered, func(e engine.Event) {
+		repo := e.(engine.RepoDiscoveredEvent).Repo
+		name := repo.DisplayName
+		if name == "" {
+			name = repo.Name
+		}
+		s.update(repo.Path, func(v *repoView) { v.Name = name })
+	})
+	eng.Subscribe(engine.EventStatusUpdated, func(e engine.Event) {
+		ev := e.(engine.StatusUpdatedEvent)
+		s.update(ev.RepoPath, func(v *repoView) {
+			v.Branch = ev.Status.Branch
+			v.Ahead = ev.Status.AheadCount
+			v.Behind = ev.Status.BehindCount
+			v.Dirty = ev.Status.IsDirty
+			v.Empty = ev.Status.NoCommits
+			v.InProgress = string(ev.Status.InProgress)
+			v.Error = ev.Status.Error
+			v.Loaded = true
+		})
+	})
+	return s
+}
+
+// update changes a repository's view and sends it to every browser
+func (s *Server) update(path string, change func(*repoView)) {
+	s.mu.Lock()
+	defer s.mu.Unlock()
+
+	v, ok := s.repos[path]
+	if !ok {
+		v = &repoView{Path: path, Group: s.groups[path]}
+		if v.Group == "" {
+			v.Group = "Ungrouped"
+		}
+		s.repos[path] = v
+	}
+	change(v)
+
+	data, err := json.Marshal(v)
+	if err != nil {
+		return
+	}
+	for client := range s.clients {
+		select {
+		case client <- data:
+		default:
+			// A browser that can't keep up reloads the full list when it reconnects
+		}
+	}
+}
+
+// Handler returns the HTTP handler of the frontend. Requests must name the listen
+// address as their Host, so a page served by another name (DNS rebinding) can't use it.
+func (s *Server) Handler() http.Handler {
+	mux := http.NewServeMux()
+	mux.HandleFunc("GET /{$}", func(w http.ResponseWriter, r *http.Request) {
+		w.Header().Set("Content-Type", "text/html; charset=utf-8")
+		_, _ = w.Write(indexHTML)
+	})
+	mux.HandleFunc("GET /api/repos", s.handleRepos)
+	mux.HandleFunc("GET /api/events", s.handleEvents)
+	mux.HandleFunc("POST /api/refresh", s.handleOperation(s.engine.Refresh))
+	mux.HandleFunc("POST /api/fetch", s.handleOperation(s.engine.Fetch))
+	mux.HandleFunc("POST /api/pull", s.handleOperation(s.engine.Pull))
+	return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if r.Host != s.addr {
+			http.Error(w, "unexpected host "+r.Host, http.StatusForbidden)
+			return
+		}
+		mux.ServeHTTP(w, r)
+	})
+}
+
+// handleRepos returns every known repository, by group and name
+func (s *Server) handleRepos(w http.ResponseWriter, r *http.Request) {
+	s.mu.Lock()
+	repos := make([]repoView, 0, len(s.repos))
+	for _, v := range s.repos {
+		repos = append(repos, *v)
+	}
+	s.mu.Unlock()
+
+	sort.Slice(repos, func(i, j int) bool {
+		if repos[i].Group != repos[j].Group {
+			return repos[i].Group < repos[j].Group
+		}
+		return repos[i].Name < repos[j].Name
+	})
+	w.Header().Set("Content-Type", "application/json")
+	_ = json.NewEncoder(w).Encode(repos)
+}
+
+// handleEvents streams repository changes as server-sent events
+func (s *Server) handleEvents(w http.ResponseWriter, r *http.Request) {
+	flusher, ok := w.(http.Flusher)
+	if !ok {
+		http.Error(w, "streaming unsupported", http.StatusInternalServerError)
+		return
+	}
+	w.Header().Set("Content-Type", "text/event-stream")
+	w.Header().Set("Cache-Control", "no-cache")
+	flusher.Flush()
+
+	client := make(chan []byte, 64)
+	s.mu.Lock()
+	s.clients[client] = true
+	s.mu.Unlock()
+	defer func() {
+		s.mu.Lock()
+		delete(s.clients, client)
+		s.mu.Unlock()
+	}()
+
+	for {
+		select {
+		case <-r.Context().Done():
+			return
+		case data := <-client:
+			if _, err := fmt.Fprintf(w, "data: %s\n\n", data); err != nil {
+				return
+			}
+			flusher.Flush()
+		}
+	}
+}
+
+// handleOperation runs an engine operation on the repositories posted as
+// {"paths": [...]}; no paths means all of them. Only JSON posted by the frontend itself
+// is accepted: other pages can send plain form posts, but not JSON without the Origin
+// of the page.
+func (s *Server) handleOperation(run func(paths ...string)) http.HandlerFunc {
+	return func(w http.ResponseWriter, r *http.Request) {
+		if origin := r.Header.Get("Origin"); origin != "" && origin != "http://"+s.addr {
+			http.Error(w, "cross-origin request refused", http.StatusForbidden)
+			return
+		}
+		if mediaType, _, _ := mime.ParseMediaType(r.Header.Get("Content-Type")); mediaType != "application/json" {
+			http.Error(w, "Content-Type must be application/json", http.StatusUnsupportedMediaType)
+			return
+		}
+		var body struct {
+			Paths []string `json:"paths"`
+		}
+		if err := json.NewDecoder(r.Body).Decode(&body); err != nil {
+			http.Error(w, "invalid request: "+err.Error(), http.StatusBadRequest)
+			return
+		}
+		run(body.Paths...)
+		w.WriteHeader(http.StatusAccepted)
+	}
+}
@@ -0,0 +1,45 @@
+//go:build web
+
+package web
+
+import (
+	"net/http"
+	"net/http/httptest"
+	"strings"
+	"testing"
+
+	"github.com/darksworm/gitagrip/engine"
+)
+
+func TestOperationsRefuseOtherSites(t *testing.T) {
+	eng := engine.New(engine.Options{})
+	defer eng.Close()
+	handler := NewServer(eng, &engine.Config{}, "127.0.0.1:7070").Handler()
+
+	post := func(host, origin, contentType string) int {
+		req := httptest.NewRequest(http.MethodPost, "http://"+host+"/api/fetch", strings.NewReader(`{"paths": ["/nowhere"]}`))
+		if origin != "" {
+			req.Header.Set("Origin", origin)
+		}
+		if contentType != "" {
+			req.Header.Set("Content-Type", contentType)
+		}
+		rec := httptest.NewRecorder()
+		handler.ServeHTTP(rec, req)
+		return rec.Code
+	}
+
+	for _, tc := range []struct {
+		name, host, origin, contentType string
+		want                            int
+	}{
+		{"frontend", "127.0.0.1:7070", "http://127.0.0.1:7070", "application/json", http.StatusAccepted},
+		{"cross-origin", "127.0.0.1:7070", "https://evil.example", "application/json", http.StatusForbidden},
+		{"form post", "127.0.0.1:7070", "", "text/plain", http.StatusUnsupportedMediaType},
+		{"rebound host", "evil.example:7070", "http://evil.example:7070", "application/json", http.StatusForbidden},
+	} {
+		if got := post(tc.host, tc.origin, tc.contentType); got != tc.want {
+			t.Errorf("%s: status %d, want %d", tc.name, got, tc.want)
+		}
+	}
+}
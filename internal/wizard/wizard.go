@@ -0,0 +1,330 @@
+// Package wizard is the setup screen shown when gitagrip starts in a directory without a
+// config: it picks the base directory and grouping strategy of the new workspace.
+package wizard
+
+import (
+	"fmt"
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+	"time"
+
+	"github.com/charmbracelet/bubbles/v2/textinput"
+	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/charmbracelet/lipgloss/v2"
+
+	"github.com/darksworm/gitagrip/internal/config"
+	"github.com/darksworm/gitagrip/internal/discovery"
+)
+
+// Choice is what the wizard settled on
+type Choice struct {
+	BaseDir  string
+	Grouping string
+}
+
+// strategies are the grouping strategies offered, in order
+var strategies = []struct {
+	name        string
+	description string
+}{
+	{config.GroupingParent, "named after the parent directory, e.g. acme"},
+	{config.GroupingPath, "named after the parent's path, e.g. work/clients/acme"},
+	{config.GroupingFlat, "no automatic groups"},
+}
+
+// maxSuggestions limits the subdirectories listed under the input
+const maxSuggestions = 8
+
+// previewDelay is how long typing has to pause before the directory is searched
+const previewDelay = 250 * time.Millisecond
+
+// Run shows the setup screen starting at dir with the given grouping strategy selected
+// ("" for the default). ok is false when it was cancelled.
+func Run(dir, grouping string) (choice Choice, ok bool, err error) {
+	m := newModel(dir, grouping)
+	final, err := tea.NewProgram(m, tea.WithAltScreen()).Run()
+	if err != nil {
+		return Choice{}, false, err
+	}
+	result := final.(*model)
+	return result.choice, result.done, nil
+}
+
+// preview is what was found in a directory
+type preview struct {
+	dir       string   // absolute path searched
+	repos     []string // repositories found
+	hasConfig bool     // the directory has a workspace config already
+	err       string   // why the directory can't be used
+}
+
+// typedMsg is sent once typing pauses, with the path typed at the time
+type typedMsg string
+
+// previewMsg carries the result of searching a directory
+type previewMsg preview
+
+type model struct {
+	input       textinput.Model
+	strategy    int
+	suggestions []string // subdirectories matching what is typed
+	preview     *preview // of the last path searched (nil while searching)
+	searched    string   // path the preview is for, as typed
+	choice      Choice
+	done        bool
+	err         string
+}
+
+func newModel(dir, grouping string) *model {
+	input := textinput.New()
+	input.Prompt = ""
+	input.SetValue(withSeparator(dir))
+	input.CursorEnd()
+	input.Focus()
+	m := &model{input: input}
+	for i, s := range strategies {
+		if s.name == grouping {
+			m.strategy = i
+		}
+	}
+	m.suggestions = suggest(m.input.Value())
+	return m
+}
+
+func (m *model) Init() tea.Cmd {
+	m.searched = m.input.Value()
+	return previewDir(m.searched)
+}
+
+func (m *model) Update(msg tea.Msg) (tea.Model, tea.Cmd) {
+	switch msg := msg.(type) {
+	case typedMsg:
+		// Search only the path still typed once typing paused
+		if string(msg) == m.input.Value() && string(msg) != m.searched {
+			m.searched = string(msg)
+			m.preview = nil
+			return m, previewDir(m.searched)
+		}
+		return m, nil
+
+	case previewMsg:
+		if msg.dir == expand(m.searched) {
+			p := preview(msg)
+			m.preview = &p
+		}
+		return m, nil
+
+	case tea.KeyMsg:
+		switch msg.String() {
+		case "ctrl+c", "esc":
+			return m, tea.Quit
+		case "up", "ctrl+p":
+			m.strategy = (m.strategy + len(strategies) - 1) % len(strategies)
+			return m, nil
+		case "down", "ctrl+n":
+			m.strategy = (m.strategy + 1) % len(strategies)
+			return m, nil
+		case "tab":
+			if completed := complete(m.input.Value()); completed != m.input.Value() {
+				m.input.SetValue(completed)
+				m.input.CursorEnd()
+				return m, m.typed()
+			}
+			return m, nil
+		case "enter":
+			return m, m.confirm()
+		}
+	}
+
+	before := m.input.Value()
+	var cmd tea.Cmd
+	m.input, cmd = m.input.Update(msg)
+	if m.input.Value() != before {
+		return m, tea.Batch(cmd, m.typed())
+	}
+	return m, cmd
+}
+
+// typed refreshes the suggestions and searches the new path once typing pauses
+func (m *model) typed() tea.Cmd {
+	m.err = ""
+	m.suggestions = suggest(m.input.Value())
+	value := m.input.Value()
+	return tea.Tick(previewDelay, func(time.Time) tea.Msg { return typedMsg(value) })
+}
+
+// confirm settles on the directory typed, once it has been searched
+func (m *model) confirm() tea.Cmd {
+	if m.searched != m.input.Value() || m.preview == nil {
+		m.err = "Still looking for repositories, try again in a moment"
+		return nil
+	}
+	if m.preview.err != "" {
+		m.err = m.preview.err
+		return nil
+	}
+	m.choice = Choice{BaseDir: m.preview.dir, Grouping: strategies[m.strategy].name}
+	m.done = true
+	return tea.Quit
+}
+
+func (m *model) View() string {
+	bold := lipgloss.NewStyle().Bold(true)
+	faint := lipgloss.NewStyle().Faint(true)
+
+	var b strings.Builder
+	b.WriteString(bold.Render("Welcome to gitagrip"))
+	b.WriteString("\n\n")
+	b.WriteString("There is no gitagrip config here yet. Which directory holds your repositories?\n\n")
+	b.WriteString("Base directory: " + m.input.View() + "\n")
+	if len(m.suggestions) > 0 {
+		b.WriteString(faint.Render("  " + strings.Join(m.suggestions, "  ")))
+		b.WriteString("\n")
+	}
+	b.WriteString("\n")
+
+	var groups []int
+	switch {
+	case m.preview == nil:
+		b.WriteString(faint.Render("Looking for repositories..."))
+	case m.preview.err != "":
+		b.WriteString(m.preview.err)
+	case m.preview.hasConfig:
+		b.WriteString(fmt.Sprintf("Found %d repositories; the gitagrip config already there will be opened", len(m.preview.repos)))
+	default:
+		b.WriteString(fmt.Sprintf("Found %d repositories", len(m.preview.repos)))
+		for _, s := range strategies {
+			count := 0
+			if s.name != config.GroupingFlat {
+				count = len(discovery.GroupRepos(m.preview.dir, m.preview.repos, s.name))
+			}
+			groups = append(groups, count)
+		}
+	}
+	b.WriteString("\n\n")
+
+	b.WriteString("Group repositories by directory:\n")
+	for i, s := range strategies {
+		marker := "  "
+		if i == m.strategy {
+			marker = "› "
+		}
+		line := fmt.Sprintf("%s%-7s %s", marker, s.name, faint.Render(s.description))
+		if groups != nil && s.name != config.GroupingFlat {
+			line += faint.Render(fmt.Sprintf(" (%d groups)", groups[i]))
+		}
+		b.WriteString(line + "\n")
+	}
+
+	if m.err != "" {
+		b.WriteString("\n" + bold.Render(m.err) + "\n")
+	}
+	b.WriteString("\n")
+	b.WriteString(faint.Render("tab complete • ↑/↓ grouping • enter save and start • esc quit"))
+	return b.String()
+}
+
+// previewDir searches the typed directory for repositories in the background
+func previewDir(typed string) tea.Cmd {
+	return func() tea.Msg {
+		dir := expand(typed)
+		p := preview{dir: dir}
+		info, err := os.Stat(dir)
+		switch {
+		case err != nil:
+			p.err = fmt.Sprintf("%s does not exist", dir)
+		case !info.IsDir():
+			p.err = fmt.Sprintf("%s is not a directory", dir)
+		default:
+			p.repos = discovery.FindRepos(dir)
+			_, err := os.Stat(filepath.Join(dir, ".gitagrip.toml"))
+			p.hasConfig = err == nil
+		}
+		return previewMsg(p)
+	}
+}
+
+// expand turns a typed path into an absolute one, ~ standing for the home directory
+func expand(typed string) string {
+	path := typed
+	if home, err := os.UserHomeDir(); err == nil {
+		if path == "~" {
+			path = home
+		} else if rest, ok := strings.CutPrefix(path, "~"+string(filepath.Separator)); ok {
+			path = filepath.Join(home, rest)
+		}
+	}
+	if abs, err := filepath.Abs(path); err == nil {
+		return abs
+	}
+	return filepath.Clean(path)
+}
+
+// withSeparator ends a directory path with a separator, so that tab lists its children
+func withSeparator(dir string) string {
+	if strings.HasSuffix(dir, string(filepath.Separator)) {
+		return dir
+	}
+	return dir + string(filepath.Separator)
+}
+
+// subdirs lists the subdirectories that the last element of what is typed starts with,
+// along with the typed directory they are in; hidden ones only when a dot was typed
+func subdirs(typed string) (parent string, names []string) {
+	parent, prefix := filepath.Split(typed)
+	listed := parent
+	if listed == "" {
+		listed = "."
+	}
+	entries, err := os.ReadDir(expand(listed))
+	if err != nil {
+		return parent, nil
+	}
+	for _, entry := range entries {
+		name := entry.Name()
+		if !entry.IsDir() || !strings.HasPrefix(name, prefix) {
+			continue
+		}
+		if strings.HasPrefix(name, ".") && !strings.HasPrefix(prefix, ".") {
+			continue
+		}
+		names = append(names, name)
+	}
+	sort.Strings(names)
+	return parent, names
+}
+
+// suggest lists the subdirectories tab can complete to
+func suggest(typed string) []string {
+	_, names := subdirs(typed)
+	if len(names) > maxSuggestions {
+		names = append(names[:maxSuggestions], "…")
+	}
+	for i, name := range names {
+		if name != "…" {
+			names[i] = name + string(filepath.Separator)
+		}
+	}
+	return names
+}
+
+// complete extends what is typed to the only matching subdirectory, or to the longest
+// prefix the matching ones share
+func complete(typed string) string {
+	parent, names := subdirs(typed)
+	if len(names) == 0 {
+		return typed
+	}
+	if len(names) == 1 {
+		return parent + names[0] + string(filepath.Separator)
+	}
+	common := names[0]
+	for _, name := range names[1:] {
+		for !strings.HasPrefix(name, common) {
+			common = common[:len(common)-1]
+		}
+	}
+	return parent + common
+}
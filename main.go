@@ -4,34 +4,91 @@ import (
 	"context"
 	"flag"
 	"fmt"
-	"io/fs"
+	"io"
 	"log"
+	"log/slog"
 	"os"
 	"os/signal"
 	"path/filepath"
 	"strings"
 	"syscall"
 
-	"gitagrip/internal/config"
-	"gitagrip/internal/discovery"
-	"gitagrip/internal/eventbus"
-	"gitagrip/internal/git"
-	"gitagrip/internal/groups"
-	"gitagrip/internal/ui"
 	tea "github.com/charmbracelet/bubbletea/v2"
+	"github.com/darksworm/gitagrip/internal/agent"
+	"github.com/darksworm/gitagrip/internal/applog"
+	"github.com/darksworm/gitagrip/internal/cli"
+	"github.com/darksworm/gitagrip/internal/config"
+	"github.com/darksworm/gitagrip/internal/control"
+	"github.com/darksworm/gitagrip/internal/discovery"
+	"github.com/darksworm/gitagrip/internal/domain"
+	"github.com/darksworm/gitagrip/internal/eventbus"
+	"github.com/darksworm/gitagrip/internal/git"
+	"github.com/darksworm/gitagrip/internal/groups"
+	"github.com/darksworm/gitagrip/internal/paths"
+	"github.com/darksworm/gitagrip/internal/session"
+	"github.com/darksworm/gitagrip/internal/snapshot"
+	"github.com/darksworm/gitagrip/internal/statuscache"
+	"github.com/darksworm/gitagrip/internal/termguard"
+	"github.com/darksworm/gitagrip/internal/ui"
+	"github.com/darksworm/gitagrip/internal/ui/termcaps"
+	"github.com/darksworm/gitagrip/internal/watchstate"
+	"github.com/darksworm/gitagrip/internal/wizard"
 )
 
 func main() {
+	// Started by git or ssh of a fetch, pull or clone to ask for a password or passphrase
+	if git.IsAskpass(os.Args[1:]) {
+		os.Exit(git.RunAskpass(os.Args[1]))
+	}
+
+	// Non-interactive subcommands (export-bundle, import-bundle, ...)
+	if exitCode, handled := cli.Run(os.Args[1:]); handled {
+		os.Exit(exitCode)
+	}
+
+	// `gitagrip jump` is the TUI as a picker: the UI goes to stderr and the path of the
+	// repository picked with enter to stdout, for cd "$(gitagrip jump)"
+	args := os.Args[1:]
+	jump := len(args) > 0 && args[0] == "jump"
+	if jump {
+		args = args[1:]
+	}
+
 	// Parse command line arguments
 	var targetDir string
 	flag.StringVar(&targetDir, "dir", "", "Directory to scan for repositories")
 	flag.StringVar(&targetDir, "d", "", "Directory to scan for repositories (shorthand)")
-	flag.Parse()
+	var grouping string
+	flag.StringVar(&grouping, "grouping", "", "How a new workspace groups repositories by directory: parent (default), path or flat")
+	var remote string
+	flag.StringVar(&remote, "remote", "", "Manage the repositories on another machine: [user@]host[:dir] (runs gitagrip agent over ssh)")
+	var profile string
+	flag.StringVar(&profile, "profile", "", "Named workspace config to use (see gitagrip -profiles); -d creates it")
+	listProfiles := flag.Bool("profiles", false, "List the workspace profiles and exit")
+	noAltScreen := flag.Bool("no-alt-screen", false, "Draw in the normal screen, so that the last frame and any crash output stay visible (for debugging)")
+	_ = flag.CommandLine.Parse(args)
+
+	if *listProfiles {
+		names, err := config.ListProfiles()
+		if err != nil {
+			fmt.Fprintf(os.Stderr, "Error: %v\n", err)
+			os.Exit(1)
+		}
+		for _, name := range names {
+			fmt.Println(name)
+		}
+		return
+	}
+	if profile != "" && remote != "" {
+		fmt.Fprintln(os.Stderr, "Error: -profile and -remote cannot be combined")
+		os.Exit(1)
+	}
 
 	// If no directory specified, check for remaining args
 	if targetDir == "" && flag.NArg() > 0 {
 		targetDir = flag.Arg(0)
 	}
+	dirGiven := targetDir != ""
 
 	// If still no directory, use current directory
 	if targetDir == "" {
@@ -49,18 +106,58 @@ func main() {
 		fmt.Printf("Error resolving path: %v\n", err)
 		os.Exit(1)
 	}
+	absDir = paths.Normalize(absDir)
 
-	// Set up logging
-	logFile, err := os.OpenFile("gitagrip.log", os.O_CREATE|os.O_WRONLY|os.O_APPEND, 0666)
+	// Set up logging to the log file; the terminal belongs to the UI
+	logFile, err := applog.Setup()
 	if err != nil {
-		log.Printf("Could not open log file: %v", err)
+		log.SetOutput(io.Discard)
 	} else {
 		defer func() {
 			_ = logFile.Close()
 		}()
-		log.SetOutput(logFile)
 	}
 
+	// Whatever panics from here on, the terminal is left usable and the panic readable
+	uiOut := io.Writer(os.Stdout)
+	if jump {
+		uiOut = os.Stderr
+	}
+	termguard.Install(os.Stdin, uiOut)
+	defer termguard.Restore()
+	defer termguard.Recover()
+
+	// Each run manages one workspace; switching profiles in the app ends the run, and the
+	// next one starts over with the chosen profile's config, services and scan
+	opts := runOptions{dir: absDir, dirGiven: dirGiven, grouping: grouping, remote: remote, profile: profile, jump: jump, noAltScreen: *noAltScreen}
+	for {
+		next, exitCode := run(opts)
+		if next == "" {
+			if exitCode != 0 {
+				os.Exit(exitCode)
+			}
+			return
+		}
+		opts = runOptions{profile: next, grouping: grouping, jump: jump, noAltScreen: *noAltScreen}
+	}
+}
+
+// runOptions selects the workspace a run manages
+type runOptions struct {
+	dir         string // workspace directory (a profile's base_dir takes over)
+	dirGiven    bool   // dir was passed on the command line rather than defaulting to the current one
+	grouping    string
+	remote      string
+	profile     string
+	jump        bool
+	noAltScreen bool
+}
+
+// run sets up the services of a workspace, runs the UI until it quits and tears them down.
+// It returns the profile to switch to next ("" to exit) and the exit code.
+func run(opts runOptions) (nextProfile string, exitCode int) {
+	absDir, remote, jump := opts.dir, opts.remote, opts.jump
+
 	// Create context for graceful shutdown
 	ctx, cancel := context.WithCancel(context.Background())
 	defer cancel()
@@ -68,147 +165,212 @@ func main() {
 	// Handle interrupt signals
 	sigChan := make(chan os.Signal, 1)
 	signal.Notify(sigChan, syscall.SIGINT, syscall.SIGTERM)
-	go func() {
-		<-sigChan
-		cancel()
-	}()
+	defer signal.Stop(sigChan)
+	termguard.Go(func() {
+		select {
+		case <-sigChan:
+			cancel()
+		case <-ctx.Done():
+		}
+	})
 
 	// Create event bus
 	bus := eventbus.New()
 
-	// Load configuration from the target directory with event bus support
+	// Load configuration from the target directory (or the profile) with event bus support
 	configPath := filepath.Join(absDir, ".gitagrip.toml")
 	configSvc := config.NewConfigServiceWithBus(bus)
-	cfg := loadOrCreateConfig(configSvc, absDir)
-
-	// Subscribe to config changes to save automatically
-	bus.Subscribe(eventbus.EventConfigChanged, func(e eventbus.DomainEvent) {
-		if event, ok := e.(eventbus.ConfigChangedEvent); ok {
-			// Update config with new groups and order
-			cfg.Groups = event.Groups
-			cfg.GroupOrder = event.GroupOrder
-			// Save config
-			if err := configSvc.SaveToPath(cfg, configPath); err != nil {
-				log.Printf("Failed to save config: %v", err)
+	var cfg *config.Config
+	var conn *agent.Conn
+	var err error
+	switch {
+	case remote != "":
+		// The repositories live on another machine, whose agent owns the config
+		conn, err = agent.Dial(ctx, remote)
+		if err != nil {
+			fmt.Fprintf(os.Stderr, "Error: %v\n", err)
+			return "", 1
+		}
+		cfg = conn.Config()
+		configPath = remote
+	case opts.profile != "":
+		cfg, configPath, err = loadProfile(configSvc, opts)
+		if err != nil {
+			fmt.Fprintf(os.Stderr, "Error: %v\n", err)
+			return "", 1
+		}
+		absDir = cfg.BaseDir
+	default:
+		grouping := opts.grouping
+		if needsSetup(opts, configPath) {
+			// First run without a directory given: ask for one rather than taking this one
+			choice, ok, err := wizard.Run(absDir, grouping)
+			if err != nil {
+				fmt.Fprintf(os.Stderr, "Error: %v\n", err)
+				return "", 1
+			}
+			if !ok {
+				return "", 0
+			}
+			absDir, grouping = choice.BaseDir, choice.Grouping
+			configPath = filepath.Join(absDir, ".gitagrip.toml")
+		}
+		cfg, err = loadOrCreateConfig(configSvc, configPath, absDir, grouping)
+		if err != nil {
+			fmt.Fprintf(os.Stderr, "Error: %v\n", err)
+			return "", 1
+		}
+	}
+
+	// Repositories pinned to a group in [repos] always start out in it
+	cfg.ApplyRepoGroups()
+
+	// Refuse to start with broken settings (e.g. two actions bound to one key)
+	if err := ui.ValidateConfig(cfg); err != nil {
+		fmt.Fprintf(os.Stderr, "Invalid configuration in %s:\n%v\n", configPath, err)
+		return "", 1
+	}
+
+	// Group changes are saved through the store (on the remote machine when connected to
+	// an agent), right away or on request when autosave_on_exit is off. The UI owns cfg;
+	// the saver and the services get copies of it, renewed on every change.
+	configStore := config.NewFileStore(configSvc, configPath)
+	if conn != nil {
+		configStore = config.StoreFunc(conn.SaveConfig)
+	}
+	configSaver := config.NewSaver(bus, cfg.Clone(), configStore)
+	bus.Subscribe(eventbus.EventConfigSaved, func(e eventbus.DomainEvent) {
+		if event, ok := e.(eventbus.ConfigSavedEvent); ok {
+			if event.Err != nil {
+				log.Printf("Failed to save config: %v", event.Err)
 			} else {
 				log.Printf("Config saved to %s", configPath)
 			}
 		}
 	})
 
-	// Initialize services
-	discoverySvc := discovery.NewDiscoveryService(bus)
-	_ = git.NewGitService(bus)                  // Git service subscribes to events automatically
-	_ = groups.NewGroupManager(bus, cfg.Groups) // Group manager subscribes to events automatically
+	// Initialize services; a remote agent runs discovery and git (with its own caches)
+	var discoverySvc discovery.DiscoveryService
+	var statusCache domain.StatusCache
+	var watchState domain.WatchState
+	var snap domain.Snapshot
+	var restored []domain.Repository
+	var reconciler *discovery.Reconciler
+	var gitSvc git.GitService
+	if conn != nil {
+		conn.Attach(bus)
+	} else {
+		// Last-known statuses are shown instantly while fresh ones are computed
+		statusCache = openStatusCache(cfg, absDir)
+
+		// Last acknowledged commits of watched branches
+		watchState = openWatchState(cfg, absDir)
+
+		// Repositories of the last run, listed before the scan finds them again
+		snap = openSnapshot(bus, cfg, absDir)
+		restored = snapshotRepos(snap, cfg.BaseDir)
+
+		shared := cfg.Clone()
+		discoverySvc = discovery.NewDiscoveryServiceWithConfig(bus, shared)
+		reconciler = discovery.NewReconciler(bus, shared, repoPaths(restored)) // Drops repos that disappear
+		gitSvc = git.NewGitServiceWithWatches(bus, shared, statusCache, watchState) // Git service subscribes to events automatically
+	}
+	_ = groups.NewGroupManager(bus, cfg.Clone().Groups) // Group manager subscribes to events automatically
+
+	slog.Info("workspace opened", "dir", cfg.BaseDir, "config", configPath, "profile", opts.profile, "remote", remote)
+
+	// Detect what the terminal can render, honoring config overrides
+	caps := termcaps.Detect(os.Environ()).WithOverrides(cfg.UISettings.Terminal)
 
 	// Create UI model
-	uiModel := ui.NewModel(bus, cfg)
+	uiModel := ui.NewModel(bus, cfg, caps)
+	uiModel.ShareConfig(func(snapshot *config.Config) {
+		// The saver changes its copy, so it gets one of its own
+		configSaver.SetConfig(snapshot.Clone())
+		if conn == nil {
+			discoverySvc.SetConfig(snapshot)
+			reconciler.SetConfig(snapshot)
+			gitSvc.SetConfig(snapshot)
+		}
+	})
+	uiModel.SetJumpMode(jump)
+	uiModel.SetProfile(opts.profile)
+	if len(restored) > 0 {
+		uiModel.RestoreSnapshot(restored)
+	}
+	sessionPath := sessionFile(absDir)
+	if sessionPath != "" {
+		uiModel.RestoreSession(session.Load(sessionPath))
+	}
 
 	// Create Bubble Tea program
-	p := tea.NewProgram(uiModel, tea.WithAltScreen())
+	programOpts := []tea.ProgramOption{tea.WithColorProfile(caps.Color)}
+	if !opts.noAltScreen {
+		programOpts = append(programOpts, tea.WithAltScreen())
+	}
+	if !cfg.UISettings.DisableMouse {
+		programOpts = append(programOpts, tea.WithMouseCellMotion())
+	}
+	if jump {
+		programOpts = append(programOpts, tea.WithOutput(os.Stderr))
+	}
+	p := tea.NewProgram(uiModel, programOpts...)
 
 	// Set program reference in model and gitOps for terminal management
 	uiModel.SetProgram(p)
 
+	// Scripts and editor plugins drive the running instance through its control socket
+	if conn == nil && !jump {
+		server, err := control.Listen(control.DefaultPath(absDir), ui.ControlHandler(p.Send))
+		if err != nil {
+			log.Printf("Control socket disabled: %v", err)
+		} else {
+			log.Printf("Control socket at %s", server.Path())
+			defer func() { _ = server.Close() }()
+		}
+	}
+
 	// Signal ready for E2E tests (only in test mode)
 	if os.Getenv("GITAGRIP_E2E_TEST") == "1" {
-		fmt.Println("__READY__")
+		// Next to the UI, so that jump's stdout holds nothing but the picked path
+		if jump {
+			fmt.Fprintln(os.Stderr, "__READY__")
+		} else {
+			fmt.Println("__READY__")
+		}
 	}
 
 	// Set up event forwarding to UI
-	eventChan := make(chan eventbus.DomainEvent, 100)
-	bus.Subscribe(eventbus.EventRepoDiscovered, func(e eventbus.DomainEvent) {
-		select {
-		case eventChan <- e:
-		default:
-			// Channel full, drop event
-			log.Println("Event channel full, dropping event")
-		}
-	})
-	bus.Subscribe(eventbus.EventStatusUpdated, func(e eventbus.DomainEvent) {
-		select {
-		case eventChan <- e:
-		default:
-			log.Println("Event channel full, dropping event")
-		}
-	})
-	bus.Subscribe(eventbus.EventError, func(e eventbus.DomainEvent) {
-		select {
-		case eventChan <- e:
-		default:
-			log.Println("Event channel full, dropping event")
-		}
-	})
-	bus.Subscribe(eventbus.EventGroupAdded, func(e eventbus.DomainEvent) {
-		select {
-		case eventChan <- e:
-		default:
-			log.Println("Event channel full, dropping event")
-		}
-	})
-	bus.Subscribe(eventbus.EventGroupRemoved, func(e eventbus.DomainEvent) {
-		select {
-		case eventChan <- e:
-		default:
-			log.Println("Event channel full, dropping event")
-		}
-	})
-	bus.Subscribe(eventbus.EventStatusRefreshRequested, func(e eventbus.DomainEvent) {
-		select {
-		case eventChan <- e:
-		default:
-			log.Println("Event channel full, dropping event")
-		}
-	})
-	bus.Subscribe(eventbus.EventFetchRequested, func(e eventbus.DomainEvent) {
-		select {
-		case eventChan <- e:
-		default:
-			log.Println("Event channel full, dropping event")
-		}
-	})
-	bus.Subscribe(eventbus.EventRepoMoved, func(e eventbus.DomainEvent) {
-		select {
-		case eventChan <- e:
-		default:
-			log.Println("Event channel full, dropping event")
-		}
-	})
-	bus.Subscribe(eventbus.EventPullRequested, func(e eventbus.DomainEvent) {
-		select {
-		case eventChan <- e:
-		default:
-			log.Println("Event channel full, dropping event")
-		}
-	})
-	bus.Subscribe(eventbus.EventFetchCompleted, func(e eventbus.DomainEvent) {
-		select {
-		case eventChan <- e:
-		default:
-			log.Println("Event channel full, dropping event")
-		}
-	})
-	bus.Subscribe(eventbus.EventPullCompleted, func(e eventbus.DomainEvent) {
-		select {
-		case eventChan <- e:
-		default:
-			log.Println("Event channel full, dropping event")
-		}
-	})
-	bus.Subscribe(eventbus.EventCommandExecuted, func(e eventbus.DomainEvent) {
-		select {
-		case eventChan <- e:
-		default:
-			log.Println("Event channel full, dropping event")
-		}
-	})
+	events := ui.NewEventQueue()
+	for _, eventType := range []eventbus.EventType{
+		eventbus.EventRepoDiscovered,
+		eventbus.EventStatusUpdated,
+		eventbus.EventError,
+		eventbus.EventGroupAdded,
+		eventbus.EventGroupRemoved,
+		eventbus.EventStatusRefreshRequested,
+		eventbus.EventFetchRequested,
+		eventbus.EventRepoMoved,
+		eventbus.EventPullRequested,
+		eventbus.EventFetchCompleted,
+		eventbus.EventPullCompleted,
+		eventbus.EventPushCompleted,
+		eventbus.EventAppMessage,
+		eventbus.EventCommandExecuted,
+		eventbus.EventOperationProgress,
+		eventbus.EventWatchUpdated,
+		eventbus.EventScanCompleted,
+		eventbus.EventRepoRemoved,
+		eventbus.EventConfigChanged,
+		eventbus.EventCredentialRequested,
+		eventbus.EventConfigSaved,
+	} {
+		bus.Subscribe(eventType, events.Push)
+	}
+	termguard.Go(events.Run)
 
-	// Start forwarding events to UI in background
-	go func() {
-		for event := range eventChan {
-			p.Send(ui.EventMsg{Event: event})
-		}
-	}()
+	// Start forwarding events to UI in background, in batches of at most one per frame
+	termguard.Go(func() { ui.ForwardEvents(events.Events(), p.Send) })
 
 	// Initialize groups from config
 	for name := range cfg.Groups {
@@ -217,38 +379,201 @@ func main() {
 
 	// Start initial scan
 	if cfg.BaseDir != "" {
-		go func() {
-			_ = discoverySvc.StartScan(ctx, []string{cfg.BaseDir})
-		}()
+		if conn != nil {
+			bus.Publish(eventbus.ScanRequestedEvent{Paths: []string{cfg.BaseDir}})
+		} else {
+			roots := []string{cfg.BaseDir}
+			termguard.Go(func() {
+				_ = discoverySvc.StartScan(ctx, roots)
+			})
+		}
 	}
 
 	// Run the UI
 	if _, err := p.Run(); err != nil {
 		fmt.Printf("Error running program: %v\n", err)
-		os.Exit(1)
+		return "", 1
 	}
 
 	// Cleanup
-	close(eventChan)
+	events.Close()
+	if conn != nil {
+		_ = conn.Close()
+	}
 	cancel()
+	if statusCache != nil {
+		if err := statusCache.Flush(); err != nil {
+			log.Printf("Failed to save status cache: %v", err)
+		}
+	}
+	if watchState != nil {
+		if err := watchState.Flush(); err != nil {
+			log.Printf("Failed to save watch state: %v", err)
+		}
+	}
+	if snap != nil {
+		if err := snap.Flush(); err != nil {
+			log.Printf("Failed to save snapshot: %v", err)
+		}
+	}
+	if sessionPath != "" {
+		if err := session.Save(sessionPath, uiModel.Session()); err != nil {
+			log.Printf("Failed to save session: %v", err)
+		}
+	}
+	if configSaver.Dirty() {
+		fmt.Fprintf(os.Stderr, "Group changes were not saved to %s\n", configPath)
+	}
+
+	// Switching profiles starts the next run
+	if next := uiModel.NextProfile(); next != "" {
+		return next, 0
+	}
+
+	// Like fzf, jump fails when nothing was picked so that cd is skipped
+	if jump {
+		if uiModel.JumpPath() == "" {
+			return "", 1
+		}
+		fmt.Println(uiModel.JumpPath())
+	}
+	return "", 0
+}
+
+// openStatusCache opens the on-disk cache of last-known statuses for a workspace
+// (nil when disabled or when no cache directory is available)
+func openStatusCache(cfg *config.Config, baseDir string) domain.StatusCache {
+	if cfg.Cache.Disable {
+		return nil
+	}
+	cachePath, err := statuscache.DefaultPath(baseDir)
+	if err != nil {
+		log.Printf("Status cache disabled: %v", err)
+		return nil
+	}
+	return statuscache.Open(cachePath)
+}
+
+// openWatchState opens the acknowledged commits of watched branches for a workspace
+// (nil when no branches are watched, caching is disabled or no cache directory is available)
+func openWatchState(cfg *config.Config, baseDir string) domain.WatchState {
+	if len(cfg.Watches) == 0 || cfg.Cache.Disable {
+		return nil
+	}
+	statePath, err := watchstate.DefaultPath(baseDir)
+	if err != nil {
+		log.Printf("Branch watches disabled: %v", err)
+		return nil
+	}
+	return watchstate.Open(statePath)
+}
+
+// openSnapshot opens the repositories and statuses of the last run of a workspace and keeps
+// them current (nil when caching is disabled or no cache directory is available)
+func openSnapshot(bus eventbus.EventBus, cfg *config.Config, baseDir string) domain.Snapshot {
+	if cfg.Cache.Disable {
+		return nil
+	}
+	snapshotPath, err := snapshot.DefaultPath(baseDir)
+	if err != nil {
+		log.Printf("Snapshot disabled: %v", err)
+		return nil
+	}
+	return snapshot.Open(bus, snapshotPath)
+}
+
+// snapshotRepos returns the repositories of the snapshot that the startup scan of baseDir
+// covers (none without a snapshot). Those found by + scans elsewhere are left out: no scan
+// would confirm or drop them, so they would stay stale.
+func snapshotRepos(snap domain.Snapshot, baseDir string) []domain.Repository {
+	if snap == nil || baseDir == "" {
+		return nil
+	}
+	var repos []domain.Repository
+	for _, repo := range snap.Repos() {
+		if paths.Within(repo.Path, baseDir) {
+			repos = append(repos, repo)
+		}
+	}
+	return repos
+}
+
+// repoPaths returns the paths of repos
+func repoPaths(repos []domain.Repository) []string {
+	var result []string
+	for _, repo := range repos {
+		result = append(result, repo.Path)
+	}
+	return result
+}
+
+// sessionFile returns where the UI state of a workspace is kept between runs ("" when no
+// cache directory is available)
+func sessionFile(baseDir string) string {
+	path, err := session.DefaultPath(baseDir)
+	if err != nil {
+		log.Printf("Session not restored: %v", err)
+		return ""
+	}
+	return path
+}
+
+// needsSetup reports whether the setup wizard should pick the workspace: gitagrip was
+// started interactively without a directory, where there is no config yet
+func needsSetup(opts runOptions, configPath string) bool {
+	if opts.dirGiven || opts.jump {
+		return false
+	}
+	if _, err := os.Stat(configPath); !os.IsNotExist(err) {
+		return false
+	}
+	info, err := os.Stdin.Stat()
+	return err == nil && info.Mode()&os.ModeCharDevice != 0
 }
 
-// loadOrCreateConfig loads config from the directory or creates a new one with auto-generated groups
-func loadOrCreateConfig(configSvc config.ConfigService, targetDir string) *config.Config {
-	// Try to load config from the target directory
-	configPath := filepath.Join(targetDir, ".gitagrip.toml")
+// loadProfile loads the config of the named profile, or creates it for the directory given
+// on the command line. It returns the config and where it is saved.
+func loadProfile(configSvc config.ConfigService, opts runOptions) (*config.Config, string, error) {
+	configPath, err := config.ProfilePath(opts.profile)
+	if err != nil {
+		return nil, "", err
+	}
+	if _, err := os.Stat(configPath); err != nil && !opts.dirGiven {
+		names, _ := config.ListProfiles()
+		if len(names) == 0 {
+			return nil, "", fmt.Errorf("no profile %q; create it with -profile %s -d <dir>", opts.profile, opts.profile)
+		}
+		return nil, "", fmt.Errorf("no profile %q (profiles: %s); create it with -profile %s -d <dir>",
+			opts.profile, strings.Join(names, ", "), opts.profile)
+	}
+	cfg, err := loadOrCreateConfig(configSvc, configPath, opts.dir, opts.grouping)
+	if err != nil {
+		return nil, "", err
+	}
+	if cfg.BaseDir == "" {
+		return nil, "", fmt.Errorf("profile %q has no base_dir (in %s)", opts.profile, configPath)
+	}
+	return cfg, configPath, nil
+}
 
+// loadOrCreateConfig loads the config at configPath or creates a new one there for the
+// target directory, with auto-generated groups grouped by the given strategy
+func loadOrCreateConfig(configSvc config.ConfigService, configPath, targetDir, grouping string) (*config.Config, error) {
 	// Check if config exists
 	if _, err := os.Stat(configPath); err == nil {
 		// Config exists, try to load it
 		if cfg, err := configSvc.LoadFromPath(configPath); err == nil {
 			log.Printf("Loaded config from %s", configPath)
-			return cfg
+			return cfg, nil
 		}
 	}
 
 	// No config or failed to load - create new one
 	log.Printf("Creating new config for %s", targetDir)
+	groups, err := discovery.AutoGroups(targetDir, grouping)
+	if err != nil {
+		return nil, err
+	}
 	cfg := &config.Config{
 		Version: 1,
 		BaseDir: targetDir,
@@ -256,7 +581,8 @@ func loadOrCreateConfig(configSvc config.ConfigService, targetDir string) *confi
 			ShowAheadBehind: true,
 			AutosaveOnExit:  true,
 		},
-		Groups: generateGroupsFromDirectory(targetDir),
+		Grouping: config.GroupingSettings{Strategy: grouping},
+		Groups:   groups,
 	}
 
 	// Save the config
@@ -264,70 +590,5 @@ func loadOrCreateConfig(configSvc config.ConfigService, targetDir string) *confi
 		log.Printf("Failed to save config: %v", err)
 	}
 
-	return cfg
-}
-
-// generateGroupsFromDirectory creates groups based on directory structure
-// For now, return empty groups and let the discovery service populate them
-func generateGroupsFromDirectory(baseDir string) map[string][]string {
-	groups := make(map[string][]string)
-
-	// Do a quick scan to find git repos and group them by immediate parent directory
-	// We'll limit depth to avoid hanging on large directories
-	maxDepth := 3
-	reposByParent := make(map[string][]string)
-
-	_ = filepath.WalkDir(baseDir, func(path string, d fs.DirEntry, err error) error {
-		if err != nil {
-			return nil // Continue walking
-		}
-
-		// Check depth
-		relPath, _ := filepath.Rel(baseDir, path)
-		depth := strings.Count(relPath, string(filepath.Separator))
-		if depth > maxDepth {
-			return filepath.SkipDir
-		}
-
-		// Skip common non-repo directories
-		if d.IsDir() {
-			name := d.Name()
-			if name == "node_modules" || name == ".npm" || name == "__pycache__" ||
-				name == ".pytest_cache" || name == "venv" || name == ".venv" ||
-				name == "target" || name == "build" || name == "dist" {
-				return filepath.SkipDir
-			}
-		}
-
-		// Check if this is a .git directory
-		if d.IsDir() && d.Name() == ".git" {
-			repoPath := filepath.Dir(path)
-
-			// Get the parent directory relative to base
-			relRepo, _ := filepath.Rel(baseDir, repoPath)
-			parentDir := filepath.Dir(relRepo)
-
-			// If repo is directly in base dir, don't create a group
-			if parentDir == "." {
-				return filepath.SkipDir
-			}
-
-			// Use the immediate parent directory as the group name
-			groupName := filepath.Base(parentDir)
-			reposByParent[groupName] = append(reposByParent[groupName], repoPath)
-
-			return filepath.SkipDir
-		}
-
-		return nil
-	})
-
-	// Only create groups that have 2 or more repos
-	for groupName, repos := range reposByParent {
-		if len(repos) >= 2 {
-			groups[groupName] = repos
-		}
-	}
-
-	return groups
+	return cfg, nil
 }